@@ -0,0 +1,19 @@
+#version 330 core
+in vec2 vUV;
+out vec4 FragColor;
+
+uniform sampler2D uImage;
+uniform vec2 uDirection;
+
+// Noyau gaussien 9 taps (5 poids symétriques)
+const float WEIGHTS[5] = float[](0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+
+void main() {
+    vec2 texel = uDirection / vec2(textureSize(uImage, 0));
+    vec3 result = texture(uImage, vUV).rgb * WEIGHTS[0];
+    for (int i = 1; i < 5; ++i) {
+        result += texture(uImage, vUV + texel * float(i)).rgb * WEIGHTS[i];
+        result += texture(uImage, vUV - texel * float(i)).rgb * WEIGHTS[i];
+    }
+    FragColor = vec4(result, 1.0);
+}
@@ -0,0 +1,23 @@
+#version 330 core
+in vec2 vUV;
+out vec4 FragColor;
+
+#include "common/tonemap.glsl"
+
+uniform sampler2D uScene;
+uniform sampler2D uBloom;
+uniform float uIntensity;
+uniform sampler2D uLensDirt;
+uniform float uLensDirtStrength;
+uniform float uExposure;
+uniform int uToneMapping;
+uniform float uGamma;
+
+void main() {
+    vec3 scene = texture(uScene, vUV).rgb;
+    vec3 bloom = texture(uBloom, vUV).rgb;
+    // Salissures d'objectif : visibles seulement là où le halo est intense
+    float dirt = texture(uLensDirt, vUV).r * uLensDirtStrength;
+    vec3 color = scene + bloom * uIntensity * (1.0 + dirt);
+    FragColor = vec4(encode_gamma(tonemap(color * uExposure, uToneMapping), uGamma), 1.0);
+}
@@ -0,0 +1,23 @@
+#version 330 core
+in vec2 vUV;
+out vec4 FragColor;
+
+#include "common/color.glsl"
+
+uniform sampler2D uScene;
+uniform float uThreshold;
+uniform float uSoftKnee;
+
+// Même courbe que `bloom::soft_threshold` côté Rust
+float soft_threshold(float luma) {
+    float knee = uThreshold * clamp(uSoftKnee, 0.0, 1.0);
+    float soft = clamp(luma - uThreshold + knee, 0.0, 2.0 * knee);
+    soft = soft * soft / (4.0 * knee + 1e-5);
+    return max(soft, luma - uThreshold) / max(luma, 1e-5);
+}
+
+void main() {
+    vec3 color = texture(uScene, vUV).rgb;
+    float luma = luminance(color);
+    FragColor = vec4(color * soft_threshold(luma), 1.0);
+}
@@ -0,0 +1,6 @@
+// Utilitaires couleur partagés par les passes de post-process
+
+// Luminance relative (Rec. 709)
+float luminance(vec3 color) {
+    return dot(color, vec3(0.2126, 0.7152, 0.0722));
+}
@@ -0,0 +1,48 @@
+// Opérateurs de tone mapping (indices = `ToneMappingMode::shader_index`)
+
+vec3 tonemap_reinhard(vec3 c) {
+    return c / (1.0 + c);
+}
+
+// Approximation ACES filmique (Narkowicz 2015)
+vec3 tonemap_aces(vec3 c) {
+    return clamp((c * (2.51 * c + 0.03)) / (c * (2.43 * c + 0.59) + 0.14), 0.0, 1.0);
+}
+
+// AgX (approximation polynomiale de la courbe de contraste par défaut)
+vec3 agx_contrast(vec3 x) {
+    vec3 x2 = x * x;
+    vec3 x4 = x2 * x2;
+    return 15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4 - 6.868 * x2 * x + 0.4298 * x2
+        + 0.1191 * x - 0.00232;
+}
+
+vec3 tonemap_agx(vec3 c) {
+    const mat3 inset = mat3(
+        0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+        0.0784335999999992, 0.878468636469772, 0.0784336,
+        0.0792237451477643, 0.0791661274605434, 0.879142973793104);
+    const mat3 outset = mat3(
+        1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+        -0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+        -0.0990297440797205, -0.0989611768448433, 1.15107367264116);
+    const float min_ev = -12.47393;
+    const float max_ev = 4.026069;
+
+    vec3 v = inset * c;
+    v = clamp(log2(max(v, vec3(1e-10))), min_ev, max_ev);
+    v = (v - min_ev) / (max_ev - min_ev);
+    return clamp(outset * agx_contrast(v), 0.0, 1.0);
+}
+
+// Encodage gamma de la sortie (1.0 : aucun), cf. `shader_gamma`
+vec3 encode_gamma(vec3 c, float gamma) {
+    return pow(max(c, vec3(0.0)), vec3(1.0 / gamma));
+}
+
+vec3 tonemap(vec3 c, int mode) {
+    if (mode == 1) return tonemap_reinhard(c);
+    if (mode == 2) return tonemap_aces(c);
+    if (mode == 3) return tonemap_agx(c);
+    return c; // linéaire : écrêtage par la cible 8 bits
+}
@@ -0,0 +1,8 @@
+#version 330 core
+out vec2 vUV;
+
+void main() {
+    // Triangle strip plein écran généré depuis gl_VertexID
+    vUV = vec2(float(gl_VertexID & 1), float(gl_VertexID >> 1));
+    gl_Position = vec4(vUV * 2.0 - 1.0, 0.0, 1.0);
+}
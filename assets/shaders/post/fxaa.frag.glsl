@@ -0,0 +1,52 @@
+#version 330 core
+// FXAA simplifié (d'après T. Lottes) : flou orienté le long des contours détectés
+// par le contraste de luminance, appliqué après la composition / l'exposition.
+in vec2 vUV;
+out vec4 FragColor;
+
+uniform sampler2D uImage;
+uniform vec2 uInvResolution;
+
+const float FXAA_SPAN_MAX = 8.0;
+const float FXAA_REDUCE_MUL = 1.0 / 8.0;
+const float FXAA_REDUCE_MIN = 1.0 / 128.0;
+
+float luma(vec3 color) {
+    return dot(clamp(color, 0.0, 1.0), vec3(0.299, 0.587, 0.114));
+}
+
+void main() {
+    vec3 rgbNW = texture(uImage, vUV + vec2(-1.0, -1.0) * uInvResolution).rgb;
+    vec3 rgbNE = texture(uImage, vUV + vec2(1.0, -1.0) * uInvResolution).rgb;
+    vec3 rgbSW = texture(uImage, vUV + vec2(-1.0, 1.0) * uInvResolution).rgb;
+    vec3 rgbSE = texture(uImage, vUV + vec2(1.0, 1.0) * uInvResolution).rgb;
+    vec3 rgbM = texture(uImage, vUV).rgb;
+
+    float lumaNW = luma(rgbNW);
+    float lumaNE = luma(rgbNE);
+    float lumaSW = luma(rgbSW);
+    float lumaSE = luma(rgbSE);
+    float lumaM = luma(rgbM);
+    float lumaMin = min(lumaM, min(min(lumaNW, lumaNE), min(lumaSW, lumaSE)));
+    float lumaMax = max(lumaM, max(max(lumaNW, lumaNE), max(lumaSW, lumaSE)));
+
+    // Direction perpendiculaire au gradient de luminance
+    vec2 dir = vec2(
+        -((lumaNW + lumaNE) - (lumaSW + lumaSE)),
+        (lumaNW + lumaSW) - (lumaNE + lumaSE)
+    );
+    float dirReduce = max((lumaNW + lumaNE + lumaSW + lumaSE) * 0.25 * FXAA_REDUCE_MUL, FXAA_REDUCE_MIN);
+    float rcpDirMin = 1.0 / (min(abs(dir.x), abs(dir.y)) + dirReduce);
+    dir = clamp(dir * rcpDirMin, vec2(-FXAA_SPAN_MAX), vec2(FXAA_SPAN_MAX)) * uInvResolution;
+
+    vec3 rgbA = 0.5 * (
+        texture(uImage, vUV + dir * (1.0 / 3.0 - 0.5)).rgb +
+        texture(uImage, vUV + dir * (2.0 / 3.0 - 0.5)).rgb);
+    vec3 rgbB = rgbA * 0.5 + 0.25 * (
+        texture(uImage, vUV - dir * 0.5).rgb +
+        texture(uImage, vUV + dir * 0.5).rgb);
+
+    // Échantillonnage large hors de la plage locale : on garde l'estimation étroite
+    float lumaB = luma(rgbB);
+    FragColor = vec4((lumaB < lumaMin || lumaB > lumaMax) ? rgbA : rgbB, 1.0);
+}
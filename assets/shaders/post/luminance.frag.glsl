@@ -0,0 +1,14 @@
+#version 330 core
+in vec2 vUV;
+out vec4 FragColor;
+
+#include "common/color.glsl"
+
+uniform sampler2D uScene;
+
+void main() {
+    // Moyenne géométrique : on moyenne log(luminance), les mipmaps font la réduction
+    vec3 color = texture(uScene, vUV).rgb;
+    float luma = luminance(color);
+    FragColor = vec4(log(luma + 1e-4), 0.0, 0.0, 1.0);
+}
@@ -0,0 +1,80 @@
+//! Benchmarks du moteur physique (`cargo bench --bench physic_bench`).
+//!
+//! Trois tailles de scène (~1k, ~10k, ~100k particules) :
+//! - `physic_update` : coût d'un pas `update(0.016)` sur une scène "au pic"
+//!   (toutes les fusées lancées puis explosées)
+//! - `iter_active_particles` : coût seul du parcours des particules actives
+//!   (ce que consomment les renderers à chaque frame)
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig,
+    physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
+    PhysicEngine, PhysicEngineIterator,
+};
+
+const PARTICLES_PER_EXPLOSION: usize = 192;
+const PARTICLES_PER_TRAIL: usize = 64;
+const DT: f32 = 0.016;
+
+/// (label, nombre de fusées) → `rockets × (192 + 64)` particules
+const SCENES: &[(&str, usize)] = &[("1k", 4), ("10k", 40), ("100k", 400)];
+
+fn bench_config(max_rockets: usize) -> PhysicConfig {
+    PhysicConfig {
+        max_rockets,
+        particles_per_explosion: PARTICLES_PER_EXPLOSION,
+        particles_per_trail: PARTICLES_PER_TRAIL,
+        // Pas de lancement automatique pendant la mesure
+        rocket_interval_mean: 1.0e6,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    }
+}
+
+/// Construit une scène au pic : toutes les fusées lancées, un pas de trail, puis explosées.
+fn build_scene(max_rockets: usize) -> PhysicEngineFireworks {
+    let mut engine = PhysicEngineFireworks::new(&bench_config(max_rockets), 1920.0);
+    engine.spawn_n_rockets(max_rockets);
+    engine.update(DT);
+    engine.force_explode_all();
+    engine
+}
+
+fn bench_update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("physic_update");
+    for &(label, rockets) in SCENES {
+        group.throughput(Throughput::Elements(
+            (rockets * (PARTICLES_PER_EXPLOSION + PARTICLES_PER_TRAIL)) as u64,
+        ));
+        group.bench_with_input(BenchmarkId::from_parameter(label), &rockets, |b, &n| {
+            b.iter_batched(
+                || build_scene(n),
+                |mut engine| {
+                    black_box(engine.update(DT).triggered_explosions.len());
+                    engine
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_iter_active_particles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter_active_particles");
+    for &(label, rockets) in SCENES {
+        let engine = build_scene(rockets);
+        let active = engine.iter_active_particles().count();
+        group.throughput(Throughput::Elements(active as u64));
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| black_box(engine.iter_active_particles().map(|p| p.pos.x).sum::<f32>()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_update, bench_iter_active_particles);
+criterion_main!(benches);
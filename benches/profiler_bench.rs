@@ -0,0 +1,37 @@
+//! Coût du profiler par appel (`cargo bench --bench profiler_bench`).
+//!
+//! - `enabled` : mesure complète (`Instant::now`, verrou, fenêtre glissante)
+//! - `disabled` : `profiler.off`, le guard ne fait rien
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+use fireworks_sim::profiler::Profiler;
+
+fn bench_measure(c: &mut Criterion) {
+    let mut group = c.benchmark_group("profiler_measure");
+    let profiler = Profiler::new(200);
+    for (label, enabled) in [("enabled", true), ("disabled", false)] {
+        Profiler::set_enabled(enabled);
+        group.bench_function(label, |b| {
+            b.iter(|| drop(black_box(profiler.measure("audio_frame"))))
+        });
+    }
+    Profiler::set_enabled(true);
+    group.finish();
+}
+
+fn bench_record_metric(c: &mut Criterion) {
+    let mut group = c.benchmark_group("profiler_record_metric");
+    let profiler = Profiler::new(200);
+    for (label, enabled) in [("enabled", true), ("disabled", false)] {
+        Profiler::set_enabled(enabled);
+        group.bench_function(label, |b| {
+            b.iter(|| profiler.record_metric("particles", black_box(42usize)))
+        });
+    }
+    Profiler::set_enabled(true);
+    group.finish();
+}
+
+criterion_group!(benches, bench_measure, bench_record_metric);
+criterion_main!(benches);
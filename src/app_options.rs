@@ -0,0 +1,449 @@
+//! Options de l'application, issues de la ligne de commande (clap) avec les
+//! variables d'environnement en repli.
+//!
+//! ```text
+//! fireworks-sim [run] [--physic-config <toml>] [--renderer-config <toml>]
+//!                     [--audio-export <wav>] [--fullscreen] [--size WxH] [--seed N]
+//!                     [--demo] [--music <wav>] [--fresh] [--duration <s>]
+//!                     [--watch-config] [--set <section.field=value>]... [--log-file <path>] ...
+//! fireworks-sim bench --frames N [--max-rockets N] [--fail-below-fps F] [--window|--no-render]
+//! fireworks-sim headless --duration <s>     (ou --headless)
+//! ```
+//!
+//! Sans sous-commande, `run` est implicite. Un argument CLI l'emporte toujours
+//! sur la variable d'environnement équivalente.
+
+use std::path::PathBuf;
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+
+use crate::bench::{BenchOptions, BenchRenderer, BENCH_DEFAULT_SEED};
+use crate::config_manager::{ConfigOverride, CONFIG_ENV_PREFIX};
+use crate::physic_engine::config::PHYSIC_CONFIG_PATH;
+use crate::profiler_export::{METRICS_APPEND_ENV, METRICS_OUT_ENV};
+use crate::renderer_engine::config::RENDERER_CONFIG_PATH;
+use crate::utils::human_bytes::ByteSize;
+use crate::utils::log_file::{
+    LogFileConfig, DEFAULT_LOG_FILE_KEEP, DEFAULT_LOG_FILE_MAX_SIZE, LOG_FILE_ENV,
+};
+
+/// Variable d'environnement équivalente à `--audio-export`
+pub const AUDIO_EXPORT_ENV: &str = "FIREWORKS_AUDIO_EXPORT";
+
+/// Taille de fenêtre par défaut
+pub const DEFAULT_WINDOW_SIZE: (i32, i32) = (1024, 800);
+
+/// Mode d'exécution (sous-commande)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppCommand {
+    /// Fenêtre interactive (par défaut)
+    Run,
+    /// Nombre fixe de frames puis rapport JSON (cf. `crate::bench`)
+    Bench(BenchOptions),
+    /// Ni fenêtre ni OpenGL : `duration` secondes simulées aussi vite que possible
+    Headless { duration: f32 },
+}
+
+/// Options résolues de l'application, transmises aux constructeurs des moteurs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppOptions {
+    pub command: AppCommand,
+    /// Racine des assets (`assets/...` re-enracinés, cf. `crate::utils::assets`)
+    pub assets_dir: Option<PathBuf>,
+    pub physic_config: PathBuf,
+    pub renderer_config: PathBuf,
+    /// `--set section.field=value`, dans l'ordre (le dernier l'emporte)
+    pub config_overrides: Vec<ConfigOverride>,
+    /// Export WAV du mixage audio
+    pub audio_export: Option<PathBuf>,
+    /// Démarre en plein écran (mode configuré dans renderer.toml)
+    pub fullscreen: bool,
+    /// Taille de la fenêtre (ou du framebuffer hors écran)
+    pub size: (i32, i32),
+    /// `--size` donné : l'emporte sur la taille enregistrée dans la session
+    pub size_from_cli: bool,
+    /// Graine de la physique (exécutions reproductibles)
+    pub seed: Option<u64>,
+    /// Enregistrement vidéo des frames rendues
+    pub record: Option<PathBuf>,
+    /// Script de commandes console exécuté au démarrage
+    pub exec: Option<PathBuf>,
+    /// Capture du profiler (Chrome trace) écrite à la fermeture
+    pub trace: Option<PathBuf>,
+    /// Résumé du profiler écrit à la fermeture
+    pub metrics_out: Option<PathBuf>,
+    /// Une ligne de métriques par intervalle de log
+    pub metrics_append: Option<PathBuf>,
+    /// Démarre en mode démo (cf. `crate::demo_director`)
+    pub demo: bool,
+    /// Bande-son sur laquelle les explosions sont calées
+    pub music: Option<PathBuf>,
+    /// Ignore la session enregistrée (cf. `crate::session`)
+    pub fresh: bool,
+    /// Fin du spectacle après ce temps simulé, ciel vidé (`run`, `headless`)
+    pub duration: Option<f32>,
+    /// Configs rechargées dès que leur fichier change (cf. `crate::config_manager`)
+    pub watch_config: bool,
+    /// Journal fichier avec rotation (cf. `crate::utils::log_file`)
+    pub log_file: Option<LogFileConfig>,
+}
+
+impl Default for AppOptions {
+    fn default() -> Self {
+        Self {
+            command: AppCommand::Run,
+            assets_dir: None,
+            physic_config: PathBuf::from(PHYSIC_CONFIG_PATH),
+            renderer_config: PathBuf::from(RENDERER_CONFIG_PATH),
+            config_overrides: Vec::new(),
+            audio_export: None,
+            fullscreen: false,
+            size: DEFAULT_WINDOW_SIZE,
+            size_from_cli: false,
+            seed: None,
+            record: None,
+            exec: None,
+            trace: None,
+            metrics_out: None,
+            metrics_append: None,
+            demo: false,
+            music: None,
+            fresh: false,
+            duration: None,
+            watch_config: false,
+            log_file: None,
+        }
+    }
+}
+
+impl AppOptions {
+    /// Options du processus courant ; affiche l'aide ou l'erreur et quitte si besoin.
+    pub fn from_env() -> Self {
+        let matches = cli_command().get_matches();
+        Self::from_matches(&matches, |name| std::env::var(name).ok())
+    }
+
+    /// Analyse `args` (nom du programme compris) ; `env` donne les variables
+    /// d'environnement utilisées en repli.
+    pub fn try_parse_from<I, T>(
+        args: I,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let matches = cli_command().try_get_matches_from(args)?;
+        Ok(Self::from_matches(&matches, env))
+    }
+
+    fn from_matches(matches: &ArgMatches, env: impl Fn(&str) -> Option<String>) -> Self {
+        let (command, args) = match matches.subcommand() {
+            Some(("bench", args)) => (
+                AppCommand::Bench(BenchOptions {
+                    frames: *args.get_one::<u64>("frames").expect("required"),
+                    max_rockets: args.get_one::<usize>("max-rockets").copied(),
+                    fail_below_fps: args.get_one::<f32>("fail-below-fps").copied(),
+                    renderer: if args.get_flag("window") {
+                        BenchRenderer::Window
+                    } else if args.get_flag("no-render") {
+                        BenchRenderer::None
+                    } else {
+                        BenchRenderer::Offscreen
+                    },
+                }),
+                args,
+            ),
+            Some(("headless", args)) => (
+                AppCommand::Headless {
+                    duration: *args.get_one::<f32>("duration").expect("default value"),
+                },
+                args,
+            ),
+            Some(("run", args)) => (AppCommand::Run, args),
+            _ => (AppCommand::Run, matches),
+        };
+        let path = |id: &str| args.get_one::<PathBuf>(id).cloned();
+        let path_or_env = |id: &str, var: &str| path(id).or_else(|| env(var).map(PathBuf::from));
+        let defaults = Self::default();
+        Self {
+            command,
+            assets_dir: path("assets-dir"),
+            physic_config: path("physic-config").unwrap_or(defaults.physic_config),
+            renderer_config: path("renderer-config").unwrap_or(defaults.renderer_config),
+            config_overrides: args
+                .get_many::<ConfigOverride>("set")
+                .map(|values| values.cloned().collect())
+                .unwrap_or_default(),
+            audio_export: path_or_env("audio-export", AUDIO_EXPORT_ENV),
+            fullscreen: args.try_get_one::<bool>("fullscreen").ok().flatten() == Some(&true),
+            size: args
+                .get_one::<(i32, i32)>("size")
+                .copied()
+                .unwrap_or(defaults.size),
+            size_from_cli: args.get_one::<(i32, i32)>("size").is_some(),
+            seed: args.get_one::<u64>("seed").copied(),
+            record: args
+                .try_get_one::<PathBuf>("record")
+                .ok()
+                .flatten()
+                .cloned(),
+            exec: path("exec"),
+            trace: path("trace"),
+            metrics_out: path_or_env("metrics-out", METRICS_OUT_ENV),
+            metrics_append: path_or_env("metrics-append", METRICS_APPEND_ENV),
+            demo: args.get_flag("demo"),
+            music: path("music"),
+            fresh: args.try_get_one::<bool>("fresh").ok().flatten() == Some(&true),
+            duration: args.try_get_one::<f32>("duration").ok().flatten().copied(),
+            watch_config: args.try_get_one::<bool>("watch-config").ok().flatten() == Some(&true),
+            log_file: path_or_env("log-file", LOG_FILE_ENV).map(|path| LogFileConfig {
+                max_size: args
+                    .get_one::<ByteSize>("log-file-max-size")
+                    .copied()
+                    .unwrap_or(DEFAULT_LOG_FILE_MAX_SIZE),
+                keep: args
+                    .get_one::<usize>("log-file-keep")
+                    .copied()
+                    .unwrap_or(DEFAULT_LOG_FILE_KEEP),
+                ..LogFileConfig::new(path)
+            }),
+        }
+    }
+}
+
+/// `WxH` (ex. `1280x720`), dimensions strictement positives
+pub fn parse_window_size(text: &str) -> Result<(i32, i32), String> {
+    let parsed = text
+        .split_once(['x', 'X'])
+        .and_then(|(w, h)| Some((w.trim().parse::<i32>().ok()?, h.trim().parse::<i32>().ok()?)));
+    match parsed {
+        Some((w, h)) if w > 0 && h > 0 => Ok((w, h)),
+        _ => Err(format!(
+            "invalid size '{}' (expected WxH, e.g. 1280x720)",
+            text
+        )),
+    }
+}
+
+/// Options communes à toutes les sous-commandes
+fn common_args() -> Vec<Arg> {
+    let path_arg = |id: &'static str, value_name: &'static str, help: String| {
+        Arg::new(id)
+            .long(id)
+            .value_name(value_name)
+            .value_parser(value_parser!(PathBuf))
+            .help(help)
+    };
+    vec![
+        path_arg(
+            "assets-dir",
+            "DIR",
+            "Assets directory, replaces the relative 'assets/' of every asset path".to_string(),
+        ),
+        path_arg(
+            "physic-config",
+            "TOML",
+            format!("Physics configuration [default: {}]", PHYSIC_CONFIG_PATH),
+        ),
+        path_arg(
+            "renderer-config",
+            "TOML",
+            format!("Renderer configuration [default: {}]", RENDERER_CONFIG_PATH),
+        ),
+        Arg::new("set")
+            .long("set")
+            .value_name("KEY=VALUE")
+            .action(ArgAction::Append)
+            .value_parser(|text: &str| text.parse::<ConfigOverride>())
+            .help(format!(
+                "Override a config key, e.g. physic.max_rockets=128 (repeatable) [env: {}<SECTION>__<FIELD>]",
+                CONFIG_ENV_PREFIX
+            )),
+        path_arg(
+            "audio-export",
+            "WAV",
+            format!(
+                "Export the mixed audio to a WAV file [env: {}]",
+                AUDIO_EXPORT_ENV
+            ),
+        )
+        .alias("export-audio"),
+        Arg::new("size")
+            .long("size")
+            .value_name("WxH")
+            .value_parser(parse_window_size)
+            .help(format!(
+                "Window size, also the width of the simulated sky [default: {}x{}]",
+                DEFAULT_WINDOW_SIZE.0, DEFAULT_WINDOW_SIZE.1
+            )),
+        Arg::new("seed")
+            .long("seed")
+            .value_name("N")
+            .value_parser(value_parser!(u64))
+            .help("Seed of the physics random generator (reproducible runs)"),
+        path_arg(
+            "exec",
+            "SCRIPT",
+            "Run a console commands script at startup".to_string(),
+        ),
+        path_arg(
+            "trace",
+            "JSON",
+            "Capture the profiler timeline (Chrome trace) until exit".to_string(),
+        ),
+        path_arg(
+            "metrics-out",
+            "CSV|JSON",
+            format!(
+                "Write the profiler metrics summary on exit [env: {}]",
+                METRICS_OUT_ENV
+            ),
+        ),
+        path_arg(
+            "metrics-append",
+            "CSV|JSON",
+            format!(
+                "Append a metrics row at every log interval [env: {}]",
+                METRICS_APPEND_ENV
+            ),
+        ),
+        path_arg(
+            "music",
+            "WAV",
+            "Soundtrack: launches are scheduled so that explosions land on its beats".to_string(),
+        ),
+        Arg::new("demo")
+            .long("demo")
+            .action(ArgAction::SetTrue)
+            .help("Attract mode: palette, shape, launch density and bloom vary slowly"),
+        path_arg(
+            "log-file",
+            "PATH",
+            format!(
+                "Also write the logs (info and above) to a rotated file [env: {}]",
+                LOG_FILE_ENV
+            ),
+        ),
+        Arg::new("log-file-max-size")
+            .long("log-file-max-size")
+            .value_name("SIZE")
+            .requires("log-file")
+            .value_parser(|text: &str| text.parse::<ByteSize>().map_err(|e| e.to_string()))
+            .help(format!(
+                "Size at which the log file is rotated, e.g. 20MiB [default: {:.0}]",
+                DEFAULT_LOG_FILE_MAX_SIZE
+            )),
+        Arg::new("log-file-keep")
+            .long("log-file-keep")
+            .value_name("N")
+            .requires("log-file")
+            .value_parser(value_parser!(usize))
+            .help(format!(
+                "Rotated log files kept (<path>.1 to <path>.N) [default: {}]",
+                DEFAULT_LOG_FILE_KEEP
+            )),
+    ]
+}
+
+/// Options propres à l'affichage dans une fenêtre (`run`)
+fn window_args() -> Vec<Arg> {
+    vec![
+        Arg::new("fullscreen")
+            .long("fullscreen")
+            .action(ArgAction::SetTrue)
+            .help("Start in fullscreen (monitor and mode from the renderer configuration)"),
+        Arg::new("record")
+            .long("record")
+            .value_name("VIDEO")
+            .value_parser(value_parser!(PathBuf))
+            .help("Record the rendered frames to a video"),
+        Arg::new("fresh")
+            .long("fresh")
+            .action(ArgAction::SetTrue)
+            .help("Ignore the saved session (window, camera, runtime tweaks)"),
+        Arg::new("duration")
+            .long("duration")
+            .value_name("SECONDS")
+            .value_parser(value_parser!(f32))
+            .help("Stop after this simulated time, once the sky is clear"),
+        Arg::new("watch-config")
+            .long("watch-config")
+            .action(ArgAction::SetTrue)
+            .help("Reload the configuration files as soon as they change"),
+    ]
+}
+
+/// Définition de la ligne de commande (`--help` la documente entièrement)
+pub fn cli_command() -> Command {
+    Command::new("fireworks-sim")
+        .about("Fireworks simulator (physics, OpenGL rendering and 3D audio)")
+        .after_help("Without a subcommand, `run` options are accepted directly.")
+        .args_conflicts_with_subcommands(true)
+        .args(common_args())
+        .args(window_args())
+        .subcommand(
+            Command::new("run")
+                .about("Interactive simulation in a window (default)")
+                .args(common_args())
+                .args(window_args()),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Run a fixed number of frames, then print a JSON report")
+                .after_help(format!(
+                    "The physics seed defaults to {}. Audio is rendered offline.",
+                    BENCH_DEFAULT_SEED
+                ))
+                .args(common_args())
+                .arg(
+                    Arg::new("frames")
+                        .long("frames")
+                        .value_name("N")
+                        .value_parser(value_parser!(u64).range(1..))
+                        .required(true)
+                        .help("Number of frames to run"),
+                )
+                .arg(
+                    Arg::new("max-rockets")
+                        .long("max-rockets")
+                        .value_name("N")
+                        .value_parser(value_parser!(usize))
+                        .help("Override max_rockets of the physics configuration"),
+                )
+                .arg(
+                    Arg::new("fail-below-fps")
+                        .long("fail-below-fps")
+                        .value_name("FPS")
+                        .value_parser(value_parser!(f32))
+                        .help("Exit with a non-zero code if the average FPS is lower"),
+                )
+                .arg(
+                    Arg::new("window")
+                        .long("window")
+                        .action(ArgAction::SetTrue)
+                        .help("Render in a visible window instead of off screen"),
+                )
+                .arg(
+                    Arg::new("no-render")
+                        .long("no-render")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("window")
+                        .help("No window nor OpenGL: physics and audio only"),
+                ),
+        )
+        .subcommand(
+            Command::new("headless")
+                .long_flag("headless")
+                .about("No window nor OpenGL: simulate as fast as possible with a fixed time step")
+                .args(common_args())
+                .arg(
+                    Arg::new("duration")
+                        .long("duration")
+                        .value_name("SECONDS")
+                        .value_parser(value_parser!(f32))
+                        .default_value("60")
+                        .help("Simulated duration (launches stop early so that the sky is clear)"),
+                ),
+        )
+}
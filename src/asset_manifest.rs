@@ -0,0 +1,289 @@
+//! Vérification des assets au démarrage.
+//!
+//! Lancé depuis le mauvais répertoire, le programme échouait par morceaux (audio,
+//! shaders, textures, police), chacun à son tour. [`AssetManifest`] liste les
+//! fichiers que le mode choisi va lire, déduits des configs, et
+//! [`AssetManifest::check`] rend un rapport unique des absents : chemins absolus
+//! résolus et répertoire courant. Un asset dont l'absence est déjà gérée par le
+//! code (config par défaut, bloom désactivé...) n'empêche pas le démarrage, un
+//! asset embarqué (feature `embedded_assets`) n'est jamais manquant.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::app_options::{AppCommand, AppOptions};
+use crate::audio_engine::{FireworksAudioConfig, AUDIO_CONFIG_PATH};
+use crate::bench::BenchRenderer;
+use crate::physic_engine::particle_type::ParticleType;
+use crate::renderer_engine::bloom::BLOOM_SHADERS;
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::key_bindings::INPUT_CONFIG_PATH;
+use crate::renderer_engine::renderer::CONSOLE_FONT_PATH;
+use crate::utils::assets::{is_asset_path, reroot, resolve_with};
+use crate::utils::embedded_assets::embedded;
+
+/// Catégorie d'asset (regroupement du rapport)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Config,
+    Shader,
+    Sound,
+    Texture,
+    Font,
+}
+
+impl AssetKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AssetKind::Config => "config",
+            AssetKind::Shader => "shader",
+            AssetKind::Sound => "sound",
+            AssetKind::Texture => "texture",
+            AssetKind::Font => "font",
+        }
+    }
+}
+
+/// Conséquence de l'absence d'un asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetRequirement {
+    /// Démarrage impossible
+    Required,
+    /// Le programme continue sans la fonctionnalité (effet décrit)
+    Degraded(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetEntry {
+    pub kind: AssetKind,
+    /// Chemin effectif (re-enraciné sous `--assets-dir`)
+    pub path: PathBuf,
+    pub requirement: AssetRequirement,
+    /// Copie embarquée dans le binaire (feature `embedded_assets`) : jamais manquant
+    pub embedded: bool,
+}
+
+impl AssetEntry {
+    pub fn is_required(&self) -> bool {
+        self.requirement == AssetRequirement::Required
+    }
+}
+
+/// Fichiers lus par le mode d'exécution choisi.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetManifest {
+    /// Racine des assets (`None` : `./assets`)
+    assets_dir: Option<PathBuf>,
+    entries: Vec<AssetEntry>,
+}
+
+impl AssetManifest {
+    pub fn new(assets_dir: Option<PathBuf>) -> Self {
+        Self {
+            assets_dir,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Manifeste de `options.command` : configs et sons toujours, shaders,
+    /// textures et police seulement avec un renderer OpenGL (`renderer`).
+    pub fn from_configs(
+        options: &AppOptions,
+        audio: &FireworksAudioConfig,
+        renderer: Option<&RendererConfig>,
+    ) -> Self {
+        let mut manifest = Self::new(options.assets_dir.clone());
+        manifest.add(
+            AssetKind::Config,
+            &options.physic_config,
+            AssetRequirement::Degraded("default physics settings"),
+        );
+        manifest.add(
+            AssetKind::Config,
+            AUDIO_CONFIG_PATH,
+            AssetRequirement::Degraded("default audio settings"),
+        );
+        manifest.add(
+            AssetKind::Sound,
+            &audio.rocket_path,
+            AssetRequirement::Required,
+        );
+        manifest.add(
+            AssetKind::Sound,
+            &audio.explosion_path,
+            AssetRequirement::Required,
+        );
+        if let Some(rumble) = &audio.rumble_path {
+            manifest.add(
+                AssetKind::Sound,
+                rumble,
+                AssetRequirement::Degraded("explosions without rumble layer"),
+            );
+        }
+
+        let Some(renderer) = renderer else {
+            return manifest;
+        };
+        manifest.add(
+            AssetKind::Config,
+            &options.renderer_config,
+            AssetRequirement::Degraded("default renderer settings"),
+        );
+        manifest.add(
+            AssetKind::Config,
+            INPUT_CONFIG_PATH,
+            AssetRequirement::Degraded("default key bindings"),
+        );
+        for shader in BLOOM_SHADERS {
+            manifest.add(
+                AssetKind::Shader,
+                shader,
+                AssetRequirement::Degraded("bloom disabled"),
+            );
+        }
+        for particle_type in ParticleType::ALL {
+            let default = particle_type.default_texture_path();
+            let configured = renderer
+                .particles
+                .get(particle_type)
+                .texture_path(particle_type);
+            if configured != default {
+                manifest.add(
+                    AssetKind::Texture,
+                    configured,
+                    AssetRequirement::Degraded("default particle texture used"),
+                );
+            }
+            manifest.add(AssetKind::Texture, default, AssetRequirement::Required);
+        }
+        if !renderer.lens_dirt_texture.is_empty() {
+            manifest.add(
+                AssetKind::Texture,
+                &renderer.lens_dirt_texture,
+                AssetRequirement::Degraded("lens dirt disabled"),
+            );
+        }
+        if has_window(options) {
+            manifest.add(
+                AssetKind::Font,
+                CONSOLE_FONT_PATH,
+                AssetRequirement::Required,
+            );
+        }
+        manifest
+    }
+
+    /// Ajoute `path` (re-enraciné sous la racine du manifeste) ; un chemin déjà
+    /// listé n'est gardé qu'une fois, avec l'exigence la plus forte.
+    pub fn add(&mut self, kind: AssetKind, path: impl AsRef<Path>, requirement: AssetRequirement) {
+        let path = path.as_ref();
+        let embedded = embedded(path).is_some();
+        // `--assets-dir` explicite : vérifié tel quel ; sinon, même recherche que
+        // les chargements (cf. `utils::assets::resolve`)
+        let path = match (&self.assets_dir, path.to_str()) {
+            (Some(dir), _) => reroot(path, dir),
+            (None, Some(text)) if is_asset_path(path) => resolve_with(None, text),
+            _ => path.to_path_buf(),
+        };
+        match self.entries.iter_mut().find(|entry| entry.path == path) {
+            Some(entry) => {
+                if requirement == AssetRequirement::Required {
+                    entry.requirement = requirement;
+                }
+            }
+            None => self.entries.push(AssetEntry {
+                kind,
+                path,
+                requirement,
+                embedded,
+            }),
+        }
+    }
+
+    pub fn entries(&self) -> &[AssetEntry] {
+        &self.entries
+    }
+
+    /// Assets absents du disque, relativement à `working_dir`.
+    pub fn check_in(&self, working_dir: &Path) -> AssetReport {
+        let missing = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.embedded && !working_dir.join(&entry.path).is_file())
+            .cloned()
+            .collect();
+        AssetReport {
+            working_dir: working_dir.to_path_buf(),
+            assets_dir: self.assets_dir.clone(),
+            missing,
+        }
+    }
+
+    /// `check_in` depuis le répertoire courant.
+    pub fn check(&self) -> AssetReport {
+        let working_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        self.check_in(&working_dir)
+    }
+}
+
+/// Assets manquants, avec le contexte nécessaire pour corriger le lancement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetReport {
+    pub working_dir: PathBuf,
+    pub assets_dir: Option<PathBuf>,
+    pub missing: Vec<AssetEntry>,
+}
+
+impl AssetReport {
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Au moins un asset manquant empêche le démarrage.
+    pub fn is_fatal(&self) -> bool {
+        self.missing.iter().any(AssetEntry::is_required)
+    }
+}
+
+impl fmt::Display for AssetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} missing asset(s)", self.missing.len())?;
+        writeln!(f, "  working directory: {}", self.working_dir.display())?;
+        if let Some(dir) = &self.assets_dir {
+            writeln!(
+                f,
+                "  assets directory: {}",
+                self.working_dir.join(dir).display()
+            )?;
+        }
+        for entry in &self.missing {
+            let effect = match &entry.requirement {
+                AssetRequirement::Required => "required",
+                AssetRequirement::Degraded(effect) => effect,
+            };
+            writeln!(
+                f,
+                "  - [{}] {} ({})",
+                entry.kind.name(),
+                self.working_dir.join(&entry.path).display(),
+                effect
+            )?;
+        }
+        if self.is_fatal() {
+            write!(
+                f,
+                "Run from the repository root or pass --assets-dir <path to assets>"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Mode avec fenêtre (console et HUD) : `run`, `bench --window`
+fn has_window(options: &AppOptions) -> bool {
+    match options.command {
+        AppCommand::Run => true,
+        AppCommand::Headless { .. } => false,
+        AppCommand::Bench(bench) => bench.renderer == BenchRenderer::Window,
+    }
+}
@@ -1,5 +1,31 @@
+use log::debug;
 use std::time::Instant;
 
+use crate::audio_engine::AudioEngine;
+use crate::physic_engine::types::UpdateResult;
+
+/// Joue les sons déclenchés par une mise à jour de la physique : départ de fusée
+/// (entretenu jusqu'à son explosion) et explosions. Partagé par le renderer et la boucle headless du simulateur.
+pub fn play_physic_events<A: AudioEngine + ?Sized>(update_result: &UpdateResult, audio: &mut A) {
+    if let Some(rocket) = &update_result.new_rocket {
+        debug!("🚀 Rocket spawned at ({}, {})", rocket.pos.x, rocket.pos.y);
+        // z négatif = devant l'auditeur : plus la fusée est profonde, plus elle est lointaine
+        // Sifflement entretenu jusqu'à l'explosion de la fusée
+        audio.play_rocket_sustained(rocket.id, (rocket.pos.x, rocket.pos.y, -rocket.depth), 0.6);
+    }
+
+    for (i, expl) in update_result.triggered_explosions.iter().enumerate() {
+        debug!(
+            "💥 Explosion triggered: {} at ({}, {})",
+            i, expl.pos.x, expl.pos.y
+        );
+        if let Some(&rocket_id) = update_result.exploded_rocket_ids.get(i) {
+            audio.release_rocket(rocket_id);
+        }
+        audio.play_explosion_3d((expl.pos.x, expl.pos.y, -expl.depth), 1.0);
+    }
+}
+
 /// Event envoyé par le renderer/physic au moteur audio
 #[derive(Clone, Debug)]
 pub struct DopplerEvent {
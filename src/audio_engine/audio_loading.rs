@@ -1,8 +1,16 @@
 // =========================
 // Audio File Loading
 // =========================
+use std::borrow::Cow;
+use std::io::Cursor;
+use std::path::Path;
+
+use anyhow::Context;
 use hound::WavReader; // WAV file loader
 
+use crate::audio_engine::onset::{detect_onsets, OnsetSettings};
+use crate::utils::embedded_assets::read_asset;
+
 /// Charge un fichier WAV et le convertit en tampon stéréo `[f32; 2]`
 ///
 /// - Gère les fichiers mono et stéréo (duplique le canal gauche si mono)
@@ -15,8 +23,18 @@ use hound::WavReader; // WAV file loader
 /// # Retour
 /// * `Vec<[f32; 2]>` — échantillons stéréo prêtes à être joués ou traités
 pub fn load_audio(path: &str) -> Vec<[f32; 2]> {
-    // Ouvre le fichier WAV
-    let mut reader = WavReader::open(path).unwrap();
+    load_audio_with_rate(path).0
+}
+
+/// Comme `load_audio`, avec la fréquence d'échantillonnage du fichier.
+pub fn load_audio_with_rate(path: &str) -> (Vec<[f32; 2]>, u32) {
+    try_load_audio_with_rate(path).unwrap()
+}
+
+/// Comme `load_audio_with_rate`, sans paniquer si le fichier est absent ou illisible.
+pub fn try_load_audio_with_rate(path: &str) -> anyhow::Result<(Vec<[f32; 2]>, u32)> {
+    // Ouvre le fichier WAV (disque, sinon copie embarquée)
+    let mut reader = open_wav(path)?;
 
     // Récupère la description du flux audio (nombre de canaux, format, etc.)
     let spec = reader.spec();
@@ -53,7 +71,19 @@ pub fn load_audio(path: &str) -> Vec<[f32; 2]> {
     }
 
     // Retourne le buffer stéréo complet
-    data
+    Ok((data, spec.sample_rate))
+}
+
+/// Charge un fichier WAV et le rééchantillonne à `sample_rate` (cf. `resample_linear`).
+pub fn load_resampled(path: &str, sample_rate: u32) -> anyhow::Result<Vec<[f32; 2]>> {
+    let (data, native_rate) = try_load_audio_with_rate(path)?;
+    Ok(resample_linear(&data, native_rate, sample_rate))
+}
+
+/// Lecteur WAV sur le contenu de l'asset `path` (cf. `read_asset`)
+fn open_wav(path: &str) -> anyhow::Result<WavReader<Cursor<Cow<'static, [u8]>>>> {
+    let bytes = read_asset(path).with_context(|| format!("Cannot read {}", path))?;
+    Ok(WavReader::new(Cursor::new(bytes))?)
 }
 
 /// Resample audio linearly from src_sr → dst_sr
@@ -84,3 +114,34 @@ pub fn resample_linear(data: &[[f32; 2]], src_sr: u32, dst_sr: u32) -> Vec<[f32;
     }
     out
 }
+
+/// Piste musicale (`--music`) : échantillons stéréo à leur fréquence d'origine
+#[derive(Debug, Clone)]
+pub struct MusicTrack {
+    pub samples: Vec<[f32; 2]>,
+    pub sample_rate: u32,
+}
+
+impl MusicTrack {
+    /// Charge un fichier WAV (PCM 16 bits, cf. `load_audio`).
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let spec = WavReader::open(path)
+            .with_context(|| format!("{}: not a readable WAV file", path.display()))?
+            .spec();
+        let samples = load_audio(&path.to_string_lossy());
+        Ok(Self {
+            samples,
+            sample_rate: spec.sample_rate,
+        })
+    }
+
+    /// Durée (s)
+    pub fn duration(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate as f32
+    }
+
+    /// Attaques de la piste (s), cf. `detect_onsets`
+    pub fn onsets(&self, settings: &OnsetSettings) -> Vec<f32> {
+        detect_onsets(&self.samples, self.sample_rate, settings)
+    }
+}
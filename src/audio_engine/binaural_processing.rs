@@ -36,7 +36,7 @@ pub fn binauralize_mono(
     let far_gain = 10f32.powf(-ild_db / 20.0);
 
     // Atténuation avec distance (linéaire simple)
-    let att = (1.0 - distance / settings.max_distance()).max(0.0);
+    let att = settings.distance_attenuation(distance);
 
     // ---------------------------------------------------------------
     // 3. Détermination du côté proche / éloigné
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::audio_engine::{AudioEngineSettings, FireworksAudioConfig};
+use crate::config_manager::{ConfigSection, Reloadable, Violations};
+
+/// Chemin par défaut de la config audio
+pub const AUDIO_CONFIG_PATH: &str = "assets/config/audio.toml";
+
+/// Réglages audio lus dans `audio.toml` (tous appliqués au démarrage du moteur).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioConfig {
+    pub rocket_sound: String,
+    pub explosion_sound: String,
+    pub sample_rate: u32,
+    /// Taille des blocs rendus par le mixeur (échantillons)
+    pub block_size: usize,
+    /// Voix simultanées (plafonnées par `max_rockets`) : au-delà, effet mitraille
+    pub max_voices: usize,
+    /// Gain appliqué à tout le mixage
+    pub global_gain: f32,
+    /// Boucle d'entretien du sifflement des fusées, en fractions de
+    /// `rocket_sound` : rejouée jusqu'à l'explosion, puis la fin du son est jouée
+    pub rocket_loop_start: f32,
+    pub rocket_loop_end: f32,
+    /// Couche grave ajoutée aux explosions proches ; absente ou illisible, les
+    /// explosions gardent leur seul son
+    pub rumble_sound: Option<String>,
+    /// Distance en deçà de laquelle la couche grave s'ajoute, d'autant plus
+    /// forte que l'explosion est proche
+    pub rumble_crossfade_distance: f32,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            rocket_sound: "assets/sounds/rocket.wav".to_string(),
+            explosion_sound: "assets/sounds/explosion.wav".to_string(),
+            sample_rate: 48000,
+            block_size: 512,
+            max_voices: 32,
+            global_gain: AudioEngineSettings::default().global_gain,
+            rocket_loop_start: AudioEngineSettings::default().rocket_loop_start,
+            rocket_loop_end: AudioEngineSettings::default().rocket_loop_end,
+            rumble_sound: None,
+            rumble_crossfade_distance: AudioEngineSettings::default().rumble_crossfade_distance,
+        }
+    }
+}
+
+/// Sons et flux audio sont fixés à l'ouverture du périphérique.
+impl Reloadable for AudioConfig {
+    const SECTION: ConfigSection = ConfigSection::Audio;
+    const RESTART_FIELDS: &'static [&'static str] = &[
+        "rocket_sound",
+        "explosion_sound",
+        "sample_rate",
+        "block_size",
+        "max_voices",
+        "global_gain",
+        "rocket_loop_start",
+        "rocket_loop_end",
+        "rumble_sound",
+        "rumble_crossfade_distance",
+    ];
+
+    fn validate(&self) -> Vec<String> {
+        let mut v = Violations::default();
+        v.check(
+            self.sample_rate > 0,
+            "sample_rate",
+            "must be greater than 0",
+        );
+        v.positive("block_size", self.block_size);
+        v.positive("max_voices", self.max_voices);
+        v.check(
+            self.global_gain >= 0.0,
+            "global_gain",
+            "must not be negative",
+        );
+        v.in_range("rocket_loop_start", self.rocket_loop_start, (0.0, 1.0));
+        v.in_range("rocket_loop_end", self.rocket_loop_end, (0.0, 1.0));
+        v.check(
+            self.rocket_loop_end > self.rocket_loop_start,
+            "rocket_loop_end",
+            "must be greater than rocket_loop_start",
+        );
+        v.check(
+            self.rumble_crossfade_distance >= 0.0,
+            "rumble_crossfade_distance",
+            "must not be negative",
+        );
+        v.into_vec()
+    }
+}
+
+impl AudioConfig {
+    /// Config du moteur : l'auditeur démarre à l'origine, au plus une voix par fusée.
+    pub fn engine_config(&self, max_rockets: usize) -> FireworksAudioConfig {
+        let settings = AudioEngineSettings {
+            global_gain: self.global_gain,
+            rocket_loop_start: self.rocket_loop_start,
+            rocket_loop_end: self.rocket_loop_end,
+            rumble_crossfade_distance: self.rumble_crossfade_distance,
+            ..AudioEngineSettings::default()
+        };
+        FireworksAudioConfig {
+            rocket_path: self.rocket_sound.clone(),
+            explosion_path: self.explosion_sound.clone(),
+            rumble_path: self.rumble_sound.clone(),
+            listener_pos: (0.0, 0.0),
+            sample_rate: self.sample_rate,
+            block_size: self.block_size,
+            max_voices: self.max_voices.min(max_rockets),
+            settings,
+        }
+    }
+}
@@ -1,3 +1,31 @@
+/// Part de la couche grave (« rumble ») d'une explosion à `distance` : 1 au
+/// contact, 0 à partir de `crossfade_distance`, raccord en smoothstep entre les
+/// deux. Une distance de fondu nulle désactive la couche.
+pub fn rumble_crossfade(distance: f32, crossfade_distance: f32) -> f32 {
+    if crossfade_distance <= 0.0 {
+        return 0.0;
+    }
+    let t = (1.0 - distance / crossfade_distance).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Superpose `layer`, pondéré par `factor`, à `main` : la sortie a la longueur
+/// du plus long des deux.
+pub fn mix_layers(main: &[[f32; 2]], layer: &[[f32; 2]], factor: f32) -> Vec<[f32; 2]> {
+    let mut out = main.to_vec();
+    if factor <= 0.0 {
+        return out;
+    }
+    if layer.len() > out.len() {
+        out.resize(layer.len(), [0.0; 2]);
+    }
+    for (o, l) in out.iter_mut().zip(layer) {
+        o[0] += l[0] * factor;
+        o[1] += l[1] * factor;
+    }
+    out
+}
+
 /// Resample mono audio (linear interpolation).
 ///
 /// - `input` : slice mono (`&[f32]`)
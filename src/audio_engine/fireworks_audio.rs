@@ -1,29 +1,42 @@
+use crate::audio_engine::mixer::{
+    apply_settings, drain_play_queue, mix_voices, playback_rate, release_rocket_voices, rms,
+    soft_clip, RocketVoices,
+};
 use crate::audio_engine::types::{
     // DopplerState,
     FireworksAudioConfig,
     PlayRequest,
     RocketAudioState,
+    Sustain,
     Voice,
 };
 use crate::audio_engine::{
     binauralize_mono,
-    load_audio,
+    load_audio_with_rate,
+    load_resampled,
+    mix_layers,
     resample_linear,
+    rumble_crossfade,
     AudioBlock,
     AudioEngine,
     // DopplerEvent,
     SafeWavWriter,
+    SharedSettings,
 };
+use crate::utils::memory_stats::register_allocation;
+use crate::utils::system_report::{record_audio, AudioBackendInfo};
 use crate::AudioEngineSettings;
-use crate::{log_metrics, profiler::Profiler};
+use crate::{
+    log_metrics,
+    profiler::{Profiler, ProfilerCategory},
+};
 // CPAL: cross-platform audio API
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 // use crossbeam::channel::Receiver;
-use hound::WavReader; // WAV file loader
-use log::{debug, info};
+use log::{debug, error, info, warn};
 use std::collections::HashMap;
 use std::collections::VecDeque; // Queue for pending sound events
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex}; // Thread-safe shared state
 use std::thread;
 use std::time::{Duration, Instant};
@@ -31,37 +44,115 @@ use std::time::{Duration, Instant};
 pub struct FireworksAudio3D {
     rocket_data: Vec<[f32; 2]>,
     explosion_data: Vec<[f32; 2]>,
+    /// Couche grave ajoutée aux explosions proches (`None` : son unique)
+    rumble_data: Option<Vec<[f32; 2]>>,
     listener_pos: (f32, f32),
     sample_rate: u32,
     block_size: usize,
     voices: Vec<Voice>,
+    /// Voix actives, mis à jour par le thread audio à chaque bloc
+    active_voices: Arc<AtomicUsize>,
+    /// Coupure de toutes les voix demandée, appliquée par le thread audio au bloc suivant
+    flush_voices: Arc<AtomicBool>,
+    /// Sons abandonnés faute de voix libre, depuis le démarrage
+    dropped_requests: Arc<AtomicU64>,
+    /// Pause globale : silence, voix figées et export WAV suspendu
+    paused: Arc<AtomicBool>,
+    /// Vitesse de lecture des voix (bits d'un `f32`, cf. `set_time_scale`)
+    playback_rate: Arc<AtomicU32>,
+    /// Niveau RMS du dernier bloc envoyé à la sortie (bits d'un `f32`)
+    output_level: Arc<AtomicU32>,
     play_queue: Arc<Mutex<VecDeque<PlayRequest>>>,
-    settings: AudioEngineSettings,
+    /// Explosions (ids de fusée) à appliquer aux voix entretenues, au bloc suivant
+    rocket_releases: Arc<Mutex<Vec<u64>>>,
+    /// Réglages lus par le mixeur à chaque bloc (cf. `set_settings`)
+    settings: SharedSettings,
     running_pair: Arc<(Mutex<bool>, Condvar)>,
+    /// Thread audio terminé (export WAV finalisé), cf. `AudioShutdown`
+    finished_pair: Arc<(Mutex<bool>, Condvar)>,
     // doppler_receiver: Option<Receiver<DopplerEvent>>,
     // doppler_states: Vec<DopplerState>,
     global_gain: f32,
+    /// Volume réglé (`global_gain` vaut 0 tant que `muted`)
+    volume: f32,
+    muted: bool,
+    /// Rendu hors-ligne en cours (`start_offline`), à la place du thread CPAL
+    offline: Option<OfflineRender>,
+}
+
+/// Arrêt du moteur audio depuis un autre contexte que son propriétaire (hook de
+/// panique) : partage le signal d'arrêt du thread audio (`running_pair`) et
+/// celui de sa fin, une fois l'export WAV finalisé.
+#[derive(Debug, Clone)]
+pub struct AudioShutdown {
+    running_pair: Arc<(Mutex<bool>, Condvar)>,
+    finished_pair: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl AudioShutdown {
+    /// `false` dès que l'arrêt est demandé
+    pub fn is_running(&self) -> bool {
+        *self
+            .running_pair
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Demande l'arrêt du thread audio (le mutex empoisonné est ignoré).
+    pub fn request_stop(&self) {
+        let (lock, cvar) = &*self.running_pair;
+        *lock.lock().unwrap_or_else(|e| e.into_inner()) = false;
+        cvar.notify_all();
+    }
+
+    /// Attend au plus `timeout` la fin du thread audio (périphérique fermé, export
+    /// WAV finalisé). Retourne `true` si le thread est terminé ou n'a jamais démarré.
+    pub fn wait_finished(&self, timeout: Duration) -> bool {
+        let (lock, cvar) = &*self.finished_pair;
+        let finished = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let (finished, _) = cvar
+            .wait_timeout_while(finished, timeout, |finished| !*finished)
+            .unwrap_or_else(|e| e.into_inner());
+        *finished
+    }
+}
+
+/// État du rendu hors-ligne : le mixage avance au rythme de la simulation.
+struct OfflineRender {
+    voices: Vec<Voice>,
+    rocket_voices: RocketVoices,
+    acc: Vec<[f32; 2]>,
+    chunk: Vec<[f32; 2]>,
+    writer: Option<SafeWavWriter>,
+    /// Frames dues mais pas encore rendues (fraction de frame comprise)
+    pending_frames: f64,
+    block_index: u64,
+    profiler: Profiler,
 }
 
 impl FireworksAudio3D {
     /// Initialize the engine with WAV paths, sample rate, and max voices
     pub fn new(config: FireworksAudioConfig) -> Self {
         // Load WAV data
-        let mut rocket_data = load_audio(&config.rocket_path);
-        let mut explosion_data = load_audio(&config.explosion_path);
+        let (mut rocket_data, rocket_sr) = load_audio_with_rate(&config.rocket_path);
+        let (mut explosion_data, explosion_sr) = load_audio_with_rate(&config.explosion_path);
 
         // Resample to target sample rate
-        let rocket_sr = WavReader::open(&config.rocket_path)
-            .unwrap()
-            .spec()
-            .sample_rate;
-        let explosion_sr = WavReader::open(&config.explosion_path)
-            .unwrap()
-            .spec()
-            .sample_rate;
-
         rocket_data = resample_linear(&rocket_data, rocket_sr, config.sample_rate);
         explosion_data = resample_linear(&explosion_data, explosion_sr, config.sample_rate);
+        // Couche grave facultative : absente ou illisible, les explosions gardent leur seul son
+        let rumble_data = config.rumble_path.as_deref().and_then(|path| {
+            load_resampled(path, config.sample_rate)
+                .inspect_err(|e| warn!("🔈 Rumble layer disabled: {:#}", e))
+                .ok()
+        });
+        let rumble_len = rumble_data.as_ref().map_or(0, Vec::len);
+        register_allocation(
+            "audio: sample data",
+            ((rocket_data.len() + explosion_data.len() + rumble_len)
+                * std::mem::size_of::<[f32; 2]>()) as u64,
+        );
 
         let mut voices = Vec::with_capacity(config.max_voices);
         voices.resize_with(config.max_voices, Voice::new);
@@ -71,46 +162,87 @@ impl FireworksAudio3D {
         Self {
             rocket_data,
             explosion_data,
+            rumble_data,
             listener_pos: config.listener_pos,
             sample_rate: config.sample_rate,
             block_size: config.block_size,
             voices,
+            active_voices: Arc::new(AtomicUsize::new(0)),
+            flush_voices: Arc::new(AtomicBool::new(false)),
+            dropped_requests: Arc::new(AtomicU64::new(0)),
+            paused: Arc::new(AtomicBool::new(false)),
+            playback_rate: Arc::new(AtomicU32::new(1.0_f32.to_bits())),
+            output_level: Arc::new(AtomicU32::new(0.0_f32.to_bits())),
             play_queue: Arc::new(Mutex::new(VecDeque::new())),
-            settings: config.settings,
+            rocket_releases: Arc::new(Mutex::new(Vec::new())),
+            settings: SharedSettings::new(config.settings),
             running_pair: Arc::new((Mutex::new(true), Condvar::new())),
+            finished_pair: Arc::new((Mutex::new(true), Condvar::new())),
             // doppler_receiver: config.doppler_receiver,
             // doppler_states: config.doppler_states,
             global_gain,
+            volume: global_gain,
+            muted: false,
+            offline: None,
         }
     }
 
+    /// Réglages courants du moteur
+    pub fn settings(&self) -> Arc<AudioEngineSettings> {
+        self.settings.load()
+    }
+
+    /// Remplace les réglages : le gain global, le filtre passe-bas et
+    /// l'atténuation des voix déjà en cours changent dès le bloc suivant.
+    pub fn set_settings(&self, settings: AudioEngineSettings) {
+        info!("🎚️ Audio settings updated: {:?}", settings);
+        self.settings.store(settings);
+    }
+
+    /// Poignée partagée des réglages (mise à jour depuis un autre thread)
+    pub fn shared_settings(&self) -> SharedSettings {
+        self.settings.clone()
+    }
+
+    /// Couche grave des explosions chargée (cf. `FireworksAudioConfig::rumble_path`)
+    pub fn has_rumble_layer(&self) -> bool {
+        self.rumble_data.is_some()
+    }
+
+    /// Distance de la source à l'auditeur (l'auditeur est dans le plan z = 0)
+    fn source_distance(&self, pos: (f32, f32, f32)) -> f32 {
+        let dx = pos.0 - self.listener_pos.0;
+        let dy = pos.1 - self.listener_pos.1;
+        (dx * dx + dy * dy + pos.2 * pos.2).sqrt()
+    }
+
     // =========================
     // Prepare a voice for playback
     // =========================
     fn prepare_voice(
         &self,
         data: &[[f32; 2]],
-        pos: (f32, f32),
+        pos: (f32, f32, f32),
         gain: f32,
-    ) -> (Vec<[f32; 2]>, usize, usize, f32) {
+    ) -> (Vec<[f32; 2]>, usize, usize, f32, f32, f32) {
+        let settings = self.settings.load();
         // Distance attenuation
         let dx = pos.0 - self.listener_pos.0;
-        let dy = pos.1 - self.listener_pos.1;
-        let distance = (dx * dx + dy * dy).sqrt();
-        let att = (1.0 - distance / self.settings.max_distance()).max(0.0);
+        let distance = self.source_distance(pos);
+        let att = settings.distance_attenuation(distance);
 
         // Spatialization: binaural or panning
-        let stereo = if self.settings.use_binaural() {
+        let stereo = if settings.use_binaural() {
             let mono: Vec<f32> = data.iter().map(|s| (s[0] + s[1]) / 2.0).collect();
             binauralize_mono(
                 &mono,
-                (pos.0, pos.1, 0.0),
+                pos,
                 (self.listener_pos.0, self.listener_pos.1, 0.0),
                 self.sample_rate,
-                &self.settings,
+                &settings,
             )
         } else {
-            let pan = (dx / self.settings.max_distance()).clamp(-1.0, 1.0);
+            let pan = (dx / settings.max_distance()).clamp(-1.0, 1.0);
             let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
             let left_gain = angle.cos() * att * gain;
             let right_gain = angle.sin() * att * gain;
@@ -123,69 +255,149 @@ impl FireworksAudio3D {
         };
 
         // Fade-in/out samples
-        let fade_in_samples =
-            (self.sample_rate as f32 * (self.settings.fade_in_ms() / 1000.0)) as usize;
+        let fade_in_samples = (self.sample_rate as f32 * (settings.fade_in_ms() / 1000.0)) as usize;
         let fade_out_samples =
-            (self.sample_rate as f32 * (self.settings.fade_out_ms() / 1000.0)) as usize;
+            (self.sample_rate as f32 * (settings.fade_out_ms() / 1000.0)) as usize;
 
-        // Distance-dependent low-pass filter
-        let fc = (self.settings.f_min()
-            + (self.settings.f_max() - self.settings.f_min())
-                * (-self.settings.distance_alpha() * distance).exp())
-        .clamp(self.settings.f_min(), self.settings.f_max());
-        let dt = 1.0 / self.sample_rate as f32;
-        let rc = 1.0 / (2.0 * std::f32::consts::PI * fc);
-        let filter_a = dt / (rc + dt);
+        // Distance-dependent low-pass filter (recalculé par le mixeur à chaque bloc)
+        let filter_a = settings.lowpass_coefficient(distance, self.sample_rate);
 
-        (stereo, fade_in_samples, fade_out_samples, filter_a)
+        (
+            stereo,
+            fade_in_samples,
+            fade_out_samples,
+            filter_a,
+            distance,
+            att,
+        )
     }
 
-    /// Queue a sound for playback
-    fn enqueue_sound(&self, data: &[[f32; 2]], pos: (f32, f32), gain: f32) {
+    /// Queue a sound for playback (held in its sustain loop until
+    /// `release_rocket` when `rocket_id` is given)
+    fn enqueue_sound(
+        &self,
+        data: &[[f32; 2]],
+        pos: (f32, f32, f32),
+        gain: f32,
+        rocket_id: Option<u64>,
+    ) {
         if self.global_gain == 0.0 {
             return;
         }
 
         let global_gain = self.global_gain * gain;
 
-        let (stereo_data, fade_in, fade_out, filter_a) = self.prepare_voice(data, pos, global_gain);
+        let (stereo_data, fade_in, fade_out, filter_a, distance, attenuation) =
+            self.prepare_voice(data, pos, global_gain);
+        let sustain = rocket_id.and_then(|rocket_id| {
+            let settings = self.settings.load();
+            let max_frames =
+                (self.sample_rate as f32 * settings.rocket_sustain_max_ms() / 1000.0) as usize;
+            Sustain::new(
+                rocket_id,
+                stereo_data.len(),
+                settings.rocket_loop_start(),
+                settings.rocket_loop_end(),
+                max_frames,
+            )
+        });
         let req = PlayRequest {
             data: stereo_data,
             fade_in,
             fade_out,
             gain: global_gain,
             filter_a,
+            distance: Some(distance),
+            attenuation,
+            sustain,
             sent_at: Instant::now(), // for monitoring
         };
         self.play_queue.lock().unwrap().push_back(req);
     }
 
     pub fn play_rocket(&self, pos: (f32, f32), gain: f32) {
-        self.enqueue_sound(&self.rocket_data, pos, gain);
+        self.play_rocket_3d((pos.0, pos.1, 0.0), gain);
     }
     pub fn play_explosion(&self, pos: (f32, f32), gain: f32) {
-        self.enqueue_sound(&self.explosion_data, pos, gain);
+        self.play_explosion_3d((pos.0, pos.1, 0.0), gain);
+    }
+    pub fn play_rocket_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        self.enqueue_sound(&self.rocket_data, pos, gain, None);
+    }
+    /// Explosion, avec sa couche grave d'autant plus présente que la source est
+    /// proche (mixée ici, avant spatialisation)
+    pub fn play_explosion_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        let Some(rumble) = &self.rumble_data else {
+            self.enqueue_sound(&self.explosion_data, pos, gain, None);
+            return;
+        };
+        let factor = rumble_crossfade(
+            self.source_distance(pos),
+            self.settings.load().rumble_crossfade_distance(),
+        );
+        let layered = mix_layers(&self.explosion_data, rumble, factor);
+        self.enqueue_sound(&layered, pos, gain, None);
+    }
+
+    /// Sifflement de la fusée `rocket_id`, entretenu jusqu'à `release_rocket`
+    pub fn play_rocket_sustained(&self, rocket_id: u64, pos: (f32, f32, f32), gain: f32) {
+        self.enqueue_sound(&self.rocket_data, pos, gain, Some(rocket_id));
+    }
+
+    /// Explosion de la fusée `rocket_id` : sa voix quitte la boucle au bloc suivant
+    pub fn release_rocket(&self, rocket_id: u64) {
+        self.rocket_releases.lock().unwrap().push(rocket_id);
+    }
+
+    /// Musique : une voix (filtre passe-tout) pour toute la piste, rééchantillonnée
+    pub fn play_music(&self, data: &[[f32; 2]], sample_rate: u32, gain: f32) {
+        let data = resample_linear(data, sample_rate, self.sample_rate);
+        register_allocation(
+            "audio: music",
+            (data.len() * std::mem::size_of::<[f32; 2]>()) as u64,
+        );
+        let fade = (self.sample_rate as f32 * (self.settings().fade_out_ms() / 1000.0)) as usize;
+        let req = PlayRequest {
+            data,
+            fade_in: 0,
+            fade_out: fade,
+            gain: self.global_gain * gain,
+            filter_a: 1.0,
+            distance: None,
+            attenuation: 1.0,
+            sustain: None,
+            sent_at: Instant::now(),
+        };
+        self.play_queue.lock().unwrap().push_back(req);
     }
 
     pub fn start_audio_thread(&mut self, export_path: Option<&str>) {
         info!("🚀 Starting Audio Engine ...");
 
         let queue = self.play_queue.clone();
+        let rocket_releases = self.rocket_releases.clone();
         let voices = Arc::new(Mutex::new(self.voices.clone()));
+        let active_voices = self.active_voices.clone();
+        let flush_voices = self.flush_voices.clone();
+        let dropped_requests = self.dropped_requests.clone();
+        let paused = self.paused.clone();
+        let playback_rate = self.playback_rate.clone();
+        let output_level = self.output_level.clone();
         let sr = self.sample_rate;
         let block_size = self.block_size;
-        let global_gain = self.settings.global_gain();
+        let settings = self.settings.clone();
 
         let running_pair_clone = self.running_pair.clone();
+        let finished_pair = self.finished_pair.clone();
+        *finished_pair.0.lock().unwrap() = false;
 
         // Partagé entre moteurs
-        let profiler = Profiler::new(200);
+        let profiler = Profiler::with_category(200, ProfilerCategory::Audio);
         let mut last_log = Instant::now();
         let log_interval = std::time::Duration::from_secs(4); // toutes les 4 secondes
 
         // Prépare les données audio à partager avec le thread audio
         let _rocket_data_ref = Arc::new(self.rocket_data.clone()); // Ce qui est zéro copie (le Arc clone est O(1)).
-        let _settings = self.settings.clone();
         let _listener_pos_clone = self.listener_pos; // utile dans prepare_voice_with_doppler
 
         let export_writer_arc: Option<Arc<Mutex<SafeWavWriter>>> = if let Some(path) = export_path {
@@ -201,6 +413,12 @@ impl FireworksAudio3D {
 
             let host = cpal::default_host();
             let device = host.default_output_device().unwrap();
+            record_audio(AudioBackendInfo {
+                host: host.id().name().to_string(),
+                device: device.name().ok(),
+                sample_rate: sr,
+                block_size,
+            });
             let config = cpal::StreamConfig {
                 channels: 2,
                 sample_rate: cpal::SampleRate(sr),
@@ -208,6 +426,7 @@ impl FireworksAudio3D {
             };
 
             let voices_clone = voices.clone();
+            let mut rocket_voices = RocketVoices::default();
 
             // Preallocate buffers
             let mut acc = vec![[0.0; 2]; block_size];
@@ -227,6 +446,13 @@ impl FireworksAudio3D {
 
                         let frames = data.len() / 2;
 
+                        // En pause : silence, les voix et la file restent en l'état
+                        if paused.load(Ordering::Relaxed) {
+                            data.fill(0.0);
+                            output_level.store(0.0_f32.to_bits(), Ordering::Relaxed);
+                            return;
+                        }
+
                         // Redimensionnement dynamique
                         if acc.len() < frames {
                             debug!(
@@ -245,100 +471,53 @@ impl FireworksAudio3D {
                             chunk.resize(frames, [0.0; 2]);
                         }
 
-                        // Reset accumulator
-                        unsafe {
-                            std::ptr::write_bytes(acc.as_mut_ptr(), 0, frames);
-                        }
-
-                        for f in acc.iter_mut().take(frames) {
-                            f[0] = 0.0;
-                            f[1] = 0.0;
-                        }
-
                         // Enqueue pending sounds
                         {
                             let mut q = queue.lock().unwrap();
                             let mut voices_lock = voices_clone.lock().unwrap();
-                            while let Some(req) = q.pop_front() {
-                                if let Some(v) = voices_lock.iter_mut().find(|v| !v.active) {
-                                    v.reset_from_request(&req);
-                                    let latency = Instant::now().duration_since(req.sent_at);
-                                    profiler.record_metric("audio latency", latency);
-                                }
-                            }
-                            let nb_actives_voices = voices_lock.iter().filter(|v| v.active).count();
-                            profiler.record_metric("nb_actives_voices", nb_actives_voices);
+                            let flush = flush_voices.swap(false, Ordering::Relaxed);
+                            let drain = drain_play_queue(
+                                &mut q,
+                                &mut voices_lock,
+                                &mut rocket_voices,
+                                flush,
+                                &profiler,
+                            );
+                            release_rocket_voices(
+                                &mut rocket_releases.lock().unwrap(),
+                                &mut voices_lock,
+                                &mut rocket_voices,
+                            );
+                            active_voices.store(drain.active_voices, Ordering::Relaxed);
+                            dropped_requests.fetch_add(drain.dropped as u64, Ordering::Relaxed);
                         }
 
+                        // Réglages courants, appliqués aux voix déjà en cours
+                        let settings = settings.load();
+
                         // Process each active voice
                         {
                             let _guard = profiler.measure("process_active_voices");
                             let mut voices_lock = voices_clone.lock().unwrap();
-                            for v in voices_lock.iter_mut() {
-                                if !v.active || v.data.is_none() {
-                                    continue;
-                                }
-
-                                let total_len = v.data.as_ref().unwrap().len();
-                                let start = v.pos;
-                                if start >= total_len {
-                                    v.active = false;
-                                    v.data = None;
-                                    continue;
-                                }
-
-                                let n = (total_len - start).min(frames).min(chunk.len());
-                                chunk[..n]
-                                    .copy_from_slice(&v.data.as_ref().unwrap()[start..start + n]);
-
-                                // Apply fade-in/fade-out
-                                for (i, item) in chunk.iter_mut().enumerate().take(n) {
-                                    if start + i < v.fade_in_samples {
-                                        let alpha = (start + i) as f32 / v.fade_in_samples as f32;
-                                        item[0] *= alpha;
-                                        item[1] *= alpha;
-                                    }
-                                    let rem = total_len - (start + i);
-                                    if rem < v.fade_out_samples {
-                                        let alpha = rem as f32 / v.fade_out_samples as f32;
-                                        item[0] *= alpha;
-                                        item[1] *= alpha;
-                                    }
-                                }
-
-                                // Low-pass filter
-                                for ch in 0..2 {
-                                    let mut prev = v.filter_state[ch];
-                                    for item in chunk.iter_mut().take(n) {
-                                        let x = item[ch];
-                                        let y = prev + v.filter_a * (x - prev);
-                                        item[ch] = y;
-                                        prev = y;
-                                    }
-                                    v.filter_state[ch] = prev;
-                                }
-
-                                // Mix into accumulator
-                                for (i, item) in chunk.iter_mut().enumerate().take(n) {
-                                    acc[i][0] += item[0] * v.user_gain;
-                                    acc[i][1] += item[1] * v.user_gain;
-                                }
-
-                                v.pos += n;
-                                if v.pos >= total_len {
-                                    v.active = false;
-                                    v.data = None;
-                                }
-                            }
+                            apply_settings(&mut voices_lock, &settings, sr);
+                            let rate = f32::from_bits(playback_rate.load(Ordering::Relaxed));
+                            mix_voices(
+                                &mut voices_lock,
+                                &mut acc[..frames],
+                                &mut chunk[..frames],
+                                rate,
+                            );
                         }
 
                         // Write to CPAL buffer with global gain and soft clipping
                         profiler.profile_block("write_cpal_buffer", || {
-                            for (i, sample) in acc.iter_mut().take(frames).enumerate() {
-                                data[2 * i] = (sample[0] * global_gain).tanh();
-                                data[2 * i + 1] = (sample[1] * global_gain).tanh();
+                            for (i, sample) in acc.iter().take(frames).enumerate() {
+                                let [left, right] = soft_clip(*sample, settings.global_gain());
+                                data[2 * i] = left;
+                                data[2 * i + 1] = right;
                             }
                         });
+                        output_level.store(rms(&data[..2 * frames]).to_bits(), Ordering::Relaxed);
 
                         if let Some(writer_arc) = &export_writer_callback {
                             // 🔹 Reuse 'data' instead of recalculating
@@ -363,7 +542,7 @@ impl FireworksAudio3D {
                             last_log = Instant::now();
                         }
                     },
-                    move |err| eprintln!("CPAL error: {:?}", err),
+                    move |err| error!("❌ CPAL stream error: {:?}", err),
                     None,
                 )
                 .unwrap();
@@ -400,21 +579,124 @@ impl FireworksAudio3D {
             if let Some(writer_arc) = export_writer_arc {
                 writer_arc.lock().unwrap().stop();
             }
+
+            let (lock, cvar) = &*finished_pair;
+            *lock.lock().unwrap_or_else(|e| e.into_inner()) = true;
+            cvar.notify_all();
         });
     }
 
-    /// Stop the audio thread
+    /// Rendu hors-ligne : aucun périphérique n'est ouvert, `render_offline` mixe
+    /// les voix avec le même code que le callback CPAL.
+    pub fn start_offline(&mut self, export_path: Option<&str>) {
+        info!("🚀 Starting Audio Engine (offline rendering) ...");
+        record_audio(AudioBackendInfo {
+            host: "offline".to_string(),
+            device: None,
+            sample_rate: self.sample_rate,
+            block_size: self.block_size,
+        });
+        self.offline = Some(OfflineRender {
+            voices: self.voices.clone(),
+            rocket_voices: RocketVoices::default(),
+            acc: vec![[0.0; 2]; self.block_size],
+            chunk: vec![[0.0; 2]; self.block_size],
+            writer: export_path.map(|path| SafeWavWriter::new(path, self.sample_rate)),
+            pending_frames: 0.0,
+            block_index: 0,
+            profiler: Profiler::with_category(200, ProfilerCategory::Audio),
+        });
+    }
+
+    /// Rend `dt` secondes d'audio, par blocs de `block_size` frames au plus.
+    pub fn render_offline(&mut self, dt: f32) {
+        let Some(offline) = &mut self.offline else {
+            return;
+        };
+        if self.paused.load(Ordering::Relaxed) {
+            self.output_level
+                .store(0.0_f32.to_bits(), Ordering::Relaxed);
+            return;
+        }
+        offline.pending_frames += dt as f64 * self.sample_rate as f64;
+        while offline.pending_frames >= 1.0 {
+            let frames = (offline.pending_frames as usize).min(self.block_size);
+            offline.pending_frames -= frames as f64;
+            let _audio_frame_guard = offline.profiler.measure("audio_frame");
+
+            {
+                let mut q = self.play_queue.lock().unwrap();
+                let flush = self.flush_voices.swap(false, Ordering::Relaxed);
+                let drain = drain_play_queue(
+                    &mut q,
+                    &mut offline.voices,
+                    &mut offline.rocket_voices,
+                    flush,
+                    &offline.profiler,
+                );
+                release_rocket_voices(
+                    &mut self.rocket_releases.lock().unwrap(),
+                    &mut offline.voices,
+                    &mut offline.rocket_voices,
+                );
+                self.active_voices
+                    .store(drain.active_voices, Ordering::Relaxed);
+                self.dropped_requests
+                    .fetch_add(drain.dropped as u64, Ordering::Relaxed);
+            }
+            let settings = self.settings.load();
+            apply_settings(&mut offline.voices, &settings, self.sample_rate);
+            mix_voices(
+                &mut offline.voices,
+                &mut offline.acc[..frames],
+                &mut offline.chunk[..frames],
+                f32::from_bits(self.playback_rate.load(Ordering::Relaxed)),
+            );
+
+            let frames: Vec<[f32; 2]> = offline.acc[..frames]
+                .iter()
+                .map(|sample| soft_clip(*sample, settings.global_gain()))
+                .collect();
+            self.output_level
+                .store(rms(frames.as_flattened()).to_bits(), Ordering::Relaxed);
+            if let Some(writer) = &offline.writer {
+                writer.push_block(AudioBlock {
+                    index: offline.block_index,
+                    frames,
+                });
+                offline.block_index += 1;
+            }
+        }
+    }
+
+    /// Stop the audio thread (idempotent)
     pub fn stop_audio_thread(&mut self) {
+        let offline = self.offline.take();
+        if offline.is_none() && !self.shutdown_handle().is_running() {
+            return;
+        }
         info!("🧹 Fermeture de l'Audio Engine");
-        let (lock, cvar) = &*self.running_pair;
-        let mut running = lock.lock().unwrap();
-        *running = false; // indiquer au thread secondaire d'arrêter
-        cvar.notify_all(); // réveiller le thread
-        drop(running); // unlock
+        if let Some(mut offline) = offline {
+            if let Some(writer) = &mut offline.writer {
+                writer.stop();
+            }
+        }
+        self.shutdown_handle().request_stop();
+    }
+
+    /// Poignée d'arrêt utilisable hors du thread principal (hook de panique)
+    pub fn shutdown_handle(&self) -> AudioShutdown {
+        AudioShutdown {
+            running_pair: self.running_pair.clone(),
+            finished_pair: self.finished_pair.clone(),
+        }
     }
 
     pub fn set_volume(&mut self, volume: f32) {
-        self.global_gain = volume;
+        self.volume = volume.max(0.0);
+        if !self.muted {
+            self.global_gain = self.volume;
+        }
     }
 }
 
@@ -427,6 +709,26 @@ impl AudioEngine for FireworksAudio3D {
         self.play_explosion(pos, gain)
     }
 
+    fn play_rocket_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        self.play_rocket_3d(pos, gain)
+    }
+
+    fn play_explosion_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        self.play_explosion_3d(pos, gain)
+    }
+
+    fn play_rocket_sustained(&self, rocket_id: u64, pos: (f32, f32, f32), gain: f32) {
+        self.play_rocket_sustained(rocket_id, pos, gain)
+    }
+
+    fn release_rocket(&self, rocket_id: u64) {
+        self.release_rocket(rocket_id)
+    }
+
+    fn play_music(&self, data: &[[f32; 2]], sample_rate: u32, gain: f32) {
+        self.play_music(data, sample_rate, gain)
+    }
+
     fn start_audio_thread(&mut self, _export_path: Option<&str>) {
         self.start_audio_thread(_export_path)
     }
@@ -435,6 +737,14 @@ impl AudioEngine for FireworksAudio3D {
         self.stop_audio_thread()
     }
 
+    fn start_offline(&mut self, export_path: Option<&str>) {
+        self.start_offline(export_path)
+    }
+
+    fn advance_offline(&mut self, dt: f32) {
+        self.render_offline(dt)
+    }
+
     fn set_listener_position(&mut self, pos: (f32, f32)) {
         self.listener_pos = pos;
         info!("🎧️ Listener position set to: {:?}", self.listener_pos);
@@ -445,12 +755,51 @@ impl AudioEngine for FireworksAudio3D {
     }
 
     fn mute(&mut self) {
-        self.set_volume(0.0);
+        self.muted = true;
+        self.global_gain = 0.0;
     }
 
     fn unmute(&mut self) -> f32 {
-        self.set_volume(self.settings.global_gain());
-        self.settings.global_gain()
+        self.muted = false;
+        self.global_gain = self.volume;
+        self.volume
+    }
+
+    fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.set_volume(volume)
+    }
+
+    fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    fn active_voices(&self) -> usize {
+        self.active_voices.load(Ordering::Relaxed)
+    }
+
+    fn stop_all_voices(&mut self) {
+        self.flush_voices.store(true, Ordering::Relaxed);
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    fn set_time_scale(&mut self, scale: f32) {
+        let rate = playback_rate(scale, self.settings().pitch_follows_time_scale());
+        self.playback_rate.store(rate.to_bits(), Ordering::Relaxed);
+    }
+
+    fn dropped_requests(&self) -> u64 {
+        self.dropped_requests.load(Ordering::Relaxed)
+    }
+
+    fn output_level(&self) -> f32 {
+        f32::from_bits(self.output_level.load(Ordering::Relaxed))
     }
 }
 
@@ -469,7 +818,7 @@ mod tests {
     fn enqueue_sound_test(engine: &FireworksAudio3D, pos: (f32, f32), gain: f32) -> PlayRequest {
         // Panning simple
         let dx = pos.0 - engine.listener_pos.0;
-        let pan = (dx / engine.settings.max_distance()).clamp(-1.0, 1.0);
+        let pan = (dx / engine.settings().max_distance()).clamp(-1.0, 1.0);
 
         let mut data_panned = dummy_data();
         for sample in &mut data_panned {
@@ -485,6 +834,9 @@ mod tests {
             fade_out: 1,
             gain,
             filter_a: 0.0025,
+            distance: None,
+            attenuation: 1.0,
+            sustain: None,
             sent_at: Instant::now(),
         }
     }
@@ -494,6 +846,7 @@ mod tests {
         FireworksAudio3D::new(FireworksAudioConfig {
             rocket_path: "assets/sounds/rocket.wav".into(),
             explosion_path: "assets/sounds/explosion.wav".into(),
+            rumble_path: None,
             listener_pos: (0.0, 0.0),
             sample_rate: 1000,
             block_size: 1024 * 4,
@@ -508,7 +861,7 @@ mod tests {
     fn test_panning_left() {
         let engine = build_engine();
 
-        let req = enqueue_sound_test(&engine, (-engine.settings.max_distance(), 0.0), 1.0);
+        let req = enqueue_sound_test(&engine, (-engine.settings().max_distance(), 0.0), 1.0);
 
         for sample in &req.data {
             let ratio = sample[0] / (sample[1] + 1e-8);
@@ -523,7 +876,7 @@ mod tests {
     fn test_panning_right() {
         let engine = build_engine();
 
-        let req = enqueue_sound_test(&engine, (engine.settings.max_distance(), 0.0), 1.0);
+        let req = enqueue_sound_test(&engine, (engine.settings().max_distance(), 0.0), 1.0);
 
         for sample in &req.data {
             let ratio = sample[1] / (sample[0] + 1e-8);
@@ -792,4 +1145,22 @@ mod tests {
             "Le son proche doit être plus fort que le son lointain"
         );
     }
+
+    #[test]
+    fn test_prepare_voice_depth_attenuation() {
+        let engine = build_engine();
+        let data = dummy_data();
+
+        // Même position écran, profondeurs différentes (z négatif = devant l'auditeur)
+        let (near, ..) = engine.prepare_voice(&data, (0.0, 100.0, 0.0), 1.0);
+        let (far, ..) = engine.prepare_voice(&data, (0.0, 100.0, -800.0), 1.0);
+
+        let e_near: f32 = near.iter().map(|s| s[0].abs() + s[1].abs()).sum();
+        let e_far: f32 = far.iter().map(|s| s[0].abs() + s[1].abs()).sum();
+
+        assert!(
+            e_near > e_far,
+            "Une fusée proche doit être plus forte qu'une fusée lointaine"
+        );
+    }
 }
@@ -0,0 +1,345 @@
+//! Mixage des voix, partagé par le callback CPAL et le rendu hors-ligne
+//! (mode headless) : les deux produisent exactement les mêmes échantillons.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+use crate::audio_engine::types::{PlayRequest, Sustain, Voice};
+use crate::audio_engine::AudioEngineSettings;
+use crate::profiler::Profiler;
+
+/// Bilan de `drain_play_queue`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueDrain {
+    pub active_voices: usize,
+    /// Sons abandonnés faute de voix libre
+    pub dropped: usize,
+}
+
+/// Indice d'une voix dans le tableau du mixeur
+pub type VoiceHandle = usize;
+
+/// Voix entretenues des fusées en vol, par id de fusée (cf. `Sustain`).
+///
+/// Tenu par le mixeur : les départs arrivent par la file de lecture, les
+/// explosions par `release_rocket_voices`.
+#[derive(Debug, Default)]
+pub struct RocketVoices(HashMap<u64, VoiceHandle>);
+
+impl RocketVoices {
+    /// Associe la voix `handle` à sa fusée ; une voix encore liée au même id
+    /// (fusée disparue sans exploser, slot réutilisé) est relâchée.
+    pub fn bind(&mut self, handle: VoiceHandle, voices: &mut [Voice]) {
+        let Some(rocket_id) = voices[handle].sustain.map(|s| s.rocket_id) else {
+            return;
+        };
+        if let Some(previous) = self.0.insert(rocket_id, handle) {
+            if previous != handle {
+                release_voice(&mut voices[previous], rocket_id);
+            }
+        }
+    }
+
+    /// Relâche la voix de la fusée `rocket_id` : fin de la boucle, la voix joue
+    /// la suite de l'échantillon puis se libère. `false` si aucune voix n'y est liée.
+    pub fn release(&mut self, rocket_id: u64, voices: &mut [Voice]) -> bool {
+        self.0
+            .remove(&rocket_id)
+            .is_some_and(|handle| release_voice(&mut voices[handle], rocket_id))
+    }
+
+    /// Nombre de fusées dont le sifflement est entretenu
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Quitte la boucle d'entretien si la voix appartient bien à `rocket_id` (elle a
+/// pu être coupée puis réattribuée entre-temps).
+fn release_voice(voice: &mut Voice, rocket_id: u64) -> bool {
+    let owned = voice.active && voice.sustain.is_some_and(|s| s.rocket_id == rocket_id);
+    if owned {
+        voice.sustain = None;
+    }
+    owned
+}
+
+/// Applique les explosions en attente (`releases`, ids de fusée) aux voix entretenues.
+pub fn release_rocket_voices(
+    releases: &mut Vec<u64>,
+    voices: &mut [Voice],
+    rocket_voices: &mut RocketVoices,
+) {
+    for rocket_id in releases.drain(..) {
+        rocket_voices.release(rocket_id, voices);
+    }
+}
+
+/// Attribue les sons en attente aux voix libres (ou coupe tout si `flush`).
+pub fn drain_play_queue(
+    queue: &mut VecDeque<PlayRequest>,
+    voices: &mut [Voice],
+    rocket_voices: &mut RocketVoices,
+    flush: bool,
+    profiler: &Profiler,
+) -> QueueDrain {
+    if flush {
+        queue.clear();
+        voices.iter_mut().for_each(|v| *v = Voice::new());
+        rocket_voices.clear();
+    }
+    let mut dropped = 0;
+    while let Some(req) = queue.pop_front() {
+        match voices.iter().position(|v| !v.active) {
+            Some(handle) => {
+                voices[handle].reset_from_request(&req);
+                rocket_voices.bind(handle, voices);
+                let latency = Instant::now().duration_since(req.sent_at);
+                profiler.record_metric("audio latency", latency);
+            }
+            None => dropped += 1,
+        }
+    }
+    let nb_actives_voices = voices.iter().filter(|v| v.active).count();
+    profiler.record_metric("nb_actives_voices", nb_actives_voices);
+    QueueDrain {
+        active_voices: nb_actives_voices,
+        dropped,
+    }
+}
+
+/// Applique les réglages courants aux voix actives, en début de bloc : le filtre
+/// passe-bas et l'atténuation sont recalculés depuis la distance de la source
+/// (l'atténuation figée dans l'échantillon est compensée par `spatial_gain`).
+pub fn apply_settings(voices: &mut [Voice], settings: &AudioEngineSettings, sample_rate: u32) {
+    for v in voices.iter_mut().filter(|v| v.active) {
+        let Some(distance) = v.distance else {
+            continue;
+        };
+        v.filter_a = settings.lowpass_coefficient(distance, sample_rate);
+        v.spatial_gain = if v.attenuation > 0.0 {
+            settings.distance_attenuation(distance) / v.attenuation
+        } else {
+            0.0
+        };
+    }
+}
+
+/// Bornes de la vitesse de lecture des voix
+pub const PLAYBACK_RATE_MIN: f32 = 0.1;
+pub const PLAYBACK_RATE_MAX: f32 = 4.0;
+
+/// Vitesse de lecture des voix pour une vitesse de simulation `time_scale` :
+/// identique (bornée) si `enabled`, sinon 1 (hauteur inchangée).
+pub fn playback_rate(time_scale: f32, enabled: bool) -> f32 {
+    if enabled && time_scale.is_finite() {
+        time_scale.clamp(PLAYBACK_RATE_MIN, PLAYBACK_RATE_MAX)
+    } else {
+        1.0
+    }
+}
+
+/// Lit `data` à partir de la position fractionnaire `pos`, en avançant de `rate`
+/// frames source par frame produite (interpolation linéaire), dans `out`.
+///
+/// Retourne le nombre de frames produites : moins que `out.len()` si la fin de
+/// `data` est atteinte.
+pub fn resample_into(data: &[[f32; 2]], pos: f64, rate: f32, out: &mut [[f32; 2]]) -> usize {
+    let last = data.len() as f64 - 1.0;
+    let mut produced = 0;
+    for (i, item) in out.iter_mut().enumerate() {
+        let p = pos + i as f64 * rate as f64;
+        if p > last {
+            break;
+        }
+        let index = p as usize;
+        let t = (p - index as f64) as f32;
+        let a = data[index];
+        let b = data[(index + 1).min(data.len() - 1)];
+        *item = [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t];
+        produced += 1;
+    }
+    produced
+}
+
+/// Lit `data` en boucle sur la région de `sustain`, à partir de la position
+/// fractionnaire `pos`, en avançant de `rate` frames source par frame produite
+/// (interpolation linéaire, y compris entre la fin et le début de la boucle).
+///
+/// Remplit tout `out` et retourne la position source de la frame suivante,
+/// ramenée dans la boucle.
+pub fn read_looped(
+    data: &[[f32; 2]],
+    sustain: &Sustain,
+    pos: f64,
+    rate: f32,
+    out: &mut [[f32; 2]],
+) -> f64 {
+    let mut p = sustain.wrap(pos);
+    for item in out.iter_mut() {
+        let index = p as usize;
+        let t = (p - index as f64) as f32;
+        let next = if index + 1 >= sustain.loop_end {
+            sustain.loop_start
+        } else {
+            index + 1
+        };
+        let a = data[index];
+        let b = data[next];
+        *item = [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t];
+        p = sustain.wrap(p + rate as f64);
+    }
+    p
+}
+
+/// Mixe les voix actives dans `acc` (remis à zéro), sur `acc.len()` frames, à
+/// la vitesse de lecture `rate` (1 : échantillons lus tels quels).
+///
+/// Une voix entretenue (`Voice::sustain`) reboucle jusqu'à son relâchement, ou
+/// jusqu'à épuisement de `Sustain::frames_left`.
+///
+/// `chunk` est un buffer de travail d'au moins `acc.len()` frames.
+pub fn mix_voices(voices: &mut [Voice], acc: &mut [[f32; 2]], chunk: &mut [[f32; 2]], rate: f32) {
+    let frames = acc.len();
+    acc.fill([0.0; 2]);
+    let resampled = rate != 1.0;
+    let capacity = frames.min(chunk.len());
+
+    for v in voices.iter_mut() {
+        let Some(data) = v.data.as_ref() else {
+            continue;
+        };
+        if !v.active {
+            continue;
+        }
+
+        let total_len = data.len();
+        let start = v.pos;
+        if start >= total_len {
+            v.active = false;
+            v.data = None;
+            continue;
+        }
+        // Boucle incohérente avec l'échantillon : lecture ponctuelle
+        if v.sustain.is_some_and(|s| s.loop_end > total_len) {
+            v.sustain = None;
+        }
+        let sustain = v.sustain;
+        let mut looped_pos = 0.0;
+
+        let n = if let Some(sustain) = &sustain {
+            let pos = start as f64 + v.pos_frac as f64;
+            looped_pos = read_looped(data, sustain, pos, rate, &mut chunk[..capacity]);
+            capacity
+        } else if resampled {
+            let pos = start as f64 + v.pos_frac as f64;
+            resample_into(data, pos, rate, &mut chunk[..capacity])
+        } else {
+            let n = (total_len - start).min(capacity);
+            chunk[..n].copy_from_slice(&data[start..start + n]);
+            n
+        };
+        // Position source de la frame produite `i` (avant rebouclage)
+        let source_index = |i: usize| {
+            if resampled {
+                start + (v.pos_frac + i as f32 * rate) as usize
+            } else {
+                start + i
+            }
+            .min(total_len - 1)
+        };
+
+        // Après rebouclage, les positions sont au-delà de `loop_start` : le fondu
+        // d'entrée n'est joué qu'une fois
+        let fade_in = v
+            .fade_in_samples
+            .min(sustain.map_or(usize::MAX, |s| s.loop_start));
+
+        // Apply fade-in/fade-out
+        for (i, item) in chunk.iter_mut().enumerate().take(n) {
+            let index = source_index(i);
+            if index < fade_in {
+                let alpha = index as f32 / v.fade_in_samples as f32;
+                item[0] *= alpha;
+                item[1] *= alpha;
+            }
+            let rem = total_len - index;
+            if sustain.is_none() && rem < v.fade_out_samples {
+                let alpha = rem as f32 / v.fade_out_samples as f32;
+                item[0] *= alpha;
+                item[1] *= alpha;
+            }
+        }
+
+        // Low-pass filter
+        for ch in 0..2 {
+            let mut prev = v.filter_state[ch];
+            for item in chunk.iter_mut().take(n) {
+                let x = item[ch];
+                let y = prev + v.filter_a * (x - prev);
+                item[ch] = y;
+                prev = y;
+            }
+            v.filter_state[ch] = prev;
+        }
+
+        // Mix into accumulator
+        let gain = v.user_gain * v.spatial_gain;
+        for (i, item) in chunk.iter().enumerate().take(n) {
+            acc[i][0] += item[0] * gain;
+            acc[i][1] += item[1] * gain;
+        }
+
+        if let Some(sustain) = &mut v.sustain {
+            v.pos = looped_pos as usize;
+            v.pos_frac = looped_pos.fract() as f32;
+            // Fusée disparue sans exploser : relâchement forcé
+            sustain.frames_left = sustain.frames_left.saturating_sub(n);
+            if sustain.frames_left == 0 {
+                v.sustain = None;
+            }
+            continue;
+        }
+
+        // Rééchantillonnage interrompu avant `frames` : fin de l'échantillon
+        if resampled && n == capacity {
+            let advanced = v.pos_frac as f64 + n as f64 * rate as f64;
+            v.pos += advanced as usize;
+            v.pos_frac = advanced.fract() as f32;
+        } else if resampled {
+            v.pos = total_len;
+        } else {
+            v.pos += n;
+        }
+        if v.pos >= total_len {
+            v.active = false;
+            v.data = None;
+        }
+    }
+}
+
+/// Niveau efficace (RMS) d'échantillons, 0 pour un bloc vide
+pub fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = samples.iter().map(|s| s * s).sum();
+    (sum / samples.len() as f32).sqrt()
+}
+
+/// Gain global et saturation douce (`tanh`) d'une frame stéréo mixée
+#[inline]
+pub fn soft_clip(frame: [f32; 2], global_gain: f32) -> [f32; 2] {
+    [
+        (frame[0] * global_gain).tanh(),
+        (frame[1] * global_gain).tanh(),
+    ]
+}
@@ -2,26 +2,35 @@ pub mod r#trait;
 pub use r#trait::AudioEngine;
 
 pub mod fireworks_audio;
-pub use fireworks_audio::FireworksAudio3D;
+pub use fireworks_audio::{AudioShutdown, FireworksAudio3D};
+
+pub mod config;
+pub use config::{AudioConfig, AUDIO_CONFIG_PATH};
 
 pub mod types;
 pub use self::types::FireworksAudioConfig;
 
 pub mod dsp;
-pub use dsp::resample_linear_mono;
+pub mod mixer;
+pub use dsp::{mix_layers, resample_linear_mono, rumble_crossfade};
 
 pub mod settings;
-pub use settings::AudioEngineSettings;
+pub use settings::{AudioEngineSettings, SharedSettings};
+
+pub mod onset;
+pub use onset::{detect_onsets, OnsetSettings};
 
 pub mod audio_loading;
-pub use audio_loading::load_audio;
 pub use audio_loading::resample_linear;
+pub use audio_loading::{
+    load_audio, load_audio_with_rate, load_resampled, try_load_audio_with_rate, MusicTrack,
+};
 
 pub mod binaural_processing;
 pub use binaural_processing::binauralize_mono;
 
 pub mod audio_event;
-pub use audio_event::DopplerEvent;
+pub use audio_event::{play_physic_events, DopplerEvent};
 
 pub mod safewavwriter;
 pub use safewavwriter::{AudioBlock, SafeWavWriter};
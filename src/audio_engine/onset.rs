@@ -0,0 +1,101 @@
+//! Détection des attaques (onsets) d'une piste musicale, pour caler les
+//! explosions sur les temps forts (`--music`).
+//!
+//! Détecteur sur l'enveloppe d'énergie : énergie de trames courtes
+//! (`frame_duration`), flux d'énergie (hausse du log de l'énergie d'une trame à
+//! la suivante), puis pics au-dessus d'un seuil adaptatif (moyenne locale +
+//! `threshold` × écart-type) et d'une hausse minimale `min_rise_db`, espacés
+//! d'au moins `min_interval`. Le début de la piste n'est pas une attaque.
+
+/// Réglages du détecteur d'onsets
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OnsetSettings {
+    /// Durée d'une trame d'analyse (s) : borne la précision des onsets
+    pub frame_duration: f32,
+    /// Demi-largeur de la fenêtre du seuil adaptatif (s)
+    pub window: f32,
+    /// Seuil, en écarts-types au-dessus de la moyenne locale du flux
+    pub threshold: f32,
+    /// Hausse minimale de l'énergie d'une trame à la suivante (dB)
+    pub min_rise_db: f32,
+    /// Écart minimal entre deux onsets (s)
+    pub min_interval: f32,
+    /// Trames plus faibles que le maximum de la piste de plus de ce nombre de dB
+    /// ignorées (bruit de fond)
+    pub floor_db: f32,
+}
+
+impl Default for OnsetSettings {
+    fn default() -> Self {
+        Self {
+            frame_duration: 0.01,
+            window: 0.5,
+            threshold: 1.5,
+            min_rise_db: 6.0,
+            min_interval: 0.1,
+            floor_db: 40.0,
+        }
+    }
+}
+
+/// Instants (s, croissants) des attaques de `samples`, échantillonnés à `sample_rate`.
+pub fn detect_onsets(samples: &[[f32; 2]], sample_rate: u32, settings: &OnsetSettings) -> Vec<f32> {
+    let frame_len = ((settings.frame_duration * sample_rate as f32) as usize).max(1);
+    let frame_duration = frame_len as f32 / sample_rate as f32;
+
+    let energies: Vec<f32> = samples
+        .chunks(frame_len)
+        .map(|frame| {
+            frame
+                .iter()
+                .map(|s| {
+                    let mono = (s[0] + s[1]) * 0.5;
+                    mono * mono
+                })
+                .sum::<f32>()
+                / frame.len() as f32
+        })
+        .collect();
+    let peak_energy = energies.iter().copied().fold(0.0_f32, f32::max);
+    if peak_energy <= 0.0 {
+        return Vec::new();
+    }
+    let floor = peak_energy * 10f32.powf(-settings.floor_db / 10.0);
+
+    // Flux : hausse du log de l'énergie, bruit de fond exclu
+    let log_energy = |e: f32| e.max(floor).ln();
+    let flux: Vec<f32> = (0..energies.len())
+        .map(|k| {
+            let previous = energies[k.saturating_sub(1)];
+            (log_energy(energies[k]) - log_energy(previous)).max(0.0)
+        })
+        .collect();
+
+    let half_window = ((settings.window / frame_duration) as usize).max(1);
+    let min_gap = settings.min_interval.max(0.0);
+    let min_rise = settings.min_rise_db.max(0.0) * std::f32::consts::LN_10 / 10.0;
+    let mut onsets: Vec<f32> = Vec::new();
+    for k in 0..flux.len() {
+        let value = flux[k];
+        if value <= 0.0 || value < min_rise {
+            continue;
+        }
+        let is_peak =
+            (k == 0 || value >= flux[k - 1]) && (k + 1 == flux.len() || value > flux[k + 1]);
+        if !is_peak {
+            continue;
+        }
+        let local = &flux[k.saturating_sub(half_window)..(k + half_window + 1).min(flux.len())];
+        let mean = local.iter().sum::<f32>() / local.len() as f32;
+        let variance = local.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / local.len() as f32;
+        if value <= mean + settings.threshold * variance.sqrt() {
+            continue;
+        }
+        // Milieu de la trame où l'énergie monte
+        let time = (k as f32 + 0.5) * frame_duration;
+        if onsets.last().is_none_or(|&last| time - last >= min_gap) {
+            onsets.push(time);
+        }
+    }
+    onsets
+}
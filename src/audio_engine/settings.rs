@@ -2,6 +2,8 @@
 // Audio Engine Configuration
 // =========================
 
+use std::sync::{Arc, Mutex};
+
 use derive_builder::Builder;
 
 /// Parameters controlling spatialization, filtering, and volume.
@@ -50,6 +52,26 @@ pub struct AudioEngineSettings {
     /// Distance-dependent filter attenuation coefficient
     #[builder(default = "0.0025")]
     pub distance_alpha: f32,
+
+    /// Voice playback rate follows the simulation speed (slow-motion pitch shift)
+    #[builder(default = "false")]
+    pub pitch_follows_time_scale: bool,
+
+    /// Start of the rocket whoosh sustain loop (fraction of the sample)
+    #[builder(default = "0.3")]
+    pub rocket_loop_start: f32,
+
+    /// End of the rocket whoosh sustain loop (fraction of the sample)
+    #[builder(default = "0.7")]
+    pub rocket_loop_end: f32,
+
+    /// Longest sustain (ms) for a rocket whose explosion never arrives
+    #[builder(default = "10000.0")]
+    pub rocket_sustain_max_ms: f32,
+
+    /// Distance below which explosions get their low-frequency rumble layer
+    #[builder(default = "300.0")]
+    pub rumble_crossfade_distance: f32,
 }
 
 impl AudioEngineSettings {
@@ -93,6 +115,63 @@ impl AudioEngineSettings {
     pub fn distance_alpha(&self) -> f32 {
         self.distance_alpha
     }
+
+    pub fn pitch_follows_time_scale(&self) -> bool {
+        self.pitch_follows_time_scale
+    }
+
+    pub fn rocket_loop_start(&self) -> f32 {
+        self.rocket_loop_start
+    }
+
+    pub fn rocket_loop_end(&self) -> f32 {
+        self.rocket_loop_end
+    }
+
+    pub fn rocket_sustain_max_ms(&self) -> f32 {
+        self.rocket_sustain_max_ms
+    }
+
+    pub fn rumble_crossfade_distance(&self) -> f32 {
+        self.rumble_crossfade_distance
+    }
+
+    /// Linear distance attenuation (0 beyond `max_distance`)
+    pub fn distance_attenuation(&self, distance: f32) -> f32 {
+        (1.0 - distance / self.max_distance).max(0.0)
+    }
+
+    /// One-pole low-pass coefficient for a source at `distance`
+    pub fn lowpass_coefficient(&self, distance: f32, sample_rate: u32) -> f32 {
+        let fc = (self.f_min + (self.f_max - self.f_min) * (-self.distance_alpha * distance).exp())
+            .clamp(self.f_min, self.f_max);
+        let dt = 1.0 / sample_rate as f32;
+        let rc = 1.0 / (2.0 * std::f32::consts::PI * fc);
+        dt / (rc + dt)
+    }
+}
+
+/// Settings shared with the audio thread, swapped as a whole.
+///
+/// The mixer takes a snapshot (`load`) at the start of each block: a `store`
+/// from another thread applies to active voices on the next block.
+#[derive(Clone, Debug, Default)]
+pub struct SharedSettings(Arc<Mutex<Arc<AudioEngineSettings>>>);
+
+impl SharedSettings {
+    pub fn new(settings: AudioEngineSettings) -> Self {
+        Self(Arc::new(Mutex::new(Arc::new(settings))))
+    }
+
+    /// Current snapshot (cheap: clones the inner `Arc`)
+    pub fn load(&self) -> Arc<AudioEngineSettings> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the settings for every holder of this handle
+    pub fn store(&self, settings: AudioEngineSettings) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Arc::new(settings);
+    }
 }
 
 /// Keep backward compatibility with `.default()`
@@ -1,13 +1,87 @@
 pub trait AudioEngine {
     fn play_rocket(&self, pos: (f32, f32), gain: f32);
     fn play_explosion(&self, pos: (f32, f32), gain: f32);
+
+    /// Variante 3D de `play_rocket` : `pos.2` est la coordonnée z de la source
+    /// (négative = devant l'auditeur). Par défaut, la profondeur est ignorée.
+    fn play_rocket_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        self.play_rocket((pos.0, pos.1), gain)
+    }
+
+    /// Variante 3D de `play_explosion` (cf. `play_rocket_3d`).
+    fn play_explosion_3d(&self, pos: (f32, f32, f32), gain: f32) {
+        self.play_explosion((pos.0, pos.1), gain)
+    }
+
+    /// Départ d'une fusée suivie jusqu'à son explosion (`release_rocket`) : le
+    /// moteur peut entretenir son sifflement pendant tout le vol. Par défaut, son
+    /// ponctuel de `play_rocket_3d`.
+    fn play_rocket_sustained(&self, _rocket_id: u64, pos: (f32, f32, f32), gain: f32) {
+        self.play_rocket_3d(pos, gain)
+    }
+
+    /// Explosion de la fusée `rocket_id` : fin de son sifflement entretenu
+    /// (cf. `play_rocket_sustained`). Par défaut, ne fait rien.
+    fn release_rocket(&self, _rocket_id: u64) {}
+
+    /// Piste continue (musique de `--music`) mixée sans spatialisation ni filtre,
+    /// à `gain` ; `sample_rate` est celui de `data`. Par défaut, ignorée.
+    fn play_music(&self, _data: &[[f32; 2]], _sample_rate: u32, _gain: f32) {}
+
     fn start_audio_thread(&mut self, export_path: Option<&str>);
     fn stop_audio_thread(&mut self);
 
+    /// Rendu hors-ligne (mode headless) : pas de périphérique audio, le mixage
+    /// avance avec `advance_offline` et finit dans `export_path` s'il est donné.
+    /// `stop_audio_thread` finalise le fichier. Par défaut, ne fait rien.
+    fn start_offline(&mut self, _export_path: Option<&str>) {}
+
+    /// Rend `dt` secondes d'audio hors-ligne (cf. `start_offline`).
+    fn advance_offline(&mut self, _dt: f32) {}
+
     // Getter/Setter
     fn set_listener_position(&mut self, pos: (f32, f32));
     fn get_listener_position(&self) -> (f32, f32);
 
     fn mute(&mut self);
     fn unmute(&mut self) -> f32;
+
+    /// Volume global (hors coupure `mute`) ; 1 si le moteur ne le gère pas.
+    fn volume(&self) -> f32 {
+        1.0
+    }
+
+    fn set_volume(&mut self, _volume: f32) {}
+
+    fn is_muted(&self) -> bool {
+        false
+    }
+
+    /// Nombre de voix en cours de lecture (HUD) ; 0 si le moteur ne le suit pas.
+    fn active_voices(&self) -> usize {
+        0
+    }
+
+    /// Coupe toutes les voix en cours et les sons en attente (`sim.reset`).
+    fn stop_all_voices(&mut self) {}
+
+    /// Pause globale : les voix restent figées (position de lecture conservée)
+    /// et le moteur produit du silence jusqu'à la reprise. Par défaut, ne fait rien.
+    fn set_paused(&mut self, _paused: bool) {}
+
+    /// Vitesse de la simulation (`sim.speed`) : les sons sont déclenchés au rythme
+    /// de la physique ; un moteur peut en plus changer la vitesse de lecture des
+    /// voix (ralenti « cinéma »). Par défaut, ne fait rien.
+    fn set_time_scale(&mut self, _scale: f32) {}
+
+    /// Sons abandonnés faute de voix libre depuis le démarrage (rapport de `bench`).
+    fn dropped_requests(&self) -> u64 {
+        0
+    }
+
+    /// Niveau RMS du dernier bloc envoyé à la sortie (après gain global et
+    /// saturation, 0..1) ; 0 si le moteur ne le mesure pas.
+    fn output_level(&self) -> f32 {
+        0.0
+    }
 }
@@ -33,11 +33,16 @@ pub struct Voice {
     pub active: bool,                // Is the voice currently playing?
     pub data: Option<Vec<[f32; 2]>>, // Stereo audio samples
     pub pos: usize,                  // Current sample index
+    pub pos_frac: f32,               // Fractional part of the position (playback rate != 1)
     pub fade_in_samples: usize,      // Number of samples for fade-in
     pub fade_out_samples: usize,     // Number of samples for fade-out
     pub filter_state: [f32; 2],      // Low-pass filter state per channel
     pub filter_a: f32,               // Low-pass filter coefficient
     pub user_gain: f32,              // Per-voice gain multiplier
+    pub distance: Option<f32>,       // Source distance (None: not spatialized)
+    pub attenuation: f32,            // Distance attenuation baked into `data`
+    pub spatial_gain: f32,           // Current/baked attenuation ratio (cf. `apply_settings`)
+    pub sustain: Option<Sustain>,    // Sustain loop until the rocket explodes
 }
 
 impl Voice {
@@ -48,11 +53,16 @@ impl Voice {
             active: false,
             data: None,
             pos: 0,
+            pos_frac: 0.0,
             fade_in_samples: 0,
             fade_out_samples: 0,
             filter_state: [0.0, 0.0],
             filter_a: 0.0,
             user_gain: 1.0,
+            distance: None,
+            attenuation: 1.0,
+            spatial_gain: 1.0,
+            sustain: None,
         }
     }
 
@@ -60,12 +70,17 @@ impl Voice {
         Self {
             data: Some(req.data.clone()),
             pos: 0,
+            pos_frac: 0.0,
             active: true,
             fade_in_samples: req.fade_in,
             fade_out_samples: req.fade_out,
             filter_a: req.filter_a,
             user_gain: req.gain,
+            distance: req.distance,
+            attenuation: req.attenuation,
+            spatial_gain: 1.0,
             filter_state: [0.0; 2],
+            sustain: req.sustain,
             _id: 0, // ou gérer l’ID
         }
     }
@@ -81,12 +96,62 @@ impl Voice {
 
 /// A request to play a sound, queued for playback in the audio thread
 pub struct PlayRequest {
-    pub data: Vec<[f32; 2]>, // Stereo audio data
-    pub fade_in: usize,      // Fade-in samples
-    pub fade_out: usize,     // Fade-out samples
-    pub gain: f32,           // Per-sound gain
-    pub filter_a: f32,       // Low-pass coefficient
-    pub sent_at: Instant,    // Timestamp of request
+    pub data: Vec<[f32; 2]>,      // Stereo audio data
+    pub fade_in: usize,           // Fade-in samples
+    pub fade_out: usize,          // Fade-out samples
+    pub gain: f32,                // Per-sound gain
+    pub filter_a: f32,            // Low-pass coefficient
+    pub distance: Option<f32>,    // Source distance (None: music, no filter)
+    pub attenuation: f32,         // Distance attenuation baked into `data`
+    pub sustain: Option<Sustain>, // Rocket whoosh held until the explosion
+    pub sent_at: Instant,         // Timestamp of request
+}
+
+// =========================
+// Sustain (rocket whoosh)
+// =========================
+
+/// Boucle d'entretien du sifflement d'une fusée : la lecture reboucle sur
+/// `[loop_start, loop_end)` (frames de l'échantillon) jusqu'à l'explosion de
+/// la fusée `rocket_id`, puis la voix joue la fin de l'échantillon et se libère.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sustain {
+    pub rocket_id: u64,
+    pub loop_start: usize,
+    pub loop_end: usize,
+    /// Frames restant avant relâchement forcé (fusée disparue sans exploser)
+    pub frames_left: usize,
+}
+
+impl Sustain {
+    /// Boucle `[start, end)` en fractions de `len` frames ; `None` si la région est
+    /// vide (le son reste alors ponctuel).
+    pub fn new(
+        rocket_id: u64,
+        len: usize,
+        start: f32,
+        end: f32,
+        max_frames: usize,
+    ) -> Option<Self> {
+        let loop_start = (len as f32 * start.clamp(0.0, 1.0)) as usize;
+        let loop_end = (len as f32 * end.clamp(0.0, 1.0)) as usize;
+        (loop_end > loop_start + 1).then_some(Self {
+            rocket_id,
+            loop_start,
+            loop_end,
+            frames_left: max_frames,
+        })
+    }
+
+    /// Position source `pos` ramenée dans la boucle une fois `loop_end` dépassé.
+    pub fn wrap(&self, pos: f64) -> f64 {
+        let end = self.loop_end as f64;
+        if pos < end {
+            return pos;
+        }
+        let start = self.loop_start as f64;
+        start + (pos - start) % (end - start)
+    }
 }
 
 #[derive(Clone)]
@@ -122,6 +187,8 @@ impl DopplerState {
 pub struct FireworksAudioConfig {
     pub rocket_path: String,
     pub explosion_path: String,
+    /// Couche grave des explosions proches (facultative)
+    pub rumble_path: Option<String>,
     pub listener_pos: (f32, f32),
     pub sample_rate: u32,
     pub block_size: usize,
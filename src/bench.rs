@@ -0,0 +1,86 @@
+//! Mode benchmark (`fireworks-sim bench --frames N`) : exactement N frames sur
+//! une simulation à graine fixe, puis un rapport JSON sur stdout pour comparer
+//! les performances entre deux commits.
+
+use serde::Serialize;
+
+use crate::profiler::{Profiler, FRAME_LABEL};
+use crate::profiler_export::MetricSummary;
+
+/// Graine de la physique d'un bench sans `--seed`
+pub const BENCH_DEFAULT_SEED: u64 = 42;
+
+/// Particules actives à chaque frame (métrique du profiler, cf. `step_simulation`)
+pub const ACTIVE_PARTICLES_METRIC: &str = "physic: active particles";
+
+/// Moteur de rendu d'un bench
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BenchRenderer {
+    /// Fenêtre invisible, rendu OpenGL hors écran (par défaut)
+    #[default]
+    Offscreen,
+    /// Fenêtre visible, boucle de rendu normale
+    Window,
+    /// Ni fenêtre ni OpenGL : physique et audio seulement
+    None,
+}
+
+/// Options de la sous-commande `bench`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchOptions {
+    pub frames: u64,
+    /// Remplace `max_rockets` de la config physique
+    pub max_rockets: Option<usize>,
+    /// Code de sortie non nul si le FPS moyen est inférieur
+    pub fail_below_fps: Option<f32>,
+    pub renderer: BenchRenderer,
+}
+
+/// Rapport d'un bench, sérialisé en JSON.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BenchReport {
+    pub frames: u64,
+    pub avg_fps: f32,
+    /// Durées de frame (ms)
+    pub frame_time: Option<MetricSummary>,
+    /// Autres séries du profiler (étapes de la frame, compteurs)
+    pub stages: Vec<MetricSummary>,
+    pub peak_particles: usize,
+    pub dropped_audio_requests: u64,
+    pub fail_below_fps: Option<f32>,
+    /// `false` si le FPS moyen est sous `fail_below_fps`
+    pub passed: bool,
+}
+
+impl BenchReport {
+    /// Rapport construit à partir des mesures du profiler de la boucle de rendu
+    pub fn from_profiler(
+        profiler: &Profiler,
+        dropped_audio_requests: u64,
+        fail_below_fps: Option<f32>,
+    ) -> Self {
+        let (frame_time, stages): (Vec<MetricSummary>, Vec<MetricSummary>) = profiler
+            .metric_summaries()
+            .into_iter()
+            .partition(|s| s.metric == FRAME_LABEL);
+        let peak_particles = profiler
+            .history(ACTIVE_PARTICLES_METRIC)
+            .into_iter()
+            .fold(0.0_f32, f32::max) as usize;
+        let avg_fps = profiler.fps();
+        Self {
+            frames: profiler.total_frames() as u64,
+            avg_fps,
+            frame_time: frame_time.into_iter().next(),
+            stages,
+            peak_particles,
+            dropped_audio_requests,
+            fail_below_fps,
+            passed: fail_below_fps.is_none_or(|min| avg_fps >= min),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("bench report is serializable")
+    }
+}
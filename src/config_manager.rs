@@ -0,0 +1,796 @@
+//! Gestionnaire des configs (`physic.toml`, `renderer.toml`, `audio.toml`).
+//!
+//! Chaque config est partagée par un [`ConfigHandle`] et construite par couches :
+//! valeurs par défaut < fichier < variables `FIREWORKS_<SECTION>__<CHAMP>` <
+//! ligne de commande. Un rechargement (touches `R` / `F7`, fichier modifié
+//! surveillé) produit un [`ConfigEvent`] : les champs « à chaud » sont déjà
+//! appliqués dans le handle, les champs déclarés par [`Reloadable`] comme
+//! nécessitant un redémarrage gardent leur valeur.
+//!
+//! La surveillance compare date de modification et taille des fichiers à chaque
+//! `poll` (au plus tous les `WATCH_INTERVAL`).
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::Context;
+use log::{info, warn};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::audio_engine::AudioConfig;
+use crate::config_presets::{preset_dir, PRESETS_DIR};
+use crate::physic_engine::config::PhysicConfig;
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::config_reload::{config_changes, format_changes, ConfigChange};
+use crate::session::toml_merge;
+use crate::utils::assets::asset_path;
+use crate::utils::embedded_assets::read_asset_to_string;
+
+/// Répertoire par défaut des configs
+pub const CONFIG_DIR: &str = "assets/config";
+
+/// Préfixe des variables d'environnement de surcharge (`FIREWORKS_PHYSIC__MAX_ROCKETS`)
+pub const CONFIG_ENV_PREFIX: &str = "FIREWORKS_";
+
+/// Intervalle minimal entre deux vérifications des fichiers surveillés
+pub const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Config gérée par le `ConfigManager`.
+pub trait Reloadable:
+    fmt::Debug + Clone + Default + Serialize + DeserializeOwned + Send + Sync + 'static
+{
+    /// Section : fichier `<section>.toml`, variables `FIREWORKS_<SECTION>__*`
+    const SECTION: ConfigSection;
+
+    /// Champs (chemins `a.b`, ou tables entières) pris en compte au redémarrage
+    /// seulement ; tous les autres s'appliquent à chaud.
+    const RESTART_FIELDS: &'static [&'static str];
+
+    /// Règles non respectées (`champ: explication`) ; une config non vide est refusée.
+    fn validate(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Réécrit la table lue dans le fichier avant sa fusion (raccourcis).
+    fn expand_file(_table: &mut toml::Table) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Clés surchargeables et type attendu (valeurs par défaut) ; les champs
+    /// `Option` absents du TOML doivent y figurer.
+    fn override_template() -> anyhow::Result<toml::Table> {
+        Ok(toml::Table::try_from(Self::default())?)
+    }
+
+    fn is_hot_field(field: &str) -> bool {
+        !Self::RESTART_FIELDS.iter().any(|restart| {
+            field == *restart
+                || field
+                    .strip_prefix(restart)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigSection {
+    Physic,
+    Renderer,
+    Audio,
+}
+
+impl ConfigSection {
+    pub const ALL: [ConfigSection; 3] = [
+        ConfigSection::Physic,
+        ConfigSection::Renderer,
+        ConfigSection::Audio,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConfigSection::Physic => "physic",
+            ConfigSection::Renderer => "renderer",
+            ConfigSection::Audio => "audio",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|section| section.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Nom du fichier dans le répertoire des configs
+    pub fn file_name(&self) -> String {
+        format!("{}.toml", self.name())
+    }
+}
+
+/// Accumulateur des règles non respectées par une config (cf. `Reloadable::validate`).
+#[derive(Debug, Default)]
+pub struct Violations(Vec<String>);
+
+impl Violations {
+    /// Ajoute `field: message` si `ok` est faux.
+    pub fn check(&mut self, ok: bool, field: &str, message: impl fmt::Display) {
+        if !ok {
+            self.0.push(format!("{}: {}", field, message));
+        }
+    }
+
+    /// Compteur strictement positif
+    pub fn positive(&mut self, field: &str, value: usize) {
+        self.check(value > 0, field, "must be greater than 0");
+    }
+
+    /// Valeur dans `[min, max]`
+    pub fn in_range<T: PartialOrd + fmt::Display>(&mut self, field: &str, value: T, range: (T, T)) {
+        let ok = value >= range.0 && value <= range.1;
+        self.check(
+            ok,
+            field,
+            format_args!("{} is out of range [{}, {}]", value, range.0, range.1),
+        );
+    }
+
+    /// Bornes d'un intervalle dans l'ordre (`min <= max`)
+    pub fn ordered<T: PartialOrd + fmt::Display>(&mut self, min: (&str, T), max: (&str, T)) {
+        self.check(
+            min.1 <= max.1,
+            min.0,
+            format_args!("{} is greater than {} ({})", min.1, max.0, max.1),
+        );
+    }
+
+    pub fn into_vec(self) -> Vec<String> {
+        self.0
+    }
+}
+
+/// Config partagée (lecture depuis n'importe quel thread)
+pub type ConfigHandle<T> = Arc<RwLock<T>>;
+
+/// Effet d'un rechargement sur une section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigEvent {
+    pub section: ConfigSection,
+    /// Champs appliqués (valeurs déjà dans le handle)
+    pub hot: Vec<ConfigChange>,
+    /// Champs modifiés dans le fichier, ignorés jusqu'au redémarrage
+    pub restart: Vec<ConfigChange>,
+}
+
+impl ConfigEvent {
+    /// Résumé d'une ligne (logs, console)
+    pub fn summary(&self) -> String {
+        let mut out = format_changes(&self.hot);
+        if !self.restart.is_empty() {
+            let fields: Vec<&str> = self.restart.iter().map(|c| c.field.as_str()).collect();
+            out.push_str(&format!("; restart required for: {}", fields.join(", ")));
+        }
+        out
+    }
+}
+
+/// Empreinte d'un fichier surveillé
+type FileStamp = Option<(SystemTime, u64)>;
+
+fn file_stamp(path: &Path) -> FileStamp {
+    let metadata = std::fs::metadata(asset_path(path)).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[derive(Debug)]
+struct ConfigSlot<T: Reloadable> {
+    handle: ConfigHandle<T>,
+    path: PathBuf,
+    /// Empreinte du fichier lors de la dernière lecture
+    stamp: FileStamp,
+}
+
+impl<T: Reloadable> ConfigSlot<T> {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            handle: Arc::new(RwLock::new(T::default())),
+            path,
+            stamp: None,
+        }
+    }
+
+    fn get(&self) -> T {
+        self.handle
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn set(&self, config: T) {
+        *self.handle.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    fn load(&mut self, overrides: &toml::Table) -> anyhow::Result<()> {
+        self.stamp = file_stamp(&self.path);
+        let config = layered(&self.path, overrides)?;
+        self.set(config);
+        Ok(())
+    }
+
+    /// Relit la config ; `None` si rien n'a changé.
+    fn reload(&mut self, overrides: &toml::Table) -> anyhow::Result<Option<ConfigEvent>> {
+        self.stamp = file_stamp(&self.path);
+        let new = layered(&self.path, overrides)?;
+        self.apply(new)
+    }
+
+    /// Config lue dans `path` (préréglage), `None` si le fichier n'existe pas.
+    fn prepare(
+        &self,
+        path: PathBuf,
+        overrides: &toml::Table,
+    ) -> anyhow::Result<Option<(PathBuf, T)>> {
+        if !asset_path(&path).is_file() {
+            return Ok(None);
+        }
+        let config = layered(&path, overrides)?;
+        Ok(Some((path, config)))
+    }
+
+    /// Adopte une config préparée et son fichier (relu et surveillé ensuite).
+    fn switch(&mut self, (path, config): (PathBuf, T)) -> anyhow::Result<Option<ConfigEvent>> {
+        self.stamp = file_stamp(&path);
+        self.path = path;
+        self.apply(config)
+    }
+
+    /// Remplace la config ; les champs « au redémarrage » gardent leur valeur.
+    fn apply(&mut self, new: T) -> anyhow::Result<Option<ConfigEvent>> {
+        let old = self.get();
+        let changes = config_changes(&old, &new)?;
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        let (hot, restart): (Vec<_>, Vec<_>) = changes
+            .into_iter()
+            .partition(|change| T::is_hot_field(&change.field));
+        let applied = if restart.is_empty() {
+            new
+        } else {
+            keep_fields(&old, &new, &restart)?
+        };
+        self.set(applied);
+        Ok(Some(ConfigEvent {
+            section: T::SECTION,
+            hot,
+            restart,
+        }))
+    }
+
+    fn file_changed(&self) -> bool {
+        file_stamp(&self.path) != self.stamp
+    }
+}
+
+/// Défauts < fichier `path` (absent : ignoré) < `overrides`, validé.
+fn layered<T: Reloadable>(path: &Path, overrides: &toml::Table) -> anyhow::Result<T> {
+    let mut table = toml::Table::try_from(T::default())?;
+    match read_asset_to_string(path) {
+        Ok(text) => {
+            // Erreurs de syntaxe et de type localisées (ligne, colonne) dans le fichier
+            toml::from_str::<T>(&text)
+                .with_context(|| format!("Invalid config file {}", path.display()))?;
+            let mut file: toml::Table = toml::from_str(&text)?;
+            T::expand_file(&mut file)?;
+            toml_merge(&mut table, &file);
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            info!(
+                "📄 No config file {}, using {} defaults",
+                path.display(),
+                T::SECTION.name()
+            );
+        }
+        Err(e) => return Err(e).with_context(|| format!("Cannot read {}", path.display())),
+    }
+    toml_merge(&mut table, overrides);
+    let config: T = toml::Value::Table(table)
+        .try_into()
+        .with_context(|| format!("Invalid {} config", T::SECTION.name()))?;
+    let violations = config.validate();
+    if !violations.is_empty() {
+        anyhow::bail!(
+            "Invalid {} config ({}):\n  - {}",
+            T::SECTION.name(),
+            path.display(),
+            violations.join("\n  - ")
+        );
+    }
+    Ok(config)
+}
+
+/// `new`, avec les valeurs de `old` pour les champs `kept`.
+fn keep_fields<T: Reloadable>(old: &T, new: &T, kept: &[ConfigChange]) -> anyhow::Result<T> {
+    let old = toml::Table::try_from(old)?;
+    let mut table = toml::Table::try_from(new)?;
+    for change in kept {
+        let path: Vec<&str> = change.field.split('.').collect();
+        set_field(&mut table, &path, table_field(&old, &path).cloned());
+    }
+    Ok(toml::Value::Table(table).try_into()?)
+}
+
+fn table_field<'a>(table: &'a toml::Table, path: &[&str]) -> Option<&'a toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get(*key)?.as_table()?;
+    }
+    table.get(*last)
+}
+
+fn set_field(table: &mut toml::Table, path: &[&str], value: Option<toml::Value>) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut table = table;
+    for key in parents {
+        let entry = table
+            .entry(key.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let Some(nested) = entry.as_table_mut() else {
+            return;
+        };
+        table = nested;
+    }
+    match value {
+        Some(value) => {
+            table.insert(last.to_string(), value);
+        }
+        None => {
+            table.remove(*last);
+        }
+    }
+}
+
+/// Valeur TOML d'une surcharge textuelle (`64`, `true`, `[1, 2]`), chaîne sinon.
+pub fn parse_override_value(text: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {}", text))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(text.to_string()))
+}
+
+/// `value` au type de `expected` (valeur par défaut du champ) : entier accepté
+/// pour un flottant, tout texte pour une chaîne.
+fn coerce_override(expected: &toml::Value, value: toml::Value) -> Result<toml::Value, String> {
+    use toml::Value;
+    match (expected, value) {
+        (Value::Float(_), Value::Integer(i)) => Ok(Value::Float(i as f64)),
+        (Value::String(_), Value::String(text)) => Ok(Value::String(text)),
+        // `--set physic.shapes_dir=2024` : un chemin, pas un entier
+        (Value::String(_), value) => Ok(Value::String(value.to_string())),
+        (expected, value) if expected.same_type(&value) => Ok(value),
+        (expected, value) => Err(format!(
+            "expected {}, got {} {}",
+            expected.type_str(),
+            value.type_str(),
+            value
+        )),
+    }
+}
+
+/// Surcharges de `layer` à plat (`camera.min_zoom`), converties selon `template`.
+fn flatten_overrides(
+    template: &toml::Table,
+    layer: &toml::Table,
+    prefix: &str,
+    out: &mut Vec<(String, Result<toml::Value, String>)>,
+) {
+    for (key, value) in layer {
+        let field = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match (template.get(key), value) {
+            (None, _) => out.push((field, Err("unknown key".to_string()))),
+            (Some(toml::Value::Table(expected)), toml::Value::Table(nested)) => {
+                flatten_overrides(expected, nested, &field, out)
+            }
+            (Some(expected), value) => out.push((field, coerce_override(expected, value.clone()))),
+        }
+    }
+}
+
+/// Origine d'une couche de surcharge, rappelée dans les messages
+#[derive(Debug, Clone, Copy)]
+enum OverrideSource {
+    Env,
+    Cli,
+}
+
+impl OverrideSource {
+    fn describe(self, section: ConfigSection, field: &str) -> String {
+        match self {
+            OverrideSource::Env => format!(
+                "{}{}__{}",
+                CONFIG_ENV_PREFIX,
+                section.name().to_ascii_uppercase(),
+                field.replace('.', "__").to_ascii_uppercase()
+            ),
+            OverrideSource::Cli => format!("--set {}.{}", section.name(), field),
+        }
+    }
+}
+
+/// Surcharge à plat : origine, champ, valeur convertie ou erreur
+type OverrideField = (OverrideSource, String, Result<toml::Value, String>);
+
+/// Surcharge `<section>.<champ>[.<sous_champ>]=<valeur>` (option `--set`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigOverride {
+    pub section: ConfigSection,
+    /// Chemin du champ dans la section (`camera.min_zoom`)
+    pub field: String,
+    pub value: toml::Value,
+}
+
+impl std::str::FromStr for ConfigOverride {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (key, value) = text
+            .split_once('=')
+            .ok_or_else(|| format!("invalid override '{}' (expected KEY=VALUE)", text))?;
+        let (section, field) = key.trim().split_once('.').unwrap_or((key.trim(), ""));
+        let section = ConfigSection::from_name(section).ok_or_else(|| {
+            format!(
+                "invalid override '{}': unknown section '{}' (physic, renderer or audio)",
+                text, section
+            )
+        })?;
+        if field.split('.').any(str::is_empty) {
+            return Err(format!(
+                "invalid override '{}' (expected {}.<field>=VALUE)",
+                text,
+                section.name()
+            ));
+        }
+        Ok(Self {
+            section,
+            field: field.to_string(),
+            value: parse_override_value(value.trim()),
+        })
+    }
+}
+
+/// Surcharges lues dans les variables `FIREWORKS_<SECTION>__<CHAMP>[__<SOUS_CHAMP>]`,
+/// rangées par section (`{ physic = { max_rockets = 64 } }`).
+pub fn env_overrides(vars: impl IntoIterator<Item = (String, String)>) -> toml::Table {
+    let mut overrides = toml::Table::new();
+    for (name, value) in vars {
+        let Some(rest) = name.strip_prefix(CONFIG_ENV_PREFIX) else {
+            continue;
+        };
+        let mut parts = rest.split("__").map(str::to_ascii_lowercase);
+        let Some(section) = parts.next().as_deref().and_then(ConfigSection::from_name) else {
+            continue;
+        };
+        let path: Vec<String> = parts.collect();
+        if path.is_empty() || path.iter().any(String::is_empty) {
+            continue;
+        }
+        let mut full = vec![section.name()];
+        full.extend(path.iter().map(String::as_str));
+        set_field(&mut overrides, &full, Some(parse_override_value(&value)));
+    }
+    overrides
+}
+
+/// Configs de l'application et leurs couches de surcharge.
+#[derive(Debug)]
+pub struct ConfigManager {
+    physic: ConfigSlot<PhysicConfig>,
+    renderer: ConfigSlot<RendererConfig>,
+    audio: ConfigSlot<AudioConfig>,
+    /// Surcharges par section : variables d'environnement puis ligne de commande
+    env: toml::Table,
+    cli: toml::Table,
+    /// Répertoire des préréglages nommés (cf. `crate::config_presets`)
+    presets_dir: PathBuf,
+    watching: bool,
+    last_poll: Option<Instant>,
+}
+
+impl Default for ConfigManager {
+    fn default() -> Self {
+        Self::new(CONFIG_DIR)
+    }
+}
+
+impl ConfigManager {
+    /// Configs `<dir>/{physic,renderer,audio}.toml`, aux valeurs par défaut
+    /// jusqu'au premier `load`.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        Self {
+            physic: ConfigSlot::new(dir.join(ConfigSection::Physic.file_name())),
+            renderer: ConfigSlot::new(dir.join(ConfigSection::Renderer.file_name())),
+            audio: ConfigSlot::new(dir.join(ConfigSection::Audio.file_name())),
+            env: toml::Table::new(),
+            cli: toml::Table::new(),
+            presets_dir: PathBuf::from(PRESETS_DIR),
+            watching: false,
+            last_poll: None,
+        }
+    }
+
+    /// Remplace le fichier d'une section (`--physic-config`, `--renderer-config`).
+    pub fn set_path(&mut self, section: ConfigSection, path: impl Into<PathBuf>) {
+        let path = path.into();
+        match section {
+            ConfigSection::Physic => self.physic.path = path,
+            ConfigSection::Renderer => self.renderer.path = path,
+            ConfigSection::Audio => self.audio.path = path,
+        }
+    }
+
+    pub fn path(&self, section: ConfigSection) -> &Path {
+        match section {
+            ConfigSection::Physic => &self.physic.path,
+            ConfigSection::Renderer => &self.renderer.path,
+            ConfigSection::Audio => &self.audio.path,
+        }
+    }
+
+    /// Couche des variables d'environnement (cf. `env_overrides`).
+    pub fn with_env(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.env = env_overrides(vars);
+        self
+    }
+
+    /// Surcharge de ligne de commande `field` (chemin `a.b`) de `section`.
+    pub fn set_override(&mut self, section: ConfigSection, field: &str, value: toml::Value) {
+        let mut path = vec![section.name()];
+        path.extend(field.split('.'));
+        set_field(&mut self.cli, &path, Some(value));
+    }
+
+    /// Surcharges de `section` à plat, environnement puis ligne de commande,
+    /// converties au type des champs.
+    fn override_fields(&self, section: ConfigSection) -> anyhow::Result<Vec<OverrideField>> {
+        let template = match section {
+            ConfigSection::Physic => PhysicConfig::override_template()?,
+            ConfigSection::Renderer => RendererConfig::override_template()?,
+            ConfigSection::Audio => AudioConfig::override_template()?,
+        };
+        let mut fields = Vec::new();
+        for (source, layer) in [
+            (OverrideSource::Env, &self.env),
+            (OverrideSource::Cli, &self.cli),
+        ] {
+            let Some(table) = layer.get(section.name()).and_then(toml::Value::as_table) else {
+                continue;
+            };
+            let mut flat = Vec::new();
+            flatten_overrides(&template, table, "", &mut flat);
+            fields.extend(
+                flat.into_iter()
+                    .map(|(field, value)| (source, field, value)),
+            );
+        }
+        Ok(fields)
+    }
+
+    /// Surcharges de `section` (la ligne de commande l'emporte) ; clé inconnue ou
+    /// type incompatible : erreur.
+    fn overrides(&self, section: ConfigSection) -> anyhow::Result<toml::Table> {
+        let mut overrides = toml::Table::new();
+        let mut errors = Vec::new();
+        for (source, field, value) in self.override_fields(section)? {
+            match value {
+                Ok(value) => {
+                    let path: Vec<&str> = field.split('.').collect();
+                    set_field(&mut overrides, &path, Some(value));
+                }
+                Err(e) => errors.push(format!(
+                    "{}.{}: {} ({})",
+                    section.name(),
+                    field,
+                    e,
+                    source.describe(section, &field)
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            anyhow::bail!(
+                "Invalid {} config overrides:\n  - {}",
+                section.name(),
+                errors.join("\n  - ")
+            );
+        }
+        Ok(overrides)
+    }
+
+    /// Surcharges valides, une par ligne (`physic.max_rockets = 128 (--set ...)`).
+    pub fn applied_overrides(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for section in ConfigSection::ALL {
+            for (source, field, value) in self.override_fields(section).unwrap_or_default() {
+                if let Ok(value) = value {
+                    lines.push(format!(
+                        "{}.{} = {} ({})",
+                        section.name(),
+                        field,
+                        value,
+                        source.describe(section, &field)
+                    ));
+                }
+            }
+        }
+        lines
+    }
+
+    /// Lit les trois configs. Une config illisible garde ses valeurs par défaut
+    /// (erreur signalée, les autres sont chargées).
+    pub fn load(&mut self) -> anyhow::Result<()> {
+        for line in self.applied_overrides() {
+            info!("🔧 Config override: {}", line);
+        }
+        let mut errors = Vec::new();
+        let loaded = self
+            .overrides(ConfigSection::Physic)
+            .and_then(|overrides| self.physic.load(&overrides));
+        if let Err(e) = loaded {
+            errors.push(format!("{:#}", e));
+        }
+        let loaded = self
+            .overrides(ConfigSection::Renderer)
+            .and_then(|overrides| self.renderer.load(&overrides));
+        if let Err(e) = loaded {
+            errors.push(format!("{:#}", e));
+        }
+        let loaded = self
+            .overrides(ConfigSection::Audio)
+            .and_then(|overrides| self.audio.load(&overrides));
+        if let Err(e) = loaded {
+            errors.push(format!("{:#}", e));
+        }
+        if !errors.is_empty() {
+            anyhow::bail!(errors.join("\n"));
+        }
+        Ok(())
+    }
+
+    /// Relit une section ; `None` si rien n'a changé.
+    pub fn reload(&mut self, section: ConfigSection) -> anyhow::Result<Option<ConfigEvent>> {
+        let overrides = self.overrides(section)?;
+        match section {
+            ConfigSection::Physic => self.physic.reload(&overrides),
+            ConfigSection::Renderer => self.renderer.reload(&overrides),
+            ConfigSection::Audio => self.audio.reload(&overrides),
+        }
+    }
+
+    pub fn physic(&self) -> ConfigHandle<PhysicConfig> {
+        self.physic.handle.clone()
+    }
+
+    pub fn renderer(&self) -> ConfigHandle<RendererConfig> {
+        self.renderer.handle.clone()
+    }
+
+    pub fn audio(&self) -> ConfigHandle<AudioConfig> {
+        self.audio.handle.clone()
+    }
+
+    /// Copie courante de la config physique
+    pub fn physic_config(&self) -> PhysicConfig {
+        self.physic.get()
+    }
+
+    pub fn renderer_config(&self) -> RendererConfig {
+        self.renderer.get()
+    }
+
+    pub fn audio_config(&self) -> AudioConfig {
+        self.audio.get()
+    }
+
+    pub fn presets_dir(&self) -> &Path {
+        &self.presets_dir
+    }
+
+    pub fn set_presets_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.presets_dir = dir.into();
+    }
+
+    /// Applique le préréglage `name` (`<presets_dir>/<name>/<section>.toml`) : toutes
+    /// ses configs sont lues et validées avant d'en appliquer une, dans l'ordre
+    /// physique, rendu, audio. Les sections sans fichier sont inchangées ; les
+    /// autres sont ensuite relues depuis le préréglage.
+    pub fn apply_preset(&mut self, name: &str) -> anyhow::Result<Vec<ConfigEvent>> {
+        let dir = preset_dir(&self.presets_dir, name)?;
+        if !asset_path(&dir).is_dir() {
+            anyhow::bail!("Unknown preset '{}' ({})", name, dir.display());
+        }
+        let file = |section: ConfigSection| dir.join(section.file_name());
+        let physic = self.physic.prepare(
+            file(ConfigSection::Physic),
+            &self.overrides(ConfigSection::Physic)?,
+        )?;
+        let renderer = self.renderer.prepare(
+            file(ConfigSection::Renderer),
+            &self.overrides(ConfigSection::Renderer)?,
+        )?;
+        let audio = self.audio.prepare(
+            file(ConfigSection::Audio),
+            &self.overrides(ConfigSection::Audio)?,
+        )?;
+        if physic.is_none() && renderer.is_none() && audio.is_none() {
+            anyhow::bail!("Preset '{}' has no config file ({})", name, dir.display());
+        }
+
+        let mut events = Vec::new();
+        if let Some(prepared) = physic {
+            events.extend(self.physic.switch(prepared)?);
+        }
+        if let Some(prepared) = renderer {
+            events.extend(self.renderer.switch(prepared)?);
+        }
+        if let Some(prepared) = audio {
+            events.extend(self.audio.switch(prepared)?);
+        }
+        Ok(events)
+    }
+
+    pub fn set_watching(&mut self, watching: bool) {
+        if watching != self.watching {
+            info!(
+                "👀 Config file watching {}",
+                if watching { "enabled" } else { "disabled" }
+            );
+        }
+        self.watching = watching;
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watching
+    }
+
+    /// Fichiers surveillés modifiés depuis leur dernière lecture : rechargés, un
+    /// événement par section changée. Sans effet hors surveillance ou avant
+    /// `WATCH_INTERVAL` depuis le dernier appel.
+    pub fn poll(&mut self, now: Instant) -> Vec<ConfigEvent> {
+        if !self.watching {
+            return Vec::new();
+        }
+        if self
+            .last_poll
+            .is_some_and(|last| now.saturating_duration_since(last) < WATCH_INTERVAL)
+        {
+            return Vec::new();
+        }
+        self.last_poll = Some(now);
+
+        let changed: Vec<ConfigSection> = ConfigSection::ALL
+            .into_iter()
+            .filter(|section| match section {
+                ConfigSection::Physic => self.physic.file_changed(),
+                ConfigSection::Renderer => self.renderer.file_changed(),
+                ConfigSection::Audio => self.audio.file_changed(),
+            })
+            .collect();
+        let mut events = Vec::new();
+        for section in changed {
+            match self.reload(section) {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => {}
+                Err(e) => warn!("⚠️ {} config not reloaded: {:#}", section.name(), e),
+            }
+        }
+        events
+    }
+}
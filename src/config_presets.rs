@@ -0,0 +1,91 @@
+//! Préréglages nommés : un répertoire `assets/presets/<nom>/` par « look »
+//! (`calme`, `finale`, `bench`...) contenant `physic.toml`, `renderer.toml` et
+//! éventuellement `audio.toml`.
+//!
+//! `sim.preset <nom>` les applique via le `ConfigManager` (cf.
+//! `ConfigManager::apply_preset`), `sim.preset.save <nom>` fige les configs
+//! courantes dans un nouveau préréglage et `sim.preset.list` les énumère.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::audio_engine::AudioConfig;
+use crate::config_manager::ConfigSection;
+use crate::physic_engine::config::PhysicConfig;
+use crate::renderer_engine::config::RendererConfig;
+use crate::utils::assets::asset_path;
+
+/// Répertoire par défaut des préréglages
+pub const PRESETS_DIR: &str = "assets/presets";
+
+/// Répertoire du préréglage `name` ; le nom est un simple nom de répertoire.
+pub fn preset_dir(presets_dir: &Path, name: &str) -> anyhow::Result<PathBuf> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!(
+            "Invalid preset name '{}' (letters, digits, '-' and '_' only)",
+            name
+        );
+    }
+    Ok(presets_dir.join(name))
+}
+
+/// Préréglages disponibles (répertoires contenant au moins une config), triés.
+pub fn list_presets(presets_dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(asset_path(presets_dir)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| {
+            ConfigSection::ALL
+                .iter()
+                .any(|section| entry.path().join(section.file_name()).is_file())
+        })
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| preset_dir(presets_dir, name).is_ok())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Configs enregistrées dans un préréglage.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PresetSnapshot<'a> {
+    pub physic: &'a PhysicConfig,
+    pub renderer: &'a RendererConfig,
+    pub audio: &'a AudioConfig,
+}
+
+/// Écrit `snapshot` dans le préréglage `name` (créé ou remplacé) ; renvoie son
+/// répertoire.
+pub fn save_preset(
+    presets_dir: &Path,
+    name: &str,
+    snapshot: PresetSnapshot,
+) -> anyhow::Result<PathBuf> {
+    let dir = preset_dir(presets_dir, name)?;
+    snapshot
+        .physic
+        .save_to_file(dir.join(ConfigSection::Physic.file_name()))?;
+    write_toml(
+        &dir.join(ConfigSection::Renderer.file_name()),
+        snapshot.renderer,
+    )?;
+    write_toml(&dir.join(ConfigSection::Audio.file_name()), snapshot.audio)?;
+    Ok(dir)
+}
+
+fn write_toml<T: serde::Serialize>(path: &Path, config: &T) -> anyhow::Result<()> {
+    let path = &asset_path(path);
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, toml::to_string_pretty(config)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -0,0 +1,367 @@
+//! Mode démo (« attract mode ») pour faire tourner le simulateur sans
+//! surveillance, comme un économiseur d'écran (`sim.demo on`, `--demo`).
+//!
+//! Un [`DemoDirector`] change périodiquement (toutes les 20 à 60 s par défaut)
+//! la palette des fusées, la forme des explosions, la densité de lancement et
+//! l'intensité du bloom, dans les bornes de la table `[demo]` de renderer.toml.
+//! De temps en temps, il déclenche un final : lancements quasi continus pendant
+//! quelques secondes, puis retour à la densité courante.
+//!
+//! Tout passe par les API existantes : `PhysicEngine::reload_config` (sans
+//! relecture de fichier) et la `RendererConfig` partagée. À l'arrêt, la config
+//! physique et l'intensité du bloom d'avant la démo sont restaurées.
+
+use std::collections::BTreeMap;
+
+use log::info;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::physic_engine::config::PhysicConfig;
+use crate::physic_engine::PhysicEngine;
+use crate::renderer_engine::config::RendererConfig;
+
+/// Palette nommée du mode démo
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DemoPalette {
+    pub name: String,
+    /// Couleurs RGB (cf. `PhysicConfig::rocket_palette`)
+    pub colors: Vec<[f32; 3]>,
+}
+
+/// Bornes du mode démo (table `[demo]` de renderer.toml)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DemoConfig {
+    /// Délai entre deux changements, tiré dans `[min, max]` (s)
+    pub change_interval: [f32; 2],
+    /// Multiplicateur de la cadence de lancement (intervalles divisés par ce facteur)
+    pub launch_density: [f32; 2],
+    /// Intensité du bloom
+    pub bloom_intensity: [f32; 2],
+    /// Poids de tirage des formes d'explosion (`sphere`, `ring`, `heart`, `star`,
+    /// `spiral`, `images`)
+    pub shape_weights: BTreeMap<String, f32>,
+    /// Palettes parcourues ; une palette vide revient aux couleurs aléatoires
+    pub palettes: Vec<DemoPalette>,
+    /// Probabilité qu'un changement déclenche un final
+    pub finale_chance: f32,
+    /// Durée d'un final (s)
+    pub finale_duration: f32,
+    /// Intervalle entre deux fusées pendant un final (s)
+    pub finale_rocket_interval: f32,
+    /// Graine des tirages (`None` : aléatoire)
+    pub seed: Option<u64>,
+}
+
+impl Default for DemoConfig {
+    fn default() -> Self {
+        let shape_weights = [
+            ("sphere", 3.0),
+            ("ring", 1.0),
+            ("heart", 1.0),
+            ("star", 1.0),
+            ("spiral", 1.0),
+        ]
+        .into_iter()
+        .map(|(name, weight)| (name.to_string(), weight))
+        .collect();
+        let palette = |name: &str, colors: &[[f32; 3]]| DemoPalette {
+            name: name.to_string(),
+            colors: colors.to_vec(),
+        };
+        Self {
+            change_interval: [20.0, 60.0],
+            launch_density: [0.5, 2.0],
+            bloom_intensity: [0.4, 1.4],
+            shape_weights,
+            palettes: vec![
+                palette("random", &[]),
+                palette(
+                    "gold",
+                    &[[1.0, 0.85, 0.4], [1.0, 0.7, 0.25], [1.0, 0.95, 0.75]],
+                ),
+                palette(
+                    "ice",
+                    &[[0.6, 0.85, 1.0], [0.8, 0.95, 1.0], [0.5, 0.6, 1.0]],
+                ),
+                palette(
+                    "fire",
+                    &[[1.0, 0.3, 0.1], [1.0, 0.55, 0.15], [1.0, 0.8, 0.3]],
+                ),
+                palette(
+                    "tricolore",
+                    &[[0.2, 0.35, 1.0], [1.0, 1.0, 1.0], [1.0, 0.2, 0.25]],
+                ),
+            ],
+            finale_chance: 0.2,
+            finale_duration: 6.0,
+            finale_rocket_interval: 0.002,
+            seed: None,
+        }
+    }
+}
+
+/// Réglages appliqués par un changement du directeur
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoScene {
+    pub palette: String,
+    pub shape: String,
+    pub launch_density: f32,
+    pub bloom_intensity: f32,
+}
+
+/// Ce qu'a fait le directeur pendant un `tick` (aussi journalisé)
+#[derive(Debug, Clone, PartialEq)]
+pub enum DemoEvent {
+    Started,
+    Changed(DemoScene),
+    FinaleStarted,
+    FinaleEnded,
+    Stopped,
+}
+
+/// Directeur du mode démo, avancé à chaque pas de simulation par `tick`.
+#[derive(Debug)]
+pub struct DemoDirector {
+    config: DemoConfig,
+    rng: SmallRng,
+    enabled: bool,
+    /// Temps restant avant le prochain changement (s)
+    next_change: f32,
+    /// Temps restant du final en cours (s)
+    finale: Option<f32>,
+    /// Config physique et intensité du bloom d'avant la démo (restaurées à l'arrêt)
+    base_physic: Option<PhysicConfig>,
+    base_bloom_intensity: f32,
+    /// Densité de lancement courante (hors final)
+    launch_density: f32,
+}
+
+impl Default for DemoDirector {
+    fn default() -> Self {
+        Self::new(DemoConfig::default())
+    }
+}
+
+impl DemoDirector {
+    pub fn new(config: DemoConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_rng(&mut rand::rng()),
+        };
+        Self {
+            config,
+            rng,
+            enabled: false,
+            next_change: 0.0,
+            finale: None,
+            base_physic: None,
+            base_bloom_intensity: 0.0,
+            launch_density: 1.0,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn in_finale(&self) -> bool {
+        self.finale.is_some()
+    }
+
+    pub fn config(&self) -> &DemoConfig {
+        &self.config
+    }
+
+    /// Démarre la démo (bornes de `renderer.demo`) : le premier changement a lieu
+    /// au `tick` suivant. L'arrêt restaure la physique et le bloom d'avant.
+    pub fn set_enabled(
+        &mut self,
+        enabled: bool,
+        physic: &mut dyn PhysicEngine,
+        renderer: &mut RendererConfig,
+    ) -> Option<DemoEvent> {
+        if enabled == self.enabled {
+            return None;
+        }
+        self.enabled = enabled;
+        let event = if enabled {
+            if self.config != renderer.demo {
+                *self = Self {
+                    enabled: true,
+                    ..Self::new(renderer.demo.clone())
+                };
+            }
+            self.base_physic = Some(physic.get_config().clone());
+            self.base_bloom_intensity = renderer.bloom_intensity;
+            self.next_change = 0.0;
+            self.launch_density = 1.0;
+            DemoEvent::Started
+        } else {
+            if let Some(base) = self.base_physic.take() {
+                physic.reload_config(&base);
+            }
+            renderer.bloom_intensity = self.base_bloom_intensity;
+            self.finale = None;
+            DemoEvent::Stopped
+        };
+        log_event(&event);
+        Some(event)
+    }
+
+    /// Avance le directeur de `dt` secondes simulées.
+    pub fn tick(
+        &mut self,
+        dt: f32,
+        physic: &mut dyn PhysicEngine,
+        renderer: &mut RendererConfig,
+    ) -> Vec<DemoEvent> {
+        let mut events = Vec::new();
+        if !self.enabled {
+            return events;
+        }
+
+        if let Some(remaining) = &mut self.finale {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                self.finale = None;
+                self.apply_density(physic, self.launch_density);
+                events.push(DemoEvent::FinaleEnded);
+            }
+        }
+
+        self.next_change -= dt;
+        if self.next_change <= 0.0 && self.finale.is_none() {
+            let scene = self.change_scene(physic, renderer);
+            events.push(DemoEvent::Changed(scene));
+            if self.rng.random::<f32>() < self.config.finale_chance {
+                self.finale = Some(self.config.finale_duration.max(0.0));
+                self.apply_finale(physic);
+                events.push(DemoEvent::FinaleStarted);
+            }
+            self.next_change = draw(&mut self.rng, self.config.change_interval).max(0.1);
+        }
+
+        events.iter().for_each(log_event);
+        events
+    }
+
+    /// Nouvelle palette, forme, densité et intensité du bloom
+    fn change_scene(
+        &mut self,
+        physic: &mut dyn PhysicEngine,
+        renderer: &mut RendererConfig,
+    ) -> DemoScene {
+        let palette = if self.config.palettes.is_empty() {
+            None
+        } else {
+            let i = self.rng.random_range(0..self.config.palettes.len());
+            Some(self.config.palettes[i].clone())
+        };
+        self.launch_density = draw(&mut self.rng, self.config.launch_density).max(0.01);
+        let bloom_intensity = draw(&mut self.rng, self.config.bloom_intensity);
+
+        let mut config = self.scaled_physic(self.launch_density);
+        if let Some(palette) = &palette {
+            config.rocket_palette = palette.colors.clone();
+        }
+        physic.reload_config(&config);
+        renderer.bloom_intensity = bloom_intensity;
+
+        let shape = self.pick_shape(physic);
+        DemoScene {
+            palette: palette.map_or_else(|| "random".to_string(), |p| p.name),
+            shape,
+            launch_density: self.launch_density,
+            bloom_intensity,
+        }
+    }
+
+    /// Forme tirée selon `shape_weights` ; la gerbe sphérique si elle ne peut
+    /// pas être installée.
+    fn pick_shape(&mut self, physic: &mut dyn PhysicEngine) -> String {
+        let weights: Vec<(&String, f32)> = self
+            .config
+            .shape_weights
+            .iter()
+            .map(|(name, &weight)| (name, weight.max(0.0)))
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+        let mut target = if total > 0.0 {
+            self.rng.random_range(0.0..total)
+        } else {
+            0.0
+        };
+        let name = weights
+            .iter()
+            .find(|(_, weight)| {
+                target -= weight;
+                target < 0.0
+            })
+            .or(weights.last())
+            .map_or("sphere", |(name, _)| name.as_str());
+
+        let installed = match name {
+            "sphere" => false,
+            "images" => physic.rescan_shapes().is_ok_and(|count| count > 0),
+            kind => physic.load_explosion_parametric(kind, &[]).is_ok(),
+        };
+        if installed {
+            name.to_string()
+        } else {
+            physic.clear_explosion_shape();
+            "sphere".to_string()
+        }
+    }
+
+    /// Config physique de base, intervalles de lancement divisés par `density`
+    fn scaled_physic(&self, density: f32) -> PhysicConfig {
+        let mut config = self.base_physic.clone().unwrap_or_default();
+        config.rocket_interval_mean /= density;
+        config.rocket_interval_variation /= density;
+        config.rocket_max_next_interval /= density;
+        config
+    }
+
+    fn apply_density(&self, physic: &mut dyn PhysicEngine, density: f32) {
+        let mut config = self.scaled_physic(density);
+        config.rocket_palette = physic.get_config().rocket_palette.clone();
+        physic.reload_config(&config);
+    }
+
+    /// Lancements quasi continus pendant le final
+    fn apply_finale(&self, physic: &mut dyn PhysicEngine) {
+        let mut config = physic.get_config().clone();
+        let interval = self.config.finale_rocket_interval.max(0.0);
+        config.rocket_interval_mean = interval;
+        config.rocket_interval_variation = 0.0;
+        config.rocket_max_next_interval = interval;
+        physic.reload_config(&config);
+    }
+}
+
+/// Valeur tirée dans `[min, max]` (bornes remises dans l'ordre)
+fn draw(rng: &mut SmallRng, [a, b]: [f32; 2]) -> f32 {
+    let (min, max) = (a.min(b), a.max(b));
+    if max > min {
+        rng.random_range(min..=max)
+    } else {
+        min
+    }
+}
+
+fn log_event(event: &DemoEvent) {
+    match event {
+        DemoEvent::Started => info!("🎬 Demo mode on"),
+        DemoEvent::Changed(scene) => info!(
+            "🎬 Demo: palette {}, shape {}, launch density x{:.2}, bloom {:.2}",
+            scene.palette, scene.shape, scene.launch_density, scene.bloom_intensity
+        ),
+        DemoEvent::FinaleStarted => info!("🎆 Demo: finale!"),
+        DemoEvent::FinaleEnded => info!("🎬 Demo: finale over"),
+        DemoEvent::Stopped => info!("🎬 Demo mode off"),
+    }
+}
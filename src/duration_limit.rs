@@ -0,0 +1,93 @@
+//! Durée limite du spectacle (`--duration`), pour les exports vidéo et audio de
+//! longueur fixe.
+//!
+//! Les lancements s'arrêtent `PhysicConfig::sky_clear_time` secondes avant la
+//! fin, pour que le ciel se vide de lui-même ; la boucle s'arrête dès que la
+//! durée est atteinte et qu'il ne reste plus de fusée active, ou au plus tard
+//! `DURATION_LIMIT_TIMEOUT` secondes simulées après (attracteurs, lancements
+//! manuels déjà partis...).
+
+use log::{info, warn};
+
+use crate::physic_engine::PhysicEngine;
+
+/// Délai (s simulées) accordé après la durée limite aux fusées encore actives
+pub const DURATION_LIMIT_TIMEOUT: f32 = 10.0;
+
+/// Tolérance sur le cumul des pas : 60 pas de 1/60 s font 1 s, pas 0.99999994
+const ELAPSED_EPSILON: f32 = 1e-4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DurationLimit {
+    duration: f32,
+    /// Arrêt des lancements à `duration - clear_time`
+    clear_time: f32,
+    /// Temps simulé écoulé (s)
+    elapsed: f32,
+    launches_stopped: bool,
+}
+
+impl DurationLimit {
+    pub fn new(duration: f32, clear_time: f32) -> Self {
+        Self {
+            duration: duration.max(0.0),
+            clear_time: clear_time.max(0.0),
+            elapsed: 0.0,
+            launches_stopped: false,
+        }
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Temps restant avant la durée limite (0 une fois atteinte)
+    pub fn remaining(&self) -> f32 {
+        let remaining = self.duration - self.elapsed;
+        if remaining <= ELAPSED_EPSILON {
+            0.0
+        } else {
+            remaining
+        }
+    }
+
+    pub fn launches_stopped(&self) -> bool {
+        self.launches_stopped
+    }
+
+    /// Avance de `dt` secondes simulées ; coupe les lancements quand il ne
+    /// reste plus que le temps de vider le ciel.
+    pub fn tick(&mut self, dt: f32, physic: &mut dyn PhysicEngine) {
+        self.elapsed += dt;
+        if !self.launches_stopped && self.remaining() <= self.clear_time {
+            self.launches_stopped = true;
+            physic.set_launches_enabled(false);
+            info!(
+                "🌌 Launches stopped, {:.1} s before the end of the show",
+                self.remaining()
+            );
+        }
+    }
+
+    /// Durée atteinte et ciel vide, ou délai de grâce écoulé.
+    pub fn is_finished(&self, active_rockets: usize) -> bool {
+        if self.remaining() > 0.0 {
+            return false;
+        }
+        if active_rockets == 0 {
+            return true;
+        }
+        let timed_out = self.elapsed >= self.duration + DURATION_LIMIT_TIMEOUT;
+        if timed_out {
+            warn!(
+                "⚠️ {} rocket(s) still active {:.0} s after the duration limit",
+                active_rockets, DURATION_LIMIT_TIMEOUT
+            );
+        }
+        timed_out
+    }
+}
@@ -1,3 +1,13 @@
+pub mod app_options;
+pub mod asset_manifest;
+pub mod bench;
+pub mod config_manager;
+pub mod config_presets;
+pub mod demo_director;
+pub mod duration_limit;
+pub use app_options::{AppCommand, AppOptions};
+pub mod session;
+pub mod sim_clock;
 pub mod simulator;
 pub use simulator::Simulator;
 // Renderer engine
@@ -16,6 +26,9 @@ pub use physic_engine::PhysicEngineIterator;
 
 // Profiler
 pub mod profiler;
+pub mod profiler_export;
+pub mod profiler_scopes;
+pub mod profiler_trace;
 // Utilities
 pub mod utils;
 
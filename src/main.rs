@@ -1,79 +1,324 @@
 // Ici on importe depuis la crate lib complète
 use anyhow::Result;
-use log::info;
-use std::{cmp, env, path::PathBuf};
+use log::{error, info, warn};
+use std::path::Path;
 
-use fireworks_sim::audio_engine::settings::AudioEngineSettings;
+use fireworks_sim::asset_manifest::AssetManifest;
 use fireworks_sim::audio_engine::{FireworksAudio3D, FireworksAudioConfig};
-use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::bench::{BenchOptions, BenchRenderer, BenchReport, BENCH_DEFAULT_SEED};
+use fireworks_sim::config_manager::{ConfigManager, ConfigSection};
 use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::AnyPhysicEngine;
+use fireworks_sim::profiler::Profiler;
+use fireworks_sim::renderer_engine::command_alias::ALIASES_CONFIG_PATH;
+use fireworks_sim::renderer_engine::command_bind::BINDS_CONFIG_PATH;
+use fireworks_sim::renderer_engine::command_script::ExecArgs;
+use fireworks_sim::renderer_engine::command_stats::COMMAND_STATS_PATH;
+use fireworks_sim::renderer_engine::headless::HEADLESS_DEFAULT_TIME_STEP;
 use fireworks_sim::renderer_engine::renderer::Renderer;
+#[cfg(feature = "backend_winit")]
+use fireworks_sim::renderer_engine::WinitRenderer;
+use fireworks_sim::renderer_engine::{HeadlessRenderer, NullRendererEngine, RendererEngine};
+use fireworks_sim::session::{Session, SESSION_PATH};
+use fireworks_sim::utils::assets::set_assets_dir;
+use fireworks_sim::utils::log_sink::init_logging;
+use fireworks_sim::utils::panic_hook::install_panic_hook;
 use fireworks_sim::utils::show_rust_core_dependencies;
-use fireworks_sim::Simulator;
+use fireworks_sim::{AppCommand, AppOptions, AudioEngine, PhysicEngineFull, Simulator};
 
 /// Main entry point for the Fireworks Simulator application.
 fn main() -> Result<()> {
-    env_logger::init();
+    // Arguments (cf. `fireworks_sim::app_options`) : `--help` et les erreurs de
+    // syntaxe quittent avant toute initialisation
+    let mut options = AppOptions::from_env();
+
+    // stderr (RUST_LOG) + console en jeu (`console_log_filter` de renderer.toml)
+    // + fichier avec rotation (`--log-file`)
+    init_logging(options.log_file.clone());
 
     info!("🚀 Starting Fireworks Simulator...");
 
     show_rust_core_dependencies();
 
-    // TODO: mettre en place un vrai gestionnaire de configurations (avec traits) !
-    let physic_config = PhysicConfig::from_file("assets/config/physic.toml").unwrap_or_default();
+    // `--assets-dir` : première racine où chercher les chemins `assets/...`, avant
+    // $FIREWORKS_ASSETS, l'exécutable, `./assets` et XDG (cf. `utils::assets::resolve`)
+    if let Some(dir) = &options.assets_dir {
+        info!("📁 Assets directory: {}", dir.display());
+    }
+    set_assets_dir(options.assets_dir.clone());
+
+    // Configs : défauts < fichiers < FIREWORKS_<SECTION>__<CHAMP> < ligne de commande
+    let mut config_manager = ConfigManager::default().with_env(std::env::vars());
+    config_manager.set_path(ConfigSection::Physic, &options.physic_config);
+    config_manager.set_path(ConfigSection::Renderer, &options.renderer_config);
+    for config_override in &options.config_overrides {
+        config_manager.set_override(
+            config_override.section,
+            &config_override.field,
+            config_override.value.clone(),
+        );
+    }
+    // Bench : simulation reproductible par défaut, charge ajustable
+    if let AppCommand::Bench(bench) = options.command {
+        options.seed.get_or_insert(BENCH_DEFAULT_SEED);
+        if let Some(max_rockets) = bench.max_rockets {
+            config_manager.set_override(
+                ConfigSection::Physic,
+                "max_rockets",
+                toml::Value::Integer(max_rockets as i64),
+            );
+        }
+    }
+    // Fichier absent : valeurs par défaut ; fichier invalide : arrêt
+    if let Err(e) = config_manager.load() {
+        error!("❌ {:#}", e);
+        std::process::exit(1);
+    }
+    config_manager.set_watching(options.watch_config);
+    let physic_config = config_manager.physic_config();
     info!("Physic config loaded:\n{:#?}", physic_config);
 
-    // --------------------------
-    // Gestion du chemin d'export audio
-    // --------------------------
-    let export_path = std::env::args()
-        .nth(1) // priorité à l'argument CLI
-        .map(PathBuf::from)
-        .or_else(|| env::var("FIREWORKS_AUDIO_EXPORT").ok().map(PathBuf::from));
+    // Session du lancement précédent : surcharge les fichiers de config, pas la
+    // ligne de commande (cf. `fireworks_sim::session`)
+    let session = match options.command {
+        AppCommand::Run if !options.fresh => {
+            Session::load(Path::new(SESSION_PATH)).unwrap_or_else(|e| {
+                warn!("⚠️ Session ignored: {:#}", e);
+                None
+            })
+        }
+        _ => None,
+    };
+    if let Some(session) = &session {
+        session.apply_to_options(&mut options);
+    }
+
+    // Capture du profiler pendant toute l'exécution (timeline Chrome trace)
+    if let Some(path) = &options.trace {
+        Profiler::start_capture(path)?;
+    }
 
-    if let Some(path) = &export_path {
+    if let Some(path) = &options.audio_export {
         info!("Audio export path set to: {}", path.display());
     }
 
     // --------------------------
     // Initialisation des moteurs
     // --------------------------
-    // Paramètres audio par défaut
-    let audio_settings = AudioEngineSettings::default();
-    // let doppler_queue = DopplerQueue::new();
-    let audio_config = FireworksAudioConfig {
-        // TODO: meilleur gestion des chemins (assets), avec une lib (python) style pathlib
-        rocket_path: "assets/sounds/rocket.wav".into(),
-        explosion_path: "assets/sounds/explosion.wav".into(),
-        // TODO: afficher visuellement la position de l'auditeur
-        listener_pos: (0.0, 0.0),
-        // TODO: faudrait étudier l'influence de ce paramètre et les types de valeurs qu'on peut utiliser (et dans quel intérêt)
-        sample_rate: 48000,
-        // TODO: étudier l'influence sonore (qualité du rendu) et de performance de ce paramètre block_size
-        block_size: 512,
-        // limité à 32 voix, si MAX_ROCKETS "grand", évite le bordel sonore (effet mitraille très désagréable)
-        max_voices: cmp::min(32, physic_config.max_rockets),
-        settings: audio_settings.clone(),
-        // doppler_receiver: Some(doppler_queue.receiver.clone()),
-        // doppler_states: Vec::new(),
-        // export_in_wav: true,
-    };
-    let audio_engine = FireworksAudio3D::new(audio_config);
+    // audio.toml : voix plafonnées par le nombre de fusées (évite l'effet mitraille)
+    let audio_config = config_manager
+        .audio_config()
+        .engine_config(physic_config.max_rockets);
+    check_assets(&options, &audio_config, &config_manager);
+    let mut audio_engine = FireworksAudio3D::new(audio_config);
+    // Panique du thread de rendu : audio arrêté proprement, GL libéré au déroulement
+    install_panic_hook(audio_engine.shutdown_handle());
 
-    let window_width = 1024;
+    // Implémentation interchangeable à chaud (`physic.engine`)
+    let physic_engine = AnyPhysicEngine::new(
+        Box::new(PhysicEngineFireworks::from_options(
+            &physic_config,
+            &options,
+        )),
+        options.size.0 as f32,
+    );
+    if let Some(seed) = options.seed {
+        info!("🎲 Physic seed: {}", seed);
+    }
+    let export_path = options.audio_export.as_ref().map(|p| p.to_str().unwrap());
+    let (width, height) = options.size;
 
-    let physic_engine = PhysicEngineFireworks::new(&physic_config, window_width as f32);
+    match options.command {
+        AppCommand::Headless { duration } => {
+            // Pas de renderer : l'auditeur reste où le placerait la fenêtre
+            audio_engine.set_listener_position((width as f32 / 2.0, 0.0));
+            let mut simulator =
+                Simulator::new(NullRendererEngine::new(), physic_engine, audio_engine);
+            setup_simulator(&mut simulator, &options);
+            let result = simulator.run_headless(duration, HEADLESS_DEFAULT_TIME_STEP, export_path);
+            simulator.close();
+            result?;
+        }
+        AppCommand::Bench(bench) => {
+            let report = match bench.renderer {
+                BenchRenderer::Offscreen => {
+                    let renderer_engine = HeadlessRenderer::new(
+                        width,
+                        height,
+                        &physic_config,
+                        bench.frames as usize,
+                    )?;
+                    run_bench(
+                        renderer_engine,
+                        physic_engine,
+                        audio_engine,
+                        &options,
+                        &bench,
+                    )?
+                }
+                BenchRenderer::Window => {
+                    let renderer_engine =
+                        Renderer::from_options(&options, &physic_config, config_manager)?;
+                    run_bench(
+                        renderer_engine,
+                        physic_engine,
+                        audio_engine,
+                        &options,
+                        &bench,
+                    )?
+                }
+                BenchRenderer::None => {
+                    audio_engine.set_listener_position((width as f32 / 2.0, 0.0));
+                    run_bench(
+                        NullRendererEngine::new(),
+                        physic_engine,
+                        audio_engine,
+                        &options,
+                        &bench,
+                    )?
+                }
+            };
+            // Seul le rapport va sur stdout (les logs sont sur stderr)
+            println!("{}", report.to_json());
+            if let Err(e) = Profiler::stop_capture() {
+                warn!("⚠️ Profiler trace not saved: {:#}", e);
+            }
+            if !report.passed {
+                warn!(
+                    "⚠️ Average FPS {:.1} below --fail-below-fps {}",
+                    report.avg_fps,
+                    bench.fail_below_fps.unwrap_or_default()
+                );
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        AppCommand::Run => {
+            // Fenêtre winit + glutin avec la feature `backend_winit`, glfw sinon
+            #[cfg(feature = "backend_winit")]
+            let renderer_engine =
+                WinitRenderer::from_options(&options, &physic_config, config_manager)?;
+            #[cfg(not(feature = "backend_winit"))]
+            let renderer_engine = Renderer::from_options(&options, &physic_config, config_manager)?;
 
-    let renderer_engine = Renderer::new(window_width, 800, "Fireworks Simulator", &physic_config)?;
+            // ----------------------------
+            // Initialisation du simulateur
+            // ----------------------------
+            info!("🚀 Starting Fireworks Simulator...");
+            let mut simulator = Simulator::new(renderer_engine, physic_engine, audio_engine);
+            setup_simulator(&mut simulator, &options);
+            if let Some(session) = &session {
+                simulator.restore_session(session);
+            }
+            simulator.set_session_file(Some(SESSION_PATH.into()));
+            let _ = simulator.run(export_path);
+            simulator.close();
+        }
+    }
 
-    // ----------------------------
-    // Initialisation du simulateur
-    // ----------------------------
-    info!("🚀 Starting Fireworks Simulator...");
+    if let Err(e) = Profiler::stop_capture() {
+        warn!("⚠️ Profiler trace not saved: {:#}", e);
+    }
+
+    Ok(())
+}
+
+/// Vérifie les assets du mode choisi avant d'initialiser les moteurs : un seul
+/// rapport, et arrêt si un asset indispensable manque.
+fn check_assets(
+    options: &AppOptions,
+    audio_config: &FireworksAudioConfig,
+    config_manager: &ConfigManager,
+) {
+    let with_renderer = match options.command {
+        AppCommand::Run => true,
+        AppCommand::Headless { .. } => false,
+        AppCommand::Bench(bench) => bench.renderer != BenchRenderer::None,
+    };
+    let renderer_config = with_renderer.then(|| config_manager.renderer_config());
+    let report =
+        AssetManifest::from_configs(options, audio_config, renderer_config.as_ref()).check();
+    if report.is_complete() {
+        return;
+    }
+    if report.is_fatal() {
+        error!("❌ {}", report);
+        std::process::exit(1);
+    }
+    warn!("⚠️ {}", report);
+}
+
+/// `bench` : `frames` frames à graine fixe, audio rendu hors-ligne, puis rapport.
+fn run_bench<R, P, A>(
+    renderer_engine: R,
+    physic_engine: P,
+    audio_engine: A,
+    options: &AppOptions,
+    bench: &BenchOptions,
+) -> Result<BenchReport>
+where
+    R: RendererEngine,
+    P: PhysicEngineFull,
+    A: AudioEngine,
+{
     let mut simulator = Simulator::new(renderer_engine, physic_engine, audio_engine);
-    simulator.init_console_commands();
-    let _ = simulator.run(export_path.as_ref().map(|p| p.to_str().unwrap()));
+    setup_simulator(&mut simulator, options);
+    simulator.set_frame_limit(Some(bench.frames));
+    simulator.set_offline_audio(true);
+    let export_path = options.audio_export.as_ref().map(|p| p.to_string_lossy());
+    let result = simulator.run(export_path.as_deref());
+    let report = simulator.bench_report(bench.fail_below_fps);
     simulator.close();
+    result?;
+    report
+}
 
-    Ok(())
+/// Commandes console, fichiers utilisateur, export des métriques et script
+/// `--exec` : communs à tous les modes.
+fn setup_simulator<R, P, A>(simulator: &mut Simulator<R, P, A>, options: &AppOptions)
+where
+    R: RendererEngine,
+    P: PhysicEngineFull,
+    A: AudioEngine,
+{
+    simulator.init_console_commands();
+    simulator
+        .commands_registry
+        .set_alias_file(ALIASES_CONFIG_PATH);
+    simulator.commands_registry.set_bind_file(BINDS_CONFIG_PATH);
+    simulator
+        .commands_registry
+        .set_stats_file(COMMAND_STATS_PATH);
+    if let Some(path) = &options.metrics_out {
+        simulator.set_metrics_output(path);
+    }
+    if let Some(path) = &options.metrics_append {
+        if let Err(e) = simulator.set_metrics_append(path) {
+            warn!("⚠️ Periodic metrics export disabled: {:#}", e);
+        }
+    }
+    if options.demo {
+        simulator.set_demo(true);
+    }
+    simulator.set_duration_limit(options.duration);
+    if let Some(path) = &options.music {
+        if let Err(e) = simulator.set_music(path) {
+            warn!("⚠️ Music disabled: {:#}", e);
+        }
+    }
+    if let Some(path) = &options.exec {
+        let args = ExecArgs {
+            path: path.clone(),
+            abort_on_error: false,
+        };
+        match simulator.exec_script(&args) {
+            Ok(report) => {
+                for line in &report.output {
+                    info!("  {}", line);
+                }
+                info!("📜 {}", report.summary());
+            }
+            Err(e) => warn!("⚠️ {:#}", e),
+        }
+    }
 }
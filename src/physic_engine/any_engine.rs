@@ -0,0 +1,257 @@
+//! Moteur physique interchangeable à chaud (`physic.engine <nom>`), pour
+//! comparer les implémentations (rendu, performances) sans relancer.
+//!
+//! [`AnyPhysicEngine`] s'utilise comme paramètre `P` du `Simulator` et délègue
+//! tout au moteur courant. Un changement construit le nouveau moteur depuis la
+//! config courante et lui transmet la largeur du monde, la forme d'explosion, les
+//! attracteurs, la chorégraphie et l'état des lancements ; les fusées en vol sont
+//! abandonnées. Le renderer recrée ses buffers GPU à la frame suivante (cf.
+//! `PhysicEngine::take_engine_switched`).
+
+use std::path::PathBuf;
+
+use glam::Vec2;
+use log::{info, warn};
+
+use crate::physic_engine::attractor::{Attractor, AttractorId};
+use crate::physic_engine::choreography::Choreography;
+use crate::physic_engine::config::PhysicConfig;
+use crate::physic_engine::explosion_shape::{restore_explosion_shape, ImageShapeSettings};
+use crate::physic_engine::finale::FinaleSettings;
+use crate::physic_engine::particle::Particle;
+use crate::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use crate::physic_engine::types::{PhysicStats, ReloadResult, UpdateResult};
+use crate::physic_engine::{
+    ParticleType, PhysicEngine, PhysicEngineFull, PhysicEngineIterator, TrailPolyline,
+};
+
+/// Implémentations disponibles pour `physic.engine`
+pub const PHYSIC_ENGINES: &[&str] = &["arena"];
+
+/// Moteur `name` construit depuis `config`.
+pub fn create_physic_engine(
+    name: &str,
+    config: &PhysicConfig,
+    window_width: f32,
+) -> anyhow::Result<Box<dyn PhysicEngineFull>> {
+    match name {
+        "arena" => Ok(Box::new(PhysicEngineFireworks::new(config, window_width))),
+        other => anyhow::bail!(
+            "Unknown physics engine '{}' (expected one of: {})",
+            other,
+            PHYSIC_ENGINES.join(", ")
+        ),
+    }
+}
+
+/// Libellé du profiler pour la mise à jour physique du moteur `engine`.
+pub fn physic_update_label(engine: &str) -> String {
+    format!("physic - update [{}]", engine)
+}
+
+pub struct AnyPhysicEngine {
+    name: String,
+    engine: Box<dyn PhysicEngineFull>,
+    /// Largeur du monde transmise au prochain moteur
+    window_width: f32,
+    /// Moteur changé depuis le dernier `take_engine_switched`
+    switched: bool,
+}
+
+impl AnyPhysicEngine {
+    pub fn new(engine: Box<dyn PhysicEngineFull>, window_width: f32) -> Self {
+        Self {
+            name: engine.engine_name().to_string(),
+            engine,
+            window_width,
+            switched: false,
+        }
+    }
+
+    /// Moteur `name` construit depuis `config`.
+    pub fn from_config(
+        name: &str,
+        config: &PhysicConfig,
+        window_width: f32,
+    ) -> anyhow::Result<Self> {
+        let engine = create_physic_engine(name, config, window_width)?;
+        Ok(Self::new(engine, window_width))
+    }
+
+    pub fn window_width(&self) -> f32 {
+        self.window_width
+    }
+}
+
+impl PhysicEngineIterator for AnyPhysicEngine {
+    fn iter_active_particles<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Particle> + 'a> {
+        self.engine.iter_active_particles()
+    }
+
+    fn iter_active_heads_not_exploded<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Particle> + 'a> {
+        self.engine.iter_active_heads_not_exploded()
+    }
+
+    fn iter_particles_by_type<'a>(
+        &'a self,
+        particle_type: ParticleType,
+    ) -> Box<dyn Iterator<Item = &'a Particle> + 'a> {
+        self.engine.iter_particles_by_type(particle_type)
+    }
+
+    fn count_particles_by_type(&self, particle_type: ParticleType) -> usize {
+        self.engine.count_particles_by_type(particle_type)
+    }
+
+    fn count_all_active(&self) -> usize {
+        self.engine.count_all_active()
+    }
+
+    fn iter_trail_polylines<'a>(&'a self) -> Box<dyn Iterator<Item = TrailPolyline<'a>> + 'a> {
+        self.engine.iter_trail_polylines()
+    }
+}
+
+impl PhysicEngine for AnyPhysicEngine {
+    fn set_window_width(&mut self, width: f32) {
+        self.window_width = width;
+        self.engine.set_window_width(width);
+    }
+
+    fn update(&mut self, dt: f32) -> UpdateResult<'_> {
+        self.engine.update(dt)
+    }
+
+    fn close(&mut self) {
+        self.engine.close();
+    }
+
+    fn reload_config(&mut self, config: &PhysicConfig) -> ReloadResult {
+        self.engine.reload_config(config)
+    }
+
+    fn get_config(&self) -> &PhysicConfig {
+        self.engine.get_config()
+    }
+
+    fn get_stats(&self) -> PhysicStats {
+        self.engine.get_stats()
+    }
+
+    fn compact_pools(&mut self) -> usize {
+        self.engine.compact_pools()
+    }
+
+    fn load_explosion_parametric(&mut self, kind: &str, params: &[f32]) -> anyhow::Result<()> {
+        self.engine.load_explosion_parametric(kind, params)
+    }
+
+    fn load_explosion_images(
+        &mut self,
+        paths: &[PathBuf],
+        settings: ImageShapeSettings,
+    ) -> anyhow::Result<usize> {
+        self.engine.load_explosion_images(paths, settings)
+    }
+
+    fn rescan_shapes(&mut self) -> anyhow::Result<usize> {
+        self.engine.rescan_shapes()
+    }
+
+    fn add_attractor(&mut self, pos: Vec2, strength: f32, radius: f32) -> AttractorId {
+        self.engine.add_attractor(pos, strength, radius)
+    }
+
+    fn remove_attractor(&mut self, id: AttractorId) -> bool {
+        self.engine.remove_attractor(id)
+    }
+
+    fn clear_attractors(&mut self) {
+        self.engine.clear_attractors();
+    }
+
+    fn attractors(&self) -> &[Attractor] {
+        self.engine.attractors()
+    }
+
+    fn clear_particles(&mut self) {
+        self.engine.clear_particles();
+    }
+
+    fn clear_explosion_shape(&mut self) {
+        self.engine.clear_explosion_shape();
+    }
+
+    fn launch_rocket(&mut self) -> bool {
+        self.engine.launch_rocket()
+    }
+
+    fn set_choreography(&mut self, choreography: Option<Choreography>) {
+        self.engine.set_choreography(choreography);
+    }
+
+    fn set_launches_enabled(&mut self, enabled: bool) {
+        self.engine.set_launches_enabled(enabled);
+    }
+
+    fn launches_enabled(&self) -> bool {
+        self.engine.launches_enabled()
+    }
+
+    fn choreography(&self) -> Option<&Choreography> {
+        self.engine.choreography()
+    }
+
+    fn start_finale(&mut self, settings: FinaleSettings) -> anyhow::Result<()> {
+        self.engine.start_finale(settings)
+    }
+
+    fn stop_finale(&mut self) -> bool {
+        self.engine.stop_finale()
+    }
+
+    fn explosion_shape_name(&self) -> &str {
+        self.engine.explosion_shape_name()
+    }
+
+    fn explosion_shape_params(&self) -> Vec<f32> {
+        self.engine.explosion_shape_params()
+    }
+
+    fn engine_name(&self) -> &str {
+        &self.name
+    }
+
+    fn switch_engine(&mut self, name: &str) -> anyhow::Result<()> {
+        let config = self.engine.get_config().clone();
+        let mut engine = create_physic_engine(name, &config, self.window_width)?;
+
+        let shape = self.engine.explosion_shape_name().to_string();
+        let params = self.engine.explosion_shape_params();
+        if let Err(e) = restore_explosion_shape(engine.as_mut(), &shape, &params) {
+            warn!("⚠️ Explosion shape '{}' not transferred: {:#}", shape, e);
+        }
+        for attractor in self.engine.attractors() {
+            engine.add_attractor(attractor.pos, attractor.strength, attractor.radius);
+        }
+        engine.set_choreography(self.engine.choreography().cloned());
+        engine.set_launches_enabled(self.engine.launches_enabled());
+
+        let dropped = self.engine.get_stats().active_rockets;
+        self.engine.close();
+        self.engine = engine;
+        info!(
+            "🔀 Physics engine: {} -> {} ({} rocket(s) in flight dropped)",
+            self.name, name, dropped
+        );
+        self.name = name.to_string();
+        self.switched = true;
+        Ok(())
+    }
+
+    fn take_engine_switched(&mut self) -> bool {
+        std::mem::take(&mut self.switched)
+    }
+}
+
+impl PhysicEngineFull for AnyPhysicEngine {}
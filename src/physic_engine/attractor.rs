@@ -0,0 +1,57 @@
+use glam::Vec2;
+
+/// Distance (px) en deçà de laquelle l'attraction n'augmente plus :
+/// évite l'explosion numérique (et les NaN) au passage près du centre.
+pub const ATTRACTOR_MIN_DISTANCE: f32 = 8.0;
+
+/// Rayon d'action par défaut d'un attracteur (px)
+pub const ATTRACTOR_DEFAULT_RADIUS: f32 = 400.0;
+
+/// Identifiant stable d'un attracteur (cf. `PhysicEngine::remove_attractor`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AttractorId(pub u64);
+
+/// Puits gravitationnel ("trou noir") qui courbe la trajectoire des particules.
+///
+/// Accélération en `strength / d²` vers `pos`, avec `d` borné inférieurement par
+/// `ATTRACTOR_MIN_DISTANCE` ; aucune influence au-delà de `radius` (coût borné :
+/// les particules hors rayon ne font qu'un test de distance).
+/// Une `strength` négative repousse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attractor {
+    pub pos: Vec2,
+    pub strength: f32,
+    pub radius: f32,
+}
+
+impl Attractor {
+    pub fn new(pos: Vec2, strength: f32, radius: f32) -> Self {
+        Self {
+            pos,
+            strength,
+            radius: radius.max(0.0),
+        }
+    }
+
+    /// Accélération (px/s²) subie par une particule en `at`.
+    #[inline(always)]
+    pub fn acceleration(&self, at: Vec2) -> Vec2 {
+        let delta = self.pos - at;
+        let dist_sq = delta.length_squared();
+        if dist_sq > self.radius * self.radius {
+            return Vec2::ZERO;
+        }
+        let clamped_sq = dist_sq.max(ATTRACTOR_MIN_DISTANCE * ATTRACTOR_MIN_DISTANCE);
+        // normalize_or_zero : particule pile au centre → aucune force (pas de NaN)
+        delta.normalize_or_zero() * (self.strength / clamped_sq)
+    }
+
+    /// Accélération cumulée d'un ensemble d'attracteurs.
+    #[inline(always)]
+    pub fn total_acceleration<'a>(
+        attractors: impl IntoIterator<Item = &'a Attractor>,
+        at: Vec2,
+    ) -> Vec2 {
+        attractors.into_iter().map(|a| a.acceleration(at)).sum()
+    }
+}
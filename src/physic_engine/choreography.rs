@@ -0,0 +1,82 @@
+//! Chorégraphie : lancements de fusées à des instants imposés (`--music`), à la
+//! place du tirage aléatoire des intervalles.
+//!
+//! Chaque lancement part `flight_time` secondes avant l'instant visé (un onset
+//! de la musique), pour que l'explosion tombe dessus. Les fusées d'une
+//! chorégraphie reçoivent une vitesse verticale qui donne exactement ce temps
+//! de vol (cf. `PhysicConfig::launch_speed_for_flight_time`).
+
+use log::info;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Choreography {
+    /// Instants de lancement (s, croissants)
+    launches: Vec<f32>,
+    flight_time: f32,
+    /// Instants visés trop tôt pour le temps de vol, abandonnés
+    skipped: usize,
+    /// Temps écoulé depuis le début de la chorégraphie (s)
+    time: f32,
+    /// Prochain lancement de `launches`
+    next: usize,
+}
+
+impl Choreography {
+    /// Lancements qui font exploser une fusée à chaque instant de `targets` (s) ;
+    /// ceux qui tomberaient avant 0 sont abandonnés.
+    pub fn from_onsets(targets: &[f32], flight_time: f32) -> Self {
+        let flight_time = flight_time.max(0.0);
+        let mut launches: Vec<f32> = targets
+            .iter()
+            .map(|t| t - flight_time)
+            .filter(|t| *t >= 0.0)
+            .collect();
+        launches.sort_by(f32::total_cmp);
+        let skipped = targets.len() - launches.len();
+        info!(
+            "🎼 Choreography: {} launches, flight time {:.2} s ({} onsets too early)",
+            launches.len(),
+            flight_time,
+            skipped
+        );
+        Self {
+            launches,
+            flight_time,
+            skipped,
+            time: 0.0,
+            next: 0,
+        }
+    }
+
+    pub fn launch_times(&self) -> &[f32] {
+        &self.launches
+    }
+
+    pub fn flight_time(&self) -> f32 {
+        self.flight_time
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Tous les lancements sont partis
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.launches.len()
+    }
+
+    /// Avance de `dt` secondes ; retourne le nombre de lancements échus.
+    pub fn advance(&mut self, dt: f32) -> usize {
+        self.time += dt;
+        let due = self.launches[self.next..]
+            .iter()
+            .take_while(|&&t| t <= self.time)
+            .count();
+        self.next += due;
+        due
+    }
+}
@@ -1,9 +1,23 @@
-use serde::Deserialize;
+use std::path::Path;
 
-#[derive(Debug, Clone, Deserialize)]
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::config_manager::{ConfigSection, Reloadable, Violations};
+use crate::physic_engine::rocket::EXPLOSION_PARTICLE_LIFE;
+use crate::utils::assets::asset_path;
+use crate::utils::embedded_assets::read_asset_to_string;
+
+/// Chemin par défaut de la config physique
+pub const PHYSIC_CONFIG_PATH: &str = "assets/config/physic.toml";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PhysicConfig {
     pub max_rockets: usize,
     pub particles_per_explosion: usize,
+    /// Plafond (mémoire) du nombre de particules de trail par fusée.
+    /// La taille réelle d'un bloc est dérivée de la config (cf. `trail_block_size`).
     pub particles_per_trail: usize,
 
     pub rocket_interval_mean: f32,
@@ -17,6 +31,50 @@ pub struct PhysicConfig {
     pub spawn_rocket_max_speed: f32,
 
     pub explosion_threshold: f32,
+    /// Gravité verticale (px/s², négative = vers le bas)
+    pub gravity: f32,
+
+    /// Trails : distance entre deux particules, durée de vie et taille
+    pub trail_spacing: f32,
+    pub trail_particle_life: f32,
+    pub trail_particle_size: f32,
+    /// Multiplicateur de longueur de trail, tiré par fusée dans `[min, max]`
+    /// (multiplie la durée de vie des particules de trail)
+    pub trail_length_multiplier_min: f32,
+    pub trail_length_multiplier_max: f32,
+    /// Fraction de la vitesse de la fusée héritée par une particule de trail
+    pub trail_velocity_inherit: f32,
+    /// Amplitude (px/s) de la vitesse latérale aléatoire d'une particule de trail
+    pub trail_lateral_jitter: f32,
+    /// Teinte (fumée/braise, RGBA) vers laquelle une particule de trail dérive en vieillissant
+    pub trail_end_color: [f32; 4],
+
+    /// Mode pseudo-3D : chaque fusée reçoit une profondeur tirée dans
+    /// `[spawn_rocket_min_depth, spawn_rocket_max_depth]`.
+    pub depth_enabled: bool,
+    pub spawn_rocket_min_depth: f32,
+    pub spawn_rocket_max_depth: f32,
+
+    /// Budgets globaux de particules actives par type (`None` = illimité).
+    /// Une explosion qui dépasserait le budget est éclaircie (une particule sur N)
+    /// plutôt que refusée ; les trails arrêtent simplement d'émettre.
+    pub max_active_explosion_particles: Option<usize>,
+    pub max_active_trail_particles: Option<usize>,
+    /// Nombre minimal d'étoiles d'une explosion, même budget épuisé (prime sur le budget)
+    pub min_explosion_particles: usize,
+
+    /// Répertoire scanné par la `ShapeLibrary` (images de formes d'explosion)
+    pub shapes_dir: String,
+
+    /// Palette des fusées (RGB) : chaque fusée tire une de ces couleurs.
+    /// Vide : couleur aléatoire (chaque canal dans `[0.5, 1]`).
+    pub rocket_palette: Vec<[f32; 3]>,
+
+    /// Compaction des pools de particules : démarre quand la fragmentation (%)
+    /// dépasse `pool_compaction_threshold`, puis déplace au plus
+    /// `pool_compaction_blocks_per_frame` blocs par pool et par frame (0 : désactivée).
+    pub pool_compaction_threshold: f32,
+    pub pool_compaction_blocks_per_frame: usize,
 }
 
 impl Default for PhysicConfig {
@@ -24,7 +82,7 @@ impl Default for PhysicConfig {
         Self {
             max_rockets: 4096 * 4,
             particles_per_explosion: 256,
-            particles_per_trail: 64,
+            particles_per_trail: 128,
             rocket_interval_mean: 1.0 * 0.025,
             rocket_interval_variation: 0.75 * 0.025,
             rocket_max_next_interval: 0.025,
@@ -34,13 +92,255 @@ impl Default for PhysicConfig {
             spawn_rocket_min_speed: 350.0,
             spawn_rocket_max_speed: 500.0,
             explosion_threshold: 50.0, // en m/s
+            gravity: -200.0,
+            trail_spacing: 2.0,
+            trail_particle_life: 0.35,
+            trail_particle_size: 2.0,
+            trail_length_multiplier_min: 1.0,
+            trail_length_multiplier_max: 1.0,
+            trail_velocity_inherit: 0.15,
+            trail_lateral_jitter: 12.0,
+            trail_end_color: [0.45, 0.25, 0.15, 1.0],
+            depth_enabled: false,
+            spawn_rocket_min_depth: 0.0,
+            spawn_rocket_max_depth: 1200.0, // en pixels "monde"
+            max_active_explosion_particles: None,
+            max_active_trail_particles: None,
+            min_explosion_particles: 16,
+            shapes_dir: "assets/shapes".to_string(),
+            rocket_palette: Vec::new(),
+            pool_compaction_threshold: 25.0,
+            pool_compaction_blocks_per_frame: 64,
         }
     }
 }
 
+/// Les pools de particules sont dimensionnés à la création du moteur.
+impl Reloadable for PhysicConfig {
+    const SECTION: ConfigSection = ConfigSection::Physic;
+    const RESTART_FIELDS: &'static [&'static str] =
+        &["particles_per_explosion", "particles_per_trail"];
+
+    fn validate(&self) -> Vec<String> {
+        self.validate()
+    }
+
+    fn override_template() -> anyhow::Result<toml::Table> {
+        let probe = Self {
+            max_active_explosion_particles: Some(0),
+            max_active_trail_particles: Some(0),
+            ..Self::default()
+        };
+        Ok(toml::Table::try_from(probe)?)
+    }
+}
+
+/// Espacement minimal entre deux particules de trail (évite une division par zéro)
+const MIN_TRAIL_SPACING: f32 = 0.01;
+
 impl PhysicConfig {
     pub fn from_file(path: &str) -> anyhow::Result<Self> {
-        let text = std::fs::read_to_string(path)?;
+        let text = read_asset_to_string(path)?;
         Ok(toml::from_str(&text)?)
     }
+
+    /// Écrit la config complète en TOML ; les clés inconnues d'un fichier déjà
+    /// présent (réglages d'une autre version) sont conservées.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = &asset_path(path);
+        let mut table = toml::Table::try_from(self)?;
+        if let Ok(text) = std::fs::read_to_string(path) {
+            let existing: toml::Table = toml::from_str(&text).unwrap_or_default();
+            let known = Self::field_names()?;
+            for (key, value) in existing {
+                if !known.contains(&key) {
+                    table.insert(key, value);
+                }
+            }
+        }
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(&table)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Clés TOML de la config (options comprises, absentes du TOML quand `None`)
+    fn field_names() -> anyhow::Result<Vec<String>> {
+        Ok(Self::override_template()?
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect())
+    }
+
+    /// Règles non respectées (`champ: explication`), vide si la config est valide.
+    pub fn validate(&self) -> Vec<String> {
+        let mut v = Violations::default();
+        v.positive("max_rockets", self.max_rockets);
+        v.positive("particles_per_explosion", self.particles_per_explosion);
+        v.positive("particles_per_trail", self.particles_per_trail);
+        v.check(
+            self.rocket_interval_mean > 0.0,
+            "rocket_interval_mean",
+            "must be greater than 0",
+        );
+        v.check(
+            self.rocket_interval_variation >= 0.0,
+            "rocket_interval_variation",
+            "must not be negative",
+        );
+        v.check(
+            self.spawn_rocket_min_speed > 0.0,
+            "spawn_rocket_min_speed",
+            "must be greater than 0",
+        );
+        v.ordered(
+            ("spawn_rocket_min_speed", self.spawn_rocket_min_speed),
+            ("spawn_rocket_max_speed", self.spawn_rocket_max_speed),
+        );
+        v.check(
+            self.trail_spacing > 0.0,
+            "trail_spacing",
+            "must be greater than 0",
+        );
+        v.check(
+            self.trail_particle_life >= 0.0,
+            "trail_particle_life",
+            "must not be negative",
+        );
+        v.check(
+            self.trail_length_multiplier_min >= 0.0,
+            "trail_length_multiplier_min",
+            "must not be negative",
+        );
+        v.ordered(
+            (
+                "trail_length_multiplier_min",
+                self.trail_length_multiplier_min,
+            ),
+            (
+                "trail_length_multiplier_max",
+                self.trail_length_multiplier_max,
+            ),
+        );
+        v.in_range(
+            "trail_velocity_inherit",
+            self.trail_velocity_inherit,
+            (0.0, 1.0),
+        );
+        for channel in self.trail_end_color {
+            v.in_range("trail_end_color", channel, (0.0, 1.0));
+        }
+        v.ordered(
+            ("spawn_rocket_min_depth", self.spawn_rocket_min_depth),
+            ("spawn_rocket_max_depth", self.spawn_rocket_max_depth),
+        );
+        v.ordered(
+            ("min_explosion_particles", self.min_explosion_particles),
+            ("particles_per_explosion", self.particles_per_explosion),
+        );
+        v.in_range(
+            "pool_compaction_threshold",
+            self.pool_compaction_threshold,
+            (0.0, 100.0),
+        );
+        for color in &self.rocket_palette {
+            for &channel in color {
+                v.in_range("rocket_palette", channel, (0.0, 1.0));
+            }
+        }
+        v.into_vec()
+    }
+
+    /// Espacement effectif entre deux particules de trail.
+    pub fn effective_trail_spacing(&self) -> f32 {
+        self.trail_spacing.max(MIN_TRAIL_SPACING)
+    }
+
+    /// Nombre maximal de particules de trail simultanément vivantes pour une fusée,
+    /// dans le pire cas (vitesse de lancement maximale, trail le plus long).
+    ///
+    /// La vitesse d'une fusée ne fait que décroître (gravité) : la distance parcourue
+    /// pendant la vie d'une particule de trail est donc bornée par `max_speed × life`.
+    pub fn implied_trail_particles(&self) -> usize {
+        let max_multiplier = self
+            .trail_length_multiplier_max
+            .max(self.trail_length_multiplier_min)
+            .max(0.0);
+        let max_life = self.trail_particle_life.max(0.0) * max_multiplier;
+        let max_distance = self.spawn_rocket_max_speed.max(0.0) * max_life;
+        (max_distance / self.effective_trail_spacing()).ceil() as usize + 1
+    }
+
+    /// Taille d'un bloc de trail dans le pool : le pire cas impliqué par la config,
+    /// plafonné par `particles_per_trail`.
+    pub fn trail_block_size(&self) -> usize {
+        self.implied_trail_particles()
+            .min(self.particles_per_trail)
+            .max(1)
+    }
+
+    /// Nombre de particules à allumer pour une nouvelle explosion de `block` particules,
+    /// sachant que `active` particules d'explosion sont déjà actives.
+    ///
+    /// Sous budget, une explosion ne prend jamais plus de la moitié du budget restant :
+    /// les explosions suivantes gardent ainsi de la marge (décroissance géométrique),
+    /// avec un plancher de `min_explosion_particles`.
+    pub fn explosion_fill(&self, block: usize, active: usize) -> usize {
+        let Some(budget) = self.max_active_explosion_particles else {
+            return block;
+        };
+        let remaining = budget.saturating_sub(active);
+        block
+            .min(remaining / 2)
+            .max(self.min_explosion_particles.min(block))
+    }
+
+    /// Retourne un avertissement si la config implique plus de particules de trail
+    /// simultanées que ce qu'un bloc peut contenir (les trails seront tronqués).
+    pub fn check_trail_budget(&self) -> Option<String> {
+        let implied = self.implied_trail_particles();
+        (implied > self.particles_per_trail).then(|| {
+            format!(
+                "trail config implies up to {} simultaneous particles per rocket \
+                 but particles_per_trail = {}: trails will be truncated",
+                implied, self.particles_per_trail
+            )
+        })
+    }
+
+    /// Temps de vol (s) d'une fusée lancée à la vitesse moyenne, dans l'axe de
+    /// lancement : elle explose quand sa vitesse verticale passe sous
+    /// `explosion_threshold`.
+    pub fn rocket_flight_time(&self) -> f32 {
+        let speed = (self.spawn_rocket_min_speed + self.spawn_rocket_max_speed) * 0.5;
+        let vertical_speed = speed * self.spawn_rocket_vertical_angle.sin();
+        if self.gravity >= 0.0 {
+            return 0.0;
+        }
+        ((vertical_speed - self.explosion_threshold) / -self.gravity).max(0.0)
+    }
+
+    /// Temps (s) pour que le ciel se vide après le dernier lancement
+    /// (`--duration`) : vol de la fusée la plus rapide, puis extinction de ses
+    /// étincelles et de son trail.
+    pub fn sky_clear_time(&self) -> f32 {
+        let flight_time = if self.gravity < 0.0 {
+            ((self.spawn_rocket_max_speed - self.explosion_threshold) / -self.gravity).max(0.0)
+        } else {
+            0.0
+        };
+        let trail_life = self.trail_particle_life.max(0.0)
+            * self
+                .trail_length_multiplier_max
+                .max(self.trail_length_multiplier_min)
+                .max(0.0);
+        flight_time + EXPLOSION_PARTICLE_LIFE.end.max(trail_life)
+    }
+
+    /// Vitesse verticale de lancement qui donne un temps de vol de `flight_time` s
+    /// (inverse de `rocket_flight_time`).
+    pub fn launch_speed_for_flight_time(&self, flight_time: f32) -> f32 {
+        self.explosion_threshold - self.gravity * flight_time.max(0.0)
+    }
 }
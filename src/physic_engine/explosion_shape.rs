@@ -0,0 +1,419 @@
+use glam::Vec2;
+use rand::Rng;
+use serde::Deserialize;
+use std::f32::consts::TAU;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::physic_engine::PhysicEngine;
+use crate::utils::assets::asset_path;
+
+/// Forme d'une explosion : distribution des vitesses initiales des particules.
+///
+/// # Contrat de normalisation
+/// Les points échantillonnés (`sampled_points`) sont exprimés dans un repère
+/// centré sur la fusée, avec une norme **≤ 1.0** (disque unité).
+/// Chaque particule d'explosion reçoit un point et part avec la vitesse
+/// `point × EXPLOSION_SHAPE_SPEED` : la forme se dessine dans le ciel en s'étendant.
+#[derive(Debug, Clone, Default)]
+pub enum ExplosionShape {
+    /// Gerbe sphérique aléatoire (comportement historique)
+    #[default]
+    Sphere,
+    /// Forme procédurale échantillonnée analytiquement
+    Parametric(ParametricShape),
+    /// Jeu d'images : chaque fusée tire une image au hasard (pondérée par `weight`)
+    MultiImage(Arc<[ImageShape]>),
+}
+
+/// Vitesse (px/s) d'un point de norme 1.0 d'une forme d'explosion.
+pub const EXPLOSION_SHAPE_SPEED: f32 = 200.0;
+
+/// Nombre maximal de branches d'une étoile (au-delà, les branches ne sont plus
+/// discernables et l'échantillonnage alloue au moins un point par arête).
+pub const MAX_STAR_POINTS: u32 = 64;
+
+impl ExplosionShape {
+    /// Points normalisés et vitesse (px/s) pour la prochaine explosion
+    /// (`None` pour la gerbe sphérique aléatoire).
+    pub fn pick<R: Rng>(&self, rng: &mut R) -> Option<(Arc<[Vec2]>, f32)> {
+        match self {
+            ExplosionShape::Sphere => None,
+            ExplosionShape::Parametric(shape) => {
+                Some((shape.sampled_points.clone(), EXPLOSION_SHAPE_SPEED))
+            }
+            ExplosionShape::MultiImage(shapes) => {
+                let total: f32 = shapes.iter().map(|s| s.settings.weight).sum();
+                let mut target = rng.random_range(0.0..total.max(f32::MIN_POSITIVE));
+                let shape = shapes
+                    .iter()
+                    .find(|s| {
+                        target -= s.settings.weight;
+                        target < 0.0
+                    })
+                    .or(shapes.last())?;
+                Some((shape.sampled_points.clone(), shape.speed()))
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExplosionShape::Sphere => "sphere",
+            ExplosionShape::Parametric(shape) => shape.kind.name(),
+            ExplosionShape::MultiImage(_) => "images",
+        }
+    }
+}
+
+/// Générateurs procéduraux disponibles (paramètres exprimés dans le disque unité).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParametricKind {
+    /// Anneau de rayon `radius` et d'épaisseur `thickness`
+    Ring { radius: f32, thickness: f32 },
+    /// Cœur (courbe classique `16 sin³t, 13 cos t − 5 cos 2t − …`) mis à l'échelle
+    Heart { scale: f32 },
+    /// Étoile à `points` branches, rayon intérieur = `inner_ratio` × rayon extérieur
+    Star { points: u32, inner_ratio: f32 },
+    /// Spirale d'Archimède de `turns` tours
+    Spiral { turns: f32 },
+}
+
+impl ParametricKind {
+    pub const NAMES: &'static [&'static str] = &["ring", "heart", "star", "spiral"];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParametricKind::Ring { .. } => "ring",
+            ParametricKind::Heart { .. } => "heart",
+            ParametricKind::Star { .. } => "star",
+            ParametricKind::Spiral { .. } => "spiral",
+        }
+    }
+
+    /// Paramètres dans l'ordre de `from_params`.
+    pub fn params(&self) -> Vec<f32> {
+        match *self {
+            ParametricKind::Ring { radius, thickness } => vec![radius, thickness],
+            ParametricKind::Heart { scale } => vec![scale],
+            ParametricKind::Star {
+                points,
+                inner_ratio,
+            } => vec![points as f32, inner_ratio],
+            ParametricKind::Spiral { turns } => vec![turns],
+        }
+    }
+
+    /// Construit un générateur depuis son nom et une liste de paramètres
+    /// (les paramètres absents prennent une valeur par défaut).
+    ///
+    /// Ex: `("ring", [0.4, 0.05])`, `("star", [5.0, 0.4])`
+    pub fn from_params(kind: &str, params: &[f32]) -> anyhow::Result<Self> {
+        let param = |i: usize, default: f32| params.get(i).copied().unwrap_or(default);
+        let kind = match kind {
+            "ring" => ParametricKind::Ring {
+                radius: param(0, 0.8),
+                thickness: param(1, 0.05),
+            },
+            "heart" => ParametricKind::Heart {
+                scale: param(0, 1.0),
+            },
+            "star" => ParametricKind::Star {
+                points: param(0, 5.0) as u32,
+                inner_ratio: param(1, 0.4),
+            },
+            "spiral" => ParametricKind::Spiral {
+                turns: param(0, 3.0),
+            },
+            other => anyhow::bail!(
+                "Unknown parametric shape '{}' (expected one of: {})",
+                other,
+                Self::NAMES.join(", ")
+            ),
+        };
+        kind.validate()?;
+        Ok(kind)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match *self {
+            ParametricKind::Ring { radius, thickness } => {
+                anyhow::ensure!(
+                    radius > 0.0 && thickness >= 0.0 && radius + thickness * 0.5 <= 1.0,
+                    "ring: expected 0 < radius, 0 <= thickness, radius + thickness/2 <= 1"
+                );
+            }
+            ParametricKind::Heart { scale } => {
+                anyhow::ensure!(
+                    scale > 0.0 && scale <= 1.0,
+                    "heart: expected 0 < scale <= 1"
+                );
+            }
+            ParametricKind::Star {
+                points,
+                inner_ratio,
+            } => {
+                anyhow::ensure!(
+                    (2..=MAX_STAR_POINTS).contains(&points),
+                    "star: expected 2 <= points <= {}",
+                    MAX_STAR_POINTS
+                );
+                anyhow::ensure!(
+                    inner_ratio > 0.0 && inner_ratio < 1.0,
+                    "star: expected 0 < inner_ratio < 1"
+                );
+            }
+            ParametricKind::Spiral { turns } => {
+                anyhow::ensure!(turns > 0.0, "spiral: expected turns > 0");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Forme procédurale : générateur + points échantillonnés (partagés entre les fusées).
+#[derive(Debug, Clone)]
+pub struct ParametricShape {
+    pub kind: ParametricKind,
+    pub sampled_points: Arc<[Vec2]>,
+}
+
+impl ParametricShape {
+    /// Échantillonne `samples` points (déterministe, sans RNG).
+    pub fn new(kind: ParametricKind, samples: usize) -> Self {
+        let n = samples.max(1);
+        let points: Vec<Vec2> = match kind {
+            ParametricKind::Ring { radius, thickness } => (0..n)
+                .map(|i| {
+                    let angle = TAU * i as f32 / n as f32;
+                    // Répartition dans l'épaisseur par suite de Weyl (nombre d'or)
+                    let u = (i as f32 * 0.618_034).fract() - 0.5;
+                    Vec2::from_angle(angle) * (radius + thickness * u)
+                })
+                .collect(),
+            ParametricKind::Heart { scale } => (0..n)
+                .map(|i| {
+                    let t = TAU * i as f32 / n as f32;
+                    Self::heart_point(t) * scale
+                })
+                .collect(),
+            ParametricKind::Star {
+                points,
+                inner_ratio,
+            } => {
+                let edges = 2 * points as usize;
+                let per_edge = (n / edges).max(1);
+                let vertex = |e: usize| {
+                    let radius = if e.is_multiple_of(2) {
+                        1.0
+                    } else {
+                        inner_ratio
+                    };
+                    // Première branche orientée vers le haut
+                    let angle = std::f32::consts::FRAC_PI_2 + TAU * e as f32 / edges as f32;
+                    Vec2::from_angle(angle) * radius
+                };
+                (0..edges)
+                    .flat_map(|e| {
+                        let (a, b) = (vertex(e), vertex(e + 1));
+                        (0..per_edge).map(move |j| a.lerp(b, j as f32 / per_edge as f32))
+                    })
+                    .collect()
+            }
+            ParametricKind::Spiral { turns } => (0..n)
+                .map(|i| {
+                    let r = (i + 1) as f32 / n as f32;
+                    Vec2::from_angle(TAU * turns * r) * r
+                })
+                .collect(),
+        };
+
+        Self {
+            kind,
+            sampled_points: points.into(),
+        }
+    }
+
+    /// Courbe du cœur normalisée dans le disque unité.
+    fn heart_point(t: f32) -> Vec2 {
+        // La courbe brute s'étend verticalement de -17 à ≈ +11.9 : on la recentre,
+        // puis on divise par sa norme maximale (≈ 18.2) pour tenir dans le disque unité.
+        const HEART_CENTER_OFFSET: f32 = 2.54;
+        const HEART_MAX_NORM: f32 = 18.25;
+        let x = 16.0 * t.sin().powi(3);
+        let y = 13.0 * t.cos() - 5.0 * (2.0 * t).cos() - 2.0 * (3.0 * t).cos() - (4.0 * t).cos();
+        Vec2::new(x, y + HEART_CENTER_OFFSET) / HEART_MAX_NORM
+    }
+}
+
+/// Formes parcourues par `cycle_explosion_shape`, dans l'ordre.
+pub fn shape_cycle() -> Vec<&'static str> {
+    let mut names = vec!["sphere"];
+    names.extend_from_slice(ParametricKind::NAMES);
+    names.push("images");
+    names
+}
+
+/// Réinstalle une forme relevée par `explosion_shape_name` / `explosion_shape_params`
+/// (session, changement de moteur) ; `images` rescanne la bibliothèque.
+pub fn restore_explosion_shape<P: PhysicEngine + ?Sized>(
+    engine: &mut P,
+    name: &str,
+    params: &[f32],
+) -> anyhow::Result<()> {
+    match name {
+        "sphere" => {
+            engine.clear_explosion_shape();
+            Ok(())
+        }
+        "images" => engine.rescan_shapes().map(|_| ()),
+        kind => engine.load_explosion_parametric(kind, params),
+    }
+}
+
+/// Installe la forme suivante (ou précédente) de `shape_cycle`, en sautant celles
+/// qui ne peuvent pas être chargées (bibliothèque d'images vide…).
+/// Retourne le nom de la forme installée.
+pub fn cycle_explosion_shape<P: PhysicEngine + ?Sized>(
+    engine: &mut P,
+    forward: bool,
+) -> anyhow::Result<&'static str> {
+    let names = shape_cycle();
+    let n = names.len();
+    let current = names
+        .iter()
+        .position(|&name| name == engine.explosion_shape_name())
+        .unwrap_or(0);
+    for step in 1..n {
+        let i = if forward {
+            (current + step) % n
+        } else {
+            (current + n - step) % n
+        };
+        let installed = match names[i] {
+            "sphere" => {
+                engine.clear_explosion_shape();
+                true
+            }
+            "images" => engine.rescan_shapes().is_ok_and(|count| count > 0),
+            kind => engine.load_explosion_parametric(kind, &[]).is_ok(),
+        };
+        if installed {
+            return Ok(names[i]);
+        }
+    }
+    anyhow::bail!("No other explosion shape available")
+}
+
+/// Réglages d'une forme image, surchargeables par un fichier TOML "sidecar"
+/// (`coeur.png` → `coeur.toml`).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ImageShapeSettings {
+    /// Taille de la forme dans le disque unité (0 < scale ≤ 1)
+    pub scale: f32,
+    /// Temps (s) mis par l'explosion pour atteindre sa taille nominale
+    pub flight_time: f32,
+    /// Poids relatif lors du tirage dans un jeu d'images
+    pub weight: f32,
+}
+
+impl Default for ImageShapeSettings {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            flight_time: 1.0,
+            weight: 1.0,
+        }
+    }
+}
+
+impl ImageShapeSettings {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let settings: Self = toml::from_str(&text)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.scale > 0.0 && self.scale <= 1.0,
+            "expected 0 < scale <= 1"
+        );
+        anyhow::ensure!(self.flight_time > 0.0, "expected flight_time > 0");
+        anyhow::ensure!(self.weight > 0.0, "expected weight > 0");
+        Ok(())
+    }
+}
+
+/// Forme issue d'une image : les pixels lumineux et opaques deviennent des points.
+#[derive(Debug, Clone)]
+pub struct ImageShape {
+    /// Nom de la forme (nom du fichier sans extension)
+    pub name: String,
+    pub settings: ImageShapeSettings,
+    pub sampled_points: Arc<[Vec2]>,
+}
+
+/// Seuil (0..1) d'intensité `alpha × luminance max` pour qu'un pixel fasse partie de la forme
+const IMAGE_SHAPE_THRESHOLD: f32 = 0.5;
+
+impl ImageShape {
+    /// Charge une image et l'échantillonne en `samples` points (déterministe).
+    ///
+    /// Les points sont recentrés sur la boîte englobante des pixels retenus puis
+    /// normalisés dans le disque unité (× `settings.scale`), axe Y vers le haut.
+    pub fn from_image<P: AsRef<Path>>(
+        path: P,
+        samples: usize,
+        settings: ImageShapeSettings,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        settings.validate()?;
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow::anyhow!("invalid image file name: {}", path.display()))?
+            .to_string();
+
+        let image = image::open(asset_path(path))?.to_rgba8();
+        let lit: Vec<Vec2> = image
+            .enumerate_pixels()
+            .filter(|(_, _, px)| {
+                let [r, g, b, a] = px.0;
+                let intensity = (a as f32 / 255.0) * (r.max(g).max(b) as f32 / 255.0);
+                intensity >= IMAGE_SHAPE_THRESHOLD
+            })
+            .map(|(x, y, _)| Vec2::new(x as f32 + 0.5, -(y as f32 + 0.5)))
+            .collect();
+        anyhow::ensure!(!lit.is_empty(), "no lit pixel in {}", path.display());
+
+        let (min, max) = lit
+            .iter()
+            .fold((lit[0], lit[0]), |(min, max), &p| (min.min(p), max.max(p)));
+        let center = (min + max) * 0.5;
+        let max_norm = lit
+            .iter()
+            .map(|&p| (p - center).length())
+            .fold(0.0_f32, f32::max)
+            .max(f32::EPSILON);
+
+        let n = samples.max(1);
+        let sampled_points: Vec<Vec2> = (0..n)
+            .map(|i| (lit[i * lit.len() / n] - center) / max_norm * settings.scale)
+            .collect();
+
+        Ok(Self {
+            name,
+            settings,
+            sampled_points: sampled_points.into(),
+        })
+    }
+
+    /// Vitesse (px/s) d'un point de norme 1.0 : la forme atteint sa taille
+    /// nominale (`EXPLOSION_SHAPE_SPEED` px) en `flight_time` secondes.
+    pub fn speed(&self) -> f32 {
+        EXPLOSION_SHAPE_SPEED / self.settings.flight_time
+    }
+}
@@ -0,0 +1,184 @@
+//! Final de spectacle (`physic.finale <secondes>`) : pendant une durée donnée,
+//! les lancements s'accélèrent jusqu'à la cadence maximale permise par les pools,
+//! avec une montée et une descente progressives plutôt qu'une bascule franche.
+//!
+//! Le final ne modifie jamais la config du moteur : il se superpose au tirage
+//! des intervalles (`FinaleState::interval`) et fournit aux fusées une copie de
+//! la config avec ses propres réglages (palette, explosions complètes). À la fin,
+//! la config d'origine reprend la main telle quelle.
+
+use anyhow::{bail, Result};
+
+use crate::physic_engine::config::PhysicConfig;
+
+/// Part de la durée consacrée à la montée (et autant à la descente)
+pub const FINALE_RAMP_FRACTION: f32 = 0.2;
+/// Intervalle entre deux lancements au plus fort du final (s)
+pub const FINALE_PEAK_INTERVAL: f32 = 0.002;
+
+/// Réglages d'un final.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinaleSettings {
+    /// Durée totale (s)
+    pub duration: f32,
+    /// Durée de la montée et de la descente (s, bornée à la moitié de la durée ;
+    /// 0 : bascule franche)
+    pub ramp: f32,
+    /// Intervalle entre deux lancements au plus fort (s)
+    pub peak_interval: f32,
+    /// Lève le budget de particules d'explosion : chaque gerbe allume tout son bloc
+    pub full_explosions: bool,
+    /// Palette des fusées du final (`None` : celle de la config)
+    pub palette: Option<Vec<[f32; 3]>>,
+}
+
+impl FinaleSettings {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ramp: duration * FINALE_RAMP_FRACTION,
+            peak_interval: FINALE_PEAK_INTERVAL,
+            full_explosions: false,
+            palette: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(self.duration.is_finite() && self.duration > 0.0) {
+            bail!("finale duration must be positive, got {}", self.duration);
+        }
+        if !(self.ramp.is_finite() && self.ramp >= 0.0) {
+            bail!("finale ramp must not be negative, got {}", self.ramp);
+        }
+        if !(self.peak_interval.is_finite() && self.peak_interval > 0.0) {
+            bail!(
+                "finale peak interval must be positive, got {}",
+                self.peak_interval
+            );
+        }
+        if let Some(palette) = &self.palette {
+            if palette.iter().flatten().any(|c| !(0.0..=1.0).contains(c)) {
+                bail!("finale palette channels must be in [0, 1]");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Avancement d'un final (cf. `PhysicStats::finale`)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FinaleProgress {
+    /// Temps écoulé (s)
+    pub elapsed: f32,
+    pub duration: f32,
+    /// Intensité courante (0 : cadence de la config, 1 : cadence maximale)
+    pub intensity: f32,
+    /// Fusées lancées depuis le début du final
+    pub launched: usize,
+}
+
+impl FinaleProgress {
+    /// Part écoulée (0..1)
+    pub fn fraction(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+/// Final en cours sur un moteur.
+#[derive(Debug, Clone)]
+pub struct FinaleState {
+    settings: FinaleSettings,
+    elapsed: f32,
+    launched: usize,
+    /// Config vue par les fusées pendant le final
+    config: PhysicConfig,
+}
+
+impl FinaleState {
+    /// Final superposé à `config` (réglages supposés validés).
+    pub fn new(settings: FinaleSettings, config: &PhysicConfig) -> Self {
+        let mut finale = Self {
+            settings,
+            elapsed: 0.0,
+            launched: 0,
+            config: config.clone(),
+        };
+        finale.rebase(config);
+        finale
+    }
+
+    /// Reprend `config` comme base (rechargement à chaud pendant le final).
+    pub fn rebase(&mut self, config: &PhysicConfig) {
+        self.config = config.clone();
+        if self.settings.full_explosions {
+            self.config.max_active_explosion_particles = None;
+        }
+        if let Some(palette) = &self.settings.palette {
+            self.config.rocket_palette = palette.clone();
+        }
+    }
+
+    /// Config à transmettre aux fusées pendant le final
+    pub fn config(&self) -> &PhysicConfig {
+        &self.config
+    }
+
+    pub fn settings(&self) -> &FinaleSettings {
+        &self.settings
+    }
+
+    /// Avance le final de `dt` secondes. Retourne `true` une fois terminé.
+    pub fn advance(&mut self, dt: f32) -> bool {
+        self.elapsed += dt.max(0.0);
+        self.is_finished()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.settings.duration
+    }
+
+    /// Compte un lancement du final
+    pub fn record_launch(&mut self) {
+        self.launched += 1;
+    }
+
+    /// Intensité à l'instant courant : montée en smoothstep, plateau, descente symétrique.
+    pub fn intensity(&self) -> f32 {
+        finale_intensity(self.elapsed, self.settings.duration, self.settings.ramp)
+    }
+
+    /// Intervalle avant le prochain lancement, à partir de l'intervalle `base`
+    /// tiré d'après la config : les cadences (1 / intervalle) sont interpolées.
+    pub fn interval(&self, base: f32) -> f32 {
+        let peak_rate = 1.0 / self.settings.peak_interval;
+        let base_rate = if base > 0.0 { 1.0 / base } else { peak_rate };
+        let rate = base_rate + (peak_rate - base_rate).max(0.0) * self.intensity();
+        1.0 / rate
+    }
+
+    pub fn progress(&self) -> FinaleProgress {
+        FinaleProgress {
+            elapsed: self.elapsed.min(self.settings.duration),
+            duration: self.settings.duration,
+            intensity: self.intensity(),
+            launched: self.launched,
+        }
+    }
+}
+
+/// Intensité d'un final de `duration` secondes à l'instant `t`, rampes de `ramp`
+/// secondes (bornées à la moitié de la durée) ; 0 hors du final.
+pub fn finale_intensity(t: f32, duration: f32, ramp: f32) -> f32 {
+    if !(0.0..duration).contains(&t) {
+        return 0.0;
+    }
+    let ramp = ramp.clamp(0.0, duration / 2.0);
+    if ramp <= 0.0 {
+        return 1.0;
+    }
+    let edge = (t.min(duration - t) / ramp).min(1.0);
+    edge * edge * (3.0 - 2.0 * edge)
+}
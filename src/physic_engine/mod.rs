@@ -2,12 +2,13 @@ pub mod r#trait;
 pub use r#trait::PhysicEngine;
 pub use r#trait::PhysicEngineFull;
 pub use r#trait::PhysicEngineIterator;
+pub use r#trait::TrailPolyline;
 
 pub mod particle_type;
 pub use particle_type::ParticleType;
 
 pub mod types;
-pub use self::types::UpdateResult;
+pub use self::types::{ActiveParticleCounts, PhysicStats, PoolStats, ReloadResult, UpdateResult};
 
 pub mod rocket;
 pub use self::rocket::Rocket;
@@ -21,5 +22,25 @@ pub use self::particle::Particle;
 pub mod config;
 pub use self::config::PhysicConfig;
 
+pub mod explosion_shape;
+pub use self::explosion_shape::{
+    ExplosionShape, ImageShape, ImageShapeSettings, ParametricKind, ParametricShape,
+};
+
+pub mod attractor;
+pub use self::attractor::{Attractor, AttractorId};
+
+pub mod shape_library;
+pub use self::shape_library::ShapeLibrary;
+
+pub mod choreography;
+pub use self::choreography::Choreography;
+
+pub mod finale;
+pub use self::finale::{FinaleProgress, FinaleSettings};
+
 // pub mod physic_engine_static_aos;
 pub mod physic_engine_generational_arena;
+
+pub mod any_engine;
+pub use self::any_engine::{AnyPhysicEngine, PHYSIC_ENGINES};
@@ -10,12 +10,16 @@ pub struct Particle {
     pub life: f32,
     pub max_life: f32,
     pub size: f32,
+    /// Profondeur (pseudo-3D) : 0.0 = plan le plus proche, croît en s'éloignant.
+    pub depth: f32,
 
     // TODO: Make private
     pub vel: Vec2,
     pub active: bool,
     pub angle: f32,
     pub particle_type: ParticleType,
+    /// Variante de sprite (ramenée au nombre de sprites de l'atlas au rendu)
+    pub sprite: u8,
 }
 
 use bytemuck::{Pod, Zeroable};
@@ -14,6 +14,31 @@ pub enum ParticleType {
 }
 
 impl ParticleType {
+    /// Tous les types, dans l'ordre de leur discriminant
+    pub const ALL: [ParticleType; 4] = [
+        ParticleType::Rocket,
+        ParticleType::Explosion,
+        ParticleType::Smoke,
+        ParticleType::Trail,
+    ];
+
+    /// Nom court (clé de config `[particles.<nom>]`, argument console)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParticleType::Rocket => "rocket",
+            ParticleType::Explosion => "explosion",
+            ParticleType::Smoke => "smoke",
+            ParticleType::Trail => "trail",
+        }
+    }
+
+    /// Type correspondant à un nom court (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.name().eq_ignore_ascii_case(name))
+    }
+
     /// Retourne le chemin de la texture par défaut pour ce type de particule
     pub fn default_texture_path(&self) -> &'static str {
         match self {
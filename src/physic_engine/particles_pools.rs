@@ -1,35 +1,120 @@
 #[cfg(debug_assertions)]
 use log::debug;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use crate::physic_engine::particle::Particle;
 use crate::physic_engine::rocket::Rocket;
+use crate::physic_engine::types::{ActiveParticleCounts, PoolStats};
+use crate::utils::memory_stats::register_allocation;
 
 #[derive(Debug)]
 pub struct ParticlesPoolsForRockets {
     pub particles_pool_for_explosions: ParticlesPool,
     pub particles_pool_for_trails: ParticlesPool,
+    /// Particules actives par type (maintenu par les fusées)
+    pub active_counts: ActiveParticleCounts,
 }
 
 impl ParticlesPoolsForRockets {
     pub fn new(max_rockets: usize, per_explosion: usize, per_trail: usize) -> Self {
-        Self {
+        let pools = Self {
             particles_pool_for_explosions: ParticlesPool::new(max_rockets, per_explosion),
             particles_pool_for_trails: ParticlesPool::new(max_rockets, per_trail),
+            active_counts: ActiveParticleCounts::default(),
+        };
+        pools.register_memory();
+        pools
+    }
+
+    /// Agrandit les deux pools pour `max_rockets` fusées (les blocs existants sont préservés).
+    pub fn grow(&mut self, max_rockets: usize) {
+        self.particles_pool_for_explosions.grow(max_rockets);
+        self.particles_pool_for_trails.grow(max_rockets);
+        self.register_memory();
+    }
+
+    /// Déclare la taille des deux pools au suivi mémoire (`sim.memory`).
+    fn register_memory(&self) {
+        register_allocation(
+            "physic: explosion particles pool",
+            self.particles_pool_for_explosions.memory_bytes(),
+        );
+        register_allocation(
+            "physic: trail particles pool",
+            self.particles_pool_for_trails.memory_bytes(),
+        );
+    }
+
+    /// Nombre total d'échecs d'allocation (explosions + trails) depuis la création.
+    pub fn allocation_failures(&self) -> u64 {
+        self.particles_pool_for_explosions.allocation_failures()
+            + self.particles_pool_for_trails.allocation_failures()
+    }
+
+    /// Fragmentation (%) du pool le plus fragmenté (cf. [`ParticlesPool::fragmentation`]).
+    pub fn fragmentation(&self) -> f32 {
+        self.particles_pool_for_explosions
+            .fragmentation()
+            .max(self.particles_pool_for_trails.fragmentation())
+    }
+
+    /// Compacte les deux pools (au plus `max_moves` blocs déplacés par pool) et recale
+    /// les `Range` des fusées dont le bloc a bougé. Retourne le nombre de blocs déplacés.
+    pub fn compact<'a>(
+        &mut self,
+        max_moves: usize,
+        rockets: impl Iterator<Item = &'a mut Rocket>,
+    ) -> usize {
+        let explosion_moves = self.particles_pool_for_explosions.compact(max_moves);
+        let trail_moves = self.particles_pool_for_trails.compact(max_moves);
+        if explosion_moves.is_empty() && trail_moves.is_empty() {
+            return 0;
+        }
+        for rocket in rockets {
+            Self::relocate(&mut rocket.explosion_particle_indices, &explosion_moves);
+            Self::relocate(&mut rocket.trail_particle_indices, &trail_moves);
+        }
+        explosion_moves.len() + trail_moves.len()
+    }
+
+    /// Recale `range` sur le nouveau début de son bloc s'il a été déplacé.
+    fn relocate(range: &mut Option<Range<usize>>, moves: &HashMap<usize, usize>) {
+        if let Some(range) = range {
+            if let Some(&start) = moves.get(&range.start) {
+                *range = start..start + range.len();
+            }
         }
     }
 
     // TODO: Il faut refactorer pour éviter de take (mut) rocket -> 0-copy
     pub fn free_blocks(&mut self, rocket: &mut Rocket) {
         if let Some(range) = rocket.explosion_particle_indices.take() {
+            Self::retire_active(
+                self.particles_pool_for_explosions.get_particles_mut(&range),
+                &mut self.active_counts,
+            );
             self.particles_pool_for_explosions.free_block(range);
         }
         if let Some(range) = rocket.trail_particle_indices.take() {
+            Self::retire_active(
+                self.particles_pool_for_trails.get_particles_mut(&range),
+                &mut self.active_counts,
+            );
             self.particles_pool_for_trails.free_block(range);
         }
     }
+
+    /// Désactive les particules encore actives d'un bloc rendu au pool
+    /// (garde les compteurs cohérents et le bloc propre pour sa réutilisation).
+    fn retire_active(slice: &mut [Particle], counts: &mut ActiveParticleCounts) {
+        for p in slice.iter_mut().filter(|p| p.active) {
+            p.active = false;
+            counts.decrement(p.particle_type);
+        }
+    }
 }
 
 pub enum PoolKind {
@@ -63,6 +148,10 @@ impl ParticlesPoolsForRockets {
 /// Chaque fusée (`Rocket`) ne possède plus ses particules,
 /// mais détient un simple `Range<usize>` pointant vers une sous-section du tableau.
 /// Cette approche évite les copies et réduit la fragmentation mémoire.
+///
+/// Les blocs libres sont servis par le début du stockage ; au fil des libérations,
+/// les blocs occupés s'éparpillent (cf. [`ParticlesPool::fragmentation`]) et
+/// [`ParticlesPool::compact`] les ramène en tête.
 #[derive(Debug)]
 pub struct ParticlesPool {
     /// Stockage global de toutes les particules
@@ -71,8 +160,14 @@ pub struct ParticlesPool {
     /// Taille d’un bloc (nombre de particules par groupe : explosion ou trail)
     per_block: usize,
 
-    /// Liste des blocs disponibles (pile LIFO)
+    /// Nombre total de blocs (capacité du pool)
+    max_blocks: usize,
+
+    /// Liste des blocs disponibles (pile LIFO, premiers blocs du stockage au sommet)
     free_blocks: Arc<Mutex<VecDeque<usize>>>,
+
+    /// Nombre de demandes d'allocation refusées (pool épuisé)
+    allocation_failures: AtomicU64,
 }
 
 impl ParticlesPool {
@@ -87,8 +182,9 @@ impl ParticlesPool {
         // Initialise toutes les particules à leur état par défaut
         let particles = vec![Particle::default(); total_particles];
 
-        // Prépare la pile des blocs libres
+        // Prépare la pile des blocs libres (le bloc 0 au sommet)
         let free_blocks = (0..max_blocks)
+            .rev()
             .map(|i| i * per_block)
             .collect::<VecDeque<_>>();
 
@@ -101,13 +197,16 @@ impl ParticlesPool {
         Self {
             particles,
             per_block,
+            max_blocks,
             free_blocks: Arc::new(Mutex::new(free_blocks)),
+            allocation_failures: AtomicU64::new(0),
         }
     }
 
     /// Alloue un bloc de particules pour une explosion ou un trail.
     ///
-    /// Retourne `Some(range)` si un bloc est disponible, sinon `None`.
+    /// Retourne `Some(range)` si un bloc est disponible, sinon `None`
+    /// (l'échec est comptabilisé, cf. [`ParticlesPool::allocation_failures`]).
     /// Complexité : **O(1)**.
     pub fn allocate_block(&self) -> Option<Range<usize>> {
         let mut free_blocks = self.free_blocks.lock().unwrap();
@@ -116,10 +215,137 @@ impl ParticlesPool {
             let end = start + self.per_block;
             Some(start..end)
         } else {
+            self.allocation_failures.fetch_add(1, Ordering::Relaxed);
             None
         }
     }
 
+    /// Agrandit le pool à `max_blocks` blocs.
+    ///
+    /// Les blocs déjà alloués restent valides (leurs `Range` ne bougent pas) :
+    /// les nouveaux blocs sont simplement ajoutés en fin de stockage.
+    /// Ne fait rien si `max_blocks` est inférieur ou égal à la capacité actuelle.
+    pub fn grow(&mut self, max_blocks: usize) {
+        if max_blocks <= self.max_blocks {
+            return;
+        }
+        self.particles
+            .resize(max_blocks * self.per_block, Particle::default());
+
+        // Nouveaux blocs sous la pile : servis après ceux déjà libres
+        let mut free_blocks = self.free_blocks.lock().unwrap();
+        for i in self.max_blocks..max_blocks {
+            free_blocks.push_front(i * self.per_block);
+        }
+
+        #[cfg(debug_assertions)]
+        debug!(
+            "ParticlesPool grown from {} to {} blocks",
+            self.max_blocks, max_blocks
+        );
+        self.max_blocks = max_blocks;
+    }
+
+    /// Mémoire réservée par le stockage des particules (octets).
+    pub fn memory_bytes(&self) -> u64 {
+        (self.particles.capacity() * std::mem::size_of::<Particle>()) as u64
+    }
+
+    /// Nombre total de blocs gérés par le pool.
+    pub fn blocks_total(&self) -> usize {
+        self.max_blocks
+    }
+
+    /// Nombre de blocs actuellement disponibles.
+    pub fn blocks_free(&self) -> usize {
+        self.free_blocks.lock().unwrap().len()
+    }
+
+    /// Taille d'un bloc (nombre de particules).
+    pub fn per_block(&self) -> usize {
+        self.per_block
+    }
+
+    /// Nombre d'allocations refusées depuis la création du pool.
+    pub fn allocation_failures(&self) -> u64 {
+        self.allocation_failures.load(Ordering::Relaxed)
+    }
+
+    /// Instantané de l'occupation du pool.
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            blocks_total: self.blocks_total(),
+            blocks_free: self.blocks_free(),
+            fragmented_blocks: self.fragmented_blocks(),
+            allocation_failures: self.allocation_failures(),
+        }
+    }
+
+    /// Occupation bloc par bloc (`true` : bloc alloué).
+    fn used_blocks(&self) -> Vec<bool> {
+        let mut used = vec![true; self.max_blocks];
+        for &start in self.free_blocks.lock().unwrap().iter() {
+            if let Some(i) = start.checked_div(self.per_block) {
+                used[i] = false;
+            }
+        }
+        used
+    }
+
+    /// Blocs alloués hors de la zone de tête (les `n` premiers blocs, `n` blocs alloués).
+    pub fn fragmented_blocks(&self) -> usize {
+        let used = self.used_blocks();
+        let allocated = used.iter().filter(|&&u| u).count();
+        used[allocated..].iter().filter(|&&u| u).count()
+    }
+
+    /// Part (%) des blocs alloués hors de la zone de tête : 0 quand les blocs
+    /// alloués sont contigus en début de stockage.
+    pub fn fragmentation(&self) -> f32 {
+        self.stats().fragmentation()
+    }
+
+    /// Déplace au plus `max_moves` blocs alloués, du dernier vers le premier trou,
+    /// et rend les blocs libérés au pool (les premiers trous seront servis d'abord).
+    ///
+    /// Retourne les déplacements (début de l'ancien bloc → début du nouveau) :
+    /// les `Range` des fusées sont à recaler en conséquence.
+    pub fn compact(&mut self, max_moves: usize) -> HashMap<usize, usize> {
+        let mut used = self.used_blocks();
+        let mut moves = HashMap::new();
+        let (mut hole, mut last) = (0, self.max_blocks);
+        while moves.len() < max_moves {
+            while hole < last && used[hole] {
+                hole += 1;
+            }
+            while last > hole && !used[last - 1] {
+                last -= 1;
+            }
+            if last <= hole {
+                break;
+            }
+            let (from, to) = ((last - 1) * self.per_block, hole * self.per_block);
+            self.particles.copy_within(from..from + self.per_block, to);
+            // L'ancien bloc ne porte plus de particules vivantes
+            for p in &mut self.particles[from..from + self.per_block] {
+                p.active = false;
+            }
+            used.swap(hole, last - 1);
+            moves.insert(from, to);
+        }
+
+        if !moves.is_empty() {
+            *self.free_blocks.lock().unwrap() = (0..self.max_blocks)
+                .rev()
+                .filter(|&i| !used[i])
+                .map(|i| i * self.per_block)
+                .collect();
+            #[cfg(debug_assertions)]
+            debug!("ParticlesPool compacted: {} blocks moved", moves.len());
+        }
+        moves
+    }
+
     /// Libère un bloc de particules après extinction.
     ///
     /// Le bloc est remis en pile pour réutilisation ultérieure.
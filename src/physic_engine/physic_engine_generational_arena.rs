@@ -1,16 +1,28 @@
 use generational_arena::{Arena, Index};
+use glam::Vec2;
 use itertools::Itertools;
-use log::{debug, info};
-use rand::Rng;
+use log::{debug, info, warn};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
 
+use crate::app_options::AppOptions;
 use crate::physic_engine::{
+    attractor::{Attractor, AttractorId},
+    choreography::Choreography,
     config::PhysicConfig,
+    explosion_shape::{
+        ExplosionShape, ImageShape, ImageShapeSettings, ParametricKind, ParametricShape,
+    },
+    finale::{FinaleSettings, FinaleState},
     particle::Particle,
     particles_pools::ParticlesPoolsForRockets,
     rocket::{Rocket, ROCKET_ID_COUNTER},
-    types::UpdateResult,
-    ParticleType, PhysicEngine, PhysicEngineFull, PhysicEngineIterator,
+    shape_library::ShapeLibrary,
+    types::{PhysicStats, ReloadResult, UpdateResult},
+    ParticleType, PhysicEngine, PhysicEngineFull, PhysicEngineIterator, TrailPolyline,
 };
 
 #[derive(Debug)]
@@ -19,25 +31,75 @@ pub struct PhysicEngineFireworks {
     active_indices: Vec<Index>, // Itération rapide sur les fusées actives
     free_indices: Vec<Index>,   // Slots disponibles à réutiliser
     triggered_explosions: Vec<Particle>,
+    exploded_rocket_ids: Vec<u64>,
 
     time_since_last_rocket: f32,
     next_rocket_interval: f32,
     window_width: f32,
-    rng: rand::rngs::ThreadRng,
+    rng: SmallRng,
 
     config: PhysicConfig,
     rocket_margin_min_x: f32,
     rocket_margin_max_x: f32,
 
     particles_pools_for_rockets: ParticlesPoolsForRockets,
+
+    /// Forme appliquée aux prochaines explosions
+    explosion_shape: ExplosionShape,
+    /// Formes images chargées depuis `config.shapes_dir`
+    shape_library: ShapeLibrary,
+
+    /// Attracteurs actifs (`attractor_ids[i]` identifie `attractors[i]`)
+    attractors: Vec<Attractor>,
+    attractor_ids: Vec<AttractorId>,
+    next_attractor_id: u64,
+
+    /// Lancements imposés (`--music`) à la place des intervalles aléatoires
+    choreography: Option<Choreography>,
+    /// `false` : plus aucun lancement (fin de spectacle)
+    launches_enabled: bool,
+    /// Final en cours (`physic.finale`)
+    finale: Option<FinaleState>,
+    /// Compaction des pools en cours (amortie sur plusieurs frames)
+    compacting: bool,
+
+    // Suivi des échecs d'allocation (warning rate-limité)
+    allocation_failures_reported: u64,
+    last_allocation_warning: Option<Instant>,
 }
 
+/// Intervalle minimal entre deux warnings d'épuisement des pools
+const ALLOCATION_WARNING_INTERVAL: Duration = Duration::from_secs(2);
+
 impl PhysicEngineFireworks {
     pub fn new(config: &PhysicConfig, window_width: f32) -> Self {
+        Self::with_rng(config, window_width, SmallRng::from_rng(&mut rand::rng()))
+    }
+
+    /// Moteur déterministe : même graine + mêmes pas de temps = même simulation.
+    pub fn with_seed(config: &PhysicConfig, window_width: f32, seed: u64) -> Self {
+        Self::with_rng(config, window_width, SmallRng::seed_from_u64(seed))
+    }
+
+    /// Moteur configuré par la ligne de commande : largeur du ciel (`--size`) et
+    /// graine (`--seed`), aléatoire sans graine.
+    ///
+    /// Seul constructeur à lire le disque : la bibliothèque de formes
+    /// (`shapes_dir`) y est scannée une première fois, `physic.shape.rescan` ensuite.
+    pub fn from_options(config: &PhysicConfig, options: &AppOptions) -> Self {
+        let window_width = options.size.0 as f32;
+        let mut engine = match options.seed {
+            Some(seed) => Self::with_seed(config, window_width, seed),
+            None => Self::new(config, window_width),
+        };
+        engine.install_shape_library();
+        engine
+    }
+
+    fn with_rng(config: &PhysicConfig, window_width: f32, mut rng: SmallRng) -> Self {
         let mut rockets = Arena::with_capacity(config.max_rockets);
         let mut free_indices = Vec::with_capacity(config.max_rockets);
 
-        let mut rng = rand::rng();
         // Pré-remplissage des slots dans l’arena et free_indices
         for _ in 0..config.max_rockets {
             let idx = rockets.insert(Rocket::new(&mut rng));
@@ -49,12 +111,14 @@ impl PhysicEngineFireworks {
 
         // il y a autant d'explositions
         let triggered_explosions = vec![Particle::default(); config.max_rockets];
+        let exploded_rocket_ids = vec![0; config.max_rockets];
 
         let mut engine = Self {
             rockets,
             active_indices: Vec::with_capacity(config.max_rockets),
             free_indices,
             triggered_explosions,
+            exploded_rocket_ids,
             time_since_last_rocket: 0.0,
             next_rocket_interval: 0.0,
             window_width,
@@ -65,40 +129,179 @@ impl PhysicEngineFireworks {
             particles_pools_for_rockets: ParticlesPoolsForRockets::new(
                 config.max_rockets,
                 config.particles_per_explosion,
-                config.particles_per_trail,
+                config.trail_block_size(),
             ),
+            explosion_shape: ExplosionShape::default(),
+            shape_library: ShapeLibrary::new(&config.shapes_dir),
+            attractors: Vec::new(),
+            attractor_ids: Vec::new(),
+            next_attractor_id: 0,
+            choreography: None,
+            launches_enabled: true,
+            finale: None,
+            compacting: false,
+            allocation_failures_reported: 0,
+            last_allocation_warning: None,
         };
 
+        if let Some(warning) = config.check_trail_budget() {
+            warn!("⚠️ {}", warning);
+        }
+
         engine.next_rocket_interval = engine.compute_next_interval();
         engine.update_spawn_rocket_margin();
         engine
     }
 
-    fn reload_config(&mut self, new_config: &PhysicConfig) -> bool {
+    fn reload_config(&mut self, new_config: &PhysicConfig) -> ReloadResult {
         let old_max_rockets = self.config.max_rockets;
+        let new_max_rockets = new_config.max_rockets;
         self.config = new_config.clone();
+        if let Some(finale) = &mut self.finale {
+            finale.rebase(new_config);
+        }
 
-        let max_rockets_updated = new_config.max_rockets != old_max_rockets;
-        if max_rockets_updated {
-            info!(
-                "Reinitializing physics buffers due to max_rockets change: {} -> {}",
-                old_max_rockets, new_config.max_rockets
-            );
-            self.triggered_explosions = vec![Particle::default(); new_config.max_rockets];
-
-            // Réinitialisation des slots free_indices et active_indices
-            self.active_indices.clear();
-            self.free_indices.clear();
-
-            for _ in 0..new_config.max_rockets {
-                let idx = self.rockets.insert(Rocket::new(&mut self.rng));
-                self.free_indices.push(idx);
-            }
+        if let Some(warning) = new_config.check_trail_budget() {
+            warn!("⚠️ {}", warning);
         }
 
+        let result = match new_max_rockets.cmp(&old_max_rockets) {
+            std::cmp::Ordering::Equal => ReloadResult::Unchanged,
+            std::cmp::Ordering::Greater => {
+                info!(
+                    "Growing physics buffers: max_rockets {} -> {}",
+                    old_max_rockets, new_max_rockets
+                );
+                // Nouveaux slots ajoutés à côté des fusées en vol (aucune n'est touchée)
+                for _ in self.rockets.len()..new_max_rockets {
+                    let idx = self.rockets.insert(Rocket::new(&mut self.rng));
+                    self.free_indices.push(idx);
+                }
+                if self.triggered_explosions.len() < new_max_rockets {
+                    self.triggered_explosions
+                        .resize(new_max_rockets, Particle::default());
+                    self.exploded_rocket_ids.resize(new_max_rockets, 0);
+                }
+                self.particles_pools_for_rockets.grow(new_max_rockets);
+                ReloadResult::Grew
+            }
+            std::cmp::Ordering::Less => {
+                // Les slots libres en trop sont rendus tout de suite ; ceux des fusées
+                // en vol le seront à leur désactivation (cf. `deactivate_rocket`).
+                self.trim_free_slots();
+                if self.active_indices.len() > new_max_rockets {
+                    info!(
+                        "Shrinking max_rockets {} -> {}: {} active rockets kept alive, spawning paused",
+                        old_max_rockets,
+                        new_max_rockets,
+                        self.active_indices.len()
+                    );
+                    ReloadResult::ShrinkPending
+                } else {
+                    info!(
+                        "Shrinking max_rockets {} -> {}",
+                        old_max_rockets, new_max_rockets
+                    );
+                    ReloadResult::Shrank
+                }
+            }
+        };
+
         self.next_rocket_interval = self.compute_next_interval();
         self.update_spawn_rocket_margin();
-        max_rockets_updated
+        result
+    }
+
+    /// Scanne la bibliothèque de formes et l'installe ; vide, la gerbe sphérique
+    /// remplace les images d'un scan précédent (retirées du disque depuis).
+    fn install_shape_library(&mut self) -> usize {
+        let count = self.shape_library.scan(self.config.particles_per_explosion);
+        self.explosion_shape = self.shape_library.explosion_shape().unwrap_or_default();
+        count
+    }
+
+    /// Retire de l'arena les slots libres au-delà de `max_rockets`.
+    ///
+    /// `triggered_explosions` et les pools de particules ne sont jamais réduits :
+    /// des fusées en vol peuvent encore y faire référence.
+    fn trim_free_slots(&mut self) {
+        while self.rockets.len() > self.config.max_rockets {
+            let Some(idx) = self.free_indices.pop() else {
+                break;
+            };
+            self.rockets.remove(idx);
+        }
+    }
+
+    /// Signale (au plus une fois par `ALLOCATION_WARNING_INTERVAL`) les nouveaux échecs
+    /// d'allocation dans les pools : sans ça, une fusée sans trail ou une explosion
+    /// réduite passe complètement inaperçue.
+    fn report_allocation_failures(&mut self) {
+        let failures = self.particles_pools_for_rockets.allocation_failures();
+        if failures <= self.allocation_failures_reported {
+            return;
+        }
+        if self
+            .last_allocation_warning
+            .is_some_and(|t| t.elapsed() < ALLOCATION_WARNING_INTERVAL)
+        {
+            return;
+        }
+
+        let stats = self.get_stats();
+        warn!(
+            "⚠️ Particle pools exhausted: {} new allocation failures (total {}) — explosions {}/{} blocks free, trails {}/{} blocks free",
+            failures - self.allocation_failures_reported,
+            failures,
+            stats.explosions_pool.blocks_free,
+            stats.explosions_pool.blocks_total,
+            stats.trails_pool.blocks_free,
+            stats.trails_pool.blocks_total,
+        );
+        self.allocation_failures_reported = failures;
+        self.last_allocation_warning = Some(Instant::now());
+    }
+
+    /// Étape de compaction amortie : démarre au-delà de `pool_compaction_threshold` %
+    /// de fragmentation, puis se poursuit frame après frame jusqu'à ce que les blocs
+    /// alloués soient contigus.
+    fn compact_pools_step(&mut self) {
+        let budget = self.config.pool_compaction_blocks_per_frame;
+        if budget == 0 {
+            self.compacting = false;
+            return;
+        }
+        if !self.compacting {
+            self.compacting = self.particles_pools_for_rockets.fragmentation()
+                > self.config.pool_compaction_threshold;
+            if !self.compacting {
+                return;
+            }
+        }
+        let moved = self
+            .particles_pools_for_rockets
+            .compact(budget, self.rockets.iter_mut().map(|(_, r)| r));
+        if moved < budget {
+            debug!("Particle pools compacted");
+            self.compacting = false;
+        }
+    }
+
+    fn get_stats(&self) -> PhysicStats {
+        let pools = &self.particles_pools_for_rockets;
+        let explosions_pool = pools.particles_pool_for_explosions.stats();
+        let trails_pool = pools.particles_pool_for_trails.stats();
+        PhysicStats {
+            active_rockets: self.active_indices.len(),
+            explosions_pool,
+            trails_pool,
+            pool_fragmentation: explosions_pool
+                .fragmentation()
+                .max(trails_pool.fragmentation()),
+            allocation_failures: pools.allocation_failures(),
+            active_particles: pools.active_counts,
+            finale: self.finale.as_ref().map(FinaleState::progress),
+        }
     }
 
     fn update_spawn_rocket_margin(&mut self) {
@@ -112,30 +315,79 @@ impl PhysicEngineFireworks {
     }
 
     fn compute_next_interval(&mut self) -> f32 {
-        self.rng
+        let interval = self
+            .rng
             .random_range(
                 (self.config.rocket_interval_mean - self.config.rocket_interval_variation)
                     ..=(self.config.rocket_interval_mean + self.config.rocket_interval_variation),
             )
-            .max(self.config.rocket_max_next_interval)
+            .max(self.config.rocket_max_next_interval);
+        match &self.finale {
+            Some(finale) => finale.interval(interval),
+            None => interval,
+        }
+    }
+
+    /// Config vue par les fusées : celle du final en cours, sinon la config du moteur
+    fn rocket_config<'a>(
+        finale: &'a Option<FinaleState>,
+        config: &'a PhysicConfig,
+    ) -> &'a PhysicConfig {
+        finale.as_ref().map_or(config, FinaleState::config)
+    }
+
+    /// Termine le final en cours : la cadence de la config reprend dès le prochain tirage.
+    fn end_finale(&mut self, interrupted: bool) -> bool {
+        let Some(finale) = self.finale.take() else {
+            return false;
+        };
+        let progress = finale.progress();
+        info!(
+            "🎆 Finale {}: {} rockets launched in {:.1} s",
+            if interrupted { "stopped" } else { "over" },
+            progress.launched,
+            progress.elapsed
+        );
+        self.next_rocket_interval = self.compute_next_interval();
+        true
     }
 
     fn spawn_rocket(&mut self) -> Option<&mut Rocket> {
+        // Après une réduction de max_rockets, on attend que des fusées s'éteignent
+        if self.active_indices.len() >= self.config.max_rockets {
+            return None;
+        }
         let idx = self.free_indices.pop()?;
-        let cfg = &self.config;
+        let cfg = Self::rocket_config(&self.finale, &self.config);
 
         if let Some(r) = self.rockets.get_mut(idx) {
             // Réutilisation sans recréer la structure complète
             r.reset(cfg, self.window_width);
+            match self.explosion_shape.pick(&mut self.rng) {
+                Some((points, speed)) => {
+                    r.explosion_points = Some(points);
+                    r.explosion_speed = speed;
+                }
+                None => r.explosion_points = None,
+            }
         }
 
         self.active_indices.push(idx);
+        self.particles_pools_for_rockets
+            .active_counts
+            .increment(ParticleType::Rocket);
         self.rockets.get_mut(idx)
     }
 
     /// Désactive une fusée et libère ses ressources associées (particules, indices, etc.)
     fn deactivate_rocket(&mut self, idx: Index) {
         if let Some(r) = self.rockets.get_mut(idx) {
+            if r.active && !r.exploded {
+                // Fusée retirée en vol (ex. `clear_particles`)
+                self.particles_pools_for_rockets
+                    .active_counts
+                    .decrement(ParticleType::Rocket);
+            }
             r.active = false;
             self.particles_pools_for_rockets.free_blocks(r);
         }
@@ -146,15 +398,60 @@ impl PhysicEngineFireworks {
         }
 
         // Ajoute le slot dans free_indices pour réutilisation
+        // (ou le rend si max_rockets a été réduit entre-temps)
         self.free_indices.push(idx);
+        self.trim_free_slots();
     }
 
     fn update(&mut self, dt: f32) -> UpdateResult<'_> {
         let mut triggered_count = 0;
         let mut new_rocket: Option<Rocket> = None;
 
-        self.time_since_last_rocket += dt;
-        if self.time_since_last_rocket >= self.next_rocket_interval {
+        if let Some(choreography) = &mut self.choreography {
+            let mut due = choreography.advance(dt);
+            if !self.launches_enabled {
+                // Les instants passent, leurs lancements sont abandonnés
+                due = 0;
+            }
+            let vertical_speed = self
+                .config
+                .launch_speed_for_flight_time(choreography.flight_time());
+            for _ in 0..due {
+                if let Some(r) = self.spawn_rocket() {
+                    r.set_vertical_speed(vertical_speed);
+                    new_rocket = Some(r.clone());
+                }
+            }
+        } else if self.launches_enabled {
+            self.time_since_last_rocket += dt;
+        }
+
+        if self.finale.as_mut().is_some_and(|f| f.advance(dt)) {
+            self.end_finale(false);
+        }
+        if self.finale.is_some() {
+            if self.choreography.is_some() && self.launches_enabled {
+                // Le final s'ajoute aux lancements chorégraphiés
+                self.time_since_last_rocket += dt;
+            }
+            // Autant de lancements que le temps écoulé en demande, dans la limite des pools
+            while self.launches_enabled && self.time_since_last_rocket >= self.next_rocket_interval
+            {
+                let Some(r) = self.spawn_rocket() else {
+                    // Pools pleins : pas de rattrapage en rafale une fois des places libérées
+                    self.time_since_last_rocket = self.next_rocket_interval;
+                    break;
+                };
+                new_rocket = Some(r.clone());
+                self.time_since_last_rocket -= self.next_rocket_interval;
+                self.next_rocket_interval = self.compute_next_interval();
+                if let Some(finale) = &mut self.finale {
+                    finale.record_launch();
+                }
+            }
+        } else if self.launches_enabled && self.time_since_last_rocket >= self.next_rocket_interval
+        {
+            // Sous chorégraphie, seuls les lancements manuels passent par ici
             if let Some(r) = self.spawn_rocket() {
                 debug!("🚀 Rocket spawned at ({}, {})", r.pos.x, r.pos.y);
                 new_rocket = Some(r.clone());
@@ -164,6 +461,7 @@ impl PhysicEngineFireworks {
         }
 
         let mut to_deactivate = Vec::new();
+        let config = Self::rocket_config(&self.finale, &self.config);
         // on parcourt la liste des id de rockets actives
         for &idx in &self.active_indices {
             // si la rocket existe
@@ -171,11 +469,20 @@ impl PhysicEngineFireworks {
                 // on sauvegarde l'état de la rocket avant update
                 let exploded_before = rocket.exploded;
 
-                rocket.update(dt, &mut self.particles_pools_for_rockets, &self.config);
+                rocket.apply_attractors(
+                    &self.attractors,
+                    dt,
+                    &mut self.particles_pools_for_rockets,
+                );
+                rocket.update(dt, &mut self.particles_pools_for_rockets, config);
 
                 // si avant l'update la rocket n'était pas explosée et qu'après elle l'est
-                // on incrémente le compteur d'explosion
-                triggered_count += (!exploded_before && rocket.exploded) as usize;
+                // on enregistre l'explosion (position + profondeur) et on incrémente le compteur
+                if !exploded_before && rocket.exploded {
+                    self.triggered_explosions[triggered_count] = *rocket.head_particle();
+                    self.exploded_rocket_ids[triggered_count] = rocket.id;
+                    triggered_count += 1;
+                }
                 // si la rocket n'est plus active, on place son ix dans la liste des rockets à déactiver.
                 // on le fait en déférer car on itère (actuellement) sur la liste (des id) des rockets actives.
                 if !rocket.active {
@@ -188,10 +495,14 @@ impl PhysicEngineFireworks {
             self.deactivate_rocket(idx);
         }
 
+        self.report_allocation_failures();
+        self.compact_pools_step();
+
         UpdateResult {
             new_rocket,
             // on renvoie le slice d'explosions déclenchées
             triggered_explosions: &self.triggered_explosions[..triggered_count],
+            exploded_rocket_ids: &self.exploded_rocket_ids[..triggered_count],
         }
     }
 }
@@ -265,6 +576,30 @@ impl PhysicEngineIterator for PhysicEngineFireworks {
             )
         }
     }
+
+    /// Lecture des compteurs incrémentaux des pools (O(1), aucun parcours).
+    fn count_particles_by_type(&self, particle_type: ParticleType) -> usize {
+        self.particles_pools_for_rockets
+            .active_counts
+            .get(particle_type)
+    }
+
+    fn count_all_active(&self) -> usize {
+        self.particles_pools_for_rockets.active_counts.total()
+    }
+
+    /// Une polyligne par fusée active (cf. `Rocket::iter_trail_polyline`).
+    fn iter_trail_polylines<'a>(&'a self) -> Box<dyn Iterator<Item = TrailPolyline<'a>> + 'a> {
+        Box::new(
+            self.active_indices
+                .iter()
+                .map(move |&idx| -> TrailPolyline<'a> {
+                    Box::new(
+                        self.rockets[idx].iter_trail_polyline(&self.particles_pools_for_rockets),
+                    )
+                }),
+        )
+    }
 }
 
 impl PhysicEngine for PhysicEngineFireworks {
@@ -281,16 +616,175 @@ impl PhysicEngine for PhysicEngineFireworks {
         self.active_indices.clear();
         self.free_indices.clear();
         self.rockets.clear();
+        self.particles_pools_for_rockets.active_counts = Default::default();
+        self.finale = None;
         debug!("PhysicEngineFireworks closed and reset.");
     }
 
-    fn reload_config(&mut self, config: &PhysicConfig) -> bool {
+    fn reload_config(&mut self, config: &PhysicConfig) -> ReloadResult {
         self.reload_config(config)
     }
 
     fn get_config(&self) -> &PhysicConfig {
         &self.config
     }
+
+    fn get_stats(&self) -> PhysicStats {
+        self.get_stats()
+    }
+
+    fn compact_pools(&mut self) -> usize {
+        self.compacting = false;
+        self.particles_pools_for_rockets
+            .compact(usize::MAX, self.rockets.iter_mut().map(|(_, r)| r))
+    }
+
+    fn load_explosion_parametric(&mut self, kind: &str, params: &[f32]) -> anyhow::Result<()> {
+        let kind = ParametricKind::from_params(kind, params)?;
+        let shape = ParametricShape::new(kind, self.config.particles_per_explosion);
+        info!(
+            "✨ Explosion shape set to {:?} ({} points)",
+            kind,
+            shape.sampled_points.len()
+        );
+        self.explosion_shape = ExplosionShape::Parametric(shape);
+        Ok(())
+    }
+
+    fn add_attractor(&mut self, pos: Vec2, strength: f32, radius: f32) -> AttractorId {
+        let id = AttractorId(self.next_attractor_id);
+        self.next_attractor_id += 1;
+        self.attractors.push(Attractor::new(pos, strength, radius));
+        self.attractor_ids.push(id);
+        info!(
+            "🕳️ Attractor {:?} added at ({}, {}), strength={}, radius={}",
+            id, pos.x, pos.y, strength, radius
+        );
+        id
+    }
+
+    fn remove_attractor(&mut self, id: AttractorId) -> bool {
+        let Some(i) = self.attractor_ids.iter().position(|&a| a == id) else {
+            return false;
+        };
+        self.attractors.swap_remove(i);
+        self.attractor_ids.swap_remove(i);
+        true
+    }
+
+    fn clear_particles(&mut self) {
+        for idx in self.active_indices.clone() {
+            self.deactivate_rocket(idx);
+        }
+        self.time_since_last_rocket = 0.0;
+    }
+
+    fn clear_attractors(&mut self) {
+        self.attractors.clear();
+        self.attractor_ids.clear();
+    }
+
+    fn attractors(&self) -> &[Attractor] {
+        &self.attractors
+    }
+
+    fn load_explosion_images(
+        &mut self,
+        paths: &[PathBuf],
+        settings: ImageShapeSettings,
+    ) -> anyhow::Result<usize> {
+        anyhow::ensure!(!paths.is_empty(), "no image to load");
+        let shapes = paths
+            .iter()
+            .map(|path| {
+                ImageShape::from_image(path, self.config.particles_per_explosion, settings)
+                    .map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let names: Vec<&str> = shapes.iter().map(|s| s.name.as_str()).collect();
+        info!("🖼️ Explosion shape set to image(s): {}", names.join(", "));
+        let count = shapes.len();
+        self.explosion_shape = ExplosionShape::MultiImage(shapes.into());
+        Ok(count)
+    }
+
+    fn rescan_shapes(&mut self) -> anyhow::Result<usize> {
+        // Le répertoire peut avoir changé depuis un reload de config
+        self.shape_library = ShapeLibrary::new(&self.config.shapes_dir);
+        Ok(self.install_shape_library())
+    }
+
+    fn clear_explosion_shape(&mut self) {
+        self.explosion_shape = ExplosionShape::Sphere;
+    }
+
+    fn launch_rocket(&mut self) -> bool {
+        if !self.launches_enabled || self.active_indices.len() >= self.config.max_rockets {
+            return false;
+        }
+        // Lancée par le prochain `update`, qui la signale (son, stats) comme les autres
+        self.time_since_last_rocket = self.time_since_last_rocket.max(self.next_rocket_interval);
+        true
+    }
+
+    fn explosion_shape_name(&self) -> &str {
+        self.explosion_shape.name()
+    }
+
+    fn engine_name(&self) -> &str {
+        "arena"
+    }
+
+    fn explosion_shape_params(&self) -> Vec<f32> {
+        match &self.explosion_shape {
+            ExplosionShape::Parametric(shape) => shape.kind.params(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_choreography(&mut self, choreography: Option<Choreography>) {
+        self.choreography = choreography;
+        self.time_since_last_rocket = 0.0;
+    }
+
+    fn set_launches_enabled(&mut self, enabled: bool) {
+        self.launches_enabled = enabled;
+    }
+
+    fn launches_enabled(&self) -> bool {
+        self.launches_enabled
+    }
+
+    fn choreography(&self) -> Option<&Choreography> {
+        self.choreography.as_ref()
+    }
+
+    fn start_finale(&mut self, settings: FinaleSettings) -> anyhow::Result<()> {
+        settings.validate()?;
+        self.end_finale(true);
+        info!(
+            "🎆 Finale: {:.1} s (ramp {:.1} s){}{}",
+            settings.duration,
+            settings.ramp.min(settings.duration / 2.0),
+            if settings.full_explosions {
+                ", full explosions"
+            } else {
+                ""
+            },
+            if settings.palette.is_some() {
+                ", custom palette"
+            } else {
+                ""
+            }
+        );
+        self.finale = Some(FinaleState::new(settings, &self.config));
+        self.next_rocket_interval = self.compute_next_interval();
+        Ok(())
+    }
+
+    fn stop_finale(&mut self) -> bool {
+        self.end_finale(true)
+    }
 }
 
 impl PhysicEngineFull for PhysicEngineFireworks {}
@@ -302,6 +796,10 @@ impl PhysicEngineFull for PhysicEngineFireworks {}
 pub trait PhysicEngineTestHelpers {
     fn force_next_launch(&mut self);
     fn rockets_count(&self) -> usize;
+    /// Lance jusqu'à `n` fusées immédiatement. Retourne le nombre réellement lancé.
+    fn spawn_n_rockets(&mut self, n: usize) -> usize;
+    /// Fait exploser toutes les fusées actives non encore explosées.
+    fn force_explode_all(&mut self);
 }
 
 #[cfg(any(test, feature = "test_helpers"))]
@@ -313,4 +811,19 @@ impl PhysicEngineTestHelpers for PhysicEngineFireworks {
     fn rockets_count(&self) -> usize {
         self.active_indices.len()
     }
+
+    fn spawn_n_rockets(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.spawn_rocket().is_some()).count()
+    }
+
+    fn force_explode_all(&mut self) {
+        let config = Self::rocket_config(&self.finale, &self.config);
+        for &idx in &self.active_indices {
+            if let Some(rocket) = self.rockets.get_mut(idx) {
+                if !rocket.exploded {
+                    rocket.trigger_explosion(&mut self.particles_pools_for_rockets, config);
+                }
+            }
+        }
+    }
 }
@@ -5,15 +5,22 @@ use rand::Rng;
 use rand::SeedableRng;
 use std::ops::Range;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 use crate::physic_engine::{
+    attractor::Attractor,
     config::PhysicConfig,
+    explosion_shape::EXPLOSION_SHAPE_SPEED,
     particle::Particle,
     particles_pools::{ParticlesPool, ParticlesPoolsForRockets, PoolKind},
+    types::ActiveParticleCounts,
     ParticleType,
 };
 use glam::{Vec2, Vec4 as Color};
 
+/// Durée de vie (s) tirée pour chaque étincelle d'explosion
+pub const EXPLOSION_PARTICLE_LIFE: Range<f32> = 0.75..1.5;
+
 /// Compteur global pour générer des ID uniques pour les rockets
 pub static ROCKET_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -33,6 +40,17 @@ pub struct Rocket {
     pub vel: Vec2,
     pub color: Color,
 
+    /// Profondeur (pseudo-3D), constante sur toute la vie de la fusée
+    pub depth: f32,
+
+    /// Multiplicateur de longueur du trail (tiré au lancement)
+    pub trail_length_multiplier: f32,
+
+    /// Points normalisés de la forme d'explosion (`None` = gerbe sphérique aléatoire)
+    pub explosion_points: Option<Arc<[Vec2]>>,
+    /// Vitesse (px/s) d'un point de norme 1.0 de `explosion_points`
+    pub explosion_speed: f32,
+
     /// État de la fusée
     pub exploded: bool,
     pub active: bool,
@@ -65,6 +83,10 @@ impl Rocket {
             pos: Vec2::default(),
             vel: Vec2::default(),
             color: Color::ONE,
+            depth: 0.0,
+            trail_length_multiplier: 1.0,
+            explosion_points: None,
+            explosion_speed: EXPLOSION_SHAPE_SPEED,
             exploded: false,
             active: false,
             explosion_particle_indices: None,
@@ -112,6 +134,27 @@ impl Rocket {
         trails.chain(explosions)
     }
 
+    /// Particules de trail actives dans l'ordre de pose, de la plus ancienne à la plus
+    /// récente (la plus proche de la tête).
+    ///
+    /// Le bloc est un ring buffer : `trail_index` désigne le prochain slot écrit, donc
+    /// le plus ancien ; on lit `[trail_index..]` puis `[..trail_index]`. Les particules
+    /// d'explosion de repli (bloc de trail réutilisé) sont ignorées.
+    pub fn iter_trail_polyline<'a>(
+        &'a self,
+        pools: &'a ParticlesPoolsForRockets,
+    ) -> impl Iterator<Item = &'a Particle> + 'a {
+        self.trail_particle_indices
+            .iter()
+            .flat_map(move |range| {
+                let slice = pools.access(PoolKind::Trails, range);
+                let split = self.trail_index.min(slice.len());
+                let (newer, older) = slice.split_at(split);
+                older.iter().chain(newer)
+            })
+            .filter(|p| p.active && p.particle_type == ParticleType::Trail)
+    }
+
     pub fn head_particle(&self) -> &Particle {
         &self.head
     }
@@ -127,26 +170,60 @@ impl Rocket {
             return;
         }
 
-        const GRAVITY: Vec2 = Vec2::new(0.0, -200.0);
+        let gravity = Vec2::new(0.0, config.gravity);
 
-        self.update_movement(dt, GRAVITY);
+        self.update_movement(dt, gravity);
         self.update_trails(
             dt,
-            GRAVITY,
+            gravity,
             &mut particles_pools.particles_pool_for_trails,
+            &mut particles_pools.active_counts,
             config,
         );
-        self.update_explosions(
-            dt,
-            GRAVITY,
-            &mut particles_pools.particles_pool_for_explosions,
-            config,
-        );
+        self.update_explosions(dt, gravity, particles_pools, config);
         self.remove_inactive_rockets(particles_pools);
 
         self.update_head_particle();
     }
 
+    /// Applique les attracteurs à la fusée et à toutes ses particules actives
+    /// (à appeler avant `update` : l'intégration de position suit).
+    pub fn apply_attractors(
+        &mut self,
+        attractors: &[Attractor],
+        dt: f32,
+        particles_pools: &mut ParticlesPoolsForRockets,
+    ) {
+        if attractors.is_empty() || !self.active {
+            return;
+        }
+
+        if !self.exploded {
+            self.vel += Attractor::total_acceleration(attractors, self.pos) * dt;
+        }
+
+        let pools = [
+            (
+                &mut particles_pools.particles_pool_for_trails,
+                &self.trail_particle_indices,
+            ),
+            (
+                &mut particles_pools.particles_pool_for_explosions,
+                &self.explosion_particle_indices,
+            ),
+        ];
+        for (pool, range) in pools {
+            let Some(range) = range else {
+                continue;
+            };
+            for p in pool.get_particles_mut(range) {
+                if p.active {
+                    p.vel += Attractor::total_acceleration(attractors, p.pos) * dt;
+                }
+            }
+        }
+    }
+
     fn remove_inactive_rockets(&mut self, particles_pools: &ParticlesPoolsForRockets) {
         let exploded_done = self
             .explosion_particle_indices
@@ -192,6 +269,7 @@ impl Rocket {
         dt: f32,
         gravity: Vec2,
         particles_pool: &mut ParticlesPool,
+        counts: &mut ActiveParticleCounts,
         config: &PhysicConfig,
     ) {
         // Alloue un bloc si nécessaire
@@ -207,11 +285,11 @@ impl Rocket {
 
         // 1) SPAWN : génération des particules de trail
         if !self.exploded {
-            self.spawn_trail_particles(slice, config);
+            self.spawn_trail_particles(slice, counts, config);
         }
 
         // 2) UPDATE : intégration physique des particules existantes
-        self.integrate_trail_particles(slice, dt, gravity);
+        self.integrate_trail_particles(slice, counts, dt, gravity, config);
     }
 
     /// Génère les nouvelles particules de trail selon la distance parcourue.
@@ -226,9 +304,26 @@ impl Rocket {
     /// Cette fonction reste **zéro allocation** et n'effectue que l’amorçage
     /// des particules dans la fenêtre du pool.
     #[inline(always)]
-    fn spawn_trail_particles(&mut self, slice: &mut [Particle], config: &PhysicConfig) {
-        const TRAIL_SPACING: f32 = 2.0;
-        let nb_particles_per_trail = config.particles_per_trail;
+    fn spawn_trail_particles(
+        &mut self,
+        slice: &mut [Particle],
+        counts: &mut ActiveParticleCounts,
+        config: &PhysicConfig,
+    ) {
+        // Le ring buffer est dimensionné par le bloc réellement alloué :
+        // aucun risque de débordement si la config change à chaud.
+        let nb_particles_per_trail = slice.len();
+        if nb_particles_per_trail == 0 {
+            return;
+        }
+
+        let spacing = config.effective_trail_spacing();
+        let life = config.trail_particle_life * self.trail_length_multiplier;
+
+        // Sillage : fraction de la vitesse de la fusée + dispersion latérale
+        let inherited_vel = self.vel * config.trail_velocity_inherit;
+        let lateral = self.vel.normalize_or_zero().perp();
+        let jitter = config.trail_lateral_jitter.abs();
 
         let movement = self.pos - self.last_trail_pos;
         let dist = movement.length();
@@ -237,28 +332,49 @@ impl Rocket {
             return;
         }
 
-        let inv_dist = 1.0 / dist;
-        let t_step = TRAIL_SPACING * inv_dist;
-        let count = (dist / TRAIL_SPACING) as u32;
+        let step = movement * (spacing / dist);
+        let count = (dist / spacing) as u32;
 
         for _ in 0..count {
-            let new_pos = self.last_trail_pos * (1.0 - t_step) + self.pos * t_step;
+            let new_pos = self.last_trail_pos + step;
             let i = self.trail_index % nb_particles_per_trail;
+            self.trail_index = (self.trail_index + 1) % nb_particles_per_trail;
+            self.last_trail_pos = new_pos;
+
+            // Budget global atteint : on n'allume pas de nouveau slot
+            // (réécrire un slot déjà actif ne change pas le total)
+            let old = slice[i];
+            if !old.active
+                && config
+                    .max_active_trail_particles
+                    .is_some_and(|budget| counts.trails >= budget)
+            {
+                continue;
+            }
+
+            let vel = if jitter > 0.0 {
+                inherited_vel + lateral * self.rng.random_range(-jitter..=jitter)
+            } else {
+                inherited_vel
+            };
 
             slice[i] = Particle {
                 pos: new_pos,
-                vel: Vec2::ZERO,
+                vel,
                 color: self.color,
-                life: 0.35,
-                max_life: 0.35,
-                size: 2.0,
+                life,
+                max_life: life,
+                size: config.trail_particle_size,
+                depth: self.depth,
                 active: true,
                 angle: 0.0,
                 particle_type: ParticleType::Trail,
+                sprite: 0,
             };
-
-            self.trail_index = (self.trail_index + 1) % nb_particles_per_trail;
-            self.last_trail_pos = new_pos;
+            if old.active {
+                counts.decrement(old.particle_type);
+            }
+            counts.increment(ParticleType::Trail);
         }
     }
 
@@ -268,12 +384,21 @@ impl Rocket {
     ///  - la gravité
     ///  - l’intégration de position
     ///  - la mise à jour de vie
+    ///  - la dérive de couleur vers `trail_end_color`
     ///  - la désactivation automatique
     ///
     /// Aucun spawn, aucune écriture dans les indices de la rocket.
     /// Optimale pour l’inlining.
     #[inline(always)]
-    fn integrate_trail_particles(&self, slice: &mut [Particle], dt: f32, gravity: Vec2) {
+    fn integrate_trail_particles(
+        &self,
+        slice: &mut [Particle],
+        counts: &mut ActiveParticleCounts,
+        dt: f32,
+        gravity: Vec2,
+        config: &PhysicConfig,
+    ) {
+        let end_color = Color::from_array(config.trail_end_color);
         // Update trails
         for p in slice {
             if !p.active {
@@ -281,9 +406,16 @@ impl Rocket {
             }
 
             p.vel.y += gravity.y * dt;
-            p.pos.y += p.vel.y * dt;
+            p.pos += p.vel * dt;
             p.life -= dt;
             p.active = p.life > 0.0;
+            if !p.active {
+                counts.decrement(p.particle_type);
+            }
+
+            // Couleur de la fusée à la naissance → teinte de fin à la mort
+            let age = 1.0 - (p.life / p.max_life.max(f32::EPSILON)).clamp(0.0, 1.0);
+            p.color = self.color.lerp(end_color, age);
         }
     }
 
@@ -292,15 +424,17 @@ impl Rocket {
         &mut self,
         dt: f32,
         gravity: Vec2,
-        particles_pool: &mut ParticlesPool,
+        particles_pools: &mut ParticlesPoolsForRockets,
         config: &PhysicConfig,
     ) {
         if !self.exploded && self.vel.y <= config.explosion_threshold {
-            self.trigger_explosion(particles_pool);
+            self.trigger_explosion(particles_pools, config);
         }
 
         if let Some(range) = &self.explosion_particle_indices {
-            let slice = particles_pool.get_particles_mut(range);
+            let slice = particles_pools
+                .particles_pool_for_explosions
+                .get_particles_mut(range);
             for p in &mut slice[..] {
                 if !p.active {
                     continue;
@@ -309,40 +443,122 @@ impl Rocket {
                 p.pos += p.vel * dt;
                 p.life -= dt;
                 p.active = p.life > 0.0;
+                if !p.active {
+                    particles_pools.active_counts.decrement(p.particle_type);
+                }
             }
         }
     }
 
     #[inline(always)]
-    fn trigger_explosion(&mut self, particles_pool: &mut ParticlesPool) {
+    pub(crate) fn trigger_explosion(
+        &mut self,
+        particles_pools: &mut ParticlesPoolsForRockets,
+        config: &PhysicConfig,
+    ) {
+        if !self.exploded {
+            // La tête de fusée laisse place à la gerbe
+            particles_pools
+                .active_counts
+                .decrement(ParticleType::Rocket);
+        }
         self.exploded = true;
 
         if self.explosion_particle_indices.is_none() {
-            self.explosion_particle_indices = particles_pool.allocate_block();
+            self.explosion_particle_indices = particles_pools
+                .particles_pool_for_explosions
+                .allocate_block();
         }
 
-        if let Some(range) = &self.explosion_particle_indices {
-            let slice = particles_pool.get_particles_mut(range);
-            for p in slice.iter_mut() {
-                let angle = self.rng.random_range(0.0..(2.0 * std::f32::consts::PI));
-                let speed = self.rng.random_range(60.0..200.0);
-                let life = self.rng.random_range(0.75..1.5);
-
-                *p = Particle {
-                    pos: self.pos,
-                    vel: Vec2::from_angle(angle) * speed,
-                    color: self.color,
-                    life,
-                    max_life: life,
-                    size: self.rng.random_range(3.0..6.0),
-                    active: true,
-                    angle,
-                    particle_type: ParticleType::Explosion,
-                };
+        if let Some(range) = self.explosion_particle_indices.clone() {
+            let slice = particles_pools
+                .particles_pool_for_explosions
+                .get_particles_mut(&range);
+            self.fill_explosion_particles(slice, &mut particles_pools.active_counts, config);
+        } else if let Some(range) = self.trail_particle_indices.clone() {
+            // Pool d'explosions épuisé : plutôt qu'une explosion invisible, on recycle
+            // le bloc de trail (plus de spawn de trail après l'explosion) pour une gerbe réduite.
+            // Les particules sont ensuite intégrées par `integrate_trail_particles`.
+            #[cfg(debug_assertions)]
+            debug!(
+                "Rocket {:?}: explosion pool exhausted, falling back to trail block",
+                self.id
+            );
+            let slice = particles_pools
+                .particles_pool_for_trails
+                .get_particles_mut(&range);
+            self.fill_explosion_particles(slice, &mut particles_pools.active_counts, config);
+        }
+    }
+
+    #[inline(always)]
+    fn fill_explosion_particles(
+        &mut self,
+        slice: &mut [Particle],
+        counts: &mut ActiveParticleCounts,
+        config: &PhysicConfig,
+    ) {
+        // Budget : on n'allume que `lit` particules, réparties régulièrement
+        // dans le bloc (une sur N) pour garder toute la forme de l'explosion.
+        let block = slice.len();
+        if block == 0 {
+            return;
+        }
+        let lit = config.explosion_fill(block, counts.explosions);
+        // Une variante de sprite par explosion (cf. atlas du renderer)
+        let sprite = self.rng.random::<u8>();
+
+        for (i, p) in slice.iter_mut().enumerate() {
+            if p.active {
+                counts.decrement(p.particle_type);
             }
+            if (i * lit) / block == ((i + 1) * lit) / block {
+                p.active = false;
+                continue;
+            }
+            counts.increment(ParticleType::Explosion);
+
+            let life = self.rng.random_range(EXPLOSION_PARTICLE_LIFE);
+
+            // Forme imposée : la vitesse initiale suit le point échantillonné,
+            // sinon gerbe sphérique aléatoire.
+            let (vel, angle) = match &self.explosion_points {
+                Some(points) if !points.is_empty() => {
+                    let v = points[i % points.len()] * self.explosion_speed;
+                    (v, v.to_angle())
+                }
+                _ => {
+                    let angle = self.rng.random_range(0.0..(2.0 * std::f32::consts::PI));
+                    let speed = self.rng.random_range(60.0..200.0);
+                    (Vec2::from_angle(angle) * speed, angle)
+                }
+            };
+
+            *p = Particle {
+                pos: self.pos,
+                vel,
+                color: self.color,
+                life,
+                max_life: life,
+                size: self.rng.random_range(3.0..6.0),
+                depth: self.depth,
+                active: true,
+                angle,
+                particle_type: ParticleType::Explosion,
+                sprite,
+            };
         }
     }
 
+    /// Couleur tirée dans `rocket_palette`, ou aléatoire si elle est vide
+    fn pick_color(&mut self, cfg: &PhysicConfig) -> Color {
+        if cfg.rocket_palette.is_empty() {
+            return self.random_color();
+        }
+        let [r, g, b] = cfg.rocket_palette[self.rng.random_range(0..cfg.rocket_palette.len())];
+        Color::new(r, g, b, 1.0)
+    }
+
     fn random_color(&mut self) -> Color {
         Color::new(
             self.rng.random_range(0.5..=1.0),
@@ -363,6 +579,22 @@ impl Rocket {
                 .random_range(cfg.spawn_rocket_min_speed..=cfg.spawn_rocket_max_speed)
     }
 
+    /// Tire une profondeur dans la plage configurée (0.0 si le mode 3D est désactivé).
+    fn random_depth(&mut self, cfg: &PhysicConfig) -> f32 {
+        if !cfg.depth_enabled {
+            return 0.0;
+        }
+        let min = cfg.spawn_rocket_min_depth.max(0.0);
+        let max = cfg.spawn_rocket_max_depth.max(min);
+        self.rng.random_range(min..=max)
+    }
+
+    fn random_trail_length_multiplier(&mut self, cfg: &PhysicConfig) -> f32 {
+        let min = cfg.trail_length_multiplier_min.max(0.0);
+        let max = cfg.trail_length_multiplier_max.max(min);
+        self.rng.random_range(min..=max)
+    }
+
     /// Réinitialise une fusée inactive pour la réutiliser sans réallocation
     pub fn reset(&mut self, cfg: &PhysicConfig, window_width: f32) {
         let cx = self
@@ -374,7 +606,9 @@ impl Rocket {
         self.pos = pos;
         self.last_trail_pos = pos;
         self.vel = self.random_vel(cfg);
-        self.color = self.random_color();
+        self.color = self.pick_color(cfg);
+        self.depth = self.random_depth(cfg);
+        self.trail_length_multiplier = self.random_trail_length_multiplier(cfg);
         self.trail_index = 0;
         self.active = true;
         self.exploded = false;
@@ -384,6 +618,15 @@ impl Rocket {
 }
 
 impl Rocket {
+    /// Ajuste la vitesse de lancement (direction conservée) à la composante
+    /// verticale `vertical_speed` (chorégraphie, cf.
+    /// `PhysicConfig::launch_speed_for_flight_time`).
+    pub fn set_vertical_speed(&mut self, vertical_speed: f32) {
+        if self.vel.y > 0.0 {
+            self.vel *= vertical_speed / self.vel.y;
+        }
+    }
+
     #[inline(always)]
     fn update_head_particle(&mut self) {
         // angle = direction de la fusée
@@ -400,10 +643,12 @@ impl Rocket {
             life: 1.0,
             max_life: 1.0,
             size: 2.0,
+            depth: self.depth,
             active: true,
             // FIXME: angle n'est vraiment utilisé que pour les têtes de fusée (pas pour les trails ou explosions)
             angle,
             particle_type: ParticleType::Rocket,
+            sprite: 0,
         };
     }
 }
@@ -0,0 +1,102 @@
+use log::{debug, info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::physic_engine::explosion_shape::{ExplosionShape, ImageShape, ImageShapeSettings};
+use crate::utils::assets::asset_path;
+
+/// Extensions d'images reconnues lors du scan
+const IMAGE_EXTENSIONS: &[&str] = &["png"];
+
+/// `path` a une extension d'image reconnue (insensible à la casse).
+pub fn has_image_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// Bibliothèque de formes d'explosion chargées depuis un répertoire d'images.
+///
+/// Chaque `*.png` du répertoire devient une `ImageShape` ; un fichier TOML de même
+/// nom (`coeur.png` → `coeur.toml`) peut surcharger `scale`, `flight_time` et `weight`.
+/// Un fichier invalide est signalé dans les logs puis ignoré.
+#[derive(Debug, Clone)]
+pub struct ShapeLibrary {
+    dir: PathBuf,
+    shapes: Vec<ImageShape>,
+}
+
+impl ShapeLibrary {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn shapes(&self) -> &[ImageShape] {
+        &self.shapes
+    }
+
+    /// (Re)scanne le répertoire et échantillonne chaque image en `samples` points.
+    /// Retourne le nombre de formes chargées.
+    pub fn scan(&mut self, samples: usize) -> usize {
+        self.shapes.clear();
+
+        let entries = match std::fs::read_dir(asset_path(&self.dir)) {
+            Ok(entries) => entries,
+            Err(e) => {
+                debug!("Shape library: cannot read {}: {}", self.dir.display(), e);
+                return 0;
+            }
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| has_image_extension(path))
+            .collect();
+        // Ordre stable, indépendant du système de fichiers
+        paths.sort();
+
+        for path in paths {
+            match Self::load_shape(&path, samples) {
+                Ok(shape) => {
+                    debug!(
+                        "Shape library: loaded '{}' ({:?})",
+                        shape.name, shape.settings
+                    );
+                    self.shapes.push(shape);
+                }
+                Err(e) => warn!("⚠️ Shape library: skipping {}: {}", path.display(), e),
+            }
+        }
+
+        info!(
+            "🖼️ Shape library: {} shape(s) loaded from {}",
+            self.shapes.len(),
+            self.dir.display()
+        );
+        self.shapes.len()
+    }
+
+    fn load_shape(path: &Path, samples: usize) -> anyhow::Result<ImageShape> {
+        let sidecar = path.with_extension("toml");
+        let settings = if sidecar.is_file() {
+            ImageShapeSettings::from_file(&sidecar)
+                .map_err(|e| anyhow::anyhow!("{}: {}", sidecar.display(), e))?
+        } else {
+            ImageShapeSettings::default()
+        };
+        ImageShape::from_image(path, samples, settings)
+    }
+
+    /// Jeu d'images à installer comme forme d'explosion (`None` si la bibliothèque est vide).
+    pub fn explosion_shape(&self) -> Option<ExplosionShape> {
+        (!self.shapes.is_empty())
+            .then(|| ExplosionShape::MultiImage(Arc::from(self.shapes.clone())))
+    }
+}
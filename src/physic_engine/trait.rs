@@ -1,7 +1,13 @@
+use crate::physic_engine::attractor::{Attractor, AttractorId};
+use crate::physic_engine::choreography::Choreography;
 use crate::physic_engine::config::PhysicConfig;
+use crate::physic_engine::explosion_shape::ImageShapeSettings;
+use crate::physic_engine::finale::FinaleSettings;
 use crate::physic_engine::particle::Particle;
-use crate::physic_engine::types::UpdateResult;
+use crate::physic_engine::types::{PhysicStats, ReloadResult, UpdateResult};
 use crate::physic_engine::ParticleType;
+use glam::Vec2;
+use std::path::PathBuf;
 
 pub trait PhysicEngineIterator {
     // Les types associés ne sont pas nécessaires ici si 'Particle' est importé.
@@ -17,8 +23,33 @@ pub trait PhysicEngineIterator {
         &'a self,
         particle_type: ParticleType,
     ) -> Box<dyn Iterator<Item = &'a Particle> + 'a>;
+
+    /// Nombre de particules que produirait `iter_particles_by_type(particle_type)`.
+    ///
+    /// Par défaut, parcourt l'itérateur ; les moteurs qui tiennent des compteurs
+    /// incrémentaux (cf. `ActiveParticleCounts`) répondent en O(1).
+    fn count_particles_by_type(&self, particle_type: ParticleType) -> usize {
+        self.iter_particles_by_type(particle_type).count()
+    }
+
+    /// Nombre total de particules actives, tous types confondus (têtes de fusée comprises).
+    fn count_all_active(&self) -> usize {
+        ParticleType::ALL
+            .iter()
+            .map(|&t| self.count_particles_by_type(t))
+            .sum()
+    }
+
+    /// Retourne, pour chaque fusée active, ses particules de trail ordonnées de la
+    /// plus ancienne à la plus récente (rendu en ruban).
+    fn iter_trail_polylines<'a>(&'a self) -> Box<dyn Iterator<Item = TrailPolyline<'a>> + 'a> {
+        Box::new(std::iter::empty())
+    }
 }
 
+/// Particules de trail d'une fusée, de la queue vers la tête.
+pub type TrailPolyline<'a> = Box<dyn Iterator<Item = &'a Particle> + 'a>;
+
 /// 🔧 Trait `PhysicEngine`
 ///
 /// Ce trait définit l’interface commune à tous les moteurs physiques.
@@ -55,9 +86,128 @@ pub trait PhysicEngine {
     /// Ferme / libère le moteur physique.
     fn close(&mut self) {} // Par défaut, fait rien.
 
-    fn reload_config(&mut self, config: &PhysicConfig) -> bool;
+    /// Applique une nouvelle config à chaud (les fusées en vol sont préservées).
+    fn reload_config(&mut self, config: &PhysicConfig) -> ReloadResult;
 
     fn get_config(&self) -> &PhysicConfig;
+
+    /// Statistiques courantes (fusées actives, occupation des pools, échecs d'allocation).
+    fn get_stats(&self) -> PhysicStats {
+        PhysicStats::default()
+    }
+
+    /// Compacte immédiatement les pools de particules (`physic.compact`).
+    /// Retourne le nombre de blocs déplacés.
+    fn compact_pools(&mut self) -> usize {
+        0
+    }
+
+    /// Remplace la forme des prochaines explosions par une forme procédurale
+    /// (`kind` ∈ `ParametricKind::NAMES`, paramètres optionnels).
+    fn load_explosion_parametric(&mut self, kind: &str, _params: &[f32]) -> anyhow::Result<()> {
+        anyhow::bail!("Parametric shape '{}' not supported by this engine", kind)
+    }
+
+    /// Installe un jeu d'images (`settings` communs, donc poids égaux) comme forme
+    /// des prochaines explosions. Aucune forme n'est installée si une image échoue.
+    /// Retourne le nombre de formes chargées.
+    fn load_explosion_images(
+        &mut self,
+        _paths: &[PathBuf],
+        _settings: ImageShapeSettings,
+    ) -> anyhow::Result<usize> {
+        anyhow::bail!("Image shapes not supported by this engine")
+    }
+
+    /// Rescanne le répertoire de formes images et installe le jeu obtenu.
+    /// Retourne le nombre de formes chargées.
+    fn rescan_shapes(&mut self) -> anyhow::Result<usize> {
+        anyhow::bail!("Shape library not supported by this engine")
+    }
+
+    /// Ajoute un attracteur (accélération en `strength / d²` jusqu'à `radius` px).
+    fn add_attractor(&mut self, _pos: Vec2, _strength: f32, _radius: f32) -> AttractorId {
+        AttractorId::default()
+    }
+
+    /// Retire un attracteur. Retourne `false` si l'identifiant est inconnu.
+    fn remove_attractor(&mut self, _id: AttractorId) -> bool {
+        false
+    }
+
+    fn clear_attractors(&mut self) {}
+
+    /// Attracteurs actifs.
+    fn attractors(&self) -> &[Attractor] {
+        &[]
+    }
+
+    /// Retire toutes les fusées et particules en vol (`sim.reset`) ; la config,
+    /// la forme d'explosion et les attracteurs sont conservés.
+    fn clear_particles(&mut self) {}
+
+    /// Revient à la gerbe sphérique aléatoire.
+    fn clear_explosion_shape(&mut self) {}
+
+    /// Lance une fusée à la prochaine mise à jour (lancement manuel).
+    /// Retourne `false` si aucune fusée ne peut partir (plafond `max_rockets` atteint).
+    fn launch_rocket(&mut self) -> bool {
+        false
+    }
+
+    /// Remplace les lancements aléatoires par une chorégraphie (`--music`) ;
+    /// `None` revient au tirage aléatoire des intervalles.
+    fn set_choreography(&mut self, _choreography: Option<Choreography>) {}
+
+    /// Coupe (ou rétablit) tous les lancements, aléatoires, chorégraphiés et
+    /// manuels (fin de spectacle, `--duration`).
+    fn set_launches_enabled(&mut self, _enabled: bool) {}
+
+    fn launches_enabled(&self) -> bool {
+        true
+    }
+
+    /// Chorégraphie en cours.
+    fn choreography(&self) -> Option<&Choreography> {
+        None
+    }
+
+    /// Démarre un final (remplace celui en cours) : cadence de lancement accélérée
+    /// pendant `settings.duration` secondes, puis retour à la config inchangée.
+    fn start_finale(&mut self, _settings: FinaleSettings) -> anyhow::Result<()> {
+        anyhow::bail!("Finale not supported by this engine")
+    }
+
+    /// Interrompt le final en cours. Retourne `false` s'il n'y en avait pas.
+    fn stop_finale(&mut self) -> bool {
+        false
+    }
+
+    /// Nom de la forme d'explosion courante.
+    fn explosion_shape_name(&self) -> &str {
+        "sphere"
+    }
+
+    /// Paramètres de la forme d'explosion procédurale courante (vide sinon).
+    fn explosion_shape_params(&self) -> Vec<f32> {
+        Vec::new()
+    }
+
+    /// Nom de l'implémentation (tag des métriques du profiler, `physic.engine`).
+    fn engine_name(&self) -> &str {
+        "custom"
+    }
+
+    /// Remplace l'implémentation à chaud (cf. `AnyPhysicEngine`).
+    fn switch_engine(&mut self, name: &str) -> anyhow::Result<()> {
+        anyhow::bail!("This physics engine cannot be switched to '{}'", name)
+    }
+
+    /// Implémentation changée depuis le dernier appel : le renderer doit
+    /// recréer ses buffers.
+    fn take_engine_switched(&mut self) -> bool {
+        false
+    }
 }
 
 pub trait PhysicEngineFull: PhysicEngine + PhysicEngineIterator {}
@@ -1,4 +1,6 @@
-use crate::physic_engine::{particle::Particle, rocket::Rocket};
+use crate::physic_engine::{
+    finale::FinaleProgress, particle::Particle, rocket::Rocket, ParticleType,
+};
 
 // ------------------------
 // UpdateResult
@@ -6,4 +8,129 @@ use crate::physic_engine::{particle::Particle, rocket::Rocket};
 pub struct UpdateResult<'a> {
     pub new_rocket: Option<Rocket>,
     pub triggered_explosions: &'a [Particle],
+    /// Id des fusées de `triggered_explosions`, dans le même ordre
+    pub exploded_rocket_ids: &'a [u64],
+}
+
+// ------------------------
+// ReloadResult
+// ------------------------
+
+/// Effet d'un rechargement de config sur la capacité du moteur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReloadResult {
+    /// Capacité inchangée (seuls les paramètres de simulation ont changé)
+    Unchanged,
+    /// Capacité augmentée : les fusées en vol sont préservées,
+    /// les buffers GPU doivent être agrandis
+    Grew,
+    /// Capacité réduite, aucune fusée au-delà du nouveau plafond
+    Shrank,
+    /// Capacité réduite, mais des fusées actives dépassent encore le plafond :
+    /// plus aucun lancement jusqu'à ce que leur nombre repasse sous la limite
+    ShrinkPending,
+}
+
+impl ReloadResult {
+    /// Indique si les buffers GPU doivent être recréés (capacité augmentée).
+    pub fn needs_buffer_recreation(&self) -> bool {
+        matches!(self, ReloadResult::Grew)
+    }
+}
+
+// ------------------------
+// Statistiques du moteur
+// ------------------------
+
+/// Occupation d'un pool de particules (en blocs).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    pub blocks_total: usize,
+    pub blocks_free: usize,
+    /// Blocs alloués hors de la zone de tête (cf. `fragmentation`)
+    pub fragmented_blocks: usize,
+    pub allocation_failures: u64,
+}
+
+impl PoolStats {
+    /// Taux d'occupation du pool dans `[0, 1]`.
+    pub fn utilization(&self) -> f32 {
+        if self.blocks_total == 0 {
+            return 0.0;
+        }
+        1.0 - self.blocks_free as f32 / self.blocks_total as f32
+    }
+
+    /// Part (%) des blocs alloués éparpillés hors des premiers blocs du pool.
+    pub fn fragmentation(&self) -> f32 {
+        let allocated = self.blocks_total - self.blocks_free;
+        if allocated == 0 {
+            return 0.0;
+        }
+        100.0 * self.fragmented_blocks as f32 / allocated as f32
+    }
+}
+
+/// Compteurs incrémentaux de particules actives, par type (tous pools confondus).
+///
+/// Maintenus à chaque activation / désactivation de particule : aucun parcours
+/// des pools n'est nécessaire pour les lire.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActiveParticleCounts {
+    /// Têtes de fusée en vol (fusées actives non explosées)
+    pub rockets: usize,
+    pub explosions: usize,
+    pub trails: usize,
+}
+
+impl ActiveParticleCounts {
+    #[inline(always)]
+    pub fn get(&self, particle_type: ParticleType) -> usize {
+        match particle_type {
+            ParticleType::Rocket => self.rockets,
+            ParticleType::Explosion => self.explosions,
+            ParticleType::Trail => self.trails,
+            ParticleType::Smoke => 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn increment(&mut self, particle_type: ParticleType) {
+        match particle_type {
+            ParticleType::Rocket => self.rockets += 1,
+            ParticleType::Explosion => self.explosions += 1,
+            ParticleType::Trail => self.trails += 1,
+            ParticleType::Smoke => {}
+        }
+    }
+
+    #[inline(always)]
+    pub fn decrement(&mut self, particle_type: ParticleType) {
+        match particle_type {
+            ParticleType::Rocket => self.rockets = self.rockets.saturating_sub(1),
+            ParticleType::Explosion => self.explosions = self.explosions.saturating_sub(1),
+            ParticleType::Trail => self.trails = self.trails.saturating_sub(1),
+            ParticleType::Smoke => {}
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.rockets + self.explosions + self.trails
+    }
+}
+
+/// Instantané des statistiques du moteur physique (cf. commande `physic.stats`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhysicStats {
+    pub active_rockets: usize,
+    pub explosions_pool: PoolStats,
+    pub trails_pool: PoolStats,
+    /// Fragmentation (%) du pool le plus fragmenté (cf. `physic.compact`)
+    pub pool_fragmentation: f32,
+    /// Total des échecs d'allocation (tous pools confondus)
+    pub allocation_failures: u64,
+    /// Particules actives par type
+    pub active_particles: ActiveParticleCounts,
+    /// Final en cours (`physic.finale`)
+    pub finale: Option<FinaleProgress>,
 }
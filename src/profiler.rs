@@ -1,9 +1,19 @@
 use log::info;
 use std::collections::HashMap;
 use std::fmt;
-use std::sync::{Arc, RwLock};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
+use crate::profiler_export::{
+    format_periodic_row, format_summary, write_file, MetricSummary, MetricsFormat, PeriodicExport,
+};
+use crate::profiler_scopes::{enter_scope, exit_scope, format_scope_tree, ScopeStats, ScopeTree};
+use crate::profiler_trace::trace_capture;
+use crate::utils::memory_stats::memory_stats;
+
 /// Valeur de métrique typée (soit f32 soit usize)
 #[derive(Debug)]
 pub enum MetricValue {
@@ -28,6 +38,17 @@ impl From<Duration> for MetricValue {
     }
 }
 
+impl MetricValue {
+    /// Valeur numérique (ms pour une durée), pour les graphes
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            MetricValue::Usize(u) => *u as f32,
+            MetricValue::F32(v) => *v,
+            MetricValue::Duration(d) => d.as_secs_f32() * 1000.0,
+        }
+    }
+}
+
 impl fmt::Display for MetricValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -38,59 +59,345 @@ impl fmt::Display for MetricValue {
     }
 }
 
+/// Label de la fenêtre des durées de frame (`Profiler::set_window`)
+pub const FRAME_LABEL: &str = "frame";
+/// Nombre de classes de l'histogramme des durées de frame
+pub const FRAME_HISTOGRAM_BUCKETS: usize = 8;
+/// Largeur (caractères) de la plus grande barre de l'histogramme
+const HISTOGRAM_BAR_WIDTH: usize = 40;
+
 /// Données internes du profiler
 pub struct ProfilerInner {
     pub samples: HashMap<String, Vec<f32>>, // Durées RAII / profile_block
     pub metrics: HashMap<String, Vec<MetricValue>>, // Valeurs scalaires typées
+    /// Fenêtre glissante par défaut (nombre d'échantillons)
     pub max_samples: usize,
+    /// Fenêtres propres à certains labels (`Profiler::set_window`)
+    pub windows: HashMap<String, usize>,
     pub total_frame_times: Vec<f32>,
+    /// Temps cumulés par chemin de scopes (`frame > render frame > …`)
+    pub scopes: ScopeTree,
+}
+
+impl ProfilerInner {
+    /// Taille de la fenêtre glissante de `label`
+    pub fn window(&self, label: &str) -> usize {
+        self.windows.get(label).copied().unwrap_or(self.max_samples)
+    }
+
+    fn push_sample(&mut self, label: &str, dt: f32) {
+        let window = self.window(label);
+        let samples = self.samples.entry(label.to_string()).or_default();
+        push_bounded(samples, dt, window);
+    }
+}
+
+/// Ajoute `value` en ne gardant que les `window` dernières valeurs.
+fn push_bounded<T>(buffer: &mut Vec<T>, value: T, window: usize) {
+    let window = window.max(1);
+    if buffer.len() >= window {
+        buffer.drain(..=buffer.len() - window);
+    }
+    buffer.push(value);
+}
+
+/// Percentiles d'une série (rang le plus proche), min et max compris.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentiles {
+    pub min: f32,
+    pub p50: f32,
+    pub p95: f32,
+    pub p99: f32,
+    pub max: f32,
+}
+
+impl Percentiles {
+    /// `None` pour une série vide.
+    pub fn from_series(series: &[f32]) -> Option<Self> {
+        if series.is_empty() {
+            return None;
+        }
+        let mut sorted = series.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        Some(Self {
+            min: sorted[0],
+            p50: percentile_nearest_rank(&sorted, 50.0),
+            p95: percentile_nearest_rank(&sorted, 95.0),
+            p99: percentile_nearest_rank(&sorted, 99.0),
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+impl fmt::Display for Percentiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min = {:.3} | p50 = {:.3} | p95 = {:.3} | p99 = {:.3} | max = {:.3}",
+            self.min, self.p50, self.p95, self.p99, self.max
+        )
+    }
+}
+
+/// Percentile `p` (0..=100) d'une série triée, méthode du rang le plus proche :
+/// la valeur de rang `⌈p/100 · n⌉`.
+pub fn percentile_nearest_rank(sorted: &[f32], p: f32) -> f32 {
+    let n = sorted.len();
+    let rank = ((p.clamp(0.0, 100.0) / 100.0) * n as f32).ceil() as usize;
+    sorted[rank.clamp(1, n) - 1]
+}
+
+/// Classes de même largeur entre le min et le max : (borne basse, borne haute, effectif).
+/// Le max tombe dans la dernière classe ; une série constante tient en une classe.
+pub fn histogram_buckets(series: &[f32], buckets: usize) -> Vec<(f32, f32, usize)> {
+    let Some(stats) = Percentiles::from_series(series) else {
+        return Vec::new();
+    };
+    let (min, max) = (stats.min, stats.max);
+    let buckets = if max > min { buckets.max(1) } else { 1 };
+    let width = (max - min) / buckets as f32;
+    let mut counts = vec![0usize; buckets];
+    for &v in series {
+        let index = if width > 0.0 {
+            (((v - min) / width) as usize).min(buckets - 1)
+        } else {
+            0
+        };
+        counts[index] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let lo = min + width * i as f32;
+            let hi = if i + 1 == buckets { max } else { lo + width };
+            (lo, hi, count)
+        })
+        .collect()
+}
+
+/// Histogramme ASCII d'une série (une ligne par classe, barre proportionnelle à
+/// l'effectif), sur le modèle de `ascii_sample_timeline`.
+pub fn ascii_histogram(series: &[f32], buckets: usize, unit: &str) -> String {
+    let buckets = histogram_buckets(series, buckets);
+    let peak = buckets.iter().map(|(_, _, c)| *c).max().unwrap_or(0).max(1);
+    buckets
+        .iter()
+        .map(|(lo, hi, count)| {
+            let bar = (count * HISTOGRAM_BAR_WIDTH).div_ceil(peak);
+            format!(
+                "{:>8.2}-{:<8.2}{} |{:<width$}| {}",
+                lo,
+                hi,
+                unit,
+                "#".repeat(bar),
+                count,
+                width = HISTOGRAM_BAR_WIDTH
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Catégorie des mesures d'un `Profiler`, activable séparément (`profiler.only`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilerCategory {
+    Audio,
+    Physics,
+    Render,
+}
+
+impl ProfilerCategory {
+    pub const ALL: [ProfilerCategory; 3] = [
+        ProfilerCategory::Audio,
+        ProfilerCategory::Physics,
+        ProfilerCategory::Render,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ProfilerCategory::Audio => "audio",
+            ProfilerCategory::Physics => "physics",
+            ProfilerCategory::Render => "render",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
 }
 
+impl FromStr for ProfilerCategory {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown profiler category '{}'", s))
+    }
+}
+
+/// Interrupteur global : désactivé, aucune mesure (ni `Instant::now`) n'est prise.
+static PROFILER_ENABLED: AtomicBool = AtomicBool::new(true);
+/// Catégories mesurées (un bit par `ProfilerCategory`)
+static ENABLED_CATEGORIES: AtomicU8 = AtomicU8::new(0b111);
+
 /// Profiler partagé et thread-safe
 #[derive(Clone)]
 pub struct Profiler {
     inner: Arc<RwLock<ProfilerInner>>,
+    /// Export périodique (`set_periodic_export`), partagé entre les clones
+    periodic: Arc<Mutex<Option<PeriodicExport>>>,
+    /// Catégorie des mesures prises par ce handle
+    category: ProfilerCategory,
 }
 
 impl Profiler {
+    /// Profiler de catégorie `Render` (cf. `with_category`)
     pub fn new(max_samples: usize) -> Self {
         Self {
             inner: Arc::new(RwLock::new(ProfilerInner {
                 samples: HashMap::new(),
                 metrics: HashMap::new(),
                 max_samples,
+                windows: HashMap::new(),
                 total_frame_times: Vec::with_capacity(max_samples),
+                scopes: ScopeTree::default(),
             })),
+            periodic: Arc::new(Mutex::new(None)),
+            category: ProfilerCategory::Render,
+        }
+    }
+
+    /// Profiler neuf dont les mesures relèvent de `category`
+    pub fn with_category(max_samples: usize, category: ProfilerCategory) -> Self {
+        Self {
+            category,
+            ..Self::new(max_samples)
         }
     }
 
-    /// Mesure globale d'une frame (RAII)
+    /// Handle sur les mêmes données, mesurant dans `category` (ex. la physique
+    /// mesurée depuis la boucle de rendu).
+    pub fn for_category(&self, category: ProfilerCategory) -> Self {
+        Self {
+            category,
+            ..self.clone()
+        }
+    }
+
+    pub fn category(&self) -> ProfilerCategory {
+        self.category
+    }
+
+    /// Active ou désactive toutes les mesures du processus
+    pub fn set_enabled(enabled: bool) {
+        PROFILER_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled() -> bool {
+        PROFILER_ENABLED.load(Ordering::Relaxed)
+    }
+
+    pub fn set_category_enabled(category: ProfilerCategory, enabled: bool) {
+        if enabled {
+            ENABLED_CATEGORIES.fetch_or(category.bit(), Ordering::Relaxed);
+        } else {
+            ENABLED_CATEGORIES.fetch_and(!category.bit(), Ordering::Relaxed);
+        }
+    }
+
+    pub fn is_category_enabled(category: ProfilerCategory) -> bool {
+        ENABLED_CATEGORIES.load(Ordering::Relaxed) & category.bit() != 0
+    }
+
+    /// Ne mesure que `category` (`None` : toutes les catégories).
+    pub fn only(category: Option<ProfilerCategory>) {
+        let mask = category.map_or(0b111, ProfilerCategory::bit);
+        ENABLED_CATEGORIES.store(mask, Ordering::Relaxed);
+    }
+
+    /// Les mesures de ce handle sont-elles prises ?
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        Self::is_enabled() && Self::is_category_enabled(self.category)
+    }
+
+    /// Mesure globale d'une frame (RAII), racine des scopes ouverts pendant la frame
     pub fn frame(&self) -> FrameGuard {
+        if !self.is_active() {
+            return FrameGuard { active: None };
+        }
+        enter_scope(FRAME_LABEL);
         FrameGuard {
-            profiler: self.clone(),
-            start: Instant::now(),
+            active: Some((self.clone(), Instant::now())),
         }
     }
 
     /// Mesure d'un bloc labelisé (RAII)
     pub fn measure(&'_ self, label: impl Into<String>) -> MeasureGuard<'_> {
+        if !self.is_active() {
+            return MeasureGuard {
+                profiler: self,
+                label: String::new(),
+                start: None,
+            };
+        }
+        let label = label.into();
+        enter_scope(&label);
         MeasureGuard {
             profiler: self,
-            label: label.into(),
-            start: Instant::now(),
+            label,
+            start: Some(Instant::now()),
         }
     }
 
     /// Enregistre une métrique scalaire typée
     pub fn record_metric<T: Into<MetricValue>>(&self, label: impl Into<String>, value: T) {
+        if !self.is_active() {
+            return;
+        }
         let label = label.into();
         let mut inner = self.inner.write().unwrap();
-        let max_samples = inner.max_samples;
+        let window = inner.window(&label);
         let buffer = inner.metrics.entry(label).or_default();
-        if buffer.len() >= max_samples {
-            buffer.remove(0);
+        push_bounded(buffer, value.into(), window);
+    }
+
+    /// Fenêtre glissante par défaut (labels sans fenêtre propre) ; les séries
+    /// existantes gardent leurs valeurs jusqu'au prochain ajout.
+    pub fn set_default_window(&self, max_samples: usize) {
+        self.inner.write().unwrap().max_samples = max_samples.max(1);
+    }
+
+    /// Fenêtre glissante propre à `label` (bloc, métrique ou `FRAME_LABEL`) ;
+    /// les valeurs en trop sont oubliées.
+    pub fn set_window(&self, label: &str, window: usize) {
+        let window = window.max(1);
+        let mut inner = self.inner.write().unwrap();
+        inner.windows.insert(label.to_string(), window);
+        let trim = |len: usize| ..len.saturating_sub(window);
+        if let Some(samples) = inner.samples.get_mut(label) {
+            samples.drain(trim(samples.len()));
+        }
+        if let Some(values) = inner.metrics.get_mut(label) {
+            values.drain(trim(values.len()));
         }
-        buffer.push(value.into());
+        if label == FRAME_LABEL {
+            let len = inner.total_frame_times.len();
+            inner.total_frame_times.drain(trim(len));
+        }
+    }
+
+    /// Percentiles d'une série (cf. `history`) sur sa fenêtre glissante
+    pub fn percentiles(&self, label: &str) -> Option<Percentiles> {
+        Percentiles::from_series(&self.history(label))
+    }
+
+    /// Percentiles des durées de frame (ms)
+    pub fn frame_percentiles(&self) -> Option<Percentiles> {
+        Percentiles::from_series(&self.frame_times())
     }
 
     /// Retourne le FPS moyen
@@ -110,6 +417,25 @@ impl Profiler {
         inner.total_frame_times.len() as f32
     }
 
+    /// Dernières durées de frame (ms), de la plus ancienne à la plus récente
+    pub fn frame_times(&self) -> Vec<f32> {
+        self.inner.read().unwrap().total_frame_times.clone()
+    }
+
+    /// Historique d'une série : durées d'un bloc (ms) ou valeurs d'une métrique
+    /// (`Duration` convertie en ms). Vide si le label est inconnu.
+    pub fn history(&self, label: &str) -> Vec<f32> {
+        let inner = self.inner.read().unwrap();
+        if let Some(samples) = inner.samples.get(label) {
+            return samples.clone();
+        }
+        inner
+            .metrics
+            .get(label)
+            .map(|values| values.iter().map(MetricValue::as_f32).collect())
+            .unwrap_or_default()
+    }
+
     /// Résumé des temps mesurés (moyenne, min, max)
     pub fn summary(&self) -> HashMap<String, (f32, f32, f32)> {
         let inner = self.inner.read().unwrap();
@@ -133,23 +459,138 @@ impl Profiler {
         inner.metrics.get(label).map(|v| summarize_metric(v))
     }
 
+    /// Démarre la capture en timeline de tous les scopes (tous threads et tous
+    /// profilers confondus), écrite dans `path` par `stop_capture`.
+    pub fn start_capture(path: impl Into<PathBuf>) -> anyhow::Result<()> {
+        let path = path.into();
+        trace_capture().start(&path)?;
+        info!("⏺️ Profiler capture started ({})", path.display());
+        Ok(())
+    }
+
+    /// Arrête la capture et écrit la trace Chrome `trace_event` ; retourne le
+    /// fichier et le nombre de scopes, `None` si aucune capture n'était en cours.
+    pub fn stop_capture() -> anyhow::Result<Option<(PathBuf, usize)>> {
+        let Some(trace) = trace_capture().stop() else {
+            return Ok(None);
+        };
+        trace.save(&trace.path)?;
+        info!(
+            "⏹️ Profiler capture saved: {} scope(s) to {}",
+            trace.events.len(),
+            trace.path.display()
+        );
+        Ok(Some((trace.path, trace.events.len())))
+    }
+
+    pub fn is_capturing() -> bool {
+        trace_capture().is_active()
+    }
+
+    /// Arbre des scopes (temps cumulés depuis le démarrage ou `reset_scopes`)
+    pub fn scope_tree(&self) -> Vec<ScopeStats> {
+        self.inner.read().unwrap().scopes.stats()
+    }
+
+    /// Remet à zéro les temps cumulés de l'arbre des scopes
+    pub fn reset_scopes(&self) {
+        self.inner.write().unwrap().scopes.clear();
+    }
+
+    /// Résumés de toutes les séries (blocs, métriques et `FRAME_LABEL`), triés par nom
+    pub fn metric_summaries(&self) -> Vec<MetricSummary> {
+        let labels: Vec<String> = {
+            let inner = self.inner.read().unwrap();
+            inner
+                .samples
+                .keys()
+                .chain(inner.metrics.keys())
+                .cloned()
+                .collect()
+        };
+        let mut summaries: Vec<MetricSummary> = labels
+            .into_iter()
+            .filter_map(|label| {
+                let series = self.history(&label);
+                MetricSummary::from_series(label, &series)
+            })
+            .chain(MetricSummary::from_series(FRAME_LABEL, &self.frame_times()))
+            .collect();
+        summaries.sort_by(|a, b| a.metric.cmp(&b.metric));
+        summaries.dedup_by(|a, b| a.metric == b.metric);
+        summaries
+    }
+
+    /// Écrit le résumé de toutes les séries dans `path` (CSV ou JSON).
+    pub fn export_summary(
+        &self,
+        path: impl AsRef<Path>,
+        format: MetricsFormat,
+    ) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let summaries = self.metric_summaries();
+        write_file(path, &format_summary(&summaries, self.fps(), format))?;
+        info!(
+            "📊 Profiler metrics exported: {} series to {}",
+            summaries.len(),
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Active l'ajout d'une ligne dans `path` à chaque `flush_periodic`.
+    pub fn set_periodic_export(
+        &self,
+        path: impl Into<PathBuf>,
+        format: MetricsFormat,
+    ) -> anyhow::Result<()> {
+        let export = PeriodicExport::new(path.into(), format)?;
+        info!("📊 Periodic metrics export to {}", export.path.display());
+        *self.periodic.lock().unwrap() = Some(export);
+        Ok(())
+    }
+
+    /// Ajoute une ligne à l'export périodique ; sans effet s'il n'est pas actif.
+    pub fn flush_periodic(&self) -> anyhow::Result<()> {
+        let periodic = self.periodic.lock().unwrap();
+        let Some(export) = periodic.as_ref() else {
+            return Ok(());
+        };
+        let summaries = match export.format {
+            MetricsFormat::Json => self.metric_summaries(),
+            MetricsFormat::Csv => Vec::new(),
+        };
+        let row = format_periodic_row(
+            export.start.elapsed(),
+            self.total_frames() as usize,
+            self.fps(),
+            self.frame_percentiles(),
+            &summaries,
+            export.format,
+        );
+        export.append(&row)
+    }
+
     /// Profile un bloc de code et retourne sa valeur de retour
     pub fn profile_block<T, F>(&self, label: impl Into<String>, f: F) -> T
     where
         F: FnOnce() -> T,
     {
+        if !self.is_active() {
+            return f();
+        }
         let label = label.into();
+        enter_scope(&label);
         let start = Instant::now();
         let result = f();
-        let dt = start.elapsed().as_secs_f32() * 1000.0;
+        let elapsed = start.elapsed();
+        let path = exit_scope();
+        trace_capture().record(&label, start, elapsed);
+        let dt = elapsed.as_secs_f32() * 1000.0;
 
         let mut inner = self.inner.write().unwrap();
-        let max_samples = inner.max_samples;
-        let samples = inner.samples.entry(label).or_default();
-        if samples.len() >= max_samples {
-            samples.remove(0);
-        }
-        samples.push(dt);
+        inner.push_sample(&label, dt);
+        inner.scopes.record(&path, elapsed.as_secs_f64() * 1000.0);
 
         result
     }
@@ -244,19 +685,27 @@ fn summarize_map(map: &HashMap<String, Vec<f32>>) -> HashMap<String, (f32, f32,
 }
 
 /// Mesure globale d'une frame
+///
+/// La décision de mesurer est prise à la création : basculer le profiler pendant
+/// la frame ne laisse ni scope ouvert ni mesure partielle.
 pub struct FrameGuard {
-    profiler: Profiler,
-    start: Instant,
+    /// `None` quand le profiler est désactivé
+    active: Option<(Profiler, Instant)>,
 }
 
 impl Drop for FrameGuard {
     fn drop(&mut self) {
-        let dt = self.start.elapsed().as_secs_f32() * 1000.0;
-        let mut inner = self.profiler.inner.write().unwrap();
-        if inner.total_frame_times.len() >= inner.max_samples {
-            inner.total_frame_times.remove(0);
-        }
-        inner.total_frame_times.push(dt);
+        let Some((profiler, start)) = self.active.take() else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        let path = exit_scope();
+        trace_capture().record("frame", start, elapsed);
+        let dt = elapsed.as_secs_f32() * 1000.0;
+        let mut inner = profiler.inner.write().unwrap();
+        let window = inner.window(FRAME_LABEL);
+        push_bounded(&mut inner.total_frame_times, dt, window);
+        inner.scopes.record(&path, elapsed.as_secs_f64() * 1000.0);
     }
 }
 
@@ -264,19 +713,22 @@ impl Drop for FrameGuard {
 pub struct MeasureGuard<'a> {
     profiler: &'a Profiler,
     label: String,
-    start: Instant,
+    /// `None` quand le profiler est désactivé
+    start: Option<Instant>,
 }
 
 impl<'a> Drop for MeasureGuard<'a> {
     fn drop(&mut self) {
-        let dt = self.start.elapsed().as_secs_f32() * 1000.0;
+        let Some(start) = self.start else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        let path = exit_scope();
+        trace_capture().record(&self.label, start, elapsed);
+        let dt = elapsed.as_secs_f32() * 1000.0;
         let mut inner = self.profiler.inner.write().unwrap();
-        let max_samples = inner.max_samples;
-        let samples = inner.samples.entry(self.label.clone()).or_default();
-        if samples.len() >= max_samples {
-            samples.remove(0);
-        }
-        samples.push(dt);
+        inner.push_sample(&self.label, dt);
+        inner.scopes.record(&path, elapsed.as_secs_f64() * 1000.0);
     }
 }
 
@@ -288,19 +740,35 @@ impl Profiler {
                 self.inner.read().unwrap().total_frame_times.len(),
                 self.fps()
             );
+            let frame_times = self.frame_times();
+            if let Some(p) = Percentiles::from_series(&frame_times) {
+                info!(target: target, "frame time (ms): {}", p);
+                info!(
+                    target: target,
+                    "frame time histogram:\n{}",
+                    ascii_histogram(&frame_times, FRAME_HISTOGRAM_BUCKETS, "ms")
+                );
+            }
         }
-        // Lecture des métriques de temps
-        for (label, (avg, min, max)) in self.summary() {
-            info!(
-                target: target,
-                "{}: avg = {:.3} ms | min = {:.3} ms | max = {:.3} ms",
-                label, avg, min, max
-            );
+        // Lecture des métriques de temps (percentiles sur la fenêtre glissante)
+        for (label, (avg, _, _)) in self.summary() {
+            if let Some(p) = self.percentiles(&label) {
+                info!(target: target, "{}: avg = {:.3} ms | {} (ms)", label, avg, p);
+            }
         }
         // Lecture des métriques scalaires
         let metrics = self.metrics_summary();
-        for (label, (avg, min, max)) in metrics {
-            info!(target: target, "{label}: avg={avg:}, min={min:}, max={max:}");
+        for (label, (avg, _, _)) in metrics {
+            if let Some(p) = self.percentiles(&label) {
+                info!(target: target, "{label}: avg={avg:} | {p}");
+            }
+        }
+        // Mémoire : RSS et grosses allocations déclarées par les moteurs
+        info!(target: target, "memory:\n{}", memory_stats().snapshot().format());
+        // Arbre des scopes : part de chaque enfant dans son parent
+        let scopes = self.scope_tree();
+        if !scopes.is_empty() {
+            info!(target: target, "scopes:\n{}", format_scope_tree(&scopes));
         }
     }
 }
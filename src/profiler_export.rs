@@ -0,0 +1,223 @@
+//! Export des métriques du profiler en CSV ou JSON, pour comparer des runs
+//! (benchmarks, CI) sans relire les logs.
+//!
+//! - `Profiler::export_summary` : résumé complet (une ligne par métrique), écrit
+//!   à la fermeture du simulateur avec `--metrics-out <path>`.
+//! - `Profiler::set_periodic_export` : une ligne ajoutée à chaque intervalle de
+//!   log du renderer avec `--metrics-append <path>`.
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::json;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::profiler::Percentiles;
+
+/// Variable d'environnement équivalente à `--metrics-out`
+pub const METRICS_OUT_ENV: &str = "FIREWORKS_METRICS_OUT";
+/// Variable d'environnement équivalente à `--metrics-append`
+pub const METRICS_APPEND_ENV: &str = "FIREWORKS_METRICS_APPEND";
+
+/// En-tête du résumé CSV
+pub const SUMMARY_CSV_HEADER: &str = "metric,count,avg,min,p50,p95,p99,max";
+/// En-tête du CSV périodique (durées de frame en ms)
+pub const PERIODIC_CSV_HEADER: &str =
+    "elapsed_s,frames,fps,frame_p50_ms,frame_p95_ms,frame_p99_ms,frame_max_ms";
+
+/// Format d'export des métriques
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsFormat {
+    Csv,
+    Json,
+}
+
+impl MetricsFormat {
+    /// Déduit le format de l'extension (`.json` / `.jsonl` → JSON, sinon CSV).
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") || ext.eq_ignore_ascii_case("jsonl") => {
+                MetricsFormat::Json
+            }
+            _ => MetricsFormat::Csv,
+        }
+    }
+}
+
+impl FromStr for MetricsFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(MetricsFormat::Csv),
+            "json" => Ok(MetricsFormat::Json),
+            other => bail!("Unknown metrics format '{}' (expected csv or json)", other),
+        }
+    }
+}
+
+/// Résumé d'une série du profiler (durées en ms).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricSummary {
+    pub metric: String,
+    pub count: usize,
+    pub avg: f32,
+    pub min: f32,
+    pub p50: f32,
+    pub p95: f32,
+    pub p99: f32,
+    pub max: f32,
+}
+
+impl MetricSummary {
+    /// `None` pour une série vide.
+    pub fn from_series(metric: impl Into<String>, series: &[f32]) -> Option<Self> {
+        let p = Percentiles::from_series(series)?;
+        Some(Self {
+            metric: metric.into(),
+            count: series.len(),
+            avg: series.iter().sum::<f32>() / series.len() as f32,
+            min: p.min,
+            p50: p.p50,
+            p95: p.p95,
+            p99: p.p99,
+            max: p.max,
+        })
+    }
+
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4}",
+            csv_field(&self.metric),
+            self.count,
+            self.avg,
+            self.min,
+            self.p50,
+            self.p95,
+            self.p99,
+            self.max
+        )
+    }
+}
+
+/// Champ CSV : entre guillemets s'il contient un séparateur ou un guillemet
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Résumé sérialisé : CSV (en-tête + une ligne par métrique) ou document JSON.
+pub fn format_summary(summaries: &[MetricSummary], fps: f32, format: MetricsFormat) -> String {
+    match format {
+        MetricsFormat::Csv => {
+            let mut out = String::from(SUMMARY_CSV_HEADER);
+            for summary in summaries {
+                out.push('\n');
+                out.push_str(&summary.csv_row());
+            }
+            out.push('\n');
+            out
+        }
+        MetricsFormat::Json => {
+            let doc = json!({ "fps": fps, "metrics": summaries });
+            format!("{:#}\n", doc)
+        }
+    }
+}
+
+/// Ligne de l'export périodique : CSV (cf. `PERIODIC_CSV_HEADER`) ou une ligne
+/// JSON (JSON Lines) avec tous les résumés.
+pub fn format_periodic_row(
+    elapsed: Duration,
+    frames: usize,
+    fps: f32,
+    frame: Option<Percentiles>,
+    summaries: &[MetricSummary],
+    format: MetricsFormat,
+) -> String {
+    match format {
+        MetricsFormat::Csv => {
+            let p = frame.unwrap_or(Percentiles {
+                min: 0.0,
+                p50: 0.0,
+                p95: 0.0,
+                p99: 0.0,
+                max: 0.0,
+            });
+            format!(
+                "{:.3},{},{:.2},{:.4},{:.4},{:.4},{:.4}",
+                elapsed.as_secs_f32(),
+                frames,
+                fps,
+                p.p50,
+                p.p95,
+                p.p99,
+                p.max
+            )
+        }
+        MetricsFormat::Json => json!({
+            "elapsed_s": elapsed.as_secs_f32(),
+            "frames": frames,
+            "fps": fps,
+            "metrics": summaries,
+        })
+        .to_string(),
+    }
+}
+
+/// Crée les répertoires parents de `path` au besoin.
+fn create_parent_dir(path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+/// Écrit `text` dans `path` (répertoires créés au besoin).
+pub(crate) fn write_file(path: &Path, text: &str) -> Result<()> {
+    create_parent_dir(path)?;
+    std::fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Destination de l'export périodique
+#[derive(Debug)]
+pub(crate) struct PeriodicExport {
+    pub path: PathBuf,
+    pub format: MetricsFormat,
+    pub start: Instant,
+}
+
+impl PeriodicExport {
+    /// Ouvre `path` en ajout ; l'en-tête CSV n'est écrit que dans un fichier vide.
+    pub fn new(path: PathBuf, format: MetricsFormat) -> Result<Self> {
+        create_parent_dir(&path)?;
+        let empty = std::fs::metadata(&path)
+            .map(|m| m.len() == 0)
+            .unwrap_or(true);
+        if empty && format == MetricsFormat::Csv {
+            std::fs::write(&path, format!("{}\n", PERIODIC_CSV_HEADER))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        Ok(Self {
+            path,
+            format,
+            start: Instant::now(),
+        })
+    }
+
+    pub fn append(&self, row: &str) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", row)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
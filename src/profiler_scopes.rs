@@ -0,0 +1,151 @@
+//! Arbre des scopes du profiler : chaque `measure` / `profile_block` connaît son
+//! parent grâce à une pile de scopes par thread.
+//!
+//! Hors capture, les mesures sont agrégées par chemin (`frame > render frame > …`) :
+//! la mémoire ne dépend que du nombre de chemins distincts, pas du nombre d'appels.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    /// Scopes ouverts du thread courant, du plus externe au plus interne
+    static SCOPE_STACK: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Ouvre `label` dans la pile du thread courant.
+pub(crate) fn enter_scope(label: &str) {
+    SCOPE_STACK.with(|stack| stack.borrow_mut().push(label.to_string()));
+}
+
+/// Ferme le scope le plus interne et retourne son chemin complet.
+pub(crate) fn exit_scope() -> Vec<String> {
+    SCOPE_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let path = stack.clone();
+        stack.pop();
+        path
+    })
+}
+
+/// Cumuls d'un chemin de scopes
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ScopeNode {
+    calls: u64,
+    total_ms: f64,
+    /// Temps passé dans les scopes enfants
+    children_ms: f64,
+}
+
+/// Temps agrégés d'un scope de l'arbre.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeStats {
+    /// Chemin depuis la racine ; le dernier élément est le label du scope
+    pub path: Vec<String>,
+    pub calls: u64,
+    /// Temps total (ms), enfants compris
+    pub total_ms: f64,
+    /// Temps propre (ms), hors enfants
+    pub self_ms: f64,
+    /// Part du temps total du parent (des racines pour un scope racine), en %
+    pub percent_of_parent: f64,
+}
+
+impl ScopeStats {
+    pub fn label(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or_default()
+    }
+
+    /// Profondeur dans l'arbre (0 pour une racine)
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+/// Agrégat des scopes par chemin.
+#[derive(Debug, Clone, Default)]
+pub struct ScopeTree {
+    nodes: HashMap<Vec<String>, ScopeNode>,
+}
+
+impl ScopeTree {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Ajoute une exécution de `path` (chemin complet du scope) durant `elapsed_ms`.
+    pub fn record(&mut self, path: &[String], elapsed_ms: f64) {
+        if path.is_empty() {
+            return;
+        }
+        let node = self.nodes.entry(path.to_vec()).or_default();
+        node.calls += 1;
+        node.total_ms += elapsed_ms;
+        if path.len() > 1 {
+            let parent = path[..path.len() - 1].to_vec();
+            self.nodes.entry(parent).or_default().children_ms += elapsed_ms;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Scopes en profondeur d'abord ; frères triés par temps total décroissant.
+    pub fn stats(&self) -> Vec<ScopeStats> {
+        let mut out = Vec::with_capacity(self.nodes.len());
+        let roots_total: f64 = self
+            .nodes
+            .iter()
+            .filter(|(path, _)| path.len() == 1)
+            .map(|(_, node)| node.total_ms)
+            .sum();
+        self.push_children(&[], roots_total, &mut out);
+        out
+    }
+
+    fn push_children(&self, parent: &[String], parent_total: f64, out: &mut Vec<ScopeStats>) {
+        let mut children: Vec<(&Vec<String>, &ScopeNode)> = self
+            .nodes
+            .iter()
+            .filter(|(path, _)| path.len() == parent.len() + 1 && path.starts_with(parent))
+            .collect();
+        children.sort_by(|(a_path, a), (b_path, b)| {
+            b.total_ms
+                .total_cmp(&a.total_ms)
+                .then_with(|| a_path.cmp(b_path))
+        });
+        for (path, node) in children {
+            out.push(ScopeStats {
+                path: path.clone(),
+                calls: node.calls,
+                total_ms: node.total_ms,
+                self_ms: (node.total_ms - node.children_ms).max(0.0),
+                percent_of_parent: if parent_total > 0.0 {
+                    100.0 * node.total_ms / parent_total
+                } else {
+                    0.0
+                },
+            });
+            self.push_children(path, node.total_ms, out);
+        }
+    }
+}
+
+/// Arbre indenté : temps total, temps propre et part du parent de chaque scope.
+pub fn format_scope_tree(stats: &[ScopeStats]) -> String {
+    stats
+        .iter()
+        .map(|s| {
+            format!(
+                "{}{}: total = {:.3} ms | self = {:.3} ms | {:.1}% of parent | calls = {}",
+                "  ".repeat(s.depth()),
+                s.label(),
+                s.total_ms,
+                s.self_ms,
+                s.percent_of_parent,
+                s.calls
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,240 @@
+//! Capture des scopes du profiler en timeline, exportée au format Chrome
+//! `trace_event` (ouvrable dans `chrome://tracing`, Perfetto ou speedscope).
+//!
+//! Le rendu et le thread audio ont chacun leur `Profiler` : la capture est donc
+//! globale au processus. Chaque thread écrit dans son propre tampon (verrou sans
+//! contention) ; les tampons sont fusionnés à l'arrêt de la capture.
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::renderer_engine::utils::screenshot::timestamped_path;
+
+/// Répertoire des captures quand aucun chemin n'est fourni
+pub const TRACES_DIR: &str = "traces";
+
+/// Chemin horodaté par défaut : `traces/trace_<secs>_<millis>.json`
+pub fn default_trace_path() -> PathBuf {
+    timestamped_path(TRACES_DIR, "trace", "json")
+}
+
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+static GLOBAL_CAPTURE: OnceLock<TraceCapture> = OnceLock::new();
+
+thread_local! {
+    /// Identifiant stable du thread dans les traces
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+    /// Tampon du thread pour la session en cours : (session, tampon)
+    static LOCAL_BUFFER: RefCell<Option<(u64, Arc<ThreadBuffer>)>> = const { RefCell::new(None) };
+}
+
+/// Capture globale, alimentée par tous les `Profiler` du processus
+pub fn trace_capture() -> &'static TraceCapture {
+    GLOBAL_CAPTURE.get_or_init(TraceCapture::new)
+}
+
+/// Scope mesuré pendant une capture
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+    pub name: String,
+    pub tid: u64,
+    /// Début, relatif au démarrage de la capture
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+#[derive(Debug)]
+struct ThreadBuffer {
+    tid: u64,
+    thread_name: String,
+    events: Mutex<Vec<TraceEvent>>,
+}
+
+#[derive(Debug)]
+struct Session {
+    id: u64,
+    start: Instant,
+    path: PathBuf,
+    buffers: Mutex<Vec<Arc<ThreadBuffer>>>,
+}
+
+/// Timeline capturée : scopes triés par début, et noms des threads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    /// Fichier de destination choisi au démarrage
+    pub path: PathBuf,
+    pub events: Vec<TraceEvent>,
+    /// (tid, nom du thread)
+    pub threads: Vec<(u64, String)>,
+}
+
+impl Trace {
+    /// Document Chrome `trace_event` : un événement complet (`"ph": "X"`) par scope,
+    /// temps en microsecondes, plus les métadonnées de nom de thread.
+    pub fn to_chrome_json(&self) -> Value {
+        let pid = std::process::id();
+        let mut events = vec![json!({
+            "name": "process_name",
+            "ph": "M",
+            "pid": pid,
+            "tid": 0,
+            "args": { "name": env!("CARGO_PKG_NAME") },
+        })];
+        events.extend(self.threads.iter().map(|(tid, name)| {
+            json!({
+                "name": "thread_name",
+                "ph": "M",
+                "pid": pid,
+                "tid": tid,
+                "args": { "name": name },
+            })
+        }));
+        events.extend(self.events.iter().map(|event| {
+            json!({
+                "name": event.name,
+                "cat": "profiler",
+                "ph": "X",
+                "ts": event.start.as_secs_f64() * 1e6,
+                "dur": event.duration.as_secs_f64() * 1e6,
+                "pid": pid,
+                "tid": event.tid,
+            })
+        }));
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+
+    /// Écrit la trace JSON dans `path` (répertoires créés au besoin).
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string(&self.to_chrome_json())?;
+        std::fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Enregistreur de scopes : inactif hors capture (un seul test atomique par scope).
+#[derive(Debug, Default)]
+pub struct TraceCapture {
+    active: AtomicBool,
+    session: RwLock<Option<Arc<Session>>>,
+}
+
+impl TraceCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Démarre une capture destinée à `path` ; erreur si une capture est en cours.
+    pub fn start(&self, path: impl Into<PathBuf>) -> Result<()> {
+        let mut session = self.session.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(current) = session.as_ref() {
+            bail!("A capture to {} is already running", current.path.display());
+        }
+        *session = Some(Arc::new(Session {
+            id: NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed),
+            start: Instant::now(),
+            path: path.into(),
+            buffers: Mutex::new(Vec::new()),
+        }));
+        self.active.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Arrête la capture et fusionne les tampons des threads ; `None` si aucune capture.
+    pub fn stop(&self) -> Option<Trace> {
+        self.active.store(false, Ordering::Relaxed);
+        let session = self
+            .session
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()?;
+        let buffers = session.buffers.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut threads: Vec<(u64, String)> = buffers
+            .iter()
+            .map(|b| (b.tid, b.thread_name.clone()))
+            .collect();
+        threads.sort();
+        threads.dedup();
+        let mut events: Vec<TraceEvent> = buffers
+            .iter()
+            .flat_map(|b| std::mem::take(&mut *b.events.lock().unwrap_or_else(|e| e.into_inner())))
+            .collect();
+        events.sort_by_key(|event| event.start);
+
+        Some(Trace {
+            path: session.path.clone(),
+            events,
+            threads,
+        })
+    }
+
+    /// Enregistre un scope du thread courant (ignoré hors capture).
+    pub fn record(&self, name: &str, start: Instant, duration: Duration) {
+        if !self.is_active() {
+            return;
+        }
+        let Some(session) = self
+            .session
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+        else {
+            return;
+        };
+        let buffer = LOCAL_BUFFER.with(|local| {
+            let mut local = local.borrow_mut();
+            match local.as_ref() {
+                Some((id, buffer)) if *id == session.id => buffer.clone(),
+                _ => {
+                    let buffer = Arc::new(ThreadBuffer::current());
+                    session
+                        .buffers
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push(buffer.clone());
+                    *local = Some((session.id, buffer.clone()));
+                    buffer
+                }
+            }
+        });
+        buffer
+            .events
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(TraceEvent {
+                name: name.to_string(),
+                tid: buffer.tid,
+                start: start.saturating_duration_since(session.start),
+                duration,
+            });
+    }
+}
+
+impl ThreadBuffer {
+    /// Tampon vide du thread courant
+    fn current() -> Self {
+        let tid = THREAD_ID.with(|id| *id);
+        let thread_name = std::thread::current()
+            .name()
+            .map(String::from)
+            .unwrap_or_else(|| format!("thread-{}", tid));
+        Self {
+            tid,
+            thread_name,
+            events: Mutex::new(Vec::new()),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Bloom réactif au son : l'intensité du halo gonfle avec le niveau de sortie
+//! audio (`AudioEngine::output_level`).
+//!
+//! Le niveau RMS change à chaque bloc audio ; il est lissé côté CPU par un
+//! suiveur d'enveloppe (attaque rapide, relâchement lent) pour que le halo
+//! respire au rythme des détonations sans scintiller d'une frame à l'autre.
+
+/// Suiveur d'enveloppe à constantes de temps distinctes en montée et en descente.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EnvelopeFollower {
+    value: f32,
+}
+
+impl EnvelopeFollower {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Valeur lissée courante
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = 0.0;
+    }
+
+    /// Rapproche l'enveloppe de `input` après `dt` secondes : constante de temps
+    /// `attack` si le niveau monte, `release` s'il descend (0 : suivi immédiat).
+    pub fn update(&mut self, input: f32, dt: f32, attack: f32, release: f32) -> f32 {
+        let input = if input.is_finite() {
+            input.max(0.0)
+        } else {
+            0.0
+        };
+        let tau = if input > self.value { attack } else { release };
+        let k = if tau <= 0.0 {
+            1.0
+        } else {
+            1.0 - (-dt.max(0.0) / tau).exp()
+        };
+        self.value += (input - self.value) * k;
+        self.value
+    }
+}
+
+/// Intensité effective du bloom : `base + reactivity × level`.
+///
+/// Avec `reactivity = 0`, `base` est retournée telle quelle.
+pub fn audio_reactive_intensity(base: f32, reactivity: f32, level: f32) -> f32 {
+    if reactivity == 0.0 {
+        return base;
+    }
+    base + reactivity * level
+}
@@ -0,0 +1,313 @@
+use gl::types::*;
+use glam::{Mat3, Vec2};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::renderer_engine::flash::{with_flash_glsl, FlashUniformLocations, FlashUniforms};
+use crate::renderer_engine::render_stats::GpuMemory;
+use crate::renderer_engine::tools::compile_shader_program;
+use crate::{cstr, gl_check};
+
+/// Réglages du fond de scène (table `[background]` de renderer.toml).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BackgroundConfig {
+    pub enabled: bool,
+    /// Couleur du ciel en haut de l'écran (RGB linéaire)
+    pub top_color: [f32; 3],
+    /// Couleur de l'horizon, en bas de l'écran
+    pub bottom_color: [f32; 3],
+    /// Nombre d'étoiles (0 = pas de champ d'étoiles)
+    pub star_count: usize,
+    /// Graine du champ d'étoiles : même graine = même ciel
+    pub star_seed: u64,
+    /// Luminosité maximale d'une étoile, à garder sous `bloom_threshold × (1 - bloom_soft_knee)`
+    pub star_max_brightness: f32,
+    /// Vitesse de scintillement (rad/s)
+    pub twinkle_speed: f32,
+}
+
+impl Default for BackgroundConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            top_color: [0.01, 0.02, 0.08],
+            bottom_color: [0.0, 0.0, 0.01],
+            star_count: 300,
+            star_seed: 7,
+            star_max_brightness: 0.35,
+            twinkle_speed: 2.0,
+        }
+    }
+}
+
+/// Étoile du fond, en coordonnées normalisées de l'écran (0..1, y vers le haut).
+///
+/// Le ciel est "à l'infini" : il ne suit pas la caméra et s'adapte
+/// naturellement au redimensionnement de la fenêtre.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Star {
+    pub pos: Vec2,
+    /// Luminosité de base (0..star_max_brightness)
+    pub brightness: f32,
+    /// Phase du scintillement
+    pub phase: f32,
+    /// Taille en pixels
+    pub size: f32,
+}
+
+/// Champ d'étoiles déterministe pour une graine donnée.
+///
+/// Les étoiles sont plus denses vers le haut du ciel (l'horizon est plus lumineux).
+pub fn generate_stars(count: usize, seed: u64, max_brightness: f32) -> Vec<Star> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let max_brightness = max_brightness.max(0.0);
+    (0..count)
+        .map(|_| {
+            let x = rng.random_range(0.0..1.0);
+            // sqrt : densité croissante avec la hauteur, horizon dégagé
+            let y = 0.15 + rng.random_range(0.0f32..1.0).sqrt() * 0.85;
+            Star {
+                pos: Vec2::new(x, y),
+                brightness: rng.random_range(0.2..=1.0) * max_brightness,
+                phase: rng.random_range(0.0..std::f32::consts::TAU),
+                size: rng.random_range(1.0..2.5),
+            }
+        })
+        .collect()
+}
+
+/// Passe de fond : dégradé vertical plein écran puis champ d'étoiles scintillantes.
+///
+/// Le dégradé reçoit aussi les éclairs de détonation : le ciel s'illumine
+/// autour de chaque explosion.
+pub struct BackgroundRenderer {
+    gradient_program: u32,
+    gradient_vao: u32,
+    loc_top: i32,
+    loc_bottom: i32,
+    loc_inv_view_proj: i32,
+    flash_uniforms: FlashUniformLocations,
+
+    stars_program: u32,
+    stars_vao: u32,
+    stars_vbo: u32,
+    loc_time: i32,
+    loc_twinkle: i32,
+
+    config: BackgroundConfig,
+    stars_count: usize,
+}
+
+impl BackgroundRenderer {
+    /// VBO des étoiles
+    pub fn gpu_memory(&self) -> GpuMemory {
+        GpuMemory {
+            buffers: (self.stars_count * std::mem::size_of::<Star>()) as u64,
+            ..Default::default()
+        }
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn new(config: &BackgroundConfig) -> Self {
+        let gradient_program = compile_shader_program(GRADIENT_VS, &with_flash_glsl(GRADIENT_FS));
+        let stars_program = compile_shader_program(STARS_VS, STARS_FS);
+
+        // Le quad plein écran est généré dans le vertex shader (gl_VertexID)
+        let mut gradient_vao = 0;
+        gl::GenVertexArrays(1, &mut gradient_vao);
+
+        let (mut stars_vao, mut stars_vbo) = (0, 0);
+        gl::GenVertexArrays(1, &mut stars_vao);
+        gl::GenBuffers(1, &mut stars_vbo);
+        gl::BindVertexArray(stars_vao);
+        gl::BindBuffer(gl::ARRAY_BUFFER, stars_vbo);
+        let stride = std::mem::size_of::<Star>() as GLsizei;
+        // location 0 : pos (vec2), location 1 : brightness, phase, size (vec3)
+        gl::VertexAttribPointer(0, 2, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+        gl::EnableVertexAttribArray(0);
+        gl::VertexAttribPointer(
+            1,
+            3,
+            gl::FLOAT,
+            gl::FALSE,
+            stride,
+            (2 * std::mem::size_of::<f32>()) as *const _,
+        );
+        gl::EnableVertexAttribArray(1);
+        gl::BindVertexArray(0);
+
+        let mut background = Self {
+            loc_top: gl::GetUniformLocation(gradient_program, cstr!("uTop")),
+            loc_bottom: gl::GetUniformLocation(gradient_program, cstr!("uBottom")),
+            loc_inv_view_proj: gl::GetUniformLocation(gradient_program, cstr!("uInvViewProj")),
+            flash_uniforms: FlashUniformLocations::from_program(gradient_program),
+            loc_time: gl::GetUniformLocation(stars_program, cstr!("uTime")),
+            loc_twinkle: gl::GetUniformLocation(stars_program, cstr!("uTwinkleSpeed")),
+            gradient_program,
+            gradient_vao,
+            stars_program,
+            stars_vao,
+            stars_vbo,
+            config: config.clone(),
+            stars_count: 0,
+        };
+        background.upload_stars();
+        background
+    }
+
+    unsafe fn upload_stars(&mut self) {
+        let stars = generate_stars(
+            self.config.star_count,
+            self.config.star_seed,
+            self.config.star_max_brightness,
+        );
+        gl::BindBuffer(gl::ARRAY_BUFFER, self.stars_vbo);
+        gl::BufferData(
+            gl::ARRAY_BUFFER,
+            std::mem::size_of_val(stars.as_slice()) as GLsizeiptr,
+            stars.as_ptr() as *const _,
+            gl::STATIC_DRAW,
+        );
+        gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        gl_check!("star field upload");
+        self.stars_count = stars.len();
+    }
+
+    /// Met à jour les réglages ; le champ d'étoiles n'est régénéré que s'il change.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn apply_config(&mut self, config: &BackgroundConfig) {
+        let stars_changed = config.star_count != self.config.star_count
+            || config.star_seed != self.config.star_seed
+            || config.star_max_brightness != self.config.star_max_brightness;
+        self.config = config.clone();
+        if stars_changed {
+            self.upload_stars();
+        }
+    }
+
+    /// Dessine le fond (à appeler juste après le clear, avant les particules) ;
+    /// `view_proj` situe les éclairs, donnés en coordonnées monde.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn render(&self, time: f32, view_proj: &Mat3, flashes: &FlashUniforms) {
+        if !self.config.enabled {
+            return;
+        }
+        let [tr, tg, tb] = self.config.top_color;
+        let [br, bg, bb] = self.config.bottom_color;
+        gl::UseProgram(self.gradient_program);
+        gl::Uniform3f(self.loc_top, tr, tg, tb);
+        gl::Uniform3f(self.loc_bottom, br, bg, bb);
+        gl::UniformMatrix3fv(
+            self.loc_inv_view_proj,
+            1,
+            gl::FALSE,
+            view_proj.inverse().as_ref().as_ptr(),
+        );
+        self.flash_uniforms.upload(flashes);
+        gl::BindVertexArray(self.gradient_vao);
+        gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+
+        if self.stars_count > 0 {
+            gl::UseProgram(self.stars_program);
+            gl::Uniform1f(self.loc_time, time);
+            gl::Uniform1f(self.loc_twinkle, self.config.twinkle_speed);
+            gl::BindVertexArray(self.stars_vao);
+            gl::DrawArrays(gl::POINTS, 0, self.stars_count as GLsizei);
+        }
+        gl::BindVertexArray(0);
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn close(&mut self) {
+        if self.stars_vbo != 0 {
+            gl::DeleteBuffers(1, &self.stars_vbo);
+            self.stars_vbo = 0;
+        }
+        for vao in [&mut self.stars_vao, &mut self.gradient_vao] {
+            if *vao != 0 {
+                gl::DeleteVertexArrays(1, vao);
+                *vao = 0;
+            }
+        }
+        for program in [&mut self.stars_program, &mut self.gradient_program] {
+            if *program != 0 {
+                gl::DeleteProgram(*program);
+                *program = 0;
+            }
+        }
+    }
+}
+
+const GRADIENT_VS: &str = r#"
+#version 330 core
+out float vHeight;
+out vec2 vWorldPos;
+
+uniform mat3 uInvViewProj; // clip space -> monde (éclairs)
+
+void main() {
+    // Triangle strip plein écran : (-1,-1) (1,-1) (-1,1) (1,1)
+    vec2 pos = vec2(float(gl_VertexID & 1), float(gl_VertexID >> 1)) * 2.0 - 1.0;
+    vHeight = pos.y * 0.5 + 0.5;
+    vWorldPos = (uInvViewProj * vec3(pos, 1.0)).xy;
+    gl_Position = vec4(pos, 0.0, 1.0);
+}
+"#;
+
+const GRADIENT_FS: &str = r#"
+#version 330 core
+in float vHeight;
+in vec2 vWorldPos;
+out vec4 FragColor;
+
+uniform vec3 uTop;
+uniform vec3 uBottom;
+
+// Part de la lumière des éclairs renvoyée par le ciel
+const float SKY_FLASH_SCALE = 0.25;
+
+void main() {
+    vec3 sky = mix(uBottom, uTop, vHeight);
+    FragColor = vec4(sky + flash_light(vWorldPos) * SKY_FLASH_SCALE, 1.0);
+}
+"#;
+
+const STARS_VS: &str = r#"
+#version 330 core
+layout(location = 0) in vec2 aPos;
+layout(location = 1) in vec3 aBrightnessPhaseSize;
+
+out float vBrightness;
+
+uniform float uTime;
+uniform float uTwinkleSpeed;
+
+void main() {
+    float twinkle = 0.75 + 0.25 * sin(uTime * uTwinkleSpeed + aBrightnessPhaseSize.y);
+    // Jamais au-dessus de la luminosité de base (déjà bornée côté CPU)
+    vBrightness = aBrightnessPhaseSize.x * twinkle;
+    gl_PointSize = aBrightnessPhaseSize.z;
+    gl_Position = vec4(aPos * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+const STARS_FS: &str = r#"
+#version 330 core
+in float vBrightness;
+out vec4 FragColor;
+
+void main() {
+    vec2 uv = gl_PointCoord - vec2(0.5);
+    float falloff = smoothstep(0.25, 0.0, dot(uv, uv));
+    FragColor = vec4(vec3(vBrightness), falloff);
+}
+"#;
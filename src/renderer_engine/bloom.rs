@@ -0,0 +1,768 @@
+use anyhow::{anyhow, Context, Result};
+use gl::types::*;
+use log::{info, warn};
+
+use crate::renderer_engine::config::{RendererConfig, RENDER_SCALE_RANGE};
+use crate::renderer_engine::render_stats::GpuMemory;
+use crate::renderer_engine::shader::try_compile_shader_program_from_files;
+use crate::renderer_engine::tonemap::{
+    comparison_cells, comparison_grid, shader_gamma, ComparisonCell, ToneMappingMode,
+    MAX_COMPARISON_CELLS,
+};
+use crate::utils::assets::asset_path;
+use crate::{cstr, gl_check};
+
+/// Facteur de réduction des textures de flou (moitié de la résolution)
+const BLOOM_DOWNSCALE: u32 = 2;
+/// Taille (puissance de 2) de la texture de log-luminance réduite par mipmaps
+const LUMINANCE_SIZE: u32 = 64;
+
+/// Shaders des passes (les `#include` sont résolus au chargement, cf. `shader.rs`)
+const FULLSCREEN_VS_PATH: &str = "assets/shaders/post/fullscreen.vert.glsl";
+const EXTRACT_FS: &str = "assets/shaders/post/bloom_extract.frag.glsl";
+const BLUR_FS: &str = "assets/shaders/post/bloom_blur.frag.glsl";
+const COMPOSITE_FS: &str = "assets/shaders/post/bloom_composition.frag.glsl";
+const LUMINANCE_FS: &str = "assets/shaders/post/luminance.frag.glsl";
+/// Fichiers lus à la création de la passe (vérifiés au démarrage, cf. `AssetManifest`)
+pub const BLOOM_SHADERS: &[&str] = &[
+    FULLSCREEN_VS_PATH,
+    EXTRACT_FS,
+    BLUR_FS,
+    COMPOSITE_FS,
+    LUMINANCE_FS,
+];
+
+/// Taille de rendu interne de la scène pour une sortie `width × height`
+/// (échelle bornée à `RENDER_SCALE_RANGE`, au moins 1 pixel).
+pub fn internal_size(width: u32, height: u32, render_scale: f32) -> (u32, u32) {
+    let scale = render_scale.clamp(RENDER_SCALE_RANGE.0, RENDER_SCALE_RANGE.1);
+    let scaled = |v: u32| ((v as f32 * scale).round() as u32).max(1);
+    (scaled(width), scaled(height))
+}
+
+/// Taille des textures de flou : résolution interne réduite de `BLOOM_DOWNSCALE`.
+pub fn bloom_buffer_size(width: u32, height: u32, render_scale: f32) -> (u32, u32) {
+    let (w, h) = internal_size(width, height, render_scale);
+    ((w / BLOOM_DOWNSCALE).max(1), (h / BLOOM_DOWNSCALE).max(1))
+}
+
+/// Contribution d'un pixel au bloom : seuil avec genou doux (courbe quadratique).
+///
+/// Retourne le facteur appliqué à la couleur : 0 sous `threshold - knee`,
+/// 1 bien au-dessus du seuil, transition continue entre les deux.
+/// Miroir CPU de la fonction `soft_threshold` du shader d'extraction.
+pub fn soft_threshold(luma: f32, threshold: f32, soft_knee: f32) -> f32 {
+    let knee = threshold * soft_knee.clamp(0.0, 1.0);
+    let soft = (luma - threshold + knee).clamp(0.0, 2.0 * knee);
+    let soft = soft * soft / (4.0 * knee + 1e-5);
+    soft.max(luma - threshold) / luma.max(1e-5)
+}
+
+/// Exposition visée pour une luminance moyenne donnée : ramène la moyenne sur `key`.
+pub fn target_exposure(average_luminance: f32, key: f32, min: f32, max: f32) -> f32 {
+    (key / average_luminance.max(1e-4)).clamp(min, max.max(min))
+}
+
+/// Lissage exponentiel de l'exposition (indépendant du framerate).
+pub fn adapt_exposure(current: f32, target: f32, speed: f32, dt: f32) -> f32 {
+    let alpha = 1.0 - (-speed.max(0.0) * dt.max(0.0)).exp();
+    current + (target - current) * alpha
+}
+
+/// Charge la texture de salissures d'objectif (niveaux de gris, origine en bas à gauche).
+pub fn load_lens_dirt(path: &str) -> Result<image::GrayImage> {
+    let img =
+        image::open(asset_path(path)).with_context(|| format!("Lens dirt texture '{}'", path))?;
+    Ok(img.flipv().to_luma8())
+}
+
+/// Envoie l'image de salissures sur le GPU (texture R8).
+unsafe fn upload_lens_dirt(img: &image::GrayImage) -> GLuint {
+    let mut texture = 0;
+    gl::GenTextures(1, &mut texture);
+    gl::BindTexture(gl::TEXTURE_2D, texture);
+    gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+    gl::TexImage2D(
+        gl::TEXTURE_2D,
+        0,
+        gl::R8 as GLint,
+        img.width() as GLsizei,
+        img.height() as GLsizei,
+        0,
+        gl::RED,
+        gl::UNSIGNED_BYTE,
+        img.as_raw().as_ptr() as *const _,
+    );
+    gl::PixelStorei(gl::UNPACK_ALIGNMENT, 4);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+    gl::TexParameteri(
+        gl::TEXTURE_2D,
+        gl::TEXTURE_WRAP_S,
+        gl::CLAMP_TO_EDGE as GLint,
+    );
+    gl::TexParameteri(
+        gl::TEXTURE_2D,
+        gl::TEXTURE_WRAP_T,
+        gl::CLAMP_TO_EDGE as GLint,
+    );
+    gl::BindTexture(gl::TEXTURE_2D, 0);
+    gl_check!("lens dirt upload");
+    texture
+}
+
+/// Surface de rendu : FBO + texture couleur RGBA16F (HDR).
+#[derive(Debug, Default)]
+pub(crate) struct Surface {
+    pub(crate) fbo: GLuint,
+    pub(crate) texture: GLuint,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl Surface {
+    /// Taille de la texture couleur (RGBA16F : 8 octets par texel)
+    pub(crate) fn byte_size(&self) -> u64 {
+        if self.fbo == 0 {
+            return 0;
+        }
+        self.width as u64 * self.height as u64 * 8
+    }
+
+    pub(crate) unsafe fn new(width: u32, height: u32) -> Result<Self> {
+        let mut texture = 0;
+        gl::GenTextures(1, &mut texture);
+        gl::BindTexture(gl::TEXTURE_2D, texture);
+        gl::TexImage2D(
+            gl::TEXTURE_2D,
+            0,
+            gl::RGBA16F as GLint,
+            width as GLsizei,
+            height as GLsizei,
+            0,
+            gl::RGBA,
+            gl::FLOAT,
+            std::ptr::null(),
+        );
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+        gl::TexParameteri(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_WRAP_S,
+            gl::CLAMP_TO_EDGE as GLint,
+        );
+        gl::TexParameteri(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_WRAP_T,
+            gl::CLAMP_TO_EDGE as GLint,
+        );
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+
+        let mut fbo = 0;
+        gl::GenFramebuffers(1, &mut fbo);
+        gl::BindFramebuffer(gl::FRAMEBUFFER, fbo);
+        gl::FramebufferTexture2D(
+            gl::FRAMEBUFFER,
+            gl::COLOR_ATTACHMENT0,
+            gl::TEXTURE_2D,
+            texture,
+            0,
+        );
+        let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+        gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        gl_check!("bloom surface setup");
+
+        let mut surface = Self {
+            fbo,
+            texture,
+            width,
+            height,
+        };
+        if status != gl::FRAMEBUFFER_COMPLETE {
+            surface.delete();
+            return Err(anyhow!("Bloom framebuffer incomplete (0x{:X})", status));
+        }
+        Ok(surface)
+    }
+
+    pub(crate) unsafe fn bind(&self) {
+        gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+        gl::Viewport(0, 0, self.width as GLsizei, self.height as GLsizei);
+    }
+
+    pub(crate) unsafe fn delete(&mut self) {
+        if self.fbo != 0 {
+            gl::DeleteFramebuffers(1, &self.fbo);
+        }
+        if self.texture != 0 {
+            gl::DeleteTextures(1, &self.texture);
+        }
+        *self = Self::default();
+    }
+}
+
+/// Bloom : la scène est rendue dans une cible HDR, les zones au-dessus du seuil
+/// sont extraites, floutées (gaussienne séparable, demi-résolution) puis ajoutées
+/// à la scène lors de la composition vers la cible de sortie.
+///
+/// Les programmes sont compilés une seule fois ; `resize` ne recrée que les surfaces.
+pub struct BloomPass {
+    scene: Surface,
+    ping_pong: [Surface; 2],
+
+    programs: BloomPrograms,
+    fullscreen_vao: u32,
+
+    /// Texture de salissures chargée (0 : absente) et sa taille (R8)
+    lens_dirt_texture: GLuint,
+    lens_dirt_bytes: u64,
+    /// Dernier chemin tenté, pour ne pas recharger (ni re-logger) à chaque frame
+    lens_dirt_path: Option<String>,
+
+    /// Log-luminance de la scène, réduite par mipmaps jusqu'à 1×1
+    luminance: Surface,
+    /// Exposition courante (lissée) et instant de la dernière adaptation
+    exposure: f32,
+    last_exposure_time: Option<f32>,
+
+    pub enabled: bool,
+    pub threshold: f32,
+    pub soft_knee: f32,
+    pub intensity: f32,
+    pub blur_passes: u32,
+    pub lens_dirt_strength: f32,
+    pub auto_exposure: bool,
+    pub exposure_speed: f32,
+    pub exposure_key: f32,
+    pub exposure_range: (f32, f32),
+    pub tone_mapping: ToneMappingMode,
+    /// Résolution interne de la scène, en fraction de la sortie
+    render_scale: f32,
+    /// Taille de la sortie (fenêtre) ; la scène est rendue à `internal_size`
+    output_size: (u32, u32),
+    /// Opérateurs de la grille de comparaison (vide : composition simple)
+    comparison_modes: Vec<ToneMappingMode>,
+    /// Gamma de sortie visé et grille gamma 1.0 / `output_gamma`
+    pub output_gamma: f32,
+    pub gamma_compare: bool,
+    /// La cible finale encode déjà en sRGB (`GL_FRAMEBUFFER_SRGB`), fixé par le renderer
+    pub srgb_output: bool,
+    /// Niveau de sortie audio lissé (bloom réactif au son), fixé par le renderer
+    pub audio_level: f32,
+}
+
+impl BloomPass {
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn new(width: u32, height: u32) -> Result<Self> {
+        let programs = BloomPrograms::compile()?;
+        let mut fullscreen_vao = 0;
+        gl::GenVertexArrays(1, &mut fullscreen_vao);
+
+        let defaults = RendererConfig::default();
+        let mut bloom = Self {
+            scene: Surface::default(),
+            ping_pong: Default::default(),
+            lens_dirt_texture: 0,
+            lens_dirt_bytes: 0,
+            lens_dirt_path: None,
+            luminance: Surface::default(),
+            exposure: 1.0,
+            last_exposure_time: None,
+            programs,
+            fullscreen_vao,
+            enabled: defaults.bloom_enabled,
+            threshold: defaults.bloom_threshold,
+            soft_knee: defaults.bloom_soft_knee,
+            intensity: defaults.bloom_intensity,
+            blur_passes: defaults.bloom_blur_passes,
+            lens_dirt_strength: defaults.lens_dirt_strength,
+            auto_exposure: defaults.auto_exposure_enabled,
+            exposure_speed: defaults.auto_exposure_speed,
+            exposure_key: defaults.auto_exposure_key,
+            exposure_range: (defaults.auto_exposure_min, defaults.auto_exposure_max),
+            tone_mapping: defaults.tonemapping,
+            render_scale: defaults.render_scale,
+            output_size: (0, 0),
+            comparison_modes: Vec::new(),
+            output_gamma: defaults.output_gamma,
+            gamma_compare: defaults.gamma_compare,
+            srgb_output: false,
+            audio_level: 0.0,
+        };
+        let setup = bloom
+            .resize(width, height)
+            .and_then(|_| bloom.create_luminance_target());
+        if let Err(e) = setup {
+            bloom.close();
+            return Err(e);
+        }
+        Ok(bloom)
+    }
+
+    fn create_hdr_target(width: u32, height: u32) -> Result<Surface> {
+        unsafe { Surface::new(width.max(1), height.max(1)) }
+    }
+
+    fn create_ping_pong(width: u32, height: u32, render_scale: f32) -> Result<[Surface; 2]> {
+        let (w, h) = bloom_buffer_size(width, height, render_scale);
+        Ok([unsafe { Surface::new(w, h)? }, unsafe {
+            Surface::new(w, h)?
+        }])
+    }
+
+    /// Cible de log-luminance avec chaîne de mipmaps complète.
+    unsafe fn create_luminance_target(&mut self) -> Result<()> {
+        self.luminance = Surface::new(LUMINANCE_SIZE, LUMINANCE_SIZE)?;
+        gl::BindTexture(gl::TEXTURE_2D, self.luminance.texture);
+        gl::TexParameteri(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MIN_FILTER,
+            gl::LINEAR_MIPMAP_NEAREST as GLint,
+        );
+        gl::GenerateMipmap(gl::TEXTURE_2D);
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+        Ok(())
+    }
+
+    /// Passe HDR nécessaire (bloom ou exposition automatique actifs).
+    pub fn is_active(&self) -> bool {
+        self.enabled
+            || self.auto_exposure
+            || self.tone_mapping != ToneMappingMode::Linear
+            || !self.comparison_modes.is_empty()
+            || self.gamma_compare
+            || self.render_scale < 1.0
+    }
+
+    /// Mémoire des cibles HDR (la chaîne de mipmaps de luminance compte pour 4/3)
+    /// et de la texture de salissures.
+    pub fn gpu_memory(&self) -> GpuMemory {
+        let surfaces: u64 = self.scene.byte_size()
+            + self.ping_pong.iter().map(Surface::byte_size).sum::<u64>()
+            + self.luminance.byte_size() * 4 / 3;
+        GpuMemory {
+            framebuffers: surfaces,
+            textures: self.lens_dirt_bytes,
+            ..Default::default()
+        }
+    }
+
+    /// Texture de la scène HDR (avant bloom et tone mapping) et sa taille
+    pub fn scene_texture(&self) -> (u32, u32, u32) {
+        (self.scene.texture, self.scene.width, self.scene.height)
+    }
+
+    /// Cibles de la scène (résolution interne) et du flou
+    pub fn framebuffer_sizes(&self) -> [(&'static str, u32, u32); 2] {
+        [
+            ("scene", self.scene.width, self.scene.height),
+            ("bloom", self.ping_pong[0].width, self.ping_pong[0].height),
+        ]
+    }
+
+    /// Échelle de rendu interne appliquée
+    pub fn render_scale(&self) -> f32 {
+        self.render_scale
+    }
+
+    /// Compose la scène une fois par opérateur, en grille (au plus `MAX_COMPARISON_CELLS`) ;
+    /// une liste vide revient à la composition simple.
+    pub fn set_comparison_modes(&mut self, mut modes: Vec<ToneMappingMode>) {
+        modes.truncate(MAX_COMPARISON_CELLS);
+        self.comparison_modes = modes;
+    }
+
+    pub fn comparison_modes(&self) -> &[ToneMappingMode] {
+        &self.comparison_modes
+    }
+
+    /// Cellules de la grille affichée (vide : composition simple)
+    pub fn comparison_cells(&self) -> Vec<ComparisonCell> {
+        comparison_cells(
+            &self.comparison_modes,
+            self.tone_mapping,
+            self.gamma_compare,
+            self.output_gamma,
+        )
+    }
+
+    /// Exposition appliquée lors de la composition (1.0 sans adaptation).
+    pub fn exposure(&self) -> f32 {
+        if self.auto_exposure {
+            self.exposure
+        } else {
+            1.0
+        }
+    }
+
+    /// Recrée les surfaces à la nouvelle taille (les shaders sont conservés).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn resize(&mut self, width: u32, height: u32) -> Result<()> {
+        self.output_size = (width, height);
+        let (w, h) = internal_size(width, height, self.render_scale);
+        if self.scene.width == w && self.scene.height == h {
+            return Ok(());
+        }
+        self.delete_surfaces();
+        self.scene = Self::create_hdr_target(w, h)?;
+        self.ping_pong = Self::create_ping_pong(width, height, self.render_scale)?;
+        Ok(())
+    }
+
+    /// Change la résolution interne ; les surfaces sont recréées immédiatement.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn set_render_scale(&mut self, render_scale: f32) -> Result<()> {
+        self.render_scale = render_scale.clamp(RENDER_SCALE_RANGE.0, RENDER_SCALE_RANGE.1);
+        let (width, height) = self.output_size;
+        self.resize(width, height)
+    }
+
+    /// Recopie les réglages `bloom_*` de la config (appelé à chaque frame),
+    /// l'intensité étant modulée par `audio_level` si le bloom réactif est actif.
+    ///
+    /// La texture de salissures n'est chargée qu'une fois activée, et rechargée
+    /// seulement si son chemin change.
+    pub fn sync_with_renderer_config(&mut self, config: &RendererConfig) {
+        self.enabled = config.bloom_enabled;
+        self.threshold = config.bloom_threshold;
+        self.soft_knee = config.bloom_soft_knee;
+        self.intensity = config.effective_bloom_intensity(self.audio_level);
+        self.blur_passes = config.bloom_blur_passes;
+        self.lens_dirt_strength = config.lens_dirt_strength;
+        if config.auto_exposure_enabled && !self.auto_exposure {
+            // Réactivation : repartir d'une exposition neutre
+            self.exposure = 1.0;
+            self.last_exposure_time = None;
+        }
+        self.auto_exposure = config.auto_exposure_enabled;
+        self.exposure_speed = config.auto_exposure_speed;
+        self.exposure_key = config.auto_exposure_key;
+        self.exposure_range = (config.auto_exposure_min, config.auto_exposure_max);
+        self.tone_mapping = config.tonemapping;
+        self.output_gamma = config.output_gamma;
+        self.gamma_compare = config.gamma_compare;
+        if config.render_scale != self.render_scale {
+            if let Err(e) = unsafe { self.set_render_scale(config.render_scale) } {
+                warn!("⚠️ Render scale {}: {}", config.render_scale, e);
+            }
+        }
+        let modes: &[ToneMappingMode] = if config.tonemapping_compare {
+            &config.tonemapping_compare_modes
+        } else {
+            &[]
+        };
+        if self.comparison_modes != modes {
+            self.set_comparison_modes(modes.to_vec());
+        }
+
+        if self.lens_dirt_strength > 0.0
+            && self.lens_dirt_path.as_deref() != Some(config.lens_dirt_texture.as_str())
+        {
+            unsafe { self.set_lens_dirt(&config.lens_dirt_texture) };
+        }
+    }
+
+    /// Remplace la texture de salissures ; en cas d'échec l'effet est désactivé.
+    unsafe fn set_lens_dirt(&mut self, path: &str) {
+        self.delete_lens_dirt();
+        self.lens_dirt_path = Some(path.to_string());
+        match load_lens_dirt(path) {
+            Ok(img) => {
+                self.lens_dirt_texture = upload_lens_dirt(&img);
+                self.lens_dirt_bytes = img.as_raw().len() as u64;
+                info!(
+                    "🔍 Lens dirt loaded: {} ({}x{})",
+                    path,
+                    img.width(),
+                    img.height()
+                );
+            }
+            Err(e) => warn!("⚠️ Lens dirt disabled: {:#}", e),
+        }
+    }
+
+    /// Poids effectif des salissures (0 si la texture n'a pas pu être chargée).
+    pub fn effective_lens_dirt_strength(&self) -> f32 {
+        if self.lens_dirt_texture != 0 {
+            self.lens_dirt_strength
+        } else {
+            0.0
+        }
+    }
+
+    unsafe fn delete_lens_dirt(&mut self) {
+        if self.lens_dirt_texture != 0 {
+            gl::DeleteTextures(1, &self.lens_dirt_texture);
+            self.lens_dirt_texture = 0;
+            self.lens_dirt_bytes = 0;
+        }
+    }
+
+    /// Redirige le rendu de la scène vers la cible HDR.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn begin(&self) {
+        self.scene.bind();
+        gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+    }
+
+    /// Extrait les zones lumineuses de la scène (demi-résolution).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn extract(&self) {
+        self.begin_fullscreen();
+        self.ping_pong[0].bind();
+        gl::UseProgram(self.programs.extract);
+        gl::Uniform1i(self.programs.loc_extract_scene, 0);
+        gl::Uniform1f(self.programs.loc_threshold, self.threshold);
+        gl::Uniform1f(self.programs.loc_soft_knee, self.soft_knee);
+        gl::BindTexture(gl::TEXTURE_2D, self.scene.texture);
+        gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+        gl_check!("bloom extract");
+        self.end_fullscreen();
+    }
+
+    /// Flou gaussien séparable : horizontal (0 → 1) puis vertical (1 → 0).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn blur(&self) {
+        self.begin_fullscreen();
+        gl::UseProgram(self.programs.blur);
+        gl::Uniform1i(self.programs.loc_blur_image, 0);
+        for _ in 0..self.blur_passes {
+            for (src, dst, direction) in [(0, 1, (1.0, 0.0)), (1, 0, (0.0, 1.0))] {
+                self.ping_pong[dst].bind();
+                gl::Uniform2f(self.programs.loc_blur_direction, direction.0, direction.1);
+                gl::BindTexture(gl::TEXTURE_2D, self.ping_pong[src].texture);
+                gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+            }
+        }
+        gl_check!("bloom blur");
+        self.end_fullscreen();
+    }
+
+    /// Compose scène + bloom dans `output_fbo` (viewport `output_size`), après
+    /// adaptation de l'exposition ; `time` (s) cadence cette adaptation.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn composite(&mut self, output_fbo: GLuint, output_size: (i32, i32), time: f32) {
+        self.begin_fullscreen();
+        if self.auto_exposure {
+            self.update_exposure(time);
+        }
+
+        gl::BindFramebuffer(gl::FRAMEBUFFER, output_fbo);
+        gl::Viewport(0, 0, output_size.0, output_size.1);
+        gl::UseProgram(self.programs.composite);
+        gl::Uniform1i(self.programs.loc_composite_scene, 0);
+        gl::Uniform1i(self.programs.loc_composite_bloom, 1);
+        let intensity = if self.enabled { self.intensity } else { 0.0 };
+        gl::Uniform1f(self.programs.loc_intensity, intensity);
+        gl::Uniform1f(self.programs.loc_exposure, self.exposure());
+        gl::Uniform1i(self.programs.loc_lens_dirt, 2);
+        gl::Uniform1f(
+            self.programs.loc_lens_dirt_strength,
+            self.effective_lens_dirt_strength(),
+        );
+        gl::BindTexture(gl::TEXTURE_2D, self.scene.texture);
+        gl::ActiveTexture(gl::TEXTURE1);
+        gl::BindTexture(gl::TEXTURE_2D, self.ping_pong[0].texture);
+        gl::ActiveTexture(gl::TEXTURE2);
+        gl::BindTexture(gl::TEXTURE_2D, self.lens_dirt_texture);
+        let cells = self.comparison_cells();
+        if cells.is_empty() {
+            gl::Uniform1i(
+                self.programs.loc_tone_mapping,
+                self.tone_mapping.shader_index(),
+            );
+            gl::Uniform1f(
+                self.programs.loc_gamma,
+                shader_gamma(self.output_gamma, self.srgb_output),
+            );
+            gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+        } else {
+            // Grille : la scène entière dans chaque cellule, un réglage par cellule
+            gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+            gl::Clear(gl::COLOR_BUFFER_BIT);
+            let rects = comparison_grid(cells.len(), output_size.0, output_size.1);
+            for (rect, cell) in rects.iter().zip(&cells) {
+                gl::Viewport(rect.x, rect.y, rect.width, rect.height);
+                gl::Uniform1i(self.programs.loc_tone_mapping, cell.mode.shader_index());
+                gl::Uniform1f(
+                    self.programs.loc_gamma,
+                    shader_gamma(cell.gamma, self.srgb_output),
+                );
+                gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+            }
+            gl::Viewport(0, 0, output_size.0, output_size.1);
+        }
+        gl_check!("bloom composite");
+
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+        gl::ActiveTexture(gl::TEXTURE1);
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+        gl::ActiveTexture(gl::TEXTURE0);
+        self.end_fullscreen();
+    }
+
+    /// Recompile les shaders depuis le disque ; les anciens sont gardés en cas d'échec.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn reload_shaders(&mut self) -> Result<()> {
+        let programs = BloomPrograms::compile()?;
+        self.programs.delete();
+        self.programs = programs;
+        Ok(())
+    }
+
+    /// États communs aux passes plein écran (sans mélange, triangle strip).
+    unsafe fn begin_fullscreen(&self) {
+        gl::Disable(gl::BLEND);
+        gl::BindVertexArray(self.fullscreen_vao);
+        gl::ActiveTexture(gl::TEXTURE0);
+    }
+
+    unsafe fn end_fullscreen(&self) {
+        gl::BindVertexArray(0);
+        gl::Enable(gl::BLEND);
+    }
+
+    /// Réduit la log-luminance de la scène jusqu'à 1×1 et y adapte l'exposition.
+    ///
+    /// La relecture du dernier niveau de mipmap (un seul texel) synchronise le GPU,
+    /// ce qui reste négligeable à cette taille.
+    unsafe fn update_exposure(&mut self, time: f32) {
+        self.luminance.bind();
+        gl::UseProgram(self.programs.luminance);
+        gl::Uniform1i(self.programs.loc_luminance_scene, 0);
+        gl::BindTexture(gl::TEXTURE_2D, self.scene.texture);
+        gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+
+        gl::BindTexture(gl::TEXTURE_2D, self.luminance.texture);
+        gl::GenerateMipmap(gl::TEXTURE_2D);
+        let last_level = LUMINANCE_SIZE.ilog2() as GLint;
+        let mut texel = [0.0f32; 4];
+        gl::GetTexImage(
+            gl::TEXTURE_2D,
+            last_level,
+            gl::RGBA,
+            gl::FLOAT,
+            texel.as_mut_ptr() as *mut _,
+        );
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+        gl_check!("auto exposure reduction");
+
+        let average_luminance = texel[0].exp();
+        let (min, max) = self.exposure_range;
+        let target = target_exposure(average_luminance, self.exposure_key, min, max);
+        let dt = self
+            .last_exposure_time
+            .map_or(0.0, |last| (time - last).max(0.0));
+        self.exposure = match self.last_exposure_time {
+            Some(_) => adapt_exposure(self.exposure, target, self.exposure_speed, dt),
+            None => target,
+        };
+        self.last_exposure_time = Some(time);
+    }
+
+    unsafe fn delete_surfaces(&mut self) {
+        self.scene.delete();
+        for surface in &mut self.ping_pong {
+            surface.delete();
+        }
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn close(&mut self) {
+        self.delete_surfaces();
+        self.delete_lens_dirt();
+        self.luminance.delete();
+        self.programs.delete();
+        if self.fullscreen_vao != 0 {
+            gl::DeleteVertexArrays(1, &self.fullscreen_vao);
+            self.fullscreen_vao = 0;
+        }
+    }
+}
+
+/// Programmes des passes de bloom et emplacements de leurs uniformes
+struct BloomPrograms {
+    extract: u32,
+    blur: u32,
+    composite: u32,
+    luminance: u32,
+
+    loc_extract_scene: i32,
+    loc_threshold: i32,
+    loc_soft_knee: i32,
+    loc_blur_image: i32,
+    loc_blur_direction: i32,
+    loc_composite_scene: i32,
+    loc_composite_bloom: i32,
+    loc_intensity: i32,
+    loc_lens_dirt: i32,
+    loc_lens_dirt_strength: i32,
+    loc_exposure: i32,
+    loc_tone_mapping: i32,
+    loc_gamma: i32,
+    loc_luminance_scene: i32,
+}
+
+impl BloomPrograms {
+    unsafe fn compile() -> Result<Self> {
+        let extract = try_compile_shader_program_from_files(FULLSCREEN_VS_PATH, EXTRACT_FS)?;
+        let blur = try_compile_shader_program_from_files(FULLSCREEN_VS_PATH, BLUR_FS)?;
+        let composite = try_compile_shader_program_from_files(FULLSCREEN_VS_PATH, COMPOSITE_FS)?;
+        let luminance = try_compile_shader_program_from_files(FULLSCREEN_VS_PATH, LUMINANCE_FS)?;
+        Ok(Self {
+            loc_extract_scene: gl::GetUniformLocation(extract, cstr!("uScene")),
+            loc_threshold: gl::GetUniformLocation(extract, cstr!("uThreshold")),
+            loc_soft_knee: gl::GetUniformLocation(extract, cstr!("uSoftKnee")),
+            loc_blur_image: gl::GetUniformLocation(blur, cstr!("uImage")),
+            loc_blur_direction: gl::GetUniformLocation(blur, cstr!("uDirection")),
+            loc_composite_scene: gl::GetUniformLocation(composite, cstr!("uScene")),
+            loc_composite_bloom: gl::GetUniformLocation(composite, cstr!("uBloom")),
+            loc_intensity: gl::GetUniformLocation(composite, cstr!("uIntensity")),
+            loc_lens_dirt: gl::GetUniformLocation(composite, cstr!("uLensDirt")),
+            loc_lens_dirt_strength: gl::GetUniformLocation(composite, cstr!("uLensDirtStrength")),
+            loc_exposure: gl::GetUniformLocation(composite, cstr!("uExposure")),
+            loc_tone_mapping: gl::GetUniformLocation(composite, cstr!("uToneMapping")),
+            loc_gamma: gl::GetUniformLocation(composite, cstr!("uGamma")),
+            loc_luminance_scene: gl::GetUniformLocation(luminance, cstr!("uScene")),
+            extract,
+            blur,
+            composite,
+            luminance,
+        })
+    }
+
+    unsafe fn delete(&mut self) {
+        for program in [
+            &mut self.extract,
+            &mut self.blur,
+            &mut self.composite,
+            &mut self.luminance,
+        ] {
+            if *program != 0 {
+                gl::DeleteProgram(*program);
+                *program = 0;
+            }
+        }
+    }
+}
+
+/// Triangle strip plein écran, partagé par toutes les passes de post-process
+pub(crate) const FULLSCREEN_VS: &str =
+    include_str!("../../assets/shaders/post/fullscreen.vert.glsl");
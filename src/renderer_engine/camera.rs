@@ -0,0 +1,153 @@
+use glam::{Mat3, Vec2};
+use serde::{Deserialize, Serialize};
+
+/// Réglages de la caméra (table `[camera]` de renderer.toml).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CameraConfig {
+    /// Vitesse de convergence vers la cible (1/s) ; 0 = déplacement instantané
+    pub smoothing: f32,
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// Facteur de zoom par cran de molette
+    pub wheel_zoom_step: f32,
+    /// L'auditeur audio suit le centre de la caméra
+    pub listener_follows_camera: bool,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self {
+            smoothing: 10.0,
+            min_zoom: 0.25,
+            max_zoom: 8.0,
+            wheel_zoom_step: 1.15,
+            listener_follows_camera: false,
+        }
+    }
+}
+
+/// Caméra 2D : centre (en coordonnées monde) et facteur de zoom.
+///
+/// Le monde est celui de la physique (pixels, y vers le haut). Les coordonnées
+/// "écran" sont en pixels du framebuffer, origine en bas à gauche (y vers le haut) :
+/// un curseur GLFW (y vers le bas) doit être retourné avant conversion.
+///
+/// `center`/`zoom` convergent en douceur vers leurs cibles à chaque `update`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera2D {
+    pub center: Vec2,
+    pub zoom: f32,
+    pub target_center: Vec2,
+    pub target_zoom: f32,
+    viewport: Vec2,
+    config: CameraConfig,
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self::new(Vec2::new(1.0, 1.0), CameraConfig::default())
+    }
+}
+
+impl Camera2D {
+    /// Caméra "identité" : le monde coïncide avec les pixels du viewport.
+    pub fn new(viewport: Vec2, config: CameraConfig) -> Self {
+        let center = viewport * 0.5;
+        Self {
+            center,
+            zoom: 1.0,
+            target_center: center,
+            target_zoom: 1.0,
+            viewport,
+            config,
+        }
+    }
+
+    pub fn viewport(&self) -> Vec2 {
+        self.viewport
+    }
+
+    pub fn config(&self) -> &CameraConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: CameraConfig) {
+        self.config = config;
+        self.target_zoom = self.clamp_zoom(self.target_zoom);
+    }
+
+    /// Redimensionnement : une caméra au repos reste calée sur la fenêtre.
+    pub fn set_viewport(&mut self, viewport: Vec2) {
+        let was_default = self.is_default();
+        self.viewport = viewport;
+        if was_default {
+            self.reset();
+            self.snap();
+        }
+    }
+
+    /// Retour (animé) à la vue identité.
+    pub fn reset(&mut self) {
+        self.target_center = self.viewport * 0.5;
+        self.target_zoom = 1.0;
+    }
+
+    fn is_default(&self) -> bool {
+        self.target_zoom == 1.0 && self.target_center == self.viewport * 0.5
+    }
+
+    /// Termine immédiatement l'interpolation.
+    pub fn snap(&mut self) {
+        self.center = self.target_center;
+        self.zoom = self.target_zoom;
+    }
+
+    fn clamp_zoom(&self, zoom: f32) -> f32 {
+        zoom.clamp(self.config.min_zoom, self.config.max_zoom)
+    }
+
+    pub fn world_to_screen(&self, world: Vec2) -> Vec2 {
+        (world - self.center) * self.zoom + self.viewport * 0.5
+    }
+
+    pub fn screen_to_world(&self, screen: Vec2) -> Vec2 {
+        (screen - self.viewport * 0.5) / self.zoom + self.center
+    }
+
+    /// Zoom cible absolu, autour du centre courant.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.target_zoom = self.clamp_zoom(zoom);
+    }
+
+    /// Multiplie le zoom cible en gardant fixe le point du monde sous `screen`.
+    pub fn zoom_about(&mut self, screen: Vec2, factor: f32) {
+        let offset = screen - self.viewport * 0.5;
+        let anchor = offset / self.target_zoom + self.target_center;
+        self.target_zoom = self.clamp_zoom(self.target_zoom * factor);
+        self.target_center = anchor - offset / self.target_zoom;
+    }
+
+    /// Déplace la vue d'un delta écran (glisser : le monde suit le curseur).
+    pub fn pan_by_screen(&mut self, delta: Vec2) {
+        self.target_center -= delta / self.target_zoom;
+    }
+
+    /// Interpolation exponentielle vers les cibles (indépendante du framerate).
+    pub fn update(&mut self, dt: f32) {
+        if self.config.smoothing <= 0.0 {
+            self.snap();
+            return;
+        }
+        let t = 1.0 - (-self.config.smoothing * dt.max(0.0)).exp();
+        self.center = self.center.lerp(self.target_center, t);
+        // Interpolation géométrique : vitesse de zoom perçue constante
+        self.zoom = (self.zoom.ln() + (self.target_zoom.ln() - self.zoom.ln()) * t).exp();
+    }
+
+    /// Transformation monde → clip space (`uViewProj` des shaders).
+    pub fn view_projection(&self) -> Mat3 {
+        let scale = 2.0 * self.zoom / self.viewport;
+        Mat3::from_scale(scale) * Mat3::from_translation(-self.center)
+    }
+}
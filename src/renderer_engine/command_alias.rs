@@ -0,0 +1,159 @@
+//! Alias de commandes console (`alias bi renderer.bloom.intensity`, puis `bi 3.5`).
+//!
+//! Un alias remplace le premier mot de la ligne ; les arguments saisis après lui
+//! sont ajoutés à la commande cible. Les alias peuvent en chaîner d'autres, les
+//! cycles sont refusés à la définition comme à l'expansion.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::assets::asset_path;
+
+/// Fichier des alias persistés
+pub const ALIASES_CONFIG_PATH: &str = "assets/config/aliases.toml";
+
+/// Contenu de `aliases.toml` : table `[aliases]` (nom → commande)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct AliasFile {
+    aliases: BTreeMap<String, String>,
+}
+
+/// Alias définis, triés par nom.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AliasTable {
+    aliases: BTreeMap<String, String>,
+}
+
+/// Premier mot de la ligne et le reste (arguments, espaces de tête retirés).
+fn split_command(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, rest.trim_start()),
+        None => (line, ""),
+    }
+}
+
+/// Nom d'alias valide : non vide, sans espace ni point (réservé aux commandes des moteurs).
+fn check_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.contains(char::is_whitespace) || name.contains('.') {
+        bail!("Invalid alias name '{}' (no spaces or dots)", name);
+    }
+    Ok(())
+}
+
+impl AliasTable {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.aliases.get(name).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// (nom, commande), triés par nom
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.aliases.keys().map(String::as_str)
+    }
+
+    /// Définit (ou remplace) `name`.
+    ///
+    /// Refuse les noms invalides et les alias qui se rappelleraient eux-mêmes.
+    pub fn define(&mut self, name: &str, command: &str) -> Result<()> {
+        check_name(name)?;
+        let command = command.trim();
+        if command.is_empty() {
+            bail!("Alias '{}' needs a command", name);
+        }
+
+        let mut candidate = self.clone();
+        candidate
+            .aliases
+            .insert(name.to_string(), command.to_string());
+        candidate.expand(name)?;
+        *self = candidate;
+        Ok(())
+    }
+
+    /// Supprime `name` ; retourne la commande qu'il désignait.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.aliases.remove(name)
+    }
+
+    /// Remplace les alias en tête de `line` jusqu'à une commande non-alias.
+    pub fn expand(&self, line: &str) -> Result<String> {
+        let mut line = line.trim().to_string();
+        let mut chain: Vec<String> = Vec::new();
+        loop {
+            let (name, args) = split_command(&line);
+            let Some(target) = self.get(name) else {
+                return Ok(line);
+            };
+            if chain.iter().any(|seen| seen == name) {
+                chain.push(name.to_string());
+                bail!("Recursive alias: {}", chain.join(" -> "));
+            }
+            chain.push(name.to_string());
+            line = if args.is_empty() {
+                target.to_string()
+            } else {
+                format!("{} {}", target, args)
+            };
+        }
+    }
+
+    /// Lit un `aliases.toml` ; chaque entrée est validée comme par `define`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: AliasFile = toml::from_str(text)?;
+        // Table complète d'abord : l'ordre du fichier n'a pas d'importance
+        let table = Self {
+            aliases: file.aliases,
+        };
+        for name in table.names() {
+            check_name(name)?;
+            table.expand(name)?;
+        }
+        Ok(table)
+    }
+
+    /// Charge `path` ; fichier absent : aucun alias.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = &asset_path(path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid aliases file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = &asset_path(path);
+        let file = AliasFile {
+            aliases: self.aliases.clone(),
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(&file)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Liste des alias (commande `alias` sans argument)
+    pub fn format(&self) -> String {
+        if self.is_empty() {
+            return "No aliases defined".to_string();
+        }
+        let mut out = String::from("Aliases:");
+        for (name, command) in self.iter() {
+            out.push_str(&format!("\n  {} = {}", name, command));
+        }
+        out
+    }
+}
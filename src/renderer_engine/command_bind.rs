@@ -0,0 +1,134 @@
+//! Commandes console liées à des touches (`bind f5 "renderer.preset high"`).
+//!
+//! Une touche liée exécute sa commande comme si elle était saisie dans la console
+//! (alias, `;` et `wait` compris). Les raccourcis d'`input.toml` restent
+//! prioritaires : seules les touches qu'ils ne consomment pas déclenchent un bind.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::renderer_engine::window_event::KeyCode;
+use crate::utils::assets::asset_path;
+
+/// Fichier des binds persistés (à côté de `aliases.toml`)
+pub const BINDS_CONFIG_PATH: &str = "assets/config/binds.toml";
+
+/// Contenu de `binds.toml` : table `[binds]` (touche → commande)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct BindFile {
+    binds: BTreeMap<String, String>,
+}
+
+/// Touche nommée comme dans `input.toml` (`"f5"`, `"grave"`…), insensible à la casse.
+pub fn parse_key(name: &str) -> Result<KeyCode> {
+    match KeyCode::from_name(name.trim()) {
+        Some(key) => Ok(key),
+        None => bail!(
+            "Unknown key '{}' (expected a-z, 0-9, f1-f12, space, enter, up, pageup…)",
+            name.trim()
+        ),
+    }
+}
+
+/// Commande de `bind`, guillemets englobants retirés : `"renderer.preset high"`.
+fn unquote(command: &str) -> &str {
+    let command = command.trim();
+    command
+        .strip_prefix('"')
+        .and_then(|c| c.strip_suffix('"'))
+        .unwrap_or(command)
+        .trim()
+}
+
+/// Binds définis, triés par nom de touche.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandBinds {
+    /// Nom canonique de la touche → commande
+    binds: BTreeMap<String, String>,
+}
+
+impl CommandBinds {
+    pub fn is_empty(&self) -> bool {
+        self.binds.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.binds.len()
+    }
+
+    /// (touche, commande), triés par touche
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.binds.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Commande liée à `key`
+    pub fn command_for(&self, key: KeyCode) -> Option<&str> {
+        self.binds.get(key.name()).map(String::as_str)
+    }
+
+    /// Lie (ou relie) `key` à `command` ; retourne la touche reconnue.
+    pub fn bind(&mut self, key: &str, command: &str) -> Result<KeyCode> {
+        let key = parse_key(key)?;
+        let command = unquote(command);
+        if command.is_empty() {
+            bail!("Bind '{}' needs a command", key.name());
+        }
+        self.binds
+            .insert(key.name().to_string(), command.to_string());
+        Ok(key)
+    }
+
+    /// Délie `key` ; retourne la commande qu'elle exécutait.
+    pub fn unbind(&mut self, key: &str) -> Result<Option<String>> {
+        let key = parse_key(key)?;
+        Ok(self.binds.remove(key.name()))
+    }
+
+    /// Lit un `binds.toml` ; chaque touche est validée comme par `bind`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: BindFile = toml::from_str(text)?;
+        let mut binds = Self::default();
+        for (key, command) in &file.binds {
+            binds.bind(key, command)?;
+        }
+        Ok(binds)
+    }
+
+    /// Charge `path` ; fichier absent : aucun bind.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = &asset_path(path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid binds file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = &asset_path(path);
+        let file = BindFile {
+            binds: self.binds.clone(),
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(&file)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Liste des binds (commande `binds`)
+    pub fn format(&self) -> String {
+        if self.is_empty() {
+            return "No key binds defined".to_string();
+        }
+        let mut out = String::from("Key binds:");
+        for (key, command) in self.iter() {
+            out.push_str(&format!("\n  {:<8} {}", key, command));
+        }
+        out
+    }
+}
@@ -1,13 +1,37 @@
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
+use log::{info, warn};
+use std::cell::{Cell, Ref, RefCell};
 use std::collections::HashMap;
-
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::profiler::{Profiler, ProfilerCategory};
+use crate::profiler_trace::default_trace_path;
+use crate::renderer_engine::command_alias::AliasTable;
+use crate::renderer_engine::command_bind::{parse_key, CommandBinds};
+use crate::renderer_engine::command_cvar::{Cvar, CvarRegistry, CvarValue, CVAR_PROFILE_PATH};
+use crate::renderer_engine::command_help::{
+    format_command_help, format_help, internal_commands, DEFAULT_HELP_WIDTH, INTERNAL_COMMAND_HELP,
+};
+use crate::renderer_engine::command_script::{
+    command_failed, run_script, ExecArgs, ScriptReport, MAX_EXEC_DEPTH,
+};
+use crate::renderer_engine::command_sim::{SimContext, SimState};
+use crate::renderer_engine::command_stats::{
+    ranked_score, CommandStats, DEFAULT_TOP_COMMANDS, DEFAULT_USAGE_WEIGHT,
+};
+use crate::renderer_engine::command_transcript::{
+    replay_transcript, save_transcript, TranscriptArgs, TRANSCRIPT_USAGE,
+};
+use crate::renderer_engine::console_output::{ConsoleOutput, Severity};
+use crate::renderer_engine::window_event::KeyCode;
 use crate::AudioEngine;
 use crate::PhysicEngine;
 
-const INTERNAL_COMMANDS: &[&str] = &["clear", "help"];
 const INPUT_BUFFER_GROWTH: usize = 256;
 const SUGGESTION_BOX_HEIGHT: f32 = 80.0;
+const FILTER_BOX_WIDTH: f32 = 240.0;
 const NOISE_TEXTURE_SIZE: usize = 16;
 
 pub struct HistoryCursor<'a> {
@@ -174,6 +198,67 @@ impl<'a> imgui::InputTextCallbackHandler for CombinedInputHandler<'a> {
     }
 }
 
+// Splits an input line on `;` into its commands (empty ones dropped).
+pub fn split_commands(line: &str) -> Vec<&str> {
+    line.split(';')
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .collect()
+}
+
+// `wait <seconds>` with a valid delay; anything else is left to the command path.
+pub(crate) fn parse_wait(command: &str) -> Option<Duration> {
+    let mut words = command.split_whitespace();
+    if words.next() != Some("wait") {
+        return None;
+    }
+    let seconds = words.next()?.parse::<f32>().ok()?;
+    if words.next().is_some() || !seconds.is_finite() || seconds < 0.0 {
+        return None;
+    }
+    Some(Duration::from_secs_f32(seconds))
+}
+
+// Commands deferred by `wait`, run in order of their due time.
+#[derive(Debug, Default)]
+pub struct CommandScheduler {
+    // Sorted by due time; commands due at the same time keep their input order
+    queue: Vec<(Instant, String)>,
+}
+
+impl CommandScheduler {
+    // Splits `line`: returns the commands to run now and queues those after a `wait`.
+    pub fn schedule(&mut self, line: &str, now: Instant) -> Vec<String> {
+        let mut delay = Duration::ZERO;
+        let mut immediate = Vec::new();
+        for command in split_commands(line) {
+            if let Some(wait) = parse_wait(command) {
+                delay += wait;
+            } else if delay.is_zero() {
+                immediate.push(command.to_string());
+            } else {
+                let due = now + delay;
+                let index = self.queue.partition_point(|(d, _)| *d <= due);
+                self.queue.insert(index, (due, command.to_string()));
+            }
+        }
+        immediate
+    }
+
+    // Removes and returns the commands due at `now`.
+    pub fn due(&mut self, now: Instant) -> Vec<String> {
+        let count = self.queue.partition_point(|(due, _)| *due <= now);
+        self.queue
+            .drain(..count)
+            .map(|(_, command)| command)
+            .collect()
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+}
+
 pub fn generate_noise_texture() -> u32 {
     let mut tex_id = 0;
 
@@ -213,7 +298,9 @@ pub struct Console {
     pub focus_previous_widget: bool,
 
     input: String,
-    output: Vec<String>, // Display history
+    output: ConsoleOutput, // Display history (bounded, filterable)
+    filter_input: String,  // Filter text box, kept in sync with `filter <text>`
+    wrap_columns: usize,   // Console width in characters, for `help`
 
     // Background
     noise_tex: u32,
@@ -231,6 +318,9 @@ pub struct Console {
     history: Vec<String>,         // Command history
     history_index: Option<usize>, // Current position in history
 
+    // Commands deferred by `wait`, drained by `tick`
+    scheduler: CommandScheduler,
+
     window: Option<()>,
 }
 
@@ -242,12 +332,15 @@ impl Default for Console {
 
 impl Console {
     pub fn new() -> Self {
-        let noise_tex = generate_noise_texture();
+        // Created on the first draw: the console itself needs no OpenGL context
+        let noise_tex = 0;
 
         Self {
             open: false,
             input: String::new(),
-            output: Vec::new(),
+            output: ConsoleOutput::default(),
+            filter_input: String::new(),
+            wrap_columns: DEFAULT_HELP_WIDTH,
             focus_previous_widget: false,
             noise_tex,
             auto_scroll: true,
@@ -257,12 +350,144 @@ impl Console {
             matcher: SkimMatcherV2::default(),
             history: Vec::new(),
             history_index: None,
+            scheduler: CommandScheduler::default(),
             window: None,
         }
     }
 
+    // Severity guessed from the text (errors, warnings)
     pub fn log(&mut self, text: impl Into<String>) {
-        self.output.push(text.into());
+        let text = text.into();
+        self.output.push(Severity::classify(&text), text);
+    }
+
+    // Command output: never an echo, even when it starts with the echoed lines
+    // of a script (`exec`, `transcript replay`), so a saved transcript replays once
+    fn log_result(&mut self, text: String) {
+        let severity = match Severity::classify(&text) {
+            Severity::Echo => Severity::Info,
+            severity => severity,
+        };
+        self.output.push(severity, text);
+    }
+
+    pub fn log_with(&mut self, severity: Severity, text: impl Into<String>) {
+        self.output.push(severity, text);
+    }
+
+    // Display history
+    pub fn output(&self) -> &ConsoleOutput {
+        &self.output
+    }
+
+    // Number of lines kept before the oldest are dropped
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        if max_lines != self.output.max_lines() {
+            self.output.set_max_lines(max_lines);
+        }
+    }
+
+    fn set_filter(&mut self, pattern: Option<&str>) {
+        self.output.set_filter(pattern);
+        self.filter_input = self.output.filter().unwrap_or("").to_string();
+    }
+
+    // Commands waiting for their `wait` delay
+    pub fn pending_commands(&self) -> usize {
+        self.scheduler.pending()
+    }
+
+    // Runs an input line: commands separated by `;`, `wait <seconds>` deferring the rest.
+    pub fn submit<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        line: &str,
+        now: Instant,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) {
+        if self.run_line(line, now, audio, physic, registry) {
+            self.history.push(line.to_string());
+            self.history_index = None;
+        }
+    }
+
+    // Runs the command bound to a key: logged like a typed line, kept out of the history.
+    pub fn run_bound<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        command: &str,
+        now: Instant,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) {
+        self.run_line(command, now, audio, physic, registry);
+        self.new_text_entered = true;
+    }
+
+    // Runs a line received by the remote console; returns the output it produced
+    // (the command is also logged in the console like a typed one).
+    pub fn run_remote<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        line: &str,
+        now: Instant,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) -> String {
+        let mark = self.output.mark();
+        self.run_line(line, now, audio, physic, registry);
+        self.new_text_entered = true;
+        self.output
+            .lines_since(mark)
+            .filter(|line| line.severity != Severity::Echo)
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Echoes and runs `line`; returns whether it produced output or deferred commands.
+    fn run_line<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        line: &str,
+        now: Instant,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) -> bool {
+        self.log_with(Severity::Echo, format!("> {}", line));
+        let pending_before = self.scheduler.pending();
+        let mut has_output = false;
+        for command in self.scheduler.schedule(line, now) {
+            let result = self.execute_command(&command, audio, physic, registry);
+            if !result.is_empty() {
+                self.log_result(result);
+                has_output = true;
+            }
+        }
+        let deferred = self.scheduler.pending() - pending_before;
+        if deferred > 0 {
+            self.log_with(Severity::Info, format!("{} command(s) deferred", deferred));
+        }
+        has_output || deferred > 0
+    }
+
+    // Runs the deferred commands that are due (called every frame, console open or not).
+    pub fn tick<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        now: Instant,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) {
+        for command in self.scheduler.due(now) {
+            self.log_with(Severity::Echo, format!("> {}", command));
+            let result = self.execute_command(&command, audio, physic, registry);
+            if !result.is_empty() {
+                self.log_result(result);
+            }
+            self.new_text_entered = true;
+        }
     }
 }
 
@@ -301,13 +526,19 @@ impl Console {
             .collapsible(false)
             .flags(imgui::WindowFlags::NO_TITLE_BAR | imgui::WindowFlags::NO_SCROLLBAR)
             .build(|| {
+                if self.noise_tex == 0 {
+                    self.noise_tex = generate_noise_texture();
+                }
                 let pos = ui.window_pos();
                 let size = ui.window_size();
+                let char_width = ui.calc_text_size("M")[0].max(1.0);
+                self.wrap_columns = ((size[0] / char_width) as usize).saturating_sub(4).max(40);
 
                 // 1. Background Overlay
                 self.draw_background_overlay(ui, pos, size);
 
-                // 2. Scrolling Region
+                // 2. Filter & Scrolling Region
+                self.draw_filter_bar(ui);
                 self.draw_scrolling_region(ui);
 
                 // 3. Suggestions Region
@@ -333,6 +564,26 @@ impl Console {
         .build();
     }
 
+    fn draw_filter_bar(&mut self, ui: &imgui::Ui) {
+        ui.set_next_item_width(FILTER_BOX_WIDTH);
+        if ui
+            .input_text("##console_filter", &mut self.filter_input)
+            .hint("filter")
+            .build()
+        {
+            self.output.set_filter(Some(&self.filter_input));
+        }
+        if let Some(pattern) = self.output.filter() {
+            ui.same_line();
+            ui.text_disabled(format!(
+                "{} / {} lines match '{}'",
+                self.output.visible().count(),
+                self.output.len(),
+                pattern
+            ));
+        }
+    }
+
     fn draw_scrolling_region(&mut self, ui: &imgui::Ui) {
         let input_height = ui.frame_height_with_spacing();
 
@@ -343,8 +594,10 @@ impl Console {
             .horizontal_scrollbar(false)
             .build(|| {
                 // Display history
-                for line in &self.output {
-                    ui.text_wrapped(line);
+                for line in self.output.visible() {
+                    let _color =
+                        ui.push_style_color(imgui::StyleColor::Text, line.severity.color());
+                    ui.text_wrapped(format!("{} {}", line.format_timestamp(), line.text));
                 }
 
                 // Handle user scroll
@@ -458,15 +711,9 @@ impl Console {
             return;
         }
 
-        let result = self.execute_command(&command, audio, physic, registry);
+        self.submit(&command, Instant::now(), audio, physic, registry);
 
-        // Display and cleanup
-        self.output.push(format!("> {}", command));
-        if !result.is_empty() {
-            self.output.push(result);
-            self.history.push(command.to_string());
-            self.history_index = None;
-        }
+        // Cleanup
         self.focus_previous_widget = true;
         self.input.clear();
         self.autocomplete_suggestions.clear();
@@ -480,6 +727,7 @@ impl Console {
         registry: &CommandRegistry,
     ) -> String {
         let trimmed_input = input.trim();
+        registry.record_command(trimmed_input);
 
         // 1. Handle Internal Commands
         match trimmed_input {
@@ -488,70 +736,272 @@ impl Console {
                 return "".into();
             }
             "help" => {
-                let available = registry.get_commands();
-
-                let all_cmds = available
-                    .iter()
-                    .map(|s| s.as_str())
-                    .chain(INTERNAL_COMMANDS.iter().cloned())
-                    .collect::<Vec<&str>>()
-                    .join(", ");
-
-                self.output
-                    .push(format!("Available commands: {}", all_cmds));
+                self.log_with(Severity::Info, format_help(registry, self.wrap_columns));
+                let aliases = registry.aliases().format();
+                if !registry.aliases().is_empty() {
+                    self.log_with(Severity::Info, aliases);
+                }
+                let binds = registry.binds().format();
+                if !registry.binds().is_empty() {
+                    self.log_with(Severity::Info, binds);
+                }
                 return "".into();
             }
+            _ if trimmed_input.starts_with("help ") => {
+                return format_command_help(registry, &trimmed_input["help ".len()..]);
+            }
+            "filter" => {
+                return match self.output.filter() {
+                    Some(pattern) => format!("Filter: '{}'", pattern),
+                    None => "Usage: filter <text> | filter off".into(),
+                }
+            }
+            "filter off" => {
+                self.set_filter(None);
+                return "Filter off".into();
+            }
+            _ if trimmed_input.starts_with("filter ") => {
+                let pattern = trimmed_input["filter ".len()..].trim();
+                self.set_filter(Some(pattern));
+                return format!("Filter: '{}'", pattern);
+            }
+            "transcript" => return TRANSCRIPT_USAGE.into(),
+            _ if trimmed_input.starts_with("transcript ") => {
+                let args = &trimmed_input["transcript ".len()..];
+                return self.execute_transcript(args, audio, physic, registry);
+            }
+            // A valid `wait` is consumed by the scheduler before reaching here
+            "wait" => return "Usage: wait <seconds>".into(),
+            _ if trimmed_input.starts_with("wait ") => return "Usage: wait <seconds>".into(),
             _ => {}
         }
 
         // 2. Delegate to Registry
         registry.execute(audio, physic, trimmed_input)
     }
+
+    // `transcript save <path>` / `transcript replay [--force] <path>`: replayed
+    // commands go through `execute_command`, internal ones included.
+    fn execute_transcript<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        args: &str,
+        audio: &mut A,
+        physic: &mut P,
+        registry: &CommandRegistry,
+    ) -> String {
+        match TranscriptArgs::parse(args) {
+            Some(TranscriptArgs::Save(path)) => match save_transcript(&path, self.output.lines()) {
+                Ok(count) => format!("Transcript saved to {} ({} lines)", path.display(), count),
+                Err(e) => format!("Error: {:#}", e),
+            },
+            Some(TranscriptArgs::Replay { path, force }) => {
+                let report = replay_transcript(&path, force, |command| {
+                    let output = self.execute_command(command, audio, physic, registry);
+                    let failed = command_failed(&output);
+                    (output, failed)
+                });
+                match report {
+                    Ok(report) => report.format(),
+                    Err(e) => format!("Error: {:#}", e),
+                }
+            }
+            None => TRANSCRIPT_USAGE.into(),
+        }
+    }
 }
 
 impl Console {
     fn update_autocomplete(&mut self, registry: &CommandRegistry) {
-        if self.input.is_empty() {
-            self.autocomplete_suggestions.clear();
-            self.selected_suggestion = 0;
-            return;
-        }
+        self.autocomplete_suggestions =
+            autocomplete_suggestions(registry, &self.matcher, &self.input);
+
+        // Reset selection index
+        self.selected_suggestion = 0;
+    }
+}
 
-        let input = self.input.trim();
+// Full command lines suggested for the console input, best match first.
+//
+// While the command name is typed, commands are fuzzy-matched; those with
+// registered arguments get a trailing space. Once past the name, the token
+// being typed is matched against `registry.get_arg_suggestions` for its position.
+pub fn autocomplete_suggestions(
+    registry: &CommandRegistry,
+    matcher: &SkimMatcherV2,
+    input: &str,
+) -> Vec<String> {
+    let input = input.trim_start();
+    if input.is_empty() {
+        return Vec::new();
+    }
 
-        // 1. Collect ALL possible commands (Registry + Internal)
-        let command_list_iter = registry
+    let Some((command, args)) = input.split_once(char::is_whitespace) else {
+        // 1. Collect ALL possible commands (Registry + Internal + Aliases),
+        //    sorted so that equal scores keep a stable (alphabetical) order
+        let mut command_list: Vec<String> = registry
             .get_commands()
             .into_iter()
-            .chain(INTERNAL_COMMANDS.iter().copied().map(String::from));
-
-        // 2. Score and Filter (Fuzzy Match) using cached matcher
-        let mut scored_suggestions: Vec<(i64, String)> = command_list_iter
-            .filter_map(|cmd| {
-                self.matcher
-                    .fuzzy_match(&cmd, input)
-                    .map(|score| (score, cmd))
-            })
+            .chain(internal_commands().map(String::from))
+            .chain(
+                registry
+                    .aliases()
+                    .names()
+                    .map(String::from)
+                    .collect::<Vec<_>>(),
+            )
             .collect();
+        command_list.sort();
+        let command_list_iter = command_list.into_iter();
+
+        // 2. Score and Filter (Fuzzy Match), frequently used commands first
+        let stats = registry.command_stats();
+        let weight = registry.usage_weight();
+        let scored = command_list_iter.filter_map(|cmd| {
+            matcher.fuzzy_match(&cmd, input).map(|score| {
+                let score = ranked_score(score, stats.count(&cmd), weight);
+                let takes_args = !registry.get_arg_suggestions(&cmd).is_empty();
+                (score, if takes_args { cmd + " " } else { cmd })
+            })
+        });
+        return sorted_by_score(scored);
+    };
+
+    // Arguments already completed, and the one being typed (empty after a space)
+    let mut done: Vec<&str> = args.split_whitespace().collect();
+    let current = if args.is_empty() || args.ends_with(char::is_whitespace) {
+        ""
+    } else {
+        done.pop().unwrap_or("")
+    };
+    let positions = registry.get_arg_suggestions(command);
+    let Some(candidates) = positions.get(done.len()) else {
+        return Vec::new();
+    };
+    let has_next = done.len() + 1 < positions.len();
+
+    let prefix = std::iter::once(command)
+        .chain(done.iter().copied())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let scored = candidates.iter().filter_map(|candidate| {
+        let score = if current.is_empty() {
+            Some(0)
+        } else {
+            matcher.fuzzy_match(candidate, current)
+        };
+        score.map(|score| {
+            let line = format!("{} {}", prefix, candidate);
+            (score, if has_next { line + " " } else { line })
+        })
+    });
+    sorted_by_score(scored)
+}
 
-        // 3. Sort by Score (descending)
-        scored_suggestions.sort_by(|a, b| b.0.cmp(&a.0));
+// Sort by Score (descending), registration order kept on ties
+fn sorted_by_score(scored: impl Iterator<Item = (i64, String)>) -> Vec<String> {
+    let mut scored: Vec<(i64, String)> = scored.collect();
+    scored.sort_by_key(|s| std::cmp::Reverse(s.0));
+    scored.into_iter().map(|(_, line)| line).collect()
+}
 
-        // 4. Update console suggestions
-        self.autocomplete_suggestions =
-            scored_suggestions.into_iter().map(|(_, cmd)| cmd).collect();
+// `profiler.capture.start [path]` (timestamped file in traces/ by default)
+fn execute_capture_start(args: &str) -> String {
+    let path = match args.split_whitespace().next() {
+        Some(path) => PathBuf::from(path),
+        None => default_trace_path(),
+    };
+    match Profiler::start_capture(&path) {
+        Ok(()) => format!("Profiler capture started: {}", path.display()),
+        Err(e) => format!("Error: {:#}", e),
+    }
+}
 
-        // Reset selection index
-        self.selected_suggestion = 0;
+fn execute_capture_stop() -> String {
+    match Profiler::stop_capture() {
+        Ok(Some((path, scopes))) => {
+            format!(
+                "Profiler capture saved: {} scope(s) to {}",
+                scopes,
+                path.display()
+            )
+        }
+        Ok(None) => "No profiler capture running".to_string(),
+        Err(e) => format!("Error: {:#}", e),
     }
 }
 
+// `profiler.on` / `profiler.off`
+fn execute_profiler_enable(enabled: bool) -> String {
+    Profiler::set_enabled(enabled);
+    if enabled {
+        format!("Profiler enabled ({})", enabled_profiler_categories())
+    } else {
+        "Profiler disabled".to_string()
+    }
+}
+
+// `profiler.only <audio|physics|render|all>` (also turns the profiler on)
+fn execute_profiler_only(args: &str) -> String {
+    let category = match args.split_whitespace().next() {
+        Some("all") => None,
+        Some(name) => match name.parse::<ProfilerCategory>() {
+            Ok(category) => Some(category),
+            Err(e) => return format!("Error: {}", e),
+        },
+        None => return "Usage: profiler.only <audio|physics|render|all>".to_string(),
+    };
+    Profiler::only(category);
+    execute_profiler_enable(true)
+}
+
+fn enabled_profiler_categories() -> String {
+    ProfilerCategory::ALL
+        .into_iter()
+        .filter(|c| Profiler::is_category_enabled(*c))
+        .map(ProfilerCategory::name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 type AudioCommandFn = dyn Fn(&mut dyn AudioEngine, &str) -> String + 'static;
 type PhysicCommandFn = dyn Fn(&mut dyn PhysicEngine, &str) -> String + 'static;
+/// Les commandes renderer capturent elles-mêmes l'état partagé qu'elles modifient
+/// (ex: `Rc<RefCell<RendererConfig>>`), le renderer étant occupé par la boucle de rendu.
+type RendererCommandFn = dyn Fn(&str) -> String + 'static;
+/// Les commandes `sim.*` reçoivent les deux moteurs et l'état partagé du renderer.
+type SimCommandFn = dyn Fn(&mut SimContext, &str) -> String + 'static;
 
 pub struct CommandRegistry {
     commands_audio: HashMap<String, Box<AudioCommandFn>>,
     commands_physic: HashMap<String, Box<PhysicCommandFn>>,
+    commands_renderer: HashMap<String, Box<RendererCommandFn>>,
+    commands_sim: HashMap<String, Box<SimCommandFn>>,
+    // Renderer state handed to `sim.*` commands (set by the renderer)
+    sim_state: SimState,
+    // User shortcuts, expanded before prefix routing.
+    // Behind a RefCell: `alias` / `unalias` run through `execute(&self)`.
+    aliases: RefCell<AliasTable>,
+    // Where aliases are persisted after each change (None = in memory only)
+    alias_file: Option<PathBuf>,
+    // Key -> command string, run by the renderer for keys no shortcut consumes
+    binds: RefCell<CommandBinds>,
+    // Where binds are persisted after each change (None = in memory only)
+    bind_file: Option<PathBuf>,
+    // Executions per command, ranking the autocomplete (`stats.commands`)
+    stats: RefCell<CommandStats>,
+    // Where the counters are persisted after each command (None = in memory only)
+    stats_file: Option<PathBuf>,
+    // Weight of the usage counters against the fuzzy score
+    usage_weight: Cell<f32>,
+    // Scripts currently running (nested `exec`), bounded by MAX_EXEC_DEPTH
+    exec_depth: Cell<usize>,
+    // Completion values for each argument position of a command
+    arg_suggestions: HashMap<String, Vec<Vec<String>>>,
+    // `help` texts: what a command does and its argument syntax
+    descriptions: HashMap<String, String>,
+    usages: HashMap<String, String>,
+    // Typed settings: `<name>` prints, `<name> <value>` sets
+    cvars: CvarRegistry,
 }
 
 impl Default for CommandRegistry {
@@ -562,9 +1012,362 @@ impl Default for CommandRegistry {
 
 impl CommandRegistry {
     pub fn new() -> Self {
-        Self {
+        let mut registry = Self {
             commands_audio: HashMap::new(),
             commands_physic: HashMap::new(),
+            commands_renderer: HashMap::new(),
+            commands_sim: HashMap::new(),
+            sim_state: SimState::default(),
+            aliases: RefCell::new(AliasTable::default()),
+            alias_file: None,
+            binds: RefCell::new(CommandBinds::default()),
+            bind_file: None,
+            stats: RefCell::new(CommandStats::default()),
+            stats_file: None,
+            usage_weight: Cell::new(DEFAULT_USAGE_WEIGHT),
+            exec_depth: Cell::new(0),
+            arg_suggestions: HashMap::new(),
+            descriptions: HashMap::new(),
+            usages: HashMap::new(),
+            cvars: CvarRegistry::default(),
+        };
+        for (name, usage, description) in INTERNAL_COMMAND_HELP {
+            registry.register_usage(name, usage);
+            registry.register_description(name, description);
+        }
+        let keys: Vec<&str> = KeyCode::ALL.iter().map(KeyCode::name).collect();
+        registry.register_args("bind", &[&keys]);
+        registry.register_args("unbind", &[&keys]);
+        registry
+    }
+
+    // Declares a console variable holding its own value.
+    pub fn register_cvar<T: CvarValue>(
+        &mut self,
+        name: &str,
+        default: T,
+        range: Option<(T, T)>,
+        on_change: impl Fn(T) + 'static,
+    ) {
+        let cvar = Cvar::new(name, default, on_change);
+        self.add_cvar(match range {
+            Some((min, max)) => cvar.range(min, max),
+            None => cvar,
+        });
+    }
+
+    // Declares a fully configured console variable (label, description, source).
+    pub fn add_cvar<T: CvarValue>(&mut self, cvar: Cvar<T>) {
+        let hints = T::hints();
+        if !hints.is_empty() {
+            let hints: Vec<&str> = hints.iter().map(String::as_str).collect();
+            self.register_args(cvar.name(), &[&hints]);
+        }
+        self.cvars.register(cvar);
+    }
+
+    pub fn cvars(&self) -> &CvarRegistry {
+        &self.cvars
+    }
+
+    // `cvar.list [filter]`, `cvar.save [path]`, `cvar.load [path]`, `cvar.reset <name|all>`
+    fn execute_cvar_command(&self, command: &str, args: &str) -> String {
+        let arg = args.split_whitespace().next();
+        let path = arg.unwrap_or(CVAR_PROFILE_PATH);
+        match command {
+            "cvar.list" => self.cvars.format_list(arg.unwrap_or("")),
+            "cvar.save" => match self.cvars.save(path) {
+                Ok(()) => format!("Cvars saved to {}", path),
+                Err(e) => format!("Error: {:#}", e),
+            },
+            "cvar.load" => match self.cvars.load(path) {
+                Ok((applied, warnings)) => {
+                    let mut out = format!("{} cvar(s) loaded from {}", applied, path);
+                    for warning in warnings {
+                        out.push_str(&format!("\n  {}", warning));
+                    }
+                    out
+                }
+                Err(e) => format!("Error: {:#}", e),
+            },
+            _ => match arg {
+                Some("all") => {
+                    self.cvars.reset_all();
+                    "All cvars reset to defaults".to_string()
+                }
+                Some(name) if self.cvars.reset(name) => format!(
+                    "{} reset to {}",
+                    name,
+                    self.cvars.value_string(name).unwrap_or_default()
+                ),
+                Some(name) => format!("Unknown cvar '{}'.", name),
+                None => "Usage: cvar.reset <name|all>".to_string(),
+            },
+        }
+    }
+
+    // Declares the values suggested for each argument position of `name`
+    // (one slice per position, an empty slice for free-form values).
+    pub fn register_args(&mut self, name: &str, positions: &[&[&str]]) {
+        let positions = positions
+            .iter()
+            .map(|values| values.iter().map(|v| v.to_string()).collect())
+            .collect();
+        self.arg_suggestions.insert(name.to_string(), positions);
+    }
+
+    // Values suggested per argument position (empty if none were registered)
+    pub fn get_arg_suggestions(&self, name: &str) -> &[Vec<String>] {
+        self.arg_suggestions
+            .get(name)
+            .map_or(&[], |positions| positions.as_slice())
+    }
+
+    // What `name` does, shown by `help` and `help <name>`
+    pub fn register_description(&mut self, name: &str, text: &str) {
+        self.descriptions.insert(name.to_string(), text.to_string());
+    }
+
+    pub fn description(&self, name: &str) -> Option<&str> {
+        self.descriptions.get(name).map(String::as_str)
+    }
+
+    // Argument syntax of `name` (e.g. "[0..1]"); without one, `help` derives it
+    // from the argument suggestions. An empty usage means "no arguments".
+    pub fn register_usage(&mut self, name: &str, usage: &str) {
+        self.usages.insert(name.to_string(), usage.to_string());
+    }
+
+    pub fn usage(&self, name: &str) -> Option<&str> {
+        self.usages.get(name).map(String::as_str)
+    }
+
+    // Loads the aliases saved in `path` and persists every later change there.
+    pub fn set_alias_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        match AliasTable::load(&path) {
+            Ok(table) => {
+                if !table.is_empty() {
+                    info!(
+                        "⌨️ {} console alias(es) loaded from {}",
+                        table.iter().count(),
+                        path.display()
+                    );
+                }
+                *self.aliases.borrow_mut() = table;
+            }
+            Err(e) => warn!("⚠️ {:#}, starting without aliases", e),
+        }
+        self.alias_file = Some(path);
+    }
+
+    pub fn aliases(&self) -> Ref<'_, AliasTable> {
+        self.aliases.borrow()
+    }
+
+    // Defines (or replaces) an alias. Internal command names cannot be shadowed.
+    pub fn define_alias(&self, name: &str, command: &str) -> anyhow::Result<()> {
+        if internal_commands().any(|internal| internal == name) {
+            anyhow::bail!("'{}' is a console command and cannot be an alias", name);
+        }
+        self.aliases.borrow_mut().define(name, command)?;
+        self.save_aliases();
+        Ok(())
+    }
+
+    pub fn remove_alias(&self, name: &str) -> bool {
+        let removed = self.aliases.borrow_mut().remove(name).is_some();
+        if removed {
+            self.save_aliases();
+        }
+        removed
+    }
+
+    fn save_aliases(&self) {
+        if let Some(path) = &self.alias_file {
+            if let Err(e) = self.aliases.borrow().save(path) {
+                warn!("⚠️ Aliases not saved: {:#}", e);
+            }
+        }
+    }
+
+    // `alias` (list), `alias <name>` (show), `alias <name> <command with args>` (define)
+    fn execute_alias(&self, args: &str) -> String {
+        let args = args.trim();
+        if args.is_empty() {
+            return self.aliases.borrow().format();
+        }
+        let (name, command) = match args.split_once(char::is_whitespace) {
+            Some((name, command)) => (name, command.trim()),
+            None => (args, ""),
+        };
+        if command.is_empty() {
+            return match self.aliases.borrow().get(name) {
+                Some(command) => format!("{} = {}", name, command),
+                None => format!("Unknown alias '{}'.", name),
+            };
+        }
+        match self.define_alias(name, command) {
+            Ok(()) => format!("Alias '{}' = {}", name, command),
+            Err(e) => format!("Error: {}", e),
+        }
+    }
+
+    fn execute_unalias(&self, name: &str) -> String {
+        let name = name.trim();
+        if name.is_empty() {
+            return "Usage: unalias <name>".to_string();
+        }
+        if self.remove_alias(name) {
+            format!("Alias '{}' removed", name)
+        } else {
+            format!("Unknown alias '{}'.", name)
+        }
+    }
+
+    // Loads the binds saved in `path` and persists every later change there.
+    pub fn set_bind_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        match CommandBinds::load(&path) {
+            Ok(binds) => {
+                if !binds.is_empty() {
+                    info!(
+                        "⌨️ {} console bind(s) loaded from {}",
+                        binds.len(),
+                        path.display()
+                    );
+                }
+                *self.binds.borrow_mut() = binds;
+            }
+            Err(e) => warn!("⚠️ {:#}, starting without binds", e),
+        }
+        self.bind_file = Some(path);
+    }
+
+    pub fn binds(&self) -> Ref<'_, CommandBinds> {
+        self.binds.borrow()
+    }
+
+    // Command bound to `key`, if any
+    pub fn bound_command(&self, key: KeyCode) -> Option<String> {
+        self.binds.borrow().command_for(key).map(String::from)
+    }
+
+    fn save_binds(&self) {
+        if let Some(path) = &self.bind_file {
+            if let Err(e) = self.binds.borrow().save(path) {
+                warn!("⚠️ Binds not saved: {:#}", e);
+            }
+        }
+    }
+
+    // Loads the usage counters saved in `path` and persists every later count there.
+    pub fn set_stats_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        match CommandStats::load(&path) {
+            Ok(stats) => *self.stats.borrow_mut() = stats,
+            Err(e) => warn!("⚠️ {:#}, starting without command stats", e),
+        }
+        self.stats_file = Some(path);
+    }
+
+    pub fn command_stats(&self) -> Ref<'_, CommandStats> {
+        self.stats.borrow()
+    }
+
+    pub fn usage_weight(&self) -> f32 {
+        self.usage_weight.get()
+    }
+
+    pub fn set_usage_weight(&self, weight: f32) {
+        self.usage_weight.set(weight.max(0.0));
+    }
+
+    // Is `name` something the console can run (command, cvar, internal, alias)?
+    pub fn is_command(&self, name: &str) -> bool {
+        self.commands_audio.contains_key(name)
+            || self.commands_physic.contains_key(name)
+            || self.commands_renderer.contains_key(name)
+            || self.commands_sim.contains_key(name)
+            || self.cvars.contains(name)
+            || internal_commands().any(|internal| internal == name)
+            || self.aliases.borrow().get(name).is_some()
+    }
+
+    // Counts one use of the command starting `input` (unknown names are ignored).
+    pub fn record_command(&self, input: &str) {
+        let Some(name) = input.split_whitespace().next() else {
+            return;
+        };
+        if !self.is_command(name) {
+            return;
+        }
+        self.stats.borrow_mut().record(name);
+        if let Some(path) = &self.stats_file {
+            if let Err(e) = self.stats.borrow().save(path) {
+                warn!("⚠️ Command stats not saved: {:#}", e);
+            }
+        }
+    }
+
+    // `stats.commands [count]`
+    fn execute_stats_commands(&self, args: &str) -> String {
+        match args.split_whitespace().next().map(str::parse::<usize>) {
+            None => self.stats.borrow().format(DEFAULT_TOP_COMMANDS),
+            Some(Ok(count)) => self.stats.borrow().format(count),
+            Some(Err(_)) => "Usage: stats.commands [count]".to_string(),
+        }
+    }
+
+    // `bind <key>` (show), `bind <key> <command...>` (define, quotes optional)
+    fn execute_bind(&self, args: &str) -> String {
+        let args = args.trim();
+        let (key, command) = match args.split_once(char::is_whitespace) {
+            Some((key, command)) => (key, command.trim()),
+            None => (args, ""),
+        };
+        if key.is_empty() {
+            return "Usage: bind <key> <command>".to_string();
+        }
+        if command.is_empty() {
+            return match parse_key(key) {
+                Ok(code) => match self.binds.borrow().command_for(code) {
+                    Some(command) => format!("{} = {}", code.name(), command),
+                    None => format!("Key '{}' is not bound.", code.name()),
+                },
+                Err(e) => format!("Error: {}", e),
+            };
+        }
+        let bound = self.binds.borrow_mut().bind(key, command);
+        match bound {
+            Ok(code) => {
+                self.save_binds();
+                format!(
+                    "Bind '{}' = {}",
+                    code.name(),
+                    self.binds.borrow().command_for(code).unwrap_or("")
+                )
+            }
+            Err(e) => format!("Error: {}", e),
+        }
+    }
+
+    fn execute_unbind(&self, key: &str) -> String {
+        let key = key.trim();
+        if key.is_empty() {
+            return "Usage: unbind <key>".to_string();
+        }
+        let code = match parse_key(key) {
+            Ok(code) => code,
+            Err(e) => return format!("Error: {}", e),
+        };
+        let removed = self.binds.borrow_mut().unbind(code.name());
+        match removed {
+            Ok(Some(_)) => {
+                self.save_binds();
+                format!("Key '{}' unbound", code.name())
+            }
+            _ => format!("Key '{}' is not bound.", code.name()),
         }
     }
 
@@ -583,11 +1386,90 @@ impl CommandRegistry {
             .insert(name.to_string(), Box::new(func));
     }
 
+    pub fn register_for_renderer<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(&str) -> String + 'static,
+    {
+        self.commands_renderer
+            .insert(name.to_string(), Box::new(func));
+    }
+
+    // Commands needing both engines and the renderer state (`sim.*`).
+    pub fn register_for_simulator<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(&mut SimContext, &str) -> String + 'static,
+    {
+        self.commands_sim.insert(name.to_string(), Box::new(func));
+    }
+
+    // Shares the renderer state (config, camera, pause) with `sim.*` commands.
+    pub fn set_sim_state(&mut self, state: SimState) {
+        self.sim_state = state;
+    }
+
+    pub fn sim_state(&self) -> &SimState {
+        &self.sim_state
+    }
+
+    // Runs a command script, each line going through `execute` (aliases, nested exec).
+    pub fn exec_file(
+        &self,
+        audio_engine: &mut dyn AudioEngine,
+        physic_engine: &mut dyn PhysicEngine,
+        args: &ExecArgs,
+    ) -> anyhow::Result<ScriptReport> {
+        if self.exec_depth.get() >= MAX_EXEC_DEPTH {
+            anyhow::bail!("exec nested too deeply (max {})", MAX_EXEC_DEPTH);
+        }
+        self.exec_depth.set(self.exec_depth.get() + 1);
+        let report = run_script(&args.path, args.abort_on_error, |line| {
+            self.run_command(audio_engine, physic_engine, line)
+        });
+        self.exec_depth.set(self.exec_depth.get() - 1);
+        report
+    }
+
     pub fn execute(
         &self,
         audio_engine: &mut dyn AudioEngine,
         physic_engine: &mut dyn PhysicEngine,
         input: &str,
+    ) -> String {
+        self.run_command(audio_engine, physic_engine, input).0
+    }
+
+    // Output of a command and whether it failed (a script fails if any of its lines did).
+    fn run_command(
+        &self,
+        audio_engine: &mut dyn AudioEngine,
+        physic_engine: &mut dyn PhysicEngine,
+        input: &str,
+    ) -> (String, bool) {
+        let expanded = match self.aliases.borrow().expand(input) {
+            Ok(expanded) => expanded,
+            Err(e) => return (format!("Error: {}", e), true),
+        };
+        if let Some(args) = expanded.strip_prefix("exec") {
+            if args.is_empty() || args.starts_with(char::is_whitespace) {
+                let Some(args) = ExecArgs::parse(args) else {
+                    return ("Usage: exec [-e] <path>".to_string(), true);
+                };
+                return match self.exec_file(audio_engine, physic_engine, &args) {
+                    Ok(report) => (report.format(), report.failed > 0),
+                    Err(e) => (format!("Error: {:#}", e), true),
+                };
+            }
+        }
+        let output = self.dispatch(audio_engine, physic_engine, &expanded);
+        let failed = command_failed(&output);
+        (output, failed)
+    }
+
+    fn dispatch(
+        &self,
+        audio_engine: &mut dyn AudioEngine,
+        physic_engine: &mut dyn PhysicEngine,
+        input: &str,
     ) -> String {
         let input = input.trim();
         let cmd_name_with_args = input.split_whitespace().next().unwrap_or("");
@@ -596,6 +1478,31 @@ impl CommandRegistry {
             return "".into();
         }
 
+        let args = input[cmd_name_with_args.len()..].trim_start();
+        match cmd_name_with_args {
+            "alias" => return self.execute_alias(args),
+            "unalias" => return self.execute_unalias(args),
+            "bind" => return self.execute_bind(args),
+            "unbind" => return self.execute_unbind(args),
+            "binds" => return self.binds.borrow().format(),
+            "stats.commands" => return self.execute_stats_commands(args),
+            "profiler.capture.start" => return execute_capture_start(args),
+            "profiler.capture.stop" => return execute_capture_stop(),
+            "profiler.on" => return execute_profiler_enable(true),
+            "profiler.off" => return execute_profiler_enable(false),
+            "profiler.only" => return execute_profiler_only(args),
+            "cvar.list" | "cvar.save" | "cvar.load" | "cvar.reset" => {
+                return self.execute_cvar_command(cmd_name_with_args, args)
+            }
+            _ => {}
+        }
+        if let Some(output) = self
+            .cvars
+            .execute(cmd_name_with_args, args.split_whitespace().next())
+        {
+            return output;
+        }
+
         // Try to split at the first dot. Example: "audio.mute" -> ("audio", "mute")
         let (prefix, _) = match cmd_name_with_args.split_once('.') {
             Some(pair) => pair,
@@ -620,6 +1527,21 @@ impl CommandRegistry {
                     return func(physic_engine, input);
                 }
             }
+            "renderer" => {
+                if let Some(func) = self.commands_renderer.get(cmd_key) {
+                    return func(input);
+                }
+            }
+            "sim" => {
+                if let Some(func) = self.commands_sim.get(cmd_key) {
+                    let mut ctx = SimContext {
+                        audio: audio_engine,
+                        physic: physic_engine,
+                        state: &self.sim_state,
+                    };
+                    return func(&mut ctx, input);
+                }
+            }
             _ => return format!("Unknown engine prefix '{}'.", prefix),
         }
 
@@ -635,7 +1557,10 @@ impl CommandRegistry {
         self.commands_audio
             .keys()
             .chain(self.commands_physic.keys())
+            .chain(self.commands_renderer.keys())
+            .chain(self.commands_sim.keys())
             .cloned()
+            .chain(self.cvars.names().map(String::from))
             .collect()
     }
 }
@@ -0,0 +1,453 @@
+//! Variables console (cvars) : réglages typés lus et modifiés par leur nom.
+//!
+//! Une cvar remplace la commande « setter » écrite à la main : `<nom>` affiche la
+//! valeur, `<nom> <valeur>` la valide (type, plage), l'applique et appelle le
+//! callback de changement. L'ensemble des cvars s'exporte dans un profil TOML
+//! (`cvar.save` / `cvar.load`).
+
+use anyhow::{bail, Context, Result};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::assets::asset_path;
+
+/// Profil de cvars par défaut (`cvar.save` / `cvar.load` sans chemin)
+pub const CVAR_PROFILE_PATH: &str = "assets/config/cvars.toml";
+
+/// Type de valeur d'une cvar.
+pub trait CvarValue: Copy + PartialEq + 'static {
+    /// Forme attendue dans les messages d'usage (`f`, `on|off`…)
+    fn type_hint() -> String;
+
+    fn parse(text: &str) -> Option<Self>;
+
+    /// Valeur affichée par la console
+    fn format(&self) -> String;
+
+    /// Plage affichée dans l'usage
+    fn format_range(range: (Self, Self)) -> String {
+        format!("{}..{}", range.0.format(), range.1.format())
+    }
+
+    /// Ramène la valeur dans `range` (types ordonnés uniquement)
+    fn clamp_to(self, _range: (Self, Self)) -> Self {
+        self
+    }
+
+    fn to_toml(&self) -> toml::Value;
+
+    fn from_toml(value: &toml::Value) -> Option<Self>;
+
+    /// Valeurs proposées par l'autocomplétion
+    fn hints() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl CvarValue for f32 {
+    fn type_hint() -> String {
+        "f".to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        text.parse::<f32>().ok().filter(|v| v.is_finite())
+    }
+
+    fn format(&self) -> String {
+        format!("{:.2}", self)
+    }
+
+    fn format_range(range: (Self, Self)) -> String {
+        format!("{:.1}..{:.1}", range.0, range.1)
+    }
+
+    fn clamp_to(self, range: (Self, Self)) -> Self {
+        self.clamp(range.0, range.1)
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::Float(*self as f64)
+    }
+
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        match value {
+            toml::Value::Float(v) => Some(*v as f32),
+            toml::Value::Integer(v) => Some(*v as f32),
+            _ => None,
+        }
+    }
+}
+
+impl CvarValue for i32 {
+    fn type_hint() -> String {
+        "n".to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn clamp_to(self, range: (Self, Self)) -> Self {
+        self.clamp(range.0, range.1)
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::Integer(*self as i64)
+    }
+
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_integer().and_then(|v| i32::try_from(v).ok())
+    }
+}
+
+impl CvarValue for bool {
+    fn type_hint() -> String {
+        "on|off".to_string()
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        match text.to_ascii_lowercase().as_str() {
+            "on" | "true" | "1" => Some(true),
+            "off" | "false" | "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn format(&self) -> String {
+        if *self { "on" } else { "off" }.to_string()
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::Boolean(*self)
+    }
+
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_bool()
+    }
+
+    fn hints() -> Vec<String> {
+        vec!["on".to_string(), "off".to_string()]
+    }
+}
+
+/// Variable console typée.
+///
+/// Sans `source`, la cvar garde sa valeur ; avec, elle reflète un état tenu
+/// ailleurs (la config du renderer, rechargeable) et `on_change` l'y écrit.
+pub struct Cvar<T: CvarValue> {
+    name: String,
+    label: String,
+    description: Option<String>,
+    default: T,
+    range: Option<(T, T)>,
+    value: Cell<T>,
+    source: Option<Box<dyn Fn() -> T>>,
+    on_change: Box<dyn Fn(T)>,
+}
+
+impl<T: CvarValue> Cvar<T> {
+    pub fn new(name: &str, default: T, on_change: impl Fn(T) + 'static) -> Self {
+        Self {
+            name: name.to_string(),
+            label: name.to_string(),
+            description: None,
+            default,
+            range: None,
+            value: Cell::new(default),
+            source: None,
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Plage admise (bornes incluses) : les valeurs hors plage sont ramenées dedans
+    pub fn range(mut self, min: T, max: T) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Libellé des messages (`"Bloom threshold set to 1.10"`), le nom par défaut
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Précision affichée dans l'usage et `cvar.list`
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Valeur courante lue ailleurs (cf. doc du type)
+    pub fn source(mut self, source: impl Fn() -> T + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get(&self) -> T {
+        match &self.source {
+            Some(source) => source(),
+            None => self.value.get(),
+        }
+    }
+
+    /// Applique `value` (ramenée dans la plage) ; retourne la valeur appliquée.
+    pub fn set(&self, value: T) -> T {
+        let applied = match self.range {
+            Some(range) => value.clamp_to(range),
+            None => value,
+        };
+        self.value.set(applied);
+        (self.on_change)(applied);
+        applied
+    }
+
+    fn usage(&self) -> String {
+        let mut details: Vec<String> = self.description.iter().cloned().collect();
+        if let Some(range) = self.range {
+            details.push(T::format_range(range));
+        }
+        let mut usage = format!("Usage: {} <{}>", self.name, T::type_hint());
+        if !details.is_empty() {
+            usage.push_str(&format!("  ({})", details.join(", ")));
+        }
+        usage
+    }
+}
+
+/// Interface sans type des cvars, pour le registre.
+trait AnyCvar {
+    fn label(&self) -> &str;
+    fn value_string(&self) -> String;
+    /// `<nom> [valeur]` : affichage ou modification
+    fn execute(&self, arg: Option<&str>) -> String;
+    fn reset(&self);
+    fn describe(&self) -> String;
+    fn to_toml(&self) -> toml::Value;
+    fn set_toml(&self, value: &toml::Value) -> Result<()>;
+    fn hints(&self) -> Vec<String>;
+    fn type_hint(&self) -> String;
+}
+
+impl<T: CvarValue> AnyCvar for Cvar<T> {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn value_string(&self) -> String {
+        self.get().format()
+    }
+
+    fn execute(&self, arg: Option<&str>) -> String {
+        let Some(arg) = arg else {
+            return format!("{}: {}", self.label, self.value_string());
+        };
+        let Some(value) = T::parse(arg) else {
+            return self.usage();
+        };
+        let applied = self.set(value);
+        match self.range {
+            // Réglage borné : la valeur appliquée peut différer de la demande
+            Some(_) if applied != value => {
+                format!("{} set to {} (clamped)", self.label, applied.format())
+            }
+            Some(_) => format!("{} set to {}", self.label, applied.format()),
+            None => format!("{}: {}", self.label, applied.format()),
+        }
+    }
+
+    fn reset(&self) {
+        self.set(self.default);
+    }
+
+    fn describe(&self) -> String {
+        let mut out = format!(
+            "{} = {}  (default {}",
+            self.name,
+            self.value_string(),
+            self.default.format()
+        );
+        if let Some(range) = self.range {
+            out.push_str(&format!(", {}", T::format_range(range)));
+        }
+        out.push(')');
+        if let Some(description) = &self.description {
+            out.push_str(&format!(" {}", description));
+        }
+        out
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        self.get().to_toml()
+    }
+
+    fn set_toml(&self, value: &toml::Value) -> Result<()> {
+        match T::from_toml(value) {
+            Some(value) => {
+                self.set(value);
+                Ok(())
+            }
+            None => bail!("expected <{}>, got {}", T::type_hint(), value),
+        }
+    }
+
+    fn hints(&self) -> Vec<String> {
+        T::hints()
+    }
+
+    fn type_hint(&self) -> String {
+        T::type_hint()
+    }
+}
+
+/// Cvars déclarées, par nom.
+#[derive(Default)]
+pub struct CvarRegistry {
+    cvars: BTreeMap<String, Box<dyn AnyCvar>>,
+}
+
+/// Aplatit les tables d'un profil : `[renderer.bloom] threshold = 1` ↔ `"renderer.bloom.threshold" = 1`.
+fn flatten_toml(prefix: &str, table: &toml::Table, out: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            toml::Value::Table(inner) => flatten_toml(&name, inner, out),
+            value => out.push((name, value.clone())),
+        }
+    }
+}
+
+impl CvarRegistry {
+    pub fn register<T: CvarValue>(&mut self, cvar: Cvar<T>) {
+        self.cvars.insert(cvar.name.clone(), Box::new(cvar));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.cvars.contains_key(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cvars.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.cvars.keys().map(String::as_str)
+    }
+
+    /// Valeur affichée de `name`
+    pub fn value_string(&self, name: &str) -> Option<String> {
+        self.cvars.get(name).map(|cvar| cvar.value_string())
+    }
+
+    /// Libellé des messages de `name`
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.cvars.get(name).map(|cvar| cvar.label())
+    }
+
+    /// Valeurs proposées par l'autocomplétion pour `name`
+    pub fn hints(&self, name: &str) -> Vec<String> {
+        self.cvars
+            .get(name)
+            .map_or_else(Vec::new, |cvar| cvar.hints())
+    }
+
+    /// Type attendu par `name` (`"f32"`, `"on|off"`…)
+    pub fn type_hint(&self, name: &str) -> Option<String> {
+        self.cvars.get(name).map(|cvar| cvar.type_hint())
+    }
+
+    /// `"<nom> = <valeur>  (default …, plage)"`, comme dans `cvar.list`
+    pub fn describe(&self, name: &str) -> Option<String> {
+        self.cvars.get(name).map(|cvar| cvar.describe())
+    }
+
+    /// Commande `<nom> [valeur]` ; `None` si `name` n'est pas une cvar.
+    pub fn execute(&self, name: &str, arg: Option<&str>) -> Option<String> {
+        self.cvars.get(name).map(|cvar| cvar.execute(arg))
+    }
+
+    /// Remet `name` à sa valeur par défaut
+    pub fn reset(&self, name: &str) -> bool {
+        self.cvars.get(name).map(|cvar| cvar.reset()).is_some()
+    }
+
+    pub fn reset_all(&self) {
+        for cvar in self.cvars.values() {
+            cvar.reset();
+        }
+    }
+
+    /// `cvar.list [filtre]` : une ligne par cvar dont le nom contient `filter`
+    pub fn format_list(&self, filter: &str) -> String {
+        let lines: Vec<String> = self
+            .cvars
+            .iter()
+            .filter(|(name, _)| name.contains(filter))
+            .map(|(_, cvar)| format!("  {}", cvar.describe()))
+            .collect();
+        if lines.is_empty() {
+            return "No cvars".to_string();
+        }
+        format!("Cvars:\n{}", lines.join("\n"))
+    }
+
+    /// Profil TOML de toutes les cvars (une clé par nom complet)
+    pub fn to_toml_string(&self) -> Result<String> {
+        let table: toml::Table = self
+            .cvars
+            .iter()
+            .map(|(name, cvar)| (name.clone(), cvar.to_toml()))
+            .collect();
+        Ok(toml::to_string_pretty(&table)?)
+    }
+
+    /// Applique un profil TOML. Retourne le nombre de cvars appliquées et les
+    /// avertissements (cvar inconnue, valeur du mauvais type).
+    pub fn apply_toml(&self, text: &str) -> Result<(usize, Vec<String>)> {
+        let table: toml::Table = toml::from_str(text)?;
+        let mut entries = Vec::new();
+        flatten_toml("", &table, &mut entries);
+
+        let mut applied = 0;
+        let mut warnings = Vec::new();
+        for (name, value) in entries {
+            match self.cvars.get(&name) {
+                Some(cvar) => match cvar.set_toml(&value) {
+                    Ok(()) => applied += 1,
+                    Err(e) => warnings.push(format!("{}: {}", name, e)),
+                },
+                None => warnings.push(format!("Unknown cvar '{}' ignored", name)),
+            }
+        }
+        Ok((applied, warnings))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = &asset_path(path);
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_toml_string()?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<(usize, Vec<String>)> {
+        let path = &asset_path(path);
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        self.apply_toml(&text)
+            .with_context(|| format!("Invalid cvar profile {}", path.display()))
+    }
+}
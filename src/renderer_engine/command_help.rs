@@ -0,0 +1,299 @@
+//! Aide de la console : `help` (commandes groupées par moteur, triées) et
+//! `help <commande>` (description complète, recherche floue si le nom est inexact).
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+use crate::renderer_engine::command_console::CommandRegistry;
+
+/// Groupes de `help`, dans l'ordre d'affichage
+pub const HELP_GROUPS: [&str; 5] = ["audio", "physic", "renderer", "sim", "internal"];
+/// Largeur (caractères) utilisée tant que la console n'a pas été affichée
+pub const DEFAULT_HELP_WIDTH: usize = 100;
+/// Valeurs d'argument citées au plus dans la syntaxe d'une commande
+const MAX_HINT_VALUES: usize = 6;
+/// Colonne maximale des descriptions dans la liste
+const MAX_DESCRIPTION_COLUMN: usize = 44;
+
+/// Commandes internes de la console : (nom, syntaxe, description)
+pub const INTERNAL_COMMAND_HELP: &[(&str, &str, &str)] = &[
+    ("clear", "", "Clear the console output"),
+    (
+        "help",
+        "[command]",
+        "List the commands, or describe one (closest name if misspelled)",
+    ),
+    (
+        "alias",
+        "[name [command...]]",
+        "List the aliases, show one, or define one",
+    ),
+    ("unalias", "<name>", "Remove an alias"),
+    (
+        "bind",
+        "<key> [command...]",
+        "Show or set the command run by a key",
+    ),
+    ("unbind", "<key>", "Remove a key bind"),
+    ("binds", "", "List the key binds"),
+    (
+        "exec",
+        "[-e] <path>",
+        "Run a command script (-e: stop at the first error)",
+    ),
+    (
+        "wait",
+        "<seconds>",
+        "Delay the rest of a ';' separated command line",
+    ),
+    (
+        "filter",
+        "<text|off>",
+        "Show only the output lines matching text",
+    ),
+    (
+        "transcript",
+        "save <path> | replay [--force] <path>",
+        "Save the console output, or re-run the commands it echoed",
+    ),
+    (
+        "stats.commands",
+        "[count]",
+        "List the most used console commands",
+    ),
+    (
+        "profiler.capture.start",
+        "[path]",
+        "Record every profiled scope into a Chrome trace (traces/ by default)",
+    ),
+    (
+        "profiler.capture.stop",
+        "",
+        "Stop the profiler capture and write the trace JSON",
+    ),
+    ("profiler.on", "", "Resume the profiler measurements"),
+    (
+        "profiler.off",
+        "",
+        "Stop every profiler measurement (near-zero overhead)",
+    ),
+    (
+        "profiler.only",
+        "<audio|physics|render|all>",
+        "Measure a single category of scopes and metrics",
+    ),
+    ("cvar.list", "[filter]", "List the console variables"),
+    (
+        "cvar.save",
+        "[path]",
+        "Save the console variables to a TOML profile",
+    ),
+    (
+        "cvar.load",
+        "[path]",
+        "Load console variables from a TOML profile",
+    ),
+    (
+        "cvar.reset",
+        "<name|all>",
+        "Reset console variables to their defaults",
+    ),
+];
+
+/// Noms des commandes internes
+pub fn internal_commands() -> impl Iterator<Item = &'static str> {
+    INTERNAL_COMMAND_HELP.iter().map(|(name, _, _)| *name)
+}
+
+/// Groupe de `name` : préfixe (`audio`, `physic`, `renderer`, `sim`) ou `internal`.
+pub fn command_group(name: &str) -> &'static str {
+    let prefix = name.split_once('.').map_or("", |(prefix, _)| prefix);
+    HELP_GROUPS[..HELP_GROUPS.len() - 1]
+        .iter()
+        .copied()
+        .find(|group| *group == prefix)
+        .unwrap_or("internal")
+}
+
+/// Syntaxe des arguments : déclarée, sinon déduite des valeurs d'autocomplétion
+/// (`<on|off>`) ou du type de la cvar.
+pub fn usage_hint(registry: &CommandRegistry, name: &str) -> String {
+    if let Some(usage) = registry.usage(name) {
+        return usage.to_string();
+    }
+    let positions = registry.get_arg_suggestions(name);
+    if !positions.is_empty() {
+        return positions
+            .iter()
+            .map(|values| match values.len() {
+                0 => "<value>".to_string(),
+                n if n > MAX_HINT_VALUES => {
+                    format!("<{}|...>", values[..MAX_HINT_VALUES].join("|"))
+                }
+                _ => format!("<{}>", values.join("|")),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+    registry
+        .cvars()
+        .type_hint(name)
+        .map(|hint| format!("[{}]", hint))
+        .unwrap_or_default()
+}
+
+/// Découpe `text` en lignes d'au plus `width` caractères (mots trop longs conservés).
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let needed = if line.is_empty() {
+            word.chars().count()
+        } else {
+            line.chars().count() + 1 + word.chars().count()
+        };
+        if needed > width && !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Toutes les commandes (moteurs, cvars, internes), triées par groupe puis par nom.
+pub fn grouped_commands(registry: &CommandRegistry) -> Vec<(&'static str, Vec<String>)> {
+    let mut names = registry.get_commands();
+    names.extend(internal_commands().map(String::from));
+    names.sort();
+    names.dedup();
+    HELP_GROUPS
+        .iter()
+        .map(|&group| {
+            let members: Vec<String> = names
+                .iter()
+                .filter(|name| command_group(name) == group)
+                .cloned()
+                .collect();
+            (group, members)
+        })
+        .filter(|(_, members)| !members.is_empty())
+        .collect()
+}
+
+/// Liste de `help` : un bloc par groupe, une commande par ligne (syntaxe puis
+/// description alignée), ajustée à `width` caractères.
+pub fn format_help(registry: &CommandRegistry, width: usize) -> String {
+    let groups = grouped_commands(registry);
+    let entries: Vec<(&str, Vec<(String, String)>)> = groups
+        .iter()
+        .map(|(group, names)| {
+            let rows = names
+                .iter()
+                .map(|name| {
+                    let hint = usage_hint(registry, name);
+                    let entry = if hint.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{} {}", name, hint)
+                    };
+                    (entry, registry.description(name).unwrap_or("").to_string())
+                })
+                .collect();
+            (*group, rows)
+        })
+        .collect();
+
+    let longest = entries
+        .iter()
+        .flat_map(|(_, rows)| rows.iter().map(|(entry, _)| entry.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let column = (longest + 4).min(MAX_DESCRIPTION_COLUMN).min(width / 2);
+    let description_width = width.saturating_sub(column).max(20);
+
+    let mut out = Vec::new();
+    for (group, rows) in entries {
+        out.push(format!("{}:", group));
+        for (entry, description) in rows {
+            let mut lines = wrap_text(&description, description_width).into_iter();
+            let entry = format!("  {}", entry);
+            match lines.next() {
+                Some(first) if entry.chars().count() < column => {
+                    out.push(format!("{:<column$}{}", entry, first));
+                }
+                Some(first) => {
+                    out.push(entry);
+                    out.push(format!("{:column$}{}", "", first));
+                }
+                None => out.push(entry),
+            }
+            out.extend(lines.map(|line| format!("{:column$}{}", "", line)));
+        }
+    }
+    out.join("\n")
+}
+
+/// Commande désignée par `query` : nom exact, sinon le plus proche (recherche floue).
+pub fn find_command(registry: &CommandRegistry, query: &str) -> Option<String> {
+    let query = query.trim();
+    let mut names = registry.get_commands();
+    names.extend(internal_commands().map(String::from));
+    if names.iter().any(|name| name == query) {
+        return Some(query.to_string());
+    }
+    let matcher = SkimMatcherV2::default();
+    names.sort();
+    names
+        .into_iter()
+        .filter_map(|name| matcher.fuzzy_match(&name, query).map(|score| (score, name)))
+        // Meilleur score ; à égalité, le nom le plus court (puis l'ordre alphabétique)
+        .max_by(|(sa, a), (sb, b)| sa.cmp(sb).then(b.len().cmp(&a.len())).then(b.cmp(a)))
+        .map(|(_, name)| name)
+}
+
+/// Aide de `help <query>` : syntaxe, description, valeurs proposées et, pour une
+/// cvar, sa valeur courante.
+pub fn format_command_help(registry: &CommandRegistry, query: &str) -> String {
+    let query = query.trim();
+    if let Some(command) = registry.aliases().get(query) {
+        return format!("{} is an alias for: {}", query, command);
+    }
+    let Some(name) = find_command(registry, query) else {
+        return format!("Unknown command '{}'. Type 'help' for the list.", query);
+    };
+
+    let mut out = Vec::new();
+    if name != query {
+        out.push(format!("No command '{}', closest match:", query));
+    }
+    let hint = usage_hint(registry, &name);
+    out.push(if hint.is_empty() {
+        name.clone()
+    } else {
+        format!("{} {}", name, hint)
+    });
+    if let Some(description) = registry.description(&name) {
+        out.extend(
+            wrap_text(description, DEFAULT_HELP_WIDTH - 2)
+                .into_iter()
+                .map(|line| format!("  {}", line)),
+        );
+    }
+    for (i, values) in registry.get_arg_suggestions(&name).iter().enumerate() {
+        if !values.is_empty() {
+            out.push(format!("  Argument {}: {}", i + 1, values.join(", ")));
+        }
+    }
+    if let Some(details) = registry.cvars().describe(&name) {
+        out.push(format!("  {}", details));
+    }
+    out.push(format!("  Group: {}", command_group(&name)));
+    out.join("\n")
+}
@@ -0,0 +1,127 @@
+//! Scripts de commandes console (`exec [-e] <fichier>`, `--exec <fichier>`).
+//!
+//! Un script est un fichier texte : une commande par ligne, lignes vides et
+//! commentaires `#` ignorés. Chaque commande passe par `CommandRegistry::execute`
+//! (alias et `exec` imbriqués compris).
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Profondeur maximale d'`exec` imbriqués (un script qui s'appelle lui-même s'arrête là)
+pub const MAX_EXEC_DEPTH: usize = 4;
+
+/// Début de sortie signalant l'échec d'une commande (conventions des commandes existantes)
+const FAILURE_PREFIXES: &[&str] = &[
+    "Error",
+    "Erreur",
+    "Unknown",
+    "Usage:",
+    "Invalid",
+    "Failed",
+    "Cannot",
+    "Impossible",
+];
+
+/// La sortie d'une commande signale-t-elle un échec ?
+pub fn command_failed(output: &str) -> bool {
+    let output = output.trim_start();
+    FAILURE_PREFIXES.iter().any(|p| output.starts_with(p)) || output.contains("not found")
+}
+
+/// Commandes d'un script : (numéro de ligne, commande), sans lignes vides ni commentaires.
+pub fn script_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Arguments de `exec` : `[-e] <fichier>` (`-e` : arrêt à la première erreur).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecArgs {
+    pub path: PathBuf,
+    pub abort_on_error: bool,
+}
+
+impl ExecArgs {
+    pub fn parse(args: &str) -> Option<Self> {
+        let args = args.trim();
+        let (abort_on_error, path) = match args.strip_prefix("-e") {
+            Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+                (true, rest.trim())
+            }
+            _ => (false, args),
+        };
+        (!path.is_empty()).then(|| Self {
+            path: PathBuf::from(path),
+            abort_on_error,
+        })
+    }
+}
+
+/// Résultat de l'exécution d'un script.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptReport {
+    pub path: PathBuf,
+    /// Commandes (`> cmd`) et leurs sorties, dans l'ordre
+    pub output: Vec<String>,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Arrêt sur erreur (`-e`) avant la fin du script
+    pub aborted: bool,
+}
+
+impl ScriptReport {
+    /// `"exec <path>: 3 succeeded, 1 failed"` (+ `", aborted"`)
+    pub fn summary(&self) -> String {
+        format!(
+            "exec {}: {} succeeded, {} failed{}",
+            self.path.display(),
+            self.succeeded,
+            self.failed,
+            if self.aborted { ", aborted" } else { "" }
+        )
+    }
+
+    /// Sortie console complète : commandes, résultats puis bilan
+    pub fn format(&self) -> String {
+        let mut lines = self.output.clone();
+        lines.push(self.summary());
+        lines.join("\n")
+    }
+}
+
+/// Exécute le script `path` ligne par ligne avec `execute`, qui retourne la
+/// sortie de la commande et son échec éventuel.
+pub fn run_script(
+    path: &Path,
+    abort_on_error: bool,
+    mut execute: impl FnMut(&str) -> (String, bool),
+) -> Result<ScriptReport> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Cannot read script {}", path.display()))?;
+    let mut report = ScriptReport {
+        path: path.to_path_buf(),
+        ..Default::default()
+    };
+    for (line_number, command) in script_lines(&text) {
+        let (result, failed) = execute(command);
+        report.output.push(format!("> {}", command));
+        if !result.is_empty() {
+            report.output.push(result);
+        }
+        if failed {
+            report.failed += 1;
+            if abort_on_error {
+                report
+                    .output
+                    .push(format!("Aborted at {}:{}", path.display(), line_number));
+                report.aborted = true;
+                break;
+            }
+        } else {
+            report.succeeded += 1;
+        }
+    }
+    Ok(report)
+}
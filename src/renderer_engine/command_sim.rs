@@ -0,0 +1,194 @@
+//! Commandes console de la simulation (`sim.*`) : elles agissent à la fois sur les
+//! deux moteurs et sur l'état du renderer.
+//!
+//! Les commandes `audio.*` / `physic.*` ne reçoivent qu'un moteur et les commandes
+//! `renderer.*` que l'état qu'elles capturent ; une commande `sim.*` reçoit un
+//! [`SimContext`] qui réunit les deux moteurs et le [`SimState`] partagé avec le
+//! renderer (config, caméra, pause, vitesse).
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::audio_engine::AudioEngine;
+use crate::demo_director::DemoDirector;
+use crate::physic_engine::PhysicEngine;
+use crate::renderer_engine::camera::Camera2D;
+use crate::renderer_engine::command_console::CommandRegistry;
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::renderer::RendererShared;
+use crate::sim_clock::{SimSpeed, SIM_SPEED_MAX, SIM_SPEED_MIN};
+use crate::utils::memory_stats::memory_stats;
+
+/// État du renderer accessible aux commandes `sim.*` (thread principal uniquement).
+///
+/// Par défaut (sans renderer, ex: tests ou `--exec` avant la boucle), l'état est
+/// local au registre.
+#[derive(Debug, Clone, Default)]
+pub struct SimState {
+    pub config: Rc<RefCell<RendererConfig>>,
+    pub camera: Rc<RefCell<Camera2D>>,
+    /// Physique figée, le rendu continue (touche `pause_sim`)
+    pub paused: Rc<Cell<bool>>,
+    /// Vitesse de simulation (touches `speed_down` / `speed_up`)
+    pub sim_speed: Rc<Cell<SimSpeed>>,
+    /// Mode démo (`sim.demo`)
+    pub demo: Rc<RefCell<DemoDirector>>,
+}
+
+impl From<&RendererShared> for SimState {
+    fn from(shared: &RendererShared) -> Self {
+        Self {
+            config: shared.config.clone(),
+            camera: shared.camera.clone(),
+            paused: shared.paused.clone(),
+            sim_speed: shared.sim_speed.clone(),
+            demo: shared.demo.clone(),
+        }
+    }
+}
+
+/// Ce que reçoit une commande `sim.*` : les deux moteurs et l'état du renderer.
+pub struct SimContext<'a> {
+    pub audio: &'a mut dyn AudioEngine,
+    pub physic: &'a mut dyn PhysicEngine,
+    pub state: &'a SimState,
+}
+
+/// Commandes `sim.*` : (nom, syntaxe, description)
+pub const SIM_COMMAND_HELP: &[(&str, &str, &str)] = &[
+    (
+        "sim.reset",
+        "",
+        "Remove every rocket and particle, stop the sounds and reset the camera",
+    ),
+    (
+        "sim.pause",
+        "[on|off]",
+        "Freeze the physics (toggle without argument); rendering goes on",
+    ),
+    (
+        "sim.speed",
+        "[factor]",
+        "Show or set the simulation speed (0.1..4.0): slow motion below 1",
+    ),
+    (
+        "sim.demo",
+        "[on|off]",
+        "Attract mode: palette, shape, launch density and bloom change every 20-60 s",
+    ),
+    (
+        "sim.info",
+        "",
+        "Show the simulation state: pause, rockets, particles, voices, camera",
+    ),
+    (
+        "sim.memory",
+        "",
+        "Show the process RSS and the big allocations of the engines",
+    ),
+];
+
+/// Enregistre `sim.reset`, `sim.pause`, `sim.speed`, `sim.demo`, `sim.info` et
+/// `sim.memory`.
+pub fn register_sim_commands(registry: &mut CommandRegistry) {
+    registry.register_for_simulator("sim.reset", |ctx: &mut SimContext, _args| {
+        let rockets = ctx.physic.get_stats().active_rockets;
+        ctx.physic.clear_particles();
+        ctx.audio.stop_all_voices();
+        ctx.state.camera.borrow_mut().reset();
+        format!("Simulation reset ({} rocket(s) removed)", rockets)
+    });
+
+    registry.register_for_simulator("sim.pause", |ctx: &mut SimContext, args| {
+        let paused = match args.split_whitespace().nth(1) {
+            None => !ctx.state.paused.get(),
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: sim.pause [on|off]".to_string(),
+        };
+        ctx.state.paused.set(paused);
+        if paused {
+            "Simulation paused".to_string()
+        } else {
+            "Simulation resumed".to_string()
+        }
+    });
+
+    registry.register_for_simulator("sim.speed", |ctx: &mut SimContext, args| {
+        let Some(arg) = args.split_whitespace().nth(1) else {
+            return format!("Simulation speed: x{}", ctx.state.sim_speed.get().value());
+        };
+        let Ok(requested) = arg.parse::<f32>() else {
+            return "Usage: sim.speed [factor]".to_string();
+        };
+        let speed = SimSpeed::new(requested);
+        ctx.state.sim_speed.set(speed);
+        if speed.value() != requested {
+            format!(
+                "Simulation speed: x{} (clamped to {}..{})",
+                speed.value(),
+                SIM_SPEED_MIN,
+                SIM_SPEED_MAX
+            )
+        } else {
+            format!("Simulation speed: x{}", speed.value())
+        }
+    });
+
+    registry.register_for_simulator("sim.demo", |ctx: &mut SimContext, args| {
+        let mut demo = ctx.state.demo.borrow_mut();
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !demo.is_enabled(),
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: sim.demo [on|off]".to_string(),
+        };
+        demo.set_enabled(enabled, ctx.physic, &mut ctx.state.config.borrow_mut());
+        format!("Demo mode: {}", if enabled { "on" } else { "off" })
+    });
+
+    registry.register_for_simulator("sim.info", |ctx: &mut SimContext, _args| {
+        let stats = ctx.physic.get_stats();
+        let camera = ctx.state.camera.borrow();
+        let (x, y) = ctx.audio.get_listener_position();
+        [
+            format!(
+                "State: {}",
+                if ctx.state.paused.get() {
+                    "paused"
+                } else {
+                    "running"
+                }
+            ),
+            format!("Speed: x{}", ctx.state.sim_speed.get().value()),
+            format!(
+                "Rockets: {} / {}",
+                stats.active_rockets,
+                ctx.physic.get_config().max_rockets
+            ),
+            format!(
+                "Particles: {} explosion, {} trail",
+                stats.active_particles.explosions, stats.active_particles.trails
+            ),
+            format!("Explosion shape: {}", ctx.physic.explosion_shape_name()),
+            format!("Audio voices: {}", ctx.audio.active_voices()),
+            format!("Listener: ({:.0}, {:.0})", x, y),
+            format!(
+                "Camera: zoom {:.2}, center ({:.0}, {:.0})",
+                camera.zoom, camera.center.x, camera.center.y
+            ),
+        ]
+        .join("\n")
+    });
+
+    registry.register_for_simulator("sim.memory", |_ctx: &mut SimContext, _args| {
+        memory_stats().snapshot().format()
+    });
+
+    for (name, usage, description) in SIM_COMMAND_HELP {
+        registry.register_usage(name, usage);
+        registry.register_description(name, description);
+    }
+    registry.register_args("sim.pause", &[&["on", "off"]]);
+    registry.register_args("sim.demo", &[&["on", "off"]]);
+}
@@ -0,0 +1,112 @@
+//! Statistiques d'utilisation des commandes console (`stats.commands`).
+//!
+//! Chaque commande exécutée depuis la console (saisie, bind, console distante)
+//! incrémente son compteur, persisté à côté des alias et des binds. L'autocomplétion
+//! combine ce compteur au score flou : les commandes fréquentes remontent.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::assets::asset_path;
+
+/// Fichier des compteurs persistés (à côté de `aliases.toml` et `binds.toml`)
+pub const COMMAND_STATS_PATH: &str = "assets/config/command_stats.toml";
+/// Poids par défaut de la fréquence dans le classement (`console_usage_weight`)
+pub const DEFAULT_USAGE_WEIGHT: f32 = 8.0;
+/// Commandes listées par `stats.commands` sans argument
+pub const DEFAULT_TOP_COMMANDS: usize = 10;
+
+/// Contenu de `command_stats.toml` : table `[commands]` (commande → exécutions)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct StatsFile {
+    commands: BTreeMap<String, u64>,
+}
+
+/// Score de l'autocomplétion : score flou + `weight · ln(1 + uses)`.
+///
+/// Le bonus logarithmique départage les scores proches sans qu'un usage intensif
+/// l'emporte sur une correspondance nettement meilleure.
+pub fn ranked_score(fuzzy_score: i64, uses: u64, weight: f32) -> i64 {
+    let bonus = weight.max(0.0) * (1.0 + uses as f32).ln();
+    fuzzy_score + bonus.round() as i64
+}
+
+/// Nombre d'exécutions par commande.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandStats {
+    counts: BTreeMap<String, u64>,
+}
+
+impl CommandStats {
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Exécutions de `name`
+    pub fn count(&self, name: &str) -> u64 {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+
+    pub fn record(&mut self, name: &str) {
+        *self.counts.entry(name.to_string()).or_default() += 1;
+    }
+
+    /// Les `n` commandes les plus utilisées (à égalité, par ordre alphabétique)
+    pub fn top(&self, n: usize) -> Vec<(&str, u64)> {
+        let mut top: Vec<(&str, u64)> = self
+            .counts
+            .iter()
+            .map(|(name, count)| (name.as_str(), *count))
+            .collect();
+        top.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        top.truncate(n);
+        top
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: StatsFile = toml::from_str(text)?;
+        Ok(Self {
+            counts: file.commands,
+        })
+    }
+
+    /// Charge `path` ; fichier absent : aucun compteur.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = &asset_path(path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid command stats file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = &asset_path(path);
+        let file = StatsFile {
+            commands: self.counts.clone(),
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(&file)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Liste de `stats.commands` : les `n` commandes les plus utilisées
+    pub fn format(&self, n: usize) -> String {
+        if self.is_empty() {
+            return "No command used yet".to_string();
+        }
+        let top = self.top(n);
+        let width = top.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        let mut out = String::from("Most used commands:");
+        for (name, count) in top {
+            out.push_str(&format!("\n  {:<width$}  {}", name, count));
+        }
+        out
+    }
+}
@@ -0,0 +1,205 @@
+//! Transcription de la console (`transcript save <fichier>`,
+//! `transcript replay [--force] <fichier>`).
+//!
+//! `save` écrit la sortie conservée : horodatage, gravité puis texte, les lignes
+//! suivantes d'un texte multiligne étant indentées. `replay` ré-exécute les seules
+//! commandes saisies (`> cmd`) : la transcription se rejoue comme un script `exec`.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::renderer_engine::command_console::{parse_wait, split_commands};
+use crate::renderer_engine::console_output::{OutputLine, Severity};
+
+pub const TRANSCRIPT_USAGE: &str =
+    "Usage: transcript save <path> | transcript replay [--force] <path>";
+
+/// Drapeau autorisant le rejeu des commandes destructrices
+pub const FORCE_FLAG: &str = "--force";
+
+/// Commandes internes destructrices, rejouées seulement avec `--force`
+pub const GUARDED_COMMANDS: &[&str] = &["clear"];
+
+/// Préfixe des commandes saisies dans la sortie
+const ECHO_PREFIX: &str = "> ";
+
+/// Arguments de `transcript`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptArgs {
+    Save(PathBuf),
+    Replay { path: PathBuf, force: bool },
+}
+
+impl TranscriptArgs {
+    pub fn parse(args: &str) -> Option<Self> {
+        let (action, rest) = args.trim().split_once(char::is_whitespace)?;
+        let rest = rest.trim();
+        match action {
+            "save" => Some(Self::Save(PathBuf::from(rest))),
+            "replay" => {
+                let (force, path) = match rest.strip_prefix(FORCE_FLAG) {
+                    Some(path) if path.is_empty() || path.starts_with(char::is_whitespace) => {
+                        (true, path.trim())
+                    }
+                    _ => (false, rest),
+                };
+                (!path.is_empty()).then(|| Self::Replay {
+                    path: PathBuf::from(path),
+                    force,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Ligne de transcription : `"[mm:ss] INFO  texte"`
+pub fn format_line(line: &OutputLine) -> String {
+    let header = format!("{} {:<5} ", line.format_timestamp(), line.severity.label());
+    let indent = " ".repeat(header.chars().count());
+    let mut text = line.text.lines();
+    let mut formatted = format!("{}{}", header, text.next().unwrap_or(""));
+    for continuation in text {
+        formatted.push('\n');
+        formatted.push_str(&indent);
+        formatted.push_str(continuation);
+    }
+    formatted
+}
+
+/// Transcription complète, de la plus ancienne ligne à la plus récente
+pub fn format_transcript<'a>(lines: impl IntoIterator<Item = &'a OutputLine>) -> String {
+    let mut text: String = lines
+        .into_iter()
+        .map(format_line)
+        .collect::<Vec<_>>()
+        .join("\n");
+    text.push('\n');
+    text
+}
+
+/// Écrit la transcription de `lines` dans `path` ; retourne le nombre de lignes.
+pub fn save_transcript<'a>(
+    path: &Path,
+    lines: impl IntoIterator<Item = &'a OutputLine>,
+) -> Result<usize> {
+    let lines: Vec<&OutputLine> = lines.into_iter().collect();
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, format_transcript(lines.iter().copied()))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(lines.len())
+}
+
+/// Commande saisie d'une ligne de transcription : gravité `ECHO` si la ligne est
+/// horodatée, préfixe `> ` seul sinon (transcription écrite à la main).
+pub fn echoed_command(line: &str) -> Option<&str> {
+    let text = match line.strip_prefix('[').and_then(|l| l.split_once("] ")) {
+        Some((_, rest)) => {
+            let (label, text) = rest.split_once(' ')?;
+            if label != Severity::Echo.label() {
+                return None;
+            }
+            text.trim_start()
+        }
+        None => line,
+    };
+    let command = text.strip_prefix(ECHO_PREFIX)?.trim();
+    (!command.is_empty()).then_some(command)
+}
+
+/// Commandes à rejouer, dans l'ordre. Seule la partie immédiate d'une ligne est
+/// gardée : les commandes différées par `wait` ont leur propre écho, à leur échéance.
+pub fn replay_commands(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(echoed_command)
+        .flat_map(|line| {
+            split_commands(line)
+                .into_iter()
+                .take_while(|command| parse_wait(command).is_none())
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Raison d'ignorer `command` au rejeu : `transcript` imbriqué (jamais rejoué) ou
+/// commande destructrice sans `--force`.
+pub fn skip_reason(command: &str, force: bool) -> Option<String> {
+    let name = command.split_whitespace().next().unwrap_or("");
+    if name == "transcript" {
+        Some(format!("Skipped '{}': nested transcript", command))
+    } else if !force && GUARDED_COMMANDS.contains(&name) {
+        Some(format!(
+            "Skipped '{}': destructive, use {}",
+            command, FORCE_FLAG
+        ))
+    } else {
+        None
+    }
+}
+
+/// Résultat d'un rejeu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    pub path: PathBuf,
+    /// Commandes (`> cmd`) et leurs sorties, dans l'ordre
+    pub output: Vec<String>,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+impl ReplayReport {
+    /// `"transcript replay <path>: 3 succeeded, 1 failed, 1 skipped"`
+    pub fn summary(&self) -> String {
+        format!(
+            "transcript replay {}: {} succeeded, {} failed, {} skipped",
+            self.path.display(),
+            self.succeeded,
+            self.failed,
+            self.skipped
+        )
+    }
+
+    /// Sortie console complète : commandes, résultats puis bilan
+    pub fn format(&self) -> String {
+        let mut lines = self.output.clone();
+        lines.push(self.summary());
+        lines.join("\n")
+    }
+}
+
+/// Rejoue les commandes saisies de la transcription `path` avec `execute`, qui
+/// retourne la sortie de la commande et son échec éventuel.
+pub fn replay_transcript(
+    path: &Path,
+    force: bool,
+    mut execute: impl FnMut(&str) -> (String, bool),
+) -> Result<ReplayReport> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Cannot read transcript {}", path.display()))?;
+    let mut report = ReplayReport {
+        path: path.to_path_buf(),
+        ..Default::default()
+    };
+    for command in replay_commands(&text) {
+        if let Some(reason) = skip_reason(&command, force) {
+            report.output.push(reason);
+            report.skipped += 1;
+            continue;
+        }
+        let (result, failed) = execute(&command);
+        report.output.push(format!("{}{}", ECHO_PREFIX, command));
+        if !result.is_empty() {
+            report.output.push(result);
+        }
+        if failed {
+            report.failed += 1;
+        } else {
+            report.succeeded += 1;
+        }
+    }
+    Ok(report)
+}
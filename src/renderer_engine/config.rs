@@ -0,0 +1,709 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::config_manager::{ConfigSection, Reloadable, Violations};
+use crate::demo_director::DemoConfig;
+use crate::physic_engine::ParticleType;
+use crate::renderer_engine::audio_reactive::audio_reactive_intensity;
+use crate::renderer_engine::background::BackgroundConfig;
+use crate::renderer_engine::camera::CameraConfig;
+use crate::renderer_engine::command_stats::DEFAULT_USAGE_WEIGHT;
+use crate::renderer_engine::console_output::DEFAULT_CONSOLE_MAX_LINES;
+use crate::renderer_engine::console_server::RemoteConsoleConfig;
+use crate::renderer_engine::tonemap::{ToneMappingMode, MAX_COMPARISON_CELLS};
+use crate::renderer_engine::utils::adaptative_sampler::DEFAULT_SPIKE_FACTOR;
+use crate::renderer_engine::utils::frame_limiter::frame_budget;
+use crate::utils::embedded_assets::read_asset_to_string;
+use crate::utils::human_bytes::ByteSize;
+use crate::utils::log_sink::DEFAULT_CONSOLE_LOG_FILTER;
+
+/// Chemin par défaut de la config du renderer
+pub const RENDERER_CONFIG_PATH: &str = "assets/config/renderer.toml";
+
+/// Budget par défaut du buffer GPU des particules
+pub const DEFAULT_MAX_GPU_BUFFER: ByteSize = ByteSize::mib(256);
+
+/// Plage admise pour `bloom_threshold` (luminance)
+pub const BLOOM_THRESHOLD_RANGE: (f32, f32) = (0.0, 2.0);
+/// Plage admise pour `bloom_soft_knee` (fraction du seuil)
+pub const BLOOM_SOFT_KNEE_RANGE: (f32, f32) = (0.0, 1.0);
+/// Plage admise pour `bloom_intensity`
+pub const BLOOM_INTENSITY_RANGE: (f32, f32) = (0.0, 10.0);
+/// Plage admise pour `bloom_blur_passes`
+pub const BLOOM_BLUR_PASSES_RANGE: (u32, u32) = (1, 16);
+/// Plage admise pour `render_scale` (fraction de la résolution de la fenêtre)
+pub const RENDER_SCALE_RANGE: (f32, f32) = (0.25, 1.0);
+/// Plage admise pour `lens_dirt_strength`
+pub const LENS_DIRT_STRENGTH_RANGE: (f32, f32) = (0.0, 1.0);
+/// Plage admise pour `output_gamma`
+pub const OUTPUT_GAMMA_RANGE: (f32, f32) = (1.0, 3.0);
+/// Plage admise pour `bloom_audio_reactivity`
+pub const BLOOM_AUDIO_REACTIVITY_RANGE: (f32, f32) = (0.0, 10.0);
+/// Plage admise pour `bloom_audio_attack` et `bloom_audio_release` (s)
+pub const BLOOM_AUDIO_SMOOTHING_RANGE: (f32, f32) = (0.0, 5.0);
+/// Plage admise pour `flash_intensity`
+pub const FLASH_INTENSITY_RANGE: (f32, f32) = (0.0, 10.0);
+/// Plage admise pour `flash_duration` (s)
+pub const FLASH_DURATION_RANGE: (f32, f32) = (0.01, 2.0);
+/// Plage admise pour `softness` (fraction du rayon du sprite)
+pub const SOFTNESS_RANGE: (f32, f32) = (0.0, 1.0);
+
+/// Réglages du rendu, modifiables à chaud (console `renderer.*`, touche R).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RendererConfig {
+    /// Synchronisation verticale (présentation calée sur le rafraîchissement de l'écran)
+    pub vsync: bool,
+    /// Plafond d'images/s appliqué côté CPU quand la v-sync est désactivée (absent : aucun)
+    pub max_fps: Option<u32>,
+    /// Écran utilisé par le plein écran (F11), index de `renderer.window.monitors`
+    pub fullscreen_monitor: usize,
+    /// Plein écran fenêtré sans bordure (pas de changement de mode vidéo)
+    pub fullscreen_borderless: bool,
+    /// Résolution interne de la scène (0.25..1.0), agrandie à la taille de la fenêtre
+    pub render_scale: f32,
+    /// Étirement des têtes de fusée le long de leur vitesse (anti-stroboscope)
+    pub motion_blur_enabled: bool,
+    /// Longueur de la traînée de flou, en fraction du déplacement d'une frame (0..1)
+    pub motion_blur_strength: f32,
+    /// Export vidéo via ffmpeg (table `[recording]`)
+    pub recording: RecordingConfig,
+    /// Zoom / déplacement de la vue (table `[camera]`)
+    pub camera: CameraConfig,
+    /// Ciel en dégradé et champ d'étoiles (table `[background]`)
+    pub background: BackgroundConfig,
+    /// Rendu par type de particule (tables `[particles.<type>]`)
+    pub particles: ParticlesConfig,
+    /// Tri du fond vers l'avant des types en mélange alpha (inutile en additif)
+    pub depth_sort_enabled: bool,
+    /// Contexte de debug OpenGL + vérifications `gl_check!` (pris en compte au démarrage)
+    pub gl_debug: bool,
+    /// Halo lumineux autour des zones brillantes (passe `BloomPass`)
+    pub bloom_enabled: bool,
+    /// Luminance à partir de laquelle un pixel contribue au bloom
+    pub bloom_threshold: f32,
+    /// Largeur de la transition douce sous le seuil, en fraction du seuil (0 = coupure nette)
+    pub bloom_soft_knee: f32,
+    /// Poids du halo lors de la composition
+    pub bloom_intensity: f32,
+    /// Nombre d'itérations du flou (horizontal + vertical)
+    pub bloom_blur_passes: u32,
+    /// Intensité du bloom modulée par le niveau de sortie audio (cf. `audio_reactive`)
+    pub bloom_audio_reactive: bool,
+    /// Intensité ajoutée pour un niveau RMS de 1
+    pub bloom_audio_reactivity: f32,
+    /// Constante de temps (s) du lissage quand le niveau monte
+    pub bloom_audio_attack: f32,
+    /// Constante de temps (s) du lissage quand le niveau descend
+    pub bloom_audio_release: f32,
+    /// Texture (niveaux de gris) des salissures d'objectif révélées par le bloom
+    pub lens_dirt_texture: String,
+    /// Poids des salissures dans la composition (0 = désactivé)
+    pub lens_dirt_strength: f32,
+    /// Adaptation automatique de l'exposition à la luminance moyenne de la scène
+    pub auto_exposure_enabled: bool,
+    /// Vitesse d'adaptation (1/s) : plus grand = l'œil s'adapte plus vite
+    pub auto_exposure_speed: f32,
+    /// Luminance moyenne visée après exposition (gris moyen)
+    pub auto_exposure_key: f32,
+    /// Exposition minimale (scènes très lumineuses, ex. bouquet final)
+    pub auto_exposure_min: f32,
+    /// Exposition maximale (ciel presque noir)
+    pub auto_exposure_max: f32,
+    /// Éclair de lumière sur toute la scène à chaque détonation (cf. `flash`)
+    pub flash_enabled: bool,
+    /// Intensité d'un éclair à la détonation
+    pub flash_intensity: f32,
+    /// Durée (s) de la décroissance exponentielle d'un éclair
+    pub flash_duration: f32,
+    /// Anti-aliasing FXAA en fin de chaîne (aucun coût si désactivé)
+    pub fxaa_enabled: bool,
+    /// Opérateur de tone mapping de la composition HDR
+    pub tonemapping: ToneMappingMode,
+    /// Grille comparant plusieurs opérateurs côte à côte
+    pub tonemapping_compare: bool,
+    /// Opérateurs de la grille, dans l'ordre d'affichage (2 à 6)
+    pub tonemapping_compare_modes: Vec<ToneMappingMode>,
+    /// Gamma d'encodage appliqué après le tone mapping (1.0 : sortie linéaire)
+    pub output_gamma: f32,
+    /// Framebuffer sRGB demandé à la création de la fenêtre : l'encodage est alors
+    /// fait par le GPU (repli sur `output_gamma` si le driver ne le fournit pas)
+    pub srgb_framebuffer: bool,
+    /// Grille comparant la sortie sans correction et avec `output_gamma`
+    pub gamma_compare: bool,
+    /// Rendu des trails : points séparés ou ruban continu par fusée
+    pub trail_style: TrailStyle,
+    /// Largeur (px) du ruban à la tête de la fusée, effilé jusqu'à 0 vers la queue
+    pub trail_ribbon_width: f32,
+    /// Lignes conservées par la sortie de la console (les plus anciennes sont évincées)
+    pub console_max_lines: usize,
+    /// Journaux recopiés dans la console, par module (`"warn,fireworks_sim=info"`)
+    pub console_log_filter: String,
+    /// Poids de la fréquence d'utilisation dans l'autocomplétion (0 : score flou seul)
+    pub console_usage_weight: f32,
+    /// Seuil des pics de la timeline périodique (durée de frame > facteur × médiane)
+    pub sampler_spike_factor: f32,
+    /// Taille maximale du buffer GPU des particules (`"256 MiB"`) : au-delà, la
+    /// capacité est plafonnée et les particules en trop ne sont pas dessinées
+    pub max_gpu_buffer: ByteSize,
+    /// Console distante sur socket TCP locale (table `[remote_console]`)
+    pub remote_console: RemoteConsoleConfig,
+    /// Bornes du mode démo (table `[demo]`, cf. `sim.demo`)
+    pub demo: DemoConfig,
+    /// Dernier préréglage de qualité appliqué (`None` : réglages à la main)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<QualityPreset>,
+}
+
+impl Default for RendererConfig {
+    fn default() -> Self {
+        Self {
+            vsync: true,
+            max_fps: None,
+            fullscreen_monitor: 0,
+            fullscreen_borderless: false,
+            render_scale: 1.0,
+            motion_blur_enabled: false,
+            motion_blur_strength: 0.5,
+            recording: RecordingConfig::default(),
+            camera: CameraConfig::default(),
+            background: BackgroundConfig::default(),
+            particles: ParticlesConfig::default(),
+            depth_sort_enabled: false,
+            gl_debug: false,
+            bloom_enabled: false,
+            bloom_threshold: 0.75,
+            bloom_soft_knee: 0.5,
+            bloom_intensity: 0.8,
+            bloom_blur_passes: 3,
+            bloom_audio_reactive: false,
+            bloom_audio_reactivity: 1.5,
+            bloom_audio_attack: 0.05,
+            bloom_audio_release: 0.6,
+            lens_dirt_texture:
+                "assets/textures/kenney_particle-pack/PNG (Black background)/dirt_01.png"
+                    .to_string(),
+            lens_dirt_strength: 0.0,
+            auto_exposure_enabled: false,
+            auto_exposure_speed: 1.5,
+            auto_exposure_key: 0.18,
+            auto_exposure_min: 0.5,
+            auto_exposure_max: 2.0,
+            flash_enabled: true,
+            flash_intensity: 0.6,
+            flash_duration: 0.15,
+            fxaa_enabled: false,
+            tonemapping: ToneMappingMode::Linear,
+            tonemapping_compare: false,
+            tonemapping_compare_modes: ToneMappingMode::ALL.to_vec(),
+            output_gamma: 2.2,
+            srgb_framebuffer: false,
+            gamma_compare: false,
+            trail_style: TrailStyle::Dots,
+            trail_ribbon_width: 3.0,
+            console_max_lines: DEFAULT_CONSOLE_MAX_LINES,
+            console_log_filter: DEFAULT_CONSOLE_LOG_FILTER.to_string(),
+            console_usage_weight: DEFAULT_USAGE_WEIGHT,
+            sampler_spike_factor: DEFAULT_SPIKE_FACTOR,
+            max_gpu_buffer: DEFAULT_MAX_GPU_BUFFER,
+            remote_console: RemoteConsoleConfig::default(),
+            demo: DemoConfig::default(),
+            preset: None,
+        }
+    }
+}
+
+/// Préréglage de qualité : fixe d'un coup le coût du post-process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QualityPreset {
+    Low,
+    Medium,
+    High,
+    Ultra,
+}
+
+/// Réglages fixés par un `QualityPreset` (mêmes clés que `RendererConfig`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct QualitySettings {
+    pub bloom_enabled: bool,
+    pub bloom_blur_passes: u32,
+    pub render_scale: f32,
+    pub fxaa_enabled: bool,
+}
+
+impl QualityPreset {
+    pub const ALL: [QualityPreset; 4] = [
+        QualityPreset::Low,
+        QualityPreset::Medium,
+        QualityPreset::High,
+        QualityPreset::Ultra,
+    ];
+
+    /// Nom utilisé par la config et la console
+    pub fn name(&self) -> &'static str {
+        match self {
+            QualityPreset::Low => "low",
+            QualityPreset::Medium => "medium",
+            QualityPreset::High => "high",
+            QualityPreset::Ultra => "ultra",
+        }
+    }
+
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn settings(&self) -> QualitySettings {
+        let (bloom_enabled, bloom_blur_passes, render_scale, fxaa_enabled) = match self {
+            QualityPreset::Low => (false, 1, 0.5, false),
+            QualityPreset::Medium => (true, 2, 0.75, false),
+            QualityPreset::High => (true, 3, 1.0, true),
+            QualityPreset::Ultra => (true, 6, 1.0, true),
+        };
+        QualitySettings {
+            bloom_enabled,
+            bloom_blur_passes,
+            render_scale,
+            fxaa_enabled,
+        }
+    }
+}
+
+impl QualitySettings {
+    /// Résumé affiché par `renderer.preset`
+    pub fn describe(&self) -> String {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+        format!(
+            "bloom {}, {} blur passes, scale {:.2}, fxaa {}",
+            on_off(self.bloom_enabled),
+            self.bloom_blur_passes,
+            self.render_scale,
+            on_off(self.fxaa_enabled)
+        )
+    }
+}
+
+/// Réglages de l'enregistrement vidéo (`--record`, `renderer.record.start`).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RecordingConfig {
+    /// Exécutable ffmpeg (chemin ou nom résolu via le PATH)
+    pub ffmpeg: String,
+    /// Images par seconde de la vidéo
+    pub fps: u32,
+    /// Débit cible passé à ffmpeg (`-b:v`), ex. "12M" ; vide = choix de l'encodeur
+    pub bitrate: String,
+    /// Codec vidéo (`-c:v`)
+    pub codec: String,
+    /// Nombre de PBO du ring de relecture (latence en frames, évite les stalls GPU)
+    pub pbo_count: usize,
+    /// Simulation à pas fixe `1 / fps` pendant l'enregistrement (vidéo sans saccade)
+    pub lock_time_step: bool,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            ffmpeg: "ffmpeg".to_string(),
+            fps: 60,
+            bitrate: "12M".to_string(),
+            codec: "libx264".to_string(),
+            pbo_count: 3,
+            lock_time_step: true,
+        }
+    }
+}
+
+/// Mode de mélange d'un type de particule avec ce qui est déjà dessiné.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlendMode {
+    /// Transparence classique (`SRC_ALPHA, ONE_MINUS_SRC_ALPHA`)
+    #[default]
+    Alpha,
+    /// Accumulation lumineuse (`SRC_ALPHA, ONE`), idéale pour les étincelles
+    Additive,
+}
+
+/// Rendu des particules de trail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailStyle {
+    /// Une particule par point (rendu historique)
+    #[default]
+    Dots,
+    /// Ruban continu reliant les points de chaque fusée
+    Ribbon,
+}
+
+impl TrailStyle {
+    pub const ALL: [TrailStyle; 2] = [TrailStyle::Dots, TrailStyle::Ribbon];
+
+    /// Nom utilisé par la config et la console
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrailStyle::Dots => "dots",
+            TrailStyle::Ribbon => "ribbon",
+        }
+    }
+
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Réglages de rendu d'un type de particule.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ParticleRenderSettings {
+    /// Texture du sprite (vide : texture par défaut du type)
+    pub texture: String,
+    /// Descripteur d'atlas de sprites, TOML ou JSON (vide : `texture` seule).
+    /// Sa texture remplace `texture` ; chaque particule y choisit une variante.
+    pub atlas: String,
+    pub blend: BlendMode,
+    /// Multiplicateur de taille des sprites
+    pub size_scale: f32,
+    /// Multiplicateur de luminosité
+    pub brightness: f32,
+    /// Atténuation radiale du bord du sprite (0 : sprite brut, 1 : dégradé depuis le centre)
+    pub softness: f32,
+}
+
+impl Default for ParticleRenderSettings {
+    fn default() -> Self {
+        Self {
+            texture: String::new(),
+            atlas: String::new(),
+            blend: BlendMode::Alpha,
+            size_scale: 1.0,
+            brightness: 1.0,
+            softness: 0.0,
+        }
+    }
+}
+
+impl ParticleRenderSettings {
+    /// Texture effective pour `particle_type`
+    pub fn texture_path(&self, particle_type: ParticleType) -> &str {
+        if self.texture.is_empty() {
+            particle_type.default_texture_path()
+        } else {
+            &self.texture
+        }
+    }
+
+    /// Règle l'atténuation du bord (bornée à `SOFTNESS_RANGE`) ; retourne la valeur appliquée.
+    pub fn set_softness(&mut self, softness: f32) -> f32 {
+        self.softness = softness.clamp(SOFTNESS_RANGE.0, SOFTNESS_RANGE.1);
+        self.softness
+    }
+}
+
+/// Réglages par type de particule (`[particles.rocket]`, `[particles.explosion]`, …).
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ParticlesConfig {
+    pub rocket: ParticleRenderSettings,
+    pub explosion: ParticleRenderSettings,
+    pub smoke: ParticleRenderSettings,
+    pub trail: ParticleRenderSettings,
+}
+
+impl ParticlesConfig {
+    pub fn get(&self, particle_type: ParticleType) -> &ParticleRenderSettings {
+        match particle_type {
+            ParticleType::Rocket => &self.rocket,
+            ParticleType::Explosion => &self.explosion,
+            ParticleType::Smoke => &self.smoke,
+            ParticleType::Trail => &self.trail,
+        }
+    }
+
+    pub fn get_mut(&mut self, particle_type: ParticleType) -> &mut ParticleRenderSettings {
+        match particle_type {
+            ParticleType::Rocket => &mut self.rocket,
+            ParticleType::Explosion => &mut self.explosion,
+            ParticleType::Smoke => &mut self.smoke,
+            ParticleType::Trail => &mut self.trail,
+        }
+    }
+}
+
+/// Contexte OpenGL, framebuffer et console distante sont créés au démarrage.
+impl Reloadable for RendererConfig {
+    const SECTION: ConfigSection = ConfigSection::Renderer;
+    const RESTART_FIELDS: &'static [&'static str] =
+        &["gl_debug", "srgb_framebuffer", "remote_console"];
+
+    fn validate(&self) -> Vec<String> {
+        self.validate()
+    }
+
+    fn expand_file(table: &mut toml::Table) -> anyhow::Result<()> {
+        expand_preset(table)
+    }
+
+    fn override_template() -> anyhow::Result<toml::Table> {
+        let probe = Self {
+            max_fps: Some(0),
+            preset: Some(QualityPreset::Medium),
+            ..Self::default()
+        };
+        Ok(toml::Table::try_from(probe)?)
+    }
+}
+
+/// Développe `preset = "<nom>"` en ses clés, sans écraser celles déjà présentes.
+fn expand_preset(table: &mut toml::Table) -> anyhow::Result<()> {
+    if let Some(value) = table.get("preset") {
+        let preset: QualityPreset = value.clone().try_into()?;
+        if let toml::Value::Table(keys) = toml::Value::try_from(preset.settings())? {
+            for (key, value) in keys {
+                table.entry(key).or_insert(value);
+            }
+        }
+    }
+    Ok(())
+}
+
+impl RendererConfig {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        let text = read_asset_to_string(path)?;
+        Self::from_toml(&text)
+    }
+
+    /// Lit une config TOML ; `preset = "<nom>"` est développé en ses clés, les clés
+    /// écrites explicitement dans le fichier restant prioritaires.
+    pub fn from_toml(text: &str) -> anyhow::Result<Self> {
+        let mut table: toml::Table = toml::from_str(text)?;
+        expand_preset(&mut table)?;
+        Ok(table.try_into()?)
+    }
+
+    /// Règles non respectées (`champ: explication`), vide si la config est valide.
+    pub fn validate(&self) -> Vec<String> {
+        let mut v = Violations::default();
+        if let Some(max_fps) = self.max_fps {
+            v.check(max_fps > 0, "max_fps", "must be greater than 0");
+        }
+        v.in_range("render_scale", self.render_scale, RENDER_SCALE_RANGE);
+        v.in_range(
+            "motion_blur_strength",
+            self.motion_blur_strength,
+            (0.0, 1.0),
+        );
+        v.in_range(
+            "bloom_threshold",
+            self.bloom_threshold,
+            BLOOM_THRESHOLD_RANGE,
+        );
+        v.in_range(
+            "bloom_soft_knee",
+            self.bloom_soft_knee,
+            BLOOM_SOFT_KNEE_RANGE,
+        );
+        v.in_range(
+            "bloom_intensity",
+            self.bloom_intensity,
+            BLOOM_INTENSITY_RANGE,
+        );
+        v.in_range(
+            "bloom_blur_passes",
+            self.bloom_blur_passes,
+            BLOOM_BLUR_PASSES_RANGE,
+        );
+        v.in_range(
+            "bloom_audio_reactivity",
+            self.bloom_audio_reactivity,
+            BLOOM_AUDIO_REACTIVITY_RANGE,
+        );
+        v.in_range(
+            "bloom_audio_attack",
+            self.bloom_audio_attack,
+            BLOOM_AUDIO_SMOOTHING_RANGE,
+        );
+        v.in_range(
+            "bloom_audio_release",
+            self.bloom_audio_release,
+            BLOOM_AUDIO_SMOOTHING_RANGE,
+        );
+        v.in_range(
+            "lens_dirt_strength",
+            self.lens_dirt_strength,
+            LENS_DIRT_STRENGTH_RANGE,
+        );
+        v.check(
+            self.auto_exposure_min > 0.0,
+            "auto_exposure_min",
+            "must be greater than 0",
+        );
+        v.ordered(
+            ("auto_exposure_min", self.auto_exposure_min),
+            ("auto_exposure_max", self.auto_exposure_max),
+        );
+        v.in_range(
+            "flash_intensity",
+            self.flash_intensity,
+            FLASH_INTENSITY_RANGE,
+        );
+        v.in_range("flash_duration", self.flash_duration, FLASH_DURATION_RANGE);
+        v.in_range(
+            "tonemapping_compare_modes",
+            self.tonemapping_compare_modes.len(),
+            (2, MAX_COMPARISON_CELLS),
+        );
+        v.in_range("output_gamma", self.output_gamma, OUTPUT_GAMMA_RANGE);
+        v.positive("console_max_lines", self.console_max_lines);
+        v.check(
+            self.max_gpu_buffer.as_u64() > 0,
+            "max_gpu_buffer",
+            "must be greater than 0",
+        );
+        v.check(
+            self.camera.min_zoom > 0.0,
+            "camera.min_zoom",
+            "must be greater than 0",
+        );
+        v.ordered(
+            ("camera.min_zoom", self.camera.min_zoom),
+            ("camera.max_zoom", self.camera.max_zoom),
+        );
+        for particle_type in ParticleType::ALL {
+            let settings = self.particles.get(particle_type);
+            let field = |name: &str| format!("particles.{}.{}", particle_type.name(), name);
+            v.check(
+                settings.size_scale > 0.0,
+                &field("size_scale"),
+                "must be greater than 0",
+            );
+            v.in_range(&field("softness"), settings.softness, SOFTNESS_RANGE);
+        }
+        v.into_vec()
+    }
+
+    /// Applique tous les réglages d'un préréglage (les surfaces suivent à la frame suivante).
+    pub fn apply_preset(&mut self, preset: QualityPreset) -> QualitySettings {
+        let settings = preset.settings();
+        self.bloom_enabled = settings.bloom_enabled;
+        self.bloom_blur_passes = settings.bloom_blur_passes;
+        self.set_render_scale(settings.render_scale);
+        self.fxaa_enabled = settings.fxaa_enabled;
+        self.preset = Some(preset);
+        settings
+    }
+
+    /// Réglages de qualité actuels, au format d'un préréglage
+    pub fn quality_settings(&self) -> QualitySettings {
+        QualitySettings {
+            bloom_enabled: self.bloom_enabled,
+            bloom_blur_passes: self.bloom_blur_passes,
+            render_scale: self.render_scale,
+            fxaa_enabled: self.fxaa_enabled,
+        }
+    }
+
+    /// Préréglage en vigueur (`None` si un de ses réglages a été modifié depuis)
+    pub fn active_preset(&self) -> Option<QualityPreset> {
+        self.preset
+            .filter(|preset| preset.settings() == self.quality_settings())
+    }
+
+    /// Règle le seuil du bloom (borné à `BLOOM_THRESHOLD_RANGE`) ; retourne la valeur appliquée.
+    pub fn set_bloom_threshold(&mut self, threshold: f32) -> f32 {
+        self.bloom_threshold = threshold.clamp(BLOOM_THRESHOLD_RANGE.0, BLOOM_THRESHOLD_RANGE.1);
+        self.bloom_threshold
+    }
+
+    /// Règle le genou du bloom (borné à `BLOOM_SOFT_KNEE_RANGE`) ; retourne la valeur appliquée.
+    pub fn set_bloom_soft_knee(&mut self, soft_knee: f32) -> f32 {
+        self.bloom_soft_knee = soft_knee.clamp(BLOOM_SOFT_KNEE_RANGE.0, BLOOM_SOFT_KNEE_RANGE.1);
+        self.bloom_soft_knee
+    }
+
+    /// Règle le gamma de sortie (borné à `OUTPUT_GAMMA_RANGE`) ; retourne la valeur appliquée.
+    pub fn set_output_gamma(&mut self, gamma: f32) -> f32 {
+        self.output_gamma = gamma.clamp(OUTPUT_GAMMA_RANGE.0, OUTPUT_GAMMA_RANGE.1);
+        self.output_gamma
+    }
+
+    /// Règle l'échelle de rendu (bornée à `RENDER_SCALE_RANGE`) ; retourne la valeur appliquée.
+    pub fn set_render_scale(&mut self, render_scale: f32) -> f32 {
+        self.render_scale = render_scale.clamp(RENDER_SCALE_RANGE.0, RENDER_SCALE_RANGE.1);
+        self.render_scale
+    }
+
+    /// Règle le poids des salissures d'objectif ; retourne la valeur appliquée.
+    pub fn set_lens_dirt_strength(&mut self, strength: f32) -> f32 {
+        self.lens_dirt_strength =
+            strength.clamp(LENS_DIRT_STRENGTH_RANGE.0, LENS_DIRT_STRENGTH_RANGE.1);
+        self.lens_dirt_strength
+    }
+
+    /// Règle les bornes de l'exposition automatique (remises dans l'ordre si inversées).
+    pub fn set_auto_exposure_range(&mut self, min: f32, max: f32) {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.auto_exposure_min = min.max(0.01);
+        self.auto_exposure_max = max.max(self.auto_exposure_min);
+    }
+
+    /// Budget de frame du limiteur CPU (`None` : v-sync active ou pas de plafond).
+    pub fn frame_budget(&self) -> Option<Duration> {
+        if self.vsync {
+            None
+        } else {
+            frame_budget(self.max_fps)
+        }
+    }
+
+    /// Les particules de ce type doivent-elles être triées avant le dessin ?
+    pub fn depth_sort_for(&self, particle_type: ParticleType) -> bool {
+        self.depth_sort_enabled && self.particles.get(particle_type).blend == BlendMode::Alpha
+    }
+
+    /// Intensité du bloom pour un niveau audio lissé `audio_level` :
+    /// `bloom_intensity` telle quelle si le bloom réactif est désactivé.
+    pub fn effective_bloom_intensity(&self, audio_level: f32) -> f32 {
+        if !self.bloom_audio_reactive {
+            return self.bloom_intensity;
+        }
+        audio_reactive_intensity(
+            self.bloom_intensity,
+            self.bloom_audio_reactivity,
+            audio_level,
+        )
+        .min(BLOOM_INTENSITY_RANGE.1)
+    }
+
+    /// Règle la réactivité du bloom au son (bornée à `BLOOM_AUDIO_REACTIVITY_RANGE`) ;
+    /// retourne la valeur appliquée.
+    pub fn set_bloom_audio_reactivity(&mut self, reactivity: f32) -> f32 {
+        self.bloom_audio_reactivity = reactivity.clamp(
+            BLOOM_AUDIO_REACTIVITY_RANGE.0,
+            BLOOM_AUDIO_REACTIVITY_RANGE.1,
+        );
+        self.bloom_audio_reactivity
+    }
+
+    /// Intensité de flou réellement appliquée (0.0 si désactivé).
+    pub fn effective_motion_blur(&self) -> f32 {
+        if self.motion_blur_enabled {
+            self.motion_blur_strength.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Règle l'intensité du flou ; `0.0` le désactive.
+    pub fn set_motion_blur(&mut self, strength: f32) {
+        self.motion_blur_strength = strength.clamp(0.0, 1.0);
+        self.motion_blur_enabled = self.motion_blur_strength > 0.0;
+    }
+}
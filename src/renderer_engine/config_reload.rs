@@ -0,0 +1,157 @@
+//! Rechargement à chaud des configs : `R` relit `physic.toml`, `F7` (ou
+//! `renderer.config.reload`) relit `renderer.toml`.
+//!
+//! Les champs modifiés sont résumés dans les logs, les buffers GPU ne sont
+//! recréés que si la capacité en particules change, et les appuis répétés sont
+//! ignorés pendant `RELOAD_DEBOUNCE` (touche maintenue, rebonds).
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use log::warn;
+
+use crate::physic_engine::types::ReloadResult;
+use crate::utils::human_bytes::ByteSize;
+
+/// Délai minimal entre deux rechargements déclenchés au clavier
+pub const RELOAD_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Champ de config modifié par un rechargement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Chemin du champ (`camera.min_zoom`)
+    pub field: String,
+    /// Anciennes / nouvelles valeurs (TOML), `None` si absentes
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "unset".to_string());
+        write!(
+            f,
+            "{}: {} → {}",
+            self.field,
+            show(&self.old),
+            show(&self.new)
+        )
+    }
+}
+
+/// Champs qui diffèrent entre `old` et `new`, triés par chemin.
+pub fn config_changes<T: Serialize>(old: &T, new: &T) -> anyhow::Result<Vec<ConfigChange>> {
+    let old = toml::Table::try_from(old)?;
+    let new = toml::Table::try_from(new)?;
+    let mut changes = Vec::new();
+    diff_tables("", &old, &new, &mut changes);
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    Ok(changes)
+}
+
+fn diff_tables(prefix: &str, old: &toml::Table, new: &toml::Table, out: &mut Vec<ConfigChange>) {
+    let keys = old
+        .keys()
+        .chain(new.keys().filter(|key| !old.contains_key(*key)));
+    for key in keys {
+        let field = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match (old.get(key), new.get(key)) {
+            (Some(toml::Value::Table(old)), Some(toml::Value::Table(new))) => {
+                diff_tables(&field, old, new, out)
+            }
+            (old, new) if old == new => {}
+            (old, new) => out.push(ConfigChange {
+                field,
+                old: old.map(format_value),
+                new: new.map(format_value),
+            }),
+        }
+    }
+}
+
+/// Valeur lisible : les configs sont en `f32`, affichés sans l'élargissement
+/// en `f64` de la sérialisation TOML (`0.8`, pas `0.800000011920929`).
+fn format_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::Float(x) => (*x as f32).to_string(),
+        toml::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(format_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Résumé d'une ligne des changements (`max_rockets: 16384 → 64, gravity: ...`).
+pub fn format_changes(changes: &[ConfigChange]) -> String {
+    if changes.is_empty() {
+        return "no field changed".to_string();
+    }
+    let list: Vec<String> = changes.iter().map(ConfigChange::to_string).collect();
+    format!("{} field(s) changed: {}", changes.len(), list.join(", "))
+}
+
+/// Nouvelle capacité des buffers GPU après un rechargement physique, `None` si
+/// elle ne change pas.
+///
+/// Les buffers ne font que grandir : après une réduction, des fusées en vol
+/// peuvent encore occuper l'ancienne capacité.
+pub fn gpu_buffer_capacity(current: usize, required: usize, result: ReloadResult) -> Option<usize> {
+    if !result.needs_buffer_recreation() && required <= current {
+        return None;
+    }
+    let capacity = required.max(current);
+    (capacity != current).then_some(capacity)
+}
+
+/// Capacité en particules (de `particle_size` octets) tenant dans `budget`
+/// (`max_gpu_buffer`) : `required` est plafonné, avec un avertissement.
+pub fn particles_within_budget(required: usize, particle_size: usize, budget: ByteSize) -> usize {
+    let affordable = (budget.as_u64() / particle_size.max(1) as u64) as usize;
+    if required > affordable {
+        warn!(
+            "⚠️ {} particles need {} on the GPU, over max_gpu_buffer ({}): capped to {}",
+            required,
+            ByteSize(particle_size as u64) * required as u64,
+            budget,
+            affordable
+        );
+        return affordable;
+    }
+    required
+}
+
+/// Filtre les rechargements trop rapprochés (`RELOAD_DEBOUNCE`).
+#[derive(Debug, Clone)]
+pub struct ReloadDebounce {
+    window: Duration,
+    last: Option<Instant>,
+}
+
+impl Default for ReloadDebounce {
+    fn default() -> Self {
+        Self::new(RELOAD_DEBOUNCE)
+    }
+}
+
+impl ReloadDebounce {
+    pub fn new(window: Duration) -> Self {
+        Self { window, last: None }
+    }
+
+    /// Rechargement accepté à `now` : aucun autre accepté depuis `window`.
+    pub fn accept(&mut self, now: Instant) -> bool {
+        let accepted = self
+            .last
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.window);
+        if accepted {
+            self.last = Some(now);
+        }
+        accepted
+    }
+}
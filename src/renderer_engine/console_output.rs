@@ -0,0 +1,193 @@
+//! Lignes affichées par la console : gravité, horodatage, filtre et rétention bornée.
+//!
+//! Les journaux du renderer et les résultats de commandes se mêlent dans la même
+//! sortie ; chaque ligne porte donc sa gravité (couleur à l'affichage) et la
+//! sortie ne garde que les `max_lines` dernières (tampon circulaire).
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::renderer_engine::command_script::command_failed;
+
+/// Nombre de lignes conservées par défaut (`console_max_lines` de `renderer.toml`)
+pub const DEFAULT_CONSOLE_MAX_LINES: usize = 2000;
+
+/// Gravité d'une ligne de sortie
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warn,
+    Error,
+    /// Commande saisie (`> cmd`)
+    Echo,
+}
+
+impl Severity {
+    /// Gravité déduite du texte : écho `> `, échec de commande, avertissement.
+    pub fn classify(text: &str) -> Self {
+        let trimmed = text.trim_start();
+        if trimmed.starts_with("> ") {
+            Severity::Echo
+        } else if command_failed(trimmed) {
+            Severity::Error
+        } else if trimmed.starts_with("Warning") || trimmed.starts_with("⚠️") {
+            Severity::Warn
+        } else {
+            Severity::Info
+        }
+    }
+
+    /// Étiquette des transcriptions (`transcript save`)
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "INFO",
+            Severity::Warn => "WARN",
+            Severity::Error => "ERROR",
+            Severity::Echo => "ECHO",
+        }
+    }
+
+    /// Couleur d'affichage (RGBA)
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            Severity::Info => [0.8, 0.8, 0.8, 1.0],
+            Severity::Warn => [1.0, 0.85, 0.2, 1.0],
+            Severity::Error => [1.0, 0.35, 0.3, 1.0],
+            Severity::Echo => [0.55, 0.55, 0.55, 1.0],
+        }
+    }
+}
+
+/// Ligne de sortie
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLine {
+    /// Temps écoulé depuis la création de la console
+    pub timestamp: Duration,
+    pub severity: Severity,
+    pub text: String,
+}
+
+impl OutputLine {
+    /// Horodatage affiché : `"[mm:ss]"`
+    pub fn format_timestamp(&self) -> String {
+        let seconds = self.timestamp.as_secs();
+        format!("[{:02}:{:02}]", seconds / 60, seconds % 60)
+    }
+}
+
+/// Sortie de la console : tampon circulaire de `max_lines` lignes et filtre d'affichage.
+pub struct ConsoleOutput {
+    lines: VecDeque<OutputLine>,
+    max_lines: usize,
+    /// Motif (recherche floue) des lignes affichées ; `None` : toutes
+    filter: Option<String>,
+    matcher: SkimMatcherV2,
+    start: Instant,
+    /// Lignes ajoutées depuis la création (évincées comprises)
+    pushed: u64,
+}
+
+impl Default for ConsoleOutput {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONSOLE_MAX_LINES)
+    }
+}
+
+impl ConsoleOutput {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            max_lines: max_lines.max(1),
+            filter: None,
+            matcher: SkimMatcherV2::default(),
+            start: Instant::now(),
+            pushed: 0,
+        }
+    }
+
+    /// Ajoute une ligne ; la plus ancienne est évincée au-delà de `max_lines`.
+    pub fn push(&mut self, severity: Severity, text: impl Into<String>) {
+        if self.lines.len() == self.max_lines {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(OutputLine {
+            timestamp: self.start.elapsed(),
+            severity,
+            text: text.into(),
+        });
+        self.pushed += 1;
+    }
+
+    /// Repère pour `lines_since` : nombre de lignes ajoutées jusqu'ici
+    pub fn mark(&self) -> u64 {
+        self.pushed
+    }
+
+    /// Lignes ajoutées après `mark` et encore conservées
+    pub fn lines_since(&self, mark: u64) -> impl Iterator<Item = &OutputLine> {
+        let added = self.pushed.saturating_sub(mark) as usize;
+        self.lines
+            .iter()
+            .skip(self.lines.len().saturating_sub(added))
+    }
+
+    pub fn max_lines(&self) -> usize {
+        self.max_lines
+    }
+
+    /// Change la capacité (au moins 1) ; les lignes en trop les plus anciennes sont évincées.
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.max_lines = max_lines.max(1);
+        let excess = self.lines.len().saturating_sub(self.max_lines);
+        self.lines.drain(..excess);
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Toutes les lignes conservées, de la plus ancienne à la plus récente
+    pub fn lines(&self) -> impl Iterator<Item = &OutputLine> {
+        self.lines.iter()
+    }
+
+    /// Textes des lignes conservées
+    pub fn texts(&self) -> Vec<&str> {
+        self.lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Filtre d'affichage ; motif vide : aucun filtre.
+    pub fn set_filter(&mut self, pattern: Option<&str>) {
+        self.filter = pattern
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from);
+    }
+
+    /// `text` passe-t-il le filtre courant ?
+    pub fn matches(&self, text: &str) -> bool {
+        match &self.filter {
+            Some(pattern) => self.matcher.fuzzy_match(text, pattern).is_some(),
+            None => true,
+        }
+    }
+
+    /// Lignes affichées (filtre appliqué)
+    pub fn visible(&self) -> impl Iterator<Item = &OutputLine> {
+        self.lines.iter().filter(|line| self.matches(&line.text))
+    }
+}
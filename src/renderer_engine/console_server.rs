@@ -0,0 +1,200 @@
+//! Console distante : commandes envoyées ligne par ligne sur une socket TCP locale.
+//!
+//! Un thread écoute `bind_address:port` et un thread par client lit les lignes ;
+//! chaque commande passe par un canal vidé à chaque frame par la boucle de rendu,
+//! qui l'exécute comme une saisie de la console (moteurs sur le thread principal)
+//! et renvoie la sortie au client.
+//!
+//! Protocole : une commande par ligne ; chaque réponse est suivie d'une ligne vide.
+//! Avec un `token` configuré, la première ligne du client doit être ce jeton.
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Délai maximal d'exécution d'une commande par la boucle de rendu
+pub const REMOTE_REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+/// Période de scrutation des connexions (arrêt du serveur compris)
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Réglages de la console distante (table `[remote_console]`)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RemoteConsoleConfig {
+    /// Désactivée par défaut : aucune socket ouverte
+    pub enabled: bool,
+    /// Adresse d'écoute ; localhost uniquement par défaut
+    pub bind_address: String,
+    /// Port TCP (0 : choisi par le système)
+    pub port: u16,
+    /// Jeton partagé attendu en première ligne (vide : pas d'authentification)
+    pub token: String,
+}
+
+impl Default for RemoteConsoleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_address: "127.0.0.1".to_string(),
+            port: 7878,
+            token: String::new(),
+        }
+    }
+}
+
+/// Commande reçue d'un client, en attente d'exécution.
+#[derive(Debug)]
+pub struct RemoteCommand {
+    pub line: String,
+    reply: Sender<String>,
+}
+
+impl RemoteCommand {
+    /// Renvoie la sortie au client (ignoré s'il s'est déconnecté).
+    pub fn reply(self, output: String) {
+        let _ = self.reply.send(output);
+    }
+}
+
+/// Serveur de la console distante ; arrêté à sa destruction.
+pub struct ConsoleServer {
+    local_addr: SocketAddr,
+    commands: Receiver<RemoteCommand>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ConsoleServer {
+    /// Ouvre la socket et lance le thread d'écoute.
+    pub fn start(config: &RemoteConsoleConfig) -> Result<Self> {
+        let address = format!("{}:{}", config.bind_address, config.port);
+        let listener =
+            TcpListener::bind(&address).with_context(|| format!("Cannot listen on {}", address))?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+
+        let (sender, commands) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let token = Arc::new(config.token.clone());
+        let thread_stop = stop.clone();
+        let thread = std::thread::Builder::new()
+            .name("remote-console".into())
+            .spawn(move || accept_loop(listener, sender, token, thread_stop))?;
+
+        info!("🖧 Remote console listening on {}", local_addr);
+        if !local_addr.ip().is_loopback() && config.token.is_empty() {
+            warn!("⚠️ Remote console reachable from the network without a token");
+        }
+        Ok(Self {
+            local_addr,
+            commands,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Commandes reçues depuis le dernier appel (non bloquant).
+    pub fn poll(&self) -> Vec<RemoteCommand> {
+        self.commands.try_iter().collect()
+    }
+}
+
+impl Drop for ConsoleServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        info!("🖧 Remote console on {} stopped", self.local_addr);
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    sender: Sender<RemoteCommand>,
+    token: Arc<String>,
+    stop: Arc<AtomicBool>,
+) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                let sender = sender.clone();
+                let token = token.clone();
+                let spawned = std::thread::Builder::new()
+                    .name("remote-console-client".into())
+                    .spawn(move || {
+                        if let Err(e) = serve_client(stream, &sender, &token) {
+                            warn!("⚠️ Remote console client {}: {}", peer, e);
+                        }
+                    });
+                if let Err(e) = spawned {
+                    warn!("⚠️ Remote console client {} refused: {}", peer, e);
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => std::thread::sleep(ACCEPT_POLL_INTERVAL),
+            Err(e) => {
+                warn!("⚠️ Remote console accept failed: {}", e);
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// Réponse suivie de la ligne vide de fin (les lignes vides internes sont retirées).
+fn write_reply(stream: &mut TcpStream, output: &str) -> std::io::Result<()> {
+    for line in output.lines().filter(|line| !line.trim().is_empty()) {
+        writeln!(stream, "{}", line)?;
+    }
+    writeln!(stream)?;
+    stream.flush()
+}
+
+fn serve_client(
+    stream: TcpStream,
+    sender: &Sender<RemoteCommand>,
+    token: &str,
+) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    let mut writer = stream.try_clone()?;
+    let mut lines = BufReader::new(stream).lines();
+
+    if !token.is_empty() {
+        match lines.next() {
+            Some(Ok(line)) if line.trim() == token => write_reply(&mut writer, "Authenticated")?,
+            _ => return write_reply(&mut writer, "Error: invalid token"),
+        }
+    }
+
+    for line in lines {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (reply, response) = channel();
+        let command = RemoteCommand {
+            line: line.to_string(),
+            reply,
+        };
+        if sender.send(command).is_err() {
+            // Serveur arrêté
+            return write_reply(&mut writer, "Error: console closed");
+        }
+        let output = response
+            .recv_timeout(REMOTE_REPLY_TIMEOUT)
+            .unwrap_or_else(|_| "Error: no response from the simulator".to_string());
+        write_reply(&mut writer, &output)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Échelle d'affichage (DPI) : facteur système et rapport framebuffer / fenêtre.
+//!
+//! Sur un écran HiDPI, la taille du framebuffer (pixels) et celle de la fenêtre
+//! (coordonnées écran, celles du curseur) diffèrent selon les systèmes : macOS
+//! double le framebuffer, Windows et X11 gardent des pixels mais annoncent un
+//! facteur de contenu. Les tailles à l'écran (texte, particules) en dépendent.
+
+use glam::Vec2;
+
+/// Bornes du facteur de contenu retenu (valeurs aberrantes de certains pilotes)
+pub const CONTENT_SCALE_RANGE: (f32, f32) = (0.5, 4.0);
+
+/// Facteur de contenu retenu pour les valeurs (x, y) du système.
+pub fn effective_content_scale(x: f32, y: f32) -> f32 {
+    let scale = x.max(y);
+    if scale.is_finite() && scale > 0.0 {
+        scale.clamp(CONTENT_SCALE_RANGE.0, CONTENT_SCALE_RANGE.1)
+    } else {
+        1.0
+    }
+}
+
+/// Échelle et tailles courantes de la fenêtre.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayScale {
+    /// Facteur de contenu signalé par le système (1.0 : 96 dpi)
+    pub content_scale: f32,
+    /// Taille de la fenêtre en coordonnées écran
+    pub window_size: (i32, i32),
+    /// Taille du framebuffer (pixels)
+    pub framebuffer_size: (i32, i32),
+}
+
+impl Default for DisplayScale {
+    fn default() -> Self {
+        Self {
+            content_scale: 1.0,
+            window_size: (0, 0),
+            framebuffer_size: (0, 0),
+        }
+    }
+}
+
+impl DisplayScale {
+    /// Pixels du framebuffer par unité de coordonnées écran (1 tant qu'une taille est inconnue).
+    pub fn framebuffer_ratio(&self) -> Vec2 {
+        let (ww, wh) = self.window_size;
+        let (fw, fh) = self.framebuffer_size;
+        if ww <= 0 || wh <= 0 || fw <= 0 || fh <= 0 {
+            return Vec2::ONE;
+        }
+        Vec2::new(fw as f32 / ww as f32, fh as f32 / wh as f32)
+    }
+
+    /// Position écran (curseur) → pixels du framebuffer, origine conservée.
+    pub fn to_framebuffer(&self, screen: Vec2) -> Vec2 {
+        screen * self.framebuffer_ratio()
+    }
+
+    /// Échelle globale de l'UI : le backend ImGui applique déjà le rapport
+    /// framebuffer / fenêtre, il ne reste que la part du facteur de contenu.
+    pub fn ui_scale(&self) -> f32 {
+        (self.content_scale / self.framebuffer_ratio().x).max(CONTENT_SCALE_RANGE.0)
+    }
+
+    /// Multiplicateur de la taille des particules (en pixels du framebuffer),
+    /// pour une taille physique identique quel que soit l'écran.
+    pub fn particle_scale(&self) -> f32 {
+        self.content_scale
+    }
+}
+
+/// Échelle courante (`renderer.window.scale`)
+pub fn format_display_scale(display: &DisplayScale) -> String {
+    let ratio = display.framebuffer_ratio();
+    format!(
+        "Display scale: content x{:.2}\n  window {}x{}, framebuffer {}x{} (x{:.2})\n  UI x{:.2}, particles x{:.2}",
+        display.content_scale,
+        display.window_size.0,
+        display.window_size.1,
+        display.framebuffer_size.0,
+        display.framebuffer_size.1,
+        ratio.x,
+        display.ui_scale(),
+        display.particle_scale()
+    )
+}
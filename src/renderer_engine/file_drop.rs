@@ -0,0 +1,108 @@
+//! Fichiers glissés-déposés sur la fenêtre.
+//!
+//! Les images deviennent la forme des prochaines explosions (plusieurs images : un
+//! jeu à poids égaux) ; déposer `renderer.toml` ou `physic.toml` recharge les
+//! configs. Les messages produits sont affichés dans la console : une image
+//! illisible doit être visible sans ouvrir les logs.
+
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+
+use crate::physic_engine::{
+    config::PHYSIC_CONFIG_PATH, explosion_shape::ImageShapeSettings,
+    shape_library::has_image_extension, PhysicEngine,
+};
+use crate::renderer_engine::config::RENDERER_CONFIG_PATH;
+use crate::utils::assets::asset_path;
+
+/// Configs rechargées lorsqu'elles sont déposées sur la fenêtre
+const RELOADABLE_CONFIGS: &[&str] = &[RENDERER_CONFIG_PATH, PHYSIC_CONFIG_PATH];
+
+/// Nature d'un fichier déposé.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DroppedFile {
+    Image,
+    Config,
+    Unsupported,
+}
+
+impl DroppedFile {
+    pub fn classify(path: &Path) -> Self {
+        if has_image_extension(path) {
+            DroppedFile::Image
+        } else if is_reloadable_config(path) {
+            DroppedFile::Config
+        } else {
+            DroppedFile::Unsupported
+        }
+    }
+}
+
+/// `path` désigne l'une des configs rechargées par le renderer (chemins résolus).
+fn is_reloadable_config(path: &Path) -> bool {
+    let Ok(path) = path.canonicalize() else {
+        return false;
+    };
+    RELOADABLE_CONFIGS
+        .iter()
+        .filter_map(|config| asset_path(config).canonicalize().ok())
+        .any(|config| config == path)
+}
+
+/// Résultat d'un dépôt de fichiers.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileDropOutcome {
+    /// Lignes à afficher dans la console
+    pub messages: Vec<String>,
+    /// Une config a été déposée : rechargement à faire par le renderer
+    pub reload_config: bool,
+}
+
+/// Traite un dépôt : les images sont chargées ensemble comme forme d'explosion.
+pub fn handle_file_drop<P: PhysicEngine + ?Sized>(
+    paths: &[PathBuf],
+    physic: &mut P,
+) -> FileDropOutcome {
+    let mut outcome = FileDropOutcome::default();
+    let mut images = Vec::new();
+
+    for path in paths {
+        match DroppedFile::classify(path) {
+            DroppedFile::Image => images.push(path.clone()),
+            DroppedFile::Config => outcome.reload_config = true,
+            DroppedFile::Unsupported => outcome
+                .messages
+                .push(format!("Ignored dropped file: {}", path.display())),
+        }
+    }
+
+    if !images.is_empty() {
+        match physic.load_explosion_images(&images, ImageShapeSettings::default()) {
+            Ok(count) => {
+                let names: Vec<String> = images
+                    .iter()
+                    .filter_map(|p| p.file_stem())
+                    .map(|s| s.to_string_lossy().into_owned())
+                    .collect();
+                outcome.messages.push(format!(
+                    "Explosion shape: {} image(s) ({})",
+                    count,
+                    names.join(", ")
+                ));
+            }
+            Err(e) => outcome.messages.push(format!("Error: {}", e)),
+        }
+    }
+    if outcome.reload_config {
+        outcome.messages.push("Config reload requested".to_string());
+    }
+
+    for message in &outcome.messages {
+        if message.starts_with("Error") {
+            warn!("⚠️ File drop: {}", message);
+        } else {
+            info!("📂 File drop: {}", message);
+        }
+    }
+    outcome
+}
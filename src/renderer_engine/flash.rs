@@ -0,0 +1,221 @@
+//! Éclairs de détonation : chaque explosion illumine brièvement toute la scène
+//! (ciel et fumée comprises) pendant quelques frames.
+//!
+//! Le renderer garde au plus `MAX_FLASHES` éclairs actifs, le plus ancien étant
+//! évincé quand la liste est pleine. Leur intensité décroît exponentiellement
+//! et ils disparaissent après `flash_duration`. Les shaders les reçoivent sous
+//! forme d'un petit tableau d'uniformes (cf. `FLASH_GLSL`) et ajoutent
+//! `flash_light(position)` à leur couleur, atténué avec la distance.
+
+use glam::{Vec2, Vec3};
+
+use crate::cstr;
+use crate::physic_engine::particle::Particle;
+
+/// Éclairs actifs simultanément (taille du tableau d'uniformes)
+pub const MAX_FLASHES: usize = 8;
+/// Distance (unités monde) à laquelle la lumière d'un éclair est divisée par deux
+pub const FLASH_RADIUS: f32 = 400.0;
+/// Constantes de temps écoulées sur `flash_duration` : il reste e⁻³ ≈ 5 % à la fin
+const FLASH_DECAY_TIME_CONSTANTS: f32 = 3.0;
+
+/// Uniformes et fonction `flash_light(vec2 world_pos)`, insérés après `#version`.
+pub const FLASH_GLSL: &str = r#"
+#define MAX_FLASHES 8
+uniform int uFlashCount;
+uniform vec2 uFlashPos[MAX_FLASHES];
+uniform vec3 uFlashColor[MAX_FLASHES]; // couleur × intensité courante
+uniform float uFlashRadius;
+
+// Lumière reçue en `world_pos` (cf. `FlashList::light_at`)
+vec3 flash_light(vec2 world_pos) {
+    vec3 light = vec3(0.0);
+    for (int i = 0; i < uFlashCount; ++i) {
+        float d = length(world_pos - uFlashPos[i]) / uFlashRadius;
+        light += uFlashColor[i] / (1.0 + d * d);
+    }
+    return light;
+}
+"#;
+
+/// Insère `FLASH_GLSL` juste après la directive `#version` de `source`.
+pub fn with_flash_glsl(source: &str) -> String {
+    match source.find("#version") {
+        Some(start) => {
+            let end = source[start..]
+                .find('\n')
+                .map_or(source.len(), |i| start + i + 1);
+            format!("{}{}{}", &source[..end], FLASH_GLSL, &source[end..])
+        }
+        None => format!("{}{}", FLASH_GLSL, source),
+    }
+}
+
+/// Décroissance d'un éclair d'âge `age` (1 à la détonation, 0 après `duration`).
+pub fn flash_decay(age: f32, duration: f32) -> f32 {
+    if duration <= 0.0 || age >= duration {
+        return 0.0;
+    }
+    (-FLASH_DECAY_TIME_CONSTANTS * age.max(0.0) / duration).exp()
+}
+
+/// Atténuation en distance : 1 au centre, 1/2 à `radius`.
+pub fn flash_falloff(distance: f32, radius: f32) -> f32 {
+    let d = distance / radius.max(f32::EPSILON);
+    1.0 / (1.0 + d * d)
+}
+
+/// Éclair d'une détonation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Flash {
+    pub position: Vec2,
+    pub color: Vec3,
+    /// Intensité à la détonation (multipliée par `flash_intensity`)
+    pub intensity: f32,
+    /// Temps écoulé depuis la détonation (s)
+    pub age: f32,
+}
+
+impl Flash {
+    pub fn new(position: Vec2, color: Vec3, intensity: f32) -> Self {
+        Self {
+            position,
+            color,
+            intensity,
+            age: 0.0,
+        }
+    }
+
+    /// Éclair d'une particule d'explosion déclenchée (position et couleur de la fusée)
+    pub fn from_explosion(particle: &Particle) -> Self {
+        Self::new(particle.pos, particle.color.truncate(), 1.0)
+    }
+
+    /// Lumière émise à cet âge, avant atténuation en distance
+    pub fn radiance(&self, duration: f32, gain: f32) -> Vec3 {
+        self.color * (self.intensity * gain * flash_decay(self.age, duration))
+    }
+}
+
+/// Éclairs actifs, `MAX_FLASHES` au plus.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlashList {
+    flashes: Vec<Flash>,
+}
+
+impl FlashList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute un éclair ; liste pleine : il remplace le plus ancien.
+    pub fn add(&mut self, flash: Flash) {
+        if self.flashes.len() < MAX_FLASHES {
+            self.flashes.push(flash);
+        } else if let Some(oldest) = self
+            .flashes
+            .iter_mut()
+            .max_by(|a, b| a.age.total_cmp(&b.age))
+        {
+            *oldest = flash;
+        }
+    }
+
+    /// Vieillit les éclairs de `dt` et retire ceux éteints après `duration`.
+    pub fn update(&mut self, dt: f32, duration: f32) {
+        for flash in &mut self.flashes {
+            flash.age += dt;
+        }
+        self.flashes.retain(|flash| flash.age < duration);
+    }
+
+    pub fn clear(&mut self) {
+        self.flashes.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.flashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flashes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Flash> {
+        self.flashes.iter()
+    }
+
+    /// Lumière reçue en `position`, identique au calcul de `flash_light` (GLSL).
+    pub fn light_at(&self, position: Vec2, duration: f32, gain: f32) -> Vec3 {
+        self.flashes
+            .iter()
+            .map(|flash| {
+                flash.radiance(duration, gain)
+                    * flash_falloff(position.distance(flash.position), FLASH_RADIUS)
+            })
+            .sum()
+    }
+
+    /// Valeurs des uniformes de `FLASH_GLSL` pour cette frame.
+    pub fn uniforms(&self, duration: f32, gain: f32) -> FlashUniforms {
+        let mut uniforms = FlashUniforms::default();
+        for (i, flash) in self.flashes.iter().enumerate() {
+            uniforms.positions[i] = flash.position.to_array();
+            uniforms.colors[i] = flash.radiance(duration, gain).to_array();
+        }
+        uniforms.count = self.flashes.len() as i32;
+        uniforms
+    }
+}
+
+/// Contenu du tableau d'uniformes des éclairs.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FlashUniforms {
+    pub count: i32,
+    pub positions: [[f32; 2]; MAX_FLASHES],
+    /// Couleur × intensité courante
+    pub colors: [[f32; 3]; MAX_FLASHES],
+}
+
+/// Emplacements des uniformes de `FLASH_GLSL` dans un programme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlashUniformLocations {
+    count: i32,
+    positions: i32,
+    colors: i32,
+    radius: i32,
+}
+
+impl FlashUniformLocations {
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn from_program(program: u32) -> Self {
+        Self {
+            count: gl::GetUniformLocation(program, cstr!("uFlashCount")),
+            positions: gl::GetUniformLocation(program, cstr!("uFlashPos")),
+            colors: gl::GetUniformLocation(program, cstr!("uFlashColor")),
+            radius: gl::GetUniformLocation(program, cstr!("uFlashRadius")),
+        }
+    }
+
+    /// Envoie les éclairs au programme courant (`glUseProgram` déjà fait).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn upload(&self, flashes: &FlashUniforms) {
+        gl::Uniform1i(self.count, flashes.count);
+        gl::Uniform1f(self.radius, FLASH_RADIUS);
+        if flashes.count > 0 {
+            gl::Uniform2fv(
+                self.positions,
+                flashes.count,
+                flashes.positions.as_ptr() as *const f32,
+            );
+            gl::Uniform3fv(
+                self.colors,
+                flashes.count,
+                flashes.colors.as_ptr() as *const f32,
+            );
+        }
+    }
+}
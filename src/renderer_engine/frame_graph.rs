@@ -0,0 +1,384 @@
+//! Liste ordonnée des passes de rendu d'une frame.
+//!
+//! Chaque passe déclare les ressources qu'elle lit et écrit ; l'ordre d'exécution
+//! en est déduit (tri topologique, stable vis-à-vis de l'ordre de déclaration).
+//! Chaque passe active est chronométrée côté GPU (`GL_TIME_ELAPSED`).
+
+use anyhow::{anyhow, Result};
+use gl::types::GLuint;
+use glam::Mat3;
+use log::{info, warn};
+use std::time::Duration;
+
+use crate::physic_engine::PhysicEngineIterator;
+use crate::renderer_engine::{
+    background::BackgroundRenderer,
+    bloom::BloomPass,
+    config::RendererConfig,
+    flash::FlashUniforms,
+    particle_renderer::ParticleGraphicsRenderer,
+    post_process::FxaaPass,
+    render_stats::RenderStats,
+    utils::gpu_timer::{timer_queries_available, GpuTimer},
+};
+
+/// Ressource produite par une passe et lue par les suivantes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// Scène (fond + particules), en HDR quand le post-process est actif
+    Scene,
+    /// Zones lumineuses extraites (demi-résolution)
+    BloomBright,
+    /// Zones lumineuses floutées
+    BloomBlur,
+    /// Image composée et tonemappée
+    Ldr,
+    /// Cible de sortie (fenêtre ou FBO headless)
+    Output,
+}
+
+impl Resource {
+    pub fn name(self) -> &'static str {
+        match self {
+            Resource::Scene => "scene",
+            Resource::BloomBright => "bloom.bright",
+            Resource::BloomBlur => "bloom.blur",
+            Resource::Ldr => "ldr",
+            Resource::Output => "output",
+        }
+    }
+}
+
+/// Ressources GPU partagées par les passes (possédées par le `Renderer`)
+pub struct PassResources {
+    pub renderers: Vec<Box<dyn ParticleGraphicsRenderer>>,
+    /// Ciel en dégradé + étoiles, dessiné avant les particules
+    pub background: BackgroundRenderer,
+    /// Cibles HDR et programmes du bloom (`None` si leur création a échoué)
+    pub bloom: Option<BloomPass>,
+    /// Anti-aliasing FXAA (cible allouée à la première activation)
+    pub fxaa: FxaaPass,
+    /// Particules dessinées par la passe de scène lors de la dernière frame
+    pub particles_drawn: usize,
+    /// Compteurs de la frame en cours (remis à zéro par le `Renderer`)
+    pub stats: RenderStats,
+}
+
+impl PassResources {
+    /// Ajoute aux statistiques la mémoire allouée et la taille des cibles.
+    pub fn record_allocations(&mut self) {
+        let stats = &mut self.stats;
+        stats.memory += self.background.gpu_memory();
+        for renderer in &self.renderers {
+            stats.memory += renderer.gpu_memory();
+        }
+        if let Some(bloom) = &self.bloom {
+            stats.memory += bloom.gpu_memory();
+            for (name, width, height) in bloom.framebuffer_sizes() {
+                stats.add_framebuffer(name, width, height);
+            }
+        }
+        stats.memory += self.fxaa.gpu_memory();
+        let (width, height) = self.fxaa.target_size();
+        stats.add_framebuffer("fxaa", width, height);
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn close(&mut self) {
+        for renderer in &mut self.renderers {
+            renderer.close();
+        }
+        self.background.close();
+        if let Some(bloom) = &mut self.bloom {
+            bloom.close();
+        }
+        self.fxaa.close();
+    }
+}
+
+/// Paramètres d'une frame, calculés par le `Renderer` avant l'exécution des passes
+pub struct FrameContext<'a> {
+    pub config: &'a RendererConfig,
+    pub physic: &'a dyn PhysicEngineIterator,
+    /// Particules actives de la frame (`count_all_active`) : 0 → aucun remplissage
+    pub active_particles: usize,
+    pub view_proj: Mat3,
+    /// Temps de rendu écoulé (s)
+    pub clock: f32,
+    /// Échelle d'affichage (DPI) des tailles de particules, cf. `DisplayScale`
+    pub content_scale: f32,
+    /// Éclairs de détonation de la frame (fond et particules)
+    pub flashes: &'a FlashUniforms,
+    /// Scène rendue dans la cible HDR puis composée (tone mapping)
+    pub hdr: bool,
+    /// Extraction et flou du bloom à exécuter
+    pub bloom: bool,
+    /// Cible FXAA intermédiaire allouée et liée
+    pub fxaa: bool,
+    /// La sortie finale est encodée en sRGB par le GPU (`GL_FRAMEBUFFER_SRGB`)
+    pub srgb: bool,
+    /// Cible finale et sa taille
+    pub output_fbo: GLuint,
+    pub output_size: (i32, i32),
+    /// Cible de la composition HDR : FXAA si actif, sinon la sortie
+    pub composite_fbo: GLuint,
+}
+
+/// Étape du rendu d'une frame.
+pub trait RenderPass {
+    /// Nom affiché (console, métriques `gpu:<nom>`)
+    fn name(&self) -> &'static str;
+
+    fn inputs(&self) -> &'static [Resource];
+
+    fn outputs(&self) -> &'static [Resource];
+
+    /// Passe exécutée pour cette frame (toujours déclarée dans le graphe)
+    fn enabled(&self, _frame: &FrameContext) -> bool {
+        true
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    unsafe fn resize(&mut self, _res: &mut PassResources, _width: u32, _height: u32) -> Result<()> {
+        Ok(())
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    unsafe fn reload_shaders(&mut self, _res: &mut PassResources) -> Result<()> {
+        Ok(())
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    unsafe fn execute(&mut self, res: &mut PassResources, frame: &FrameContext);
+}
+
+/// Ordre d'exécution des passes : chaque passe suit les producteurs de ses entrées.
+///
+/// À dépendances égales, l'ordre de déclaration est conservé. Erreur si une
+/// ressource a deux producteurs, si une entrée n'est produite par aucune passe,
+/// ou en cas de cycle.
+pub fn execution_order(passes: &[Box<dyn RenderPass>]) -> Result<Vec<usize>> {
+    let mut producers: Vec<(Resource, usize)> = Vec::new();
+    for (i, pass) in passes.iter().enumerate() {
+        for &resource in pass.outputs() {
+            if let Some(&(_, other)) = producers.iter().find(|(r, _)| *r == resource) {
+                return Err(anyhow!(
+                    "'{}' is written by both '{}' and '{}'",
+                    resource.name(),
+                    passes[other].name(),
+                    pass.name()
+                ));
+            }
+            producers.push((resource, i));
+        }
+    }
+
+    let mut dependencies: Vec<Vec<usize>> = Vec::with_capacity(passes.len());
+    for (i, pass) in passes.iter().enumerate() {
+        let mut deps = Vec::new();
+        for &resource in pass.inputs() {
+            let &(_, producer) =
+                producers
+                    .iter()
+                    .find(|(r, _)| *r == resource)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "'{}' reads '{}', which no pass writes",
+                            pass.name(),
+                            resource.name()
+                        )
+                    })?;
+            if producer != i {
+                deps.push(producer);
+            }
+        }
+        dependencies.push(deps);
+    }
+
+    // Kahn : à chaque étape, la première passe (ordre de déclaration) prête
+    let mut placed = vec![false; passes.len()];
+    let mut order = Vec::with_capacity(passes.len());
+    while order.len() < passes.len() {
+        let ready = (0..passes.len())
+            .find(|&i| !placed[i] && dependencies[i].iter().all(|&d| placed[d]))
+            .ok_or_else(|| {
+                let cycle: Vec<_> = (0..passes.len())
+                    .filter(|&i| !placed[i])
+                    .map(|i| passes[i].name())
+                    .collect();
+                anyhow!("Render pass cycle between: {}", cycle.join(", "))
+            })?;
+        placed[ready] = true;
+        order.push(ready);
+    }
+    Ok(order)
+}
+
+/// État d'une passe lors de la dernière frame (commande `renderer.passes`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassStatus {
+    pub name: &'static str,
+    pub inputs: &'static [Resource],
+    pub outputs: &'static [Resource],
+    pub enabled: bool,
+    /// Dernière durée GPU relue (différée de quelques frames)
+    pub gpu_time: Option<Duration>,
+}
+
+/// Une ligne par passe, dans l'ordre d'exécution.
+pub fn format_pass_list(passes: &[PassStatus]) -> String {
+    if passes.is_empty() {
+        return "No render pass executed yet".to_string();
+    }
+    let resources = |list: &[Resource]| {
+        if list.is_empty() {
+            "-".to_string()
+        } else {
+            list.iter().map(|r| r.name()).collect::<Vec<_>>().join(", ")
+        }
+    };
+    let mut lines = vec![format!("Render passes ({}):", passes.len())];
+    for (i, pass) in passes.iter().enumerate() {
+        let timing = match (pass.enabled, pass.gpu_time) {
+            (false, _) => "off".to_string(),
+            (true, Some(t)) => format!("{:.3} ms", t.as_secs_f64() * 1000.0),
+            (true, None) => "n/a".to_string(),
+        };
+        lines.push(format!(
+            "{:>2}. {:<14} {} -> {}  [{}]",
+            i + 1,
+            pass.name,
+            resources(pass.inputs),
+            resources(pass.outputs),
+            timing
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Passes ordonnées et leurs chronomètres GPU.
+pub struct FrameGraph {
+    passes: Vec<Box<dyn RenderPass>>,
+    /// Créés à la première exécution (le graphe se construit sans contexte GL)
+    timers: Vec<Option<GpuTimer>>,
+    /// Passes exécutées lors de la dernière frame
+    active: Vec<bool>,
+    /// Requêtes de chronométrage disponibles (détecté à la première exécution)
+    gpu_timing: Option<bool>,
+}
+
+impl FrameGraph {
+    pub fn new(passes: Vec<Box<dyn RenderPass>>) -> Result<Self> {
+        let order = execution_order(&passes)?;
+        let mut slots: Vec<_> = passes.into_iter().map(Some).collect();
+        let passes: Vec<_> = order.iter().filter_map(|&i| slots[i].take()).collect();
+        let count = passes.len();
+        Ok(Self {
+            passes,
+            timers: (0..count).map(|_| None).collect(),
+            active: vec![false; count],
+            gpu_timing: None,
+        })
+    }
+
+    /// Noms des passes, dans l'ordre d'exécution
+    pub fn names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|p| p.name()).collect()
+    }
+
+    /// Exécute les passes actives, chacune encadrée par son chronomètre GPU.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn execute(&mut self, res: &mut PassResources, frame: &FrameContext) {
+        let gpu_timing = *self.gpu_timing.get_or_insert_with(|| {
+            let available = timer_queries_available();
+            if !available {
+                warn!("⚠️ GPU timer queries unavailable: no gpu:* metrics");
+            }
+            available
+        });
+        for ((pass, timer), active) in self
+            .passes
+            .iter_mut()
+            .zip(&mut self.timers)
+            .zip(&mut self.active)
+        {
+            *active = pass.enabled(frame);
+            if !*active {
+                continue;
+            }
+            if !gpu_timing {
+                pass.execute(res, frame);
+                continue;
+            }
+            let timer = timer.get_or_insert_with(|| GpuTimer::new());
+            timer.begin();
+            pass.execute(res, frame);
+            timer.end();
+        }
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn resize(&mut self, res: &mut PassResources, width: u32, height: u32) {
+        for pass in &mut self.passes {
+            if let Err(e) = pass.resize(res, width, height) {
+                warn!("⚠️ {} resize: {}", pass.name(), e);
+            }
+        }
+    }
+
+    /// Recompile les shaders de chaque passe ; une passe en échec garde les siens.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn reload_shaders(&mut self, res: &mut PassResources) {
+        let mut failures = 0;
+        for pass in &mut self.passes {
+            if let Err(e) = pass.reload_shaders(res) {
+                warn!("⚠️ {} shaders not reloaded: {:#}", pass.name(), e);
+                failures += 1;
+            }
+        }
+        if failures == 0 {
+            info!("🔁 Render pass shaders reloaded");
+        }
+    }
+
+    /// Durées GPU des passes exécutées lors de la dernière frame
+    pub fn gpu_times(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.passes
+            .iter()
+            .zip(&self.timers)
+            .zip(&self.active)
+            .filter(|(_, &active)| active)
+            .filter_map(|((pass, timer), _)| Some((pass.name(), timer.as_ref()?.last()?)))
+    }
+
+    /// Remplit `out` (réutilisé d'une frame à l'autre) avec l'état de chaque passe.
+    pub fn status_into(&self, out: &mut Vec<PassStatus>) {
+        out.clear();
+        for ((pass, timer), &enabled) in self.passes.iter().zip(&self.timers).zip(&self.active) {
+            out.push(PassStatus {
+                name: pass.name(),
+                inputs: pass.inputs(),
+                outputs: pass.outputs(),
+                enabled,
+                gpu_time: timer.as_ref().and_then(|t| t.last()),
+            });
+        }
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn close(&mut self) {
+        for timer in self.timers.iter_mut().flatten() {
+            timer.delete();
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Sélection de l'écran et du mode plein écran, indépendante du backend.
+//!
+//! [`FullscreenState`] décide du placement de la fenêtre ([`WindowPlacement`]) à
+//! partir d'une demande (F11, console) et de la liste des écrans ; le backend
+//! (glfw) se contente de l'appliquer. Le redimensionnement qui suit passe par
+//! l'événement `Resize` habituel (viewport, largeur physique, auditeur audio).
+
+use anyhow::{bail, Result};
+
+/// Écran connecté, tel que rapporté par le backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorInfo {
+    pub name: String,
+    /// Coin haut-gauche de l'écran dans le bureau virtuel
+    pub position: (i32, i32),
+    /// Mode vidéo courant (pixels)
+    pub size: (u32, u32),
+    pub refresh_rate: u32,
+    /// Zone de travail (bureau hors barres système) : x, y, largeur, hauteur
+    pub work_area: (i32, i32, i32, i32),
+    pub primary: bool,
+}
+
+/// Mode d'affichage de la fenêtre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    /// Plein écran exclusif (changement de mode vidéo)
+    Fullscreen { monitor: usize },
+    /// Fenêtre sans bordure couvrant la zone de travail de l'écran
+    Borderless { monitor: usize },
+}
+
+impl DisplayMode {
+    pub fn describe(&self, monitors: &[MonitorInfo]) -> String {
+        let name = |i: usize| monitors.get(i).map_or("?", |m| m.name.as_str()).to_string();
+        match *self {
+            DisplayMode::Windowed => "windowed".to_string(),
+            DisplayMode::Fullscreen { monitor } => {
+                format!("fullscreen on monitor {} ({})", monitor, name(monitor))
+            }
+            DisplayMode::Borderless { monitor } => {
+                format!("borderless on monitor {} ({})", monitor, name(monitor))
+            }
+        }
+    }
+}
+
+/// Demande de changement de mode (F11 ou `renderer.window.fullscreen`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FullscreenRequest {
+    /// Bascule fenêtré ↔ mode configuré (`fullscreen_monitor`, `fullscreen_borderless`)
+    Toggle,
+    Fullscreen(usize),
+    Borderless(usize),
+    Windowed,
+}
+
+impl FullscreenRequest {
+    /// Arguments de `renderer.window.fullscreen` : vide, `<index>`, `borderless [index]`, `off`.
+    /// `default_monitor` sert pour `borderless` sans index.
+    pub fn parse(args: &[&str], default_monitor: usize) -> Option<Self> {
+        match args {
+            [] => Some(FullscreenRequest::Toggle),
+            ["off"] => Some(FullscreenRequest::Windowed),
+            ["borderless"] => Some(FullscreenRequest::Borderless(default_monitor)),
+            ["borderless", index] => index.parse().ok().map(FullscreenRequest::Borderless),
+            [index] => index.parse().ok().map(FullscreenRequest::Fullscreen),
+            _ => None,
+        }
+    }
+}
+
+/// Placement à appliquer par le backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowPlacement {
+    /// Fenêtre décorée, position et taille restaurées
+    Windowed { pos: (i32, i32), size: (i32, i32) },
+    Fullscreen {
+        monitor: usize,
+        size: (u32, u32),
+        refresh_rate: u32,
+    },
+    /// Fenêtre sans décoration à `pos`, de taille `size`
+    Borderless { pos: (i32, i32), size: (i32, i32) },
+}
+
+/// Mode courant et géométrie fenêtrée à restaurer.
+#[derive(Debug, Clone, Default)]
+pub struct FullscreenState {
+    pub mode: DisplayMode,
+    /// Position et taille de la fenêtre avant le passage en plein écran
+    pub windowed_pos: (i32, i32),
+    pub windowed_size: (i32, i32),
+}
+
+impl FullscreenState {
+    pub fn new(windowed_pos: (i32, i32), windowed_size: (i32, i32)) -> Self {
+        Self {
+            mode: DisplayMode::Windowed,
+            windowed_pos,
+            windowed_size,
+        }
+    }
+
+    /// Résout `request` ; `None` si la fenêtre est déjà dans le mode demandé.
+    ///
+    /// `current_pos` / `current_size` (géométrie actuelle de la fenêtre) sont mémorisés
+    /// en quittant le mode fenêtré. `config` : écran et mode utilisés par `Toggle`.
+    pub fn resolve(
+        &mut self,
+        request: FullscreenRequest,
+        monitors: &[MonitorInfo],
+        current_pos: (i32, i32),
+        current_size: (i32, i32),
+        config: (usize, bool),
+    ) -> Result<Option<WindowPlacement>> {
+        let target = match request {
+            FullscreenRequest::Windowed => DisplayMode::Windowed,
+            FullscreenRequest::Toggle if self.mode != DisplayMode::Windowed => {
+                DisplayMode::Windowed
+            }
+            FullscreenRequest::Toggle => {
+                // Écran configuré absent (projecteur débranché) : écran principal
+                let (monitor, borderless) = config;
+                let monitor = if monitor < monitors.len() {
+                    monitor
+                } else {
+                    monitors.iter().position(|m| m.primary).unwrap_or(0)
+                };
+                if borderless {
+                    DisplayMode::Borderless { monitor }
+                } else {
+                    DisplayMode::Fullscreen { monitor }
+                }
+            }
+            FullscreenRequest::Fullscreen(monitor) => DisplayMode::Fullscreen { monitor },
+            FullscreenRequest::Borderless(monitor) => DisplayMode::Borderless { monitor },
+        };
+
+        if let DisplayMode::Fullscreen { monitor } | DisplayMode::Borderless { monitor } = target {
+            if monitor >= monitors.len() {
+                bail!(
+                    "Monitor {} not found ({} connected)",
+                    monitor,
+                    monitors.len()
+                );
+            }
+        }
+        if target == self.mode {
+            return Ok(None);
+        }
+
+        if self.mode == DisplayMode::Windowed {
+            self.windowed_pos = current_pos;
+            self.windowed_size = current_size;
+        }
+        self.mode = target;
+
+        Ok(Some(match target {
+            DisplayMode::Windowed => WindowPlacement::Windowed {
+                pos: self.windowed_pos,
+                size: self.windowed_size,
+            },
+            DisplayMode::Fullscreen { monitor } => WindowPlacement::Fullscreen {
+                monitor,
+                size: monitors[monitor].size,
+                refresh_rate: monitors[monitor].refresh_rate,
+            },
+            DisplayMode::Borderless { monitor } => {
+                let (x, y, width, height) = monitors[monitor].work_area;
+                WindowPlacement::Borderless {
+                    pos: (x, y),
+                    size: (width, height),
+                }
+            }
+        }))
+    }
+}
+
+/// Liste des écrans (`renderer.window.monitors`)
+pub fn format_monitors(monitors: &[MonitorInfo]) -> String {
+    if monitors.is_empty() {
+        return "No monitor detected".to_string();
+    }
+    let mut out = String::from("Monitors:");
+    for (i, m) in monitors.iter().enumerate() {
+        out.push_str(&format!(
+            "\n  {}: {} {}x{}@{}Hz at ({}, {}){}",
+            i,
+            m.name,
+            m.size.0,
+            m.size.1,
+            m.refresh_rate,
+            m.position.0,
+            m.position.1,
+            if m.primary { " [primary]" } else { "" }
+        ));
+    }
+    out
+}
@@ -0,0 +1,308 @@
+//! Manettes : état neutre, liaisons bouton → `Action` et interprétation par frame.
+//!
+//! Le backend (glfw) fournit à chaque frame la liste des manettes et leur état
+//! ([`GamepadDevice`]) ; le [`GamepadController`] en tire des [`Reaction`]s, comme
+//! l'`EventRouter` pour le clavier : boutons liés à une action (front montant),
+//! lancement de fusée répété tant que la gâchette est tenue, déplacement de la
+//! caméra au stick gauche.
+
+use glam::Vec2;
+use log::info;
+
+use crate::renderer_engine::window_event::{Action, Reaction};
+
+/// Intervalle (s) entre deux lancements tant que l'entrée `launch_rocket` est tenue
+pub const LAUNCH_REPEAT_INTERVAL: f32 = 0.25;
+/// Zone morte radiale des sticks (fraction de la course)
+pub const STICK_DEADZONE: f32 = 0.15;
+/// Exposant de la courbe de réponse des sticks (> 1 : plus de précision au centre)
+pub const STICK_RESPONSE_EXPONENT: f32 = 2.0;
+/// Vitesse de déplacement de la caméra stick au maximum (pixels écran / s)
+pub const STICK_PAN_SPEED: f32 = 800.0;
+/// Seuil (0..1) au-delà duquel une gâchette compte comme pressée
+pub const TRIGGER_THRESHOLD: f32 = 0.5;
+
+macro_rules! gamepad_inputs {
+    ($($variant:ident => $name:literal),* $(,)?) => {
+        /// Bouton ou gâchette de manette (disposition Xbox), nommé comme dans `input.toml`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum GamepadInput {
+            $($variant,)*
+        }
+
+        impl GamepadInput {
+            pub const ALL: &'static [GamepadInput] = &[$(GamepadInput::$variant,)*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(GamepadInput::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+gamepad_inputs! {
+    A => "a", B => "b", X => "x", Y => "y",
+    LeftBumper => "lb", RightBumper => "rb",
+    LeftTrigger => "lt", RightTrigger => "rt",
+    Back => "back", Start => "start", Guide => "guide",
+    LeftThumb => "ls", RightThumb => "rs",
+    DpadUp => "dpad_up", DpadRight => "dpad_right",
+    DpadDown => "dpad_down", DpadLeft => "dpad_left",
+}
+
+impl GamepadInput {
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|input| input.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Entrée de manette par défaut d'une action (`None` : clavier uniquement).
+pub fn default_gamepad_input(action: Action) -> Option<GamepadInput> {
+    match action {
+        Action::LaunchRocket => Some(GamepadInput::RightTrigger),
+        Action::NextShape => Some(GamepadInput::A),
+        Action::PrevShape => Some(GamepadInput::B),
+        Action::PauseSim => Some(GamepadInput::Start),
+        Action::ToggleHud => Some(GamepadInput::Back),
+        Action::Screenshot => Some(GamepadInput::Y),
+        _ => None,
+    }
+}
+
+/// État d'une manette à la frame courante.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamepadState {
+    /// Boutons pressés (gâchettes comprises, cf. `TRIGGER_THRESHOLD`)
+    pub pressed: Vec<GamepadInput>,
+    /// Stick gauche, -1..1, y vers le haut
+    pub left_stick: Vec2,
+}
+
+/// Manette connectée.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadDevice {
+    /// Identifiant du backend (emplacement glfw)
+    pub id: usize,
+    pub name: String,
+    pub state: GamepadState,
+}
+
+/// Applique la zone morte radiale puis la courbe de réponse à un stick.
+///
+/// La course restante après la zone morte est ramenée à 0..1 (pas de saut en
+/// sortie de zone morte) ; la direction est conservée.
+pub fn stick_response(stick: Vec2, deadzone: f32, exponent: f32) -> Vec2 {
+    let magnitude = stick.length().min(1.0);
+    if magnitude <= deadzone {
+        return Vec2::ZERO;
+    }
+    let scaled = ((magnitude - deadzone) / (1.0 - deadzone).max(f32::EPSILON)).clamp(0.0, 1.0);
+    stick.normalize() * scaled.powf(exponent)
+}
+
+/// Répétition bornée d'une entrée tenue : déclenche à l'appui, puis toutes les
+/// `interval` secondes tant qu'elle reste tenue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatLimiter {
+    pub interval: f32,
+    held: bool,
+    cooldown: f32,
+}
+
+impl RepeatLimiter {
+    pub fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            held: false,
+            cooldown: 0.0,
+        }
+    }
+
+    /// Retourne `true` si l'entrée déclenche à cette frame.
+    pub fn update(&mut self, held: bool, dt: f32) -> bool {
+        if !held {
+            self.held = false;
+            return false;
+        }
+        if !self.held {
+            self.held = true;
+            self.cooldown = self.interval;
+            return true;
+        }
+        self.cooldown -= dt;
+        if self.cooldown <= 0.0 {
+            // Reliquat conservé (cadence stable quel que soit le framerate), sauf
+            // après une frame très longue : pas de rafale de rattrapage
+            self.cooldown += self.interval;
+            if self.cooldown <= 0.0 {
+                self.cooldown = self.interval;
+            }
+            return true;
+        }
+        false
+    }
+}
+
+/// Entrée de manette liée à chaque `Action`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GamepadBindings {
+    /// Une entrée (optionnelle) par action, dans l'ordre de `Action::ALL`
+    inputs: [Option<GamepadInput>; Action::ALL.len()],
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Self {
+            inputs: Action::ALL.map(default_gamepad_input),
+        }
+    }
+}
+
+impl GamepadBindings {
+    fn slot(action: Action) -> usize {
+        Action::ALL
+            .iter()
+            .position(|&a| a == action)
+            .expect("action listed in Action::ALL")
+    }
+
+    pub fn input_for(&self, action: Action) -> Option<GamepadInput> {
+        self.inputs[Self::slot(action)]
+    }
+
+    pub fn bind(&mut self, action: Action, input: Option<GamepadInput>) {
+        self.inputs[Self::slot(action)] = input;
+    }
+
+    /// Action déclenchée par `input` ; en cas de doublon, la première de `Action::ALL`.
+    pub fn action_for(&self, input: GamepadInput) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .zip(self.inputs)
+            .find(|&(_, bound)| bound == Some(input))
+            .map(|(action, _)| action)
+    }
+}
+
+/// Suivi des manettes connectées et interprétation de leur état.
+#[derive(Debug, Clone)]
+pub struct GamepadController {
+    /// Manettes vues à la frame précédente : (id, nom)
+    connected: Vec<(usize, String)>,
+    /// Entrées pressées à la frame précédente, toutes manettes confondues
+    previous: Vec<GamepadInput>,
+    launch: RepeatLimiter,
+}
+
+impl Default for GamepadController {
+    fn default() -> Self {
+        Self {
+            connected: Vec::new(),
+            previous: Vec::new(),
+            launch: RepeatLimiter::new(LAUNCH_REPEAT_INTERVAL),
+        }
+    }
+}
+
+impl GamepadController {
+    /// Manettes connectées : (id, nom)
+    pub fn connected(&self) -> &[(usize, String)] {
+        &self.connected
+    }
+
+    /// Interprète l'état des manettes pour une frame de durée `dt`.
+    ///
+    /// Retourne les réactions à appliquer et `true` si la liste des manettes a changé.
+    pub fn update(
+        &mut self,
+        devices: &[GamepadDevice],
+        bindings: &GamepadBindings,
+        dt: f32,
+    ) -> (Vec<Reaction>, bool) {
+        let changed = self.track_connections(devices);
+
+        let mut pressed: Vec<GamepadInput> = devices
+            .iter()
+            .flat_map(|d| d.state.pressed.iter().copied())
+            .collect();
+        pressed.sort_by_key(|input| input.name());
+        pressed.dedup();
+
+        let mut reactions = Vec::new();
+        let launch_held = bindings
+            .input_for(Action::LaunchRocket)
+            .is_some_and(|input| pressed.contains(&input));
+        if self.launch.update(launch_held, dt) {
+            reactions.push(Reaction::Action(Action::LaunchRocket));
+        }
+        for &input in &pressed {
+            if self.previous.contains(&input) {
+                continue;
+            }
+            match bindings.action_for(input) {
+                Some(Action::LaunchRocket) | None => {}
+                Some(action) => reactions.push(Reaction::Action(action)),
+            }
+        }
+        self.previous = pressed;
+
+        let stick: Vec2 = devices
+            .iter()
+            .map(|d| stick_response(d.state.left_stick, STICK_DEADZONE, STICK_RESPONSE_EXPONENT))
+            .sum();
+        if stick != Vec2::ZERO {
+            // Le stick déplace la vue : la scène glisse en sens inverse
+            reactions.push(Reaction::Pan(
+                -stick.clamp_length_max(1.0) * STICK_PAN_SPEED * dt,
+            ));
+        }
+        (reactions, changed)
+    }
+
+    fn track_connections(&mut self, devices: &[GamepadDevice]) -> bool {
+        let current: Vec<(usize, String)> =
+            devices.iter().map(|d| (d.id, d.name.clone())).collect();
+        if current == self.connected {
+            return false;
+        }
+        for (id, name) in &current {
+            if !self.connected.iter().any(|(known, _)| known == id) {
+                info!("🎮 Gamepad {} connected: {}", id, name);
+            }
+        }
+        for (id, name) in &self.connected {
+            if !current.iter().any(|(known, _)| known == id) {
+                info!("🎮 Gamepad {} disconnected: {}", id, name);
+            }
+        }
+        self.connected = current;
+        true
+    }
+}
+
+/// Manettes et liaisons (`renderer.input.gamepad`)
+pub fn format_gamepads(connected: &[(usize, String)], bindings: &GamepadBindings) -> String {
+    let mut out = if connected.is_empty() {
+        String::from("Gamepads: none")
+    } else {
+        let mut out = String::from("Gamepads:");
+        for (id, name) in connected {
+            out.push_str(&format!("\n  {}: {}", id, name));
+        }
+        out
+    };
+    out.push_str("\nGamepad bindings:");
+    for action in Action::ALL {
+        if let Some(input) = bindings.input_for(action) {
+            out.push_str(&format!("\n  {:<18} {}", action.name(), input.name()));
+        }
+    }
+    out.push_str(&format!("\n  {:<18} {}", "camera_pan", "left stick"));
+    out
+}
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use image::RgbaImage;
+use log::info;
+
+use crate::audio_engine::AudioEngine;
+use crate::duration_limit::DurationLimit;
+use crate::physic_engine::{config::PhysicConfig, PhysicEngineFull};
+use crate::profiler::Profiler;
+use crate::renderer_engine::command_console::CommandRegistry;
+use crate::renderer_engine::render_stats::RenderStats;
+use crate::renderer_engine::{Renderer, RendererEngine};
+use crate::simulator::step_simulation;
+
+/// Pas de temps fixe par défaut (60 FPS), pour des simulations reproductibles
+pub const HEADLESS_DEFAULT_TIME_STEP: f32 = 1.0 / 60.0;
+
+/// Moteur de rendu sans affichage, pour la CI et les tests d'images de référence.
+///
+/// Exécute un nombre fixe de frames à pas de temps constant, sans ImGui ni swap,
+/// et conserve l'image de la dernière frame. La durée limite (`--duration`) peut
+/// arrêter la boucle avant.
+pub struct HeadlessRenderer {
+    renderer: Renderer,
+    frames: usize,
+    duration_limit: Option<DurationLimit>,
+    time_step: f32,
+    last_image: Option<RgbaImage>,
+}
+
+impl HeadlessRenderer {
+    pub fn new(
+        width: i32,
+        height: i32,
+        physic_config: &PhysicConfig,
+        frames: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            renderer: Renderer::new_headless(width, height, physic_config)?,
+            frames,
+            duration_limit: None,
+            time_step: HEADLESS_DEFAULT_TIME_STEP,
+            last_image: None,
+        })
+    }
+
+    pub fn with_time_step(mut self, time_step: f32) -> Self {
+        self.time_step = time_step;
+        self
+    }
+
+    pub fn renderer(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Image de la dernière frame rendue par `run_loop`
+    pub fn last_image(&self) -> Option<&RgbaImage> {
+        self.last_image.as_ref()
+    }
+}
+
+impl RendererEngine for HeadlessRenderer {
+    fn run_loop<P: PhysicEngineFull, A: AudioEngine>(
+        &mut self,
+        physic: &mut P,
+        audio: &mut A,
+        commands_registry: &CommandRegistry,
+    ) -> Result<()> {
+        info!(
+            "🎞️ Headless run: {} frames (dt = {:.4}s)",
+            self.frames, self.time_step
+        );
+        let profiler = self.renderer.profiler.clone();
+        for frame in 0..self.frames {
+            if self
+                .duration_limit
+                .as_ref()
+                .is_some_and(|limit| limit.is_finished(physic.get_stats().active_rockets))
+            {
+                // Spectacle fini avant la dernière frame : l'image est celle du ciel vidé
+                info!("🏁 Duration limit reached after {} frames", frame);
+                self.last_image = Some(self.renderer.render_to_image(physic)?);
+                break;
+            }
+            let _frame_guard = profiler.frame();
+            let flashes = step_simulation(
+                Some(&profiler),
+                physic,
+                audio,
+                commands_registry.sim_state(),
+                self.duration_limit.as_mut(),
+                self.time_step,
+            );
+            self.renderer.advance_clock(self.time_step);
+            self.renderer
+                .follow_audio_level(audio.output_level(), self.time_step);
+            for flash in flashes {
+                self.renderer.add_flash(flash);
+            }
+
+            // Seule la dernière frame est relue depuis le GPU
+            if frame + 1 == self.frames {
+                self.last_image = Some(self.renderer.render_to_image(physic)?);
+            } else {
+                let drawn = profiler.profile_block("render frame", || unsafe {
+                    self.renderer.render_offscreen(physic)
+                });
+                profiler.record_metric("total particles drawn", drawn);
+            }
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.renderer.close();
+    }
+
+    fn register_commands(&self, registry: &mut CommandRegistry) {
+        self.renderer.register_commands(registry);
+    }
+
+    fn render_stats(&self) -> RenderStats {
+        self.renderer.render_stats()
+    }
+
+    fn set_frame_limit(&mut self, frames: Option<u64>) {
+        if let Some(frames) = frames {
+            self.frames = frames as usize;
+        }
+    }
+
+    fn set_duration_limit(&mut self, limit: Option<DurationLimit>) {
+        self.duration_limit = limit;
+    }
+
+    fn profiler(&self) -> Option<&Profiler> {
+        Some(&self.renderer.profiler)
+    }
+}
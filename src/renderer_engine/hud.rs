@@ -0,0 +1,149 @@
+use imgui_glfw_rs::imgui;
+
+use crate::physic_engine::{ParticleType, PhysicStats};
+use crate::profiler::Profiler;
+use crate::renderer_engine::config::RendererConfig;
+
+/// Largeur de l'overlay, en pixels
+const HUD_WIDTH: f32 = 260.0;
+
+/// Texte de l'indicateur de pause
+pub const PAUSED_LABEL: &str = "PAUSED";
+
+/// Valeurs affichées par le HUD de debug, relevées une fois par frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HudStats {
+    /// FPS lissé (moyenne exponentielle)
+    pub fps: f32,
+    /// Dernières durées de frame (ms)
+    pub frame_times: Vec<f32>,
+    /// Particules actives par type
+    pub particles: Vec<(ParticleType, usize)>,
+    pub active_rockets: usize,
+    pub audio_voices: usize,
+    pub bloom_enabled: bool,
+    pub bloom_threshold: f32,
+    pub bloom_intensity: f32,
+    /// Vitesse de simulation (`sim.speed`)
+    pub sim_speed: f32,
+    /// Temps restant avant la fin du spectacle (`--duration`)
+    pub remaining_time: Option<f32>,
+}
+
+impl HudStats {
+    pub fn collect(
+        fps: f32,
+        profiler: &Profiler,
+        physic: &PhysicStats,
+        audio_voices: usize,
+        config: &RendererConfig,
+    ) -> Self {
+        let particles = ParticleType::ALL
+            .iter()
+            .map(|&t| (t, physic.active_particles.get(t)))
+            .collect();
+
+        Self {
+            fps,
+            frame_times: profiler.frame_times(),
+            particles,
+            active_rockets: physic.active_rockets,
+            audio_voices,
+            bloom_enabled: config.bloom_enabled,
+            bloom_threshold: config.bloom_threshold,
+            bloom_intensity: config.bloom_intensity,
+            sim_speed: 1.0,
+            remaining_time: None,
+        }
+    }
+}
+
+/// Temps restant au format `m:ss.s` (`1:05.3`)
+pub fn format_remaining(seconds: f32) -> String {
+    let tenths = (seconds.max(0.0) * 10.0).round() as u64;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+/// Dessine l'overlay en haut à droite, sans capturer le clavier ni la souris.
+pub fn draw_hud(ui: &imgui::Ui, stats: &HudStats) {
+    let display_width = ui.io().display_size[0];
+    let _window_bg = ui.push_style_color(imgui::StyleColor::WindowBg, [0.0, 0.0, 0.0, 0.45]);
+
+    ui.window("HUD")
+        .position(
+            [display_width - HUD_WIDTH - 10.0, 10.0],
+            imgui::Condition::Always,
+        )
+        .size([HUD_WIDTH, 0.0], imgui::Condition::Always)
+        .flags(
+            imgui::WindowFlags::NO_DECORATION
+                | imgui::WindowFlags::NO_INPUTS
+                | imgui::WindowFlags::NO_NAV
+                | imgui::WindowFlags::NO_FOCUS_ON_APPEARING
+                | imgui::WindowFlags::NO_BRING_TO_FRONT_ON_FOCUS
+                | imgui::WindowFlags::NO_SAVED_SETTINGS
+                | imgui::WindowFlags::ALWAYS_AUTO_RESIZE,
+        )
+        .build(|| {
+            ui.text(format!("FPS: {:.1}", stats.fps));
+            // Ralenti / accéléré mis en évidence
+            let speed_color = if stats.sim_speed == 1.0 {
+                [1.0, 1.0, 1.0, 1.0]
+            } else {
+                [1.0, 0.8, 0.2, 1.0]
+            };
+            ui.same_line();
+            ui.text_colored(speed_color, format!("  Speed: x{}", stats.sim_speed));
+            if let Some(remaining) = stats.remaining_time {
+                ui.text(format!("Remaining: {}", format_remaining(remaining)));
+            }
+            ui.plot_lines("##frame_times", &stats.frame_times)
+                .graph_size([HUD_WIDTH - 16.0, 40.0])
+                .scale_min(0.0)
+                .overlay_text(format!(
+                    "{:.2} ms",
+                    stats.frame_times.last().copied().unwrap_or(0.0)
+                ))
+                .build();
+
+            ui.separator();
+            for (particle_type, count) in &stats.particles {
+                ui.text(format!("{:<10} {}", particle_type.name(), count));
+            }
+            ui.text(format!("Active rockets: {}", stats.active_rockets));
+            ui.text(format!("Audio voices: {}", stats.audio_voices));
+
+            ui.separator();
+            if stats.bloom_enabled {
+                ui.text(format!(
+                    "Bloom: threshold {:.2}, intensity {:.2}",
+                    stats.bloom_threshold, stats.bloom_intensity
+                ));
+            } else {
+                ui.text("Bloom: off");
+            }
+        });
+}
+
+/// Indicateur « PAUSED » en haut au centre, affiché pendant la pause globale
+/// même si le HUD est masqué.
+pub fn draw_pause_indicator(ui: &imgui::Ui) {
+    let display_width = ui.io().display_size[0];
+    let _window_bg = ui.push_style_color(imgui::StyleColor::WindowBg, [0.0, 0.0, 0.0, 0.45]);
+
+    ui.window("Paused")
+        .position([display_width / 2.0, 10.0], imgui::Condition::Always)
+        .position_pivot([0.5, 0.0])
+        .flags(
+            imgui::WindowFlags::NO_DECORATION
+                | imgui::WindowFlags::NO_INPUTS
+                | imgui::WindowFlags::NO_NAV
+                | imgui::WindowFlags::NO_FOCUS_ON_APPEARING
+                | imgui::WindowFlags::NO_BRING_TO_FRONT_ON_FOCUS
+                | imgui::WindowFlags::NO_SAVED_SETTINGS
+                | imgui::WindowFlags::ALWAYS_AUTO_RESIZE,
+        )
+        .build(|| {
+            ui.text_colored([1.0, 0.8, 0.2, 1.0], format!("⏸ {}", PAUSED_LABEL));
+        });
+}
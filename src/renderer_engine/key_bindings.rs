@@ -0,0 +1,162 @@
+use anyhow::Result;
+use log::{info, warn};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::renderer_engine::gamepad::{GamepadBindings, GamepadInput};
+use crate::renderer_engine::window_event::{Action, KeyCode};
+use crate::utils::embedded_assets::read_asset_to_string;
+
+/// Chemin par défaut des raccourcis clavier
+pub const INPUT_CONFIG_PATH: &str = "assets/config/input.toml";
+
+/// Contenu de `input.toml` : tables `[bindings]` (action → touche) et
+/// `[gamepad]` (action → entrée de manette ou `"none"`).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct InputFile {
+    bindings: BTreeMap<String, String>,
+    gamepad: BTreeMap<String, String>,
+}
+
+/// Touche (et entrée de manette) associée à chaque `Action`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    /// Une touche par action, dans l'ordre de `Action::ALL`
+    keys: [KeyCode; Action::ALL.len()],
+    pub gamepad: GamepadBindings,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: Action::ALL.map(|action| action.default_key()),
+            gamepad: GamepadBindings::default(),
+        }
+    }
+}
+
+impl KeyBindings {
+    fn slot(action: Action) -> usize {
+        Action::ALL
+            .iter()
+            .position(|&a| a == action)
+            .expect("action listed in Action::ALL")
+    }
+
+    pub fn key_for(&self, action: Action) -> KeyCode {
+        self.keys[Self::slot(action)]
+    }
+
+    pub fn bind(&mut self, action: Action, key: KeyCode) {
+        self.keys[Self::slot(action)] = key;
+    }
+
+    /// Action déclenchée par `key` ; en cas de doublon, la première de `Action::ALL`.
+    pub fn action_for(&self, key: KeyCode) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .zip(self.keys)
+            .find(|&(_, bound)| bound == key)
+            .map(|(action, _)| action)
+    }
+
+    /// Touches liées à plusieurs actions.
+    pub fn duplicates(&self) -> Vec<(KeyCode, Vec<Action>)> {
+        let mut duplicates: Vec<(KeyCode, Vec<Action>)> = Vec::new();
+        for (action, key) in Action::ALL.into_iter().zip(self.keys) {
+            match duplicates.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, actions)) => actions.push(action),
+                None => duplicates.push((key, vec![action])),
+            }
+        }
+        duplicates.retain(|(_, actions)| actions.len() > 1);
+        duplicates
+    }
+
+    /// Lit un `input.toml`. Action ou touche inconnue : avertissement et valeur par défaut.
+    /// Retourne les liaisons et les avertissements (doublons compris).
+    pub fn parse(text: &str) -> Result<(Self, Vec<String>)> {
+        let file: InputFile = toml::from_str(text)?;
+        let mut bindings = Self::default();
+        let mut warnings = Vec::new();
+
+        for (action_name, key_name) in &file.bindings {
+            let Some(action) = Action::from_name(action_name) else {
+                warnings.push(format!("Unknown action '{}' ignored", action_name));
+                continue;
+            };
+            match KeyCode::from_name(key_name) {
+                Some(key) => bindings.bind(action, key),
+                None => warnings.push(format!(
+                    "Unknown key '{}' for {}, using default '{}'",
+                    key_name,
+                    action.name(),
+                    action.default_key().name()
+                )),
+            }
+        }
+
+        for (action_name, input_name) in &file.gamepad {
+            let Some(action) = Action::from_name(action_name) else {
+                warnings.push(format!("Unknown gamepad action '{}' ignored", action_name));
+                continue;
+            };
+            if input_name.eq_ignore_ascii_case("none") {
+                bindings.gamepad.bind(action, None);
+                continue;
+            }
+            match GamepadInput::from_name(input_name) {
+                Some(input) => bindings.gamepad.bind(action, Some(input)),
+                None => warnings.push(format!(
+                    "Unknown gamepad input '{}' for {}, keeping default",
+                    input_name,
+                    action.name()
+                )),
+            }
+        }
+
+        for (key, actions) in bindings.duplicates() {
+            let names: Vec<&str> = actions.iter().map(Action::name).collect();
+            warnings.push(format!(
+                "Key '{}' bound to {}: only {} is triggered",
+                key.name(),
+                names.join(", "),
+                names[0]
+            ));
+        }
+        Ok((bindings, warnings))
+    }
+
+    /// Charge `path` ; fichier absent ou invalide : liaisons par défaut.
+    pub fn load(path: &str) -> Self {
+        let text = match read_asset_to_string(path) {
+            Ok(text) => text,
+            Err(_) => {
+                info!("⌨️ No key bindings at {}, using defaults", path);
+                return Self::default();
+            }
+        };
+        match Self::parse(&text) {
+            Ok((bindings, warnings)) => {
+                for warning in warnings {
+                    warn!("⚠️ {}: {}", path, warning);
+                }
+                bindings
+            }
+            Err(e) => {
+                warn!("⚠️ Invalid key bindings {}: {}, using defaults", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Une ligne par action (`renderer.input.bindings`)
+    pub fn format(&self) -> String {
+        let mut out = String::from("Key bindings:");
+        for (action, key) in Action::ALL.into_iter().zip(self.keys) {
+            out.push_str(&format!("\n  {:<18} {}", action.name(), key.name()));
+        }
+        out
+    }
+}
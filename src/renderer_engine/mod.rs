@@ -1,16 +1,66 @@
 pub mod r#trait;
 pub use r#trait::RendererEngine;
 
+pub mod audio_reactive;
+pub mod background;
+pub use self::background::BackgroundRenderer;
+pub mod bloom;
+pub use self::bloom::BloomPass;
+pub mod camera;
+pub use self::camera::Camera2D;
+pub mod command_alias;
+pub mod command_bind;
+pub mod command_cvar;
+pub mod command_help;
+pub mod command_script;
+pub mod command_sim;
+pub mod command_stats;
+pub mod command_transcript;
+pub mod config;
+pub mod config_reload;
+pub mod display_scale;
+pub mod file_drop;
+pub mod flash;
+pub mod frame_graph;
+pub mod fullscreen;
+pub mod gamepad;
+pub use self::frame_graph::{FrameGraph, RenderPass};
+pub mod hud;
+pub mod key_bindings;
+pub mod post_process;
+pub use self::config::{RecordingConfig, RendererConfig};
+pub use self::post_process::{FxaaPass, PostPass};
+
 pub mod renderer;
 pub use self::renderer::Renderer;
+pub mod headless;
+pub use self::headless::HeadlessRenderer;
+pub mod null_renderer;
+pub use self::null_renderer::NullRendererEngine;
+#[cfg(feature = "backend_winit")]
+pub mod winit_renderer;
+#[cfg(feature = "backend_winit")]
+pub use self::winit_renderer::WinitRenderer;
+pub mod recorder;
+pub mod render_passes;
+pub mod render_stats;
+pub use self::recorder::FrameRecorder;
+pub use self::render_stats::RenderStats;
 pub mod particle_renderer;
 pub use self::particle_renderer::ParticleGraphicsRenderer;
 pub mod renderer_graphics;
 pub use self::renderer_graphics::RendererGraphics;
 pub mod renderer_graphics_instanced;
 pub use self::renderer_graphics_instanced::RendererGraphicsInstanced;
+pub mod renderer_trail_ribbon;
+pub use self::renderer_trail_ribbon::TrailRibbonRenderer;
 
+pub mod shader;
+pub mod testing;
+pub mod tonemap;
 pub mod tools;
+pub mod window_event;
+pub mod window_status;
 pub use self::tools::show_opengl_context_info;
 
 pub mod types;
@@ -20,4 +70,6 @@ pub mod utils;
 pub use self::utils::glfw_window;
 
 pub mod command_console;
+pub mod console_output;
+pub mod console_server;
 pub use self::command_console::Console;
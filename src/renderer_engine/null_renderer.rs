@@ -0,0 +1,90 @@
+use anyhow::Result;
+
+use crate::audio_engine::AudioEngine;
+use crate::duration_limit::DurationLimit;
+use crate::physic_engine::PhysicEngineFull;
+use crate::profiler::Profiler;
+use crate::renderer_engine::command_console::CommandRegistry;
+use crate::renderer_engine::headless::HEADLESS_DEFAULT_TIME_STEP;
+use crate::renderer_engine::RendererEngine;
+use crate::simulator::step_simulation;
+
+/// Moteur de rendu vide : ni fenêtre, ni contexte OpenGL.
+///
+/// Utilisé par le mode `--headless` : la boucle à pas fixe est celle du
+/// simulateur (`Simulator::run_headless`), ce moteur ne fournit que le profiler
+/// dont les métriques sont exportées (`--metrics-out`). Avec une limite de
+/// frames (`bench --no-render`), `run_loop` simule ces frames à pas fixe, avec le
+/// même pas que la boucle fenêtrée (démo, durée limite).
+pub struct NullRendererEngine {
+    profiler: Profiler,
+    frame_limit: Option<u64>,
+    duration_limit: Option<DurationLimit>,
+    time_step: f32,
+}
+
+impl NullRendererEngine {
+    pub fn new() -> Self {
+        Self {
+            profiler: Profiler::new(200),
+            frame_limit: None,
+            duration_limit: None,
+            time_step: HEADLESS_DEFAULT_TIME_STEP,
+        }
+    }
+}
+
+impl Default for NullRendererEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RendererEngine for NullRendererEngine {
+    fn run_loop<P: PhysicEngineFull, A: AudioEngine>(
+        &mut self,
+        physic: &mut P,
+        audio: &mut A,
+        commands_registry: &CommandRegistry,
+    ) -> Result<()> {
+        let Some(frames) = self.frame_limit else {
+            anyhow::bail!(
+                "NullRendererEngine has no frame loop without a frame limit: \
+                 use Simulator::run_headless"
+            )
+        };
+        for _ in 0..frames {
+            if self
+                .duration_limit
+                .as_ref()
+                .is_some_and(|limit| limit.is_finished(physic.get_stats().active_rockets))
+            {
+                break;
+            }
+            let _frame_guard = self.profiler.frame();
+            step_simulation(
+                Some(&self.profiler),
+                physic,
+                audio,
+                commands_registry.sim_state(),
+                self.duration_limit.as_mut(),
+                self.time_step,
+            );
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) {}
+
+    fn set_frame_limit(&mut self, frames: Option<u64>) {
+        self.frame_limit = frames;
+    }
+
+    fn set_duration_limit(&mut self, limit: Option<DurationLimit>) {
+        self.duration_limit = limit;
+    }
+
+    fn profiler(&self) -> Option<&Profiler> {
+        Some(&self.profiler)
+    }
+}
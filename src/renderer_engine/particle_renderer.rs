@@ -1,4 +1,10 @@
+use glam::Mat3;
+use std::time::Duration;
+
 use crate::physic_engine::PhysicEngineIterator;
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::flash::FlashUniforms;
+use crate::renderer_engine::render_stats::{DrawStats, GpuMemory};
 
 /// Trait générique pour un rendu de particules.
 /// Permet d'abstraire le type de rendu (points, quads texturés, etc.)
@@ -17,11 +23,40 @@ pub trait ParticleGraphicsRenderer {
     /// Cette fonction est unsafe car elle manipule directement des ressources OpenGL.
     unsafe fn fill_particle_data_direct(&mut self, physic: &dyn PhysicEngineIterator) -> usize;
 
-    /// Dessine les particules à l'écran.
+    /// Dessine les particules à l'écran (`view_proj` : monde → clip space, cf. `Camera2D`).
     ///
     /// # Safety
     /// Cette fonction est unsafe car elle manipule directement des ressources OpenGL.
-    unsafe fn render_particles_with_persistent_buffer(&self, count: usize, window_size: (f32, f32));
+    unsafe fn render_particles_with_persistent_buffer(&self, count: usize, view_proj: &Mat3);
+
+    /// Attente CPU sur le GPU lors du dernier remplissage du buffer (multi-buffering).
+    fn sync_wait(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Durée du tri des particules lors du dernier remplissage (0 si non trié).
+    fn sort_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Particules envoyées et appels de dessin de la dernière frame.
+    fn draw_stats(&self) -> DrawStats {
+        DrawStats::default()
+    }
+
+    /// Mémoire GPU allouée (buffers et textures).
+    fn gpu_memory(&self) -> GpuMemory {
+        GpuMemory::default()
+    }
+
+    /// Applique les réglages de rendu courants (appelé avant chaque frame).
+    fn apply_config(&mut self, _config: &RendererConfig) {}
+
+    /// Facteur d'échelle de l'affichage (DPI) appliqué aux tailles en pixels.
+    fn set_content_scale(&mut self, _scale: f32) {}
+
+    /// Éclairs de détonation actifs, ajoutés à la couleur des particules.
+    fn set_flashes(&mut self, _flashes: &FlashUniforms) {}
 
     /// Libère les ressources GPU.
     ///
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use gl::types::*;
+
+use crate::renderer_engine::bloom::{Surface, FULLSCREEN_VS};
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::render_stats::GpuMemory;
+use crate::renderer_engine::tonemap::ToneMappingMode;
+use crate::renderer_engine::tools::compile_shader_program;
+use crate::{cstr, gl_check};
+
+const FXAA_FS: &str = include_str!("../../assets/shaders/post/fxaa.frag.glsl");
+
+/// Étapes du rendu d'une frame, dans l'ordre d'exécution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostPass {
+    /// Fond + particules
+    Scene,
+    /// Extraction et flou des zones lumineuses
+    Bloom,
+    /// Composition scène + bloom et exposition
+    Tonemap,
+    /// Anti-aliasing FXAA
+    Fxaa,
+    /// Présentation dans la cible de sortie (fenêtre ou FBO headless)
+    Backbuffer,
+}
+
+/// Chaîne de passes active pour une config : les passes désactivées n'apparaissent pas
+/// (et ne coûtent donc rien).
+pub fn post_process_chain(config: &RendererConfig) -> Vec<PostPass> {
+    let mut chain = vec![PostPass::Scene];
+    if config.bloom_enabled {
+        chain.push(PostPass::Bloom);
+    }
+    if config.bloom_enabled
+        || config.auto_exposure_enabled
+        || config.tonemapping != ToneMappingMode::Linear
+        || config.tonemapping_compare
+        || config.gamma_compare
+        || config.render_scale < 1.0
+    {
+        chain.push(PostPass::Tonemap);
+    }
+    if config.fxaa_enabled {
+        chain.push(PostPass::Fxaa);
+    }
+    chain.push(PostPass::Backbuffer);
+    chain
+}
+
+/// Passe FXAA : la frame est rendue dans une cible intermédiaire puis filtrée
+/// vers la cible de sortie. La cible n'est allouée qu'à la première utilisation.
+pub struct FxaaPass {
+    target: Surface,
+    program: u32,
+    fullscreen_vao: u32,
+    loc_image: i32,
+    loc_inv_resolution: i32,
+}
+
+impl FxaaPass {
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn new() -> Self {
+        let program = compile_shader_program(FULLSCREEN_VS, FXAA_FS);
+        let mut fullscreen_vao = 0;
+        gl::GenVertexArrays(1, &mut fullscreen_vao);
+        Self {
+            target: Surface::default(),
+            loc_image: gl::GetUniformLocation(program, cstr!("uImage")),
+            loc_inv_resolution: gl::GetUniformLocation(program, cstr!("uInvResolution")),
+            program,
+            fullscreen_vao,
+        }
+    }
+
+    /// FBO de la cible intermédiaire (0 tant qu'elle n'est pas allouée).
+    pub fn framebuffer(&self) -> GLuint {
+        self.target.fbo
+    }
+
+    /// Taille de la cible intermédiaire ((0, 0) tant qu'elle n'est pas allouée).
+    pub fn target_size(&self) -> (u32, u32) {
+        (self.target.width, self.target.height)
+    }
+
+    pub fn gpu_memory(&self) -> GpuMemory {
+        GpuMemory {
+            framebuffers: self.target.byte_size(),
+            ..Default::default()
+        }
+    }
+
+    /// Recrée la cible si elle est déjà allouée et que la taille change.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn resize(&mut self, width: u32, height: u32) -> Result<()> {
+        if self.target.fbo == 0 || (self.target.width, self.target.height) == (width, height) {
+            return Ok(());
+        }
+        self.target.delete();
+        self.target = Surface::new(width.max(1), height.max(1))?;
+        Ok(())
+    }
+
+    /// Alloue la cible au besoin puis l'active et l'efface.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn begin(&mut self, width: u32, height: u32) -> Result<()> {
+        if self.target.fbo == 0 {
+            self.target = Surface::new(width.max(1), height.max(1))?;
+        } else {
+            self.resize(width, height)?;
+        }
+        self.target.bind();
+        gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+        Ok(())
+    }
+
+    /// Filtre la cible intermédiaire vers `output_fbo`.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn end(&self, output_fbo: GLuint, output_size: (i32, i32)) {
+        gl::BindFramebuffer(gl::FRAMEBUFFER, output_fbo);
+        gl::Viewport(0, 0, output_size.0, output_size.1);
+        gl::Disable(gl::BLEND);
+        gl::UseProgram(self.program);
+        gl::Uniform1i(self.loc_image, 0);
+        gl::Uniform2f(
+            self.loc_inv_resolution,
+            1.0 / self.target.width as f32,
+            1.0 / self.target.height as f32,
+        );
+        gl::BindVertexArray(self.fullscreen_vao);
+        gl::ActiveTexture(gl::TEXTURE0);
+        gl::BindTexture(gl::TEXTURE_2D, self.target.texture);
+        gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+        gl_check!("fxaa pass");
+
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+        gl::BindVertexArray(0);
+        gl::Enable(gl::BLEND);
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn close(&mut self) {
+        self.target.delete();
+        if self.program != 0 {
+            gl::DeleteProgram(self.program);
+            self.program = 0;
+        }
+        if self.fullscreen_vao != 0 {
+            gl::DeleteVertexArrays(1, &self.fullscreen_vao);
+            self.fullscreen_vao = 0;
+        }
+    }
+}
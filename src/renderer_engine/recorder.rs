@@ -0,0 +1,329 @@
+use anyhow::{anyhow, Context, Result};
+use gl::types::*;
+use log::{info, warn};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+
+use crate::gl_check;
+use crate::renderer_engine::config::RecordingConfig;
+use crate::renderer_engine::utils::screenshot::timestamped_path;
+
+/// Répertoire des vidéos quand aucun chemin n'est fourni
+pub const RECORDINGS_DIR: &str = "recordings";
+
+/// Chemin horodaté par défaut : `recordings/record_<secs>_<millis>.mp4`
+pub fn default_recording_path() -> PathBuf {
+    timestamped_path(RECORDINGS_DIR, "record", "mp4")
+}
+
+/// Arguments ffmpeg : frames RGBA brutes sur stdin → vidéo `output`.
+///
+/// OpenGL relit les lignes de bas en haut : `vflip` les remet à l'endroit,
+/// et la conversion vers `yuv420p` (compatible lecteurs) est laissée à ffmpeg.
+pub fn ffmpeg_args(
+    config: &RecordingConfig,
+    width: u32,
+    height: u32,
+    output: &Path,
+) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "-y",
+        "-loglevel",
+        "error",
+        "-f",
+        "rawvideo",
+        "-pix_fmt",
+        "rgba",
+        "-s",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    args.push(format!("{}x{}", width, height));
+    args.extend(["-r".to_string(), config.fps.to_string()]);
+    args.extend(["-i", "-", "-vf", "vflip", "-c:v"].map(String::from));
+    args.push(config.codec.clone());
+    if !config.bitrate.is_empty() {
+        args.extend(["-b:v".to_string(), config.bitrate.clone()]);
+    }
+    args.extend(["-pix_fmt", "yuv420p"].map(String::from));
+    args.push(output.to_string_lossy().into_owned());
+    args
+}
+
+/// Machine à états du ring de PBO (sans appel OpenGL).
+///
+/// Chaque frame écrit dans le slot suivant ; une fois le ring plein, le slot à
+/// réécrire contient la plus ancienne relecture, qui est consommée juste avant.
+/// La relecture d'une frame est donc disponible `slots - 1` frames plus tard,
+/// quand le transfert GPU → PBO est terminé : pas de stall sur `glMapBuffer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PboRingState {
+    slots: usize,
+    next: usize,
+    in_flight: usize,
+}
+
+impl PboRingState {
+    pub fn new(slots: usize) -> Self {
+        Self {
+            slots: slots.max(1),
+            next: 0,
+            in_flight: 0,
+        }
+    }
+
+    pub fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// Nombre de relectures lancées et pas encore consommées
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+
+    /// Avance d'une frame : retourne `(slot à remplir, slot prêt à lire)`.
+    /// Le slot prêt (s'il existe) doit être lu avant d'être réécrit.
+    pub fn advance(&mut self) -> (usize, Option<usize>) {
+        let write = self.next;
+        let ready = if self.in_flight == self.slots {
+            Some(write)
+        } else {
+            self.in_flight += 1;
+            None
+        };
+        self.next = (self.next + 1) % self.slots;
+        (write, ready)
+    }
+
+    /// Slots encore en vol, du plus ancien au plus récent ; vide le ring.
+    pub fn drain(&mut self) -> Vec<usize> {
+        let start = (self.next + self.slots - self.in_flight) % self.slots;
+        let pending = (0..self.in_flight)
+            .map(|i| (start + i) % self.slots)
+            .collect();
+        self.in_flight = 0;
+        pending
+    }
+}
+
+/// Ring de Pixel Buffer Objects pour relire le framebuffer de façon asynchrone.
+struct PboRing {
+    pbos: Vec<GLuint>,
+    state: PboRingState,
+    width: u32,
+    height: u32,
+}
+
+impl PboRing {
+    unsafe fn new(slots: usize, width: u32, height: u32) -> Self {
+        let state = PboRingState::new(slots);
+        let mut pbos = vec![0; state.slots()];
+        gl::GenBuffers(pbos.len() as GLsizei, pbos.as_mut_ptr());
+        for &pbo in &pbos {
+            gl::BindBuffer(gl::PIXEL_PACK_BUFFER, pbo);
+            gl::BufferData(
+                gl::PIXEL_PACK_BUFFER,
+                Self::frame_size(width, height) as GLsizeiptr,
+                std::ptr::null(),
+                gl::STREAM_READ,
+            );
+        }
+        gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+        gl_check!("recorder PBO allocation");
+        Self {
+            pbos,
+            state,
+            width,
+            height,
+        }
+    }
+
+    fn frame_size(width: u32, height: u32) -> usize {
+        width as usize * height as usize * 4
+    }
+
+    /// Lance la relecture de la frame courante ; retourne une frame plus ancienne si prête.
+    unsafe fn capture(&mut self) -> Option<Vec<u8>> {
+        let (write, ready) = self.state.advance();
+        let frame = ready.map(|slot| self.read_slot(slot));
+
+        gl::BindBuffer(gl::PIXEL_PACK_BUFFER, self.pbos[write]);
+        gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+        gl::ReadPixels(
+            0,
+            0,
+            self.width as GLsizei,
+            self.height as GLsizei,
+            gl::RGBA,
+            gl::UNSIGNED_BYTE,
+            std::ptr::null_mut(),
+        );
+        gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+        frame
+    }
+
+    unsafe fn read_slot(&self, slot: usize) -> Vec<u8> {
+        let size = Self::frame_size(self.width, self.height);
+        let mut frame = vec![0u8; size];
+        gl::BindBuffer(gl::PIXEL_PACK_BUFFER, self.pbos[slot]);
+        let ptr = gl::MapBuffer(gl::PIXEL_PACK_BUFFER, gl::READ_ONLY) as *const u8;
+        if !ptr.is_null() {
+            std::ptr::copy_nonoverlapping(ptr, frame.as_mut_ptr(), size);
+            gl::UnmapBuffer(gl::PIXEL_PACK_BUFFER);
+        }
+        gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+        frame
+    }
+
+    /// Relit toutes les frames encore en vol (arrêt de l'enregistrement).
+    unsafe fn drain(&mut self) -> Vec<Vec<u8>> {
+        self.state
+            .drain()
+            .into_iter()
+            .map(|slot| self.read_slot(slot))
+            .collect()
+    }
+
+    unsafe fn delete(&mut self) {
+        if !self.pbos.is_empty() {
+            gl::DeleteBuffers(self.pbos.len() as GLsizei, self.pbos.as_ptr());
+            self.pbos.clear();
+        }
+    }
+}
+
+/// Enregistreur vidéo : relit chaque frame présentée et l'envoie à un processus ffmpeg.
+pub struct FrameRecorder {
+    output: PathBuf,
+    fps: u32,
+    lock_time_step: bool,
+    child: Child,
+    stdin: Option<ChildStdin>,
+    ring: PboRing,
+    frames_written: u64,
+}
+
+impl FrameRecorder {
+    /// Lance ffmpeg et alloue le ring de PBO.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn start(
+        config: &RecordingConfig,
+        width: u32,
+        height: u32,
+        output: PathBuf,
+    ) -> Result<Self> {
+        if width == 0 || height == 0 || config.fps == 0 {
+            return Err(anyhow!(
+                "Invalid recording format {}x{} @ {} fps",
+                width,
+                height,
+                config.fps
+            ));
+        }
+        if let Some(dir) = output.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let args = ffmpeg_args(config, width, height, &output);
+        let mut child = Command::new(&config.ffmpeg)
+            .args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .with_context(|| format!("cannot spawn '{}'", config.ffmpeg))?;
+        let stdin = child.stdin.take();
+
+        info!(
+            "🎬 Recording {}x{} @ {} fps -> {}",
+            width,
+            height,
+            config.fps,
+            output.display()
+        );
+        Ok(Self {
+            output,
+            fps: config.fps,
+            lock_time_step: config.lock_time_step,
+            child,
+            stdin,
+            ring: PboRing::new(config.pbo_count, width, height),
+            frames_written: 0,
+        })
+    }
+
+    pub fn output(&self) -> &Path {
+        &self.output
+    }
+
+    pub fn frames_written(&self) -> u64 {
+        self.frames_written
+    }
+
+    /// Pas de temps imposé à la simulation pendant l'enregistrement
+    pub fn fixed_time_step(&self) -> Option<f32> {
+        self.lock_time_step.then(|| 1.0 / self.fps as f32)
+    }
+
+    /// Relit la frame qui vient d'être dessinée (à appeler avant le swap).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn capture_frame(&mut self) -> Result<()> {
+        if let Some(frame) = self.ring.capture() {
+            self.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+
+    fn write_frame(&mut self, frame: &[u8]) -> Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| anyhow!("ffmpeg stdin closed"))?;
+        stdin
+            .write_all(frame)
+            .context("ffmpeg stopped accepting frames")?;
+        self.frames_written += 1;
+        Ok(())
+    }
+
+    /// Vide le ring, ferme stdin et attend la fin de l'encodage.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn finish(mut self) -> Result<u64> {
+        for frame in self.ring.drain() {
+            if let Err(e) = self.write_frame(&frame) {
+                warn!("⚠️ Recording: {}", e);
+                break;
+            }
+        }
+        self.ring.delete();
+
+        // Fermer stdin signale la fin du flux à ffmpeg
+        self.stdin = None;
+        let status = self.child.wait()?;
+        if !status.success() {
+            return Err(anyhow!("ffmpeg exited with {}", status));
+        }
+        info!(
+            "🎬 Recording saved: {} ({} frames)",
+            self.output.display(),
+            self.frames_written
+        );
+        Ok(self.frames_written)
+    }
+}
+
+impl Drop for FrameRecorder {
+    fn drop(&mut self) {
+        // Arrêt sans `finish` (erreur, panic) : on laisse ffmpeg clore le fichier
+        if self.stdin.take().is_some() {
+            let _ = self.child.wait();
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Passes du rendu d'une frame : scène → bloom (extraction, flou) → tone mapping → FXAA.
+
+use anyhow::Result;
+use glam::Mat3;
+use log::warn;
+
+use crate::physic_engine::{config::PhysicConfig, ParticleType, PhysicEngineIterator};
+use crate::renderer_engine::config::RendererConfig;
+use crate::renderer_engine::flash::{FlashList, FlashUniforms};
+use crate::renderer_engine::frame_graph::{
+    FrameContext, FrameGraph, PassResources, RenderPass, Resource,
+};
+use crate::renderer_engine::particle_renderer::ParticleGraphicsRenderer;
+use crate::renderer_engine::post_process::{post_process_chain, FxaaPass, PostPass};
+use crate::renderer_engine::{
+    BackgroundRenderer, BloomPass, RenderStats, RendererGraphics, RendererGraphicsInstanced,
+    TrailRibbonRenderer,
+};
+
+/// Passes du renderer, dans leur ordre de déclaration
+pub fn default_passes() -> Vec<Box<dyn RenderPass>> {
+    vec![
+        Box::new(ScenePass),
+        Box::new(BloomExtractPass),
+        Box::new(BloomBlurPass),
+        Box::new(TonemapPass),
+        Box::new(FxaaOutputPass),
+    ]
+}
+
+/// Renderers de particules, fond, bloom et FXAA des passes, pour un framebuffer
+/// de `width` × `height` (bloom désactivé si ses cibles ne peuvent être créées).
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn default_resources(
+    config: &RendererConfig,
+    physic_config: &PhysicConfig,
+    max_particles_on_gpu: usize,
+    width: i32,
+    height: i32,
+) -> PassResources {
+    let bloom = match BloomPass::new(width as u32, height as u32) {
+        Ok(bloom) => Some(bloom),
+        Err(e) => {
+            warn!("⚠️ Bloom disabled: {}", e);
+            None
+        }
+    };
+    let renderers: Vec<Box<dyn ParticleGraphicsRenderer>> = vec![
+        Box::new(RendererGraphics::new(max_particles_on_gpu)),
+        Box::new(TrailRibbonRenderer::new()),
+        Box::new(RendererGraphicsInstanced::new(
+            physic_config.max_rockets,
+            ParticleType::Rocket,
+            &config.particles.rocket,
+            true,
+        )),
+    ];
+    PassResources {
+        renderers,
+        background: BackgroundRenderer::new(&config.background),
+        bloom,
+        fxaa: FxaaPass::new(),
+        particles_drawn: 0,
+        stats: RenderStats::default(),
+    }
+}
+
+/// Ce que le renderer hôte (fenêtre glfw, winit ou headless) fournit à une frame
+#[derive(Debug, Clone, Copy)]
+pub struct SceneView {
+    pub view_proj: Mat3,
+    /// Temps de rendu écoulé (s)
+    pub clock: f32,
+    /// Échelle d'affichage (DPI) des tailles de particules
+    pub content_scale: f32,
+    /// Niveau de sortie audio lissé (bloom réactif)
+    pub audio_level: f32,
+    /// La cible liée est le back buffer d'une fenêtre encodant en sRGB
+    pub srgb_target: bool,
+}
+
+/// Rend une frame dans la cible liée : choix des passes selon la config
+/// (scène → bloom → tonemap → FXAA) puis exécution du graphe.
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn render_scene(
+    frame_graph: &mut FrameGraph,
+    resources: &mut PassResources,
+    config: &RendererConfig,
+    flashes: &FlashList,
+    physic: &dyn PhysicEngineIterator,
+    view: &SceneView,
+) {
+    let chain = post_process_chain(config);
+    if let Some(bloom) = &mut resources.bloom {
+        bloom.audio_level = view.audio_level;
+        bloom.sync_with_renderer_config(config);
+    }
+    let hdr = resources.bloom.is_some() && chain.contains(&PostPass::Tonemap);
+    // Encodage sRGB matériel : uniquement vers le back buffer de la fenêtre
+    let srgb = hdr && config.srgb_framebuffer && view.srgb_target;
+    if let Some(bloom) = &mut resources.bloom {
+        bloom.srgb_output = srgb;
+    }
+    let mut fxaa = chain.contains(&PostPass::Fxaa);
+
+    let mut output_fbo = 0;
+    let mut viewport = [0; 4];
+    if hdr || fxaa {
+        gl::GetIntegerv(gl::DRAW_FRAMEBUFFER_BINDING, &mut output_fbo);
+        gl::GetIntegerv(gl::VIEWPORT, viewport.as_mut_ptr());
+    }
+    if fxaa {
+        if let Err(e) = resources.fxaa.begin(viewport[2] as u32, viewport[3] as u32) {
+            warn!("⚠️ FXAA skipped: {}", e);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, output_fbo as u32);
+            fxaa = false;
+        }
+    }
+    // Cible de la composition HDR : FXAA si actif, sinon la sortie
+    let composite_fbo = if fxaa {
+        resources.fxaa.framebuffer()
+    } else {
+        output_fbo as u32
+    };
+
+    let flashes = if config.flash_enabled {
+        flashes.uniforms(config.flash_duration, config.flash_intensity)
+    } else {
+        FlashUniforms::default()
+    };
+    let frame = FrameContext {
+        config,
+        flashes: &flashes,
+        physic,
+        active_particles: physic.count_all_active(),
+        view_proj: view.view_proj,
+        clock: view.clock,
+        content_scale: view.content_scale,
+        hdr,
+        bloom: hdr && chain.contains(&PostPass::Bloom),
+        fxaa,
+        srgb,
+        output_fbo: output_fbo as u32,
+        output_size: (viewport[2], viewport[3]),
+        composite_fbo,
+    };
+    resources.stats.reset();
+    frame_graph.execute(resources, &frame);
+}
+
+/// Fond puis particules, dans la cible HDR si le post-process est actif
+/// (sinon dans la cible courante : FXAA ou sortie).
+///
+/// Les surfaces HDR (scène et flou) appartiennent à `BloomPass` : cette passe
+/// les redimensionne pour toutes les passes du bloom.
+pub struct ScenePass;
+
+impl RenderPass for ScenePass {
+    fn name(&self) -> &'static str {
+        "scene"
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        &[]
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        &[Resource::Scene]
+    }
+
+    unsafe fn resize(&mut self, res: &mut PassResources, width: u32, height: u32) -> Result<()> {
+        match &mut res.bloom {
+            Some(bloom) => bloom.resize(width, height),
+            None => Ok(()),
+        }
+    }
+
+    unsafe fn execute(&mut self, res: &mut PassResources, frame: &FrameContext) {
+        if frame.hdr {
+            if let Some(bloom) = &res.bloom {
+                bloom.begin();
+            }
+        }
+
+        res.background.apply_config(&frame.config.background);
+        res.background
+            .render(frame.clock, &frame.view_proj, frame.flashes);
+
+        res.particles_drawn = 0;
+        if frame.active_particles == 0 {
+            // Ciel vide : aucun buffer à remplir ni appel de dessin
+            return;
+        }
+        for renderer in &mut res.renderers {
+            renderer.apply_config(frame.config);
+            renderer.set_content_scale(frame.content_scale);
+            renderer.set_flashes(frame.flashes);
+            // Remplit le buffer GPU
+            let nb = renderer.fill_particle_data_direct(frame.physic);
+            // Dessine les particules
+            renderer.render_particles_with_persistent_buffer(nb, &frame.view_proj);
+            res.particles_drawn += nb;
+            res.stats.record_draw(&renderer.draw_stats());
+        }
+    }
+}
+
+/// Extraction des zones lumineuses de la scène.
+///
+/// Recharge les shaders de toutes les passes du bloom (un seul jeu de programmes).
+pub struct BloomExtractPass;
+
+impl RenderPass for BloomExtractPass {
+    fn name(&self) -> &'static str {
+        "bloom.extract"
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        &[Resource::Scene]
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        &[Resource::BloomBright]
+    }
+
+    fn enabled(&self, frame: &FrameContext) -> bool {
+        frame.bloom
+    }
+
+    unsafe fn reload_shaders(&mut self, res: &mut PassResources) -> Result<()> {
+        match &mut res.bloom {
+            Some(bloom) => bloom.reload_shaders(),
+            None => Ok(()),
+        }
+    }
+
+    unsafe fn execute(&mut self, res: &mut PassResources, _frame: &FrameContext) {
+        if let Some(bloom) = &res.bloom {
+            bloom.extract();
+        }
+    }
+}
+
+/// Flou gaussien séparable des zones extraites
+pub struct BloomBlurPass;
+
+impl RenderPass for BloomBlurPass {
+    fn name(&self) -> &'static str {
+        "bloom.blur"
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        &[Resource::BloomBright]
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        &[Resource::BloomBlur]
+    }
+
+    fn enabled(&self, frame: &FrameContext) -> bool {
+        frame.bloom
+    }
+
+    unsafe fn execute(&mut self, res: &mut PassResources, _frame: &FrameContext) {
+        if let Some(bloom) = &res.bloom {
+            bloom.blur();
+        }
+    }
+}
+
+/// Exposition, composition scène + bloom et tone mapping (ou grille de comparaison)
+pub struct TonemapPass;
+
+impl RenderPass for TonemapPass {
+    fn name(&self) -> &'static str {
+        "tonemap"
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        &[Resource::Scene, Resource::BloomBlur]
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        &[Resource::Ldr]
+    }
+
+    fn enabled(&self, frame: &FrameContext) -> bool {
+        frame.hdr
+    }
+
+    unsafe fn execute(&mut self, res: &mut PassResources, frame: &FrameContext) {
+        if let Some(bloom) = &mut res.bloom {
+            // Sans FXAA, la composition écrit directement dans le back buffer sRGB
+            with_srgb_output(frame.srgb && !frame.fxaa, || {
+                bloom.composite(frame.composite_fbo, frame.output_size, frame.clock)
+            });
+        }
+    }
+}
+
+/// Anti-aliasing FXAA vers la cible de sortie
+pub struct FxaaOutputPass;
+
+impl RenderPass for FxaaOutputPass {
+    fn name(&self) -> &'static str {
+        "fxaa"
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        &[Resource::Ldr]
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        &[Resource::Output]
+    }
+
+    fn enabled(&self, frame: &FrameContext) -> bool {
+        frame.fxaa
+    }
+
+    unsafe fn resize(&mut self, res: &mut PassResources, width: u32, height: u32) -> Result<()> {
+        res.fxaa.resize(width, height)
+    }
+
+    unsafe fn execute(&mut self, res: &mut PassResources, frame: &FrameContext) {
+        with_srgb_output(frame.srgb, || {
+            res.fxaa.end(frame.output_fbo, frame.output_size)
+        });
+    }
+}
+
+/// Active l'encodage sRGB matériel le temps d'une écriture vers la sortie.
+unsafe fn with_srgb_output(enabled: bool, draw: impl FnOnce()) {
+    if enabled {
+        gl::Enable(gl::FRAMEBUFFER_SRGB);
+    }
+    draw();
+    if enabled {
+        gl::Disable(gl::FRAMEBUFFER_SRGB);
+    }
+}
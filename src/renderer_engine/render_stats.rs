@@ -0,0 +1,136 @@
+//! Compteurs de rendu de la dernière frame (commande `renderer.stats`).
+
+use std::ops::{Add, AddAssign};
+
+use crate::physic_engine::ParticleType;
+use crate::utils::human_bytes;
+
+/// Mémoire GPU estimée d'après les allocations (hors surcoût du driver).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GpuMemory {
+    /// VBO (buffers de particules, étoiles, quads)
+    pub buffers: u64,
+    /// Textures attachées aux FBO (cibles HDR, FXAA, headless)
+    pub framebuffers: u64,
+    /// Textures chargées (sprites, salissures d'objectif)
+    pub textures: u64,
+}
+
+impl GpuMemory {
+    pub fn total(&self) -> u64 {
+        self.buffers + self.framebuffers + self.textures
+    }
+}
+
+impl Add for GpuMemory {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            buffers: self.buffers + other.buffers,
+            framebuffers: self.framebuffers + other.framebuffers,
+            textures: self.textures + other.textures,
+        }
+    }
+}
+
+impl AddAssign for GpuMemory {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Ce qu'un renderer de particules a envoyé et dessiné lors de la dernière frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DrawStats {
+    /// Particules écrites, indexées par `ParticleType as usize`
+    pub particles: [usize; ParticleType::ALL.len()],
+    /// Octets écrits dans le buffer mappé
+    pub bytes_uploaded: u64,
+    pub draw_calls: usize,
+    pub instanced_draw_calls: usize,
+}
+
+/// Compteurs de la dernière frame, remis à zéro au début de chaque frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderStats {
+    pub draw_calls: usize,
+    pub instanced_draw_calls: usize,
+    /// Particules envoyées au GPU, indexées par `ParticleType as usize`
+    pub particles: [usize; ParticleType::ALL.len()],
+    pub bytes_uploaded: u64,
+    pub memory: GpuMemory,
+    /// Cibles de rendu courantes (nom, largeur, hauteur)
+    pub framebuffers: Vec<(&'static str, u32, u32)>,
+}
+
+impl RenderStats {
+    /// Remet les compteurs à zéro (les allocations de `framebuffers` sont conservées).
+    pub fn reset(&mut self) {
+        self.draw_calls = 0;
+        self.instanced_draw_calls = 0;
+        self.particles = [0; ParticleType::ALL.len()];
+        self.bytes_uploaded = 0;
+        self.memory = GpuMemory::default();
+        self.framebuffers.clear();
+    }
+
+    /// Ajoute le dessin d'un renderer de particules.
+    pub fn record_draw(&mut self, draw: &DrawStats) {
+        self.draw_calls += draw.draw_calls;
+        self.instanced_draw_calls += draw.instanced_draw_calls;
+        for (total, count) in self.particles.iter_mut().zip(draw.particles) {
+            *total += count;
+        }
+        self.bytes_uploaded += draw.bytes_uploaded;
+    }
+
+    /// Déclare une cible de rendu ; une taille nulle (cible non allouée) est ignorée.
+    pub fn add_framebuffer(&mut self, name: &'static str, width: u32, height: u32) {
+        if width > 0 && height > 0 {
+            self.framebuffers.push((name, width, height));
+        }
+    }
+
+    pub fn particles_of(&self, particle_type: ParticleType) -> usize {
+        self.particles[particle_type as usize]
+    }
+
+    /// Rapport multi-lignes de la console.
+    pub fn format(&self) -> String {
+        let particles = ParticleType::ALL
+            .iter()
+            .map(|&t| format!("{} {}", t.name(), self.particles_of(t)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let framebuffers = if self.framebuffers.is_empty() {
+            "-".to_string()
+        } else {
+            self.framebuffers
+                .iter()
+                .map(|(name, w, h)| format!("{} {}x{}", name, w, h))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        [
+            format!(
+                "Draw calls: {} ({} instanced)",
+                self.draw_calls, self.instanced_draw_calls
+            ),
+            format!("Particles uploaded: {}", particles),
+            format!(
+                "Mapped buffer writes: {}",
+                human_bytes::format(self.bytes_uploaded)
+            ),
+            format!(
+                "GPU memory (estimated): {} (buffers {}, framebuffers {}, textures {})",
+                human_bytes::format(self.memory.total()),
+                human_bytes::format(self.memory.buffers),
+                human_bytes::format(self.memory.framebuffers),
+                human_bytes::format(self.memory.textures)
+            ),
+            format!("Framebuffers: {}", framebuffers),
+        ]
+        .join("\n")
+    }
+}
@@ -1,28 +1,97 @@
-use crate::physic_engine::{PhysicEngineFull, PhysicEngineIterator};
+use crate::physic_engine::{ParticleType, PhysicEngineFull, PhysicEngineIterator};
 use crate::RendererEngine;
 use crate::{log_metrics_and_fps, profiler::Profiler};
 use anyhow::{anyhow, Result};
-use glfw::{Action, Context, Key, WindowMode};
+use glam::Vec2;
+use glfw::Context;
 use imgui::Context as ImContext;
 use imgui_glfw_rs::glfw;
 use imgui_glfw_rs::imgui;
 use imgui_glfw_rs::ImguiGLFW;
 use log::{debug, info, warn};
-use std::time::Instant;
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
+use crate::app_options::AppOptions;
 use crate::audio_engine::AudioEngine;
-use crate::physic_engine::{config::PhysicConfig, PhysicEngine, UpdateResult};
-use crate::renderer_engine::particle_renderer::ParticleGraphicsRenderer;
-use crate::renderer_engine::RendererGraphics;
-use crate::renderer_engine::RendererGraphicsInstanced;
+use crate::bench::ACTIVE_PARTICLES_METRIC;
+use crate::config_manager::{ConfigEvent, ConfigManager, ConfigSection};
+use crate::config_presets::{list_presets, save_preset, PresetSnapshot};
+use crate::demo_director::DemoDirector;
+use crate::duration_limit::DurationLimit;
+use crate::physic_engine::{
+    config::PhysicConfig, explosion_shape::cycle_explosion_shape, types::ReloadResult, PhysicEngine,
+};
+#[cfg(feature = "headless_egl")]
+use crate::renderer_engine::utils::egl_context::SurfacelessContext;
+use crate::renderer_engine::ParticleGPU;
 use crate::renderer_engine::{
+    audio_reactive::EnvelopeFollower,
+    camera::Camera2D,
     command_console::{CommandRegistry, Console},
-    tools::{setup_opengl_debug, show_opengl_context_info},
+    command_cvar::Cvar,
+    command_sim::{SimContext, SimState},
+    config::{
+        QualityPreset, RendererConfig, TrailStyle, BLOOM_AUDIO_REACTIVITY_RANGE,
+        BLOOM_SOFT_KNEE_RANGE, BLOOM_THRESHOLD_RANGE, LENS_DIRT_STRENGTH_RANGE, OUTPUT_GAMMA_RANGE,
+        RENDER_SCALE_RANGE, SOFTNESS_RANGE,
+    },
+    config_reload::{format_changes, gpu_buffer_capacity, particles_within_budget, ReloadDebounce},
+    console_output::Severity,
+    console_server::{ConsoleServer, RemoteConsoleConfig},
+    display_scale::{effective_content_scale, format_display_scale, DisplayScale},
+    file_drop::handle_file_drop,
+    flash::{Flash, FlashList},
+    frame_graph::{format_pass_list, FrameGraph, PassResources, PassStatus},
+    fullscreen::{
+        format_monitors, DisplayMode, FullscreenRequest, FullscreenState, MonitorInfo,
+        WindowPlacement,
+    },
+    gamepad::{format_gamepads, GamepadController},
+    hud::{draw_hud, draw_pause_indicator, HudStats},
+    key_bindings::{KeyBindings, INPUT_CONFIG_PATH},
+    post_process::{post_process_chain, PostPass},
+    recorder::{default_recording_path, FrameRecorder},
+    render_passes::{default_passes, default_resources, render_scene, SceneView},
+    render_stats::RenderStats,
+    tonemap::{comparison_grid, parse_comparison_modes, CellRect, ToneMappingMode},
+    tools::{
+        default_framebuffer_is_srgb, set_gl_checks_enabled, setup_opengl_debug,
+        show_opengl_context_info,
+    },
     utils::{
-        adaptative_sampler::{ascii_sample_timeline, AdaptiveSampler},
-        glfw_window::Fullscreen,
+        adaptative_sampler::{
+            ascii_frame_time_timeline, ascii_one_percent_low_timeline, ascii_sample_timeline,
+            AdaptiveSampler,
+        },
+        atlas::AtlasDescriptor,
+        frame_limiter::FrameLimiter,
+        glfw_window::{
+            list_monitors, poll_gamepads, set_window_icon, translate_event, Fullscreen, VSync,
+        },
+        label::burn_label,
+        offscreen::OffscreenTarget,
+        screenshot::{
+            default_hdr_screenshot_path, default_screenshot_path, flip_rows, read_framebuffer_rgba,
+            read_texture_rgba32f, save_rgba32f_async, save_rgba_async, timestamped_path,
+            SCREENSHOTS_DIR,
+        },
     },
+    window_event::{Action, EventRouter, Reaction, WindowEvent},
+    window_status::{TitleUpdater, WindowActivity, WINDOW_ICON_PNG},
 };
+use crate::session::{CameraSession, Session, WindowSession};
+use crate::sim_clock::{next_speed_preset, SimClock, SimSpeed};
+use crate::simulator::{begin_sim_frame, step_simulation};
+use crate::utils::embedded_assets::{read_asset, read_asset_to_string};
+use crate::utils::log_sink::{console_log_sink, set_console_log_filter, LogFilter};
+use crate::utils::system_report::{system_report, BugReport, BUG_REPORT_PATH};
+
+/// Police de la console et du HUD
+pub const CONSOLE_FONT_PATH: &str = "assets/fonts/PerfectDOSVGA437.ttf";
 
 //
 pub struct ImguiSystem {
@@ -30,27 +99,85 @@ pub struct ImguiSystem {
     pub glfw: ImguiGLFW,
 }
 
+/// File des événements de la fenêtre glfw
+type GlfwEvents = glfw::GlfwReceiver<(f64, glfw::WindowEvent)>;
+
 // ---------------------------------------------------------
 pub struct Renderer {
-    pub glfw: glfw::Glfw,
+    /// `None` en headless EGL (feature `headless_egl`) : ni glfw ni fenêtre
+    pub glfw: Option<glfw::Glfw>,
     pub window: Option<glfw::PWindow>,
-    pub events: Option<glfw::GlfwReceiver<(f64, glfw::WindowEvent)>>,
+    pub events: Option<GlfwEvents>,
 
     pub imgui_system: Option<ImguiSystem>,
     console: Console,
+    /// Filtre des journaux de la console appliqué en dernier (`console_log_filter`)
+    console_log_filter: String,
+    /// Console distante (`[remote_console]`) et réglages avec lesquels elle a été lancée
+    console_server: Option<ConsoleServer>,
+    console_server_config: Option<RemoteConsoleConfig>,
 
     max_particles_on_gpu: usize,
 
+    /// État partagé avec les commandes console `renderer.*`
+    shared: RendererShared,
+
     frames: u32,
-    last_time: Instant,
+    /// Horloge de la simulation, seule du programme : durée des frames et pas de
+    /// la physique, pause et vitesse comprises (cf. `begin_sim_frame`)
+    sim_clock: SimClock,
 
     // Window management
     window_size: (i32, i32),
     window_size_f32: (f32, f32),
-    window_last_pos: (i32, i32),
-    window_last_size: (i32, i32),
-
-    renderers: Vec<Box<dyn ParticleGraphicsRenderer>>,
+    /// Mode d'affichage courant et géométrie fenêtrée à restaurer
+    fullscreen: FullscreenState,
+    /// Manettes connectées et répétition du lancement
+    gamepad: GamepadController,
+    /// Titre mis à jour avec les FPS et le nombre de particules
+    title: TitleUpdater,
+    /// Fenêtre réduite : rendu suspendu, boucle ralentie
+    activity: WindowActivity,
+
+    /// Renderers de particules, fond, bloom et FXAA, partagés par les passes
+    resources: PassResources,
+    /// Passes de la frame, dans leur ordre d'exécution
+    frame_graph: FrameGraph,
+    /// Temps de rendu écoulé (s), anime le scintillement des étoiles
+    clock: f32,
+    /// Éclairs des dernières détonations (cf. `add_flash`)
+    flashes: FlashList,
+    /// Niveau de sortie audio lissé, module le bloom (cf. `follow_audio_level`)
+    audio_envelope: EnvelopeFollower,
+
+    /// Cible de rendu du mode headless (`None` : framebuffer de la fenêtre)
+    offscreen: Option<OffscreenTarget>,
+
+    /// Export vidéo en cours (`--record`, `renderer.record.start`)
+    recorder: Option<FrameRecorder>,
+
+    // Curseur et glisser caméra (coordonnées écran, y vers le haut)
+    event_router: EventRouter,
+
+    /// V-sync actuellement appliquée au contexte
+    vsync: bool,
+    /// Plafond d'images/s quand la v-sync est désactivée
+    frame_limiter: FrameLimiter,
+    /// Le back buffer de la fenêtre encode en sRGB (`srgb_framebuffer` accordé)
+    srgb_capable: bool,
+    /// Mesures de la boucle de rendu, exportables à la fermeture (`--metrics-out`)
+    pub(crate) profiler: Profiler,
+    /// Rechargements au clavier trop rapprochés ignorés
+    physic_reload_debounce: ReloadDebounce,
+    renderer_reload_debounce: ReloadDebounce,
+    /// `run_loop` s'arrête après ce nombre de frames (`bench`)
+    frame_limit: Option<u64>,
+    /// `run_loop` s'arrête à la fin du spectacle (`--duration`)
+    duration_limit: Option<DurationLimit>,
+    /// Contexte GL du mode headless sans affichage ; dernier champ : libéré après
+    /// tout le reste
+    #[cfg(feature = "headless_egl")]
+    egl_context: Option<SurfacelessContext>,
 }
 
 // ---------------------------------------------------------
@@ -78,8 +205,234 @@ pub struct Renderer {
 //   dans le binaire, ce qui peut augmenter légèrement la taille du code.
 impl Renderer {
     pub fn new(width: i32, height: i32, title: &str, physic_config: &PhysicConfig) -> Result<Self> {
+        Self::create(
+            width,
+            height,
+            title,
+            physic_config,
+            false,
+            default_config_manager(),
+        )
+    }
+
+    /// Renderer fenêtré configuré par la ligne de commande : taille, configs (du
+    /// `ConfigManager`, relues par `reload_config`), plein écran et enregistrement
+    /// au démarrage.
+    pub fn from_options(
+        options: &AppOptions,
+        physic_config: &PhysicConfig,
+        config_manager: ConfigManager,
+    ) -> Result<Self> {
+        let (width, height) = options.size;
+        let mut renderer = Self::create(
+            width,
+            height,
+            "Fireworks Simulator",
+            physic_config,
+            false,
+            config_manager,
+        )?;
+        if options.fullscreen {
+            renderer.apply_fullscreen_request(FullscreenRequest::Toggle);
+        }
+        if let Some(path) = &options.record {
+            renderer.start_recording(Some(path.clone()))?;
+        }
+        Ok(renderer)
+    }
+
+    /// Renderer sans affichage : fenêtre invisible (porteuse du contexte GL), ou
+    /// contexte EGL surfaceless avec la feature `headless_egl` (ni X11 ni Wayland
+    /// requis) ; pas d'ImGui, frames dessinées dans un FBO et relues via
+    /// `render_to_image`.
+    pub fn new_headless(width: i32, height: i32, physic_config: &PhysicConfig) -> Result<Self> {
+        Self::create(
+            width,
+            height,
+            "Fireworks (headless)",
+            physic_config,
+            true,
+            default_config_manager(),
+        )
+    }
+
+    fn create(
+        width: i32,
+        height: i32,
+        title: &str,
+        physic_config: &PhysicConfig,
+        headless: bool,
+        config_manager: ConfigManager,
+    ) -> Result<Self> {
         let _ = env_logger::builder().is_test(true).try_init();
 
+        let config = config_manager.renderer_config();
+        info!("Renderer config loaded:\n{:#?}", config);
+
+        // Debug OpenGL opt-in : feature `gl_debug` ou `gl_debug = true` dans renderer.toml
+        let gl_debug = cfg!(feature = "gl_debug") || config.gl_debug;
+
+        // Headless avec `headless_egl` : contexte EGL, ni glfw ni fenêtre
+        #[cfg(feature = "headless_egl")]
+        let egl_context = if headless {
+            Some(SurfacelessContext::new(gl_debug)?)
+        } else {
+            None
+        };
+        let (mut glfw, mut window, events) = if headless && cfg!(feature = "headless_egl") {
+            (None, None, None)
+        } else {
+            let (glfw, window, events) =
+                Self::create_window(width, height, title, headless, gl_debug, &config)?;
+            (Some(glfw), Some(window), Some(events))
+        };
+
+        let (fullscreen, display) = match &window {
+            Some(window) => {
+                let (scale_x, scale_y) = window.get_content_scale();
+                (
+                    FullscreenState::new(window.get_pos(), window.get_size()),
+                    DisplayScale {
+                        content_scale: effective_content_scale(scale_x, scale_y),
+                        window_size: window.get_size(),
+                        framebuffer_size: window.get_framebuffer_size(),
+                    },
+                )
+            }
+            None => (
+                FullscreenState::new((0, 0), (width, height)),
+                DisplayScale {
+                    content_scale: 1.0,
+                    window_size: (width, height),
+                    framebuffer_size: (width, height),
+                },
+            ),
+        };
+        info!("🔍 {}", format_display_scale(&display));
+        let monitors = glfw.as_mut().map(list_monitors).unwrap_or_default();
+
+        info!("✅ OpenGL context ready for '{}'", title);
+
+        unsafe {
+            show_opengl_context_info();
+
+            // activate OpenGL debug output
+            set_gl_checks_enabled(gl_debug);
+            if gl_debug {
+                setup_opengl_debug();
+            }
+
+            // set OpenGL states for the rendering
+            // but it's link to the renderer graphics
+            gl::Enable(gl::PROGRAM_POINT_SIZE);
+            gl::Enable(gl::BLEND);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+        }
+
+        let srgb_capable = !headless && unsafe { default_framebuffer_is_srgb() };
+        if config.srgb_framebuffer && !srgb_capable {
+            warn!("⚠️ sRGB framebuffer unavailable, falling back to shader gamma");
+        }
+
+        let imgui_system = match &mut window {
+            Some(window) if !headless => {
+                let mut system = Self::create_imgui(window);
+                system.context.io_mut().font_global_scale = display.ui_scale();
+                Some(system)
+            }
+            _ => None,
+        };
+        let offscreen = if headless {
+            Some(unsafe { OffscreenTarget::new(width as u32, height as u32)? })
+        } else {
+            None
+        };
+
+        let max_particles_on_gpu = particles_within_budget(
+            physic_config.max_rockets * physic_config.particles_per_explosion,
+            std::mem::size_of::<ParticleGPU>(),
+            config.max_gpu_buffer,
+        );
+        let (fb_width, fb_height) = display.framebuffer_size;
+        let resources = unsafe {
+            default_resources(
+                &config,
+                physic_config,
+                max_particles_on_gpu,
+                fb_width,
+                fb_height,
+            )
+        };
+
+        let console = Console::new();
+
+        let vsync = config.vsync;
+        let key_bindings = Rc::new(RefCell::new(KeyBindings::load(INPUT_CONFIG_PATH)));
+        Ok(Self {
+            glfw,
+            window,
+            events,
+            imgui_system,
+            console,
+            console_log_filter: String::new(),
+            console_server: None,
+            console_server_config: None,
+            frames: 0,
+            sim_clock: SimClock::new(),
+            window_size: (width, height),
+            window_size_f32: (width as f32, height as f32),
+            fullscreen,
+            gamepad: GamepadController::default(),
+            title: TitleUpdater::new(title),
+            activity: WindowActivity::default(),
+            resources,
+            frame_graph: FrameGraph::new(default_passes())?,
+            clock: 0.0,
+            flashes: FlashList::new(),
+            audio_envelope: EnvelopeFollower::new(),
+            max_particles_on_gpu,
+            shared: RendererShared {
+                camera: Rc::new(RefCell::new(Camera2D::new(
+                    Vec2::new(width as f32, height as f32),
+                    config.camera.clone(),
+                ))),
+                config: Rc::new(RefCell::new(config)),
+                config_manager: Rc::new(RefCell::new(config_manager)),
+                key_bindings: key_bindings.clone(),
+                monitors: Rc::new(RefCell::new(monitors)),
+                display_scale: Rc::new(Cell::new(display)),
+                ..Default::default()
+            },
+            offscreen,
+            recorder: None,
+            event_router: EventRouter {
+                bindings: key_bindings,
+                display,
+                ..EventRouter::new(display.framebuffer_size.1 as f32)
+            },
+            vsync,
+            frame_limiter: FrameLimiter::default(),
+            srgb_capable,
+            profiler: Profiler::new(200),
+            physic_reload_debounce: ReloadDebounce::default(),
+            renderer_reload_debounce: ReloadDebounce::default(),
+            frame_limit: None,
+            duration_limit: None,
+            #[cfg(feature = "headless_egl")]
+            egl_context,
+        })
+    }
+
+    /// Fenêtre glfw (invisible en headless) porteuse du contexte OpenGL 3.3 core,
+    /// rendue courante, fonctions OpenGL chargées.
+    fn create_window(
+        width: i32,
+        height: i32,
+        title: &str,
+        headless: bool,
+        gl_debug: bool,
+        config: &RendererConfig,
+    ) -> Result<(glfw::Glfw, glfw::PWindow, GlfwEvents)> {
         let mut glfw = glfw::init(glfw::fail_on_errors)
             .map_err(|_| anyhow!("Impossible d’initialiser GLFW"))?;
 
@@ -88,6 +441,11 @@ impl Renderer {
         glfw.window_hint(glfw::WindowHint::OpenGlProfile(
             glfw::OpenGlProfileHint::Core,
         ));
+        if headless {
+            glfw.window_hint(glfw::WindowHint::Visible(false));
+        }
+        glfw.window_hint(glfw::WindowHint::OpenGlDebugContext(gl_debug));
+        glfw.window_hint(glfw::WindowHint::SRgbCapable(config.srgb_framebuffer));
 
         let (mut window, events) = glfw
             .create_window(
@@ -99,39 +457,31 @@ impl Renderer {
             .expect("Erreur création fenêtre GLFW");
 
         window.make_current();
+        glfw.set_vsync(config.vsync);
         window.set_key_polling(true);
         window.set_char_polling(true);
         window.set_framebuffer_size_polling(true);
         window.set_cursor_pos_polling(true);
         window.set_mouse_button_polling(true);
         window.set_scroll_polling(true);
-
-        let window_last_pos = window.get_pos();
-        let window_last_size = window.get_size();
-
-        info!("✅ OpenGL context ready for '{}'", title);
+        window.set_drag_and_drop_polling(true);
+        window.set_iconify_polling(true);
+        window.set_size_polling(true);
+        window.set_content_scale_polling(true);
+        if let Err(e) = set_window_icon(&mut window, WINDOW_ICON_PNG) {
+            warn!("⚠️ Window icon not set: {}", e);
+        }
 
         // load OpenGL function pointers
         gl::load_with(|s| window.get_proc_address(s) as *const _);
+        Ok((glfw, window, events))
+    }
 
-        unsafe {
-            show_opengl_context_info();
-
-            // activate OpenGL debug output
-            setup_opengl_debug();
-
-            // set OpenGL states for the rendering
-            // but it's link to the renderer graphics
-            gl::Enable(gl::PROGRAM_POINT_SIZE);
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-        }
-
+    fn create_imgui(window: &mut glfw::PWindow) -> ImguiSystem {
         let mut imgui = ImContext::create();
 
         // Charge la font TTF “Quake style”
-        let font_data =
-            std::fs::read("assets/fonts/PerfectDOSVGA437.ttf").expect("Failed to read font file");
+        let font_data = read_asset(CONSOLE_FONT_PATH).expect("Failed to read font file");
         imgui.fonts().add_font(&[imgui::FontSource::TtfData {
             data: &font_data,
             size_pixels: 18.0, // ajuste la taille selon le rendu
@@ -152,61 +502,137 @@ impl Renderer {
 
         imgui.style_mut().use_dark_colors();
 
-        let imgui_glfw = ImguiGLFW::new(&mut imgui, &mut window);
+        let imgui_glfw = ImguiGLFW::new(&mut imgui, window);
 
-        let max_particles_on_gpu: usize =
-            physic_config.max_rockets * physic_config.particles_per_explosion;
+        ImguiSystem {
+            context: imgui,
+            glfw: imgui_glfw,
+        }
+    }
 
-        let renderers: Vec<Box<dyn ParticleGraphicsRenderer>> = vec![
-            Box::new(RendererGraphics::new(max_particles_on_gpu)),
-            Box::new(RendererGraphicsInstanced::new(
-                physic_config.max_rockets,
-                crate::physic_engine::ParticleType::Rocket,
-                "assets/textures/04ddeae2-7367-45f1-87e0-361d1d242630_scaled.png",
-            )),
-        ];
+    /// Avance l'horloge de rendu (animations indépendantes de la physique).
+    /// Avance le temps de rendu ; les éclairs s'éteignent au même rythme.
+    pub fn advance_clock(&mut self, dt: f32) {
+        self.clock += dt;
+        let duration = self.shared.config.borrow().flash_duration;
+        self.flashes.update(dt, duration);
+    }
 
-        let console = Console::new();
+    /// Éclair de détonation (ignoré si `flash_enabled` est désactivé).
+    pub fn add_flash(&mut self, flash: Flash) {
+        if self.shared.config.borrow().flash_enabled {
+            self.flashes.add(flash);
+        }
+    }
 
-        Ok(Self {
-            glfw,
-            window: Some(window),
-            events: Some(events),
-            imgui_system: Some(ImguiSystem {
-                context: imgui,
-                glfw: imgui_glfw,
-            }),
-            console,
-            frames: 0,
-            last_time: Instant::now(),
-            window_size: (width, height),
-            window_size_f32: (width as f32, height as f32),
-            window_last_pos,
-            window_last_size,
-            renderers,
-            max_particles_on_gpu,
-        })
+    /// Éclairs encore visibles
+    pub fn flashes(&self) -> &FlashList {
+        &self.flashes
+    }
+
+    /// Lisse le niveau de sortie audio `level` sur `dt` secondes pour le bloom
+    /// réactif ; l'enveloppe retombe à 0 quand il est désactivé.
+    pub fn follow_audio_level(&mut self, level: f32, dt: f32) {
+        let config = self.shared.config.borrow();
+        if config.bloom_audio_reactive {
+            self.audio_envelope.update(
+                level,
+                dt,
+                config.bloom_audio_attack,
+                config.bloom_audio_release,
+            );
+        } else {
+            self.audio_envelope.reset();
+        }
+    }
+
+    /// Niveau audio lissé appliqué au bloom
+    pub fn audio_level(&self) -> f32 {
+        self.audio_envelope.value()
     }
 
+    pub fn is_headless(&self) -> bool {
+        self.offscreen.is_some()
+    }
+
+    /// Relit les deux configs (dépôt d'un fichier de config sur la fenêtre).
     pub fn reload_config<P: PhysicEngine>(&mut self, physic: &mut P) {
-        let physic_config =
-            PhysicConfig::from_file("assets/config/physic.toml").unwrap_or_default();
-        info!("Physic config loaded:\n{:#?}", physic_config);
+        self.reload_physic_config(physic);
+        self.reload_renderer_config();
+    }
+
+    /// Relit `physic.toml` (touche R).
+    pub fn reload_physic_config<P: PhysicEngine>(&mut self, physic: &mut P) {
+        let reloaded = self
+            .shared
+            .config_manager
+            .borrow_mut()
+            .reload(ConfigSection::Physic);
+        match reloaded {
+            Ok(Some(event)) => self.apply_config_event(&event, physic),
+            Ok(None) => info!("🔄 Physic config reloaded: no field changed"),
+            Err(e) => warn!("⚠️ Physic config not reloaded: {:#}", e),
+        }
+    }
+
+    /// Applique un rechargement du `ConfigManager` (touches, fichier surveillé).
+    pub fn apply_config_event<P: PhysicEngine>(&mut self, event: &ConfigEvent, physic: &mut P) {
+        info!(
+            "🔄 {} config reloaded: {}",
+            event.section.name(),
+            event.summary()
+        );
+        match event.section {
+            ConfigSection::Physic => {
+                let physic_config = self.shared.config_manager.borrow().physic_config();
+                self.apply_physic_config(&physic_config, physic);
+            }
+            ConfigSection::Renderer => self.shared.apply_manager_config(),
+            // Moteur audio configuré à son ouverture seulement
+            ConfigSection::Audio => {}
+        }
+    }
 
-        physic.reload_config(&physic_config);
+    /// Nouvelle config physique ; les buffers GPU ne sont recréés que si la
+    /// capacité en particules change.
+    fn apply_physic_config<P: PhysicEngine>(
+        &mut self,
+        physic_config: &PhysicConfig,
+        physic: &mut P,
+    ) {
+        let result = physic.reload_config(physic_config);
+        self.sync_gpu_capacity(physic_config, result);
+    }
 
-        let new_max = physic_config.max_rockets * physic_config.particles_per_explosion; // ou autre logique
+    /// Agrandit les buffers GPU après un rechargement physique si nécessaire.
+    fn sync_gpu_capacity(&mut self, physic_config: &PhysicConfig, result: ReloadResult) {
+        debug!("Physic reload result: {:?}", result);
 
-        if new_max != self.max_particles_on_gpu {
+        let required = particles_within_budget(
+            physic_config.max_rockets * physic_config.particles_per_explosion,
+            std::mem::size_of::<ParticleGPU>(),
+            self.shared.config.borrow().max_gpu_buffer,
+        );
+        if let Some(new_max) = gpu_buffer_capacity(self.max_particles_on_gpu, required, result) {
             info!(
                 "🔁 GPU buffer reallocation required ({} → {})",
                 self.max_particles_on_gpu, new_max
             );
             unsafe {
-                for renderer in &mut self.renderers {
+                for renderer in &mut self.resources.renderers {
                     renderer.recreate_buffers(new_max);
                 }
             }
+            self.max_particles_on_gpu = new_max;
+        }
+    }
+
+    /// Relit `renderer.toml` (caméra comprise).
+    pub fn reload_renderer_config(&mut self) {
+        match self.shared.reload_config() {
+            Ok(Some(event)) => info!("🔄 Renderer config reloaded: {}", event.summary()),
+            Ok(None) => info!("🔄 Renderer config reloaded: no field changed"),
+            Err(e) => warn!("⚠️ Renderer config not reloaded: {:#}", e),
         }
     }
 
@@ -214,17 +640,513 @@ impl Renderer {
     /// # Safety
     /// Cette fonction est unsafe car elle effectue des appels OpenGL non sécurisés.
     pub unsafe fn render_frame<P: PhysicEngineIterator>(&mut self, physic: &P) -> usize {
-        let mut total_particles = 0;
-        for renderer in &mut self.renderers {
-            // Remplit le buffer GPU
-            let nb = renderer.fill_particle_data_direct(physic);
-            // Dessine les particules
-            renderer.render_particles_with_persistent_buffer(nb, self.window_size_f32);
-            total_particles += nb;
+        let config = self.shared.config.borrow();
+        let view = SceneView {
+            view_proj: self.shared.camera.borrow().view_projection(),
+            clock: self.clock,
+            content_scale: self.event_router.display.particle_scale(),
+            audio_level: self.audio_envelope.value(),
+            srgb_target: self.srgb_capable && self.offscreen.is_none(),
+        };
+        render_scene(
+            &mut self.frame_graph,
+            &mut self.resources,
+            &config,
+            &self.flashes,
+            physic,
+            &view,
+        );
+        self.frame_graph
+            .status_into(&mut self.shared.passes.borrow_mut());
+
+        let stats = &mut self.resources.stats;
+        if let Some(target) = &self.offscreen {
+            stats.memory.framebuffers += target.byte_size();
+            stats.add_framebuffer("output", target.width, target.height);
+        } else if let Some(window) = &self.window {
+            let (width, height) = window.get_framebuffer_size();
+            stats.add_framebuffer("output", width.max(0) as u32, height.max(0) as u32);
+        }
+        self.resources.record_allocations();
+        self.shared
+            .stats
+            .borrow_mut()
+            .clone_from(&self.resources.stats);
+        self.resources.particles_drawn
+    }
+
+    /// Durées GPU des passes exécutées (relues avec quelques frames de retard).
+    pub fn pass_gpu_times(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.frame_graph.gpu_times()
+    }
+
+    /// Durées GPU des passes relues, en métriques `gpu:<passe>` du profiler
+    /// (aucune si les requêtes de chronométrage sont indisponibles).
+    pub fn report_gpu_times(&self, profiler: &Profiler) {
+        for (pass, gpu_time) in self.pass_gpu_times() {
+            profiler.record_metric(format!("gpu:{}", pass), gpu_time);
+        }
+    }
+
+    /// Attente cumulée des renderers sur leurs fences GPU lors de la dernière frame.
+    pub fn gpu_sync_wait(&self) -> Duration {
+        self.resources.renderers.iter().map(|r| r.sync_wait()).sum()
+    }
+
+    /// Temps passé à trier les particules (mélange alpha) lors de la dernière frame.
+    pub fn particles_sort_time(&self) -> Duration {
+        self.resources.renderers.iter().map(|r| r.sort_time()).sum()
+    }
+
+    /// Efface puis dessine une frame dans la cible courante
+    /// (FBO en mode headless, framebuffer de la fenêtre sinon).
+    ///
+    /// # Safety
+    /// Cette fonction est unsafe car elle effectue des appels OpenGL non sécurisés.
+    pub unsafe fn render_offscreen<P: PhysicEngineIterator>(&mut self, physic: &P) -> usize {
+        if let Some(target) = &self.offscreen {
+            target.bind();
+        }
+        gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+        let total_particles = self.render_frame(physic);
+        if let Some(target) = &self.offscreen {
+            target.unbind();
         }
         total_particles
     }
 
+    /// Dessine une frame et la relit sous forme d'image (origine en haut à gauche).
+    pub fn render_to_image<P: PhysicEngineIterator>(
+        &mut self,
+        physic: &P,
+    ) -> Result<image::RgbaImage> {
+        unsafe { self.render_offscreen(physic) };
+
+        let (width, height, mut pixels) = match &self.offscreen {
+            Some(target) => (target.width, target.height, unsafe { target.read_rgba() }),
+            None => {
+                let window = self
+                    .window
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("No window to read from"))?;
+                let (w, h) = window.get_framebuffer_size();
+                let (w, h) = (w.max(0) as u32, h.max(0) as u32);
+                (w, h, unsafe { read_framebuffer_rgba(w, h) })
+            }
+        };
+        flip_rows(&mut pixels, width as usize, height as usize, 4);
+        image::RgbaImage::from_raw(width, height, pixels)
+            .ok_or_else(|| anyhow!("Framebuffer read size mismatch"))
+    }
+
+    /// Capture le framebuffer courant dans un PNG.
+    ///
+    /// La lecture GPU est synchrone, l'inversion des lignes et l'encodage se font
+    /// sur un thread dédié pour ne pas faire sauter de frame.
+    pub fn capture_screenshot(&self, path: Option<PathBuf>) -> Result<JoinHandle<Result<PathBuf>>> {
+        self.capture_screenshot_with_labels(path.unwrap_or_else(default_screenshot_path), &[])
+    }
+
+    /// Capture la scène HDR de la dernière frame, avant bloom et tone mapping,
+    /// dans un fichier OpenEXR (valeurs linéaires non bornées).
+    ///
+    /// Nécessite la passe HDR (bloom, exposition automatique ou tone mapping non
+    /// linéaire) ; la taille est celle de la résolution interne de rendu.
+    pub fn capture_hdr_screenshot(
+        &self,
+        path: Option<PathBuf>,
+    ) -> Result<JoinHandle<Result<PathBuf>>> {
+        let hdr = post_process_chain(&self.shared.config.borrow()).contains(&PostPass::Tonemap);
+        let bloom = self
+            .resources
+            .bloom
+            .as_ref()
+            .filter(|_| hdr)
+            .ok_or_else(|| {
+                anyhow!("No HDR target (enable bloom, auto exposure or a tone mapping operator)")
+            })?;
+        let (texture, width, height) = bloom.scene_texture();
+        let path = path.unwrap_or_else(default_hdr_screenshot_path);
+        let pixels = unsafe { read_texture_rgba32f(texture, width, height) };
+        info!(
+            "📸 Capturing HDR {} x {} -> {}",
+            width,
+            height,
+            path.display()
+        );
+        Ok(save_rgba32f_async(pixels, width, height, path))
+    }
+
+    /// Capture avec des étiquettes incrustées dans le coin haut-gauche de chaque rectangle.
+    fn capture_screenshot_with_labels(
+        &self,
+        path: PathBuf,
+        labels: &[(CellRect, &str)],
+    ) -> Result<JoinHandle<Result<PathBuf>>> {
+        let window = self
+            .window
+            .as_ref()
+            .ok_or_else(|| anyhow!("No window to capture"))?;
+        let (width, height) = window.get_framebuffer_size();
+        if width <= 0 || height <= 0 {
+            return Err(anyhow!("Empty framebuffer ({} x {})", width, height));
+        }
+        let (width, height) = (width as u32, height as u32);
+        let mut pixels = unsafe { read_framebuffer_rgba(width, height) };
+        for (cell, text) in labels {
+            let top = (height as i32 - (cell.y + cell.height)).max(0) as usize;
+            burn_label(
+                &mut pixels,
+                width as usize,
+                height as usize,
+                cell.x.max(0) as usize + 8,
+                top + 8,
+                text,
+                2,
+            );
+        }
+        info!("📸 Capturing {} x {} -> {}", width, height, path.display());
+        Ok(save_rgba_async(pixels, width, height, path))
+    }
+
+    /// Traite une capture demandée (F12 ou `renderer.screenshot`), avant l'overlay imgui.
+    fn process_screenshot_request(&self) {
+        if let Some(path) = self.shared.screenshot_request.borrow_mut().take() {
+            if let Err(e) = self.capture_screenshot(path) {
+                warn!("⚠️ Screenshot failed: {}", e);
+            }
+        }
+        if let Some(path) = self.shared.hdr_screenshot_request.borrow_mut().take() {
+            if let Err(e) = self.capture_hdr_screenshot(path) {
+                warn!("⚠️ Screenshot failed: {}", e);
+            }
+        }
+        if let Some(path) = self.shared.comparison_save_request.borrow_mut().take() {
+            if let Err(e) = self.save_tonemapping_comparison(path) {
+                warn!("⚠️ Tone mapping comparison not saved: {}", e);
+            }
+        }
+    }
+
+    /// Capture la grille de comparaison affichée (opérateurs ou gamma), réglage incrusté.
+    fn save_tonemapping_comparison(&self, path: Option<PathBuf>) -> Result<()> {
+        let cells = self
+            .resources
+            .bloom
+            .as_ref()
+            .map(|bloom| bloom.comparison_cells())
+            .unwrap_or_default();
+        if cells.is_empty() {
+            return Err(anyhow!("comparison grid is not displayed"));
+        }
+        let window = self
+            .window
+            .as_ref()
+            .ok_or_else(|| anyhow!("No window to capture"))?;
+        let (width, height) = window.get_framebuffer_size();
+        let labels: Vec<(CellRect, &str)> = comparison_grid(cells.len(), width, height)
+            .into_iter()
+            .zip(cells.iter().map(|cell| cell.label.as_str()))
+            .collect();
+        let path = path.unwrap_or_else(|| timestamped_path(SCREENSHOTS_DIR, "tonemapping", "png"));
+        self.capture_screenshot_with_labels(path, &labels)?;
+        Ok(())
+    }
+
+    /// Démarre l'export vidéo de chaque frame présentée (réglages `[recording]`).
+    pub fn start_recording(&mut self, output: Option<PathBuf>) -> Result<()> {
+        if let Some(recorder) = &self.recorder {
+            return Err(anyhow!(
+                "Already recording to {}",
+                recorder.output().display()
+            ));
+        }
+        let (width, height) = match (&self.offscreen, &self.window) {
+            (Some(target), _) => (target.width, target.height),
+            (None, Some(window)) => {
+                let (w, h) = window.get_framebuffer_size();
+                (w.max(0) as u32, h.max(0) as u32)
+            }
+            (None, None) => return Err(anyhow!("No window to record")),
+        };
+        let config = self.shared.config.borrow().recording.clone();
+        let output = output.unwrap_or_else(default_recording_path);
+        self.recorder = Some(unsafe { FrameRecorder::start(&config, width, height, output)? });
+        Ok(())
+    }
+
+    /// Termine l'export : vide le ring de PBO et attend ffmpeg.
+    /// Retourne le nombre de frames écrites (0 si aucun enregistrement).
+    pub fn stop_recording(&mut self) -> Result<u64> {
+        match self.recorder.take() {
+            Some(recorder) => unsafe { recorder.finish() },
+            None => Ok(0),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Traite une demande `renderer.record.*` puis relit la frame si un export est actif.
+    fn process_recording(&mut self) {
+        let request = self.shared.record_request.borrow_mut().take();
+        let result = match request {
+            Some(RecordRequest::Start(path)) => self.start_recording(path),
+            Some(RecordRequest::Stop) => self.stop_recording().map(|_| ()),
+            None => Ok(()),
+        };
+        if let Err(e) = result {
+            warn!("⚠️ Recording: {}", e);
+        }
+
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(e) = unsafe { recorder.capture_frame() } {
+                warn!("⚠️ Recording stopped: {}", e);
+                let _ = self.stop_recording();
+            }
+        }
+    }
+
+    /// Applique l'effet d'un événement de fenêtre (cf. `EventRouter`).
+    fn apply_reaction<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        reaction: Reaction,
+        physic: &mut P,
+        audio: &mut A,
+    ) {
+        match reaction {
+            Reaction::Resize(w, h) => unsafe {
+                gl::Viewport(0, 0, w, h);
+                self.window_size_f32 = (w as f32, h as f32);
+                self.frame_graph
+                    .resize(&mut self.resources, w as u32, h as u32);
+                // La physique reste en coordonnées monde (vue identité)
+                physic.set_window_width(w as f32);
+                self.shared
+                    .camera
+                    .borrow_mut()
+                    .set_viewport(Vec2::new(w as f32, h as f32));
+                audio.set_listener_position(((w / 2) as f32, 0.0));
+                self.apply_display_scale(self.event_router.display);
+            },
+            Reaction::Rescale(display) => {
+                if display.content_scale != self.shared.display_scale.get().content_scale {
+                    info!("🔍 {}", format_display_scale(&display));
+                }
+                self.apply_display_scale(display);
+            }
+            Reaction::Pan(delta) => self.shared.camera.borrow_mut().pan_by_screen(delta),
+            Reaction::Zoom { anchor, steps } => {
+                let mut camera = self.shared.camera.borrow_mut();
+                let factor = camera.config().wheel_zoom_step.powf(steps);
+                camera.zoom_about(anchor, factor);
+            }
+            Reaction::Action(action) => self.apply_action(action, physic),
+            // Chargement traité par la boucle (accès au moteur physique complet)
+            Reaction::FilesDropped(_) => {}
+            Reaction::Minimized(minimized) => {
+                if self.activity.set_minimized(minimized) {
+                    if minimized {
+                        info!("🪟 Window minimized: rendering paused, updates throttled");
+                    } else {
+                        info!("🪟 Window restored");
+                    }
+                }
+            }
+        }
+    }
+
+    /// (Re)lance ou arrête la console distante quand `[remote_console]` change.
+    fn update_console_server(&mut self) {
+        let config = self.shared.config.borrow().remote_console.clone();
+        if self.console_server_config.as_ref() == Some(&config) {
+            return;
+        }
+        // Libère le port avant d'en rouvrir un
+        self.console_server = None;
+        if config.enabled {
+            match ConsoleServer::start(&config) {
+                Ok(server) => self.console_server = Some(server),
+                Err(e) => warn!("⚠️ Remote console disabled: {:#}", e),
+            }
+        }
+        self.console_server_config = Some(config);
+    }
+
+    /// Recopie dans la console les journaux reçus depuis la dernière frame.
+    fn drain_console_logs(&mut self) {
+        let Some(sink) = console_log_sink() else {
+            return;
+        };
+        let spec = &self.shared.config.borrow().console_log_filter;
+        if *spec != self.console_log_filter {
+            match spec.parse::<LogFilter>() {
+                Ok(filter) => set_console_log_filter(filter),
+                Err(e) => warn!("⚠️ console_log_filter '{}' ignored: {}", spec, e),
+            }
+            self.console_log_filter = spec.clone();
+        }
+
+        let (messages, dropped) = sink.drain();
+        for message in messages {
+            let severity = match message.level {
+                log::Level::Error => Severity::Error,
+                log::Level::Warn => Severity::Warn,
+                _ => Severity::Info,
+            };
+            self.console.log_with(severity, message.text);
+        }
+        if dropped > 0 {
+            self.console.log_with(
+                Severity::Warn,
+                format!("⚠️ {} log message(s) dropped", dropped),
+            );
+        }
+    }
+
+    /// Échelle de l'UI ; les particules la lisent à chaque frame (`FrameContext`).
+    fn apply_display_scale(&mut self, display: DisplayScale) {
+        if let Some(system) = &mut self.imgui_system {
+            system.context.io_mut().font_global_scale = display.ui_scale();
+        }
+        self.shared.display_scale.set(display);
+    }
+
+    /// Facteur d'échelle du contenu (DPI) de l'écran de la fenêtre.
+    pub fn get_content_scale(&self) -> f32 {
+        self.event_router.display.content_scale
+    }
+
+    fn apply_action<P: PhysicEngine>(&mut self, action: Action, physic: &mut P) {
+        match action {
+            Action::Quit => {
+                if let Some(window) = &mut self.window {
+                    window.set_should_close(true);
+                }
+            }
+            // Rechargement traité par la boucle (accès au moteur physique complet)
+            Action::ReloadConfig => {}
+            Action::ReloadRendererConfig => {
+                if self.renderer_reload_debounce.accept(Instant::now()) {
+                    self.reload_renderer_config();
+                }
+            }
+            Action::ReloadShaders => {
+                info!("🔄 Reloading shaders");
+                unsafe { self.frame_graph.reload_shaders(&mut self.resources) };
+            }
+            Action::PauseSim => {
+                let paused = !self.shared.paused.get();
+                self.shared.paused.set(paused);
+                info!(
+                    "{}",
+                    if paused {
+                        "⏸️ Simulation paused"
+                    } else {
+                        "▶️ Simulation resumed"
+                    }
+                );
+            }
+            Action::SpeedDown | Action::SpeedUp => {
+                let current = self.shared.sim_speed.get().value();
+                let speed = next_speed_preset(current, action == Action::SpeedUp);
+                self.shared.sim_speed.set(SimSpeed::new(speed));
+                info!("⏩ Simulation speed: x{}", speed);
+            }
+            Action::Screenshot => self.shared.request_screenshot(None),
+            Action::LaunchRocket => {
+                if !physic.launch_rocket() {
+                    debug!("🚀 Manual launch skipped: max_rockets reached");
+                }
+            }
+            Action::NextShape | Action::PrevShape => {
+                let message = match cycle_explosion_shape(physic, action == Action::NextShape) {
+                    Ok(name) => format!("Explosion shape: {}", name),
+                    Err(e) => format!("Error: {}", e),
+                };
+                info!("✨ {}", message);
+                self.console.log(message);
+            }
+            Action::ToggleHud => {
+                let hud = &self.shared.hud_visible;
+                hud.set(!hud.get());
+            }
+            Action::ToggleFullscreen => self.apply_fullscreen_request(FullscreenRequest::Toggle),
+            Action::ToggleConsole => {
+                self.console.open = !self.console.open;
+                if let Some(window) = &mut self.window {
+                    window.set_cursor_mode(if self.console.open {
+                        self.console.focus_previous_widget = true;
+                        glfw::CursorMode::Normal
+                    } else {
+                        glfw::CursorMode::Disabled
+                    });
+                }
+            }
+        }
+    }
+
+    /// Change le mode d'affichage (F11 ou `renderer.window.fullscreen`).
+    ///
+    /// Le redimensionnement du framebuffer qui suit est traité par `Reaction::Resize`.
+    fn apply_fullscreen_request(&mut self, request: FullscreenRequest) {
+        let Some(window) = &mut self.window else {
+            return;
+        };
+        let monitors = list_monitors(&mut window.glfw.clone());
+        let config = {
+            let config = self.shared.config.borrow();
+            (config.fullscreen_monitor, config.fullscreen_borderless)
+        };
+        let placement = match self.fullscreen.resolve(
+            request,
+            &monitors,
+            window.get_pos(),
+            window.get_size(),
+            config,
+        ) {
+            Ok(Some(placement)) => placement,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("⚠️ Display mode unchanged: {}", e);
+                self.console.log(format!("Error: {}", e));
+                return;
+            }
+        };
+        window.set_placement(placement);
+
+        self.window_size = match placement {
+            WindowPlacement::Windowed { size, .. } | WindowPlacement::Borderless { size, .. } => {
+                size
+            }
+            WindowPlacement::Fullscreen { size, .. } => (size.0 as i32, size.1 as i32),
+        };
+        self.window_size_f32 = (self.window_size.0 as f32, self.window_size.1 as f32);
+        let mode = self.fullscreen.mode.describe(&monitors);
+        info!(
+            "🖥️ Display: {} ({} x {})",
+            mode, self.window_size.0, self.window_size.1
+        );
+        self.shared.display_mode.set(self.fullscreen.mode);
+        *self.shared.monitors.borrow_mut() = monitors;
+    }
+
+    fn process_fullscreen_request(&mut self) {
+        let request = self.shared.fullscreen_request.borrow_mut().take();
+        if let Some(request) = request {
+            self.apply_fullscreen_request(request);
+        }
+    }
+
+    /// Transmet l'événement brut du backend à l'UI (imgui), qui garde ses propres entrées.
+    fn forward_event_to_ui(&mut self, event: &glfw::WindowEvent) {
+        if let Some(system) = &mut self.imgui_system {
+            system.glfw.handle_event(&mut system.context, event);
+        }
+    }
+
     /// Boucle infinie (production) qui appelle `step_frame`
     pub fn run_loop<P: PhysicEngineFull, A: AudioEngine>(
         &mut self,
@@ -233,7 +1155,9 @@ impl Renderer {
         commands_registry: &CommandRegistry,
     ) -> Result<()> {
         // Partagé entre moteurs
-        let profiler = Profiler::new(200);
+        let profiler = self.profiler.clone();
+        // Démo, pause et vitesse : l'état partagé avec les commandes `sim.*`
+        let sim_state = SimState::from(&self.shared);
         let mut last_log = Instant::now();
         let log_interval = std::time::Duration::from_secs(5);
 
@@ -243,6 +1167,12 @@ impl Renderer {
         let mut sampled_fps: Vec<f32> = Vec::with_capacity(target_samples);
 
         audio.set_listener_position((self.window_size_f32.0 / 2.0, 0.0));
+        // Écran HiDPI : le framebuffer ne fait pas la taille demandée pour la fenêtre
+        let display = self.event_router.display;
+        if display.framebuffer_size != self.window_size {
+            let (w, h) = display.framebuffer_size;
+            self.apply_reaction(Reaction::Resize(w, h), physic, audio);
+        }
 
         // moyenne pondérée EMA
         let alpha = 0.15;
@@ -257,124 +1187,189 @@ impl Renderer {
             if window.should_close() {
                 break;
             }
+            if self
+                .frame_limit
+                .is_some_and(|limit| self.frames as u64 >= limit)
+            {
+                info!("🏁 Frame limit reached ({} frames)", self.frames);
+                break;
+            }
+            if let Some(limit) = &self.duration_limit {
+                if limit.is_finished(physic.get_stats().active_rockets) {
+                    info!(
+                        "🏁 Duration limit reached ({:.2} s simulated)",
+                        limit.elapsed()
+                    );
+                    break;
+                }
+            }
+            let mut reload_physic_config = false;
             let mut reload_config = false;
 
-            // Window events
-            if let Some(window) = &mut self.window {
-                self.glfw.poll_events();
-
-                if let Some(events) = &self.events {
-                    for (_, event) in glfw::flush_messages(events) {
-                        match event {
-                            glfw::WindowEvent::FramebufferSize(w, h) => unsafe {
-                                gl::Viewport(0, 0, w, h);
-                                self.window_size_f32 = (w as f32, h as f32);
-                                physic.set_window_width(w as f32);
-                                audio.set_listener_position(((w / 2) as f32, 0.0));
-                            },
-                            glfw::WindowEvent::Key(Key::Escape, _, Action::Press, _) => {
-                                window.set_should_close(true);
-                            }
-                            glfw::WindowEvent::Key(Key::R, _, Action::Press, _) => {
-                                reload_config = true;
+            // Window events : traduits en événements neutres puis transmis à l'UI
+            if let Some(glfw) = &mut self.glfw {
+                glfw.poll_events();
+            }
+            let raw_events: Vec<glfw::WindowEvent> = match &self.events {
+                Some(events) => glfw::flush_messages(events).map(|(_, e)| e).collect(),
+                None => Vec::new(),
+            };
+            for raw in raw_events {
+                if let Some(event) = translate_event(&raw) {
+                    match self.event_router.route(&event, self.console.open) {
+                        Some(Reaction::Action(Action::ReloadConfig)) => {
+                            reload_physic_config |=
+                                self.physic_reload_debounce.accept(Instant::now());
+                        }
+                        Some(Reaction::FilesDropped(paths)) => {
+                            let outcome = handle_file_drop(&paths, physic);
+                            for message in outcome.messages {
+                                self.console.log(message);
                             }
-                            glfw::WindowEvent::Key(Key::F11, _, Action::Press, _) => {
-                                if window.is_fullscreen() {
-                                    window.set_monitor(
-                                        WindowMode::Windowed,
-                                        self.window_last_pos.0,
-                                        self.window_last_pos.1,
-                                        self.window_last_size.0 as u32,
-                                        self.window_last_size.1 as u32,
-                                        None,
-                                    );
-                                    self.window_size = self.window_last_size;
-                                    self.window_size_f32 = (
-                                        self.window_last_size.0 as f32,
-                                        self.window_last_size.1 as f32,
-                                    );
-                                    info!(
-                                        "🖥️ Window resized: {} x {}",
-                                        self.window_size.0, self.window_size.1
+                            reload_config |= outcome.reload_config;
+                        }
+                        Some(reaction) => self.apply_reaction(reaction, physic, audio),
+                        // Touche libre : commande liée par `bind` (hors saisie console)
+                        None => {
+                            if let WindowEvent::KeyPress(key) = event {
+                                let typing = self.console.open && key.is_printable();
+                                if let Some(command) =
+                                    commands_registry.bound_command(key).filter(|_| !typing)
+                                {
+                                    self.console.run_bound(
+                                        &command,
+                                        Instant::now(),
+                                        audio,
+                                        physic,
+                                        commands_registry,
                                     );
-                                } else {
-                                    self.window_last_pos = window.get_pos();
-                                    self.window_last_size = window.get_size();
-
-                                    let mut glfw = window.glfw.clone();
-                                    glfw.with_primary_monitor(|_, monitor| {
-                                        if let Some(monitor) = monitor {
-                                            window.set_fullscreen(monitor);
-                                            self.window_size = (
-                                                monitor.get_video_mode().unwrap().width as i32,
-                                                monitor.get_video_mode().unwrap().height as i32,
-                                            );
-                                            self.window_size_f32 = (
-                                                self.window_last_size.0 as f32,
-                                                self.window_last_size.1 as f32,
-                                            );
-                                            info!(
-                                                "🖥️ Fullscreen: {} x {}",
-                                                self.window_size.0, self.window_size.1
-                                            );
-                                        }
-                                    });
                                 }
                             }
-                            glfw::WindowEvent::Key(Key::GraveAccent, _, Action::Press, _) => {
-                                self.console.open = !self.console.open;
-                                window.set_cursor_mode(if self.console.open {
-                                    self.console.focus_previous_widget = true;
-                                    glfw::CursorMode::Normal
-                                } else {
-                                    glfw::CursorMode::Disabled
-                                });
-                            }
-                            _ => {}
-                        }
-                        // Pas besoin de helper externe, on peut le faire "inline"
-                        if let Some(system) = &mut self.imgui_system {
-                            system.glfw.handle_event(&mut system.context, &event);
                         }
                     }
                 }
+                self.forward_event_to_ui(&raw);
             }
             if reload_config {
                 self.reload_config(physic);
+            } else if reload_physic_config {
+                self.reload_physic_config(physic);
+            }
+            // Fichiers de config surveillés (`--watch-config`)
+            let config_events = self.shared.config_manager.borrow_mut().poll(Instant::now());
+            for event in &config_events {
+                self.apply_config_event(event, physic);
+            }
+            // Rechargement fait par la console (`physic.config.reload`)
+            if let Some(result) = self.shared.physic_reload.take() {
+                let physic_config = physic.get_config().clone();
+                self.sync_gpu_capacity(&physic_config, result);
+            }
+            // Moteur physique changé (`physic.engine`) : buffers GPU repartis de zéro,
+            // à la même capacité
+            if physic.take_engine_switched() {
+                info!(
+                    "🔁 GPU buffers recreated for the {} engine",
+                    physic.engine_name()
+                );
+                unsafe {
+                    for renderer in &mut self.resources.renderers {
+                        renderer.recreate_buffers(self.max_particles_on_gpu);
+                    }
+                }
+            }
+
+            // Manettes : interrogées à chaque frame (branchement à chaud)
+            let devices = self.glfw.as_ref().map(poll_gamepads).unwrap_or_default();
+            let pad_dt = self
+                .sim_clock
+                .last_frame()
+                .map_or(0.0, |last| last.elapsed().as_secs_f32());
+            let bindings = self.shared.key_bindings.borrow().gamepad.clone();
+            let (reactions, changed) = self.gamepad.update(&devices, &bindings, pad_dt);
+            if changed {
+                *self.shared.gamepads.borrow_mut() = self.gamepad.connected().to_vec();
+            }
+            for reaction in reactions {
+                self.apply_reaction(reaction, physic, audio);
             }
 
             // 🔹 start global frame
             let _frame_guard = profiler.frame(); // RAII: mesure totale de la frame
 
-            let now = Instant::now();
-            let delta = now.duration_since(self.last_time).as_secs_f32();
-            self.last_time = now;
+            // Pause globale (P, `sim.pause`) : physique et voix audio figées ;
+            // vitesse de simulation (`sim.speed`, `[` / `]`)
+            let timing = begin_sim_frame(&mut self.sim_clock, &sim_state, audio, Instant::now());
+            let delta = timing.frame_dt;
             self.frames += 1;
 
             // 🔹 Calcul FPS instantané
             let fps = if delta > 0.0 { 1.0 / delta } else { 0.0 };
 
             // 🔹 On demande à l’échantillonneur s’il faut enregistrer ce FPS
-            if sampler.should_sample(delta) {
+            // (les frames en pause sont exclues des moyennes)
+            if !timing.paused && sampler.should_sample(delta) {
                 sampled_fps.push(fps);
             }
 
-            let update_result = profiler.profile_block("physic - update", || physic.update(delta));
-            self.synch_audio_with_physic(&update_result, audio);
+            self.advance_clock(delta);
+            self.follow_audio_level(audio.output_level(), delta);
+            {
+                let mut camera = self.shared.camera.borrow_mut();
+                camera.update(delta);
+                if camera.config().listener_follows_camera {
+                    audio.set_listener_position((camera.center.x, camera.center.y));
+                }
+            }
 
-            // Clear screen before rendering
-            unsafe {
-                // Efface l’écran (fond noir)
-                gl::ClearColor(0.0, 0.0, 0.0, 1.0);
-                gl::Clear(gl::COLOR_BUFFER_BIT);
+            // Pendant un export vidéo, la simulation avance au rythme de la vidéo
+            if !timing.paused {
+                let sim_delta = self
+                    .recorder
+                    .as_ref()
+                    .and_then(FrameRecorder::fixed_time_step)
+                    .map(|dt| dt * self.sim_clock.time_scale())
+                    .unwrap_or(timing.sim_dt);
+                let flashes = step_simulation(
+                    Some(&profiler),
+                    physic,
+                    audio,
+                    &sim_state,
+                    self.duration_limit.as_mut(),
+                    sim_delta,
+                );
+                for flash in flashes {
+                    self.add_flash(flash);
+                }
+            } else {
+                let active = physic.get_stats().active_particles;
+                profiler.record_metric(ACTIVE_PARTICLES_METRIC, active.explosions + active.trails);
             }
 
-            // Render frame with all renderers
-            profiler.profile_block("render frame", || {
-                profiler.record_metric("total particles drawn", unsafe {
-                    self.render_frame(physic)
+            // Fenêtre réduite : physique et audio seulement
+            let rendering = self.activity.should_render();
+            if rendering {
+                // Clear screen before rendering
+                unsafe {
+                    // Efface l’écran (fond noir)
+                    gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+                    gl::Clear(gl::COLOR_BUFFER_BIT);
+                }
+
+                // Render frame with all renderers
+                profiler.profile_block("render frame", || {
+                    profiler.record_metric("total particles drawn", unsafe {
+                        self.render_frame(physic)
+                    });
                 });
-            });
+                profiler.record_metric("gpu sync wait", self.gpu_sync_wait());
+                profiler.record_metric("particles sort", self.particles_sort_time());
+                self.report_gpu_times(&profiler);
+
+                self.process_screenshot_request();
+                self.process_fullscreen_request();
+                self.process_recording();
+            }
 
             // FPSmoyenne​ ← α⋅FPSinstant ​+ (1 − α)⋅FPSmoyenne​
             fps_avg = alpha * fps + (1.0 - alpha) * fps_avg;
@@ -402,12 +1397,34 @@ impl Renderer {
                     // [Trait Iterator - for_each - Calls a closure on each element of an iterator.](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.for_each)
                     graph.lines().for_each(|line| info!("{}", line));
 
+                    // 🔹 Durées de frame : pics marqués, puis 1% low glissant
+                    let spike_factor = self.shared.config.borrow().sampler_spike_factor;
+                    info!("Graphe - Frame Time Timeline");
+                    ascii_frame_time_timeline(
+                        &sampler.frame_times,
+                        log_interval.as_secs_f32(),
+                        50,
+                        spike_factor,
+                    )
+                    .lines()
+                    .for_each(|line| info!("{}", line));
+                    info!("Graphe - 1% Low Timeline");
+                    ascii_one_percent_low_timeline(
+                        &sampler.frame_times,
+                        log_interval.as_secs_f32(),
+                        50,
+                        1.0,
+                    )
+                    .lines()
+                    .for_each(|line| info!("{}", line));
+
                     info!(
                         "Samples: {} / {} | Moyenne FPS: {:.2}",
                         sampler.samples.len(),
                         sampler.target_samples,
                         avg_fps
                     );
+                    profiler.record_metric("sampled fps", avg_fps);
 
                     sampler.reset();
 
@@ -415,19 +1432,92 @@ impl Renderer {
                     info!("FPS moyen (iter): {:.2}", fps_avg_iter);
                 }
 
+                if let Err(e) = profiler.flush_periodic() {
+                    warn!("⚠️ Periodic metrics export failed: {e:#}");
+                }
                 last_log = Instant::now();
             }
 
+            // Journaux du simulateur, puis commandes console différées par `wait`
+            self.console
+                .set_max_lines(self.shared.config.borrow().console_max_lines);
+            commands_registry.set_usage_weight(self.shared.config.borrow().console_usage_weight);
+            self.drain_console_logs();
+            self.update_console_server();
+            if let Some(server) = &self.console_server {
+                for command in server.poll() {
+                    let output = self.console.run_remote(
+                        &command.line,
+                        Instant::now(),
+                        audio,
+                        physic,
+                        commands_registry,
+                    );
+                    command.reply(output);
+                }
+            }
+            self.console
+                .tick(Instant::now(), audio, physic, commands_registry);
+
+            let stats = physic.get_stats();
+            let title = self
+                .title
+                .update(delta, fps_avg, stats.active_particles.total());
+
             if let Some(window) = &mut self.window {
-                if self.console.open {
+                if let Some(title) = title {
+                    window.set_title(&title);
+                }
+                let hud_visible = self.shared.hud_visible.get();
+                if rendering && (self.console.open || hud_visible || timing.paused) {
                     if let Some(system) = &mut self.imgui_system {
                         let ui = system.glfw.frame(window, &mut system.context);
-                        self.console.draw(ui, audio, physic, commands_registry);
+                        // Après la capture d'écran : l'indicateur n'y figure pas
+                        if timing.paused {
+                            draw_pause_indicator(ui);
+                        }
+                        if hud_visible {
+                            let stats = HudStats {
+                                sim_speed: self.sim_clock.time_scale(),
+                                remaining_time: self
+                                    .duration_limit
+                                    .as_ref()
+                                    .map(DurationLimit::remaining),
+                                ..HudStats::collect(
+                                    fps_avg,
+                                    &profiler,
+                                    &stats,
+                                    audio.active_voices(),
+                                    &self.shared.config.borrow(),
+                                )
+                            };
+                            draw_hud(ui, &stats);
+                        }
+                        if self.console.open {
+                            self.console.draw(ui, audio, physic, commands_registry);
+                        }
                         system.glfw.draw(&mut system.context, window);
                     }
                 }
 
-                window.swap_buffers();
+                if rendering {
+                    window.swap_buffers();
+                }
+
+                // V-sync modifiable à chaud ; sinon plafond CPU éventuel
+                let (vsync, budget) = {
+                    let config = self.shared.config.borrow();
+                    (config.vsync, config.frame_budget())
+                };
+                if vsync != self.vsync {
+                    if let Some(glfw) = &mut self.glfw {
+                        glfw.set_vsync(vsync);
+                    }
+                    self.vsync = vsync;
+                    info!("🖥️ V-sync: {}", if vsync { "on" } else { "off" });
+                }
+                let budget = self.activity.frame_budget(budget);
+                profiler.record_metric("frame limiter wait", self.frame_limiter.wait(budget));
 
                 if first_frame {
                     info!("🚀 First frame rendered");
@@ -439,33 +1529,25 @@ impl Renderer {
         Ok(())
     }
 
-    fn synch_audio_with_physic<A: AudioEngine>(
-        &mut self,
-        update_result: &UpdateResult,
-        audio: &mut A,
-    ) {
-        if let Some(rocket) = &update_result.new_rocket {
-            debug!("🚀 Rocket spawned at ({}, {})", rocket.pos.x, rocket.pos.y);
-            audio.play_rocket((rocket.pos.x, rocket.pos.y), 0.6);
+    /// Libère les ressources GL puis la fenêtre. Idempotent.
+    pub fn close(&mut self) {
+        if !self.has_gl_context() {
+            return;
         }
+        info!("🧹 Fermeture du Renderer");
 
-        for (i, expl) in update_result.triggered_explosions.iter().enumerate() {
-            debug!(
-                "💥 Explosion triggered: {} at ({}, {})",
-                i, expl.pos.x, expl.pos.y
-            );
-            audio.play_explosion((expl.pos.x, expl.pos.y), 1.0);
+        if let Err(e) = self.stop_recording() {
+            warn!("⚠️ Recording: {}", e);
         }
-    }
-
-    pub fn close(&mut self) {
-        info!("🧹 Fermeture du Renderer");
 
         unsafe {
-            for renderer in &mut self.renderers {
-                renderer.close();
+            self.frame_graph.close();
+            self.resources.close();
+            if let Some(target) = &mut self.offscreen {
+                target.delete();
             }
         }
+        self.offscreen = None;
 
         // Important de drop la ressource imgui pour glfw avant de drop la window glfw
         // car la window porte le contexte OpenGL et au drop de imgui_glfw il sera alors
@@ -474,6 +1556,19 @@ impl Renderer {
         self.imgui_system = None;
 
         self.window = None;
+        #[cfg(feature = "headless_egl")]
+        {
+            self.egl_context = None;
+        }
+    }
+
+    /// Contexte OpenGL encore ouvert (fenêtre, ou contexte EGL en headless)
+    fn has_gl_context(&self) -> bool {
+        #[cfg(feature = "headless_egl")]
+        if self.egl_context.is_some() {
+            return true;
+        }
+        self.window.is_some()
     }
 }
 
@@ -491,4 +1586,1320 @@ impl RendererEngine for Renderer {
     fn close(&mut self) {
         self.close();
     }
+
+    fn register_commands(&self, registry: &mut CommandRegistry) {
+        register_renderer_commands(registry, &self.shared);
+        registry.set_sim_state(SimState::from(&self.shared));
+    }
+
+    fn render_stats(&self) -> RenderStats {
+        self.shared.stats.borrow().clone()
+    }
+
+    fn set_frame_limit(&mut self, frames: Option<u64>) {
+        self.frame_limit = frames;
+    }
+
+    fn set_duration_limit(&mut self, limit: Option<DurationLimit>) {
+        self.duration_limit = limit;
+    }
+
+    fn add_flash(&mut self, flash: Flash) {
+        self.add_flash(flash);
+    }
+
+    fn profiler(&self) -> Option<&Profiler> {
+        Some(&self.profiler)
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        if let Some(window) = &self.window {
+            // En plein écran, on retient la fenêtre d'avant pour y revenir
+            let windowed = self.fullscreen.mode == DisplayMode::Windowed;
+            let (pos, size) = if windowed {
+                (window.get_pos(), window.get_size())
+            } else {
+                (self.fullscreen.windowed_pos, self.fullscreen.windowed_size)
+            };
+            session.window = Some(WindowSession {
+                size: [size.0, size.1],
+                position: Some([pos.0, pos.1]),
+                fullscreen: !windowed,
+            });
+        }
+        let camera = self.shared.camera.borrow();
+        session.camera = Some(CameraSession {
+            center: camera.target_center.into(),
+            zoom: camera.target_zoom,
+        });
+        let base = self.shared.config_manager.borrow().renderer_config();
+        if let Err(e) = session.set_renderer_overrides(&base, &self.shared.config.borrow()) {
+            warn!("⚠️ Renderer settings not saved in the session: {:#}", e);
+        }
+    }
+
+    fn restore_session(&mut self, session: &Session) {
+        if let Some([x, y]) = session.window.as_ref().and_then(|w| w.position) {
+            if let Some(window) = &mut self.window {
+                if self.fullscreen.mode == DisplayMode::Windowed {
+                    window.set_pos(x, y);
+                }
+            }
+            self.fullscreen.windowed_pos = (x, y);
+        }
+        let restored = session.apply_renderer_overrides(&self.shared.config.borrow());
+        match restored {
+            Ok(config) => {
+                self.shared
+                    .camera
+                    .borrow_mut()
+                    .set_config(config.camera.clone());
+                *self.shared.config.borrow_mut() = config;
+            }
+            Err(e) => warn!("⚠️ Session renderer settings ignored: {:#}", e),
+        }
+        if let Some(saved) = &session.camera {
+            let mut camera = self.shared.camera.borrow_mut();
+            camera.target_center = Vec2::from(saved.center);
+            camera.center = camera.target_center;
+            camera.target_zoom = saved.zoom;
+            camera.zoom = saved.zoom;
+        }
+    }
+}
+
+/// État du renderer partagé avec les commandes console (thread principal uniquement).
+#[derive(Debug, Clone, Default)]
+pub struct RendererShared {
+    /// Réglages de rendu
+    pub config: Rc<RefCell<RendererConfig>>,
+    /// Configs sur disque et leurs surcharges (`renderer.config.reload`, touches R / F7)
+    pub config_manager: Rc<RefCell<ConfigManager>>,
+    /// Résultat de `physic.config.reload`, buffers GPU ajustés à la prochaine frame
+    pub physic_reload: Rc<Cell<Option<ReloadResult>>>,
+    /// Capture demandée pour la prochaine frame (`Some(None)` = chemin horodaté)
+    pub screenshot_request: Rc<RefCell<Option<Option<PathBuf>>>>,
+    /// Capture HDR (OpenEXR, avant tone mapping) demandée pour la prochaine frame
+    pub hdr_screenshot_request: Rc<RefCell<Option<Option<PathBuf>>>>,
+    /// Export de la grille de comparaison du tone mapping (`Some(None)` = chemin horodaté)
+    pub comparison_save_request: Rc<RefCell<Option<Option<PathBuf>>>>,
+    /// Démarrage / arrêt d'export vidéo demandé pour la prochaine frame
+    pub record_request: Rc<RefCell<Option<RecordRequest>>>,
+    /// Caméra de la vue (zoom / déplacement)
+    pub camera: Rc<RefCell<Camera2D>>,
+    /// Overlay de debug affiché (F1 ou `renderer.hud`)
+    pub hud_visible: Rc<Cell<bool>>,
+    /// Passes de la dernière frame, dans l'ordre d'exécution (`renderer.passes`)
+    pub passes: Rc<RefCell<Vec<PassStatus>>>,
+    /// Compteurs de la dernière frame (`renderer.stats`)
+    pub stats: Rc<RefCell<RenderStats>>,
+    /// Raccourcis clavier (`renderer.input.bindings`, `renderer.input.reload`)
+    pub key_bindings: Rc<RefCell<KeyBindings>>,
+    /// Physique figée (touche `pause_sim`), le rendu continue
+    pub paused: Rc<Cell<bool>>,
+    /// Vitesse de simulation (`sim.speed`, touches `speed_down` / `speed_up`)
+    pub sim_speed: Rc<Cell<SimSpeed>>,
+    /// Mode démo (`sim.demo`, `--demo`), avancé à chaque pas de simulation
+    pub demo: Rc<RefCell<DemoDirector>>,
+    /// Écrans connectés, relus à chaque changement de mode (`renderer.window.monitors`)
+    pub monitors: Rc<RefCell<Vec<MonitorInfo>>>,
+    /// Mode d'affichage courant
+    pub display_mode: Rc<Cell<DisplayMode>>,
+    /// Changement de mode demandé par la console, appliqué à la prochaine frame
+    pub fullscreen_request: Rc<RefCell<Option<FullscreenRequest>>>,
+    /// Manettes connectées : (id, nom) (`renderer.input.gamepad`)
+    pub gamepads: Rc<RefCell<Vec<(usize, String)>>>,
+    /// Échelle d'affichage courante (`renderer.window.scale`)
+    pub display_scale: Rc<Cell<DisplayScale>>,
+}
+
+/// Demande d'export vidéo émise par la console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordRequest {
+    /// Démarre l'export (`None` = chemin horodaté)
+    Start(Option<PathBuf>),
+    Stop,
+}
+
+impl RendererShared {
+    pub fn request_screenshot(&self, path: Option<PathBuf>) {
+        *self.screenshot_request.borrow_mut() = Some(path);
+    }
+
+    pub fn request_hdr_screenshot(&self, path: Option<PathBuf>) {
+        *self.hdr_screenshot_request.borrow_mut() = Some(path);
+    }
+
+    pub fn request_comparison_save(&self, path: Option<PathBuf>) {
+        *self.comparison_save_request.borrow_mut() = Some(path);
+    }
+
+    pub fn request_record(&self, request: RecordRequest) {
+        *self.record_request.borrow_mut() = Some(request);
+    }
+
+    pub fn request_fullscreen(&self, request: FullscreenRequest) {
+        *self.fullscreen_request.borrow_mut() = Some(request);
+    }
+
+    /// Fichier de config du rendu (`--renderer-config`, `renderer.toml` par défaut)
+    pub fn config_path(&self) -> PathBuf {
+        self.config_manager
+            .borrow()
+            .path(ConfigSection::Renderer)
+            .to_path_buf()
+    }
+
+    /// Relit la config du rendu et l'applique (caméra comprise) ; `None` si
+    /// rien n'a changé.
+    pub fn reload_config(&self) -> anyhow::Result<Option<ConfigEvent>> {
+        let event = self
+            .config_manager
+            .borrow_mut()
+            .reload(ConfigSection::Renderer)?;
+        if event.is_some() {
+            self.apply_manager_config();
+        }
+        Ok(event)
+    }
+
+    /// Reprend la config du rendu du `ConfigManager` (caméra comprise).
+    pub fn apply_manager_config(&self) {
+        let config = self.config_manager.borrow().renderer_config();
+        self.camera.borrow_mut().set_config(config.camera.clone());
+        *self.config.borrow_mut() = config;
+    }
+}
+
+/// Commandes console `renderer.*`, agissant sur l'état partagé du renderer.
+pub fn register_renderer_commands(registry: &mut CommandRegistry, shared: &RendererShared) {
+    // "renderer.motionblur [0..1]" : sans argument, affiche la valeur courante
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.motionblur", move |args| {
+        match args.split_whitespace().nth(1).map(str::parse::<f32>) {
+            None => {
+                let cfg = cfg.borrow();
+                format!(
+                    "Motion blur: {} (strength {:.2})",
+                    if cfg.motion_blur_enabled { "on" } else { "off" },
+                    cfg.motion_blur_strength
+                )
+            }
+            Some(Ok(strength)) if (0.0..=1.0).contains(&strength) => {
+                cfg.borrow_mut().set_motion_blur(strength);
+                format!("Motion blur strength set to {:.2}", strength)
+            }
+            Some(_) => "Usage: renderer.motionblur <0..1>".to_string(),
+        }
+    });
+
+    // "renderer.screenshot [--hdr] [path]" : capture à la fin de la frame courante
+    // (`--hdr` : scène avant tone mapping, en OpenEXR)
+    let request = shared.clone();
+    registry.register_for_renderer("renderer.screenshot", move |args| {
+        let mut words = args.split_whitespace().skip(1).peekable();
+        let hdr = words.next_if_eq(&"--hdr").is_some();
+        let path = words.next();
+        if words.next().is_some() || path.is_some_and(|p| p.starts_with("--")) {
+            return "Usage: renderer.screenshot [--hdr] [path]".to_string();
+        }
+        let path = path.map(PathBuf::from);
+        let kind = if hdr { "HDR screenshot" } else { "Screenshot" };
+        let message = match &path {
+            Some(path) => format!("{} requested: {}", kind, path.display()),
+            None => format!("{} requested", kind),
+        };
+        if hdr {
+            request.request_hdr_screenshot(path);
+        } else {
+            request.request_screenshot(path);
+        }
+        message
+    });
+
+    // "renderer.record.start [path]" / "renderer.record.stop" : export vidéo via ffmpeg
+    let request = shared.clone();
+    registry.register_for_renderer("renderer.record.start", move |args| {
+        let path = args.split_whitespace().nth(1).map(PathBuf::from);
+        let message = match &path {
+            Some(path) => format!("Recording requested: {}", path.display()),
+            None => "Recording requested".to_string(),
+        };
+        request.request_record(RecordRequest::Start(path));
+        message
+    });
+
+    let request = shared.clone();
+    registry.register_for_renderer("renderer.record.stop", move |_args| {
+        request.request_record(RecordRequest::Stop);
+        "Recording stop requested".to_string()
+    });
+
+    // "renderer.camera.zoom [f]" : zoom cible autour du centre de la vue
+    let camera = shared.camera.clone();
+    registry.register_for_renderer("renderer.camera.zoom", move |args| {
+        match args.split_whitespace().nth(1).map(str::parse::<f32>) {
+            None => format!("Camera zoom: {:.2}", camera.borrow().zoom),
+            Some(Ok(zoom)) if zoom > 0.0 => {
+                let mut camera = camera.borrow_mut();
+                camera.set_zoom(zoom);
+                format!("Camera zoom set to {:.2}", camera.target_zoom)
+            }
+            Some(_) => "Usage: renderer.camera.zoom <f>".to_string(),
+        }
+    });
+
+    let camera = shared.camera.clone();
+    registry.register_for_renderer("renderer.camera.reset", move |_args| {
+        camera.borrow_mut().reset();
+        "Camera reset".to_string()
+    });
+
+    // "renderer.background <on|off>" : ciel en dégradé et étoiles
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.background", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().background.enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.background <on|off>".to_string(),
+        };
+        cfg.borrow_mut().background.enabled = enabled;
+        format!("Background: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.flash <on|off>" : éclair de lumière à chaque détonation
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.flash", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().flash_enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.flash <on|off>".to_string(),
+        };
+        cfg.borrow_mut().flash_enabled = enabled;
+        format!("Explosion flash: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.bloom <on|off>" : halo autour des zones lumineuses
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.bloom", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().bloom_enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.bloom <on|off>".to_string(),
+        };
+        cfg.borrow_mut().bloom_enabled = enabled;
+        format!("Bloom: {}", if enabled { "on" } else { "off" })
+    });
+
+    // Cvars du bloom : valeur lue dans la config (rechargeable), écrite par ses setters
+    let defaults = RendererConfig::default();
+
+    // "renderer.bloom.threshold <f>" : luminance minimale contribuant au bloom
+    let (cfg, source) = (shared.config.clone(), shared.config.clone());
+    let (min, max) = BLOOM_THRESHOLD_RANGE;
+    registry.add_cvar(
+        Cvar::new(
+            "renderer.bloom.threshold",
+            defaults.bloom_threshold,
+            move |value| {
+                cfg.borrow_mut().set_bloom_threshold(value);
+            },
+        )
+        .range(min, max)
+        .label("Bloom threshold")
+        .description("luminance")
+        .source(move || source.borrow().bloom_threshold),
+    );
+
+    // "renderer.bloom.knee <f>" : douceur de la transition sous le seuil
+    let (cfg, source) = (shared.config.clone(), shared.config.clone());
+    let (min, max) = BLOOM_SOFT_KNEE_RANGE;
+    registry.add_cvar(
+        Cvar::new(
+            "renderer.bloom.knee",
+            defaults.bloom_soft_knee,
+            move |value| {
+                cfg.borrow_mut().set_bloom_soft_knee(value);
+            },
+        )
+        .range(min, max)
+        .label("Bloom soft knee")
+        .description("fraction of threshold")
+        .source(move || source.borrow().bloom_soft_knee),
+    );
+
+    // "renderer.bloom.dirt <0..1>" : salissures d'objectif révélées par le halo
+    let (cfg, source) = (shared.config.clone(), shared.config.clone());
+    let (min, max) = LENS_DIRT_STRENGTH_RANGE;
+    registry.add_cvar(
+        Cvar::new(
+            "renderer.bloom.dirt",
+            defaults.lens_dirt_strength,
+            move |value| {
+                cfg.borrow_mut().set_lens_dirt_strength(value);
+            },
+        )
+        .range(min, max)
+        .label("Lens dirt strength")
+        .source(move || source.borrow().lens_dirt_strength),
+    );
+
+    // "renderer.bloom.audio <on|off|reactivity>" : intensité du bloom modulée par le son
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.bloom.audio", move |args| {
+        let usage = format!(
+            "Usage: renderer.bloom.audio <on|off|reactivity>  ({:.1}..{:.1})",
+            BLOOM_AUDIO_REACTIVITY_RANGE.0, BLOOM_AUDIO_REACTIVITY_RANGE.1
+        );
+        let mut config = cfg.borrow_mut();
+        let mut clamped = false;
+        match args.split_whitespace().nth(1) {
+            None => config.bloom_audio_reactive = !config.bloom_audio_reactive,
+            Some("on") => config.bloom_audio_reactive = true,
+            Some("off") => config.bloom_audio_reactive = false,
+            Some(value) => match value.parse::<f32>() {
+                Ok(reactivity) if reactivity.is_finite() => {
+                    clamped = config.set_bloom_audio_reactivity(reactivity) != reactivity;
+                    config.bloom_audio_reactive = true;
+                }
+                _ => return usage,
+            },
+        }
+        if !config.bloom_audio_reactive {
+            return "Audio-reactive bloom: off".to_string();
+        }
+        format!(
+            "Audio-reactive bloom: on (reactivity {:.2}{})",
+            config.bloom_audio_reactivity,
+            if clamped { ", clamped" } else { "" }
+        )
+    });
+
+    // "renderer.exposure <on|off>" : exposition automatique (adaptation de l'œil)
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.exposure", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().auto_exposure_enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.exposure <on|off>".to_string(),
+        };
+        cfg.borrow_mut().auto_exposure_enabled = enabled;
+        format!("Auto exposure: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.exposure.speed <f>" : vitesse d'adaptation (1/s)
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.exposure.speed", move |args| {
+        match args.split_whitespace().nth(1).map(str::parse::<f32>) {
+            None => format!("Exposure speed: {:.2}", cfg.borrow().auto_exposure_speed),
+            Some(Ok(speed)) if speed.is_finite() && speed >= 0.0 => {
+                cfg.borrow_mut().auto_exposure_speed = speed;
+                format!("Exposure speed set to {:.2}", speed)
+            }
+            Some(_) => "Usage: renderer.exposure.speed <f>  (>= 0, 1/s)".to_string(),
+        }
+    });
+
+    // "renderer.exposure.range <min> <max>" : bornes de l'exposition
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.exposure.range", move |args| {
+        let values: Vec<_> = args
+            .split_whitespace()
+            .skip(1)
+            .map(str::parse::<f32>)
+            .collect();
+        match values.as_slice() {
+            [] => {
+                let cfg = cfg.borrow();
+                format!(
+                    "Exposure range: {:.2}..{:.2}",
+                    cfg.auto_exposure_min, cfg.auto_exposure_max
+                )
+            }
+            [Ok(min), Ok(max)] if min.is_finite() && max.is_finite() => {
+                let mut cfg = cfg.borrow_mut();
+                cfg.set_auto_exposure_range(*min, *max);
+                format!(
+                    "Exposure range set to {:.2}..{:.2}",
+                    cfg.auto_exposure_min, cfg.auto_exposure_max
+                )
+            }
+            _ => "Usage: renderer.exposure.range <min> <max>".to_string(),
+        }
+    });
+
+    // "renderer.fxaa <on|off>" : anti-aliasing en fin de chaîne
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.fxaa", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().fxaa_enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.fxaa <on|off>".to_string(),
+        };
+        cfg.borrow_mut().fxaa_enabled = enabled;
+        format!("FXAA: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.tonemapping [mode]" : opérateur de la composition HDR
+    let (cfg, source) = (shared.config.clone(), shared.config.clone());
+    registry.add_cvar(
+        Cvar::new(
+            "renderer.tonemapping",
+            RendererConfig::default().tonemapping,
+            move |mode| cfg.borrow_mut().tonemapping = mode,
+        )
+        .label("Tone mapping")
+        .source(move || source.borrow().tonemapping),
+    );
+
+    // "renderer.tonemapping.compare <on|off>" : grille de comparaison des opérateurs
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.tonemapping.compare", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().tonemapping_compare,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.tonemapping.compare <on|off>".to_string(),
+        };
+        cfg.borrow_mut().tonemapping_compare = enabled;
+        format!(
+            "Tone mapping comparison: {}",
+            if enabled { "on" } else { "off" }
+        )
+    });
+
+    // "renderer.tonemapping.compare.modes aces reinhard agx" : contenu et ordre de la grille
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.tonemapping.compare.modes", move |args| {
+        let names: Vec<&str> = args.split_whitespace().skip(1).collect();
+        if names.is_empty() {
+            let config = cfg.borrow();
+            let current: Vec<&str> = config
+                .tonemapping_compare_modes
+                .iter()
+                .map(ToneMappingMode::name)
+                .collect();
+            return format!("Tone mapping comparison modes: {}", current.join(" "));
+        }
+        match parse_comparison_modes(names) {
+            Ok(modes) => {
+                let list: Vec<&str> = modes.iter().map(ToneMappingMode::name).collect();
+                let message = format!("Tone mapping comparison: {}", list.join(" "));
+                let mut config = cfg.borrow_mut();
+                config.tonemapping_compare_modes = modes;
+                config.tonemapping_compare = true;
+                message
+            }
+            Err(e) => format!(
+                "{}\nUsage: renderer.tonemapping.compare.modes <{}>...",
+                e,
+                tonemapping_mode_names().join("|")
+            ),
+        }
+    });
+
+    // "renderer.tonemapping.compare.save [path]" : capture de la grille, étiquettes incrustées
+    let request = shared.clone();
+    registry.register_for_renderer("renderer.tonemapping.compare.save", move |args| {
+        let comparing = {
+            let config = request.config.borrow();
+            config.tonemapping_compare || config.gamma_compare
+        };
+        if !comparing {
+            return "Tone mapping comparison is off (renderer.tonemapping.compare on)".to_string();
+        }
+        let path = args.split_whitespace().nth(1).map(PathBuf::from);
+        let message = match &path {
+            Some(path) => format!("Comparison grid requested: {}", path.display()),
+            None => "Comparison grid requested".to_string(),
+        };
+        request.request_comparison_save(path);
+        message
+    });
+
+    // "renderer.preset <low|medium|high|ultra>" : réglages de qualité appliqués d'un bloc
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.preset", move |args| {
+        let usage = || {
+            let names: Vec<&str> = QualityPreset::ALL.iter().map(QualityPreset::name).collect();
+            format!("Usage: renderer.preset <{}>", names.join("|"))
+        };
+        match args.split_whitespace().nth(1) {
+            None => {
+                let config = cfg.borrow();
+                let name = config.active_preset().map_or("custom", |p| p.name());
+                format!(
+                    "Quality preset: {} ({})",
+                    name,
+                    config.quality_settings().describe()
+                )
+            }
+            Some(name) => match QualityPreset::from_name(name) {
+                Some(preset) => {
+                    let settings = cfg.borrow_mut().apply_preset(preset);
+                    format!(
+                        "Quality preset: {} ({})",
+                        preset.name(),
+                        settings.describe()
+                    )
+                }
+                None => usage(),
+            },
+        }
+    });
+
+    // "renderer.trails <dots|ribbon>" : rendu des trails
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.trails", move |args| {
+        match args.split_whitespace().nth(1) {
+            None => format!("Trail style: {}", cfg.borrow().trail_style.name()),
+            Some(name) => match TrailStyle::from_name(name) {
+                Some(style) => {
+                    cfg.borrow_mut().trail_style = style;
+                    format!("Trail style: {}", style.name())
+                }
+                None => "Usage: renderer.trails <dots|ribbon>".to_string(),
+            },
+        }
+    });
+
+    // "renderer.gamma <f>" : gamma d'encodage de la sortie (1.0 : linéaire)
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.gamma", move |args| {
+        let (min, max) = OUTPUT_GAMMA_RANGE;
+        match args.split_whitespace().nth(1).map(str::parse::<f32>) {
+            None => format!("Output gamma: {:.2}", cfg.borrow().output_gamma),
+            Some(Ok(value)) if value.is_finite() => {
+                let applied = cfg.borrow_mut().set_output_gamma(value);
+                format_clamped("Output gamma", value, applied)
+            }
+            Some(_) => format!("Usage: renderer.gamma <f>  ({:.1}..{:.1})", min, max),
+        }
+    });
+
+    // "renderer.gamma.compare <on|off>" : grille gamma 1.0 / output_gamma
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.gamma.compare", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().gamma_compare,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.gamma.compare <on|off>".to_string(),
+        };
+        cfg.borrow_mut().gamma_compare = enabled;
+        format!("Gamma comparison: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.scale <f>" : résolution interne de la scène (surfaces recréées à la frame suivante)
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.scale", move |args| {
+        let (min, max) = RENDER_SCALE_RANGE;
+        match args.split_whitespace().nth(1).map(str::parse::<f32>) {
+            None => format!("Render scale: {:.2}", cfg.borrow().render_scale),
+            Some(Ok(value)) if value.is_finite() => {
+                let applied = cfg.borrow_mut().set_render_scale(value);
+                format_clamped("Render scale", value, applied)
+            }
+            Some(_) => format!("Usage: renderer.scale <f>  ({:.2}..{:.1})", min, max),
+        }
+    });
+
+    // "renderer.vsync <on|off>" : synchronisation verticale
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.vsync", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().vsync,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.vsync <on|off>".to_string(),
+        };
+        cfg.borrow_mut().vsync = enabled;
+        format!("V-sync: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.fps_cap <n|off>" : plafond d'images/s (effectif sans v-sync)
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.fps_cap", move |args| {
+        let max_fps = match args.split_whitespace().nth(1) {
+            None => {
+                return match cfg.borrow().max_fps {
+                    Some(fps) => format!("FPS cap: {}", fps),
+                    None => "FPS cap: off".to_string(),
+                }
+            }
+            Some("off") => None,
+            Some(value) => match value.parse::<u32>() {
+                Ok(fps) if fps > 0 => Some(fps),
+                _ => return "Usage: renderer.fps_cap <n|off>".to_string(),
+            },
+        };
+        let mut config = cfg.borrow_mut();
+        config.max_fps = max_fps;
+        match max_fps {
+            Some(fps) if config.vsync => format!("FPS cap: {} (inactive while v-sync is on)", fps),
+            Some(fps) => format!("FPS cap: {}", fps),
+            None => "FPS cap: off".to_string(),
+        }
+    });
+
+    // "renderer.hud <on|off>" : overlay de debug (équivalent de F1)
+    let hud = shared.hud_visible.clone();
+    registry.register_for_renderer("renderer.hud", move |args| {
+        let visible = match args.split_whitespace().nth(1) {
+            None => !hud.get(),
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.hud <on|off>".to_string(),
+        };
+        hud.set(visible);
+        format!("HUD: {}", if visible { "on" } else { "off" })
+    });
+
+    // "renderer.stats" : appels de dessin, envois de particules et mémoire GPU estimée
+    let stats = shared.stats.clone();
+    registry.register_for_renderer("renderer.stats", move |_args| stats.borrow().format());
+
+    // "renderer.input.bindings" : raccourcis clavier courants
+    let bindings = shared.key_bindings.clone();
+    registry.register_for_renderer("renderer.input.bindings", move |_args| {
+        bindings.borrow().format()
+    });
+
+    // "renderer.config.reload" : relit renderer.toml (touche F7)
+    let reload_shared = shared.clone();
+    registry.register_for_renderer("renderer.config.reload", move |_args| {
+        match reload_shared.reload_config() {
+            Ok(event) => format!(
+                "Renderer config reloaded from {}: {}",
+                reload_shared.config_path().display(),
+                event.map_or_else(|| format_changes(&[]), |event| event.summary())
+            ),
+            Err(e) => format!("Renderer config not reloaded: {:#}", e),
+        }
+    });
+
+    // "physic.config.reload" : relit physic.toml (touche R)
+    let reload_shared = shared.clone();
+    registry.register_for_physic(
+        "physic.config.reload",
+        move |engine: &mut dyn PhysicEngine, _args| {
+            let path = reload_shared
+                .config_manager
+                .borrow()
+                .path(ConfigSection::Physic)
+                .display()
+                .to_string();
+            let reloaded = reload_shared
+                .config_manager
+                .borrow_mut()
+                .reload(ConfigSection::Physic);
+            match reloaded {
+                Ok(Some(event)) => {
+                    let config = reload_shared.config_manager.borrow().physic_config();
+                    reload_shared
+                        .physic_reload
+                        .set(Some(engine.reload_config(&config)));
+                    format!("Physic config reloaded from {}: {}", path, event.summary())
+                }
+                Ok(None) => format!(
+                    "Physic config reloaded from {}: {}",
+                    path,
+                    format_changes(&[])
+                ),
+                Err(e) => format!("Physic config not reloaded: {:#}", e),
+            }
+        },
+    );
+
+    // "physic.config.save [path]" : écrit la config physique courante (par défaut
+    // dans le fichier d'où elle a été chargée)
+    let save_shared = shared.clone();
+    registry.register_for_physic(
+        "physic.config.save",
+        move |engine: &mut dyn PhysicEngine, args| {
+            let path = match args.split_whitespace().nth(1) {
+                Some(path) => PathBuf::from(path),
+                None => save_shared
+                    .config_manager
+                    .borrow()
+                    .path(ConfigSection::Physic)
+                    .to_path_buf(),
+            };
+            let config = engine.get_config();
+            let violations = config.validate();
+            if !violations.is_empty() {
+                return format!(
+                    "Physic config not saved:\n  - {}",
+                    violations.join("\n  - ")
+                );
+            }
+            match config.save_to_file(&path) {
+                Ok(()) => format!("Physic config saved to {}", path.display()),
+                Err(e) => format!("Physic config not saved: {:#}", e),
+            }
+        },
+    );
+
+    // "sim.preset <name>" : applique assets/presets/<name>/ (physique, rendu, audio)
+    let preset_shared = shared.clone();
+    registry.register_for_simulator("sim.preset", move |ctx: &mut SimContext, args| {
+        let Some(name) = args.split_whitespace().nth(1) else {
+            return "Usage: sim.preset <name>".to_string();
+        };
+        let applied = preset_shared.config_manager.borrow_mut().apply_preset(name);
+        let events = match applied {
+            Ok(events) => events,
+            Err(e) => return format!("Preset not applied: {:#}", e),
+        };
+        let mut summaries = Vec::new();
+        for event in &events {
+            match event.section {
+                ConfigSection::Physic => {
+                    let config = preset_shared.config_manager.borrow().physic_config();
+                    preset_shared
+                        .physic_reload
+                        .set(Some(ctx.physic.reload_config(&config)));
+                }
+                ConfigSection::Renderer => preset_shared.apply_manager_config(),
+                ConfigSection::Audio => {}
+            }
+            summaries.push(format!("{}: {}", event.section.name(), event.summary()));
+        }
+        if summaries.is_empty() {
+            summaries.push(format_changes(&[]));
+        }
+        format!("Preset '{}' applied: {}", name, summaries.join("; "))
+    });
+
+    // "sim.preset.save <name>" : fige les configs courantes dans un préréglage
+    let preset_shared = shared.clone();
+    registry.register_for_simulator("sim.preset.save", move |ctx: &mut SimContext, args| {
+        let Some(name) = args.split_whitespace().nth(1) else {
+            return "Usage: sim.preset.save <name>".to_string();
+        };
+        let manager = preset_shared.config_manager.borrow();
+        let renderer = preset_shared.config.borrow();
+        let audio = manager.audio_config();
+        let snapshot = PresetSnapshot {
+            physic: ctx.physic.get_config(),
+            renderer: &renderer,
+            audio: &audio,
+        };
+        match save_preset(manager.presets_dir(), name, snapshot) {
+            Ok(dir) => format!("Preset '{}' saved to {}", name, dir.display()),
+            Err(e) => format!("Preset not saved: {:#}", e),
+        }
+    });
+
+    // "sim.report [path]" : rapport système + configs courantes, en JSON
+    let report_shared = shared.clone();
+    registry.register_for_simulator("sim.report", move |ctx: &mut SimContext, args| {
+        let path = PathBuf::from(args.split_whitespace().nth(1).unwrap_or(BUG_REPORT_PATH));
+        let system = system_report();
+        let renderer = report_shared.config.borrow();
+        let audio = report_shared.config_manager.borrow().audio_config();
+        let report = BugReport {
+            system: &system,
+            configs: PresetSnapshot {
+                physic: ctx.physic.get_config(),
+                renderer: &renderer,
+                audio: &audio,
+            },
+        };
+        match report.write(&path) {
+            Ok(()) => format!("Bug report written to {}", path.display()),
+            Err(e) => format!("Bug report not written: {:#}", e),
+        }
+    });
+
+    // "sim.preset.list" : préréglages disponibles
+    let preset_shared = shared.clone();
+    registry.register_for_simulator("sim.preset.list", move |_ctx: &mut SimContext, _args| {
+        let manager = preset_shared.config_manager.borrow();
+        let names = list_presets(manager.presets_dir());
+        if names.is_empty() {
+            format!("No preset in {}", manager.presets_dir().display())
+        } else {
+            format!("Presets: {}", names.join(", "))
+        }
+    });
+    let names = list_presets(shared.config_manager.borrow().presets_dir());
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    registry.register_args("sim.preset", &[&names]);
+
+    // "renderer.input.reload" : relit assets/config/input.toml
+    let bindings = shared.key_bindings.clone();
+    registry.register_for_renderer("renderer.input.reload", move |_args| {
+        let text = match read_asset_to_string(INPUT_CONFIG_PATH) {
+            Ok(text) => text,
+            Err(e) => return format!("Cannot read {}: {}", INPUT_CONFIG_PATH, e),
+        };
+        match KeyBindings::parse(&text) {
+            Ok((parsed, warnings)) => {
+                *bindings.borrow_mut() = parsed;
+                let mut out = format!("Key bindings reloaded from {}", INPUT_CONFIG_PATH);
+                for warning in warnings {
+                    out.push_str(&format!("\n⚠️ {}", warning));
+                }
+                out
+            }
+            Err(e) => format!("Key bindings not reloaded: {}", e),
+        }
+    });
+
+    // "renderer.window.monitors" : écrans connectés et mode courant
+    let monitors = shared.monitors.clone();
+    let display_mode = shared.display_mode.clone();
+    registry.register_for_renderer("renderer.window.monitors", move |_args| {
+        let monitors = monitors.borrow();
+        format!(
+            "{}\nDisplay: {}",
+            format_monitors(&monitors),
+            display_mode.get().describe(&monitors)
+        )
+    });
+
+    // "renderer.window.fullscreen [index|borderless [index]|off]" : sans argument, bascule (F11)
+    let cfg = shared.config.clone();
+    let monitors = shared.monitors.clone();
+    let request = shared.clone();
+    registry.register_for_renderer("renderer.window.fullscreen", move |args| {
+        let words: Vec<&str> = args.split_whitespace().skip(1).collect();
+        let default_monitor = cfg.borrow().fullscreen_monitor;
+        let Some(change) = FullscreenRequest::parse(&words, default_monitor) else {
+            return "Usage: renderer.window.fullscreen [index|borderless [index]|off]".to_string();
+        };
+        if let FullscreenRequest::Fullscreen(index) | FullscreenRequest::Borderless(index) = change
+        {
+            let count = monitors.borrow().len();
+            if index >= count {
+                return format!("Monitor {} not found ({} connected)", index, count);
+            }
+        }
+        request.request_fullscreen(change);
+        "Display mode change requested".to_string()
+    });
+
+    // "renderer.window.scale" : facteur DPI, tailles fenêtre / framebuffer et échelles appliquées
+    let display_scale = shared.display_scale.clone();
+    registry.register_for_renderer("renderer.window.scale", move |_args| {
+        format_display_scale(&display_scale.get())
+    });
+
+    // "renderer.input.gamepad" : manettes détectées et liaisons
+    let gamepads = shared.gamepads.clone();
+    let bindings = shared.key_bindings.clone();
+    registry.register_for_renderer("renderer.input.gamepad", move |_args| {
+        format_gamepads(&gamepads.borrow(), &bindings.borrow().gamepad)
+    });
+
+    // "renderer.passes" : passes de la dernière frame, ordre d'exécution et durées GPU
+    let passes = shared.passes.clone();
+    registry.register_for_renderer("renderer.passes", move |_args| {
+        format_pass_list(&passes.borrow())
+    });
+
+    // "renderer.particles.sort <on|off>" : tri du fond vers l'avant des types en mélange alpha
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.particles.sort", move |args| {
+        let enabled = match args.split_whitespace().nth(1) {
+            None => !cfg.borrow().depth_sort_enabled,
+            Some("on") => true,
+            Some("off") => false,
+            Some(_) => return "Usage: renderer.particles.sort <on|off>".to_string(),
+        };
+        cfg.borrow_mut().depth_sort_enabled = enabled;
+        format!("Depth sort: {}", if enabled { "on" } else { "off" })
+    });
+
+    // "renderer.particles.texture <type> <path>" : texture d'un type, appliquée à la frame suivante
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.particles.texture", move |args| {
+        let usage = "Usage: renderer.particles.texture <rocket|explosion|smoke|trail> <path>";
+        let mut parts = args.split_whitespace().skip(1);
+        let Some(particle_type) = parts.next().and_then(ParticleType::from_name) else {
+            return usage.to_string();
+        };
+        // Le chemin peut contenir des espaces (ex. "PNG (Black background)")
+        let path = parts.collect::<Vec<_>>().join(" ");
+        if path.is_empty() {
+            let config = cfg.borrow();
+            return format!(
+                "{} texture: {}",
+                particle_type.name(),
+                config
+                    .particles
+                    .get(particle_type)
+                    .texture_path(particle_type)
+            );
+        }
+        if !std::path::Path::new(&path).is_file() {
+            return format!("Texture not found: {}", path);
+        }
+        cfg.borrow_mut().particles.get_mut(particle_type).texture = path.clone();
+        format!("{} texture set to {}", particle_type.name(), path)
+    });
+
+    // "renderer.particles.atlas <type> [path|none]" : atlas de sprites d'un type
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.particles.atlas", move |args| {
+        let usage = "Usage: renderer.particles.atlas <rocket|explosion|smoke|trail> <path|none>";
+        let mut parts = args.split_whitespace().skip(1);
+        let Some(particle_type) = parts.next().and_then(ParticleType::from_name) else {
+            return usage.to_string();
+        };
+        let path = parts.collect::<Vec<_>>().join(" ");
+        match path.as_str() {
+            "" => {
+                let config = cfg.borrow();
+                let atlas = &config.particles.get(particle_type).atlas;
+                format!(
+                    "{} atlas: {}",
+                    particle_type.name(),
+                    if atlas.is_empty() { "none" } else { atlas }
+                )
+            }
+            "none" => {
+                cfg.borrow_mut()
+                    .particles
+                    .get_mut(particle_type)
+                    .atlas
+                    .clear();
+                format!("{} atlas disabled", particle_type.name())
+            }
+            _ => match AtlasDescriptor::load(&path) {
+                Ok(descriptor) => {
+                    cfg.borrow_mut().particles.get_mut(particle_type).atlas = path.clone();
+                    format!(
+                        "{} atlas set to {} ({} sprites)",
+                        particle_type.name(),
+                        path,
+                        descriptor.sprites.len()
+                    )
+                }
+                Err(e) => format!("Invalid atlas: {:#}", e),
+            },
+        }
+    });
+
+    // "renderer.particles.softness <type> [0..1]" : atténuation radiale du bord des sprites
+    let cfg = shared.config.clone();
+    registry.register_for_renderer("renderer.particles.softness", move |args| {
+        let usage = format!(
+            "Usage: renderer.particles.softness <rocket|explosion|smoke|trail> <f>  ({:.1}..{:.1})",
+            SOFTNESS_RANGE.0, SOFTNESS_RANGE.1
+        );
+        let mut parts = args.split_whitespace().skip(1);
+        let Some(particle_type) = parts.next().and_then(ParticleType::from_name) else {
+            return usage;
+        };
+        let label = format!("{} softness", particle_type.name());
+        match parts.next().map(str::parse::<f32>) {
+            None => format!(
+                "{}: {:.2}",
+                label,
+                cfg.borrow().particles.get(particle_type).softness
+            ),
+            Some(Ok(value)) if value.is_finite() => {
+                let applied = cfg
+                    .borrow_mut()
+                    .particles
+                    .get_mut(particle_type)
+                    .set_softness(value);
+                format_clamped(&label, value, applied)
+            }
+            _ => usage,
+        }
+    });
+
+    register_renderer_args(registry);
+    register_renderer_descriptions(registry);
+}
+
+/// Aide des commandes `renderer.*` : (nom, syntaxe, description). Syntaxe vide :
+/// déduite des valeurs d'autocomplétion (ou aucun argument).
+const RENDERER_COMMAND_HELP: &[(&str, &str, &str)] = &[
+    (
+        "renderer.motionblur",
+        "[0..1]",
+        "Show or set the rocket motion blur strength (0 disables it)",
+    ),
+    (
+        "renderer.screenshot",
+        "[--hdr] [path]",
+        "Save a PNG of the current frame (--hdr: OpenEXR before tone mapping)",
+    ),
+    (
+        "renderer.record.start",
+        "[path]",
+        "Start recording the frames to a video through ffmpeg",
+    ),
+    ("renderer.record.stop", "", "Stop the video recording"),
+    (
+        "renderer.camera.zoom",
+        "[factor]",
+        "Show or set the zoom around the view center",
+    ),
+    (
+        "renderer.camera.reset",
+        "",
+        "Reset the camera zoom and position",
+    ),
+    (
+        "renderer.background",
+        "",
+        "Toggle the gradient sky and stars",
+    ),
+    (
+        "renderer.flash",
+        "",
+        "Toggle the light pulse lighting the scene on each detonation",
+    ),
+    ("renderer.bloom", "", "Toggle the glow around bright areas"),
+    (
+        "renderer.bloom.threshold",
+        "",
+        "Luminance from which a pixel contributes to the bloom",
+    ),
+    (
+        "renderer.bloom.knee",
+        "",
+        "Softness of the bloom transition below the threshold",
+    ),
+    (
+        "renderer.bloom.dirt",
+        "",
+        "Strength of the lens dirt revealed by the bloom",
+    ),
+    (
+        "renderer.bloom.audio",
+        "",
+        "Toggle audio-reactive bloom or set its reactivity",
+    ),
+    (
+        "renderer.exposure",
+        "",
+        "Toggle the automatic exposure (eye adaptation)",
+    ),
+    (
+        "renderer.exposure.speed",
+        "[f]",
+        "Show or set the exposure adaptation speed (1/s)",
+    ),
+    (
+        "renderer.exposure.range",
+        "[min max]",
+        "Show or set the exposure bounds",
+    ),
+    ("renderer.fxaa", "", "Toggle the FXAA anti-aliasing pass"),
+    (
+        "renderer.tonemapping",
+        "",
+        "Show or set the HDR tone mapping operator",
+    ),
+    (
+        "renderer.tonemapping.compare",
+        "",
+        "Toggle the side-by-side tone mapping grid",
+    ),
+    (
+        "renderer.tonemapping.compare.modes",
+        "<mode...>",
+        "Operators shown by the comparison grid, in order (2 to 6)",
+    ),
+    (
+        "renderer.tonemapping.compare.save",
+        "[path]",
+        "Save the comparison grid with labels burnt in",
+    ),
+    (
+        "renderer.preset",
+        "",
+        "Apply a quality preset (bloom, blur passes, render scale, FXAA)",
+    ),
+    (
+        "renderer.trails",
+        "",
+        "Draw trails as separate dots or as a tapered ribbon",
+    ),
+    (
+        "renderer.gamma",
+        "[f]",
+        "Show or set the output gamma (1.0: linear output)",
+    ),
+    (
+        "renderer.gamma.compare",
+        "",
+        "Toggle the gamma 1.0 / output gamma comparison grid",
+    ),
+    (
+        "renderer.scale",
+        "[0.25..1]",
+        "Show or set the internal scene resolution",
+    ),
+    ("renderer.vsync", "", "Toggle the vertical synchronisation"),
+    (
+        "renderer.fps_cap",
+        "",
+        "Cap the frame rate when v-sync is off",
+    ),
+    ("renderer.hud", "", "Toggle the debug overlay (F1)"),
+    (
+        "renderer.stats",
+        "",
+        "Draw calls, particle uploads and estimated GPU memory",
+    ),
+    (
+        "renderer.config.reload",
+        "",
+        "Reload the renderer config file (F7)",
+    ),
+    (
+        "physic.config.reload",
+        "",
+        "Reload the physics config file (R)",
+    ),
+    (
+        "sim.preset",
+        "<name>",
+        "Apply a named preset (physics, renderer and audio configs)",
+    ),
+    (
+        "sim.preset.save",
+        "<name>",
+        "Save the current configs as a named preset",
+    ),
+    ("sim.preset.list", "", "List the named presets"),
+    (
+        "sim.report",
+        "[path]",
+        "Write the system report and the current configs as JSON, for bug reports",
+    ),
+    (
+        "physic.config.save",
+        "[path]",
+        "Save the current physics config (default: the file it was loaded from)",
+    ),
+    ("renderer.input.bindings", "", "List the keyboard shortcuts"),
+    (
+        "renderer.input.reload",
+        "",
+        "Reload assets/config/input.toml",
+    ),
+    (
+        "renderer.window.monitors",
+        "",
+        "List the connected monitors and the current display mode",
+    ),
+    (
+        "renderer.window.fullscreen",
+        "[index|borderless [index]|off]",
+        "Toggle or set the fullscreen mode (F11)",
+    ),
+    (
+        "renderer.window.scale",
+        "",
+        "DPI factor, window / framebuffer sizes and applied scales",
+    ),
+    (
+        "renderer.input.gamepad",
+        "",
+        "List the detected gamepads and their bindings",
+    ),
+    (
+        "renderer.passes",
+        "",
+        "Passes of the last frame, execution order and GPU timings",
+    ),
+    (
+        "renderer.particles.sort",
+        "",
+        "Toggle back-to-front sorting of alpha-blended particles",
+    ),
+    (
+        "renderer.particles.texture",
+        "",
+        "Set the sprite texture of a particle type",
+    ),
+    (
+        "renderer.particles.atlas",
+        "",
+        "Show, set or disable (none) the sprite atlas of a particle type",
+    ),
+    (
+        "renderer.particles.softness",
+        "",
+        "Show or set the sprite edge softness of a particle type",
+    ),
+];
+
+/// Configs par défaut (`assets/config`), surcharges `FIREWORKS_*` comprises
+fn default_config_manager() -> ConfigManager {
+    let mut manager = ConfigManager::default().with_env(std::env::vars());
+    if let Err(e) = manager.load() {
+        warn!("⚠️ Default config used: {:#}", e);
+    }
+    manager
+}
+
+fn register_renderer_descriptions(registry: &mut CommandRegistry) {
+    for (name, usage, description) in RENDERER_COMMAND_HELP {
+        if !usage.is_empty() {
+            registry.register_usage(name, usage);
+        }
+        registry.register_description(name, description);
+    }
+}
+
+/// Valeurs proposées par l'autocomplétion de la console, par position d'argument.
+fn register_renderer_args(registry: &mut CommandRegistry) {
+    const ON_OFF: &[&str] = &["on", "off"];
+    for name in [
+        "renderer.background",
+        "renderer.flash",
+        "renderer.bloom",
+        "renderer.bloom.audio",
+        "renderer.exposure",
+        "renderer.fxaa",
+        "renderer.tonemapping.compare",
+        "renderer.gamma.compare",
+        "renderer.vsync",
+        "renderer.hud",
+        "renderer.particles.sort",
+    ] {
+        registry.register_args(name, &[ON_OFF]);
+    }
+    let presets: Vec<&str> = QualityPreset::ALL.iter().map(QualityPreset::name).collect();
+    registry.register_args("renderer.preset", &[&presets]);
+    let styles: Vec<&str> = TrailStyle::ALL.iter().map(TrailStyle::name).collect();
+    registry.register_args("renderer.trails", &[&styles]);
+    registry.register_args("renderer.fps_cap", &[&["off"]]);
+    registry.register_args("renderer.window.fullscreen", &[&["borderless", "off"]]);
+    // Type de particule puis chemin / valeur libres
+    let types: Vec<&str> = ParticleType::ALL.iter().map(ParticleType::name).collect();
+    registry.register_args("renderer.particles.texture", &[&types, &[]]);
+    registry.register_args("renderer.particles.atlas", &[&types, &["none"]]);
+    registry.register_args("renderer.particles.softness", &[&types, &[]]);
+}
+
+/// Noms des opérateurs de tone mapping, pour les messages d'usage
+fn tonemapping_mode_names() -> Vec<&'static str> {
+    ToneMappingMode::ALL
+        .iter()
+        .map(ToneMappingMode::name)
+        .collect()
+}
+
+/// Message de confirmation d'un réglage numérique, signale un éventuel bornage.
+fn format_clamped(label: &str, requested: f32, applied: f32) -> String {
+    if requested == applied {
+        format!("{} set to {:.2}", label, applied)
+    } else {
+        format!("{} set to {:.2} (clamped)", label, applied)
+    }
 }
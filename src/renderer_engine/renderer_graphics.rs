@@ -1,7 +1,14 @@
+use glam::Mat3;
 use log::{debug, info};
 
-use crate::physic_engine::PhysicEngineIterator;
-use crate::renderer_engine::{tools::compile_shader_program, types::ParticleGPU};
+use crate::gl_check;
+use crate::physic_engine::{ParticleType, PhysicEngineIterator};
+use crate::renderer_engine::{
+    config::{RendererConfig, TrailStyle},
+    render_stats::{DrawStats, GpuMemory},
+    shader::{get_or_compile_program, PreprocessedShader},
+    types::ParticleGPU,
+};
 use crate::utils::human_bytes::HumanBytes;
 
 macro_rules! cstr {
@@ -18,17 +25,33 @@ pub struct RendererGraphics {
 
     // Shader
     pub shader_program: u32,
-    pub loc_size: i32,
+    pub loc_view_proj: i32,
+    pub loc_point_scale: i32,
 
     pub max_particles_on_gpu: usize,
+
+    /// Particules écrites lors du dernier remplissage, par type
+    uploaded: [usize; ParticleType::ALL.len()],
+    /// Trails dessinés en ruban par `TrailRibbonRenderer` : ignorés ici
+    skip_trails: bool,
+    /// Échelle d'affichage (DPI) de la taille des points
+    content_scale: f32,
 }
 
 impl RendererGraphics {
     pub fn new(max_particles_on_gpu: usize) -> Self {
         let (vertex_src, fragment_src) = RendererGraphics::src_shaders_particles();
-        let shader_program = unsafe { compile_shader_program(vertex_src, fragment_src) };
+        let shader_program = unsafe {
+            get_or_compile_program(
+                &PreprocessedShader::from_source("particles.vert", vertex_src),
+                &PreprocessedShader::from_source("particles.frag", fragment_src),
+            )
+        }
+        .unwrap_or_else(|e| panic!("{:#}", e));
 
-        let loc_size = unsafe { gl::GetUniformLocation(shader_program, cstr!("uSize")) };
+        let loc_view_proj = unsafe { gl::GetUniformLocation(shader_program, cstr!("uViewProj")) };
+        let loc_point_scale =
+            unsafe { gl::GetUniformLocation(shader_program, cstr!("uPointScale")) };
 
         // VAO/VBO setup
         unsafe {
@@ -40,8 +63,12 @@ impl RendererGraphics {
                 vbo_particles,
                 mapped_ptr,
                 shader_program,
-                loc_size,
+                loc_view_proj,
+                loc_point_scale,
                 max_particles_on_gpu,
+                uploaded: [0; ParticleType::ALL.len()],
+                skip_trails: false,
+                content_scale: 1.0,
             }
         }
     }
@@ -52,22 +79,23 @@ impl RendererGraphics {
         layout(location = 0) in vec4 aPos;
         layout(location = 1) in vec3 aColor;
         layout(location = 2) in vec2 aLifeMaxLife;
+        layout(location = 3) in float aDepthScale;
 
         out vec3 vertexColor;
         out float alpha;
 
-        uniform vec2 uSize;
+        uniform mat3 uViewProj; // monde -> clip space (caméra)
+        uniform float uPointScale; // échelle d'affichage (DPI)
 
         void main() {
             float a = clamp(aLifeMaxLife.x / max(aLifeMaxLife.y, 0.0001), 0.0, 1.0);
-            alpha = a;
+            // Pseudo-3D : les particules lointaines sont plus petites et plus sombres
+            alpha = a * mix(0.35, 1.0, aDepthScale);
             vertexColor = aColor;
 
-            float x = aPos.x / uSize.x * 2.0 - 1.0;
-            float y = aPos.y / uSize.y * 2.0 - 1.0;
-            gl_Position = vec4(x, y, 0.0, 1.0);
+            gl_Position = vec4((uViewProj * vec3(aPos.xy, 1.0)).xy, 0.0, 1.0);
 
-            gl_PointSize = 2.0 + 5.0 * a;
+            gl_PointSize = (2.0 + 5.0 * a) * aDepthScale * uPointScale;
         }
         "#;
 
@@ -126,6 +154,7 @@ impl RendererGraphics {
                 | gl::MAP_COHERENT_BIT
                 | gl::MAP_FLUSH_EXPLICIT_BIT,
         ) as *mut ParticleGPU;
+        gl_check!("persistent particle buffer mapping");
 
         // === Définition des attributs instanciés ===
         ParticleGPU::setup_vertex_attribs();
@@ -176,6 +205,7 @@ impl RendererGraphics {
         physic: &P,
     ) -> usize {
         let mut count = 0;
+        self.uploaded = [0; ParticleType::ALL.len()];
 
         // Slice Rust mutable mappé directement sur la mémoire GPU.
         // Toute écriture dans ce slice écrit physiquement dans la BAR / VRAM.
@@ -183,22 +213,15 @@ impl RendererGraphics {
 
         // Ici, `iter_active_particles()` fournit un flux paresseux, sans allocation CPU
         // intermédiaire : idéal pour écrire contigu dans le buffer GPU.
+        let skip_trails = self.skip_trails;
         for (i, p) in physic
             .iter_active_particles()
+            .filter(|p| !(skip_trails && p.particle_type == ParticleType::Trail))
             .take(self.max_particles_on_gpu)
             .enumerate()
         {
-            gpu_slice[i] = ParticleGPU {
-                pos_x: p.pos.x,
-                pos_y: p.pos.y,
-                col_r: p.color.x,
-                col_g: p.color.y,
-                col_b: p.color.z,
-                life: p.life,
-                max_life: p.max_life,
-                size: p.size,
-                angle: p.angle,
-            };
+            gpu_slice[i] = ParticleGPU::from(p);
+            self.uploaded[p.particle_type as usize] += 1;
             count += 1;
         }
         // Flush explicite de la zone écrite.
@@ -232,11 +255,7 @@ impl RendererGraphics {
     /// manipulent directement des pointeurs mémoire GPU et des ressources système.
     /// Il est de la responsabilité de l’appelant de garantir que le contexte OpenGL
     /// est valide et que les ressources (`VAO`, `VBO`, shader, etc.) sont correctement initialisées.
-    pub unsafe fn render_particles_with_persistent_buffer(
-        &self,
-        count: usize,
-        window_size: (f32, f32),
-    ) {
+    pub unsafe fn render_particles_with_persistent_buffer(&self, count: usize, view_proj: &Mat3) {
         // Si aucune particule, on ne fait rien
         if count == 0 {
             return;
@@ -245,8 +264,14 @@ impl RendererGraphics {
         // Active le shader de rendu des particules
         gl::UseProgram(self.shader_program);
 
-        // Envoie les dimensions de la fenêtre au shader (uniforms)
-        gl::Uniform2f(self.loc_size, window_size.0, window_size.1);
+        // Envoie la transformation de la caméra au shader (uniforms)
+        gl::UniformMatrix3fv(
+            self.loc_view_proj,
+            1,
+            gl::FALSE,
+            view_proj.as_ref().as_ptr(),
+        );
+        gl::Uniform1f(self.loc_point_scale, self.content_scale);
 
         // Lie le VAO et VBO correspondant aux particules
         gl::BindVertexArray(self.vao);
@@ -254,6 +279,7 @@ impl RendererGraphics {
         gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo_particles);
         // Dessine les particules sous forme de points
         gl::DrawArrays(gl::POINTS, 0, count as i32);
+        gl_check!("particles draw");
     }
 
     /// Libère les ressources GPU associées à ce RendererGraphics.
@@ -288,12 +314,33 @@ impl ParticleGraphicsRenderer for RendererGraphics {
         self.fill_particle_data_direct(physic)
     }
 
-    unsafe fn render_particles_with_persistent_buffer(
-        &self,
-        count: usize,
-        window_size: (f32, f32),
-    ) {
-        self.render_particles_with_persistent_buffer(count, window_size);
+    unsafe fn render_particles_with_persistent_buffer(&self, count: usize, view_proj: &Mat3) {
+        self.render_particles_with_persistent_buffer(count, view_proj);
+    }
+
+    fn draw_stats(&self) -> DrawStats {
+        let count: usize = self.uploaded.iter().sum();
+        DrawStats {
+            particles: self.uploaded,
+            bytes_uploaded: (count * std::mem::size_of::<ParticleGPU>()) as u64,
+            draw_calls: usize::from(count > 0),
+            instanced_draw_calls: 0,
+        }
+    }
+
+    fn gpu_memory(&self) -> GpuMemory {
+        GpuMemory {
+            buffers: (self.max_particles_on_gpu * std::mem::size_of::<ParticleGPU>()) as u64,
+            ..Default::default()
+        }
+    }
+
+    fn apply_config(&mut self, config: &RendererConfig) {
+        self.skip_trails = config.trail_style == TrailStyle::Ribbon;
+    }
+
+    fn set_content_scale(&mut self, scale: f32) {
+        self.content_scale = scale;
     }
 
     unsafe fn close(&mut self) {
@@ -1,26 +1,154 @@
-use log::{debug, info};
+use glam::Mat3;
+use log::{debug, info, warn};
+use std::time::Duration;
 
 use crate::cstr;
+use crate::gl_check;
 use crate::physic_engine::{ParticleType, PhysicEngineIterator};
 use crate::renderer_engine::{
-    tools::compile_shader_program, types::ParticleGPU, utils::texture::load_texture,
+    config::{BlendMode, ParticleRenderSettings},
+    flash::{with_flash_glsl, FlashUniformLocations, FlashUniforms},
+    render_stats::{DrawStats, GpuMemory},
+    shader::{get_or_compile_program, PreprocessedShader},
+    types::ParticleGPU,
+    utils::{
+        atlas::{AtlasDescriptor, UvRect, MAX_ATLAS_SPRITES},
+        depth_sort::DepthSorter,
+        fence_ring::{FenceRing, BUFFER_REGIONS},
+        texture::{try_load_texture, TextureSlot},
+    },
 };
 use crate::utils::human_bytes::HumanBytes;
+use crate::utils::memory_stats::register_allocation;
+
+/// Longueur de la traînée des fusées : distance parcourue en ce temps (s)
+pub const ROCKET_STREAK_SECONDS: f32 = 0.04;
+/// Longueur maximale de la traînée (px, au premier plan)
+pub const ROCKET_STREAK_MAX_LENGTH: f32 = 60.0;
+/// Surbrillance de la tête de fusée (> 1 : alimente le bloom)
+pub const ROCKET_STREAK_BOOST: f32 = 2.5;
+
+/// Quad unité (triangle strip) partagé par toutes les instances
+const QUAD_VERTICES: [f32; 8] = [
+    -1.0, -1.0, // bottom-left
+    1.0, -1.0, // bottom-right
+    -1.0, 1.0, // top-left
+    1.0, 1.0, // top-right
+];
+
+/// Opacité au rayon `radius` (0 : centre, 1 : bord du sprite) pour une atténuation
+/// `softness`, identique au calcul du shader (`1 - smoothstep(1 - softness, 1, r)`).
+pub fn soft_falloff(radius: f32, softness: f32) -> f32 {
+    if softness <= 0.0 {
+        return 1.0;
+    }
+    let t = ((radius - (1.0 - softness)) / softness).clamp(0.0, 1.0);
+    1.0 - t * t * (3.0 - 2.0 * t)
+}
+
+/// Emplacements des uniformes du shader instancié, relevés une seule fois à la
+/// création du programme (le rechargement des réglages ne les interroge pas).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstancedUniforms {
+    pub view_proj: i32,
+    pub texture: i32,
+    pub motion_blur: i32,
+    pub tex_ratio: i32,
+    pub size_scale: i32,
+    pub brightness: i32,
+    pub softness: i32,
+    pub sprite_count: i32,
+    pub sprite_rects: i32,
+}
+
+impl InstancedUniforms {
+    /// Noms GLSL (terminés par `\0`), dans l'ordre des champs
+    pub const NAMES: [&'static str; 9] = [
+        "uViewProj\0",
+        "uTexture\0",
+        "uMotionBlur\0",
+        "uTexRatio\0",
+        "uSizeScale\0",
+        "uBrightness\0",
+        "uSoftness\0",
+        "uSpriteCount\0",
+        "uSpriteRects\0",
+    ];
+
+    /// Relève chaque emplacement via `lookup` (un appel par uniforme).
+    pub fn locate(mut lookup: impl FnMut(&'static str) -> i32) -> Self {
+        let [view_proj, texture, motion_blur, tex_ratio, size_scale, brightness, softness, sprite_count, sprite_rects] =
+            Self::NAMES.map(&mut lookup);
+        Self {
+            view_proj,
+            texture,
+            motion_blur,
+            tex_ratio,
+            size_scale,
+            brightness,
+            softness,
+            sprite_count,
+            sprite_rects,
+        }
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn from_program(program: u32) -> Self {
+        Self::locate(|name| gl::GetUniformLocation(program, name.as_ptr() as *const i8))
+    }
+}
+
+/// Longueur (px) de la traînée d'une fusée, identique au calcul du shader `ROCKET_STREAK`.
+pub fn rocket_streak_length(speed: f32, depth_scale: f32) -> f32 {
+    (speed * ROCKET_STREAK_SECONDS).clamp(0.0, ROCKET_STREAK_MAX_LENGTH) * depth_scale
+}
+
+/// Label du VBO persistant d'un type de particules dans le suivi mémoire
+fn vbo_label(particle_type: ParticleType) -> String {
+    format!("gpu: {} particles VBO", particle_type.name())
+}
 
 pub struct RendererGraphicsInstanced {
     vao: u32,
     vbo_particles: u32,
     vbo_quad: u32,
 
+    /// Début du buffer mappé : `BUFFER_REGIONS` régions de `max_particles_on_gpu` particules
     mapped_ptr: *mut ParticleGPU,
+    /// Région écrite cette frame ; les autres peuvent encore être lues par le GPU
+    regions: FenceRing,
 
     shader_program: u32,
-    // Shader
-    loc_size: i32,
-    loc_tex: i32,
-    texture_id: u32,
+    uniforms: InstancedUniforms,
+    flash_uniforms: FlashUniformLocations,
+    /// Éclairs de détonation de la frame (cf. `set_flashes`)
+    flashes: FlashUniforms,
+    texture: TextureSlot,
+    /// Dernière texture demandée dont le chargement a échoué (pas de nouvel essai par frame)
+    failed_texture: Option<String>,
+    /// Descripteur de l'atlas chargé (vide : texture seule)
+    atlas: String,
+    /// Rectangles UV des sprites de l'atlas (vide : texture entière)
+    sprite_rects: Vec<UvRect>,
+    /// Dernier atlas demandé dont le chargement a échoué
+    failed_atlas: Option<String>,
+
+    blend: BlendMode,
+    depth_sort: bool,
+    sorter: DepthSorter,
+    size_scale: f32,
+    /// Échelle d'affichage (DPI), multipliée à `size_scale`
+    content_scale: f32,
+    brightness: f32,
+    softness: f32,
+
+    /// Intensité du flou de mouvement (0.0 = désactivé)
+    motion_blur: f32,
 
     max_particles_on_gpu: usize,
+    /// Particules écrites lors du dernier remplissage
+    uploaded: usize,
 
     // Configuration du type de particule
     particle_type: ParticleType,
@@ -30,41 +158,113 @@ impl RendererGraphicsInstanced {
     pub fn new(
         max_particles_on_gpu: usize,
         particle_type: ParticleType,
-        texture_path: &str,
+        settings: &ParticleRenderSettings,
+        streak: bool,
     ) -> Self {
         let (vertex_src, fragment_src) = RendererGraphicsInstanced::src_shaders_instanced_quads();
-        let shader_program = unsafe { compile_shader_program(vertex_src, fragment_src) };
-
-        let loc_size = unsafe { gl::GetUniformLocation(shader_program, cstr!("uSize")) };
-        let loc_tex = unsafe { gl::GetUniformLocation(shader_program, cstr!("uTexture")) };
-
-        let (texture_id, tex_width, tex_height) = load_texture(texture_path);
-        unsafe {
-            gl::UseProgram(shader_program);
-            gl::Uniform1f(
-                gl::GetUniformLocation(shader_program, cstr!("uTexRatio")),
-                tex_width as f32 / tex_height as f32,
-            );
+        let vertex_src = vertex_src.replacen(
+            "#version 330 core",
+            &format!(
+                "#version 330 core\n#define MAX_ATLAS_SPRITES {}",
+                MAX_ATLAS_SPRITES
+            ),
+            1,
+        );
+        // Variante traînée de comète (têtes de fusée)
+        let (vertex_name, vertex_src) = if streak {
+            (
+                "instanced_quads_streak.vert",
+                vertex_src.replacen(
+                    "#version 330 core",
+                    "#version 330 core\n#define ROCKET_STREAK",
+                    1,
+                ),
+            )
+        } else {
+            ("instanced_quads.vert", vertex_src)
+        };
+        let shader_program = unsafe {
+            get_or_compile_program(
+                &PreprocessedShader::from_source(vertex_name, &vertex_src),
+                &PreprocessedShader::from_source(
+                    "instanced_quads.frag",
+                    &with_flash_glsl(fragment_src),
+                ),
+            )
         }
+        .unwrap_or_else(|e| panic!("{:#}", e));
+        if streak {
+            unsafe {
+                gl::UseProgram(shader_program);
+                gl::Uniform1f(
+                    gl::GetUniformLocation(shader_program, cstr!("uStreakSeconds")),
+                    ROCKET_STREAK_SECONDS,
+                );
+                gl::Uniform1f(
+                    gl::GetUniformLocation(shader_program, cstr!("uStreakMaxLength")),
+                    ROCKET_STREAK_MAX_LENGTH,
+                );
+                gl::Uniform1f(
+                    gl::GetUniformLocation(shader_program, cstr!("uStreakBoost")),
+                    ROCKET_STREAK_BOOST,
+                );
+            }
+        }
+
+        let uniforms = unsafe { InstancedUniforms::from_program(shader_program) };
+        let flash_uniforms = unsafe { FlashUniformLocations::from_program(shader_program) };
+
+        // Texture configurée, repli sur celle du type si elle est illisible
+        let texture_path = settings.texture_path(particle_type);
+        let (texture, failed_texture) = match try_load_texture(texture_path) {
+            Ok((id, w, h)) => (TextureSlot::new(id, texture_path, w, h), None),
+            Err(e) => {
+                warn!("⚠️ {:#}, using the default texture", e);
+                let fallback = particle_type.default_texture_path();
+                let (id, w, h) = try_load_texture(fallback).expect("Failed to load texture");
+                (
+                    TextureSlot::new(id, fallback, w, h),
+                    Some(texture_path.to_string()),
+                )
+            }
+        };
 
         // VAO/VBO setup
-        unsafe {
-            let (vao, vbo_quad, vbo_particles, mapped_ptr, _buffer_size) =
+        let mut renderer = unsafe {
+            let (vao, vbo_quad, vbo_particles, mapped_ptr, buffer_size) =
                 RendererGraphicsInstanced::setup_gpu_buffers(max_particles_on_gpu);
+            register_allocation(vbo_label(particle_type), buffer_size as u64);
 
             Self {
                 vao,
                 vbo_particles,
                 vbo_quad,
                 mapped_ptr,
+                regions: FenceRing::new(BUFFER_REGIONS),
                 shader_program,
-                loc_size,
-                loc_tex,
-                texture_id,
+                uniforms,
+                flash_uniforms,
+                flashes: FlashUniforms::default(),
+                texture,
+                failed_texture,
+                atlas: String::new(),
+                sprite_rects: Vec::new(),
+                failed_atlas: None,
+                blend: settings.blend,
+                depth_sort: false,
+                sorter: DepthSorter::default(),
+                size_scale: settings.size_scale,
+                content_scale: 1.0,
+                brightness: settings.brightness,
+                softness: settings.softness,
+                motion_blur: 0.0,
                 max_particles_on_gpu,
+                uploaded: 0,
                 particle_type,
             }
-        }
+        };
+        renderer.apply_atlas(&settings.atlas);
+        renderer
     }
     /// Recrée les buffers GPU avec une nouvelle taille maximale.
     /// Cette opération libère les anciens buffers et en crée de nouveaux,
@@ -75,13 +275,16 @@ impl RendererGraphicsInstanced {
     /// L'appelant doit s'assurer que le contexte OpenGL est valide.
     pub unsafe fn recreate_buffers(&mut self, new_max: usize) {
         // 1. Libérer les anciens buffers
+        self.regions.delete();
+        self.regions = FenceRing::new(BUFFER_REGIONS);
         gl::DeleteVertexArrays(1, &self.vao);
         gl::DeleteBuffers(1, &self.vbo_particles);
         gl::DeleteBuffers(1, &self.vbo_quad);
 
         // 2. Recréer avec la nouvelle taille
-        let (vao, vbo_quad, vbo_particles, mapped_ptr, _buffer_size) =
+        let (vao, vbo_quad, vbo_particles, mapped_ptr, buffer_size) =
             RendererGraphicsInstanced::setup_gpu_buffers(new_max);
+        register_allocation(vbo_label(self.particle_type), buffer_size as u64);
 
         // 3. Mettre à jour les champs
         self.vao = vao;
@@ -91,6 +294,106 @@ impl RendererGraphicsInstanced {
         self.max_particles_on_gpu = new_max;
     }
 
+    /// Remplace la texture des sprites ; l'ancienne est libérée.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn set_texture(&mut self, path: &str) -> anyhow::Result<()> {
+        let (id, width, height) = try_load_texture(path)?;
+        if let Some(old) = self.texture.replace(id, path, width, height) {
+            gl::DeleteTextures(1, &old);
+        }
+        info!(
+            "🖼️ {} texture: {} ({}x{})",
+            self.particle_type.name(),
+            path,
+            width,
+            height
+        );
+        Ok(())
+    }
+
+    /// Texture actuellement utilisée.
+    pub fn texture(&self) -> &TextureSlot {
+        &self.texture
+    }
+
+    /// Rectangles UV des sprites de l'atlas chargé (vide : texture entière).
+    pub fn sprite_rects(&self) -> &[UvRect] {
+        &self.sprite_rects
+    }
+
+    /// Charge l'atlas `path` : sa texture remplace la texture courante.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn set_atlas(&mut self, path: &str) -> anyhow::Result<()> {
+        let descriptor = AtlasDescriptor::load(path)?;
+        let (id, width, height) = try_load_texture(&descriptor.texture)?;
+        let rects = match descriptor.uv_rects(width, height) {
+            Ok(rects) => rects,
+            Err(e) => {
+                gl::DeleteTextures(1, &id);
+                return Err(e.context(format!("In {}", path)));
+            }
+        };
+        if let Some(old) = self.texture.replace(id, &descriptor.texture, width, height) {
+            gl::DeleteTextures(1, &old);
+        }
+        info!(
+            "🖼️ {} atlas: {} ({} sprites in {})",
+            self.particle_type.name(),
+            path,
+            rects.len(),
+            descriptor.texture
+        );
+        self.atlas = path.to_string();
+        self.sprite_rects = rects;
+        Ok(())
+    }
+
+    /// Suit le réglage `atlas` ; un atlas vide ou illisible rend la main à la texture seule.
+    fn apply_atlas(&mut self, path: &str) {
+        if path == self.atlas || self.failed_atlas.as_deref() == Some(path) {
+            return;
+        }
+        self.atlas.clear();
+        self.sprite_rects.clear();
+        if path.is_empty() {
+            return;
+        }
+        match unsafe { self.set_atlas(path) } {
+            Ok(()) => self.failed_atlas = None,
+            Err(e) => {
+                warn!("⚠️ {:#}, using a single texture", e);
+                self.failed_atlas = Some(path.to_string());
+            }
+        }
+    }
+
+    /// Applique les réglages du type ; change de texture si le chemin a changé.
+    pub fn apply_settings(&mut self, settings: &ParticleRenderSettings) {
+        self.blend = settings.blend;
+        self.size_scale = settings.size_scale;
+        self.brightness = settings.brightness;
+        self.softness = settings.softness;
+
+        self.apply_atlas(&settings.atlas);
+        if !self.atlas.is_empty() {
+            return;
+        }
+        let path = settings.texture_path(self.particle_type);
+        if path != self.texture.path && self.failed_texture.as_deref() != Some(path) {
+            match unsafe { self.set_texture(path) } {
+                Ok(()) => self.failed_texture = None,
+                Err(e) => {
+                    warn!("⚠️ {:#}, keeping {}", e, self.texture.path);
+                    self.failed_texture = Some(path.to_string());
+                }
+            }
+        }
+    }
+
     /// Remplit directement le buffer GPU mappé avec les particules du type spécifié.
     ///
     /// Cette fonction :
@@ -101,6 +404,10 @@ impl RendererGraphicsInstanced {
     ///
     /// C'est un pattern AZDO performant : aucune écriture sparse, aucun saut mémoire,
     /// seulement du contigu cpu → gpu.
+    ///
+    /// Triple buffering : l'écriture se fait dans la région suivante, après avoir
+    /// attendu la fence posée lors de son dernier dessin (pas d'écriture pendant
+    /// une lecture GPU).
     /// # Safety
     /// This function is unsafe because it directly manipulates GPU resources.
     /// The caller must ensure that the OpenGL context is valid.
@@ -110,27 +417,44 @@ impl RendererGraphicsInstanced {
     ) -> usize {
         let mut count = 0;
 
+        // Compteurs du moteur : rien à écrire (ni fence à attendre) sans particule,
+        // et la boucle de remplissage est bornée d'avance
+        let expected = physic
+            .count_particles_by_type(self.particle_type)
+            .min(self.max_particles_on_gpu);
+        if expected == 0 {
+            self.sorter.skip();
+            self.uploaded = 0;
+            return 0;
+        }
+
+        self.regions.acquire_next();
+        let region_start = self.regions.ring().offset(self.max_particles_on_gpu);
+
         // Slice Rust mutable mappé directement sur la mémoire GPU.
         // Toute écriture dans ce slice écrit physiquement dans la BAR / VRAM.
-        let gpu_slice = std::slice::from_raw_parts_mut(self.mapped_ptr, self.max_particles_on_gpu);
+        let gpu_slice = std::slice::from_raw_parts_mut(
+            self.mapped_ptr.add(region_start),
+            self.max_particles_on_gpu,
+        );
+
+        // Mélange alpha : ordre du fond vers l'avant
+        if self.depth_sort {
+            self.uploaded = self.sorter.fill_sorted(
+                physic.iter_particles_by_type(self.particle_type),
+                &mut gpu_slice[..expected],
+            );
+            return self.uploaded;
+        }
+        self.sorter.skip();
 
         // Utilise iter_particles_by_type pour filtrer les particules du bon type
         for (i, p) in physic
             .iter_particles_by_type(self.particle_type)
-            .take(self.max_particles_on_gpu)
+            .take(expected)
             .enumerate()
         {
-            gpu_slice[i] = ParticleGPU {
-                pos_x: p.pos.x,
-                pos_y: p.pos.y,
-                col_r: p.color.x,
-                col_g: p.color.y,
-                col_b: p.color.z,
-                life: p.life,
-                max_life: p.max_life,
-                size: p.size,
-                angle: p.angle,
-            };
+            gpu_slice[i] = ParticleGPU::from(p);
             count += 1;
         }
 
@@ -139,6 +463,7 @@ impl RendererGraphicsInstanced {
         // let written_bytes = (count * std::mem::size_of::<ParticleGPU>()) as isize;
         // gl::FlushMappedBufferRange(gl::ARRAY_BUFFER, 0, written_bytes);
 
+        self.uploaded = count;
         count
     }
 
@@ -165,11 +490,7 @@ impl RendererGraphicsInstanced {
     /// manipulent directement des pointeurs mémoire GPU et des ressources système.
     /// Il est de la responsabilité de l’appelant de garantir que le contexte OpenGL
     /// est valide et que les ressources (`VAO`, `VBO`, shader, etc.) sont correctement initialisées.
-    pub unsafe fn render_particles_with_persistent_buffer(
-        &self,
-        count: usize,
-        window_size: (f32, f32),
-    ) {
+    pub unsafe fn render_particles_with_persistent_buffer(&self, count: usize, view_proj: &Mat3) {
         // Si aucune particule, on ne fait rien
         if count == 0 {
             return;
@@ -178,18 +499,67 @@ impl RendererGraphicsInstanced {
         // Active le shader de rendu des particules
         gl::UseProgram(self.shader_program);
 
-        // Envoie les dimensions de la fenêtre au shader (uniforms)
-        gl::Uniform2f(self.loc_size, window_size.0, window_size.1);
+        // Envoie la transformation de la caméra au shader (uniforms)
+        gl::UniformMatrix3fv(
+            self.uniforms.view_proj,
+            1,
+            gl::FALSE,
+            view_proj.as_ref().as_ptr(),
+        );
+        gl::Uniform1f(self.uniforms.motion_blur, self.motion_blur);
+        gl::Uniform1f(self.uniforms.tex_ratio, self.texture.aspect_ratio);
+        gl::Uniform1f(
+            self.uniforms.size_scale,
+            self.size_scale * self.content_scale,
+        );
+        gl::Uniform1f(self.uniforms.brightness, self.brightness);
+        gl::Uniform1f(self.uniforms.softness, self.softness);
+        gl::Uniform1i(self.uniforms.sprite_count, self.sprite_rects.len() as i32);
+        if !self.sprite_rects.is_empty() {
+            let rects: Vec<[f32; 4]> = self.sprite_rects.iter().map(UvRect::to_array).collect();
+            gl::Uniform4fv(
+                self.uniforms.sprite_rects,
+                rects.len() as i32,
+                rects.as_ptr() as *const f32,
+            );
+        }
+        self.flash_uniforms.upload(&self.flashes);
 
         // Lie le VAO et VBO correspondant aux particules
         gl::BindVertexArray(self.vao);
 
+        // Attributs instanciés pointant sur la région écrite cette frame
+        gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo_particles);
+        ParticleGPU::setup_vertex_attribs_for_instanced_quad_at(
+            self.regions.ring().offset(self.max_particles_on_gpu)
+                * std::mem::size_of::<ParticleGPU>(),
+        );
+
         gl::ActiveTexture(gl::TEXTURE0);
-        gl::BindTexture(gl::TEXTURE_2D, self.texture_id);
-        gl::Uniform1i(self.loc_tex, 0);
+        gl::BindTexture(gl::TEXTURE_2D, self.texture.id);
+        gl::Uniform1i(self.uniforms.texture, 0);
         //
         gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo_quad);
+        if self.blend == BlendMode::Additive {
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE);
+        }
         gl::DrawArraysInstanced(gl::TRIANGLE_STRIP, 0, 4, count as i32);
+        gl_check!("particles draw");
+        // Mélange par défaut du renderer pour les passes suivantes
+        gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+
+        // La région ne sera réécrite qu'une fois ce dessin terminé
+        self.regions.fence_current();
+    }
+
+    /// Temps passé à attendre le GPU avant la dernière écriture du buffer.
+    pub fn sync_wait(&self) -> Duration {
+        self.regions.last_wait()
+    }
+
+    /// Nombre total d'écritures ayant dû attendre le GPU.
+    pub fn sync_stalls(&self) -> usize {
+        self.regions.stalls()
     }
 
     /// Libère les ressources GPU associées à ce RendererGraphics.
@@ -198,6 +568,11 @@ impl RendererGraphicsInstanced {
     /// Cette fonction est unsafe car elle manipule directement des ressources OpenGL.
     /// L'appelant doit s'assurer que le contexte OpenGL est valide.
     pub unsafe fn close(&mut self) {
+        self.regions.delete();
+        if self.texture.id != 0 {
+            gl::DeleteTextures(1, &self.texture.id);
+            self.texture.id = 0;
+        }
         if self.vbo_particles != 0 {
             gl::DeleteBuffers(1, &self.vbo_particles);
             self.vbo_particles = 0;
@@ -228,19 +603,42 @@ impl RendererGraphicsInstanced {
         layout(location = 1) in vec2 aPos;
         layout(location = 2) in vec3 aColor;
         layout(location = 3) in vec4 aLifeMaxLifeSizeAngle;
+        layout(location = 4) in float aDepthScale;
+        layout(location = 5) in vec2 aVel;
+        layout(location = 6) in float aSprite;
 
         out vec3 vColor;
         out float vAlpha;
-        out vec2 vUV;
+        out vec2 vUV;      // coordonnées dans la texture (rectangle du sprite)
+        out vec2 vQuadUV;  // coordonnées dans le quad (0..1)
+        out vec2 vWorldPos;
 
-        uniform vec2 uSize;
+        uniform mat3 uViewProj; // monde -> clip space (caméra)
         uniform float uTexRatio;
-
-        mat3 build_world_matrix(float size, float angle) {
+        uniform float uSizeScale; // multiplicateur de taille du type (× atténuation de profondeur)
+        uniform float uBrightness; // multiplicateur de luminosité du type
+        // Flou de mouvement : fraction du déplacement d'une frame (à 60 FPS)
+        // ajoutée derrière la particule
+        uniform float uMotionBlur;
+        const float REFERENCE_FRAME_TIME = 1.0 / 60.0;
+        // Atlas : rectangles (offset.xy, scale.zw) des sprites, 0 = texture entière
+        // (cf. `sprite_rect`)
+        uniform int uSpriteCount;
+        uniform vec4 uSpriteRects[MAX_ATLAS_SPRITES];
+
+        #ifdef ROCKET_STREAK
+        // Traînée de comète : quad aligné sur la vitesse, étiré vers l'arrière
+        // (cf. `rocket_streak_length`)
+        uniform float uStreakSeconds;
+        uniform float uStreakMaxLength;
+        uniform float uStreakBoost;
+        #endif
+
+        mat3 build_world_matrix(float size, float angle, float ratio) {
             // Position du sommet quad dans l'espace clip (avec taille)
-            float scale = size * (2.0 + 5.0 * vAlpha);
+            float scale = size * (2.0 + 5.0 * vAlpha) * aDepthScale * uSizeScale;
             
-            float sx = scale * uTexRatio;
+            float sx = scale * ratio;
             float sy = scale * 1.0;            
 
             mat3 mat_scale = mat3(
@@ -274,18 +672,48 @@ impl RendererGraphicsInstanced {
 
             // Ratio de vie (comme avant)
             vAlpha = clamp(life / max(max_life, 0.0001), 0.0, 1.0);
-            vColor = aColor;
+            // Pseudo-3D : atténuation de la luminosité avec la profondeur
+            vColor = aColor * mix(0.35, 1.0, aDepthScale) * uBrightness;
 
             // On reconstruit les coordonnées UV du quad (-1.0 → -1.0) -> (0.0, 0.0)
-            vUV = aQuad * 0.5 + 0.5;            
+            vQuadUV = aQuad * 0.5 + 0.5;
+            // Sprite de la particule dans l'atlas (indice ramené modulo)
+            vec4 rect = vec4(0.0, 0.0, 1.0, 1.0);
+            if (uSpriteCount > 0) {
+                rect = uSpriteRects[int(aSprite) % uSpriteCount];
+            }
+            vUV = rect.xy + vQuadUV * rect.zw;
+            float ratio = uTexRatio * rect.z / rect.w;
         
-            mat3 mat_model = build_world_matrix(size, angle);
+        #ifdef ROCKET_STREAK
+            float speed = length(aVel);
+            vec2 dir = speed > 0.0 ? aVel / speed : vec2(0.0, 1.0);
+            vec2 side = vec2(-dir.y, dir.x);
+            float width = size * (2.0 + 5.0 * vAlpha) * aDepthScale * uSizeScale;
+            float streak = clamp(speed * uStreakSeconds, 0.0, uStreakMaxLength) * aDepthScale;
+            // aQuad.y = +1 : tête ; -1 : queue repoussée le long de -vitesse
+            float along = aQuad.y > 0.0 ? width : -(width + streak);
+            vec2 world_pos = aPos + side * (aQuad.x * width) + dir * along;
+            // Tête surexposée : les valeurs > 1 nourrissent le bloom
+            vColor *= uStreakBoost;
+        #else
+            mat3 mat_model = build_world_matrix(size, angle, ratio);
             vec2 world_pos = (mat_model * vec3(aQuad, 1.0)).xy;
 
+            // Flou de mouvement : les sommets arrière du quad sont repoussés
+            // le long de -vitesse, le sprite s'étire en traînée.
+            float speed = length(aVel);
+            if (uMotionBlur > 0.0 && speed > 0.0) {
+                vec2 dir = aVel / speed;
+                if (dot(world_pos - aPos, dir) < 0.0) {
+                    world_pos -= dir * speed * REFERENCE_FRAME_TIME * uMotionBlur;
+                }
+            }
+        #endif
+
+            vWorldPos = world_pos;
             // Clip space
-            float x = world_pos.x / uSize.x * 2.0 - 1.0;
-            float y = world_pos.y / uSize.y * 2.0 - 1.0;
-            gl_Position = vec4(x, y, 0.0, 1.0);
+            gl_Position = vec4((uViewProj * vec3(world_pos, 1.0)).xy, 0.0, 1.0);
         }        
         "#;
 
@@ -295,14 +723,25 @@ impl RendererGraphicsInstanced {
         in vec3 vColor;
         in float vAlpha;
         in vec2 vUV;
+        in vec2 vQuadUV;
+        in vec2 vWorldPos;
 
         out vec4 FragColor;
 
         uniform sampler2D uTexture;
+        // Atténuation radiale du bord (0 : sprite brut), cf. `soft_falloff`
+        uniform float uSoftness;
 
         void main() {
             if (vAlpha <= 0.0) discard;
-            FragColor = vec4(vColor, vAlpha) * texture(uTexture, vUV);
+            float falloff = 1.0;
+            if (uSoftness > 0.0) {
+                float r = length(vQuadUV * 2.0 - 1.0);
+                falloff = 1.0 - smoothstep(1.0 - uSoftness, 1.0, r);
+            }
+            // Éclairs de détonation : la fumée et les étincelles voisines s'illuminent
+            vec3 color = vColor * (1.0 + flash_light(vWorldPos));
+            FragColor = vec4(color, vAlpha * falloff) * texture(uTexture, vUV);
         }
         "#;
         (vertex_src, fragment_src)
@@ -318,13 +757,6 @@ impl RendererGraphicsInstanced {
         gl::BindVertexArray(vao);
 
         // === 1️⃣ QuadVertexAttribPointer unité statique ===
-        const QUAD_VERTICES: [f32; 8] = [
-            -1.0, -1.0, // bottom-left
-            1.0, -1.0, // bottom-right
-            -1.0, 1.0, // top-left
-            1.0, 1.0, // top-right
-        ];
-
         gl::GenBuffers(1, &mut vbo_quad);
         gl::BindBuffer(gl::ARRAY_BUFFER, vbo_quad);
         gl::BufferData(
@@ -350,10 +782,12 @@ impl RendererGraphicsInstanced {
         gl::GenBuffers(1, &mut vbo_particles);
         gl::BindBuffer(gl::ARRAY_BUFFER, vbo_particles);
 
-        let buffer_size = (max_particles_on_gpu * std::mem::size_of::<ParticleGPU>()) as isize;
+        let buffer_size =
+            (BUFFER_REGIONS * max_particles_on_gpu * std::mem::size_of::<ParticleGPU>()) as isize;
         info!(
-            "🎮 Allocating instanced particle buffer: {} particles → {}",
+            "🎮 Allocating instanced particle buffer: {} particles × {} regions → {}",
             max_particles_on_gpu,
+            BUFFER_REGIONS,
             buffer_size.human_bytes()
         );
 
@@ -375,6 +809,7 @@ impl RendererGraphicsInstanced {
                 | gl::MAP_COHERENT_BIT
                 | gl::MAP_FLUSH_EXPLICIT_BIT,
         ) as *mut ParticleGPU;
+        gl_check!("persistent particle buffer mapping");
 
         // === Définition des attributs instanciés ===
         ParticleGPU::setup_vertex_attribs_for_instanced_quad();
@@ -384,6 +819,7 @@ impl RendererGraphicsInstanced {
         (vao, vbo_quad, vbo_particles, mapped_ptr, buffer_size)
     }
 }
+use crate::renderer_engine::config::RendererConfig;
 use crate::renderer_engine::particle_renderer::ParticleGraphicsRenderer;
 
 impl ParticleGraphicsRenderer for RendererGraphicsInstanced {
@@ -395,12 +831,52 @@ impl ParticleGraphicsRenderer for RendererGraphicsInstanced {
         self.fill_particle_data_direct(physic)
     }
 
-    unsafe fn render_particles_with_persistent_buffer(
-        &self,
-        count: usize,
-        window_size: (f32, f32),
-    ) {
-        self.render_particles_with_persistent_buffer(count, window_size);
+    unsafe fn render_particles_with_persistent_buffer(&self, count: usize, view_proj: &Mat3) {
+        self.render_particles_with_persistent_buffer(count, view_proj);
+    }
+
+    fn sync_wait(&self) -> Duration {
+        self.sync_wait()
+    }
+
+    fn sort_time(&self) -> Duration {
+        self.sorter.last_duration()
+    }
+
+    fn draw_stats(&self) -> DrawStats {
+        let mut particles = [0; ParticleType::ALL.len()];
+        particles[self.particle_type as usize] = self.uploaded;
+        let draws = usize::from(self.uploaded > 0);
+        DrawStats {
+            particles,
+            bytes_uploaded: (self.uploaded * std::mem::size_of::<ParticleGPU>()) as u64,
+            draw_calls: draws,
+            instanced_draw_calls: draws,
+        }
+    }
+
+    fn gpu_memory(&self) -> GpuMemory {
+        let particles =
+            self.max_particles_on_gpu * BUFFER_REGIONS * std::mem::size_of::<ParticleGPU>();
+        GpuMemory {
+            buffers: (particles + std::mem::size_of_val(&QUAD_VERTICES)) as u64,
+            textures: self.texture.byte_size(),
+            ..Default::default()
+        }
+    }
+
+    fn apply_config(&mut self, config: &RendererConfig) {
+        self.motion_blur = config.effective_motion_blur();
+        self.depth_sort = config.depth_sort_for(self.particle_type);
+        self.apply_settings(config.particles.get(self.particle_type));
+    }
+
+    fn set_content_scale(&mut self, scale: f32) {
+        self.content_scale = scale;
+    }
+
+    fn set_flashes(&mut self, flashes: &FlashUniforms) {
+        self.flashes = *flashes;
     }
 
     unsafe fn close(&mut self) {
@@ -0,0 +1,316 @@
+use bytemuck::{Pod, Zeroable};
+use glam::{Mat3, Vec2};
+use log::debug;
+use std::mem;
+
+use crate::gl_check;
+use crate::physic_engine::{Particle, ParticleType, PhysicEngineIterator};
+use crate::renderer_engine::{
+    config::{RendererConfig, TrailStyle},
+    particle_renderer::ParticleGraphicsRenderer,
+    render_stats::{DrawStats, GpuMemory},
+    shader::{get_or_compile_program, PreprocessedShader},
+    types::depth_to_scale,
+};
+
+macro_rules! cstr {
+    ($s:expr) => {
+        concat!($s, "\0").as_ptr() as *const i8
+    };
+}
+
+/// Sommet du ruban : un bord gauche (`side = -1`) et un bord droit (`side = 1`) par point.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct RibbonVertex {
+    pub pos: [f32; 2],
+    pub color: [f32; 4],
+    pub side: f32,
+}
+
+/// Ajoute à `out` le triangle strip d'une polyligne de trail ordonnée de la queue vers
+/// la tête. La largeur s'effile de `width` (tête) à 0 (queue), l'alpha suit la même
+/// rampe et la vie restante de chaque particule.
+///
+/// Retourne le nombre de sommets ajoutés (0 si moins de deux points).
+pub fn append_ribbon(points: &[Particle], width: f32, out: &mut Vec<RibbonVertex>) -> usize {
+    let n = points.len();
+    if n < 2 {
+        return 0;
+    }
+    let mut normal = Vec2::Y;
+    for (i, p) in points.iter().enumerate() {
+        // Tangente par différences centrées (une seule différence aux extrémités)
+        let prev = points[i.saturating_sub(1)].pos;
+        let next = points[(i + 1).min(n - 1)].pos;
+        let tangent = (next - prev).normalize_or_zero();
+        if tangent != Vec2::ZERO {
+            normal = tangent.perp();
+        }
+
+        let t = i as f32 / (n - 1) as f32;
+        let depth_scale = depth_to_scale(p.depth);
+        let half_width = 0.5 * width * t * depth_scale;
+        let life = (p.life / p.max_life.max(0.0001)).clamp(0.0, 1.0);
+        let alpha = t * life * (0.35 + 0.65 * depth_scale);
+        let color = [p.color.x, p.color.y, p.color.z, alpha];
+
+        for side in [-1.0, 1.0] {
+            let pos = p.pos + normal * (half_width * side);
+            out.push(RibbonVertex {
+                pos: pos.to_array(),
+                color,
+                side,
+            });
+        }
+    }
+    2 * n
+}
+
+/// Rendu des trails en rubans continus (`trail_style = "ribbon"`).
+///
+/// Chaque fusée produit un triangle strip de longueur variable ; tous les strips
+/// sont envoyés dans un seul buffer (réalloué à la frame) et dessinés par un
+/// unique `glMultiDrawArrays`.
+pub struct TrailRibbonRenderer {
+    vao: u32,
+    vbo: u32,
+    shader_program: u32,
+    loc_view_proj: i32,
+
+    /// Capacité du buffer GPU (sommets)
+    capacity: usize,
+    enabled: bool,
+    width: f32,
+    /// Échelle d'affichage (DPI), multipliée à `width`
+    content_scale: f32,
+
+    vertices: Vec<RibbonVertex>,
+    firsts: Vec<i32>,
+    counts: Vec<i32>,
+    /// Copie de la polyligne en cours de construction
+    polyline: Vec<Particle>,
+    /// Points de trail envoyés lors du dernier remplissage
+    uploaded: usize,
+}
+
+impl TrailRibbonRenderer {
+    pub fn new() -> Self {
+        let (vertex_src, fragment_src) = Self::src_shaders_ribbon();
+        let shader_program = unsafe {
+            get_or_compile_program(
+                &PreprocessedShader::from_source("trail_ribbon.vert", vertex_src),
+                &PreprocessedShader::from_source("trail_ribbon.frag", fragment_src),
+            )
+        }
+        .unwrap_or_else(|e| panic!("{:#}", e));
+        let loc_view_proj = unsafe { gl::GetUniformLocation(shader_program, cstr!("uViewProj")) };
+
+        let (mut vao, mut vbo) = (0u32, 0u32);
+        unsafe {
+            gl::GenVertexArrays(1, &mut vao);
+            gl::BindVertexArray(vao);
+            gl::GenBuffers(1, &mut vbo);
+            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+
+            let stride = mem::size_of::<RibbonVertex>() as i32;
+            let attribs = [
+                (0, 2, mem::offset_of!(RibbonVertex, pos)),
+                (1, 4, mem::offset_of!(RibbonVertex, color)),
+                (2, 1, mem::offset_of!(RibbonVertex, side)),
+            ];
+            for (location, components, offset) in attribs {
+                gl::EnableVertexAttribArray(location);
+                gl::VertexAttribPointer(
+                    location,
+                    components,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    stride,
+                    offset as *const _,
+                );
+            }
+            gl::BindVertexArray(0);
+            gl_check!("trail ribbon buffers");
+        }
+
+        Self {
+            vao,
+            vbo,
+            shader_program,
+            loc_view_proj,
+            capacity: 0,
+            enabled: false,
+            width: RendererConfig::default().trail_ribbon_width,
+            content_scale: 1.0,
+            vertices: Vec::new(),
+            firsts: Vec::new(),
+            counts: Vec::new(),
+            polyline: Vec::new(),
+            uploaded: 0,
+        }
+    }
+
+    fn src_shaders_ribbon() -> (&'static str, &'static str) {
+        let vertex_src = r#"
+        #version 330 core
+        layout(location = 0) in vec2 aPos;
+        layout(location = 1) in vec4 aColor;
+        layout(location = 2) in float aSide;
+
+        out vec4 vColor;
+        out float vSide;
+
+        uniform mat3 uViewProj; // monde -> clip space (caméra)
+
+        void main() {
+            vColor = aColor;
+            vSide = aSide;
+            gl_Position = vec4((uViewProj * vec3(aPos, 1.0)).xy, 0.0, 1.0);
+        }
+        "#;
+
+        let fragment_src = r#"
+        #version 330 core
+        in vec4 vColor;
+        in float vSide;
+        out vec4 FragColor;
+
+        void main() {
+            // Bords adoucis : plein au centre du ruban, nul sur les bords
+            float edge = 1.0 - vSide * vSide;
+            FragColor = vec4(vColor.rgb, vColor.a * edge);
+        }
+        "#;
+        (vertex_src, fragment_src)
+    }
+}
+
+impl Default for TrailRibbonRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParticleGraphicsRenderer for TrailRibbonRenderer {
+    unsafe fn recreate_buffers(&mut self, _new_max: usize) {
+        // Buffer dimensionné à la demande lors du remplissage
+    }
+
+    unsafe fn fill_particle_data_direct(&mut self, physic: &dyn PhysicEngineIterator) -> usize {
+        self.vertices.clear();
+        self.firsts.clear();
+        self.counts.clear();
+        self.uploaded = 0;
+        if !self.enabled || physic.count_particles_by_type(ParticleType::Trail) == 0 {
+            return 0;
+        }
+
+        for polyline in physic.iter_trail_polylines() {
+            self.polyline.clear();
+            self.polyline.extend(polyline.copied());
+            let first = self.vertices.len();
+            let count = append_ribbon(
+                &self.polyline,
+                self.width * self.content_scale,
+                &mut self.vertices,
+            );
+            if count > 0 {
+                self.firsts.push(first as i32);
+                self.counts.push(count as i32);
+                self.uploaded += self.polyline.len();
+            }
+        }
+        if self.vertices.is_empty() {
+            return 0;
+        }
+
+        gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+        if self.vertices.len() > self.capacity {
+            self.capacity = self.vertices.len().next_power_of_two();
+        }
+        // Orphelinage : nouveau stockage à chaque frame, pas d'attente sur la frame précédente
+        let capacity_bytes = (self.capacity * mem::size_of::<RibbonVertex>()) as isize;
+        gl::BufferData(
+            gl::ARRAY_BUFFER,
+            capacity_bytes,
+            std::ptr::null(),
+            gl::STREAM_DRAW,
+        );
+        let bytes: &[u8] = bytemuck::cast_slice(&self.vertices);
+        gl::BufferSubData(
+            gl::ARRAY_BUFFER,
+            0,
+            bytes.len() as isize,
+            bytes.as_ptr() as *const _,
+        );
+        gl_check!("trail ribbon upload");
+        self.uploaded
+    }
+
+    unsafe fn render_particles_with_persistent_buffer(&self, _count: usize, view_proj: &Mat3) {
+        if self.counts.is_empty() {
+            return;
+        }
+        gl::UseProgram(self.shader_program);
+        gl::UniformMatrix3fv(
+            self.loc_view_proj,
+            1,
+            gl::FALSE,
+            view_proj.as_ref().as_ptr(),
+        );
+        gl::BindVertexArray(self.vao);
+        gl::MultiDrawArrays(
+            gl::TRIANGLE_STRIP,
+            self.firsts.as_ptr(),
+            self.counts.as_ptr(),
+            self.counts.len() as i32,
+        );
+        gl::BindVertexArray(0);
+        gl_check!("trail ribbon draw");
+    }
+
+    fn draw_stats(&self) -> DrawStats {
+        let mut particles = [0; ParticleType::ALL.len()];
+        particles[ParticleType::Trail as usize] = self.uploaded;
+        DrawStats {
+            particles,
+            bytes_uploaded: (self.vertices.len() * mem::size_of::<RibbonVertex>()) as u64,
+            draw_calls: usize::from(!self.counts.is_empty()),
+            instanced_draw_calls: 0,
+        }
+    }
+
+    fn gpu_memory(&self) -> GpuMemory {
+        GpuMemory {
+            buffers: (self.capacity * mem::size_of::<RibbonVertex>()) as u64,
+            ..Default::default()
+        }
+    }
+
+    fn apply_config(&mut self, config: &RendererConfig) {
+        self.enabled = config.trail_style == TrailStyle::Ribbon;
+        self.width = config.trail_ribbon_width.max(0.0);
+    }
+
+    fn set_content_scale(&mut self, scale: f32) {
+        self.content_scale = scale;
+    }
+
+    unsafe fn close(&mut self) {
+        if self.vbo != 0 {
+            gl::DeleteBuffers(1, &self.vbo);
+            self.vbo = 0;
+        }
+        if self.vao != 0 {
+            gl::DeleteVertexArrays(1, &self.vao);
+            self.vao = 0;
+        }
+        if self.shader_program != 0 {
+            gl::DeleteProgram(self.shader_program);
+            self.shader_program = 0;
+        }
+        self.capacity = 0;
+        debug!("Trail ribbon renderer closed and reset.");
+    }
+}
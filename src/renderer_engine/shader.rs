@@ -1,57 +1,556 @@
+use anyhow::{anyhow, bail, Context, Result};
 use gl::types::*;
-use std::{ffi::CString, ptr};
+use log::{debug, info, warn};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::ptr;
 
+use crate::renderer_engine::tools::{format_glsl_error_context, parse_glsl_error_line};
+use crate::utils::embedded_assets::read_asset_to_string;
+
+/// Profondeur maximale d'imbrication des `#include`
+pub const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Répertoire par défaut du cache de programmes compilés
+pub const SHADER_CACHE_DIR: &str = "target/shader_cache";
+/// Variable d'environnement pour changer le répertoire du cache (`off` le désactive)
+pub const SHADER_CACHE_ENV: &str = "FIREWORKS_SHADER_CACHE";
+
+/// Position d'une ligne dans son fichier d'origine (avant résolution des `#include`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    /// Numéro de ligne (à partir de 1)
+    pub line: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// Source GLSL dont les `#include` ont été résolus, avec la table de correspondance
+/// ligne générée → fichier/ligne d'origine.
+#[derive(Debug, Clone, Default)]
+pub struct PreprocessedShader {
+    pub source: String,
+    pub line_map: Vec<SourceLocation>,
+}
+
+impl PreprocessedShader {
+    /// Source sans `#include` (shader embarqué dans le code), nommé `name` dans les erreurs.
+    pub fn from_source(name: &str, source: &str) -> Self {
+        let mut shader = Self::default();
+        for (index, line) in source.lines().enumerate() {
+            shader.source += line;
+            shader.source.push('\n');
+            shader.line_map.push(SourceLocation {
+                file: PathBuf::from(name),
+                line: index + 1,
+            });
+        }
+        shader
+    }
+
+    /// Nom du fichier d'origine (première ligne), utilisé pour nommer l'entrée de cache.
+    fn name(&self) -> String {
+        self.line_map
+            .first()
+            .and_then(|loc| loc.file.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "inline".to_string())
+    }
+
+    /// Origine de la ligne `line` (numérotation GLSL, à partir de 1) du source généré.
+    pub fn original_location(&self, line: usize) -> Option<&SourceLocation> {
+        line.checked_sub(1).and_then(|i| self.line_map.get(i))
+    }
+
+    /// Log de compilation enrichi : fichier/ligne d'origine + extrait du source généré.
+    pub fn describe_compile_error(&self, log: &str) -> String {
+        let mut out = log.trim_end().to_string();
+        if let Some((line, _col)) = parse_glsl_error_line(log) {
+            if let Some(location) = self.original_location(line) {
+                out += &format!("\n📍 at {}", location);
+            }
+            let context = format_glsl_error_context(&self.source, line);
+            if !context.is_empty() {
+                out += "\n";
+                out += context.trim_end();
+            }
+        }
+        out
+    }
+}
+
+/// Résout récursivement les `#include "chemin/relatif.glsl"` d'un fichier shader.
+pub fn preprocess_shader_file(path: impl AsRef<Path>) -> Result<PreprocessedShader> {
+    preprocess_shader_with(path, |p| {
+        read_asset_to_string(p).with_context(|| format!("Cannot read shader '{}'", p.display()))
+    })
+}
+
+/// Comme `preprocess_shader_file`, avec un chargeur de fichiers fourni par l'appelant.
+pub fn preprocess_shader_with<F>(path: impl AsRef<Path>, mut load: F) -> Result<PreprocessedShader>
+where
+    F: FnMut(&Path) -> Result<String>,
+{
+    let mut output = PreprocessedShader::default();
+    let mut stack = Vec::new();
+    expand_file(
+        &normalize(path.as_ref()),
+        &mut load,
+        &mut stack,
+        &mut output,
+    )?;
+    Ok(output)
+}
+
+fn expand_file<F>(
+    path: &Path,
+    load: &mut F,
+    stack: &mut Vec<PathBuf>,
+    output: &mut PreprocessedShader,
+) -> Result<()>
+where
+    F: FnMut(&Path) -> Result<String>,
+{
+    if stack.iter().any(|p| p == path) {
+        let chain: Vec<String> = stack
+            .iter()
+            .chain(std::iter::once(&path.to_path_buf()))
+            .map(|p| p.display().to_string())
+            .collect();
+        bail!("Shader include cycle: {}", chain.join(" -> "));
+    }
+    if stack.len() >= MAX_INCLUDE_DEPTH {
+        bail!(
+            "Shader include depth exceeds {} at '{}'",
+            MAX_INCLUDE_DEPTH,
+            path.display()
+        );
+    }
+
+    let text = load(path)?;
+    stack.push(path.to_path_buf());
+    for (index, line) in text.lines().enumerate() {
+        let location = SourceLocation {
+            file: path.to_path_buf(),
+            line: index + 1,
+        };
+        match parse_include(line) {
+            Some(Ok(include)) => {
+                let dir = path.parent().unwrap_or_else(|| Path::new(""));
+                let included = normalize(&dir.join(include));
+                expand_file(&included, load, stack, output)
+                    .with_context(|| format!("included from {}", location))?;
+            }
+            Some(Err(())) => bail!("Malformed #include directive at {}", location),
+            None => {
+                output.source += line;
+                output.source.push('\n');
+                output.line_map.push(location);
+            }
+        }
+    }
+    stack.pop();
+    Ok(())
+}
+
+/// `Some(Ok(chemin))` pour une directive `#include "chemin"`, `Some(Err)` si mal formée.
+fn parse_include(line: &str) -> Option<Result<&str, ()>> {
+    let rest = line.trim_start().strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix("include")?.trim();
+    let path = rest
+        .strip_prefix('"')
+        .and_then(|r| r.strip_suffix('"'))
+        .filter(|p| !p.is_empty() && !p.contains('"'));
+    Some(path.ok_or(()))
+}
+
+/// Normalisation lexicale (`a/./b/../c` → `a/c`) pour comparer les chemins inclus.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Compile et lie un programme à partir de fichiers shader (avec résolution des `#include`).
+/// Les erreurs de compilation pointent vers le fichier et la ligne d'origine.
+/// Passe par le cache de binaires (`get_or_compile_program`).
+///
 /// # Safety
-/// This function is unsafe because it interacts with raw OpenGL pointers and requires
-/// the caller to ensure that the provided shader source strings are valid for the duration
-/// of the shader compilation process.
-pub unsafe fn compile_shader_program(vertex_src: &str, fragment_src: &str) -> u32 {
-    fn compile_shader(src: &str, ty: GLenum) -> u32 {
-        let shader = unsafe { gl::CreateShader(ty) };
-        let c_str = CString::new(src).unwrap();
-        unsafe {
-            gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
-            gl::CompileShader(shader);
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn try_compile_shader_program_from_files(
+    vertex_path: impl AsRef<Path>,
+    fragment_path: impl AsRef<Path>,
+) -> Result<u32> {
+    let vertex = preprocess_shader_file(vertex_path)?;
+    let fragment = preprocess_shader_file(fragment_path)?;
+    get_or_compile_program(&vertex, &fragment)
+}
 
-            let mut success = gl::FALSE as GLint;
-            gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
-            if success != gl::TRUE as GLint {
-                let mut len = 0;
-                gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut len);
-                let mut buf = Vec::with_capacity(len as usize);
-                gl::GetShaderInfoLog(shader, len, ptr::null_mut(), buf.as_mut_ptr() as *mut _);
-                buf.set_len(len as usize);
-                panic!(
-                    "Shader compilation failed: {}",
-                    String::from_utf8_lossy(&buf)
-                );
+// ---------------------------------------------------------
+// Cache des binaires de programmes (GL_ARB_get_program_binary)
+// ---------------------------------------------------------
+
+/// Provenance d'un programme retourné par `get_or_compile_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramOrigin {
+    /// Binaire rechargé depuis le cache
+    Cache,
+    /// Compilation complète des sources
+    Compiled,
+}
+
+/// Binaire de programme tel que stocké dans le cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedProgram {
+    /// Empreinte des sources et du pilote ; un écart invalide l'entrée
+    pub source_hash: u64,
+    /// Format binaire propre au pilote (`glGetProgramBinary`)
+    pub format: u32,
+    pub binary: Vec<u8>,
+}
+
+const CACHE_MAGIC: &[u8; 4] = b"FWSC";
+
+impl CachedProgram {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(16 + self.binary.len());
+        bytes.extend_from_slice(CACHE_MAGIC);
+        bytes.extend_from_slice(&self.source_hash.to_le_bytes());
+        bytes.extend_from_slice(&self.format.to_le_bytes());
+        bytes.extend_from_slice(&self.binary);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let rest = bytes.strip_prefix(CACHE_MAGIC)?;
+        if rest.len() < 12 {
+            return None;
+        }
+        let (hash, rest) = rest.split_at(8);
+        let (format, binary) = rest.split_at(4);
+        Some(Self {
+            source_hash: u64::from_le_bytes(hash.try_into().ok()?),
+            format: u32::from_le_bytes(format.try_into().ok()?),
+            binary: binary.to_vec(),
+        })
+    }
+}
+
+/// Empreinte stable (FNV-1a 64 bits) des sources d'un programme et du pilote GL.
+pub fn program_source_hash(driver_id: &str, vertex_src: &str, fragment_src: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in [driver_id, vertex_src, fragment_src] {
+        // Séparateur pour que ("ab", "c") et ("a", "bc") diffèrent
+        for byte in part.bytes().chain(std::iter::once(0xff)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Persistance des binaires (répertoire sur disque, ou mémoire dans les tests).
+pub trait ProgramCacheStore {
+    fn load(&self, name: &str) -> Option<CachedProgram>;
+    fn store(&mut self, name: &str, program: &CachedProgram) -> Result<()>;
+}
+
+/// Cache sur disque : un fichier `<nom>.bin` par programme.
+#[derive(Debug, Clone)]
+pub struct FsProgramCache {
+    pub dir: PathBuf,
+}
+
+impl FsProgramCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Cache par défaut (`SHADER_CACHE_DIR`, ou `$FIREWORKS_SHADER_CACHE` ; `off` = aucun).
+    pub fn from_env() -> Option<Self> {
+        match std::env::var(SHADER_CACHE_ENV) {
+            Ok(value) if value == "off" => None,
+            Ok(value) if !value.is_empty() => Some(Self::new(value)),
+            _ => Some(Self::new(SHADER_CACHE_DIR)),
+        }
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        let file: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.dir.join(format!("{}.bin", file))
+    }
+}
+
+impl ProgramCacheStore for FsProgramCache {
+    fn load(&self, name: &str) -> Option<CachedProgram> {
+        let bytes = std::fs::read(self.path(name)).ok()?;
+        CachedProgram::from_bytes(&bytes)
+    }
+
+    fn store(&mut self, name: &str, program: &CachedProgram) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(name), program.to_bytes())?;
+        Ok(())
+    }
+}
+
+/// Opérations GL nécessaires au cache (abstraites pour les tests sans contexte GL).
+pub trait ProgramBackend {
+    /// Identifiant du pilote (un binaire n'est valable que pour le pilote qui l'a produit)
+    fn driver_id(&self) -> String;
+    fn supports_binaries(&self) -> bool;
+    /// Programme lié à partir d'un binaire, `None` si le pilote le refuse
+    fn load_binary(&mut self, cached: &CachedProgram) -> Option<u32>;
+    fn compile(
+        &mut self,
+        vertex: &PreprocessedShader,
+        fragment: &PreprocessedShader,
+    ) -> Result<u32>;
+    /// Binaire d'un programme lié : `(format, octets)`
+    fn program_binary(&mut self, program: u32) -> Option<(u32, Vec<u8>)>;
+}
+
+/// Recharge le programme depuis le cache si l'empreinte correspond, sinon le compile
+/// et met le cache à jour.
+pub fn get_or_compile_with(
+    backend: &mut dyn ProgramBackend,
+    store: Option<&mut dyn ProgramCacheStore>,
+    vertex: &PreprocessedShader,
+    fragment: &PreprocessedShader,
+) -> Result<(u32, ProgramOrigin)> {
+    let store = store.filter(|_| backend.supports_binaries());
+    let Some(store) = store else {
+        return Ok((backend.compile(vertex, fragment)?, ProgramOrigin::Compiled));
+    };
+
+    let name = format!("{}+{}", vertex.name(), fragment.name());
+    let hash = program_source_hash(&backend.driver_id(), &vertex.source, &fragment.source);
+
+    if let Some(cached) = store.load(&name).filter(|c| c.source_hash == hash) {
+        if let Some(program) = backend.load_binary(&cached) {
+            debug!("🗃️ Shader program '{}' loaded from cache", name);
+            return Ok((program, ProgramOrigin::Cache));
+        }
+        debug!("Shader cache entry '{}' rejected by the driver", name);
+    }
+
+    let program = backend.compile(vertex, fragment)?;
+    if let Some((format, binary)) = backend.program_binary(program) {
+        let cached = CachedProgram {
+            source_hash: hash,
+            format,
+            binary,
+        };
+        if let Err(e) = store.store(&name, &cached) {
+            warn!("⚠️ Shader cache: cannot store '{}': {}", name, e);
+        }
+    }
+    Ok((program, ProgramOrigin::Compiled))
+}
+
+/// Backend OpenGL réel.
+pub struct GlProgramBackend;
+
+impl ProgramBackend for GlProgramBackend {
+    fn driver_id(&self) -> String {
+        let get = |name| unsafe {
+            let ptr = gl::GetString(name);
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr as *const _)
+                    .to_string_lossy()
+                    .into_owned()
             }
+        };
+        format!(
+            "{}|{}|{}",
+            get(gl::VENDOR),
+            get(gl::RENDERER),
+            get(gl::VERSION)
+        )
+    }
+
+    fn supports_binaries(&self) -> bool {
+        if !gl::GetProgramBinary::is_loaded() || !gl::ProgramBinary::is_loaded() {
+            return false;
         }
-        shader
+        let mut formats = 0;
+        unsafe { gl::GetIntegerv(gl::NUM_PROGRAM_BINARY_FORMATS, &mut formats) };
+        formats > 0
     }
 
-    let vs = compile_shader(vertex_src, gl::VERTEX_SHADER);
-    let fs = compile_shader(fragment_src, gl::FRAGMENT_SHADER);
+    fn load_binary(&mut self, cached: &CachedProgram) -> Option<u32> {
+        unsafe {
+            let program = gl::CreateProgram();
+            gl::ProgramBinary(
+                program,
+                cached.format,
+                cached.binary.as_ptr() as *const _,
+                cached.binary.len() as GLsizei,
+            );
+            let mut success = gl::FALSE as GLint;
+            gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
+            // Un binaire refusé laisse une erreur GL : on la consomme
+            while gl::GetError() != gl::NO_ERROR {}
+            if success == gl::TRUE as GLint {
+                Some(program)
+            } else {
+                gl::DeleteProgram(program);
+                None
+            }
+        }
+    }
 
-    let program = unsafe { gl::CreateProgram() };
-    unsafe {
-        gl::AttachShader(program, vs);
-        gl::AttachShader(program, fs);
-        gl::LinkProgram(program);
+    fn compile(
+        &mut self,
+        vertex: &PreprocessedShader,
+        fragment: &PreprocessedShader,
+    ) -> Result<u32> {
+        unsafe { link_program(vertex, fragment) }
+    }
 
-        let mut success = gl::FALSE as GLint;
-        gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
-        if success != gl::TRUE as GLint {
+    fn program_binary(&mut self, program: u32) -> Option<(u32, Vec<u8>)> {
+        unsafe {
             let mut len = 0;
-            gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut len);
-            let mut buf = Vec::with_capacity(len as usize);
-            gl::GetProgramInfoLog(program, len, ptr::null_mut(), buf.as_mut_ptr() as *mut _);
-            buf.set_len(len as usize);
-            panic!("Shader link failed: {}", String::from_utf8_lossy(&buf));
+            gl::GetProgramiv(program, gl::PROGRAM_BINARY_LENGTH, &mut len);
+            if len <= 0 {
+                return None;
+            }
+            let mut binary = vec![0u8; len as usize];
+            let mut written = 0;
+            let mut format = 0;
+            gl::GetProgramBinary(
+                program,
+                len,
+                &mut written,
+                &mut format,
+                binary.as_mut_ptr() as *mut _,
+            );
+            binary.truncate(written.max(0) as usize);
+            (!binary.is_empty()).then_some((format, binary))
         }
+    }
+}
 
-        gl::DeleteShader(vs);
-        gl::DeleteShader(fs);
+/// Programme lié, rechargé depuis le cache de binaires quand c'est possible
+/// (repli sur la compilation si l'extension manque ou si les sources ont changé).
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn get_or_compile_program(
+    vertex: &PreprocessedShader,
+    fragment: &PreprocessedShader,
+) -> Result<u32> {
+    let mut store = FsProgramCache::from_env();
+    let (program, origin) = get_or_compile_with(
+        &mut GlProgramBackend,
+        store
+            .as_mut()
+            .map(|store| store as &mut dyn ProgramCacheStore),
+        vertex,
+        fragment,
+    )?;
+    if origin == ProgramOrigin::Compiled {
+        info!(
+            "🛠️ Shader program compiled: {} + {}",
+            vertex.name(),
+            fragment.name()
+        );
+    }
+    Ok(program)
+}
+
+unsafe fn link_program(vertex: &PreprocessedShader, fragment: &PreprocessedShader) -> Result<u32> {
+    let vs = compile_stage(vertex, gl::VERTEX_SHADER)?;
+    let fs = match compile_stage(fragment, gl::FRAGMENT_SHADER) {
+        Ok(fs) => fs,
+        Err(e) => {
+            gl::DeleteShader(vs);
+            return Err(e);
+        }
+    };
+
+    let program = gl::CreateProgram();
+    if gl::ProgramParameteri::is_loaded() {
+        // Rend le binaire récupérable pour le cache
+        gl::ProgramParameteri(
+            program,
+            gl::PROGRAM_BINARY_RETRIEVABLE_HINT,
+            gl::TRUE as GLint,
+        );
+    }
+    gl::AttachShader(program, vs);
+    gl::AttachShader(program, fs);
+    gl::LinkProgram(program);
+    gl::DeleteShader(vs);
+    gl::DeleteShader(fs);
+
+    let mut success = gl::FALSE as GLint;
+    gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
+    if success != gl::TRUE as GLint {
+        let log = info_log(program, gl::GetProgramiv, gl::GetProgramInfoLog);
+        gl::DeleteProgram(program);
+        bail!("Shader link failed:\n{}", log);
     }
-    program
+    Ok(program)
+}
+
+unsafe fn compile_stage(shader_src: &PreprocessedShader, ty: GLenum) -> Result<u32> {
+    let c_str = CString::new(shader_src.source.as_str())
+        .map_err(|_| anyhow!("Shader source contains a NUL byte"))?;
+    let shader = gl::CreateShader(ty);
+    gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
+    gl::CompileShader(shader);
+
+    let mut success = gl::FALSE as GLint;
+    gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
+    if success != gl::TRUE as GLint {
+        let log = info_log(shader, gl::GetShaderiv, gl::GetShaderInfoLog);
+        gl::DeleteShader(shader);
+        bail!(
+            "❌ Shader compilation failed:\n{}",
+            shader_src.describe_compile_error(&log)
+        );
+    }
+    Ok(shader)
+}
+
+unsafe fn info_log(
+    object: GLuint,
+    get_iv: unsafe fn(GLuint, GLenum, *mut GLint),
+    get_log: unsafe fn(GLuint, GLsizei, *mut GLsizei, *mut GLchar),
+) -> String {
+    let mut len = 0;
+    get_iv(object, gl::INFO_LOG_LENGTH, &mut len);
+    let mut buf = vec![0u8; len.max(0) as usize];
+    get_log(object, len, ptr::null_mut(), buf.as_mut_ptr() as *mut _);
+    String::from_utf8_lossy(&buf)
+        .trim_end_matches('\0')
+        .to_string()
 }
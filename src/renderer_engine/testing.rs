@@ -0,0 +1,284 @@
+//! Outils des tests de rendu : comparaison perceptuelle d'images (ΔE moyen dans
+//! l'espace CIELAB), image des différences et vérification d'une image de
+//! référence stockée dans `tests/golden/`.
+//!
+//! Les images de référence sont versionnées : une référence absente est une
+//! erreur, seule `UPDATE_GOLDEN=1` (re)génère les images. En cas d'écart, l'image
+//! obtenue et l'image des différences sont écrites dans `target/golden/` pour
+//! inspection.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use image::{Rgba, RgbaImage};
+use log::info;
+
+/// Variable d'environnement qui force la régénération des images de référence
+pub const UPDATE_GOLDEN_ENV: &str = "UPDATE_GOLDEN";
+/// Répertoire des images de référence
+pub const GOLDEN_DIR: &str = "tests/golden";
+/// Écart ΔE à partir duquel une différence de couleur devient perceptible
+pub const JUST_NOTICEABLE_DELTA_E: f32 = 2.3;
+
+/// Écart admis entre une image et sa référence.
+///
+/// Les drivers diffèrent par la rastérisation et les arrondis : quelques pixels
+/// de bord peuvent changer franchement sans que la scène change. On borne donc
+/// l'écart moyen (sous le seuil de perception) et la part de pixels visiblement
+/// différents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoldenTolerance {
+    /// ΔE moyen maximal sur l'image
+    pub max_mean_delta_e: f32,
+    /// Part maximale de pixels dont le ΔE dépasse `JUST_NOTICEABLE_DELTA_E`
+    pub max_changed_ratio: f32,
+}
+
+impl Default for GoldenTolerance {
+    fn default() -> Self {
+        Self {
+            max_mean_delta_e: 1.0,
+            max_changed_ratio: 0.01,
+        }
+    }
+}
+
+/// Couleur sRGB 8 bits → CIELAB (illuminant D65).
+pub fn srgb_to_lab([r, g, b]: [u8; 3]) -> [f32; 3] {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+    // sRGB linéaire → XYZ, normalisé par le blanc D65
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.950_47;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.088_83;
+    let f = |t: f32| {
+        if t > 0.008_856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Écart de couleur CIE76 entre deux couleurs CIELAB.
+pub fn delta_e(a: [f32; 3], b: [f32; 3]) -> f32 {
+    let [dl, da, db] = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+fn pixel_delta_e(a: &Rgba<u8>, b: &Rgba<u8>) -> f32 {
+    let rgb = |p: &Rgba<u8>| [p[0], p[1], p[2]];
+    delta_e(srgb_to_lab(rgb(a)), srgb_to_lab(rgb(b)))
+}
+
+/// Résultat de la comparaison de deux images de même taille (alpha ignoré).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageComparison {
+    pub mean_delta_e: f32,
+    pub max_delta_e: f32,
+    /// Pixels dont le ΔE dépasse `JUST_NOTICEABLE_DELTA_E`
+    pub changed_pixels: usize,
+    pub total_pixels: usize,
+}
+
+impl ImageComparison {
+    pub fn changed_ratio(&self) -> f32 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        self.changed_pixels as f32 / self.total_pixels as f32
+    }
+
+    pub fn within(&self, tolerance: &GoldenTolerance) -> bool {
+        self.mean_delta_e <= tolerance.max_mean_delta_e
+            && self.changed_ratio() <= tolerance.max_changed_ratio
+    }
+}
+
+impl fmt::Display for ImageComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean ΔE {:.3}, max ΔE {:.1}, {:.2} % pixels changed",
+            self.mean_delta_e,
+            self.max_delta_e,
+            self.changed_ratio() * 100.0
+        )
+    }
+}
+
+/// Compare `actual` à `expected` pixel par pixel (ΔE CIE76).
+pub fn compare_images(expected: &RgbaImage, actual: &RgbaImage) -> Result<ImageComparison> {
+    if expected.dimensions() != actual.dimensions() {
+        bail!(
+            "image size differs: expected {}x{}, got {}x{}",
+            expected.width(),
+            expected.height(),
+            actual.width(),
+            actual.height()
+        );
+    }
+    let mut sum = 0.0f64;
+    let mut max_delta_e = 0.0f32;
+    let mut changed_pixels = 0;
+    for (a, b) in expected.pixels().zip(actual.pixels()) {
+        let de = pixel_delta_e(a, b);
+        sum += de as f64;
+        max_delta_e = max_delta_e.max(de);
+        if de > JUST_NOTICEABLE_DELTA_E {
+            changed_pixels += 1;
+        }
+    }
+    let total_pixels = expected.pixels().len();
+    Ok(ImageComparison {
+        mean_delta_e: if total_pixels == 0 {
+            0.0
+        } else {
+            (sum / total_pixels as f64) as f32
+        },
+        max_delta_e,
+        changed_pixels,
+        total_pixels,
+    })
+}
+
+/// Image des différences : référence estompée en gris, écarts en rouge
+/// (saturé à 4 × `JUST_NOTICEABLE_DELTA_E`).
+pub fn diff_image(expected: &RgbaImage, actual: &RgbaImage) -> RgbaImage {
+    RgbaImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let a = expected.get_pixel(x, y);
+        let gray = ((a[0] as u32 + a[1] as u32 + a[2] as u32) / 12) as u8;
+        let Some(b) = actual.get_pixel_checked(x, y) else {
+            return Rgba([255, 0, 255, 255]);
+        };
+        let de = pixel_delta_e(a, b);
+        if de <= JUST_NOTICEABLE_DELTA_E {
+            return Rgba([gray, gray, gray, 255]);
+        }
+        let t = (de / (4.0 * JUST_NOTICEABLE_DELTA_E)).min(1.0);
+        Rgba([gray.max((128.0 + 127.0 * t) as u8), gray / 2, gray / 2, 255])
+    })
+}
+
+/// Issue d'une vérification réussie.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GoldenStatus {
+    /// Image conforme à sa référence
+    Matched(ImageComparison),
+    /// Régénération demandée (`UPDATE_GOLDEN`) : l'image a été écrite
+    Written,
+}
+
+/// Vérification d'une image rendue contre `<golden_dir>/<name>.png`.
+#[derive(Debug, Clone)]
+pub struct GoldenCheck {
+    pub name: String,
+    pub golden_dir: PathBuf,
+    /// Destination des images obtenues et des différences en cas d'échec
+    pub output_dir: PathBuf,
+    pub tolerance: GoldenTolerance,
+    /// Réécrit la référence au lieu de comparer (`UPDATE_GOLDEN`)
+    pub update: bool,
+}
+
+impl GoldenCheck {
+    pub fn new(name: impl Into<String>) -> Self {
+        let target =
+            std::env::var_os("CARGO_TARGET_DIR").map_or_else(|| "target".into(), PathBuf::from);
+        Self {
+            name: name.into(),
+            golden_dir: PathBuf::from(GOLDEN_DIR),
+            output_dir: target.join("golden"),
+            tolerance: GoldenTolerance::default(),
+            update: std::env::var_os(UPDATE_GOLDEN_ENV).is_some(),
+        }
+    }
+
+    pub fn golden_path(&self) -> PathBuf {
+        self.golden_dir.join(format!("{}.png", self.name))
+    }
+
+    pub fn actual_path(&self) -> PathBuf {
+        self.output_dir.join(format!("{}.actual.png", self.name))
+    }
+
+    pub fn diff_path(&self) -> PathBuf {
+        self.output_dir.join(format!("{}.diff.png", self.name))
+    }
+
+    /// Compare `actual` à la référence ; en cas d'écart, écrit l'image obtenue
+    /// et les différences dans `output_dir` puis retourne une erreur.
+    ///
+    /// Référence absente hors mode `update` : erreur (l'image obtenue est écrite
+    /// dans `output_dir`), pour qu'un test ne passe jamais faute de référence.
+    pub fn check(&self, actual: &RgbaImage) -> Result<GoldenStatus> {
+        let golden_path = self.golden_path();
+        if self.update {
+            save_png(actual, &golden_path)?;
+            info!("🖼️ Golden image written: {}", golden_path.display());
+            return Ok(GoldenStatus::Written);
+        }
+        if !golden_path.exists() {
+            save_png(actual, &self.actual_path())?;
+            bail!(
+                "'{}': golden {} is missing (actual: {}); create it with {}=1",
+                self.name,
+                golden_path.display(),
+                self.actual_path().display(),
+                UPDATE_GOLDEN_ENV
+            );
+        }
+        let expected = image::open(&golden_path)
+            .with_context(|| format!("Cannot read {}", golden_path.display()))?
+            .to_rgba8();
+        let outcome = compare_images(&expected, actual);
+        if let Ok(comparison) = &outcome {
+            if comparison.within(&self.tolerance) {
+                return Ok(GoldenStatus::Matched(*comparison));
+            }
+        }
+
+        save_png(actual, &self.actual_path())?;
+        let details = match outcome {
+            Ok(comparison) => comparison.to_string(),
+            Err(e) => e.to_string(),
+        };
+        let mut diff = String::new();
+        if expected.dimensions() == actual.dimensions() {
+            save_png(&diff_image(&expected, actual), &self.diff_path())?;
+            diff = format!(", diff: {}", self.diff_path().display());
+        }
+        bail!(
+            "'{}' differs from {}: {} (tolerance: mean ΔE {}, {} % pixels); \
+             actual: {}{}; regenerate with {}=1 if the change is intended",
+            self.name,
+            golden_path.display(),
+            details,
+            self.tolerance.max_mean_delta_e,
+            self.tolerance.max_changed_ratio * 100.0,
+            self.actual_path().display(),
+            diff,
+            UPDATE_GOLDEN_ENV
+        )
+    }
+}
+
+fn save_png(image: &RgbaImage, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Cannot create {}", parent.display()))?;
+    }
+    image
+        .save(path)
+        .with_context(|| format!("Cannot write {}", path.display()))
+}
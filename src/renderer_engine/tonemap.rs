@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use crate::renderer_engine::command_cvar::CvarValue;
+
+/// Nombre maximal de cellules de la grille de comparaison
+pub const MAX_COMPARISON_CELLS: usize = 6;
+
+/// Opérateur de tone mapping appliqué lors de la composition HDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToneMappingMode {
+    /// Aucun opérateur : les valeurs > 1 sont écrêtées (rendu historique)
+    #[default]
+    Linear,
+    Reinhard,
+    Aces,
+    Agx,
+}
+
+impl ToneMappingMode {
+    pub const ALL: [ToneMappingMode; 4] = [
+        ToneMappingMode::Linear,
+        ToneMappingMode::Reinhard,
+        ToneMappingMode::Aces,
+        ToneMappingMode::Agx,
+    ];
+
+    /// Nom utilisé par la config et la console
+    pub fn name(&self) -> &'static str {
+        match self {
+            ToneMappingMode::Linear => "linear",
+            ToneMappingMode::Reinhard => "reinhard",
+            ToneMappingMode::Aces => "aces",
+            ToneMappingMode::Agx => "agx",
+        }
+    }
+
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Valeur de l'uniform `uToneMapping` (cf. `common/tonemap.glsl`)
+    pub fn shader_index(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl CvarValue for ToneMappingMode {
+    fn type_hint() -> String {
+        Self::hints().join("|")
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        Self::from_name(text)
+    }
+
+    fn format(&self) -> String {
+        self.name().to_string()
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        toml::Value::String(self.name().to_string())
+    }
+
+    fn from_toml(value: &toml::Value) -> Option<Self> {
+        value.as_str().and_then(Self::from_name)
+    }
+
+    fn hints() -> Vec<String> {
+        Self::ALL
+            .iter()
+            .map(|mode| mode.name().to_string())
+            .collect()
+    }
+}
+
+/// Gamma approché de l'encodage sRGB appliqué par un framebuffer sRGB
+pub const SRGB_GAMMA: f32 = 2.2;
+
+/// Valeur de l'uniform `uGamma` pour un gamma de sortie `target` : la part déjà
+/// encodée par un framebuffer sRGB n'est pas appliquée une seconde fois.
+pub fn shader_gamma(target: f32, srgb_output: bool) -> f32 {
+    if srgb_output {
+        target / SRGB_GAMMA
+    } else {
+        target
+    }
+}
+
+/// Miroir CPU de `encode_gamma` (`common/tonemap.glsl`) pour une composante.
+pub fn encode_gamma(value: f32, gamma: f32) -> f32 {
+    value.max(0.0).powf(1.0 / gamma)
+}
+
+/// Cellule de la grille de comparaison : opérateur et gamma de sortie.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonCell {
+    pub mode: ToneMappingMode,
+    pub gamma: f32,
+    /// Étiquette incrustée lors de l'export
+    pub label: String,
+}
+
+/// Cellules affichées : un opérateur par cellule si `modes` n'est pas vide, sinon
+/// l'opérateur courant sans correction puis avec `output_gamma` si `gamma_compare`.
+/// Vide : composition simple.
+pub fn comparison_cells(
+    modes: &[ToneMappingMode],
+    tone_mapping: ToneMappingMode,
+    gamma_compare: bool,
+    output_gamma: f32,
+) -> Vec<ComparisonCell> {
+    if !modes.is_empty() {
+        return modes
+            .iter()
+            .take(MAX_COMPARISON_CELLS)
+            .map(|&mode| ComparisonCell {
+                mode,
+                gamma: output_gamma,
+                label: mode.name().to_uppercase(),
+            })
+            .collect();
+    }
+    if !gamma_compare {
+        return Vec::new();
+    }
+    [1.0, output_gamma]
+        .into_iter()
+        .map(|gamma| ComparisonCell {
+            mode: tone_mapping,
+            gamma,
+            label: format!("GAMMA {:.1}", gamma),
+        })
+        .collect()
+}
+
+/// Rectangle en pixels, origine en bas à gauche (convention `glViewport`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Colonnes et lignes de la grille pour `count` cellules.
+///
+/// Chaque cellule montre la scène entière à l'échelle `min(1/cols, 1/rows)` :
+/// on maximise cette échelle, puis on minimise les cases vides ; à égalité,
+/// une fenêtre large préfère les colonnes et une fenêtre haute les lignes.
+pub fn grid_dimensions(count: usize, width: i32, height: i32) -> (usize, usize) {
+    let count = count.clamp(1, MAX_COMPARISON_CELLS);
+    let wide = width >= height;
+    (1..=count)
+        .map(|cols| (cols, count.div_ceil(cols)))
+        .max_by(|&(c1, r1), &(c2, r2)| {
+            let scale = |c: usize, r: usize| 1.0 / c.max(r) as f32;
+            let orientation = |c: usize, r: usize| if wide { c >= r } else { r >= c };
+            scale(c1, r1)
+                .total_cmp(&scale(c2, r2))
+                .then((c2 * r2).cmp(&(c1 * r1)))
+                .then(orientation(c1, r1).cmp(&orientation(c2, r2)))
+        })
+        .unwrap_or((1, 1))
+}
+
+/// Rectangles des cellules (de gauche à droite, de haut en bas) pour `count` opérateurs
+/// dans une cible `width × height`. Les proportions de la scène sont conservées
+/// et une dernière ligne incomplète est centrée.
+pub fn comparison_grid(count: usize, width: i32, height: i32) -> Vec<CellRect> {
+    if count == 0 || width <= 0 || height <= 0 {
+        return Vec::new();
+    }
+    let count = count.min(MAX_COMPARISON_CELLS);
+    let (cols, rows) = grid_dimensions(count, width, height);
+    let scale = 1.0 / cols.max(rows) as f32;
+    let (cell_w, cell_h) = (width as f32 / cols as f32, height as f32 / rows as f32);
+    let (image_w, image_h) = (width as f32 * scale, height as f32 * scale);
+
+    (0..count)
+        .map(|i| {
+            let (row, col) = (i / cols, i % cols);
+            let in_row = (count - row * cols).min(cols);
+            let row_offset = (cols - in_row) as f32 * cell_w / 2.0;
+            let x = row_offset + col as f32 * cell_w + (cell_w - image_w) / 2.0;
+            // Ligne 0 en haut de l'écran
+            let y = height as f32 - (row + 1) as f32 * cell_h + (cell_h - image_h) / 2.0;
+            CellRect {
+                x: x.round() as i32,
+                y: y.round() as i32,
+                width: image_w.round() as i32,
+                height: image_h.round() as i32,
+            }
+        })
+        .collect()
+}
+
+/// Analyse une liste d'opérateurs (`aces reinhard agx …`), 2 à `MAX_COMPARISON_CELLS`.
+pub fn parse_comparison_modes<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<ToneMappingMode>, String> {
+    let modes = names
+        .into_iter()
+        .map(|name| {
+            ToneMappingMode::from_name(name)
+                .ok_or_else(|| format!("Unknown tone mapping mode: {}", name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if !(2..=MAX_COMPARISON_CELLS).contains(&modes.len()) {
+        return Err(format!(
+            "Expected 2 to {} modes, got {}",
+            MAX_COMPARISON_CELLS,
+            modes.len()
+        ));
+    }
+    Ok(modes)
+}
@@ -1,15 +1,40 @@
 // use gl::types::*;
 use gl::types::*;
-use log::{debug, info, warn};
-use std::collections::HashSet;
+use log::{debug, error, info, log, warn, Level};
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::os::raw::c_void;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use std::{ffi::CString, ptr};
 
+use crate::utils::system_report::{record_graphics, GraphicsInfo};
+
+/// Intervalle minimal entre deux logs d'un même message de debug OpenGL
+pub const GL_DEBUG_LOG_INTERVAL: Duration = Duration::from_secs(2);
+
 lazy_static::lazy_static! {
-    static ref LOGGED_IDS: Mutex<HashSet<u32>> = Mutex::new(HashSet::new());
-    static ref MESSAGE_COUNT: Mutex<std::collections::HashMap<u32, u32>> = Mutex::new(std::collections::HashMap::new());
+    static ref DEBUG_RATE_LIMITER: Mutex<DebugRateLimiter> =
+        Mutex::new(DebugRateLimiter::new(GL_DEBUG_LOG_INTERVAL));
+}
+
+/// Vérifications `gl_check!` actives (feature `gl_debug` ou `gl_debug = true` dans renderer.toml)
+static GL_CHECKS_ENABLED: AtomicBool = AtomicBool::new(cfg!(feature = "gl_debug"));
+/// Nombre de messages de debug OpenGL effectivement logués
+static GL_DEBUG_MESSAGES_LOGGED: AtomicU64 = AtomicU64::new(0);
+
+/// Vérifie `glGetError` après un appel critique, si les vérifications sont actives.
+///
+/// `gl_check!()` ou `gl_check!("contexte")` ; retourne le nombre d'erreurs relevées.
+#[macro_export]
+macro_rules! gl_check {
+    () => {
+        $crate::renderer_engine::tools::check_gl_errors(file!(), line!(), "")
+    };
+    ($label:expr) => {
+        $crate::renderer_engine::tools::check_gl_errors(file!(), line!(), $label)
+    };
 }
 
 #[macro_export]
@@ -19,7 +44,31 @@ macro_rules! cstr {
     };
 }
 
-/// Affiche les informations OpenGL / GPU du contexte actuel
+/// Vendor / renderer / versions du contexte OpenGL actuel (`SystemReport`)
+/// # Safety
+///
+/// L'appelant doit s'assurer que le contexte OpenGL est valide et actif.
+pub unsafe fn opengl_context_info() -> GraphicsInfo {
+    let string = |name: GLenum| {
+        let ptr = gl::GetString(name);
+        if ptr.is_null() {
+            return "Unknown".to_string();
+        }
+        CStr::from_ptr(ptr as *const i8)
+            .to_str()
+            .unwrap_or("Unknown")
+            .to_string()
+    };
+    GraphicsInfo {
+        vendor: string(gl::VENDOR),
+        renderer: string(gl::RENDERER),
+        version: string(gl::VERSION),
+        glsl_version: string(gl::SHADING_LANGUAGE_VERSION),
+    }
+}
+
+/// Affiche les informations OpenGL / GPU du contexte actuel et les ajoute au
+/// rapport système.
 /// # Safety
 ///
 /// L'appelant doit s'assurer que le contexte OpenGL est valide et actif.
@@ -27,24 +76,13 @@ pub unsafe fn show_opengl_context_info() {
     use std::ffi::CStr;
 
     // Vendor / Renderer / Version / GLSL
-    let vendor = CStr::from_ptr(gl::GetString(gl::VENDOR) as *const i8)
-        .to_str()
-        .unwrap_or("Unknown");
-    let renderer = CStr::from_ptr(gl::GetString(gl::RENDERER) as *const i8)
-        .to_str()
-        .unwrap_or("Unknown");
-    let version = CStr::from_ptr(gl::GetString(gl::VERSION) as *const i8)
-        .to_str()
-        .unwrap_or("Unknown");
-    let glsl_version = CStr::from_ptr(gl::GetString(gl::SHADING_LANGUAGE_VERSION) as *const i8)
-        .to_str()
-        .unwrap_or("Unknown");
-
+    let info = opengl_context_info();
     info!("🖥 OpenGL context info:");
-    info!("  Vendor   : {}", vendor);
-    info!("  Renderer : {}", renderer);
-    info!("  OpenGL   : {}", version);
-    info!("  GLSL     : {}", glsl_version);
+    info!("  Vendor   : {}", info.vendor);
+    info!("  Renderer : {}", info.renderer);
+    info!("  OpenGL   : {}", info.version);
+    info!("  GLSL     : {}", info.glsl_version);
+    record_graphics(info);
 
     // Nombre d'extensions
     let mut num_ext = 0;
@@ -130,7 +168,7 @@ pub unsafe fn compile_shader_program(vertex_src: &str, fragment_src: &str) -> u3
 }
 
 /// Essaie d’extraire le numéro de ligne de l’erreur GLSL (ex: "0:12(105): ...")
-fn parse_glsl_error_line(log: &str) -> Option<(usize, usize)> {
+pub fn parse_glsl_error_line(log: &str) -> Option<(usize, usize)> {
     let re = regex::Regex::new(r"(\d+):(\d+)\((\d+)\)").ok()?;
     re.captures(log).and_then(|cap| {
         let line = cap.get(2)?.as_str().parse::<usize>().ok()?;
@@ -139,18 +177,18 @@ fn parse_glsl_error_line(log: &str) -> Option<(usize, usize)> {
     })
 }
 
-/// Affiche un extrait du code GLSL autour de la ligne fautive
-fn show_glsl_error_context(src: &str, line_number: usize) {
+/// Extrait du code GLSL autour de la ligne fautive (vide si la ligne est hors source)
+pub fn format_glsl_error_context(src: &str, line_number: usize) -> String {
     let lines: Vec<&str> = src.lines().collect();
 
     // Handle empty source or line number beyond source length
     if lines.is_empty() || line_number == 0 {
-        return;
+        return String::new();
     }
 
     let context_range = 2; // nb de lignes avant/après à afficher
 
-    eprintln!("🔍 Error context (line {}):", line_number);
+    let mut out = format!("🔍 Error context (line {}):\n", line_number);
 
     let start = line_number.saturating_sub(1 + context_range);
     let end = (line_number + context_range).min(lines.len());
@@ -162,12 +200,125 @@ fn show_glsl_error_context(src: &str, line_number: usize) {
     for (i, line) in lines[safe_start..safe_end].iter().enumerate() {
         let current = safe_start + i + 1;
         if current == line_number {
-            eprintln!("> {:>3} | {}", current, line);
-            eprintln!("        {}", "^".repeat(line.len().min(80)));
+            out += &format!("> {:>3} | {}\n", current, line);
+            out += &format!("        {}\n", "^".repeat(line.len().min(80)));
         } else {
-            eprintln!("  {:>3} | {}", current, line);
+            out += &format!("  {:>3} | {}\n", current, line);
         }
     }
+    out
+}
+
+/// Affiche un extrait du code GLSL autour de la ligne fautive
+fn show_glsl_error_context(src: &str, line_number: usize) {
+    eprint!("{}", format_glsl_error_context(src, line_number));
+}
+
+/// Limiteur de débit des messages de debug OpenGL, par identifiant de message.
+///
+/// Le premier message d'un identifiant passe toujours ; les répétitions sont
+/// ensuite regroupées : au plus un log par `interval`, avec le nombre de
+/// messages supprimés entre-temps.
+#[derive(Debug)]
+pub struct DebugRateLimiter {
+    interval: Duration,
+    /// id -> (dernier log, répétitions supprimées depuis)
+    entries: HashMap<u32, (Instant, u32)>,
+}
+
+impl DebugRateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// `Some(supprimés)` si le message doit être logué maintenant.
+    pub fn should_log(&mut self, id: u32, now: Instant) -> Option<u32> {
+        match self.entries.get_mut(&id) {
+            None => {
+                self.entries.insert(id, (now, 0));
+                Some(0)
+            }
+            Some((last, suppressed)) if now.duration_since(*last) >= self.interval => {
+                let count = *suppressed;
+                *last = now;
+                *suppressed = 0;
+                Some(count)
+            }
+            Some((_, suppressed)) => {
+                *suppressed += 1;
+                None
+            }
+        }
+    }
+}
+
+/// Niveau de log associé à une sévérité de message de debug OpenGL.
+pub fn gl_debug_severity_level(severity: GLenum) -> Level {
+    match severity {
+        gl::DEBUG_SEVERITY_HIGH => Level::Error,
+        gl::DEBUG_SEVERITY_MEDIUM => Level::Warn,
+        gl::DEBUG_SEVERITY_LOW => Level::Info,
+        _ => Level::Debug,
+    }
+}
+
+/// Nom lisible d'un code `glGetError`.
+pub fn gl_error_name(code: GLenum) -> &'static str {
+    match code {
+        gl::INVALID_ENUM => "GL_INVALID_ENUM",
+        gl::INVALID_VALUE => "GL_INVALID_VALUE",
+        gl::INVALID_OPERATION => "GL_INVALID_OPERATION",
+        gl::INVALID_FRAMEBUFFER_OPERATION => "GL_INVALID_FRAMEBUFFER_OPERATION",
+        gl::OUT_OF_MEMORY => "GL_OUT_OF_MEMORY",
+        gl::STACK_UNDERFLOW => "GL_STACK_UNDERFLOW",
+        gl::STACK_OVERFLOW => "GL_STACK_OVERFLOW",
+        _ => "GL_UNKNOWN_ERROR",
+    }
+}
+
+/// Active / désactive les vérifications `gl_check!` à l'exécution.
+pub fn set_gl_checks_enabled(enabled: bool) {
+    GL_CHECKS_ENABLED.store(enabled || cfg!(feature = "gl_debug"), Ordering::Relaxed);
+}
+
+pub fn gl_checks_enabled() -> bool {
+    GL_CHECKS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Nombre de messages de debug OpenGL logués depuis le démarrage.
+pub fn gl_debug_messages_logged() -> u64 {
+    GL_DEBUG_MESSAGES_LOGGED.load(Ordering::Relaxed)
+}
+
+/// Vide la pile `glGetError` et logue chaque erreur (cf. [`gl_check!`]).
+pub fn check_gl_errors(file: &str, line: u32, label: &str) -> usize {
+    if !gl_checks_enabled() {
+        return 0;
+    }
+    let mut errors = 0;
+    loop {
+        let code = unsafe { gl::GetError() };
+        if code == gl::NO_ERROR {
+            break;
+        }
+        errors += 1;
+        error!(
+            "❌ {} (0x{:X}) at {}:{} {}",
+            gl_error_name(code),
+            code,
+            file,
+            line,
+            label
+        );
+        // Contexte perdu ou erreur persistante : évite de boucler indéfiniment
+        if errors >= 16 {
+            break;
+        }
+    }
+    errors
 }
 
 /// Callback OpenGL debug, safe pour Rust
@@ -183,16 +334,16 @@ extern "system" fn gl_debug_callback(
     // Unsafe uniquement pour lire le C string
     let msg = unsafe { CStr::from_ptr(message).to_string_lossy() };
 
-    if severity == gl::DEBUG_SEVERITY_NOTIFICATION {
-        return; // ignore notifications
-    }
+    let level = gl_debug_severity_level(severity);
 
-    // Ne logue qu’une fois par ID
-    let mut logged = LOGGED_IDS.lock().unwrap();
-    if logged.contains(&id) {
+    // Rate limiting par identifiant : évite l'inondation des logs à chaque frame
+    let Some(suppressed) = DEBUG_RATE_LIMITER
+        .lock()
+        .map(|mut limiter| limiter.should_log(id, Instant::now()))
+        .unwrap_or(Some(0))
+    else {
         return;
-    }
-    logged.insert(id);
+    };
 
     let src_str = match source {
         gl::DEBUG_SOURCE_API => "API",
@@ -225,15 +376,22 @@ extern "system" fn gl_debug_callback(
         _ => "Unknown",
     };
 
-    let mut counts = MESSAGE_COUNT.lock().unwrap();
-    let count = counts.entry(id).or_insert(0);
-    *count += 1;
-    if *count == 1 || (*count).is_multiple_of(60) {
-        warn!(
-            "[OpenGL Debug] id: {:X}, source: {}, type: {}, severity: {}, message: {}",
-            id, src_str, type_str, sev_str, msg
-        );
-    }
+    let repeated = if suppressed > 0 {
+        format!(" (+{} repeated)", suppressed)
+    } else {
+        String::new()
+    };
+    log!(
+        level,
+        "[OpenGL Debug] id: {:X}, source: {}, type: {}, severity: {}, message: {}{}",
+        id,
+        src_str,
+        type_str,
+        sev_str,
+        msg,
+        repeated
+    );
+    GL_DEBUG_MESSAGES_LOGGED.fetch_add(1, Ordering::Relaxed);
 }
 
 /// Active le debug OpenGL
@@ -264,6 +422,25 @@ pub unsafe fn setup_opengl_debug() {
     );
 }
 
+/// Indique si le framebuffer par défaut (back buffer de la fenêtre) encode en sRGB.
+///
+/// # Safety
+///
+/// L'appelant doit s'assurer que le contexte OpenGL est valide et actif.
+pub unsafe fn default_framebuffer_is_srgb() -> bool {
+    let mut encoding: GLint = 0;
+    gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+    gl::GetFramebufferAttachmentParameteriv(
+        gl::FRAMEBUFFER,
+        gl::BACK_LEFT,
+        gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+        &mut encoding,
+    );
+    // Le back buffer n'existe pas toujours (fenêtre cachée) : on ignore l'erreur
+    while gl::GetError() != gl::NO_ERROR {}
+    encoding as GLenum == gl::SRGB
+}
+
 /// Formats a byte size into a human-readable string with appropriate units (bytes, KB, MB, GB).
 pub fn format_bytes(size: isize) -> String {
     const KB: f64 = 1024.0;
@@ -443,6 +620,8 @@ mod tests {
         let id = 0x12345678;
         let msg = CString::new("Test debug message").unwrap();
 
+        let before = gl_debug_messages_logged();
+
         // First call
         gl_debug_callback(
             gl::DEBUG_SOURCE_APPLICATION,
@@ -454,19 +633,10 @@ mod tests {
             std::ptr::null_mut(),
         );
 
-        // Check if it's in LOGGED_IDS
-        {
-            let logged = LOGGED_IDS.lock().unwrap();
-            assert!(logged.contains(&id));
-        }
-
-        // Check MESSAGE_COUNT
-        {
-            let counts = MESSAGE_COUNT.lock().unwrap();
-            assert_eq!(counts.get(&id), Some(&1));
-        }
+        // First message for this id is always logged
+        assert_eq!(gl_debug_messages_logged(), before + 1);
 
-        // Second call - should return early due to deduplication
+        // Second call - should be suppressed by the rate limiter
         gl_debug_callback(
             gl::DEBUG_SOURCE_APPLICATION,
             gl::DEBUG_TYPE_ERROR,
@@ -477,10 +647,47 @@ mod tests {
             std::ptr::null_mut(),
         );
 
-        // Check MESSAGE_COUNT again - should still be 1
+        // Still a single log entry, the repetition is counted as suppressed
+        assert_eq!(gl_debug_messages_logged(), before + 1);
         {
-            let counts = MESSAGE_COUNT.lock().unwrap();
-            assert_eq!(counts.get(&id), Some(&1));
+            let limiter = DEBUG_RATE_LIMITER.lock().unwrap();
+            assert_eq!(limiter.entries.get(&id).map(|e| e.1), Some(1));
         }
     }
+
+    #[test]
+    fn test_debug_rate_limiter_interval() {
+        let mut limiter = DebugRateLimiter::new(Duration::from_secs(1));
+        let t0 = Instant::now();
+        assert_eq!(limiter.should_log(1, t0), Some(0));
+        assert_eq!(limiter.should_log(1, t0 + Duration::from_millis(100)), None);
+        assert_eq!(limiter.should_log(1, t0 + Duration::from_millis(200)), None);
+        // Autre identifiant : indépendant
+        assert_eq!(limiter.should_log(2, t0), Some(0));
+        // Intervalle écoulé : logué avec le nombre de répétitions supprimées
+        assert_eq!(limiter.should_log(1, t0 + Duration::from_secs(1)), Some(2));
+        assert_eq!(
+            limiter.should_log(1, t0 + Duration::from_millis(1500)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gl_debug_severity_and_error_names() {
+        assert_eq!(
+            gl_debug_severity_level(gl::DEBUG_SEVERITY_HIGH),
+            Level::Error
+        );
+        assert_eq!(
+            gl_debug_severity_level(gl::DEBUG_SEVERITY_MEDIUM),
+            Level::Warn
+        );
+        assert_eq!(gl_debug_severity_level(gl::DEBUG_SEVERITY_LOW), Level::Info);
+        assert_eq!(
+            gl_debug_severity_level(gl::DEBUG_SEVERITY_NOTIFICATION),
+            Level::Debug
+        );
+        assert_eq!(gl_error_name(gl::INVALID_ENUM), "GL_INVALID_ENUM");
+        assert_eq!(gl_error_name(0xFFFF), "GL_UNKNOWN_ERROR");
+    }
 }
@@ -1,6 +1,11 @@
 use crate::audio_engine::AudioEngine;
+use crate::duration_limit::DurationLimit;
 use crate::physic_engine::PhysicEngineFull;
+use crate::profiler::Profiler;
 use crate::renderer_engine::command_console::CommandRegistry;
+use crate::renderer_engine::flash::Flash;
+use crate::renderer_engine::render_stats::RenderStats;
+use crate::session::Session;
 
 use anyhow::Result;
 
@@ -12,4 +17,34 @@ pub trait RendererEngine {
         commands_registry: &CommandRegistry,
     ) -> Result<()>;
     fn close(&mut self);
+
+    /// Enregistre les commandes console propres au renderer (`renderer.*`).
+    fn register_commands(&self, _registry: &mut CommandRegistry) {}
+
+    /// Compteurs de rendu de la dernière frame.
+    fn render_stats(&self) -> RenderStats {
+        RenderStats::default()
+    }
+
+    /// `run_loop` s'arrête après `frames` frames (`None` : jusqu'à la fermeture).
+    fn set_frame_limit(&mut self, _frames: Option<u64>) {}
+
+    /// `run_loop` s'arrête à la fin du spectacle (`--duration`, cf. `DurationLimit`).
+    fn set_duration_limit(&mut self, _limit: Option<DurationLimit>) {}
+
+    /// Éclair d'une détonation survenue hors de `run_loop` (`Simulator::step`).
+    fn add_flash(&mut self, _flash: Flash) {}
+
+    /// Profiler de la boucle de rendu (export des métriques), s'il y en a un.
+    fn profiler(&self) -> Option<&Profiler> {
+        None
+    }
+
+    /// Recopie dans `session` la fenêtre, la caméra et les réglages modifiés à
+    /// chaud (appelé avant `close`).
+    fn save_session(&self, _session: &mut Session) {}
+
+    /// Réapplique la partie renderer d'une session (position de la fenêtre,
+    /// caméra, réglages) ; la taille et le plein écran passent par `AppOptions`.
+    fn restore_session(&mut self, _session: &Session) {}
 }
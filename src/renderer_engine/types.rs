@@ -2,6 +2,24 @@ use gl::types::*;
 use memoffset::offset_of;
 use std::mem;
 
+use crate::physic_engine::Particle;
+
+/// Distance focale (en pixels "monde") de la projection pseudo-3D.
+///
+/// Une particule à la profondeur `DEPTH_FOCAL_LENGTH` est affichée deux fois
+/// plus petite qu'une particule au premier plan (profondeur 0).
+pub const DEPTH_FOCAL_LENGTH: f32 = 600.0;
+
+/// Convertit une profondeur en facteur d'échelle (taille / luminosité).
+///
+/// Projection perspective simple : `f / (f + depth)`.
+/// - `depth = 0` → `1.0` (premier plan, comportement 2D historique)
+/// - `depth → ∞` → `0.0`
+#[inline(always)]
+pub fn depth_to_scale(depth: f32) -> f32 {
+    DEPTH_FOCAL_LENGTH / (DEPTH_FOCAL_LENGTH + depth.max(0.0))
+}
+
 /// Structure envoyée au GPU représentant une particule.
 ///
 /// Chaque instance de `ParticleGPU` correspond à un *vertex* (ou une particule)
@@ -33,6 +51,8 @@ use std::mem;
 /// | `3`       | `float`| `max_life`                |
 /// | `4`       | `float`| `size`                    |
 /// | `5`       | `float`| `angle`                   |
+/// | `6`       | `float`| `depth_scale`             |
+/// | `7`       | `vec2` | `vel_x`, `vel_y`          |
 #[repr(C)] // garantit un layout C-compatible pour l’envoi GPU
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ParticleGPU {
@@ -62,8 +82,93 @@ pub struct ParticleGPU {
 
     /// Angle de rotation de la particule.
     pub angle: f32,
+
+    /// Facteur d'échelle dérivé de la profondeur (cf. [`depth_to_scale`]).
+    pub depth_scale: f32,
+
+    /// Vitesse (px/s), utilisée pour le flou de mouvement.
+    pub vel_x: f32,
+    pub vel_y: f32,
+
+    /// Variante de sprite dans l'atlas (cf. `utils::atlas::sprite_rect`).
+    pub sprite: f32,
+}
+
+impl From<&Particle> for ParticleGPU {
+    #[inline(always)]
+    fn from(p: &Particle) -> Self {
+        Self {
+            pos_x: p.pos.x,
+            pos_y: p.pos.y,
+            col_r: p.color.x,
+            col_g: p.color.y,
+            col_b: p.color.z,
+            life: p.life,
+            max_life: p.max_life,
+            size: p.size,
+            angle: p.angle,
+            depth_scale: depth_to_scale(p.depth),
+            vel_x: p.vel.x,
+            vel_y: p.vel.y,
+            sprite: p.sprite as f32,
+        }
+    }
 }
 
+/// Attribut de sommet flottant lu dans un `ParticleGPU`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttrib {
+    /// `layout(location = …)` dans le shader
+    pub location: GLuint,
+    /// Nombre de `float` (1 à 4)
+    pub components: GLint,
+    /// Décalage en octets dans `ParticleGPU`
+    pub offset: usize,
+}
+
+/// Pas entre deux particules dans le buffer instancié
+pub const INSTANCED_STRIDE: usize = mem::size_of::<ParticleGPU>();
+
+/// Attributs instanciés du shader de quads (la location 0 est le quad unité).
+pub const INSTANCED_ATTRIBS: [VertexAttrib; 6] = [
+    // position (vec2)
+    VertexAttrib {
+        location: 1,
+        components: 2,
+        offset: mem::offset_of!(ParticleGPU, pos_x),
+    },
+    // couleur (vec3)
+    VertexAttrib {
+        location: 2,
+        components: 3,
+        offset: mem::offset_of!(ParticleGPU, col_r),
+    },
+    // vie, vie max, taille, angle (vec4)
+    VertexAttrib {
+        location: 3,
+        components: 4,
+        offset: mem::offset_of!(ParticleGPU, life),
+    },
+    // facteur d'échelle lié à la profondeur (float)
+    VertexAttrib {
+        location: 4,
+        components: 1,
+        offset: mem::offset_of!(ParticleGPU, depth_scale),
+    },
+    // vitesse (vec2) : flou de mouvement et traînée des fusées
+    VertexAttrib {
+        location: 5,
+        components: 2,
+        offset: mem::offset_of!(ParticleGPU, vel_x),
+    },
+    // variante de sprite de l'atlas (float)
+    VertexAttrib {
+        location: 6,
+        components: 1,
+        offset: mem::offset_of!(ParticleGPU, sprite),
+    },
+];
+
 impl ParticleGPU {
     /// Configure les attributs de sommets (vertex attributes) pour OpenGL.
     ///
@@ -107,48 +212,42 @@ impl ParticleGPU {
                 offset_of!(Self, life) as *const _,
             );
             gl::EnableVertexAttribArray(2);
-        }
-    }
-
-    pub fn setup_vertex_attribs_for_instanced_quad() {
-        let stride = std::mem::size_of::<Self>() as GLsizei;
 
-        unsafe {
-            // layout(location = 1) : position (vec2)
+            // Attribut 3 : facteur d'échelle lié à la profondeur
             gl::VertexAttribPointer(
+                3,
                 1,
-                2,
                 gl::FLOAT,
                 gl::FALSE,
                 stride,
-                offset_of!(Self, pos_x) as *const _,
+                offset_of!(Self, depth_scale) as *const _,
             );
-            gl::EnableVertexAttribArray(1);
-            gl::VertexAttribDivisor(1, 1); // 🔑 une fois par particule
+            gl::EnableVertexAttribArray(3);
+        }
+    }
 
-            // layout(location = 2) : couleur (vec3)
-            gl::VertexAttribPointer(
-                2,
-                3,
-                gl::FLOAT,
-                gl::FALSE,
-                stride,
-                offset_of!(Self, col_r) as *const _,
-            );
-            gl::EnableVertexAttribArray(2);
-            gl::VertexAttribDivisor(2, 1);
+    pub fn setup_vertex_attribs_for_instanced_quad() {
+        Self::setup_vertex_attribs_for_instanced_quad_at(0);
+    }
 
-            // layout(location = 3) : vie (float), vie max (float), taille (float), angle (float)
-            gl::VertexAttribPointer(
-                3,
-                4,
-                gl::FLOAT,
-                gl::FALSE,
-                stride,
-                offset_of!(Self, life) as *const _,
-            );
-            gl::EnableVertexAttribArray(3);
-            gl::VertexAttribDivisor(3, 1);
+    /// Attributs instanciés lus à partir de `base_offset` octets dans le VBO lié
+    /// (région courante d'un buffer multi-régions).
+    pub fn setup_vertex_attribs_for_instanced_quad_at(base_offset: usize) {
+        let stride = INSTANCED_STRIDE as GLsizei;
+
+        unsafe {
+            for attrib in &INSTANCED_ATTRIBS {
+                gl::VertexAttribPointer(
+                    attrib.location,
+                    attrib.components,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    stride,
+                    (base_offset + attrib.offset) as *const _,
+                );
+                gl::EnableVertexAttribArray(attrib.location);
+                gl::VertexAttribDivisor(attrib.location, 1); // 🔑 une fois par particule
+            }
         }
     }
 }
@@ -1,6 +1,13 @@
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::time::{Duration, Instant};
 
+/// Seuil par défaut des pics : durée de frame > `2 ×` la médiane
+pub const DEFAULT_SPIKE_FACTOR: f32 = 2.0;
+/// Marqueur d'un pic dans la timeline des durées de frame
+pub const SPIKE_MARKER: char = '!';
+/// Rampe de la timeline du 1% low (du plus bas au plus haut)
+const LOW_RAMP: &[u8] = b"_.:-=+*#";
+
 /// ----------------------------------------------------------------------------
 /// # AdaptiveSampler
 ///
@@ -74,6 +81,9 @@ pub struct AdaptiveSampler {
     /// Historique des samples capturés :
     /// (temps écoulé depuis le début de la fenêtre, fps mesuré)
     pub samples: Vec<(f32, f32)>,
+
+    /// Durées de frame des mêmes samples : (temps écoulé, durée en ms)
+    pub frame_times: Vec<(f32, f32)>,
 }
 
 impl AdaptiveSampler {
@@ -95,6 +105,7 @@ impl AdaptiveSampler {
             avg_dt: 1.0 / initial_fps_guess,
             alpha: 0.15, // pondération pour la moyenne glissante (EMA)
             samples: Vec::with_capacity(target_samples),
+            frame_times: Vec::with_capacity(target_samples),
         }
     }
 
@@ -145,6 +156,7 @@ impl AdaptiveSampler {
             self.samples_taken += 1;
             self.samples
                 .push((elapsed.as_secs_f32(), 1.0 / dt.max(0.00001))); // (temps, FPS instantané)
+            self.frame_times.push((elapsed.as_secs_f32(), dt * 1000.0)); // (temps, durée en ms)
         }
 
         take
@@ -158,6 +170,7 @@ impl AdaptiveSampler {
     pub fn reset(&mut self) {
         self.samples_taken = 0;
         self.samples.clear();
+        self.frame_times.clear();
         self.window_start = Instant::now();
     }
 }
@@ -191,3 +204,173 @@ pub fn ascii_sample_timeline(
         line.into_iter().collect::<String>()
     )
 }
+
+/// Colonne de la timeline pour un sample au temps `t`
+fn timeline_column(t: f32, window_secs: f32, width: usize) -> usize {
+    ((t / window_secs) * (width as f32 - 1.0)).round() as usize
+}
+
+fn timeline_header(window_secs: f32, width: usize) -> String {
+    let end = format!("{}s", window_secs);
+    format!(
+        "[0s{}{}]",
+        ".".repeat(width.saturating_sub(2 + end.len())),
+        end
+    )
+}
+
+/// Médiane d'une série (`None` si vide)
+pub fn median(values: &[f32]) -> Option<f32> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let mid = sorted.len() / 2;
+    Some(if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    })
+}
+
+/// Pics d'une série de durées de frame : samples au-delà de `factor ×` la médiane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpikeReport {
+    pub median_ms: f32,
+    pub threshold_ms: f32,
+    /// Indices des samples en pic
+    pub spikes: Vec<usize>,
+}
+
+impl SpikeReport {
+    pub fn detect(frame_times_ms: &[f32], factor: f32) -> Option<Self> {
+        let median_ms = median(frame_times_ms)?;
+        let threshold_ms = median_ms * factor;
+        let spikes = frame_times_ms
+            .iter()
+            .enumerate()
+            .filter(|(_, ms)| **ms > threshold_ms)
+            .map(|(i, _)| i)
+            .collect();
+        Some(Self {
+            median_ms,
+            threshold_ms,
+            spikes,
+        })
+    }
+}
+
+/// Timeline des durées de frame : `#` par sample, `!` pour un pic (prioritaire
+/// sur la même colonne), et le nombre de pics en légende.
+pub fn ascii_frame_time_timeline(
+    frame_times: &[(f32, f32)], // (timestamp, ms)
+    window_secs: f32,
+    width: usize,
+    spike_factor: f32,
+) -> String {
+    let values: Vec<f32> = frame_times.iter().map(|(_, ms)| *ms).collect();
+    let Some(report) = SpikeReport::detect(&values, spike_factor) else {
+        return format!(
+            "{}\n|{}|\nNo frame sampled",
+            timeline_header(window_secs, width),
+            ".".repeat(width)
+        );
+    };
+
+    let mut line = vec!['.'; width];
+    for (i, &(t, _)) in frame_times.iter().enumerate() {
+        let pos = timeline_column(t, window_secs, width);
+        if pos < width && line[pos] != SPIKE_MARKER {
+            line[pos] = if report.spikes.contains(&i) {
+                SPIKE_MARKER
+            } else {
+                '#'
+            };
+        }
+    }
+
+    format!(
+        "{}\n|{}|\nSpikes (> {:.1}x median {:.2} ms): {}",
+        timeline_header(window_secs, width),
+        line.into_iter().collect::<String>(),
+        spike_factor,
+        report.median_ms,
+        report.spikes.len()
+    )
+}
+
+/// 1% low (FPS) : FPS moyen du 1 % des frames les plus lentes (au moins une).
+pub fn one_percent_low(frame_times_ms: &[f32]) -> Option<f32> {
+    if frame_times_ms.is_empty() {
+        return None;
+    }
+    let mut sorted = frame_times_ms.to_vec();
+    sorted.sort_by(|a, b| b.total_cmp(a));
+    let worst = &sorted[..sorted.len().div_ceil(100)];
+    let avg_ms = worst.iter().sum::<f32>() / worst.len() as f32;
+    Some(1000.0 / avg_ms.max(1e-3))
+}
+
+/// 1% low sur une fenêtre glissante de `sliding_secs` centrée sur chaque colonne.
+/// Retourne une valeur par colonne (`None` sans sample dans la fenêtre).
+pub fn sliding_one_percent_low(
+    frame_times: &[(f32, f32)], // (timestamp, ms)
+    window_secs: f32,
+    width: usize,
+    sliding_secs: f32,
+) -> Vec<Option<f32>> {
+    (0..width)
+        .map(|col| {
+            let center = window_secs * col as f32 / (width as f32 - 1.0).max(1.0);
+            let in_window: Vec<f32> = frame_times
+                .iter()
+                .filter(|(t, _)| (t - center).abs() <= sliding_secs / 2.0)
+                .map(|(_, ms)| *ms)
+                .collect();
+            one_percent_low(&in_window)
+        })
+        .collect()
+}
+
+/// Timeline du 1% low glissant : rampe `_.:-=+*#` du plus bas au plus haut
+/// (espace sans sample), bornes en légende.
+pub fn ascii_one_percent_low_timeline(
+    frame_times: &[(f32, f32)], // (timestamp, ms)
+    window_secs: f32,
+    width: usize,
+    sliding_secs: f32,
+) -> String {
+    let lows = sliding_one_percent_low(frame_times, window_secs, width, sliding_secs);
+    let known: Vec<f32> = lows.iter().flatten().copied().collect();
+    let (min, max) = known
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(*v), hi.max(*v))
+        });
+    let line: String = lows
+        .iter()
+        .map(|low| match low {
+            None => ' ',
+            Some(v) if max > min => {
+                let level = ((v - min) / (max - min) * (LOW_RAMP.len() - 1) as f32).round();
+                LOW_RAMP[level as usize] as char
+            }
+            Some(_) => LOW_RAMP[LOW_RAMP.len() - 1] as char,
+        })
+        .collect();
+    let caption = if known.is_empty() {
+        "1% low: no frame sampled".to_string()
+    } else {
+        format!(
+            "1% low ({}s sliding): min {:.1} FPS | max {:.1} FPS",
+            sliding_secs, min, max
+        )
+    };
+    format!(
+        "{}\n|{}|\n{}",
+        timeline_header(window_secs, width),
+        line,
+        caption
+    )
+}
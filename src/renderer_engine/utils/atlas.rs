@@ -0,0 +1,170 @@
+//! Atlas de sprites : une texture regroupant plusieurs variantes de sprite et un
+//! descripteur (TOML ou JSON) de leurs rectangles en pixels.
+//!
+//! Chaque particule porte un indice de sprite (`Particle::sprite`) ; le shader
+//! instancié le ramène au nombre de sprites de l'atlas (modulo) et en déduit le
+//! décalage et l'échelle UV de son quad (cf. [`sprite_rect`]), le tout dans un
+//! seul appel de dessin. Sans atlas, la texture entière est utilisée.
+//!
+//! ```toml
+//! texture = "assets/textures/atlas/explosion_atlas.png"
+//!
+//! [[sprites]]
+//! name = "soft_dot"
+//! rect = [0, 0, 128, 128] # x, y (depuis le haut), largeur, hauteur
+//! ```
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::embedded_assets::read_asset_to_string;
+
+/// Sprites d'un atlas (taille du tableau d'uniformes `uSpriteRects`)
+pub const MAX_ATLAS_SPRITES: usize = 16;
+
+/// Variante de sprite : son nom et son rectangle dans la texture.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AtlasSprite {
+    pub name: String,
+    /// x, y (depuis le coin haut gauche de l'image), largeur, hauteur, en pixels
+    pub rect: [u32; 4],
+}
+
+/// Descripteur d'atlas : texture et rectangles des sprites.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AtlasDescriptor {
+    /// Texture de l'atlas
+    pub texture: String,
+    pub sprites: Vec<AtlasSprite>,
+}
+
+impl AtlasDescriptor {
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let descriptor: Self = toml::from_str(text).context("Invalid atlas descriptor")?;
+        descriptor.validate()?;
+        Ok(descriptor)
+    }
+
+    pub fn from_json(text: &str) -> Result<Self> {
+        let descriptor: Self = serde_json::from_str(text).context("Invalid atlas descriptor")?;
+        descriptor.validate()?;
+        Ok(descriptor)
+    }
+
+    /// Analyse `text` selon l'extension de `path` (`.json`, sinon TOML).
+    pub fn parse(text: &str, path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json(text),
+            _ => Self::from_toml(text),
+        }
+    }
+
+    /// Lit le descripteur `path` (disque, sinon copie embarquée).
+    pub fn load(path: &str) -> Result<Self> {
+        let text = read_asset_to_string(path)
+            .with_context(|| format!("Cannot read atlas descriptor {}", path))?;
+        Self::parse(&text, Path::new(path)).with_context(|| format!("In {}", path))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.sprites.is_empty() {
+            bail!("atlas has no sprite");
+        }
+        if self.sprites.len() > MAX_ATLAS_SPRITES {
+            bail!(
+                "atlas has {} sprites, at most {} are supported",
+                self.sprites.len(),
+                MAX_ATLAS_SPRITES
+            );
+        }
+        if let Some(sprite) = self
+            .sprites
+            .iter()
+            .find(|s| s.rect[2] == 0 || s.rect[3] == 0)
+        {
+            bail!("sprite '{}' has an empty rect", sprite.name);
+        }
+        Ok(())
+    }
+
+    /// Indice du sprite `name`
+    pub fn sprite_index(&self, name: &str) -> Option<usize> {
+        self.sprites.iter().position(|s| s.name == name)
+    }
+
+    /// Rectangles UV des sprites pour une texture de `width`×`height` pixels.
+    ///
+    /// Échoue si un rectangle déborde de la texture (descripteur et image désaccordés).
+    pub fn uv_rects(&self, width: u32, height: u32) -> Result<Vec<UvRect>> {
+        self.sprites
+            .iter()
+            .map(|sprite| {
+                let [x, y, w, h] = sprite.rect;
+                if x as u64 + w as u64 > width as u64 || y as u64 + h as u64 > height as u64 {
+                    bail!(
+                        "sprite '{}' {:?} exceeds the {}x{} texture",
+                        sprite.name,
+                        sprite.rect,
+                        width,
+                        height
+                    );
+                }
+                Ok(UvRect::from_pixels(sprite.rect, width, height))
+            })
+            .collect()
+    }
+}
+
+/// Rectangle d'un sprite en coordonnées de texture : `uv = offset + quad_uv × scale`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvRect {
+    pub offset: Vec2,
+    pub scale: Vec2,
+}
+
+impl UvRect {
+    /// Texture entière (sans atlas)
+    pub const FULL: Self = Self {
+        offset: Vec2::ZERO,
+        scale: Vec2::ONE,
+    };
+
+    /// Rectangle `[x, y, w, h]` en pixels (origine en haut à gauche de l'image).
+    ///
+    /// Les textures sont retournées au chargement (origine OpenGL en bas à
+    /// gauche) : le bas du rectangle donne le `v` de départ.
+    pub fn from_pixels([x, y, w, h]: [u32; 4], width: u32, height: u32) -> Self {
+        let (width, height) = (width.max(1) as f32, height.max(1) as f32);
+        Self {
+            offset: Vec2::new(x as f32 / width, 1.0 - (y + h) as f32 / height),
+            scale: Vec2::new(w as f32 / width, h as f32 / height),
+        }
+    }
+
+    /// Coordonnée de texture du point `quad_uv` (0..1) du sprite
+    pub fn apply(&self, quad_uv: Vec2) -> Vec2 {
+        self.offset + quad_uv * self.scale
+    }
+
+    /// Rapport largeur / hauteur du sprite dans une texture de rapport `texture_ratio`
+    pub fn aspect_ratio(&self, texture_ratio: f32) -> f32 {
+        texture_ratio * self.scale.x / self.scale.y.max(f32::EPSILON)
+    }
+
+    /// `vec4(offset, scale)` pour l'uniforme `uSpriteRects`
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.offset.x, self.offset.y, self.scale.x, self.scale.y]
+    }
+}
+
+/// Rectangle du sprite `sprite` parmi `rects` (indice ramené modulo leur nombre),
+/// identique au calcul du shader ; texture entière sans atlas.
+pub fn sprite_rect(rects: &[UvRect], sprite: u8) -> UvRect {
+    if rects.is_empty() {
+        return UvRect::FULL;
+    }
+    rects[sprite as usize % rects.len()]
+}
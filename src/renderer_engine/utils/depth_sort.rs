@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+use crate::physic_engine::particle::Particle;
+use crate::renderer_engine::types::ParticleGPU;
+
+/// Clé de tri d'une particule : sa profondeur (0.0 = plan le plus proche).
+#[inline(always)]
+pub fn sort_key(p: &Particle) -> f32 {
+    p.depth
+}
+
+/// Ordre de dessin « du fond vers l'avant » sur des couples `(clé, indice)`.
+/// À profondeur égale, l'ordre d'émission est conservé (tri stable, pas de scintillement).
+#[inline(always)]
+pub fn back_to_front(a: &(f32, u32), b: &(f32, u32)) -> Ordering {
+    b.0.total_cmp(&a.0).then(a.1.cmp(&b.1))
+}
+
+/// Tri des particules avant l'écriture dans le buffer GPU mappé,
+/// nécessaire au mélange alpha (fumée) ; les buffers sont réutilisés d'une frame à l'autre.
+#[derive(Debug, Default)]
+pub struct DepthSorter {
+    staging: Vec<ParticleGPU>,
+    order: Vec<(f32, u32)>,
+    last_duration: Duration,
+}
+
+impl DepthSorter {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            staging: Vec::with_capacity(capacity),
+            order: Vec::with_capacity(capacity),
+            last_duration: Duration::ZERO,
+        }
+    }
+
+    /// Écrit au plus `out.len()` particules dans `out`, de la plus lointaine à la plus proche.
+    /// Retourne le nombre de particules écrites.
+    pub fn fill_sorted<'a, I>(&mut self, particles: I, out: &mut [ParticleGPU]) -> usize
+    where
+        I: Iterator<Item = &'a Particle>,
+    {
+        let start = Instant::now();
+        self.staging.clear();
+        self.order.clear();
+
+        for (i, p) in particles.take(out.len()).enumerate() {
+            self.staging.push(ParticleGPU::from(p));
+            self.order.push((sort_key(p), i as u32));
+        }
+        self.order.sort_unstable_by(back_to_front);
+
+        for (dst, &(_, i)) in out.iter_mut().zip(&self.order) {
+            *dst = self.staging[i as usize];
+        }
+
+        self.last_duration = start.elapsed();
+        self.order.len()
+    }
+
+    /// Durée du dernier tri (0 si aucun tri n'a eu lieu).
+    pub fn last_duration(&self) -> Duration {
+        self.last_duration
+    }
+
+    /// Remet la mesure à zéro (frame rendue sans tri).
+    pub fn skip(&mut self) {
+        self.last_duration = Duration::ZERO;
+    }
+
+    /// Capacité des buffers de travail (indépendante du nombre de frames triées).
+    pub fn capacity(&self) -> usize {
+        self.order.capacity()
+    }
+}
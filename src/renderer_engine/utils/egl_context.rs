@@ -0,0 +1,68 @@
+//! Contexte OpenGL sans affichage (feature `headless_egl`).
+//!
+//! EGL « surfaceless » ouvert directement sur un périphérique
+//! (`EGL_EXT_platform_device`) : ni serveur X ni Wayland, le rendu ne vise que
+//! des FBO. Le rasteriseur logiciel de Mesa (llvmpipe) est préféré quand il est
+//! présent, pour que les images de référence ne dépendent pas du GPU de la machine.
+
+use anyhow::{anyhow, Context, Result};
+use glutin::api::egl::{context::PossiblyCurrentContext, device::Device, display::Display};
+use glutin::config::{ConfigSurfaceTypes, ConfigTemplateBuilder};
+use glutin::context::{ContextApi, ContextAttributesBuilder, GlProfile, Version};
+use glutin::display::GlDisplay;
+use log::info;
+use std::ffi::CString;
+
+/// Extension EGL du périphérique logiciel de Mesa
+const MESA_SOFTWARE_DEVICE: &str = "EGL_MESA_device_software";
+
+/// Contexte OpenGL 3.3 core courant sur le thread qui l'a créé, sans surface.
+///
+/// Les fonctions OpenGL sont chargées à la création ; le contexte doit survivre
+/// aux ressources GL qui en dépendent.
+pub struct SurfacelessContext {
+    _context: PossiblyCurrentContext,
+    _display: Display,
+}
+
+impl SurfacelessContext {
+    pub fn new(gl_debug: bool) -> Result<Self> {
+        let devices: Vec<Device> = Device::query_devices()
+            .context("EGL device enumeration unavailable")?
+            .collect();
+        let device = devices
+            .iter()
+            .find(|device| device.extensions().contains(MESA_SOFTWARE_DEVICE))
+            .or(devices.first())
+            .ok_or_else(|| anyhow!("No EGL device found"))?;
+        let display = unsafe { Display::with_device(device, None)? };
+
+        let template = ConfigTemplateBuilder::new()
+            .with_surface_type(ConfigSurfaceTypes::empty())
+            .build();
+        let config = unsafe { display.find_configs(template)? }
+            .next()
+            .ok_or_else(|| anyhow!("No surfaceless EGL config"))?;
+        let attributes = ContextAttributesBuilder::new()
+            .with_context_api(ContextApi::OpenGl(Some(Version::new(3, 3))))
+            .with_profile(GlProfile::Core)
+            .with_debug(gl_debug)
+            .build(None);
+        let context =
+            unsafe { display.create_context(&config, &attributes)? }.make_current_surfaceless()?;
+
+        gl::load_with(|symbol| {
+            let symbol = CString::new(symbol).expect("Nom de fonction OpenGL invalide");
+            display.get_proc_address(&symbol) as *const _
+        });
+        info!(
+            "✅ Surfaceless EGL context ready ({})",
+            device.name().unwrap_or("unnamed device")
+        );
+
+        Ok(Self {
+            _context: context,
+            _display: display,
+        })
+    }
+}
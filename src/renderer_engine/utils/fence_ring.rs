@@ -0,0 +1,149 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use gl::types::GLsync;
+use log::warn;
+
+use crate::gl_check;
+
+/// Nombre de régions d'un buffer persistant (triple buffering)
+pub const BUFFER_REGIONS: usize = 3;
+/// Attente maximale sur une fence avant de réécrire quand même la région
+pub const FENCE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Tourniquet des régions d'un buffer découpé en `regions` parts égales.
+#[derive(Debug, Clone)]
+pub struct RegionRing {
+    regions: usize,
+    current: usize,
+}
+
+impl RegionRing {
+    pub fn new(regions: usize) -> Self {
+        let regions = regions.max(1);
+        // Le premier `advance` tombe sur la région 0
+        Self {
+            regions,
+            current: regions - 1,
+        }
+    }
+
+    pub fn regions(&self) -> usize {
+        self.regions
+    }
+
+    /// Région en cours d'écriture / de dessin
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Passe à la région suivante et la retourne
+    pub fn advance(&mut self) -> usize {
+        self.current = (self.current + 1) % self.regions;
+        self.current
+    }
+
+    /// Décalage (octets ou éléments) du début de la région courante
+    pub fn offset(&self, region_size: usize) -> usize {
+        self.current * region_size
+    }
+}
+
+/// Régions d'un buffer persistant protégées par des fences GPU : on n'écrit
+/// dans une région qu'une fois terminé le dessin qui la lisait.
+pub struct FenceRing {
+    ring: RegionRing,
+    fences: Vec<Cell<GLsync>>,
+    last_wait: Duration,
+    stalls: usize,
+}
+
+impl FenceRing {
+    pub fn new(regions: usize) -> Self {
+        let ring = RegionRing::new(regions);
+        Self {
+            fences: (0..ring.regions())
+                .map(|_| Cell::new(std::ptr::null()))
+                .collect(),
+            ring,
+            last_wait: Duration::ZERO,
+            stalls: 0,
+        }
+    }
+
+    pub fn ring(&self) -> &RegionRing {
+        &self.ring
+    }
+
+    /// Durée d'attente CPU lors du dernier `acquire_next`
+    pub fn last_wait(&self) -> Duration {
+        self.last_wait
+    }
+
+    /// Nombre d'acquisitions ayant dû attendre le GPU
+    pub fn stalls(&self) -> usize {
+        self.stalls
+    }
+
+    /// Passe à la région suivante et attend que le GPU ait fini de la lire.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn acquire_next(&mut self) -> usize {
+        let region = self.ring.advance();
+        let fence = self.fences[region].replace(std::ptr::null());
+        self.last_wait = Duration::ZERO;
+        if fence.is_null() {
+            return region;
+        }
+
+        let start = Instant::now();
+        let status = gl::ClientWaitSync(
+            fence,
+            gl::SYNC_FLUSH_COMMANDS_BIT,
+            FENCE_TIMEOUT.as_nanos() as u64,
+        );
+        match status {
+            gl::ALREADY_SIGNALED => {}
+            gl::CONDITION_SATISFIED => {
+                self.last_wait = start.elapsed();
+                self.stalls += 1;
+            }
+            gl::TIMEOUT_EXPIRED => {
+                self.last_wait = start.elapsed();
+                self.stalls += 1;
+                warn!(
+                    "⚠️ GPU fence not signaled after {:?} (region {}), writing anyway",
+                    FENCE_TIMEOUT, region
+                );
+            }
+            _ => warn!("⚠️ glClientWaitSync failed (region {})", region),
+        }
+        gl::DeleteSync(fence);
+        region
+    }
+
+    /// Pose une fence après le dessin lisant la région courante.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn fence_current(&self) {
+        let cell = &self.fences[self.ring.current()];
+        let previous = cell.replace(gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0));
+        if !previous.is_null() {
+            gl::DeleteSync(previous);
+        }
+        gl_check!("particle buffer fence");
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn delete(&mut self) {
+        for cell in &self.fences {
+            let fence = cell.replace(std::ptr::null());
+            if !fence.is_null() {
+                gl::DeleteSync(fence);
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+/// Fin d'attente en attente active : `thread::sleep` peut dépasser de ~1 ms
+pub const SPIN_MARGIN: Duration = Duration::from_millis(2);
+
+/// Durée cible d'une frame pour un plafond de `max_fps` images/s (`None` : pas de plafond).
+pub fn frame_budget(max_fps: Option<u32>) -> Option<Duration> {
+    max_fps
+        .filter(|&fps| fps > 0)
+        .map(|fps| Duration::from_secs_f64(1.0 / fps as f64))
+}
+
+/// Répartition de l'attente restante pour une frame ayant duré `elapsed` :
+/// `(sommeil, attente active)`. Rien à attendre si la frame a dépassé son budget.
+pub fn wait_plan(
+    elapsed: Duration,
+    budget: Duration,
+    spin_margin: Duration,
+) -> (Duration, Duration) {
+    let remaining = budget.saturating_sub(elapsed);
+    let sleep = remaining.saturating_sub(spin_margin);
+    (sleep, remaining - sleep)
+}
+
+/// Limiteur de fréquence côté CPU (utilisé quand la v-sync est désactivée).
+#[derive(Debug)]
+pub struct FrameLimiter {
+    frame_start: Instant,
+}
+
+impl Default for FrameLimiter {
+    fn default() -> Self {
+        Self {
+            frame_start: Instant::now(),
+        }
+    }
+}
+
+impl FrameLimiter {
+    /// Attend la fin du budget de la frame courante puis démarre la suivante.
+    /// Retourne le temps passé à attendre.
+    pub fn wait(&mut self, budget: Option<Duration>) -> Duration {
+        let start = Instant::now();
+        if let Some(budget) = budget {
+            let (sleep, _) = wait_plan(start - self.frame_start, budget, SPIN_MARGIN);
+            if !sleep.is_zero() {
+                std::thread::sleep(sleep);
+            }
+            // Attente active jusqu'à l'échéance exacte
+            let deadline = self.frame_start + budget;
+            while Instant::now() < deadline {
+                std::hint::spin_loop();
+            }
+        }
+        let now = Instant::now();
+        self.frame_start = now;
+        now - start
+    }
+}
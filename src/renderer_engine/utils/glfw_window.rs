@@ -1,6 +1,12 @@
-use glfw::{Monitor, Window, WindowMode};
+use glfw::{Action, Key, Monitor, Window, WindowMode};
 use std::mem::discriminant;
 
+use crate::renderer_engine::fullscreen::{MonitorInfo, WindowPlacement};
+use crate::renderer_engine::gamepad::{
+    GamepadDevice, GamepadInput, GamepadState, TRIGGER_THRESHOLD,
+};
+use crate::renderer_engine::window_event::{KeyCode, MouseButton, WindowEvent};
+
 pub trait CenterWindow {
     fn center_on_primary_monitor(&mut self);
 }
@@ -32,6 +38,8 @@ fn center_window(window: &mut Window, monitor: &Monitor) {
 pub trait Fullscreen {
     fn is_fullscreen(&self) -> bool;
     fn set_fullscreen(&mut self, monitor: &Monitor);
+    /// Applique un placement résolu par `FullscreenState` (index d'écran de `list_monitors`).
+    fn set_placement(&mut self, placement: WindowPlacement);
 }
 
 impl Fullscreen for Window {
@@ -51,4 +59,283 @@ impl Fullscreen for Window {
             );
         }
     }
+
+    fn set_placement(&mut self, placement: WindowPlacement) {
+        match placement {
+            WindowPlacement::Windowed { pos, size } => {
+                self.set_decorated(true);
+                self.set_monitor(
+                    WindowMode::Windowed,
+                    pos.0,
+                    pos.1,
+                    size.0 as u32,
+                    size.1 as u32,
+                    None,
+                );
+            }
+            WindowPlacement::Fullscreen { monitor, .. } => {
+                self.set_decorated(true);
+                let mut glfw = self.glfw.clone();
+                glfw.with_connected_monitors(|_, monitors| {
+                    if let Some(monitor) = monitors.get(monitor) {
+                        self.set_fullscreen(monitor);
+                    }
+                });
+            }
+            WindowPlacement::Borderless { pos, size } => {
+                // Fenêtre ordinaire sans décoration : aucun changement de mode vidéo
+                self.set_decorated(false);
+                self.set_monitor(
+                    WindowMode::Windowed,
+                    pos.0,
+                    pos.1,
+                    size.0 as u32,
+                    size.1 as u32,
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// Écrans connectés, dans l'ordre de glfw (index utilisés par la config et la console).
+pub fn list_monitors(glfw: &mut glfw::Glfw) -> Vec<MonitorInfo> {
+    let primary_pos = glfw.with_primary_monitor(|_, monitor| monitor.map(|m| m.get_pos()));
+    glfw.with_connected_monitors(|_, monitors| {
+        monitors
+            .iter()
+            .map(|monitor| {
+                let mode = monitor.get_video_mode();
+                let position = monitor.get_pos();
+                MonitorInfo {
+                    name: monitor.get_name().unwrap_or_else(|| "unknown".to_string()),
+                    position,
+                    size: mode.map_or((0, 0), |m| (m.width, m.height)),
+                    refresh_rate: mode.map_or(0, |m| m.refresh_rate),
+                    work_area: monitor.get_workarea(),
+                    primary: primary_pos == Some(position),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Icône de la fenêtre depuis une image PNG (en mémoire).
+pub fn set_window_icon(window: &mut Window, png: &[u8]) -> anyhow::Result<()> {
+    let image = image::load_from_memory(png)?.to_rgba8();
+    let pixels = image.pixels().map(|px| u32::from_le_bytes(px.0)).collect();
+    window.set_icon_from_pixels(vec![glfw::PixelImage {
+        width: image.width(),
+        height: image.height(),
+        pixels,
+    }]);
+    Ok(())
+}
+
+pub trait VSync {
+    fn set_vsync(&mut self, enabled: bool);
+}
+
+impl VSync for glfw::Glfw {
+    /// Agit sur le contexte OpenGL courant.
+    fn set_vsync(&mut self, enabled: bool) {
+        self.set_swap_interval(if enabled {
+            glfw::SwapInterval::Sync(1)
+        } else {
+            glfw::SwapInterval::None
+        });
+    }
+}
+
+/// Traduit un événement glfw en événement neutre (`None` : ignoré par le simulateur).
+pub fn translate_event(event: &glfw::WindowEvent) -> Option<WindowEvent> {
+    match *event {
+        glfw::WindowEvent::FramebufferSize(width, height) => {
+            Some(WindowEvent::Resize(width, height))
+        }
+        glfw::WindowEvent::Key(key, _, Action::Press, _) => {
+            translate_key(key).map(WindowEvent::KeyPress)
+        }
+        glfw::WindowEvent::Char(c) => Some(WindowEvent::Char(c)),
+        glfw::WindowEvent::MouseButton(button, action, _) => Some(WindowEvent::MouseButton(
+            translate_mouse_button(button),
+            action == Action::Press,
+        )),
+        glfw::WindowEvent::CursorPos(x, y) => Some(WindowEvent::CursorPos(x as f32, y as f32)),
+        glfw::WindowEvent::Scroll(_, dy) => Some(WindowEvent::Scroll(dy as f32)),
+        glfw::WindowEvent::FileDrop(ref paths) => Some(WindowEvent::FileDrop(paths.clone())),
+        glfw::WindowEvent::Size(width, height) => Some(WindowEvent::WindowSize(width, height)),
+        glfw::WindowEvent::ContentScale(x, y) => Some(WindowEvent::ContentScale(x, y)),
+        glfw::WindowEvent::Iconify(iconified) => Some(WindowEvent::Iconified(iconified)),
+        glfw::WindowEvent::Close => Some(WindowEvent::Close),
+        _ => None,
+    }
+}
+
+/// Boutons glfw et leur équivalent neutre
+const GAMEPAD_BUTTONS: [(glfw::GamepadButton, GamepadInput); 15] = [
+    (glfw::GamepadButton::ButtonA, GamepadInput::A),
+    (glfw::GamepadButton::ButtonB, GamepadInput::B),
+    (glfw::GamepadButton::ButtonX, GamepadInput::X),
+    (glfw::GamepadButton::ButtonY, GamepadInput::Y),
+    (
+        glfw::GamepadButton::ButtonLeftBumper,
+        GamepadInput::LeftBumper,
+    ),
+    (
+        glfw::GamepadButton::ButtonRightBumper,
+        GamepadInput::RightBumper,
+    ),
+    (glfw::GamepadButton::ButtonBack, GamepadInput::Back),
+    (glfw::GamepadButton::ButtonStart, GamepadInput::Start),
+    (glfw::GamepadButton::ButtonGuide, GamepadInput::Guide),
+    (
+        glfw::GamepadButton::ButtonLeftThumb,
+        GamepadInput::LeftThumb,
+    ),
+    (
+        glfw::GamepadButton::ButtonRightThumb,
+        GamepadInput::RightThumb,
+    ),
+    (glfw::GamepadButton::ButtonDpadUp, GamepadInput::DpadUp),
+    (
+        glfw::GamepadButton::ButtonDpadRight,
+        GamepadInput::DpadRight,
+    ),
+    (glfw::GamepadButton::ButtonDpadDown, GamepadInput::DpadDown),
+    (glfw::GamepadButton::ButtonDpadLeft, GamepadInput::DpadLeft),
+];
+
+/// Interroge les 16 emplacements de joystick et retourne les manettes reconnues
+/// (mapping SDL connu de glfw). Un débranchement fait simplement disparaître l'entrée.
+pub fn poll_gamepads(glfw: &glfw::Glfw) -> Vec<GamepadDevice> {
+    (0..=glfw::ffi::JOYSTICK_LAST)
+        .filter_map(glfw::JoystickId::from_i32)
+        .filter_map(|id| {
+            let joystick = glfw.get_joystick(id);
+            if !joystick.is_present() || !joystick.is_gamepad() {
+                return None;
+            }
+            let state = joystick.get_gamepad_state()?;
+            Some(GamepadDevice {
+                id: id as usize,
+                name: joystick
+                    .get_gamepad_name()
+                    .unwrap_or_else(|| "gamepad".to_string()),
+                state: translate_gamepad_state(&state),
+            })
+        })
+        .collect()
+}
+
+fn translate_gamepad_state(state: &glfw::GamepadState) -> GamepadState {
+    let mut pressed: Vec<GamepadInput> = GAMEPAD_BUTTONS
+        .iter()
+        .filter(|(button, _)| state.get_button_state(*button) == Action::Press)
+        .map(|&(_, input)| input)
+        .collect();
+    // Gâchettes : -1 (relâchée) .. 1 (enfoncée)
+    let triggers = [
+        (
+            glfw::GamepadAxis::AxisLeftTrigger,
+            GamepadInput::LeftTrigger,
+        ),
+        (
+            glfw::GamepadAxis::AxisRightTrigger,
+            GamepadInput::RightTrigger,
+        ),
+    ];
+    for (axis, input) in triggers {
+        if (state.get_axis(axis) + 1.0) * 0.5 >= TRIGGER_THRESHOLD {
+            pressed.push(input);
+        }
+    }
+    GamepadState {
+        pressed,
+        // glfw : y vers le bas
+        left_stick: glam::Vec2::new(
+            state.get_axis(glfw::GamepadAxis::AxisLeftX),
+            -state.get_axis(glfw::GamepadAxis::AxisLeftY),
+        ),
+    }
+}
+
+fn translate_mouse_button(button: glfw::MouseButton) -> MouseButton {
+    match button {
+        glfw::MouseButton::Button1 => MouseButton::Left,
+        glfw::MouseButton::Button2 => MouseButton::Right,
+        glfw::MouseButton::Button3 => MouseButton::Middle,
+        _ => MouseButton::Other,
+    }
+}
+
+/// Touche glfw correspondante (`None` : touche sans équivalent neutre).
+pub fn translate_key(key: Key) -> Option<KeyCode> {
+    Some(match key {
+        Key::A => KeyCode::A,
+        Key::B => KeyCode::B,
+        Key::C => KeyCode::C,
+        Key::D => KeyCode::D,
+        Key::E => KeyCode::E,
+        Key::F => KeyCode::F,
+        Key::G => KeyCode::G,
+        Key::H => KeyCode::H,
+        Key::I => KeyCode::I,
+        Key::J => KeyCode::J,
+        Key::K => KeyCode::K,
+        Key::L => KeyCode::L,
+        Key::M => KeyCode::M,
+        Key::N => KeyCode::N,
+        Key::O => KeyCode::O,
+        Key::P => KeyCode::P,
+        Key::Q => KeyCode::Q,
+        Key::R => KeyCode::R,
+        Key::S => KeyCode::S,
+        Key::T => KeyCode::T,
+        Key::U => KeyCode::U,
+        Key::V => KeyCode::V,
+        Key::W => KeyCode::W,
+        Key::X => KeyCode::X,
+        Key::Y => KeyCode::Y,
+        Key::Z => KeyCode::Z,
+        Key::Num0 => KeyCode::Num0,
+        Key::Num1 => KeyCode::Num1,
+        Key::Num2 => KeyCode::Num2,
+        Key::Num3 => KeyCode::Num3,
+        Key::Num4 => KeyCode::Num4,
+        Key::Num5 => KeyCode::Num5,
+        Key::Num6 => KeyCode::Num6,
+        Key::Num7 => KeyCode::Num7,
+        Key::Num8 => KeyCode::Num8,
+        Key::Num9 => KeyCode::Num9,
+        Key::F1 => KeyCode::F1,
+        Key::F2 => KeyCode::F2,
+        Key::F3 => KeyCode::F3,
+        Key::F4 => KeyCode::F4,
+        Key::F5 => KeyCode::F5,
+        Key::F6 => KeyCode::F6,
+        Key::F7 => KeyCode::F7,
+        Key::F8 => KeyCode::F8,
+        Key::F9 => KeyCode::F9,
+        Key::F10 => KeyCode::F10,
+        Key::F11 => KeyCode::F11,
+        Key::F12 => KeyCode::F12,
+        Key::Escape => KeyCode::Escape,
+        Key::Enter => KeyCode::Enter,
+        Key::Tab => KeyCode::Tab,
+        Key::Backspace => KeyCode::Backspace,
+        Key::Space => KeyCode::Space,
+        Key::GraveAccent => KeyCode::GraveAccent,
+        Key::LeftBracket => KeyCode::LeftBracket,
+        Key::RightBracket => KeyCode::RightBracket,
+        Key::Up => KeyCode::Up,
+        Key::Down => KeyCode::Down,
+        Key::Left => KeyCode::Left,
+        Key::Right => KeyCode::Right,
+        Key::PageUp => KeyCode::PageUp,
+        Key::PageDown => KeyCode::PageDown,
+        Key::Home => KeyCode::Home,
+        Key::End => KeyCode::End,
+        _ => return None,
+    })
 }
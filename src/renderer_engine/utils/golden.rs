@@ -0,0 +1,35 @@
+use image::{imageops::FilterType, DynamicImage, RgbaImage};
+
+/// Signature compacte d'une image : luminance réduite à `size × size` pixels.
+///
+/// Le sous-échantillonnage absorbe les petites différences entre drivers
+/// (rastérisation, arrondis) tout en gardant la composition de la scène.
+pub fn image_signature(image: &RgbaImage, size: u32) -> Vec<u8> {
+    DynamicImage::ImageRgba8(image.clone())
+        .resize_exact(size, size, FilterType::Triangle)
+        .to_luma8()
+        .into_raw()
+}
+
+/// Écart moyen normalisé (0 = identiques, 1 = opposées) entre deux signatures.
+pub fn signature_distance(a: &[u8], b: &[u8]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 1.0;
+    }
+    let sum: u64 = a.iter().zip(b).map(|(x, y)| x.abs_diff(*y) as u64).sum();
+    sum as f32 / (a.len() as f32 * 255.0)
+}
+
+/// Encodage hexadécimal, pour stocker une signature dans un fichier texte.
+pub fn signature_to_hex(signature: &[u8]) -> String {
+    signature.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn signature_from_hex(hex: &str) -> anyhow::Result<Vec<u8>> {
+    let hex = hex.trim();
+    anyhow::ensure!(hex.len().is_multiple_of(2), "odd-length hex signature");
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| Ok(u8::from_str_radix(&hex[i..i + 2], 16)?))
+        .collect()
+}
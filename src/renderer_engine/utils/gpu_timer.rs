@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use gl::types::*;
+
+use crate::renderer_engine::utils::fence_ring::RegionRing;
+
+/// Profondeur du tourniquet de requêtes : un résultat est relu deux frames
+/// après sa mesure, quand le GPU l'a presque toujours terminé.
+pub const GPU_TIMER_LATENCY: usize = 3;
+
+/// Les requêtes de chronométrage (`GL_TIME_ELAPSED`) sont-elles disponibles ?
+/// OpenGL 3.3 ou l'extension `GL_ARB_timer_query`.
+pub fn supports_timer_queries(major: i32, minor: i32, extensions: &[&str]) -> bool {
+    (major, minor) >= (3, 3) || extensions.contains(&"GL_ARB_timer_query")
+}
+
+/// Interroge le contexte courant (cf. `supports_timer_queries`).
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn timer_queries_available() -> bool {
+    if !gl::GenQueries::is_loaded() || !gl::GetQueryObjectui64v::is_loaded() {
+        return false;
+    }
+    let (mut major, mut minor, mut count) = (0, 0, 0);
+    gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+    gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+    gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+    let extensions: Vec<String> = (0..count.max(0) as GLuint)
+        .filter_map(|i| {
+            let name = gl::GetStringi(gl::EXTENSIONS, i);
+            (!name.is_null()).then(|| {
+                std::ffi::CStr::from_ptr(name as *const _)
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        })
+        .collect();
+    let extensions: Vec<&str> = extensions.iter().map(String::as_str).collect();
+    supports_timer_queries(major, minor, &extensions)
+}
+
+/// Comptabilité des requêtes en vol d'un chronomètre, sans appel GL.
+///
+/// Chaque frame émet une requête dans le créneau suivant du tourniquet ; le
+/// créneau réutilisé contient la mesure émise `depth` frames plus tôt, à relire
+/// (ou à abandonner si le GPU ne l'a pas terminée).
+#[derive(Debug, Clone)]
+pub struct QueryRing {
+    ring: RegionRing,
+    pending: Vec<bool>,
+    resolved: usize,
+    dropped: usize,
+}
+
+impl QueryRing {
+    pub fn new(depth: usize) -> Self {
+        let ring = RegionRing::new(depth);
+        Self {
+            pending: vec![false; ring.regions()],
+            ring,
+            resolved: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Créneau de la prochaine requête, et s'il contient une mesure à relire d'abord.
+    pub fn begin(&mut self) -> (usize, bool) {
+        let slot = self.ring.advance();
+        let must_resolve = self.pending[slot];
+        self.pending[slot] = true;
+        (slot, must_resolve)
+    }
+
+    /// Issue de la relecture demandée par `begin` : mesure disponible ou abandonnée.
+    pub fn resolve(&mut self, available: bool) {
+        if available {
+            self.resolved += 1;
+        } else {
+            self.dropped += 1;
+        }
+    }
+
+    /// Requêtes émises et pas encore relues
+    pub fn in_flight(&self) -> usize {
+        self.pending.iter().filter(|p| **p).count()
+    }
+
+    /// Mesures relues
+    pub fn resolved(&self) -> usize {
+        self.resolved
+    }
+
+    /// Mesures abandonnées (GPU en retard de plus de `depth` frames)
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+/// Chronomètre GPU (`GL_TIME_ELAPSED`) à relecture différée, sans bloquer le CPU.
+///
+/// Un seul intervalle `begin`/`end` par frame ; les requêtes `GL_TIME_ELAPSED`
+/// ne s'imbriquent pas.
+pub struct GpuTimer {
+    ring: QueryRing,
+    queries: [GLuint; GPU_TIMER_LATENCY],
+    last: Option<Duration>,
+}
+
+impl GpuTimer {
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn new() -> Self {
+        let mut queries = [0; GPU_TIMER_LATENCY];
+        gl::GenQueries(GPU_TIMER_LATENCY as GLsizei, queries.as_mut_ptr());
+        Self {
+            ring: QueryRing::new(GPU_TIMER_LATENCY),
+            queries,
+            last: None,
+        }
+    }
+
+    /// Relit la plus ancienne mesure si elle est disponible, puis démarre la suivante.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn begin(&mut self) {
+        let (slot, must_resolve) = self.ring.begin();
+        if must_resolve {
+            let mut available = 0;
+            gl::GetQueryObjectiv(
+                self.queries[slot],
+                gl::QUERY_RESULT_AVAILABLE,
+                &mut available,
+            );
+            if available != 0 {
+                let mut elapsed_ns: GLuint64 = 0;
+                gl::GetQueryObjectui64v(self.queries[slot], gl::QUERY_RESULT, &mut elapsed_ns);
+                self.last = Some(Duration::from_nanos(elapsed_ns));
+            }
+            // Sinon la mesure est abandonnée : la requête est réutilisée
+            self.ring.resolve(available != 0);
+        }
+        gl::BeginQuery(gl::TIME_ELAPSED, self.queries[slot]);
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn end(&self) {
+        gl::EndQuery(gl::TIME_ELAPSED);
+    }
+
+    /// Dernière durée GPU relue (`None` avant la première relecture)
+    pub fn last(&self) -> Option<Duration> {
+        self.last
+    }
+
+    /// Comptabilité des requêtes (relues / abandonnées)
+    pub fn queries(&self) -> &QueryRing {
+        &self.ring
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn delete(&mut self) {
+        if self.queries[0] != 0 {
+            gl::DeleteQueries(GPU_TIMER_LATENCY as GLsizei, self.queries.as_ptr());
+            self.queries = [0; GPU_TIMER_LATENCY];
+        }
+    }
+}
@@ -1,3 +1,16 @@
 pub mod adaptative_sampler;
+pub mod atlas;
+pub mod depth_sort;
+#[cfg(feature = "headless_egl")]
+pub mod egl_context;
+pub mod fence_ring;
+pub mod frame_limiter;
 pub mod glfw_window;
+pub mod golden;
+pub mod gpu_timer;
+pub mod label;
+pub mod offscreen;
+pub mod screenshot;
 pub mod texture;
+#[cfg(feature = "backend_winit")]
+pub mod winit_window;
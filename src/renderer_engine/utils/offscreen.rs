@@ -0,0 +1,109 @@
+use crate::gl_check;
+use anyhow::{anyhow, Result};
+use gl::types::*;
+
+/// Cible de rendu hors écran : un FBO avec une texture couleur RGBA8.
+///
+/// Utilisée par le mode headless (pas de swap, pas d'ImGui) : les frames sont
+/// dessinées dans la texture puis relues avec `read_rgba`.
+#[derive(Debug)]
+pub struct OffscreenTarget {
+    fbo: GLuint,
+    color_tex: GLuint,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl OffscreenTarget {
+    /// Taille de la texture couleur (RGBA8)
+    pub fn byte_size(&self) -> u64 {
+        self.width as u64 * self.height as u64 * 4
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn new(width: u32, height: u32) -> Result<Self> {
+        let mut color_tex = 0;
+        gl::GenTextures(1, &mut color_tex);
+        gl::BindTexture(gl::TEXTURE_2D, color_tex);
+        gl::TexImage2D(
+            gl::TEXTURE_2D,
+            0,
+            gl::RGBA8 as GLint,
+            width as GLsizei,
+            height as GLsizei,
+            0,
+            gl::RGBA,
+            gl::UNSIGNED_BYTE,
+            std::ptr::null(),
+        );
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+        gl::BindTexture(gl::TEXTURE_2D, 0);
+
+        let mut fbo = 0;
+        gl::GenFramebuffers(1, &mut fbo);
+        gl::BindFramebuffer(gl::FRAMEBUFFER, fbo);
+        gl::FramebufferTexture2D(
+            gl::FRAMEBUFFER,
+            gl::COLOR_ATTACHMENT0,
+            gl::TEXTURE_2D,
+            color_tex,
+            0,
+        );
+        gl_check!("offscreen framebuffer setup");
+        let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+        gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+
+        let mut target = Self {
+            fbo,
+            color_tex,
+            width,
+            height,
+        };
+        if status != gl::FRAMEBUFFER_COMPLETE {
+            target.delete();
+            return Err(anyhow!("Offscreen framebuffer incomplete (0x{:X})", status));
+        }
+        Ok(target)
+    }
+
+    /// Redirige les prochains draw calls vers la cible.
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn bind(&self) {
+        gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+        gl::Viewport(0, 0, self.width as GLsizei, self.height as GLsizei);
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn unbind(&self) {
+        gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+    }
+
+    /// Relit la texture couleur (RGBA8, lignes de bas en haut).
+    ///
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn read_rgba(&self) -> Vec<u8> {
+        gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.fbo);
+        let pixels = super::screenshot::read_framebuffer_rgba(self.width, self.height);
+        gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+        pixels
+    }
+
+    /// # Safety
+    /// Un contexte OpenGL valide doit être courant sur ce thread.
+    pub unsafe fn delete(&mut self) {
+        if self.fbo != 0 {
+            gl::DeleteFramebuffers(1, &self.fbo);
+            self.fbo = 0;
+        }
+        if self.color_tex != 0 {
+            gl::DeleteTextures(1, &self.color_tex);
+            self.color_tex = 0;
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::Context;
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Répertoire des captures quand aucun chemin n'est fourni
+pub const SCREENSHOTS_DIR: &str = "screenshots";
+
+/// Extension des captures HDR (OpenEXR, flottants 32 bits)
+pub const HDR_SCREENSHOT_EXT: &str = "exr";
+
+/// Chemin horodaté par défaut : `screenshots/screenshot_<secs>_<millis>.png`
+pub fn default_screenshot_path() -> PathBuf {
+    timestamped_path(SCREENSHOTS_DIR, "screenshot", "png")
+}
+
+/// Chemin horodaté des captures HDR : `screenshots/screenshot_hdr_<secs>_<millis>.exr`
+pub fn default_hdr_screenshot_path() -> PathBuf {
+    timestamped_path(SCREENSHOTS_DIR, "screenshot_hdr", HDR_SCREENSHOT_EXT)
+}
+
+/// `<dir>/<prefix>_<secs>_<millis>.<ext>`
+pub fn timestamped_path(dir: &str, prefix: &str, ext: &str) -> PathBuf {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    Path::new(dir).join(format!(
+        "{}_{}_{:03}.{}",
+        prefix,
+        now.as_secs(),
+        now.subsec_millis(),
+        ext
+    ))
+}
+
+/// Retourne verticalement une image stockée ligne par ligne
+/// (OpenGL lit le framebuffer depuis le coin bas-gauche) ; `channels` :
+/// composantes par pixel (octets en RGBA8, flottants en RGBA32F).
+pub fn flip_rows<T>(pixels: &mut [T], width: usize, height: usize, channels: usize) {
+    let row_len = width * channels;
+    assert_eq!(pixels.len(), row_len * height, "pixel buffer size mismatch");
+    for y in 0..height / 2 {
+        let (top, bottom) = pixels.split_at_mut((height - 1 - y) * row_len);
+        top[y * row_len..(y + 1) * row_len].swap_with_slice(&mut bottom[..row_len]);
+    }
+}
+
+/// Lit le framebuffer courant (RGBA8, lignes de bas en haut).
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread.
+pub unsafe fn read_framebuffer_rgba(width: u32, height: u32) -> Vec<u8> {
+    let mut pixels = vec![0u8; width as usize * height as usize * 4];
+    gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+    gl::ReadPixels(
+        0,
+        0,
+        width as i32,
+        height as i32,
+        gl::RGBA,
+        gl::UNSIGNED_BYTE,
+        pixels.as_mut_ptr() as *mut _,
+    );
+    pixels
+}
+
+/// Lit une texture couleur en RGBA32F (lignes de bas en haut), par exemple la
+/// cible HDR de la scène avant tone mapping.
+///
+/// # Safety
+/// Un contexte OpenGL valide doit être courant sur ce thread et `texture` doit
+/// être une texture 2D de `width` × `height` texels.
+pub unsafe fn read_texture_rgba32f(texture: u32, width: u32, height: u32) -> Vec<f32> {
+    let mut pixels = vec![0f32; width as usize * height as usize * 4];
+    gl::PixelStorei(gl::PACK_ALIGNMENT, 4);
+    gl::BindTexture(gl::TEXTURE_2D, texture);
+    gl::GetTexImage(
+        gl::TEXTURE_2D,
+        0,
+        gl::RGBA,
+        gl::FLOAT,
+        pixels.as_mut_ptr() as *mut _,
+    );
+    gl::BindTexture(gl::TEXTURE_2D, 0);
+    pixels
+}
+
+/// Retourne les lignes et encode l'image (format déduit de l'extension)
+/// sur un thread dédié : la frame courante n'attend pas l'encodage.
+///
+/// L'issue est journalisée par le thread lui-même : l'appelant peut ignorer le
+/// `JoinHandle` sans perdre une erreur d'écriture.
+pub fn save_rgba_async(
+    pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+    path: PathBuf,
+) -> JoinHandle<anyhow::Result<PathBuf>> {
+    std::thread::spawn(move || log_saved(save_rgba(pixels, width, height, path)))
+}
+
+fn save_rgba(
+    mut pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+    path: PathBuf,
+) -> anyhow::Result<PathBuf> {
+    anyhow::ensure!(
+        pixels.len() == width as usize * height as usize * 4,
+        "pixel buffer does not match image size ({} x {})",
+        width,
+        height
+    );
+    flip_rows(&mut pixels, width as usize, height as usize, 4);
+    let image = image::RgbaImage::from_raw(width, height, pixels)
+        .context("pixel buffer does not match image size")?;
+    create_parent_dir(&path)?;
+    image
+        .save(&path)
+        .with_context(|| format!("cannot save screenshot to {}", path.display()))?;
+    Ok(path)
+}
+
+/// Variante HDR de [`save_rgba_async`] : valeurs linéaires non bornées, écrites
+/// en OpenEXR (seul format flottant accepté, extension `.exr`).
+pub fn save_rgba32f_async(
+    pixels: Vec<f32>,
+    width: u32,
+    height: u32,
+    path: PathBuf,
+) -> JoinHandle<anyhow::Result<PathBuf>> {
+    std::thread::spawn(move || log_saved(save_rgba32f(pixels, width, height, path)))
+}
+
+fn save_rgba32f(
+    mut pixels: Vec<f32>,
+    width: u32,
+    height: u32,
+    path: PathBuf,
+) -> anyhow::Result<PathBuf> {
+    anyhow::ensure!(
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(HDR_SCREENSHOT_EXT)),
+        "HDR screenshots are saved as OpenEXR, expected a .{} path: {}",
+        HDR_SCREENSHOT_EXT,
+        path.display()
+    );
+    anyhow::ensure!(
+        pixels.len() == width as usize * height as usize * 4,
+        "pixel buffer does not match image size ({} x {})",
+        width,
+        height
+    );
+    flip_rows(&mut pixels, width as usize, height as usize, 4);
+    let image = image::Rgba32FImage::from_raw(width, height, pixels)
+        .context("pixel buffer does not match image size")?;
+    create_parent_dir(&path)?;
+    image
+        .save(&path)
+        .with_context(|| format!("cannot save screenshot to {}", path.display()))?;
+    Ok(path)
+}
+
+fn create_parent_dir(path: &Path) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+fn log_saved(result: anyhow::Result<PathBuf>) -> anyhow::Result<PathBuf> {
+    match &result {
+        Ok(path) => info!("📸 Screenshot saved: {}", path.display()),
+        Err(e) => warn!("⚠️ Screenshot failed: {:#}", e),
+    }
+    result
+}
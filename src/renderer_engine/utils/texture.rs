@@ -1,9 +1,56 @@
+use crate::gl_check;
+use crate::utils::embedded_assets::read_asset;
+use anyhow::{Context, Result};
 use image::GenericImageView;
-use std::path::Path;
+
+/// Texture GPU et sa provenance, remplaçable à chaud.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextureSlot {
+    pub id: u32,
+    pub path: String,
+    /// Largeur / hauteur (uniform `uTexRatio`)
+    pub aspect_ratio: f32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl TextureSlot {
+    pub fn new(id: u32, path: &str, width: u32, height: u32) -> Self {
+        let mut slot = Self::default();
+        slot.replace(id, path, width, height);
+        slot
+    }
+
+    /// Installe une nouvelle texture ; retourne l'ancien identifiant à libérer.
+    pub fn replace(&mut self, id: u32, path: &str, width: u32, height: u32) -> Option<u32> {
+        let old = std::mem::replace(&mut self.id, id);
+        self.path = path.to_string();
+        self.aspect_ratio = width as f32 / height.max(1) as f32;
+        self.width = width;
+        self.height = height;
+        (old != 0 && old != id).then_some(old)
+    }
+
+    /// Taille estimée sur le GPU (RGBA8, sans mipmaps)
+    pub fn byte_size(&self) -> u64 {
+        if self.id == 0 {
+            return 0;
+        }
+        self.width as u64 * self.height as u64 * 4
+    }
+}
 
 pub fn load_texture(path: &str) -> (u32, u32, u32) {
+    try_load_texture(path).expect("Failed to load texture")
+}
+
+/// Comme `load_texture`, mais retourne une erreur si l'image est illisible.
+pub fn try_load_texture(path: &str) -> Result<(u32, u32, u32)> {
     // Charge l'image
-    let img = image::open(Path::new(path)).expect("Failed to load texture");
+    let img = read_asset(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|bytes| Ok(image::load_from_memory(&bytes)?))
+        .with_context(|| format!("Texture '{}'", path))?;
     let img = img.flipv(); // OpenGL attend l'origine en bas à gauche
     let (width, height) = img.dimensions();
     let rgba = img.to_rgba8();
@@ -31,9 +78,10 @@ pub fn load_texture(path: &str) -> (u32, u32, u32) {
             gl::UNSIGNED_BYTE,
             data.as_ptr() as *const _,
         );
+        gl_check!("texture upload");
 
         gl::BindTexture(gl::TEXTURE_2D, 0);
     }
 
-    (tex_id, width, height)
+    Ok((tex_id, width, height))
 }
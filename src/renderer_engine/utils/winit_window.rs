@@ -0,0 +1,167 @@
+//! Traduction des événements winit en événements neutres (feature `backend_winit`).
+//!
+//! winit donne positions et tailles en pixels physiques : le curseur et la taille
+//! de fenêtre sont ramenés en coordonnées logiques, celles des événements glfw,
+//! pour que `DisplayScale::to_framebuffer` s'applique à l'identique.
+
+use winit::event::{ElementState, MouseScrollDelta};
+use winit::keyboard::{KeyCode as WinitKey, PhysicalKey};
+
+use crate::renderer_engine::window_event::{KeyCode, MouseButton, WindowEvent};
+
+/// Pixels de défilement (pavé tactile) comptés comme un cran de molette
+pub const PIXELS_PER_SCROLL_STEP: f64 = 40.0;
+
+/// Traduit un événement winit en événements neutres (vide : ignoré par le
+/// simulateur) ; `scale_factor` est le facteur d'échelle courant de la fenêtre.
+pub fn translate_event(event: &winit::event::WindowEvent, scale_factor: f64) -> Vec<WindowEvent> {
+    use winit::event::WindowEvent as Winit;
+    match *event {
+        Winit::Resized(size) => {
+            let logical = size.to_logical::<f64>(scale_factor);
+            vec![
+                WindowEvent::Resize(size.width as i32, size.height as i32),
+                WindowEvent::WindowSize(
+                    logical.width.round() as i32,
+                    logical.height.round() as i32,
+                ),
+            ]
+        }
+        Winit::ScaleFactorChanged { scale_factor, .. } => {
+            vec![WindowEvent::ContentScale(
+                scale_factor as f32,
+                scale_factor as f32,
+            )]
+        }
+        Winit::KeyboardInput { ref event, .. } => translate_key_input(
+            event.physical_key,
+            event.state,
+            event.repeat,
+            event.text.as_deref(),
+        ),
+        Winit::MouseInput { state, button, .. } => vec![WindowEvent::MouseButton(
+            translate_mouse_button(button),
+            state == ElementState::Pressed,
+        )],
+        Winit::CursorMoved { position, .. } => {
+            let logical = position.to_logical::<f32>(scale_factor);
+            vec![WindowEvent::CursorPos(logical.x, logical.y)]
+        }
+        Winit::MouseWheel { delta, .. } => vec![WindowEvent::Scroll(match delta {
+            MouseScrollDelta::LineDelta(_, dy) => dy,
+            MouseScrollDelta::PixelDelta(position) => (position.y / PIXELS_PER_SCROLL_STEP) as f32,
+        })],
+        Winit::DroppedFile(ref path) => vec![WindowEvent::FileDrop(vec![path.clone()])],
+        Winit::Occluded(occluded) => vec![WindowEvent::Iconified(occluded)],
+        Winit::CloseRequested => vec![WindowEvent::Close],
+        _ => Vec::new(),
+    }
+}
+
+/// Frappe clavier : la touche enfoncée (si elle a un équivalent neutre) puis le
+/// texte produit, caractère par caractère. Comme avec glfw, relâchements et
+/// répétitions ne déclenchent pas d'action ; le texte, lui, se répète.
+pub fn translate_key_input(
+    key: PhysicalKey,
+    state: ElementState,
+    repeat: bool,
+    text: Option<&str>,
+) -> Vec<WindowEvent> {
+    if state == ElementState::Released {
+        return Vec::new();
+    }
+    let key = match key {
+        PhysicalKey::Code(code) if !repeat => translate_key(code),
+        _ => None,
+    };
+    key.map(WindowEvent::KeyPress)
+        .into_iter()
+        .chain(
+            text.unwrap_or("")
+                .chars()
+                .filter(|c| !c.is_control())
+                .map(WindowEvent::Char),
+        )
+        .collect()
+}
+
+fn translate_mouse_button(button: winit::event::MouseButton) -> MouseButton {
+    match button {
+        winit::event::MouseButton::Left => MouseButton::Left,
+        winit::event::MouseButton::Right => MouseButton::Right,
+        winit::event::MouseButton::Middle => MouseButton::Middle,
+        _ => MouseButton::Other,
+    }
+}
+
+/// Touche winit (position physique, disposition US) correspondante
+/// (`None` : touche sans équivalent neutre).
+pub fn translate_key(key: WinitKey) -> Option<KeyCode> {
+    Some(match key {
+        WinitKey::KeyA => KeyCode::A,
+        WinitKey::KeyB => KeyCode::B,
+        WinitKey::KeyC => KeyCode::C,
+        WinitKey::KeyD => KeyCode::D,
+        WinitKey::KeyE => KeyCode::E,
+        WinitKey::KeyF => KeyCode::F,
+        WinitKey::KeyG => KeyCode::G,
+        WinitKey::KeyH => KeyCode::H,
+        WinitKey::KeyI => KeyCode::I,
+        WinitKey::KeyJ => KeyCode::J,
+        WinitKey::KeyK => KeyCode::K,
+        WinitKey::KeyL => KeyCode::L,
+        WinitKey::KeyM => KeyCode::M,
+        WinitKey::KeyN => KeyCode::N,
+        WinitKey::KeyO => KeyCode::O,
+        WinitKey::KeyP => KeyCode::P,
+        WinitKey::KeyQ => KeyCode::Q,
+        WinitKey::KeyR => KeyCode::R,
+        WinitKey::KeyS => KeyCode::S,
+        WinitKey::KeyT => KeyCode::T,
+        WinitKey::KeyU => KeyCode::U,
+        WinitKey::KeyV => KeyCode::V,
+        WinitKey::KeyW => KeyCode::W,
+        WinitKey::KeyX => KeyCode::X,
+        WinitKey::KeyY => KeyCode::Y,
+        WinitKey::KeyZ => KeyCode::Z,
+        WinitKey::Digit0 => KeyCode::Num0,
+        WinitKey::Digit1 => KeyCode::Num1,
+        WinitKey::Digit2 => KeyCode::Num2,
+        WinitKey::Digit3 => KeyCode::Num3,
+        WinitKey::Digit4 => KeyCode::Num4,
+        WinitKey::Digit5 => KeyCode::Num5,
+        WinitKey::Digit6 => KeyCode::Num6,
+        WinitKey::Digit7 => KeyCode::Num7,
+        WinitKey::Digit8 => KeyCode::Num8,
+        WinitKey::Digit9 => KeyCode::Num9,
+        WinitKey::F1 => KeyCode::F1,
+        WinitKey::F2 => KeyCode::F2,
+        WinitKey::F3 => KeyCode::F3,
+        WinitKey::F4 => KeyCode::F4,
+        WinitKey::F5 => KeyCode::F5,
+        WinitKey::F6 => KeyCode::F6,
+        WinitKey::F7 => KeyCode::F7,
+        WinitKey::F8 => KeyCode::F8,
+        WinitKey::F9 => KeyCode::F9,
+        WinitKey::F10 => KeyCode::F10,
+        WinitKey::F11 => KeyCode::F11,
+        WinitKey::F12 => KeyCode::F12,
+        WinitKey::Escape => KeyCode::Escape,
+        WinitKey::Enter => KeyCode::Enter,
+        WinitKey::Tab => KeyCode::Tab,
+        WinitKey::Backspace => KeyCode::Backspace,
+        WinitKey::Space => KeyCode::Space,
+        WinitKey::Backquote => KeyCode::GraveAccent,
+        WinitKey::BracketLeft => KeyCode::LeftBracket,
+        WinitKey::BracketRight => KeyCode::RightBracket,
+        WinitKey::ArrowUp => KeyCode::Up,
+        WinitKey::ArrowDown => KeyCode::Down,
+        WinitKey::ArrowLeft => KeyCode::Left,
+        WinitKey::ArrowRight => KeyCode::Right,
+        WinitKey::PageUp => KeyCode::PageUp,
+        WinitKey::PageDown => KeyCode::PageDown,
+        WinitKey::Home => KeyCode::Home,
+        WinitKey::End => KeyCode::End,
+        _ => return None,
+    })
+}
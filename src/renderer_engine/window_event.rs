@@ -0,0 +1,290 @@
+//! Événements de fenêtre indépendants du backend (glfw, ou winit avec la feature
+//! `backend_winit`).
+//!
+//! Le backend traduit ses événements en [`WindowEvent`] ; l'[`EventRouter`] les
+//! convertit en [`Reaction`]s appliquées par le renderer. Toute la logique
+//! d'interprétation est donc testable sans fenêtre ni contexte OpenGL.
+
+use glam::Vec2;
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::renderer_engine::display_scale::{effective_content_scale, DisplayScale};
+use crate::renderer_engine::key_bindings::KeyBindings;
+
+macro_rules! key_codes {
+    ($($variant:ident => $name:literal),* $(,)?) => {
+        /// Touche du clavier, nommée comme dans les fichiers de config (`"f11"`, `"grave"`…).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum KeyCode {
+            $($variant,)*
+        }
+
+        impl KeyCode {
+            pub const ALL: &'static [KeyCode] = &[$(KeyCode::$variant,)*];
+
+            /// Nom utilisé par la config et la console
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(KeyCode::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+key_codes! {
+    A => "a", B => "b", C => "c", D => "d", E => "e", F => "f", G => "g", H => "h",
+    I => "i", J => "j", K => "k", L => "l", M => "m", N => "n", O => "o", P => "p",
+    Q => "q", R => "r", S => "s", T => "t", U => "u", V => "v", W => "w", X => "x",
+    Y => "y", Z => "z",
+    Num0 => "0", Num1 => "1", Num2 => "2", Num3 => "3", Num4 => "4",
+    Num5 => "5", Num6 => "6", Num7 => "7", Num8 => "8", Num9 => "9",
+    F1 => "f1", F2 => "f2", F3 => "f3", F4 => "f4", F5 => "f5", F6 => "f6",
+    F7 => "f7", F8 => "f8", F9 => "f9", F10 => "f10", F11 => "f11", F12 => "f12",
+    Escape => "escape", Enter => "enter", Tab => "tab", Backspace => "backspace",
+    Space => "space", GraveAccent => "grave", LeftBracket => "[", RightBracket => "]",
+    Up => "up", Down => "down", Left => "left", Right => "right",
+    PageUp => "pageup", PageDown => "pagedown", Home => "home", End => "end",
+}
+
+impl KeyCode {
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|key| key.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Touche produisant du texte (lettre, chiffre, espace) : réservée à la saisie
+    /// quand la console est ouverte.
+    pub fn is_printable(&self) -> bool {
+        let name = self.name();
+        name.len() == 1 || *self == KeyCode::Space
+    }
+}
+
+/// Bouton de la souris
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Other,
+}
+
+/// Événement de fenêtre, quel que soit le backend.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowEvent {
+    /// Nouvelle taille du framebuffer (pixels)
+    Resize(i32, i32),
+    /// Nouvelle taille de la fenêtre (coordonnées écran, celles du curseur)
+    WindowSize(i32, i32),
+    /// Facteur d'échelle du contenu (DPI), par axe
+    ContentScale(f32, f32),
+    KeyPress(KeyCode),
+    /// Caractère saisi (console)
+    Char(char),
+    MouseButton(MouseButton, bool),
+    /// Position du curseur, origine en haut à gauche (convention des backends)
+    CursorPos(f32, f32),
+    /// Molette (crans verticaux)
+    Scroll(f32),
+    /// Fichiers glissés-déposés sur la fenêtre
+    FileDrop(Vec<PathBuf>),
+    /// Fenêtre réduite (`true`) ou restaurée
+    Iconified(bool),
+    Close,
+}
+
+/// Action déclenchée par une touche (cf. `KeyBindings`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    /// Relit `physic.toml`
+    ReloadConfig,
+    /// Relit `renderer.toml`
+    ReloadRendererConfig,
+    ReloadShaders,
+    ToggleFullscreen,
+    ToggleConsole,
+    ToggleHud,
+    Screenshot,
+    PauseSim,
+    /// Lancement manuel d'une fusée
+    LaunchRocket,
+    /// Forme d'explosion suivante / précédente
+    NextShape,
+    PrevShape,
+    /// Vitesse de simulation prédéfinie précédente / suivante
+    SpeedDown,
+    SpeedUp,
+}
+
+impl Action {
+    pub const ALL: [Action; 14] = [
+        Action::Quit,
+        Action::ReloadConfig,
+        Action::ReloadRendererConfig,
+        Action::ReloadShaders,
+        Action::ToggleFullscreen,
+        Action::ToggleConsole,
+        Action::ToggleHud,
+        Action::Screenshot,
+        Action::PauseSim,
+        Action::LaunchRocket,
+        Action::NextShape,
+        Action::PrevShape,
+        Action::SpeedDown,
+        Action::SpeedUp,
+    ];
+
+    /// Nom utilisé par `input.toml` et la console
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::ReloadConfig => "reload_config",
+            Action::ReloadRendererConfig => "reload_renderer_config",
+            Action::ReloadShaders => "reload_shaders",
+            Action::ToggleFullscreen => "toggle_fullscreen",
+            Action::ToggleConsole => "toggle_console",
+            Action::ToggleHud => "toggle_hud",
+            Action::Screenshot => "screenshot",
+            Action::PauseSim => "pause_sim",
+            Action::LaunchRocket => "launch_rocket",
+            Action::NextShape => "next_shape",
+            Action::PrevShape => "prev_shape",
+            Action::SpeedDown => "speed_down",
+            Action::SpeedUp => "speed_up",
+        }
+    }
+
+    /// Inverse de `name` (insensible à la casse)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Touche par défaut
+    pub fn default_key(&self) -> KeyCode {
+        match self {
+            Action::Quit => KeyCode::Escape,
+            Action::ReloadConfig => KeyCode::R,
+            Action::ReloadRendererConfig => KeyCode::F7,
+            Action::ReloadShaders => KeyCode::F5,
+            Action::ToggleFullscreen => KeyCode::F11,
+            Action::ToggleConsole => KeyCode::GraveAccent,
+            Action::ToggleHud => KeyCode::F1,
+            Action::Screenshot => KeyCode::F12,
+            Action::PauseSim => KeyCode::P,
+            Action::LaunchRocket => KeyCode::Space,
+            Action::NextShape => KeyCode::N,
+            Action::PrevShape => KeyCode::B,
+            Action::SpeedDown => KeyCode::LeftBracket,
+            Action::SpeedUp => KeyCode::RightBracket,
+        }
+    }
+}
+
+/// Action associée à `key` par les liaisons par défaut.
+pub fn default_key_action(key: KeyCode) -> Option<Action> {
+    Action::ALL
+        .into_iter()
+        .find(|action| action.default_key() == key)
+}
+
+/// Effet d'un événement sur le renderer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reaction {
+    Action(Action),
+    Resize(i32, i32),
+    /// Déplacement de la caméra (pixels écran, y vers le haut)
+    Pan(Vec2),
+    /// Zoom de `steps` crans autour du point écran `anchor`
+    Zoom {
+        anchor: Vec2,
+        steps: f32,
+    },
+    /// Fichiers déposés à charger (cf. `file_drop`)
+    FilesDropped(Vec<PathBuf>),
+    /// Fenêtre réduite : plus de rendu, boucle ralentie (cf. `WindowActivity`)
+    Minimized(bool),
+    /// Échelle d'affichage modifiée (UI et taille des particules à ajuster)
+    Rescale(DisplayScale),
+}
+
+/// État d'entrée (curseur, glisser caméra) et interprétation des événements.
+#[derive(Debug, Clone, Default)]
+pub struct EventRouter {
+    /// Position du curseur (pixels écran, y vers le haut)
+    pub cursor_pos: Vec2,
+    pub camera_drag: bool,
+    /// Hauteur courante du framebuffer, pour retourner l'axe y du curseur
+    pub window_height: f32,
+    /// Échelle d'affichage : conversion du curseur en pixels du framebuffer
+    pub display: DisplayScale,
+    /// Liaisons touche → action (partagées avec la console `renderer.input.*`)
+    pub bindings: Rc<RefCell<KeyBindings>>,
+}
+
+impl EventRouter {
+    pub fn new(window_height: f32) -> Self {
+        Self {
+            window_height,
+            ..Default::default()
+        }
+    }
+
+    /// Interprète un événement ; `console_open` coupe les contrôles caméra.
+    pub fn route(&mut self, event: &WindowEvent, console_open: bool) -> Option<Reaction> {
+        match *event {
+            WindowEvent::FileDrop(ref paths) => {
+                (!paths.is_empty()).then(|| Reaction::FilesDropped(paths.clone()))
+            }
+            WindowEvent::Resize(width, height) => {
+                self.window_height = height as f32;
+                self.display.framebuffer_size = (width, height);
+                Some(Reaction::Resize(width, height))
+            }
+            WindowEvent::WindowSize(width, height) => {
+                self.display.window_size = (width, height);
+                Some(Reaction::Rescale(self.display))
+            }
+            WindowEvent::ContentScale(x, y) => {
+                self.display.content_scale = effective_content_scale(x, y);
+                Some(Reaction::Rescale(self.display))
+            }
+            WindowEvent::KeyPress(key) => {
+                let action = self.bindings.borrow().action_for(key)?;
+                // Console ouverte : les touches de texte servent à la saisie
+                let typing = console_open && key.is_printable();
+                (!typing || action == Action::ToggleConsole).then_some(Reaction::Action(action))
+            }
+            WindowEvent::Close => Some(Reaction::Action(Action::Quit)),
+            WindowEvent::Iconified(minimized) => {
+                // Le bouton relâché pendant la réduction n'arrivera pas forcément
+                self.camera_drag = false;
+                Some(Reaction::Minimized(minimized))
+            }
+            WindowEvent::CursorPos(x, y) => {
+                let pixel = self.display.to_framebuffer(Vec2::new(x, y));
+                let pos = Vec2::new(pixel.x, self.window_height - pixel.y);
+                let delta = pos - self.cursor_pos;
+                self.cursor_pos = pos;
+                self.camera_drag.then_some(Reaction::Pan(delta))
+            }
+            WindowEvent::MouseButton(MouseButton::Middle, pressed) => {
+                self.camera_drag = pressed && !console_open;
+                None
+            }
+            WindowEvent::Scroll(steps) if !console_open => Some(Reaction::Zoom {
+                anchor: self.cursor_pos,
+                steps,
+            }),
+            _ => None,
+        }
+    }
+}
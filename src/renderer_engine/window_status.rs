@@ -0,0 +1,89 @@
+//! Titre de fenêtre vivant et ralentissement quand la fenêtre est réduite.
+//!
+//! Logique indépendante du backend : le renderer fournit le temps écoulé et les
+//! compteurs, applique le titre retourné et le budget de frame.
+
+use std::time::Duration;
+
+/// Icône de la fenêtre, embarquée dans l'exécutable
+pub const WINDOW_ICON_PNG: &[u8] = include_bytes!("../../assets/icons/fireworks.png");
+
+/// Période (s) de rafraîchissement du titre
+pub const TITLE_UPDATE_INTERVAL: f32 = 1.0;
+/// Fréquence de la boucle (physique + audio) quand la fenêtre est réduite
+pub const MINIMIZED_UPDATE_HZ: f32 = 10.0;
+
+/// Titre affiché : `"<base> | 60 FPS | 12345 particles"`.
+pub fn format_window_title(base: &str, fps: f32, particles: usize) -> String {
+    format!(
+        "{} | {:.0} FPS | {} particles",
+        base,
+        fps.max(0.0),
+        particles
+    )
+}
+
+/// Rafraîchit le titre au plus une fois par `TITLE_UPDATE_INTERVAL`.
+#[derive(Debug, Clone)]
+pub struct TitleUpdater {
+    base: String,
+    elapsed: f32,
+}
+
+impl TitleUpdater {
+    pub fn new(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Nouveau titre à appliquer, ou `None` si la période n'est pas écoulée.
+    pub fn update(&mut self, dt: f32, fps: f32, particles: usize) -> Option<String> {
+        self.elapsed += dt;
+        if self.elapsed < TITLE_UPDATE_INTERVAL {
+            return None;
+        }
+        self.elapsed %= TITLE_UPDATE_INTERVAL;
+        Some(format_window_title(&self.base, fps, particles))
+    }
+}
+
+/// État réduit / visible de la fenêtre.
+///
+/// Réduite, la boucle ne rend plus rien et tourne à `MINIMIZED_UPDATE_HZ` : la
+/// physique et l'audio continuent, sans consommer le GPU.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WindowActivity {
+    minimized: bool,
+}
+
+impl WindowActivity {
+    pub fn is_minimized(&self) -> bool {
+        self.minimized
+    }
+
+    /// Retourne `true` si l'état a changé.
+    pub fn set_minimized(&mut self, minimized: bool) -> bool {
+        let changed = self.minimized != minimized;
+        self.minimized = minimized;
+        changed
+    }
+
+    pub fn should_render(&self) -> bool {
+        !self.minimized
+    }
+
+    /// Budget de frame à respecter : `normal` (v-sync / plafond) ou la cadence réduite.
+    pub fn frame_budget(&self, normal: Option<Duration>) -> Option<Duration> {
+        if self.minimized {
+            Some(Duration::from_secs_f32(1.0 / MINIMIZED_UPDATE_HZ))
+        } else {
+            normal
+        }
+    }
+}
@@ -0,0 +1,686 @@
+//! Renderer fenêtré winit + glutin (feature `backend_winit`).
+//!
+//! Même simulation (`begin_sim_frame` / `step_simulation`), mêmes événements
+//! neutres (`EventRouter`) et même graphe de passes que le [`Renderer`] glfw ;
+//! seuls la fenêtre, le contexte OpenGL et la boucle d'événements changent.
+//! La console ImGui, le HUD, les captures, l'export vidéo, le plein écran et les
+//! manettes restent propres au backend glfw : les commandes et touches qui les
+//! pilotent sont sans effet ici.
+//!
+//! [`Renderer`]: crate::renderer_engine::Renderer
+
+use anyhow::{anyhow, Result};
+use glam::Vec2;
+use glutin::config::{Config, ConfigTemplateBuilder, GlConfig};
+use glutin::context::{
+    ContextApi, ContextAttributesBuilder, GlProfile, PossiblyCurrentContext, Version,
+};
+use glutin::display::{GetGlDisplay, GlDisplay};
+use glutin::prelude::{GlSurface, NotCurrentGlContext};
+use glutin::surface::{Surface, SurfaceAttributesBuilder, SwapInterval, WindowSurface};
+use glutin_winit::{DisplayBuilder, GlWindow};
+use log::{debug, info, warn};
+use raw_window_handle::HasWindowHandle;
+use std::cell::{Cell, RefCell};
+use std::ffi::CString;
+use std::num::NonZeroU32;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use winit::application::ApplicationHandler;
+use winit::dpi::LogicalSize;
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+use winit::window::{Window, WindowId};
+
+use crate::app_options::AppOptions;
+use crate::audio_engine::AudioEngine;
+use crate::bench::ACTIVE_PARTICLES_METRIC;
+use crate::config_manager::ConfigManager;
+use crate::duration_limit::DurationLimit;
+use crate::log_metrics_and_fps;
+use crate::physic_engine::explosion_shape::cycle_explosion_shape;
+use crate::physic_engine::{config::PhysicConfig, PhysicEngine, PhysicEngineFull};
+use crate::profiler::Profiler;
+use crate::renderer_engine::{
+    audio_reactive::EnvelopeFollower,
+    camera::Camera2D,
+    command_console::CommandRegistry,
+    command_sim::SimState,
+    config_reload::particles_within_budget,
+    display_scale::{effective_content_scale, format_display_scale, DisplayScale},
+    file_drop::handle_file_drop,
+    flash::{Flash, FlashList},
+    frame_graph::{FrameGraph, PassResources},
+    key_bindings::{KeyBindings, INPUT_CONFIG_PATH},
+    render_passes::{default_passes, default_resources, render_scene, SceneView},
+    render_stats::RenderStats,
+    renderer::{register_renderer_commands, RendererShared},
+    tools::{
+        default_framebuffer_is_srgb, set_gl_checks_enabled, setup_opengl_debug,
+        show_opengl_context_info,
+    },
+    utils::{frame_limiter::FrameLimiter, winit_window::translate_event},
+    window_event::{Action, EventRouter, Reaction, WindowEvent},
+    ParticleGPU, RendererEngine,
+};
+use crate::sim_clock::{next_speed_preset, SimClock, SimSpeed};
+use crate::simulator::{begin_sim_frame, step_simulation};
+
+/// Fenêtre winit, son contexte OpenGL et les ressources GPU du rendu.
+///
+/// Créés à la reprise de la boucle d'événements (`resumed`), exigence de winit.
+struct GlTarget {
+    resources: PassResources,
+    frame_graph: FrameGraph,
+    /// Le back buffer encode en sRGB (`srgb_framebuffer` accordé)
+    srgb_capable: bool,
+    /// V-sync actuellement appliquée à la surface
+    vsync: bool,
+    // Ordre de libération : surface, contexte, puis fenêtre
+    surface: Surface<WindowSurface>,
+    context: PossiblyCurrentContext,
+    window: Window,
+}
+
+pub struct WinitRenderer {
+    title: String,
+    window_size: (i32, i32),
+    physic_config: PhysicConfig,
+    /// État partagé avec les commandes console `renderer.*` et `sim.*`
+    shared: RendererShared,
+    event_router: EventRouter,
+    /// Horloge de la simulation (cf. `begin_sim_frame`)
+    sim_clock: SimClock,
+    gl: Option<GlTarget>,
+    /// Temps de rendu écoulé (s), anime le scintillement des étoiles
+    clock: f32,
+    flashes: FlashList,
+    audio_envelope: EnvelopeFollower,
+    frame_limiter: FrameLimiter,
+    /// Fenêtre masquée : rendu suspendu
+    occluded: bool,
+    /// Fermeture demandée (touche `quit`, bouton de la fenêtre)
+    should_close: bool,
+    frames: u64,
+    profiler: Profiler,
+    /// `run_loop` s'arrête après ce nombre de frames (`bench`)
+    frame_limit: Option<u64>,
+    /// `run_loop` s'arrête à la fin du spectacle (`--duration`)
+    duration_limit: Option<DurationLimit>,
+}
+
+impl WinitRenderer {
+    /// Renderer configuré par la ligne de commande ; la fenêtre n'est ouverte
+    /// qu'au lancement de `run_loop`.
+    pub fn from_options(
+        options: &AppOptions,
+        physic_config: &PhysicConfig,
+        config_manager: ConfigManager,
+    ) -> Result<Self> {
+        let _ = env_logger::builder().is_test(true).try_init();
+        if options.fullscreen || options.record.is_some() {
+            warn!("⚠️ --fullscreen and --record are not supported by the winit backend");
+        }
+
+        let config = config_manager.renderer_config();
+        info!("Renderer config loaded:\n{:#?}", config);
+        let (width, height) = options.size;
+        let key_bindings = Rc::new(RefCell::new(KeyBindings::load(INPUT_CONFIG_PATH)));
+        Ok(Self {
+            title: "Fireworks Simulator".to_string(),
+            window_size: (width, height),
+            physic_config: physic_config.clone(),
+            shared: RendererShared {
+                camera: Rc::new(RefCell::new(Camera2D::new(
+                    Vec2::new(width as f32, height as f32),
+                    config.camera.clone(),
+                ))),
+                config: Rc::new(RefCell::new(config)),
+                config_manager: Rc::new(RefCell::new(config_manager)),
+                key_bindings: key_bindings.clone(),
+                display_scale: Rc::new(Cell::new(DisplayScale::default())),
+                ..Default::default()
+            },
+            event_router: EventRouter {
+                bindings: key_bindings,
+                ..EventRouter::new(height as f32)
+            },
+            sim_clock: SimClock::new(),
+            gl: None,
+            clock: 0.0,
+            flashes: FlashList::new(),
+            audio_envelope: EnvelopeFollower::new(),
+            frame_limiter: FrameLimiter::default(),
+            occluded: false,
+            should_close: false,
+            frames: 0,
+            profiler: Profiler::new(200),
+            frame_limit: None,
+            duration_limit: None,
+        })
+    }
+
+    /// Ouvre la fenêtre et crée un contexte OpenGL 3.3 core, puis les passes.
+    fn create_window(&mut self, event_loop: &ActiveEventLoop) -> Result<()> {
+        let config = self.shared.config.borrow().clone();
+        let gl_debug = cfg!(feature = "gl_debug") || config.gl_debug;
+
+        let attributes = Window::default_attributes()
+            .with_title(&self.title)
+            .with_inner_size(LogicalSize::new(self.window_size.0, self.window_size.1));
+        let (window, gl_config) = DisplayBuilder::new()
+            .with_window_attributes(Some(attributes))
+            .build(event_loop, ConfigTemplateBuilder::new(), |configs| {
+                pick_config(configs, config.srgb_framebuffer)
+            })
+            .map_err(|e| anyhow!("Impossible de créer la fenêtre winit : {}", e))?;
+        let window = window.ok_or_else(|| anyhow!("Fenêtre winit non créée"))?;
+
+        let context_attributes = ContextAttributesBuilder::new()
+            .with_context_api(ContextApi::OpenGl(Some(Version::new(3, 3))))
+            .with_profile(GlProfile::Core)
+            .with_debug(gl_debug)
+            .build(Some(window.window_handle()?.as_raw()));
+        let display = gl_config.display();
+        let context = unsafe { display.create_context(&gl_config, &context_attributes)? };
+        let surface_attributes = window.build_surface_attributes(
+            SurfaceAttributesBuilder::new().with_srgb(Some(config.srgb_framebuffer)),
+        )?;
+        let surface = unsafe { display.create_window_surface(&gl_config, &surface_attributes)? };
+        let context = context.make_current(&surface)?;
+        set_swap_interval(&surface, &context, config.vsync);
+
+        info!("✅ OpenGL context ready for '{}' (winit)", self.title);
+
+        gl::load_with(|symbol| {
+            let symbol = CString::new(symbol).expect("Nom de fonction OpenGL invalide");
+            display.get_proc_address(&symbol) as *const _
+        });
+
+        unsafe {
+            show_opengl_context_info();
+            set_gl_checks_enabled(gl_debug);
+            if gl_debug {
+                setup_opengl_debug();
+            }
+            gl::Enable(gl::PROGRAM_POINT_SIZE);
+            gl::Enable(gl::BLEND);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+        }
+
+        let srgb_capable = unsafe { default_framebuffer_is_srgb() };
+        if config.srgb_framebuffer && !srgb_capable {
+            warn!("⚠️ sRGB framebuffer unavailable, falling back to shader gamma");
+        }
+
+        let size = window.inner_size();
+        let scale_factor = window.scale_factor();
+        let logical = size.to_logical::<f64>(scale_factor);
+        let display_scale = DisplayScale {
+            content_scale: effective_content_scale(scale_factor as f32, scale_factor as f32),
+            window_size: (logical.width.round() as i32, logical.height.round() as i32),
+            framebuffer_size: (size.width as i32, size.height as i32),
+        };
+        info!("🔍 {}", format_display_scale(&display_scale));
+        self.event_router.display = display_scale;
+        self.event_router.window_height = size.height as f32;
+        self.shared.display_scale.set(display_scale);
+
+        let max_particles_on_gpu = particles_within_budget(
+            self.physic_config.max_rockets * self.physic_config.particles_per_explosion,
+            std::mem::size_of::<ParticleGPU>(),
+            config.max_gpu_buffer,
+        );
+        let resources = unsafe {
+            default_resources(
+                &config,
+                &self.physic_config,
+                max_particles_on_gpu,
+                size.width as i32,
+                size.height as i32,
+            )
+        };
+        self.gl = Some(GlTarget {
+            resources,
+            frame_graph: FrameGraph::new(default_passes())?,
+            srgb_capable,
+            vsync: config.vsync,
+            surface,
+            context,
+            window,
+        });
+        Ok(())
+    }
+
+    /// Facteur d'échelle de la fenêtre (1 avant son ouverture).
+    fn scale_factor(&self) -> f64 {
+        self.gl.as_ref().map_or(1.0, |gl| gl.window.scale_factor())
+    }
+
+    fn is_finished<P: PhysicEngine>(&self, physic: &P) -> bool {
+        if self.should_close {
+            return true;
+        }
+        if self.frame_limit.is_some_and(|limit| self.frames >= limit) {
+            info!("🏁 Frame limit reached ({} frames)", self.frames);
+            return true;
+        }
+        if let Some(limit) = &self.duration_limit {
+            if limit.is_finished(physic.get_stats().active_rockets) {
+                info!(
+                    "🏁 Duration limit reached ({:.2} s simulated)",
+                    limit.elapsed()
+                );
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Applique l'effet d'un événement de fenêtre (cf. `EventRouter`).
+    fn apply_reaction<P: PhysicEngine, A: AudioEngine>(
+        &mut self,
+        reaction: Reaction,
+        physic: &mut P,
+        audio: &mut A,
+    ) {
+        match reaction {
+            Reaction::Resize(w, h) => {
+                if let Some(gl) = &mut self.gl {
+                    gl.window.resize_surface(&gl.surface, &gl.context);
+                    unsafe {
+                        gl::Viewport(0, 0, w, h);
+                        gl.frame_graph.resize(&mut gl.resources, w as u32, h as u32);
+                    }
+                }
+                // La physique reste en coordonnées monde (vue identité)
+                physic.set_window_width(w as f32);
+                self.shared
+                    .camera
+                    .borrow_mut()
+                    .set_viewport(Vec2::new(w as f32, h as f32));
+                audio.set_listener_position(((w / 2) as f32, 0.0));
+                self.shared.display_scale.set(self.event_router.display);
+            }
+            Reaction::Rescale(display) => {
+                if display.content_scale != self.shared.display_scale.get().content_scale {
+                    info!("🔍 {}", format_display_scale(&display));
+                }
+                self.shared.display_scale.set(display);
+            }
+            Reaction::Pan(delta) => self.shared.camera.borrow_mut().pan_by_screen(delta),
+            Reaction::Zoom { anchor, steps } => {
+                let mut camera = self.shared.camera.borrow_mut();
+                let factor = camera.config().wheel_zoom_step.powf(steps);
+                camera.zoom_about(anchor, factor);
+            }
+            Reaction::Action(action) => self.apply_action(action, physic),
+            Reaction::FilesDropped(paths) => {
+                let outcome = handle_file_drop(&paths, physic);
+                for message in outcome.messages {
+                    info!("{}", message);
+                }
+                if outcome.reload_config {
+                    warn!("⚠️ Config reload is not supported by the winit backend");
+                }
+            }
+            Reaction::Minimized(minimized) => self.occluded = minimized,
+        }
+    }
+
+    fn apply_action<P: PhysicEngine>(&mut self, action: Action, physic: &mut P) {
+        match action {
+            Action::Quit => self.should_close = true,
+            Action::ReloadRendererConfig => match self.shared.reload_config() {
+                Ok(Some(event)) => info!("🔄 Renderer config reloaded: {}", event.summary()),
+                Ok(None) => info!("🔄 Renderer config reloaded: no field changed"),
+                Err(e) => warn!("⚠️ Renderer config not reloaded: {:#}", e),
+            },
+            Action::ReloadShaders => {
+                if let Some(gl) = &mut self.gl {
+                    info!("🔄 Reloading shaders");
+                    unsafe { gl.frame_graph.reload_shaders(&mut gl.resources) };
+                }
+            }
+            Action::PauseSim => {
+                let paused = !self.shared.paused.get();
+                self.shared.paused.set(paused);
+                info!(
+                    "{}",
+                    if paused {
+                        "⏸️ Simulation paused"
+                    } else {
+                        "▶️ Simulation resumed"
+                    }
+                );
+            }
+            Action::SpeedDown | Action::SpeedUp => {
+                let current = self.shared.sim_speed.get().value();
+                let speed = next_speed_preset(current, action == Action::SpeedUp);
+                self.shared.sim_speed.set(SimSpeed::new(speed));
+                info!("⏩ Simulation speed: x{}", speed);
+            }
+            Action::LaunchRocket => {
+                if !physic.launch_rocket() {
+                    debug!("🚀 Manual launch skipped: max_rockets reached");
+                }
+            }
+            Action::NextShape | Action::PrevShape => {
+                match cycle_explosion_shape(physic, action == Action::NextShape) {
+                    Ok(name) => info!("✨ Explosion shape: {}", name),
+                    Err(e) => warn!("⚠️ Explosion shape: {}", e),
+                }
+            }
+            Action::ReloadConfig
+            | Action::ToggleFullscreen
+            | Action::ToggleConsole
+            | Action::ToggleHud
+            | Action::Screenshot => {
+                debug!("'{}' is not supported by the winit backend", action.name());
+            }
+        }
+    }
+
+    /// Événement winit : traduit, routé puis appliqué ; une touche libre exécute
+    /// la commande liée par `bind`.
+    fn handle_event<P: PhysicEngineFull, A: AudioEngine>(
+        &mut self,
+        event: &winit::event::WindowEvent,
+        physic: &mut P,
+        audio: &mut A,
+        commands_registry: &CommandRegistry,
+    ) {
+        for event in translate_event(event, self.scale_factor()) {
+            match self.event_router.route(&event, false) {
+                Some(reaction) => self.apply_reaction(reaction, physic, audio),
+                None => {
+                    if let WindowEvent::KeyPress(key) = event {
+                        if let Some(command) = commands_registry.bound_command(key) {
+                            let output = commands_registry.execute(audio, physic, &command);
+                            if !output.is_empty() {
+                                info!("{}", output);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Une frame : pas de simulation partagé, rendu du graphe de passes, swap.
+    fn frame<P: PhysicEngineFull, A: AudioEngine>(
+        &mut self,
+        physic: &mut P,
+        audio: &mut A,
+        commands_registry: &CommandRegistry,
+    ) {
+        let profiler = self.profiler.clone();
+        let _frame_guard = profiler.frame();
+
+        let timing = begin_sim_frame(
+            &mut self.sim_clock,
+            commands_registry.sim_state(),
+            audio,
+            Instant::now(),
+        );
+        let delta = timing.frame_dt;
+        self.frames += 1;
+
+        self.clock += delta;
+        {
+            let config = self.shared.config.borrow();
+            self.flashes.update(delta, config.flash_duration);
+            if config.bloom_audio_reactive {
+                self.audio_envelope.update(
+                    audio.output_level(),
+                    delta,
+                    config.bloom_audio_attack,
+                    config.bloom_audio_release,
+                );
+            } else {
+                self.audio_envelope.reset();
+            }
+        }
+        {
+            let mut camera = self.shared.camera.borrow_mut();
+            camera.update(delta);
+            if camera.config().listener_follows_camera {
+                audio.set_listener_position((camera.center.x, camera.center.y));
+            }
+        }
+
+        if !timing.paused {
+            let flashes = step_simulation(
+                Some(&profiler),
+                physic,
+                audio,
+                commands_registry.sim_state(),
+                self.duration_limit.as_mut(),
+                timing.sim_dt,
+            );
+            for flash in flashes {
+                self.add_flash(flash);
+            }
+        } else {
+            let active = physic.get_stats().active_particles;
+            profiler.record_metric(ACTIVE_PARTICLES_METRIC, active.explosions + active.trails);
+        }
+
+        let Some(gl) = &mut self.gl else {
+            return;
+        };
+        let config = self.shared.config.borrow();
+        if !self.occluded {
+            let view = SceneView {
+                view_proj: self.shared.camera.borrow().view_projection(),
+                clock: self.clock,
+                content_scale: self.event_router.display.particle_scale(),
+                audio_level: self.audio_envelope.value(),
+                srgb_target: gl.srgb_capable,
+            };
+            profiler.profile_block("render frame", || unsafe {
+                gl::ClearColor(0.0, 0.0, 0.0, 1.0);
+                gl::Clear(gl::COLOR_BUFFER_BIT);
+                render_scene(
+                    &mut gl.frame_graph,
+                    &mut gl.resources,
+                    &config,
+                    &self.flashes,
+                    physic,
+                    &view,
+                );
+            });
+            profiler.record_metric("total particles drawn", gl.resources.particles_drawn);
+            gl.frame_graph
+                .status_into(&mut self.shared.passes.borrow_mut());
+            let size = gl.window.inner_size();
+            gl.resources
+                .stats
+                .add_framebuffer("output", size.width, size.height);
+            gl.resources.record_allocations();
+            self.shared
+                .stats
+                .borrow_mut()
+                .clone_from(&gl.resources.stats);
+
+            if let Err(e) = gl.surface.swap_buffers(&gl.context) {
+                warn!("⚠️ Swap buffers failed: {}", e);
+            }
+        }
+
+        // V-sync modifiable à chaud ; sinon plafond CPU éventuel
+        if config.vsync != gl.vsync {
+            set_swap_interval(&gl.surface, &gl.context, config.vsync);
+            gl.vsync = config.vsync;
+            info!("🖥️ V-sync: {}", if config.vsync { "on" } else { "off" });
+        }
+        let budget = if self.occluded {
+            Some(Duration::from_millis(100))
+        } else {
+            config.frame_budget()
+        };
+        profiler.record_metric("frame limiter wait", self.frame_limiter.wait(budget));
+    }
+
+    fn add_flash(&mut self, flash: Flash) {
+        if self.shared.config.borrow().flash_enabled {
+            self.flashes.add(flash);
+        }
+    }
+
+    /// Libère les ressources GL (contexte encore courant) puis la fenêtre. Idempotent.
+    fn release_window(&mut self) {
+        if let Some(mut gl) = self.gl.take() {
+            info!("🧹 Fermeture du renderer winit");
+            unsafe {
+                gl.frame_graph.close();
+                gl.resources.close();
+            }
+        }
+    }
+}
+
+/// Configuration de framebuffer : sRGB si demandé et disponible, sinon la première.
+fn pick_config(configs: Box<dyn Iterator<Item = Config> + '_>, srgb: bool) -> Config {
+    configs
+        .reduce(|best, config| {
+            if srgb && config.srgb_capable() && !best.srgb_capable() {
+                config
+            } else {
+                best
+            }
+        })
+        .expect("Aucune configuration OpenGL disponible")
+}
+
+fn set_swap_interval(
+    surface: &Surface<WindowSurface>,
+    context: &PossiblyCurrentContext,
+    vsync: bool,
+) {
+    let interval = if vsync {
+        SwapInterval::Wait(NonZeroU32::MIN)
+    } else {
+        SwapInterval::DontWait
+    };
+    if let Err(e) = surface.set_swap_interval(context, interval) {
+        warn!("⚠️ V-sync not changed: {}", e);
+    }
+}
+
+/// Boucle d'événements winit : emprunte le renderer et les deux moteurs le temps
+/// de `run_app`.
+struct WinitApp<'a, P, A> {
+    renderer: &'a mut WinitRenderer,
+    physic: &'a mut P,
+    audio: &'a mut A,
+    commands_registry: &'a CommandRegistry,
+    /// Échec de création de la fenêtre, rendu par `run_loop`
+    error: Option<anyhow::Error>,
+    last_log: Instant,
+}
+
+/// Intervalle entre deux journaux des métriques
+const LOG_INTERVAL: Duration = Duration::from_secs(5);
+
+impl<P: PhysicEngineFull, A: AudioEngine> ApplicationHandler for WinitApp<'_, P, A> {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.renderer.gl.is_some() {
+            return;
+        }
+        if let Err(e) = self.renderer.create_window(event_loop) {
+            self.error = Some(e);
+            event_loop.exit();
+            return;
+        }
+        let (width, height) = self.renderer.event_router.display.framebuffer_size;
+        self.renderer
+            .apply_reaction(Reaction::Resize(width, height), self.physic, self.audio);
+        info!("🪟 Window ready (winit)");
+    }
+
+    fn window_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: winit::event::WindowEvent,
+    ) {
+        self.renderer
+            .handle_event(&event, self.physic, self.audio, self.commands_registry);
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if self.renderer.is_finished(self.physic) {
+            event_loop.exit();
+            return;
+        }
+        self.renderer
+            .frame(self.physic, self.audio, self.commands_registry);
+
+        if self.last_log.elapsed() >= LOG_INTERVAL {
+            log_metrics_and_fps!(&self.renderer.profiler);
+            if let Err(e) = self.renderer.profiler.flush_periodic() {
+                warn!("⚠️ Periodic metrics export failed: {e:#}");
+            }
+            self.last_log = Instant::now();
+        }
+    }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Contexte encore courant : dernier moment pour libérer les ressources GL
+        self.renderer.release_window();
+    }
+}
+
+impl RendererEngine for WinitRenderer {
+    fn run_loop<P: PhysicEngineFull, A: AudioEngine>(
+        &mut self,
+        physic: &mut P,
+        audio: &mut A,
+        commands_registry: &CommandRegistry,
+    ) -> Result<()> {
+        let event_loop = EventLoop::new()?;
+        event_loop.set_control_flow(ControlFlow::Poll);
+        let mut app = WinitApp {
+            renderer: self,
+            physic,
+            audio,
+            commands_registry,
+            error: None,
+            last_log: Instant::now(),
+        };
+        event_loop.run_app(&mut app)?;
+        match app.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn close(&mut self) {
+        self.release_window();
+    }
+
+    fn register_commands(&self, registry: &mut CommandRegistry) {
+        register_renderer_commands(registry, &self.shared);
+        registry.set_sim_state(SimState::from(&self.shared));
+    }
+
+    fn render_stats(&self) -> RenderStats {
+        self.shared.stats.borrow().clone()
+    }
+
+    fn set_frame_limit(&mut self, frames: Option<u64>) {
+        self.frame_limit = frames;
+    }
+
+    fn set_duration_limit(&mut self, limit: Option<DurationLimit>) {
+        self.duration_limit = limit;
+    }
+
+    fn add_flash(&mut self, flash: Flash) {
+        self.add_flash(flash);
+    }
+
+    fn profiler(&self) -> Option<&Profiler> {
+        Some(&self.profiler)
+    }
+}
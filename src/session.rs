@@ -0,0 +1,157 @@
+//! Session : état de l'application retrouvé d'un lancement à l'autre (taille et
+//! position de la fenêtre, plein écran, caméra, réglages du rendu modifiés à
+//! chaud, volume audio, palette et forme d'explosion).
+//!
+//! Enregistrée à la fermeture de `run`, relue au démarrage après les fichiers de
+//! config, dont elle surcharge les valeurs. Priorités, de la plus faible à la
+//! plus forte : valeurs par défaut < fichiers de config < session < ligne de
+//! commande. `--fresh` l'ignore, `sim.session.reset` l'efface.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::app_options::AppOptions;
+use crate::renderer_engine::config::RendererConfig;
+use crate::utils::assets::asset_path;
+
+/// Fichier de session par défaut
+pub const SESSION_PATH: &str = "assets/config/session.toml";
+
+/// Fenêtre (position et taille en mode fenêtré)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct WindowSession {
+    pub size: [i32; 2],
+    pub position: Option<[i32; 2]>,
+    pub fullscreen: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CameraSession {
+    pub center: [f32; 2],
+    pub zoom: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AudioSession {
+    pub volume: f32,
+    pub muted: bool,
+}
+
+/// Palette des fusées et forme des explosions
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PhysicSession {
+    pub rocket_palette: Vec<[f32; 3]>,
+    /// `sphere`, `images` (bibliothèque de formes, pondérations comprises) ou
+    /// une forme procédurale
+    pub explosion_shape: String,
+    #[serde(default)]
+    pub explosion_params: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Session {
+    pub window: Option<WindowSession>,
+    pub camera: Option<CameraSession>,
+    pub audio: Option<AudioSession>,
+    pub physic: Option<PhysicSession>,
+    /// Clés de `RendererConfig` qui diffèrent du fichier de config
+    pub renderer: toml::Table,
+}
+
+impl Session {
+    /// Session enregistrée (`None` si le fichier n'existe pas).
+    pub fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        let path = &asset_path(path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = std::fs::read_to_string(path)?;
+        let session = toml::from_str(&text)
+            .with_context(|| format!("invalid session file {}", path.display()))?;
+        Ok(Some(session))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let path = &asset_path(path);
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("cannot write session file {}", path.display()))
+    }
+
+    /// Taille et plein écran de la session, sauf ce que la ligne de commande
+    /// fixe déjà (`--size`, `--fullscreen`).
+    pub fn apply_to_options(&self, options: &mut AppOptions) {
+        let Some(window) = &self.window else {
+            return;
+        };
+        if !options.size_from_cli && window.size.iter().all(|&d| d > 0) {
+            options.size = (window.size[0], window.size[1]);
+        }
+        options.fullscreen |= window.fullscreen;
+    }
+
+    /// Retient les réglages de `current` qui diffèrent de `base` (le fichier de config).
+    pub fn set_renderer_overrides(
+        &mut self,
+        base: &RendererConfig,
+        current: &RendererConfig,
+    ) -> anyhow::Result<()> {
+        self.renderer = toml_diff(
+            &toml::Table::try_from(base)?,
+            &toml::Table::try_from(current)?,
+        );
+        Ok(())
+    }
+
+    /// `config` surchargée par les réglages de la session.
+    pub fn apply_renderer_overrides(
+        &self,
+        config: &RendererConfig,
+    ) -> anyhow::Result<RendererConfig> {
+        let mut table = toml::Table::try_from(config)?;
+        toml_merge(&mut table, &self.renderer);
+        table
+            .try_into()
+            .context("invalid renderer settings in the session")
+    }
+}
+
+/// Entrées de `current` absentes de `base` ou de valeur différente (tables comparées
+/// clé par clé).
+fn toml_diff(base: &toml::Table, current: &toml::Table) -> toml::Table {
+    let mut diff = toml::Table::new();
+    for (key, value) in current {
+        match (base.get(key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(current)) => {
+                let nested = toml_diff(base, current);
+                if !nested.is_empty() {
+                    diff.insert(key.clone(), toml::Value::Table(nested));
+                }
+            }
+            (Some(base), value) if base == value => {}
+            _ => {
+                diff.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    diff
+}
+
+/// Recopie `overrides` dans `table`, récursivement pour les tables.
+pub(crate) fn toml_merge(table: &mut toml::Table, overrides: &toml::Table) {
+    for (key, value) in overrides {
+        match (table.get_mut(key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(nested)) => {
+                toml_merge(existing, nested)
+            }
+            _ => {
+                table.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
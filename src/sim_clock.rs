@@ -0,0 +1,167 @@
+//! Horloge de la simulation : convertit le temps réel écoulé entre deux frames
+//! en pas de physique, avec la pause globale (touche P, `sim.pause`).
+//!
+//! En pause, le rendu continue (console, réglages, captures d'écran) mais la
+//! physique et l'audio sont figés. À la reprise, le premier pas est borné pour
+//! que la simulation ne rattrape pas d'un coup une frame anormalement longue.
+//!
+//! La vitesse de simulation (`sim.speed`, touches `[` / `]`) multiplie le pas :
+//! ralenti sous 1, accéléré au-dessus.
+
+use std::time::Instant;
+
+/// Pas de simulation maximal de la première frame après une pause (s)
+pub const RESUME_MAX_DT: f32 = 1.0 / 60.0;
+
+/// Bornes de la vitesse de simulation
+pub const SIM_SPEED_MIN: f32 = 0.1;
+pub const SIM_SPEED_MAX: f32 = 4.0;
+
+/// Vitesses parcourues par les touches `speed_down` / `speed_up`
+pub const SIM_SPEED_PRESETS: [f32; 7] = [0.1, 0.25, 0.5, 1.0, 1.5, 2.0, 4.0];
+
+/// Vitesse ramenée dans `[SIM_SPEED_MIN, SIM_SPEED_MAX]` (1 si non finie)
+pub fn clamp_sim_speed(speed: f32) -> f32 {
+    if speed.is_finite() {
+        speed.clamp(SIM_SPEED_MIN, SIM_SPEED_MAX)
+    } else {
+        1.0
+    }
+}
+
+/// Vitesse de simulation bornée, partagée entre la console et la boucle de
+/// rendu (1 par défaut).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimSpeed(f32);
+
+impl SimSpeed {
+    pub fn new(speed: f32) -> Self {
+        Self(clamp_sim_speed(speed))
+    }
+
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Default for SimSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Vitesse prédéfinie suivante (`faster`) ou précédente, depuis `current`
+/// (qui n'est pas forcément une des vitesses prédéfinies). Reste aux bornes.
+pub fn next_speed_preset(current: f32, faster: bool) -> f32 {
+    const EPSILON: f32 = 1e-3;
+    let next = if faster {
+        SIM_SPEED_PRESETS
+            .into_iter()
+            .find(|&preset| preset > current + EPSILON)
+    } else {
+        SIM_SPEED_PRESETS
+            .into_iter()
+            .rev()
+            .find(|&preset| preset < current - EPSILON)
+    };
+    next.unwrap_or(clamp_sim_speed(current))
+}
+
+/// Durées d'une frame, calculées par `SimClock::update_frame_timing`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameTiming {
+    /// Temps réel écoulé depuis la frame précédente (s)
+    pub frame_dt: f32,
+    /// Pas à donner à la physique (s), vitesse appliquée : 0 en pause
+    pub sim_dt: f32,
+    pub paused: bool,
+}
+
+/// Horloge de la simulation, pilotée par des instants fournis par l'appelant
+/// (`Instant::now()` dans la boucle de rendu, instants synthétiques en test).
+#[derive(Debug, Clone)]
+pub struct SimClock {
+    last_frame: Option<Instant>,
+    paused: bool,
+    /// La prochaine frame est la première après une pause
+    resuming: bool,
+    /// Multiplicateur du pas (cf. `clamp_sim_speed`)
+    time_scale: f32,
+    /// Temps simulé cumulé (s), hors pauses
+    sim_time: f64,
+}
+
+impl Default for SimClock {
+    fn default() -> Self {
+        Self {
+            last_frame: None,
+            paused: false,
+            resuming: false,
+            time_scale: 1.0,
+            sim_time: 0.0,
+        }
+    }
+}
+
+impl SimClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Change la vitesse de simulation ; retourne la vitesse appliquée (bornée).
+    pub fn set_time_scale(&mut self, scale: f32) -> f32 {
+        self.time_scale = clamp_sim_speed(scale);
+        self.time_scale
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Met en pause ou reprend ; retourne `true` si l'état a changé.
+    pub fn set_paused(&mut self, paused: bool) -> bool {
+        if self.paused == paused {
+            return false;
+        }
+        self.paused = paused;
+        self.resuming = !paused;
+        true
+    }
+
+    /// Temps simulé cumulé (s), les pauses exclues
+    pub fn sim_time(&self) -> f64 {
+        self.sim_time
+    }
+
+    /// Instant de la dernière frame (`None` avant la première)
+    pub fn last_frame(&self) -> Option<Instant> {
+        self.last_frame
+    }
+
+    /// Début d'une frame à l'instant `now`. La première frame a une durée nulle.
+    pub fn update_frame_timing(&mut self, now: Instant) -> FrameTiming {
+        let frame_dt = self.last_frame.map_or(0.0, |last| {
+            now.saturating_duration_since(last).as_secs_f32()
+        });
+        self.last_frame = Some(now);
+
+        let sim_dt = if self.paused {
+            0.0
+        } else if std::mem::take(&mut self.resuming) {
+            frame_dt.min(RESUME_MAX_DT) * self.time_scale
+        } else {
+            frame_dt * self.time_scale
+        };
+        self.sim_time += sim_dt as f64;
+
+        FrameTiming {
+            frame_dt,
+            sim_dt,
+            paused: self.paused,
+        }
+    }
+}
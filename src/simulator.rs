@@ -1,7 +1,120 @@
-use crate::audio_engine::AudioEngine;
-use crate::physic_engine::{PhysicEngine, PhysicEngineFull};
+use crate::audio_engine::{play_physic_events, AudioEngine, MusicTrack, OnsetSettings};
+use crate::bench::{BenchReport, ACTIVE_PARTICLES_METRIC};
+use crate::demo_director::DemoConfig;
+use crate::duration_limit::DurationLimit;
+use crate::physic_engine::any_engine::physic_update_label;
+use crate::physic_engine::attractor::ATTRACTOR_DEFAULT_RADIUS;
+use crate::physic_engine::explosion_shape::restore_explosion_shape;
+use crate::physic_engine::{
+    Choreography, FinaleSettings, ParametricKind, PhysicEngine, PhysicEngineFull, PHYSIC_ENGINES,
+};
+use crate::profiler::{Profiler, ProfilerCategory, FRAME_LABEL};
+use crate::profiler_export::MetricsFormat;
 use crate::renderer_engine::command_console::CommandRegistry;
+use crate::renderer_engine::command_script::{ExecArgs, ScriptReport};
+use crate::renderer_engine::command_sim::{register_sim_commands, SimState};
+use crate::renderer_engine::flash::Flash;
 use crate::renderer_engine::RendererEngine;
+use crate::session::{AudioSession, PhysicSession, Session};
+use crate::sim_clock::{FrameTiming, SimClock};
+use glam::Vec2;
+use log::{info, warn};
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Intervalle (simulé) entre deux logs de métriques en mode headless
+const HEADLESS_LOG_INTERVAL_S: f32 = 5.0;
+
+/// Un pas de simulation sans rendu : physique (profilée), sons déclenchés, rendu
+/// audio hors-ligne (`AudioEngine::advance_offline`), puis directeur du mode démo
+/// (`state.demo`) et durée limite du spectacle. Seul pas de simulation du projet :
+/// partagé par `Simulator::step`, la boucle de rendu et les renderers sans
+/// boucle d'événements (`bench --renderer none|offscreen`).
+///
+/// Retourne les éclairs des explosions déclenchées, à transmettre au renderer.
+pub fn step_simulation<P, A>(
+    profiler: Option<&Profiler>,
+    physic: &mut P,
+    audio: &mut A,
+    state: &SimState,
+    duration_limit: Option<&mut DurationLimit>,
+    dt: f32,
+) -> Vec<Flash>
+where
+    P: PhysicEngineFull,
+    A: AudioEngine,
+{
+    let update_result = match profiler {
+        Some(p) => {
+            // Mesure globale, et par implémentation (comparaison A/B, `physic.engine`)
+            let physics = p.for_category(ProfilerCategory::Physics);
+            let label = physic_update_label(physic.engine_name());
+            physics.profile_block("physic - update", || {
+                physics.profile_block(label, || physic.update(dt))
+            })
+        }
+        None => physic.update(dt),
+    };
+    play_physic_events(&update_result, audio);
+    let flashes = update_result
+        .triggered_explosions
+        .iter()
+        .map(Flash::from_explosion)
+        .collect();
+    audio.advance_offline(dt);
+    state
+        .demo
+        .borrow_mut()
+        .tick(dt, physic, &mut state.config.borrow_mut());
+    if let Some(limit) = duration_limit {
+        limit.tick(dt, physic);
+    }
+    if let Some(p) = profiler {
+        let active = physic.get_stats().active_particles;
+        p.record_metric(ACTIVE_PARTICLES_METRIC, active.explosions + active.trails);
+    }
+    flashes
+}
+
+/// Début d'une frame de la boucle de rendu : la pause (touche P, `sim.pause`) et
+/// la vitesse (`sim.speed`, touches `[` / `]`) demandées dans `state` sont
+/// appliquées à `clock`, et transmises à l'audio quand elles changent. Retourne
+/// les durées de la frame commencée à `now` (pas nul en pause).
+pub fn begin_sim_frame<A: AudioEngine>(
+    clock: &mut SimClock,
+    state: &SimState,
+    audio: &mut A,
+    now: Instant,
+) -> FrameTiming {
+    let paused = state.paused.get();
+    if clock.set_paused(paused) {
+        audio.set_paused(paused);
+    }
+    let speed = state.sim_speed.get().value();
+    if speed != clock.time_scale() {
+        audio.set_time_scale(clock.set_time_scale(speed));
+    }
+    clock.update_frame_timing(now)
+}
+
+/// Bilan d'une exécution headless (`Simulator::run_headless`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadlessReport {
+    pub steps: u64,
+    /// Durée simulée (s)
+    pub simulated: f32,
+    /// Durée réelle de l'exécution
+    pub elapsed: Duration,
+}
+
+impl HeadlessReport {
+    /// Facteur d'accélération par rapport au temps réel
+    pub fn speedup(&self) -> f32 {
+        self.simulated / self.elapsed.as_secs_f32().max(f32::EPSILON)
+    }
+}
 
 pub struct Simulator<R, P, A>
 where
@@ -13,6 +126,20 @@ where
     physic_engine: P,
     pub audio_engine: A,
     pub commands_registry: CommandRegistry,
+    /// Résumé des métriques écrit à la fermeture (`--metrics-out`)
+    metrics_out: Option<PathBuf>,
+    /// `run` s'arrête après ce nombre de frames (`bench`)
+    frame_limit: Option<u64>,
+    /// Fin du spectacle (`--duration`)
+    duration_limit: Option<DurationLimit>,
+    /// `run` rend l'audio hors-ligne au lieu d'ouvrir le périphérique
+    offline_audio: bool,
+    /// `close` déjà appelé (explicitement ou par `Drop`)
+    closed: bool,
+    /// Session enregistrée à la fermeture (`run`)
+    session_file: Option<PathBuf>,
+    /// `sim.session.reset` : session effacée, rien d'enregistré à la fermeture
+    session_reset: Rc<Cell<bool>>,
 }
 
 impl<R, P, A> Simulator<R, P, A>
@@ -27,11 +154,32 @@ where
             physic_engine,
             audio_engine,
             commands_registry: CommandRegistry::new(),
+            metrics_out: None,
+            frame_limit: None,
+            duration_limit: None,
+            offline_audio: false,
+            closed: false,
+            session_file: None,
+            session_reset: Rc::new(Cell::new(false)),
         }
     }
 
     pub fn run(&mut self, export_path: Option<&str>) -> anyhow::Result<()> {
-        self.audio_engine.start_audio_thread(export_path);
+        if self.offline_audio {
+            self.audio_engine.start_offline(export_path);
+        } else {
+            self.audio_engine.start_audio_thread(export_path);
+        }
+        if let Some(frames) = self.frame_limit {
+            // Toutes les frames du run restent dans les fenêtres du profiler
+            if let Some(profiler) = self.renderer_engine.profiler() {
+                profiler.set_default_window(frames as usize);
+                profiler.set_window(FRAME_LABEL, frames as usize);
+            }
+        }
+        self.renderer_engine.set_frame_limit(self.frame_limit);
+        self.renderer_engine
+            .set_duration_limit(self.duration_limit.clone());
 
         // On passe les références mutables des moteurs au Renderer
         self.renderer_engine.run_loop(
@@ -43,7 +191,288 @@ where
         Ok(())
     }
 
+    /// Avance la simulation d'un pas fixe `dt`, sans rendu (cf. `step_simulation`).
+    pub fn step(&mut self, dt: f32) {
+        let profiler = self.renderer_engine.profiler().cloned();
+        let _frame_guard = profiler.as_ref().map(|p| p.frame());
+        let flashes = step_simulation(
+            profiler.as_ref(),
+            &mut self.physic_engine,
+            &mut self.audio_engine,
+            self.commands_registry.sim_state(),
+            self.duration_limit.as_mut(),
+            dt,
+        );
+        for flash in flashes {
+            self.renderer_engine.add_flash(flash);
+        }
+    }
+
+    /// Mode démo (`--demo`, `sim.demo`) : cf. `DemoDirector`.
+    pub fn set_demo(&mut self, enabled: bool) {
+        let state = self.commands_registry.sim_state();
+        state.demo.borrow_mut().set_enabled(
+            enabled,
+            &mut self.physic_engine,
+            &mut state.config.borrow_mut(),
+        );
+    }
+
+    pub fn is_demo(&self) -> bool {
+        self.commands_registry
+            .sim_state()
+            .demo
+            .borrow()
+            .is_enabled()
+    }
+
+    /// Bande-son (`--music`) : les attaques de la piste deviennent une
+    /// chorégraphie dont les explosions tombent sur les temps forts, et la piste
+    /// est mixée par le moteur audio. Retourne le nombre de lancements prévus.
+    pub fn set_music(&mut self, path: &Path) -> anyhow::Result<usize> {
+        let track = MusicTrack::load(path)?;
+        let onsets = track.onsets(&OnsetSettings::default());
+        let flight_time = self.physic_engine.get_config().rocket_flight_time();
+        let choreography = Choreography::from_onsets(&onsets, flight_time);
+        let launches = choreography.launch_times().len();
+        info!(
+            "🎵 Music {} ({:.1} s): {} onsets",
+            path.display(),
+            track.duration(),
+            onsets.len()
+        );
+        self.physic_engine.set_choreography(Some(choreography));
+        self.audio_engine
+            .play_music(&track.samples, track.sample_rate, 1.0);
+        Ok(launches)
+    }
+
+    /// Session enregistrée dans `path` à la fermeture (`None` : pas de session).
+    pub fn set_session_file(&mut self, path: Option<PathBuf>) {
+        self.session_file = path;
+    }
+
+    /// État courant des moteurs, tel qu'enregistré dans la session.
+    pub fn capture_session(&self) -> Session {
+        let mut session = Session::default();
+        self.renderer_engine.save_session(&mut session);
+        session.audio = Some(AudioSession {
+            volume: self.audio_engine.volume(),
+            muted: self.audio_engine.is_muted(),
+        });
+        session.physic = Some(PhysicSession {
+            rocket_palette: self.physic_engine.get_config().rocket_palette.clone(),
+            explosion_shape: self.physic_engine.explosion_shape_name().to_string(),
+            explosion_params: self.physic_engine.explosion_shape_params(),
+        });
+        session
+    }
+
+    /// Réapplique une session enregistrée, par-dessus les fichiers de config.
+    pub fn restore_session(&mut self, session: &Session) {
+        self.renderer_engine.restore_session(session);
+        if let Some(audio) = &session.audio {
+            self.audio_engine.set_volume(audio.volume);
+            if audio.muted {
+                self.audio_engine.mute();
+            }
+        }
+        if let Some(physic) = &session.physic {
+            let mut config = self.physic_engine.get_config().clone();
+            config.rocket_palette = physic.rocket_palette.clone();
+            self.physic_engine.reload_config(&config);
+            if let Err(e) = restore_explosion_shape(
+                &mut self.physic_engine,
+                &physic.explosion_shape,
+                &physic.explosion_params,
+            ) {
+                warn!(
+                    "⚠️ Session explosion shape '{}' ignored: {:#}",
+                    physic.explosion_shape, e
+                );
+            }
+        }
+        info!("💾 Session restored");
+    }
+
+    /// Enregistre la session à la fermeture, ou l'efface après `sim.session.reset`.
+    fn save_session(&self) {
+        let Some(path) = &self.session_file else {
+            return;
+        };
+        if self.session_reset.get() {
+            if path.exists() {
+                if let Err(e) = std::fs::remove_file(path) {
+                    warn!("⚠️ Cannot remove session {}: {}", path.display(), e);
+                }
+            }
+            return;
+        }
+        match self.capture_session().save(path) {
+            Ok(()) => info!("💾 Session saved to {}", path.display()),
+            Err(e) => warn!("⚠️ Session not saved: {e:#}"),
+        }
+    }
+
+    /// `run` s'arrête après `frames` frames (`None` : jusqu'à la fermeture).
+    pub fn set_frame_limit(&mut self, frames: Option<u64>) {
+        self.frame_limit = frames;
+    }
+
+    /// Le spectacle s'arrête après `duration` secondes simulées, ciel vidé
+    /// (`None` : pas de limite). Cf. `DurationLimit`.
+    pub fn set_duration_limit(&mut self, duration: Option<f32>) {
+        let clear_time = self.physic_engine.get_config().sky_clear_time();
+        self.duration_limit = duration.map(|d| DurationLimit::new(d, clear_time));
+        if let Some(duration) = duration {
+            info!(
+                "⏱️ Show limited to {:.1} s (launches stop {:.1} s before)",
+                duration, clear_time
+            );
+        }
+    }
+
+    pub fn duration_limit(&self) -> Option<&DurationLimit> {
+        self.duration_limit.as_ref()
+    }
+
+    /// `run` rend l'audio hors-ligne (pas de périphérique, résultat reproductible).
+    pub fn set_offline_audio(&mut self, offline: bool) {
+        self.offline_audio = offline;
+    }
+
+    /// Rapport de bench des mesures de `run`. Erreur si le renderer n'a pas de profiler.
+    pub fn bench_report(&self, fail_below_fps: Option<f32>) -> anyhow::Result<BenchReport> {
+        let Some(profiler) = self.renderer_engine.profiler() else {
+            anyhow::bail!("This renderer has no profiler to report");
+        };
+        Ok(BenchReport::from_profiler(
+            profiler,
+            self.audio_engine.dropped_requests(),
+            fail_below_fps,
+        ))
+    }
+
+    /// Mode headless : `duration` secondes simulées à pas fixe `dt`, aussi vite
+    /// que possible (ni fenêtre, ni GLFW, ni périphérique audio). L'audio est
+    /// rendu hors-ligne dans `export_path` s'il est donné. Avec une durée limite
+    /// (`set_duration_limit`), c'est elle qui arrête la boucle, ciel vidé.
+    pub fn run_headless(
+        &mut self,
+        duration: f32,
+        dt: f32,
+        export_path: Option<&str>,
+    ) -> anyhow::Result<HeadlessReport> {
+        anyhow::ensure!(dt > 0.0, "Headless time step must be positive (got {dt})");
+        anyhow::ensure!(
+            duration >= 0.0,
+            "Headless duration must not be negative (got {duration})"
+        );
+        let steps = (duration / dt).round() as u64;
+        let log_every = ((HEADLESS_LOG_INTERVAL_S / dt).round() as u64).max(1);
+        info!(
+            "🎞️ Headless run: {:.1}s simulated, {} steps (dt = {:.4}s)",
+            duration, steps, dt
+        );
+
+        self.audio_engine.start_offline(export_path);
+        let start = Instant::now();
+        let mut step = 0;
+        loop {
+            let done = match &self.duration_limit {
+                Some(limit) => limit.is_finished(self.physic_engine.get_stats().active_rockets),
+                None => step >= steps,
+            };
+            if done {
+                break;
+            }
+            step += 1;
+            self.step(dt);
+            if step % log_every == 0 {
+                if let Some(profiler) = self.renderer_engine.profiler() {
+                    crate::log_metrics!(profiler);
+                    if let Err(e) = profiler.flush_periodic() {
+                        warn!("⚠️ Periodic metrics export failed: {e:#}");
+                    }
+                }
+            }
+        }
+
+        let report = HeadlessReport {
+            steps: step,
+            simulated: step as f32 * dt,
+            elapsed: start.elapsed(),
+        };
+        info!(
+            "🏁 Headless run done: {:.1}s simulated in {:.2?} (x{:.1} real time)",
+            report.simulated,
+            report.elapsed,
+            report.speedup()
+        );
+        Ok(report)
+    }
+
+    /// Exécute un script de commandes console (`--exec`), avant la boucle de rendu.
+    pub fn exec_script(&mut self, args: &ExecArgs) -> anyhow::Result<ScriptReport> {
+        self.commands_registry
+            .exec_file(&mut self.audio_engine, &mut self.physic_engine, args)
+    }
+
+    /// Résumé des métriques à écrire dans `path` à la fermeture (CSV, ou JSON
+    /// pour une extension `.json`).
+    pub fn set_metrics_output(&mut self, path: impl Into<PathBuf>) {
+        self.metrics_out = Some(path.into());
+    }
+
+    /// Ajoute une ligne de métriques dans `path` à chaque intervalle de log du renderer.
+    pub fn set_metrics_append(&mut self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        match self.renderer_engine.profiler() {
+            Some(profiler) => profiler.set_periodic_export(path, MetricsFormat::from_path(path)),
+            None => anyhow::bail!("This renderer has no profiler to export"),
+        }
+    }
+
+    /// Écrit le résumé du profiler du renderer, complété des compteurs physique
+    /// et audio, dans `path`. Retourne `false` si le renderer n'a pas de profiler.
+    pub fn export_metrics(&self, path: impl AsRef<Path>) -> anyhow::Result<bool> {
+        let Some(profiler) = self.renderer_engine.profiler() else {
+            return Ok(false);
+        };
+        let stats = self.physic_engine.get_stats();
+        profiler.record_metric("physic: active rockets", stats.active_rockets);
+        profiler.record_metric(
+            "physic: active explosion particles",
+            stats.active_particles.explosions,
+        );
+        profiler.record_metric(
+            "physic: active trail particles",
+            stats.active_particles.trails,
+        );
+        profiler.record_metric(
+            "physic: allocation failures",
+            stats.allocation_failures as usize,
+        );
+        profiler.record_metric("audio: active voices", self.audio_engine.active_voices());
+
+        let path = path.as_ref();
+        profiler.export_summary(path, MetricsFormat::from_path(path))?;
+        Ok(true)
+    }
+
+    /// Ferme les moteurs et exporte les métriques. Idempotent : appelé aussi par
+    /// `Drop` (retour anticipé, panique du thread de rendu).
     pub fn close(&mut self) {
+        if std::mem::replace(&mut self.closed, true) {
+            return;
+        }
+        if let Some(path) = self.metrics_out.clone() {
+            if let Err(e) = self.export_metrics(&path) {
+                warn!("⚠️ Metrics export to {} failed: {e:#}", path.display());
+            }
+        }
+        // Avant la fermeture du renderer, qui détruit la fenêtre
+        self.save_session();
         self.renderer_engine.close();
         self.physic_engine.close();
         self.audio_engine.stop_audio_thread();
@@ -69,6 +498,10 @@ where
     A: AudioEngine,
 {
     pub fn init_console_commands(&mut self) {
+        self.renderer_engine
+            .register_commands(&mut self.commands_registry);
+        register_sim_commands(&mut self.commands_registry);
+
         // Commande "mute"
         self.commands_registry.register_for_audio(
             "audio.mute",
@@ -77,6 +510,8 @@ where
                 "Audio muted".to_string()
             },
         );
+        self.commands_registry
+            .register_description("audio.mute", "Mute all sounds");
 
         // Tu pourrais ajouter d'autres commandes ici (unmute, volume, etc.)
         self.commands_registry.register_for_audio(
@@ -86,6 +521,8 @@ where
                 "Audio unmuted".to_string()
             },
         );
+        self.commands_registry
+            .register_description("audio.unmute", "Restore the sounds muted by audio.mute");
 
         self.commands_registry
             // register_physic est ici une méthode qui stocke la closure pour
@@ -96,5 +533,253 @@ where
                 // Or, get_config() est bien dans PhysicEngine (maintenant Dyn Compatible).
                 format!("{:#?}", engine.get_config())
             });
+        self.commands_registry
+            .register_description("physic.config", "Show the physics configuration");
+
+        self.commands_registry
+            .register_for_physic("physic.stats", |engine: &mut dyn PhysicEngine, _args| {
+                format!("{:#?}", engine.get_stats())
+            });
+        self.commands_registry.register_description(
+            "physic.stats",
+            "Show the active rockets and particles counters",
+        );
+
+        self.commands_registry.register_for_physic(
+            "physic.compact",
+            |engine: &mut dyn PhysicEngine, _args| {
+                let before = engine.get_stats().pool_fragmentation;
+                let moved = engine.compact_pools();
+                format!(
+                    "Particle pools compacted: {} blocks moved, fragmentation {:.1}% -> {:.1}%",
+                    moved,
+                    before,
+                    engine.get_stats().pool_fragmentation
+                )
+            },
+        );
+        self.commands_registry.register_description(
+            "physic.compact",
+            "Move the particle blocks in use to the front of the pools now",
+        );
+
+        // Final : "physic.finale 12 big gold", "physic.finale stop"
+        self.commands_registry
+            .register_for_physic("physic.finale", finale_command);
+        self.commands_registry
+            .register_usage("physic.finale", "[<seconds> [big] [palette] | stop]");
+        self.commands_registry
+            .register_args("physic.finale", &[&["stop"], &["big"]]);
+        self.commands_registry.register_description(
+            "physic.finale",
+            "Launch rockets as fast as possible for a while, with a ramp up and down",
+        );
+
+        // Formes d'explosion procédurales : "physic.shape.ring 0.4 0.05", etc.
+        for &kind in ParametricKind::NAMES {
+            self.commands_registry.register_for_physic(
+                &format!("physic.shape.{}", kind),
+                move |engine: &mut dyn PhysicEngine, args| {
+                    // `args` contient la ligne complète : on saute le nom de la commande
+                    let params: Result<Vec<f32>, _> = args
+                        .split_whitespace()
+                        .skip(1)
+                        .map(str::parse::<f32>)
+                        .collect();
+                    let Ok(params) = params else {
+                        return format!("Invalid parameters for '{}': {}", kind, args);
+                    };
+                    match engine.load_explosion_parametric(kind, &params) {
+                        Ok(()) => format!("Explosion shape: {}", kind),
+                        Err(e) => format!("Error: {}", e),
+                    }
+                },
+            );
+            let name = format!("physic.shape.{}", kind);
+            self.commands_registry.register_usage(&name, "[params...]");
+            self.commands_registry.register_description(
+                &name,
+                &format!("Use the procedural '{}' explosion shape", kind),
+            );
+        }
+
+        // Attracteurs : "physic.attractor.add <x> <y> <strength> [radius]"
+        self.commands_registry.register_for_physic(
+            "physic.attractor.add",
+            |engine: &mut dyn PhysicEngine, args| {
+                // nan / inf rejetés : un attracteur non fini contaminerait toutes les vitesses
+                let params: Option<Vec<f32>> = args
+                    .split_whitespace()
+                    .skip(1)
+                    .map(|word| word.parse::<f32>().ok().filter(|v| v.is_finite()))
+                    .collect();
+                match params.as_deref() {
+                    Some([x, y, strength]) => {
+                        let id = engine.add_attractor(
+                            Vec2::new(*x, *y),
+                            *strength,
+                            ATTRACTOR_DEFAULT_RADIUS,
+                        );
+                        format!("Attractor {} added", id.0)
+                    }
+                    Some([x, y, strength, radius]) => {
+                        let id = engine.add_attractor(Vec2::new(*x, *y), *strength, *radius);
+                        format!("Attractor {} added", id.0)
+                    }
+                    _ => "Usage: physic.attractor.add <x> <y> <strength> [radius]".to_string(),
+                }
+            },
+        );
+        self.commands_registry
+            .register_usage("physic.attractor.add", "<x> <y> <strength> [radius]");
+        self.commands_registry.register_description(
+            "physic.attractor.add",
+            "Add a point attracting (or repelling, if negative) the particles",
+        );
+
+        self.commands_registry.register_for_physic(
+            "physic.attractor.clear",
+            |engine: &mut dyn PhysicEngine, _args| {
+                let count = engine.attractors().len();
+                engine.clear_attractors();
+                format!("{} attractor(s) removed", count)
+            },
+        );
+        self.commands_registry
+            .register_description("physic.attractor.clear", "Remove all attractors");
+
+        self.commands_registry.register_for_physic(
+            "physic.shape.rescan",
+            |engine: &mut dyn PhysicEngine, _args| match engine.rescan_shapes() {
+                Ok(count) => format!("{} image shape(s) loaded", count),
+                Err(e) => format!("Error: {}", e),
+            },
+        );
+        self.commands_registry.register_description(
+            "physic.shape.rescan",
+            "Reload the image explosion shapes from disk",
+        );
+
+        self.commands_registry.register_for_physic(
+            "physic.shape.sphere",
+            |engine: &mut dyn PhysicEngine, _args| {
+                engine.clear_explosion_shape();
+                "Explosion shape: sphere".to_string()
+            },
+        );
+        self.commands_registry.register_description(
+            "physic.shape.sphere",
+            "Back to the default spherical explosions",
+        );
+
+        // Implémentation physique changée à chaud (comparaison A/B)
+        self.commands_registry.register_for_physic(
+            "physic.engine",
+            |engine: &mut dyn PhysicEngine, args| match args.split_whitespace().nth(1) {
+                None => format!(
+                    "Physics engine: {} (available: {})",
+                    engine.engine_name(),
+                    PHYSIC_ENGINES.join(", ")
+                ),
+                Some(name) => match engine.switch_engine(name) {
+                    Ok(()) => format!("Physics engine: {}", name),
+                    Err(e) => format!("Error: {}", e),
+                },
+            },
+        );
+        self.commands_registry
+            .register_usage("physic.engine", "[name]");
+        self.commands_registry
+            .register_args("physic.engine", &[PHYSIC_ENGINES]);
+        self.commands_registry.register_description(
+            "physic.engine",
+            "Show or switch the physics engine implementation (in-flight rockets are dropped)",
+        );
+
+        let session_reset = self.session_reset.clone();
+        self.commands_registry
+            .register_for_simulator("sim.session.reset", move |_ctx, _args| {
+                session_reset.set(true);
+                "Session cleared: defaults on next launch".to_string()
+            });
+        self.commands_registry.register_description(
+            "sim.session.reset",
+            "Forget the saved session (window, camera, tweaks) on exit",
+        );
+    }
+}
+
+/// `physic.finale` : état du final, démarrage (`<secondes> [big] [palette]`) ou arrêt.
+///
+/// Les palettes sont celles du mode démo (`random` : couleurs aléatoires).
+fn finale_command(engine: &mut dyn PhysicEngine, args: &str) -> String {
+    const USAGE: &str = "Usage: physic.finale [<seconds> [big] [palette] | stop]";
+    let mut words = args.split_whitespace().skip(1);
+    let Some(first) = words.next() else {
+        return match engine.get_stats().finale {
+            Some(p) => format!(
+                "Finale: {:.1} / {:.1} s, intensity {:.2}, {} rockets launched",
+                p.elapsed, p.duration, p.intensity, p.launched
+            ),
+            None => "No finale in progress".to_string(),
+        };
+    };
+    if first == "stop" {
+        return if engine.stop_finale() {
+            "Finale stopped".to_string()
+        } else {
+            "No finale in progress".to_string()
+        };
+    }
+    let Ok(duration) = first.parse::<f32>() else {
+        return USAGE.to_string();
+    };
+
+    let mut settings = FinaleSettings::new(duration);
+    let mut palette_name = None;
+    let palettes = DemoConfig::default().palettes;
+    for word in words {
+        if word == "big" {
+            settings.full_explosions = true;
+            continue;
+        }
+        let Some(palette) = palettes.iter().find(|p| p.name == word) else {
+            let names: Vec<&str> = palettes.iter().map(|p| p.name.as_str()).collect();
+            return format!(
+                "Unknown palette '{}' (available: {})",
+                word,
+                names.join(", ")
+            );
+        };
+        settings.palette = Some(palette.colors.clone());
+        palette_name = Some(word);
+    }
+
+    let full_explosions = settings.full_explosions;
+    match engine.start_finale(settings) {
+        Ok(()) => format!(
+            "Finale: {:.1} s{}{}",
+            duration,
+            if full_explosions {
+                ", full explosions"
+            } else {
+                ""
+            },
+            palette_name
+                .map(|name| format!(", palette {}", name))
+                .unwrap_or_default()
+        ),
+        Err(e) => format!("Error: {:#}", e),
+    }
+}
+
+impl<R, P, A> Drop for Simulator<R, P, A>
+where
+    R: RendererEngine,
+    P: PhysicEngineFull,
+    A: AudioEngine,
+{
+    fn drop(&mut self) {
+        self.close();
     }
 }
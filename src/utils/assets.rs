@@ -0,0 +1,157 @@
+//! Racine des assets (`--assets-dir`) et recherche des fichiers d'assets.
+//!
+//! Les chemins d'assets du code et des configs sont écrits `assets/...`
+//! (`assets/config/physic.toml`...). [`resolve`] cherche le fichier sous
+//! plusieurs racines, dans l'ordre : `--assets-dir`, `$FIREWORKS_ASSETS`,
+//! `<répertoire de l'exécutable>/assets`, `./assets`, puis les répertoires de
+//! données XDG (`<data_dir>/fireworks-sim/assets`). Les autres chemins relatifs
+//! (scripts, exports, captures) ne sont pas touchés.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+
+use log::info;
+
+/// Premier composant des chemins d'assets re-enracinés
+pub const ASSETS_DIR_NAME: &str = "assets";
+
+/// Variable d'environnement donnant une racine d'assets
+pub const ASSETS_ENV: &str = "FIREWORKS_ASSETS";
+
+/// Sous-répertoire des données XDG (`~/.local/share/fireworks-sim/assets`)
+pub const XDG_APP_DIR: &str = "fireworks-sim";
+
+static ASSETS_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Racines déjà journalisées par `resolve`
+static LOGGED_ROOTS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Fixe la racine des assets (`None` : `./assets`).
+pub fn set_assets_dir(dir: Option<PathBuf>) {
+    *ASSETS_DIR.write().unwrap_or_else(|e| e.into_inner()) = dir;
+}
+
+/// Racine fixée par `set_assets_dir`
+pub fn assets_dir() -> Option<PathBuf> {
+    ASSETS_DIR.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Chemin `assets/...` (re-enraciné et cherché par `asset_path`)
+pub fn is_asset_path(path: &Path) -> bool {
+    strip_assets_dir(path).is_some()
+}
+
+/// `<reste>` d'un chemin `assets/<reste>` (`./` initial ignoré), `None` sinon.
+fn strip_assets_dir(path: &Path) -> Option<&Path> {
+    let mut components = path.components();
+    let mut first = components.next();
+    while first == Some(Component::CurDir) {
+        first = components.next();
+    }
+    match first {
+        Some(Component::Normal(first)) if first == ASSETS_DIR_NAME => Some(components.as_path()),
+        _ => None,
+    }
+}
+
+/// `assets/<reste>` devient `<assets_dir>/<reste>` ; les autres chemins (absolus,
+/// ou relatifs hors `assets/`) sont inchangés.
+pub fn reroot(path: &Path, assets_dir: &Path) -> PathBuf {
+    match strip_assets_dir(path) {
+        Some(rest) => assets_dir.join(rest),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Racines d'assets, par ordre de priorité. `explicit` : `--assets-dir` ;
+/// `env` lit les variables d'environnement ; `exe_dir` : répertoire de
+/// l'exécutable. `./assets` reste relatif (chemins et messages inchangés).
+pub fn search_roots(
+    explicit: Option<&Path>,
+    env: impl Fn(&str) -> Option<String>,
+    exe_dir: Option<&Path>,
+) -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = explicit.map(Path::to_path_buf).into_iter().collect();
+    roots.extend(
+        env(ASSETS_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from),
+    );
+    roots.extend(exe_dir.map(|dir| dir.join(ASSETS_DIR_NAME)));
+    roots.push(PathBuf::from(ASSETS_DIR_NAME));
+
+    // XDG : $XDG_DATA_HOME (~/.local/share), puis $XDG_DATA_DIRS
+    let data_home = env("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".local/share")));
+    let data_dirs = env("XDG_DATA_DIRS")
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    let data_dirs = data_dirs
+        .split(':')
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from);
+    roots.extend(
+        data_home
+            .into_iter()
+            .chain(data_dirs)
+            .map(|dir| dir.join(XDG_APP_DIR).join(ASSETS_DIR_NAME)),
+    );
+    roots
+}
+
+/// Première racine de `roots` contenant `relative` (`assets/...` ou chemin
+/// relatif à la racine), avec le chemin trouvé.
+pub fn resolve_in(relative: &str, roots: &[PathBuf]) -> Option<(PathBuf, PathBuf)> {
+    let relative = Path::new(relative);
+    let rest = strip_assets_dir(relative).unwrap_or(relative);
+    roots
+        .iter()
+        .map(|root| (root.clone(), root.join(rest)))
+        .find(|(_, path)| path.exists())
+}
+
+/// Chemin de l'asset `relative` sous la première racine qui le contient (cf.
+/// `search_roots`) ; introuvable : sous `--assets-dir`, sinon `./assets`. La
+/// racine retenue est journalisée la première fois.
+pub fn resolve(relative: &str) -> PathBuf {
+    resolve_with(assets_dir().as_deref(), relative)
+}
+
+/// `resolve` avec la racine explicite `explicit` au lieu de `--assets-dir`.
+pub fn resolve_with(explicit: Option<&Path>, relative: &str) -> PathBuf {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    let roots = search_roots(
+        explicit,
+        |name| std::env::var(name).ok(),
+        exe_dir.as_deref(),
+    );
+    match resolve_in(relative, &roots) {
+        Some((root, path)) => {
+            let mut logged = LOGGED_ROOTS.lock().unwrap_or_else(|e| e.into_inner());
+            if !logged.contains(&root) {
+                info!("📁 Assets found in {}", root.display());
+                logged.push(root);
+            }
+            path
+        }
+        None => {
+            let root = explicit.unwrap_or(Path::new(ASSETS_DIR_NAME));
+            let relative = Path::new(relative);
+            root.join(strip_assets_dir(relative).unwrap_or(relative))
+        }
+    }
+}
+
+/// Chemin effectif d'un asset : `assets/...` est cherché par `resolve`, les
+/// autres chemins sont inchangés.
+pub fn asset_path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    match (strip_assets_dir(path), path.to_str()) {
+        (Some(_), Some(text)) => resolve(text),
+        _ => path.to_path_buf(),
+    }
+}
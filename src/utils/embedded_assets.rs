@@ -0,0 +1,108 @@
+//! Assets embarqués dans l'exécutable (feature `embedded_assets`).
+//!
+//! Les chemins d'assets sont relatifs au répertoire courant : lancé ailleurs qu'à
+//! la racine du dépôt, le programme ne trouvait rien. Avec la feature, les
+//! fichiers indispensables (configs, shaders, textures par défaut, police et deux
+//! sons courts) sont inclus dans le binaire ; [`read_asset`] lit d'abord le
+//! disque puis, si le fichier est absent, la copie embarquée. Sans la feature,
+//! la table est vide et seul le disque compte.
+
+use std::borrow::Cow;
+use std::io;
+use std::path::{Component, Path};
+
+use log::{debug, info};
+
+use crate::utils::assets::asset_path;
+
+/// Copie embarquée d'un asset, sous son chemin logique (`assets/...`)
+#[cfg(feature = "embedded_assets")]
+macro_rules! embed {
+    ($path:literal) => {
+        ($path, include_bytes!(concat!("../../", $path)) as &[u8])
+    };
+    ($path:literal, $file:literal) => {
+        ($path, include_bytes!(concat!("../../", $file)) as &[u8])
+    };
+}
+
+#[cfg(feature = "embedded_assets")]
+const EMBEDDED: &[(&str, &[u8])] = &[
+    embed!("assets/config/physic.toml"),
+    embed!("assets/config/renderer.toml"),
+    embed!("assets/config/audio.toml"),
+    embed!("assets/config/input.toml"),
+    embed!("assets/shaders/post/fullscreen.vert.glsl"),
+    embed!("assets/shaders/post/bloom_extract.frag.glsl"),
+    embed!("assets/shaders/post/bloom_blur.frag.glsl"),
+    embed!("assets/shaders/post/bloom_composition.frag.glsl"),
+    embed!("assets/shaders/post/luminance.frag.glsl"),
+    embed!("assets/shaders/post/fxaa.frag.glsl"),
+    embed!("assets/shaders/post/common/color.glsl"),
+    embed!("assets/shaders/post/common/tonemap.glsl"),
+    embed!("assets/textures/04ddeae2-7367-45f1-87e0-361d1d242630_scaled.png"),
+    embed!("assets/textures/kenney_particle-pack/PNG (Black background)/circle_05.png"),
+    embed!("assets/textures/kenney_particle-pack/PNG (Black background)/smoke_01.png"),
+    embed!("assets/textures/kenney_particle-pack/PNG (Black background)/trace_03.png"),
+    embed!("assets/fonts/PerfectDOSVGA437.ttf"),
+    // Versions courtes (mono 22 kHz) : quelques dizaines de Ko au lieu de 700
+    embed!(
+        "assets/sounds/rocket.wav",
+        "assets/embedded/sounds/rocket.wav"
+    ),
+    embed!(
+        "assets/sounds/explosion.wav",
+        "assets/embedded/sounds/explosion.wav"
+    ),
+];
+
+#[cfg(not(feature = "embedded_assets"))]
+const EMBEDDED: &[(&str, &[u8])] = &[];
+
+/// Chemins logiques des assets embarqués (vide sans la feature).
+pub fn embedded_paths() -> impl Iterator<Item = &'static str> {
+    EMBEDDED.iter().map(|(path, _)| *path)
+}
+
+/// Copie embarquée de `path` (`assets/...`, `./` initial ignoré).
+pub fn embedded(path: impl AsRef<Path>) -> Option<&'static [u8]> {
+    let path: &Path = path.as_ref();
+    let path = path
+        .components()
+        .skip_while(|c| *c == Component::CurDir)
+        .collect::<std::path::PathBuf>();
+    EMBEDDED
+        .iter()
+        .find(|(embedded, _)| Path::new(embedded) == path)
+        .map(|(_, bytes)| *bytes)
+}
+
+/// Contenu de l'asset `path` : fichier (re-enraciné, cf. `asset_path`), sinon
+/// copie embarquée. La source utilisée est journalisée.
+pub fn read_asset(path: impl AsRef<Path>) -> io::Result<Cow<'static, [u8]>> {
+    let path = path.as_ref();
+    let resolved = asset_path(path);
+    match std::fs::read(&resolved) {
+        Ok(bytes) => {
+            debug!("📄 Asset {} read from disk", resolved.display());
+            Ok(Cow::Owned(bytes))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => match embedded(path) {
+            Some(bytes) => {
+                info!(
+                    "📦 Asset {} not found on disk, using the embedded copy",
+                    path.display()
+                );
+                Ok(Cow::Borrowed(bytes))
+            }
+            None => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// `read_asset` pour un fichier texte (UTF-8).
+pub fn read_asset_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let bytes = read_asset(path)?;
+    String::from_utf8(bytes.into_owned()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
@@ -1,3 +1,12 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 pub trait HumanBytes {
     fn human_bytes(&self) -> String;
 }
@@ -27,9 +36,230 @@ macro_rules! impl_human_bytes {
 // Implémentation pour les types usuels
 impl_human_bytes!(usize, isize, u64, i64, u32, i32);
 
+/// Raccourci de `HumanBytes` pour un nombre d'octets (ex. `format(1536)` → `"1.50 KB"`).
+pub fn format(bytes: u64) -> String {
+    bytes.human_bytes()
+}
+
+/// Unités d'affichage d'une `ByteSize`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteUnits {
+    /// Puissances de 1024 : KiB, MiB, GiB
+    #[default]
+    Binary,
+    /// Puissances de 1000 : KB, MB, GB
+    Decimal,
+}
+
+impl ByteUnits {
+    /// (suffixe, facteur) du plus grand au plus petit
+    fn scale(self) -> [(&'static str, u64); 3] {
+        match self {
+            ByteUnits::Binary => [("GiB", 1 << 30), ("MiB", 1 << 20), ("KiB", 1 << 10)],
+            ByteUnits::Decimal => [("GB", 1_000_000_000), ("MB", 1_000_000), ("KB", 1_000)],
+        }
+    }
+}
+
+/// Taille en octets, lue et écrite sous forme lisible (`"256 MiB"`, `"1.5 GB"`).
+///
+/// `Display` affiche en unités binaires avec deux décimales ; la précision du
+/// format (`{:.0}`) et le drapeau `#` (unités décimales) se règlent comme pour
+/// un flottant. En TOML, la valeur est une chaîne (ou un entier d'octets).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub const fn kib(n: u64) -> Self {
+        Self(n << 10)
+    }
+
+    pub const fn mib(n: u64) -> Self {
+        Self(n << 20)
+    }
+
+    pub const fn gib(n: u64) -> Self {
+        Self(n << 30)
+    }
+
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Affichage avec `precision` décimales dans le système `units`
+    pub fn display(self, units: ByteUnits, precision: usize) -> String {
+        match units
+            .scale()
+            .into_iter()
+            .find(|(_, factor)| self.0 >= *factor)
+        {
+            Some((suffix, factor)) => {
+                format!("{:.*} {}", precision, self.0 as f64 / factor as f64, suffix)
+            }
+            None => format!("{} B", self.0),
+        }
+    }
+
+    /// Forme exacte (relue à l'identique) : plus grande unité qui divise la taille
+    fn exact(self) -> String {
+        let units = ByteUnits::Binary.scale().into_iter();
+        match units
+            .chain(ByteUnits::Decimal.scale())
+            .find(|(_, factor)| self.0 >= *factor && self.0.is_multiple_of(*factor))
+        {
+            Some((suffix, factor)) => format!("{} {}", self.0 / factor, suffix),
+            None => format!("{} B", self.0),
+        }
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = if f.alternate() {
+            ByteUnits::Decimal
+        } else {
+            ByteUnits::Binary
+        };
+        f.write_str(&self.display(units, f.precision().unwrap_or(2)))
+    }
+}
+
+/// `"<nombre> [unité]"` : unité B, KB, KiB, MB, MiB, GB ou GiB (casse ignorée,
+/// octets par défaut), nombre décimal accepté sauf en octets.
+impl FromStr for ByteSize {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> anyhow::Result<Self> {
+        let text = text.trim();
+        let split = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(text.len());
+        let (number, unit) = (&text[..split], text[split..].trim());
+        if number.is_empty() {
+            bail!("invalid byte size '{}': missing number", text);
+        }
+
+        let factor: u64 = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1_000,
+            "kib" => 1 << 10,
+            "mb" => 1_000_000,
+            "mib" => 1 << 20,
+            "gb" => 1_000_000_000,
+            "gib" => 1 << 30,
+            _ => bail!(
+                "invalid byte size '{}': unknown unit '{}' (expected B, KB, KiB, MB, MiB, GB or GiB)",
+                text,
+                unit
+            ),
+        };
+        let bytes = match number.parse::<u64>() {
+            Ok(n) => n.checked_mul(factor),
+            Err(_) => {
+                let value: f64 = number.parse().map_err(|_| {
+                    anyhow!("invalid byte size '{}': bad number '{}'", text, number)
+                })?;
+                let bytes = value * factor as f64;
+                if bytes.fract() != 0.0 && factor == 1 {
+                    bail!("invalid byte size '{}': fractional byte count", text);
+                }
+                (bytes < u64::MAX as f64).then_some(bytes.round() as u64)
+            }
+        };
+        bytes
+            .map(ByteSize)
+            .ok_or_else(|| anyhow!("invalid byte size '{}': too large", text))
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+impl Add for ByteSize {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for ByteSize {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+/// Différence bornée à zéro (une taille n'est jamais négative)
+impl Sub for ByteSize {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul<u64> for ByteSize {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Sum for ByteSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ByteSize(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a ByteSize> for ByteSize {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Serialize for ByteSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.exact())
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ByteSizeVisitor;
+
+        impl Visitor<'_> for ByteSizeVisitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a byte size such as \"256 MiB\" or a number of bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, text: &str) -> Result<ByteSize, E> {
+                text.parse().map_err(|e| E::custom(format!("{:#}", e)))
+            }
+
+            fn visit_u64<E: de::Error>(self, bytes: u64) -> Result<ByteSize, E> {
+                Ok(ByteSize(bytes))
+            }
+
+            fn visit_i64<E: de::Error>(self, bytes: i64) -> Result<ByteSize, E> {
+                u64::try_from(bytes)
+                    .map(ByteSize)
+                    .map_err(|_| E::custom("byte size must not be negative"))
+            }
+        }
+
+        deserializer.deserialize_any(ByteSizeVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::HumanBytes;
+    use super::{ByteSize, ByteUnits, HumanBytes};
 
     #[test]
     fn test_bytes_to_human_readable() {
@@ -100,6 +330,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_boundaries() {
+        use super::format;
+        assert_eq!(format(0), "0 B");
+        assert_eq!(format(1023), "1023 B");
+        assert_eq!(format(1024), "1.00 KB");
+        // Juste sous 1 MB : reste en KB
+        assert_eq!(format(1024 * 1024 - 1), "1024.00 KB");
+        assert_eq!(format(1024 * 1024), "1.00 MB");
+        assert_eq!(format(1024 * 1024 * 1024 - 1), "1024.00 MB");
+        assert_eq!(format(1024 * 1024 * 1024), "1.00 GB");
+    }
+
     #[test]
     fn test_large_values_display_correctly() {
         let big_value = 10u64 * 1024 * 1024 * 1024; // 10 GB
@@ -114,6 +357,141 @@ mod tests {
         assert!(s.starts_with("1.23"), "format incorrect: {}", s);
     }
 
+    #[test]
+    fn test_byte_size_parse_units() {
+        let parse = |s: &str| s.parse::<ByteSize>().unwrap().as_u64();
+        assert_eq!(parse("0"), 0);
+        assert_eq!(parse("512"), 512);
+        assert_eq!(parse("512 B"), 512);
+        assert_eq!(parse("1 KB"), 1_000);
+        assert_eq!(parse("1 KiB"), 1_024);
+        assert_eq!(parse("256 MiB"), 256 * 1024 * 1024);
+        assert_eq!(parse("3 MB"), 3_000_000);
+        assert_eq!(parse("2 GiB"), 2 << 30);
+        assert_eq!(parse("2 GB"), 2_000_000_000);
+        // Décimales
+        assert_eq!(parse("1.5 KiB"), 1_536);
+        assert_eq!(parse("0.5 GB"), 500_000_000);
+        assert_eq!(parse(".25 MiB"), 256 * 1024);
+        assert_eq!(parse("1.0 B"), 1);
+    }
+
+    #[test]
+    fn test_byte_size_parse_whitespace_and_case() {
+        let parse = |s: &str| s.parse::<ByteSize>().unwrap();
+        assert_eq!(parse("  256MiB  "), ByteSize::mib(256));
+        assert_eq!(parse("256\tMiB"), ByteSize::mib(256));
+        assert_eq!(parse("256 mib"), ByteSize::mib(256));
+        assert_eq!(parse("256 MIB"), ByteSize::mib(256));
+        assert_eq!(parse("1 kb"), ByteSize(1_000));
+        assert_eq!(parse("7 b"), ByteSize(7));
+    }
+
+    #[test]
+    fn test_byte_size_parse_errors() {
+        for (text, reason) in [
+            ("", "missing number"),
+            ("   ", "missing number"),
+            ("MiB", "missing number"),
+            ("-1 KiB", "missing number"),
+            ("12 TB", "unknown unit 'TB'"),
+            ("12 MiBs", "unknown unit 'MiBs'"),
+            ("12 M", "unknown unit 'M'"),
+            ("1e3", "unknown unit 'e3'"),
+            ("1.2.3 KiB", "bad number '1.2.3'"),
+            (". KiB", "bad number '.'"),
+            ("1.5 B", "fractional byte count"),
+            ("1.5", "fractional byte count"),
+            ("99999999999999999999 GiB", "too large"),
+            ("20000000000 GiB", "too large"),
+        ] {
+            let err = text.parse::<ByteSize>().unwrap_err().to_string();
+            assert!(err.contains(reason), "{:?}: {}", text, err);
+        }
+    }
+
+    #[test]
+    fn test_byte_size_display() {
+        assert_eq!(ByteSize(0).to_string(), "0 B");
+        assert_eq!(ByteSize(1023).to_string(), "1023 B");
+        assert_eq!(ByteSize(1536).to_string(), "1.50 KiB");
+        assert_eq!(ByteSize::mib(256).to_string(), "256.00 MiB");
+        assert_eq!(format!("{:.0}", ByteSize::mib(256)), "256 MiB");
+        assert_eq!(format!("{:.1}", ByteSize::gib(3)), "3.0 GiB");
+        // Unités décimales
+        assert_eq!(format!("{:#}", ByteSize(1_500)), "1.50 KB");
+        assert_eq!(format!("{:#.1}", ByteSize(2_500_000)), "2.5 MB");
+        assert_eq!(ByteSize(999).display(ByteUnits::Decimal, 3), "999 B");
+        assert_eq!(
+            ByteSize(1_234_567_890).display(ByteUnits::Decimal, 3),
+            "1.235 GB"
+        );
+    }
+
+    #[test]
+    fn test_byte_size_display_round_trip() {
+        for size in [
+            ByteSize(0),
+            ByteSize(1),
+            ByteSize(1_000),
+            ByteSize::kib(3),
+            ByteSize(1_536),
+            ByteSize::mib(256),
+            ByteSize(2_500_000),
+            ByteSize::gib(4),
+        ] {
+            for units in [ByteUnits::Binary, ByteUnits::Decimal] {
+                let text = size.display(units, 10);
+                assert_eq!(text.parse::<ByteSize>().unwrap(), size, "{}", text);
+            }
+            // Forme exacte : toujours relue à l'identique
+            assert_eq!(size.exact().parse::<ByteSize>().unwrap(), size);
+        }
+        assert_eq!(ByteSize::mib(256).exact(), "256 MiB");
+        assert_eq!(ByteSize(1_500_000).exact(), "1500 KB");
+        assert_eq!(ByteSize(1_537).exact(), "1537 B");
+    }
+
+    #[test]
+    fn test_byte_size_arithmetic() {
+        assert_eq!(ByteSize::kib(1) + ByteSize(512), ByteSize(1_536));
+        assert_eq!(ByteSize::mib(1) - ByteSize::kib(1), ByteSize(1_047_552));
+        // Soustraction bornée à zéro
+        assert_eq!(ByteSize(1) - ByteSize(2), ByteSize(0));
+        assert_eq!(ByteSize::kib(4) * 3, ByteSize::kib(12));
+        let mut total = ByteSize::default();
+        total += ByteSize(10);
+        assert_eq!(total, ByteSize(10));
+
+        let sizes = [ByteSize::kib(1), ByteSize::kib(2), ByteSize::kib(3)];
+        assert_eq!(sizes.iter().sum::<ByteSize>(), ByteSize::kib(6));
+        assert_eq!(sizes.into_iter().sum::<ByteSize>(), ByteSize::kib(6));
+    }
+
+    #[test]
+    fn test_byte_size_serde() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Budget {
+            max: ByteSize,
+        }
+
+        let budget: Budget = toml::from_str("max = \"256 MiB\"").unwrap();
+        assert_eq!(budget.max, ByteSize::mib(256));
+        let budget: Budget = toml::from_str("max = 4096").unwrap();
+        assert_eq!(budget.max, ByteSize::kib(4));
+        assert_eq!(
+            toml::to_string(&Budget {
+                max: ByteSize::gib(1)
+            })
+            .unwrap(),
+            "max = \"1 GiB\"\n"
+        );
+
+        let err = toml::from_str::<Budget>("max = \"12 parsecs\"").unwrap_err();
+        assert!(err.to_string().contains("unknown unit"), "{}", err);
+        assert!(toml::from_str::<Budget>("max = -1").is_err());
+    }
+
     #[test]
     fn test_consistency_across_types() {
         // Vérifie que différents types donnent le même résultat
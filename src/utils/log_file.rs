@@ -0,0 +1,234 @@
+//! Journal fichier avec rotation (`--log-file`), pour les longues exécutions sans
+//! surveillance.
+//!
+//! Chaque enregistrement est horodaté puis écrit dans `path` ; au-delà de
+//! `max_size`, le fichier devient `path.1` (les archives précédentes sont
+//! décalées, `path.<keep>` est supprimée) et un nouveau fichier est ouvert. Les
+//! avertissements et erreurs sont vidés immédiatement, le reste à chaque
+//! rotation, au `flush` du logger et dans le hook de panique.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{Level, Log, Metadata, Record};
+
+use crate::utils::human_bytes::ByteSize;
+use crate::utils::log_sink::LogFilter;
+
+/// Variable d'environnement équivalente à `--log-file`
+pub const LOG_FILE_ENV: &str = "FIREWORKS_LOG_FILE";
+/// Taille maximale par défaut d'un fichier de journal
+pub const DEFAULT_LOG_FILE_MAX_SIZE: ByteSize = ByteSize::mib(10);
+/// Archives conservées par défaut (`path.1` à `path.5`)
+pub const DEFAULT_LOG_FILE_KEEP: usize = 5;
+/// Filtre du fichier : tout le processus à partir d'`info`, indépendamment de `RUST_LOG`
+pub const DEFAULT_FILE_LOG_FILTER: &str = "info";
+
+static FILE_SINK: OnceLock<Arc<FileLogger>> = OnceLock::new();
+
+/// Réglages du journal fichier
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFileConfig {
+    pub path: PathBuf,
+    /// Taille au-delà de laquelle le fichier est archivé
+    pub max_size: ByteSize,
+    /// Archives conservées (0 : le fichier est simplement tronqué)
+    pub keep: usize,
+}
+
+impl LogFileConfig {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_size: DEFAULT_LOG_FILE_MAX_SIZE,
+            keep: DEFAULT_LOG_FILE_KEEP,
+        }
+    }
+}
+
+/// Chemin de l'archive `index` (`fireworks.log.2`)
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+/// Fichier en ajout, archivé dès qu'il dépasserait `max_size`.
+#[derive(Debug)]
+pub struct RotatingFile {
+    config: LogFileConfig,
+    writer: BufWriter<File>,
+    /// Taille du fichier courant (octets écrits compris)
+    size: u64,
+}
+
+impl RotatingFile {
+    /// Ouvre `config.path` en ajout (répertoires parents créés).
+    pub fn open(config: LogFileConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            config,
+            writer: BufWriter::new(file),
+            size,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.config.path
+    }
+
+    /// Écrit `line` (et un saut de ligne), après rotation si elle ferait
+    /// dépasser `max_size` un fichier non vide.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.size > 0 && self.size + len > self.config.max_size.as_u64() {
+            self.rotate()?;
+        }
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        self.size += len;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// `path` → `path.1` → ... → `path.<keep>` (supprimée), puis fichier vide
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        let path = &self.config.path;
+        let keep = self.config.keep;
+        if keep == 0 {
+            let file = File::create(path)?;
+            self.writer = BufWriter::new(file);
+            self.size = 0;
+            return Ok(());
+        }
+        match std::fs::remove_file(rotated_path(path, keep)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+        for index in (1..keep).rev() {
+            let from = rotated_path(path, index);
+            if from.exists() {
+                std::fs::rename(&from, rotated_path(path, index + 1))?;
+            }
+        }
+        std::fs::rename(path, rotated_path(path, 1))?;
+        self.writer = BufWriter::new(File::create(path)?);
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// Date UTC `AAAA-MM-JJTHH:MM:SS.mmmZ` de `time`
+pub fn format_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    // Jours depuis 1970 → date civile (algorithme de H. Hinnant)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Journal fichier : filtre propre et `RotatingFile` partagé entre threads.
+#[derive(Debug)]
+pub struct FileLogger {
+    filter: LogFilter,
+    file: Mutex<RotatingFile>,
+}
+
+impl FileLogger {
+    pub fn new(file: RotatingFile, filter: LogFilter) -> Self {
+        Self {
+            filter,
+            file: Mutex::new(file),
+        }
+    }
+
+    pub fn filter(&self) -> &LogFilter {
+        &self.filter
+    }
+
+    /// Écrit une ligne brute (message de panique) puis vide le fichier, sans
+    /// attendre un verrou tenu par le thread qui panique.
+    pub fn write_emergency(&self, line: &str) {
+        if let Ok(mut file) = self.file.try_lock() {
+            let _ = file.write_line(line);
+            let _ = file.flush();
+        }
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.filter.enabled(metadata.target(), metadata.level())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{} {:<5} [{}] {}",
+            format_timestamp(SystemTime::now()),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        // Un disque plein ne doit pas interrompre la simulation
+        if file.write_line(&line).is_ok() && record.level() <= Level::Warn {
+            let _ = file.flush();
+        }
+    }
+
+    fn flush(&self) {
+        let _ = self.file.lock().unwrap_or_else(|e| e.into_inner()).flush();
+    }
+}
+
+/// Ouvre le journal fichier global (une seule fois par processus).
+pub fn open_log_file(config: LogFileConfig) -> io::Result<Arc<FileLogger>> {
+    if let Some(logger) = FILE_SINK.get() {
+        return Ok(logger.clone());
+    }
+    let filter = DEFAULT_FILE_LOG_FILTER
+        .parse()
+        .expect("default file log filter is valid");
+    let logger = Arc::new(FileLogger::new(RotatingFile::open(config)?, filter));
+    Ok(FILE_SINK.get_or_init(|| logger).clone())
+}
+
+/// Journal fichier global, si `--log-file` est actif
+pub fn log_file() -> Option<&'static Arc<FileLogger>> {
+    FILE_SINK.get()
+}
@@ -0,0 +1,268 @@
+//! Journaux (`log`) recopiés dans la console en jeu.
+//!
+//! [`TeeLogger`] envoie chaque enregistrement à `env_logger` (stderr, `RUST_LOG`),
+//! à un [`LogSink`] et, avec `--log-file`, au journal fichier
+//! (cf. `crate::utils::log_file`). Le [`LogSink`] est une file bornée, filtrée par
+//! module, vidée à chaque frame dans la console. La file est partagée entre
+//! threads (le thread audio journalise aussi) ; pleine, elle compte les messages
+//! perdus plutôt que de grossir.
+
+use log::{info, warn, Level, LevelFilter, Log, Metadata, Record};
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::utils::log_file::{open_log_file, FileLogger, LogFileConfig};
+
+/// Filtre par défaut : les journaux du simulateur à partir d'`info` (ni cpal ni glfw)
+pub const DEFAULT_CONSOLE_LOG_FILTER: &str = "fireworks_sim=info";
+/// Messages en attente au plus entre deux frames
+pub const LOG_QUEUE_CAPACITY: usize = 256;
+
+static CONSOLE_SINK: OnceLock<Arc<LogSink>> = OnceLock::new();
+
+/// Filtre par module, syntaxe `RUST_LOG` simplifiée : `"warn,fireworks_sim=info"`.
+///
+/// Le préfixe de module le plus long l'emporte ; un niveau seul fixe le défaut
+/// (`off` si absent).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    /// (préfixe de module, niveau)
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        DEFAULT_CONSOLE_LOG_FILTER
+            .parse()
+            .expect("default console log filter is valid")
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(spec: &str) -> anyhow::Result<Self> {
+        let mut filter = Self {
+            default: LevelFilter::Off,
+            modules: Vec::new(),
+        };
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let parse_level = |level: &str| {
+                LevelFilter::from_str(level.trim())
+                    .map_err(|_| anyhow::anyhow!("Invalid log level '{}'", level.trim()))
+            };
+            match directive.split_once('=') {
+                Some((module, level)) => filter
+                    .modules
+                    .push((module.trim().to_string(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+impl LogFilter {
+    /// Niveau maximal admis pour `target`
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target == module
+                    || target
+                        .strip_prefix(module.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level_for(target)
+    }
+
+    /// Niveau le plus verbeux que le filtre peut laisser passer
+    pub fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+/// Message en attente d'affichage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    pub level: Level,
+    /// `"[module] message"` (dernier segment du module)
+    pub text: String,
+}
+
+#[derive(Debug)]
+struct SinkState {
+    filter: LogFilter,
+    queue: VecDeque<LogMessage>,
+    capacity: usize,
+    dropped: usize,
+}
+
+/// File bornée des journaux destinés à la console.
+#[derive(Debug)]
+pub struct LogSink {
+    state: Mutex<SinkState>,
+}
+
+impl LogSink {
+    pub fn new(filter: LogFilter, capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(SinkState {
+                filter,
+                queue: VecDeque::new(),
+                capacity: capacity.max(1),
+                dropped: 0,
+            }),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, SinkState> {
+        // Un thread qui panique en journalisant ne doit pas couper les journaux
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn filter(&self) -> LogFilter {
+        self.state().filter.clone()
+    }
+
+    pub fn set_filter(&self, filter: LogFilter) {
+        self.state().filter = filter;
+    }
+
+    /// Vide la file : messages dans l'ordre et nombre de messages perdus depuis le dernier appel.
+    pub fn drain(&self) -> (Vec<LogMessage>, usize) {
+        let mut state = self.state();
+        let dropped = std::mem::take(&mut state.dropped);
+        (state.queue.drain(..).collect(), dropped)
+    }
+}
+
+impl Log for LogSink {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.state()
+            .filter
+            .enabled(metadata.target(), metadata.level())
+    }
+
+    fn log(&self, record: &Record) {
+        let mut state = self.state();
+        if !state.filter.enabled(record.target(), record.level()) {
+            return;
+        }
+        if state.queue.len() >= state.capacity {
+            state.dropped += 1;
+            return;
+        }
+        let module = record.target().rsplit("::").next().unwrap_or_default();
+        state.queue.push_back(LogMessage {
+            level: record.level(),
+            text: format!("[{}] {}", module, record.args()),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Journal à plusieurs sorties : `env_logger` (stderr), la console et
+/// éventuellement un fichier.
+pub struct TeeLogger {
+    stderr: env_logger::Logger,
+    console: Arc<LogSink>,
+    file: Option<Arc<FileLogger>>,
+}
+
+impl Log for TeeLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.stderr.enabled(metadata)
+            || self.console.enabled(metadata)
+            || self
+                .file
+                .as_ref()
+                .is_some_and(|file| file.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if self.stderr.matches(record) {
+            self.stderr.log(record);
+        }
+        self.console.log(record);
+        if let Some(file) = &self.file {
+            file.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.stderr.flush();
+        if let Some(file) = &self.file {
+            file.flush();
+        }
+    }
+}
+
+/// Installe le journal global (remplace `env_logger::init()`), avec le journal
+/// fichier si `log_file` est donné ; le filtre de la console pourra être changé
+/// ensuite par [`set_console_log_filter`].
+pub fn init_logging(log_file: Option<LogFileConfig>) {
+    let stderr = env_logger::Builder::from_default_env().build();
+    let stderr_level = stderr.filter();
+    let console = CONSOLE_SINK
+        .get_or_init(|| Arc::new(LogSink::new(LogFilter::default(), LOG_QUEUE_CAPACITY)))
+        .clone();
+    let console_level = console.filter().max_level();
+    let file = log_file
+        .clone()
+        .map(|config| (config.path.clone(), open_log_file(config)));
+    let (file, file_error) = match file {
+        Some((_, Ok(file))) => (Some(file), None),
+        Some((path, Err(e))) => (None, Some((path, e))),
+        None => (None, None),
+    };
+    let file_level = file
+        .as_ref()
+        .map_or(LevelFilter::Off, |file| file.filter().max_level());
+    let logger = TeeLogger {
+        stderr,
+        console,
+        file,
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(stderr_level.max(console_level).max(file_level));
+    }
+
+    if let Some((path, e)) = file_error {
+        warn!("⚠️ Log file {} not opened: {}", path.display(), e);
+    } else if let Some(config) = log_file {
+        info!(
+            "📝 Logging to {} (rotated at {}, {} archive(s) kept)",
+            config.path.display(),
+            config.max_size,
+            config.keep
+        );
+    }
+}
+
+/// File de la console, si `init_logging` a été appelé
+pub fn console_log_sink() -> Option<&'static Arc<LogSink>> {
+    CONSOLE_SINK.get()
+}
+
+/// Change le filtre des journaux de la console (niveau global relevé si nécessaire).
+pub fn set_console_log_filter(filter: LogFilter) {
+    if let Some(sink) = console_log_sink() {
+        let level = filter.max_level();
+        sink.set_filter(filter);
+        if level > log::max_level() {
+            log::set_max_level(level);
+        }
+    }
+}
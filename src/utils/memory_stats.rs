@@ -0,0 +1,127 @@
+//! Suivi mémoire : RSS du processus et grosses allocations déclarées par les
+//! moteurs (pools de particules, échantillons audio, buffers GPU).
+//!
+//! Les allocations sont indexées par label : redéclarer un label (pool agrandi,
+//! buffers recréés) remplace sa taille au lieu de l'additionner.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::utils::human_bytes::ByteSize;
+
+/// Taille de page supposée pour `/proc/self/statm` (4 KiB sur x86_64 et aarch64)
+pub const PAGE_SIZE: u64 = 4096;
+
+static GLOBAL_MEMORY_STATS: OnceLock<MemoryStats> = OnceLock::new();
+
+/// Collecteur global, alimenté par tous les moteurs du processus
+pub fn memory_stats() -> &'static MemoryStats {
+    GLOBAL_MEMORY_STATS.get_or_init(MemoryStats::default)
+}
+
+/// Déclare (ou met à jour) une grosse allocation dans le collecteur global.
+pub fn register_allocation(label: impl Into<String>, bytes: u64) {
+    memory_stats().register_allocation(label, bytes);
+}
+
+/// RSS (octets) d'une ligne de `/proc/<pid>/statm` : le 2ᵉ champ, en pages.
+pub fn parse_statm(text: &str, page_size: u64) -> Option<u64> {
+    let resident: u64 = text.split_whitespace().nth(1)?.parse().ok()?;
+    Some(resident * page_size)
+}
+
+/// RSS courant du processus (`None` si indisponible sur la plateforme).
+#[cfg(target_os = "linux")]
+pub fn process_rss() -> Option<u64> {
+    let text = std::fs::read_to_string("/proc/self/statm").ok()?;
+    parse_statm(&text, PAGE_SIZE)
+}
+
+/// RSS courant du processus (`None` si indisponible sur la plateforme).
+#[cfg(not(target_os = "linux"))]
+pub fn process_rss() -> Option<u64> {
+    None
+}
+
+/// Grosses allocations déclarées, par label.
+#[derive(Debug, Default)]
+pub struct MemoryStats {
+    allocations: Mutex<BTreeMap<String, u64>>,
+}
+
+impl MemoryStats {
+    pub fn register_allocation(&self, label: impl Into<String>, bytes: u64) {
+        self.allocations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(label.into(), bytes);
+    }
+
+    /// Oublie une allocation libérée
+    pub fn unregister_allocation(&self, label: &str) {
+        self.allocations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(label);
+    }
+
+    /// Taille déclarée pour `label`
+    pub fn allocation(&self, label: &str) -> Option<u64> {
+        self.allocations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(label)
+            .copied()
+    }
+
+    /// Instantané : allocations triées par label et RSS du processus
+    pub fn snapshot(&self) -> MemorySnapshot {
+        let allocations = self
+            .allocations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(label, bytes)| (label.clone(), *bytes))
+            .collect();
+        MemorySnapshot {
+            rss: process_rss(),
+            allocations,
+        }
+    }
+}
+
+/// État mémoire à un instant donné.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemorySnapshot {
+    pub rss: Option<u64>,
+    pub allocations: Vec<(String, u64)>,
+}
+
+impl MemorySnapshot {
+    /// Somme des allocations déclarées
+    pub fn tracked_total(&self) -> ByteSize {
+        self.allocations
+            .iter()
+            .map(|(_, bytes)| ByteSize(*bytes))
+            .sum()
+    }
+
+    /// Rapport multi-lignes (log périodique et `sim.memory`)
+    pub fn format(&self) -> String {
+        let width = self
+            .allocations
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        let mut out = match self.rss {
+            Some(rss) => format!("Process RSS: {}", ByteSize(rss)),
+            None => "Process RSS: n/a".to_string(),
+        };
+        for (label, bytes) in &self.allocations {
+            out.push_str(&format!("\n  {:<width$}  {}", label, ByteSize(*bytes)));
+        }
+        out.push_str(&format!("\nTracked allocations: {}", self.tracked_total()));
+        out
+    }
+}
@@ -1,4 +1,11 @@
+pub mod assets;
+pub mod embedded_assets;
 pub mod human_bytes;
+pub mod log_file;
+pub mod log_sink;
+pub mod memory_stats;
+pub mod panic_hook;
+pub mod system_report;
 pub mod tools;
 
 pub use self::human_bytes::HumanBytes;
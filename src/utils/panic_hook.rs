@@ -0,0 +1,56 @@
+//! Hook de panique : avant le déroulement de la pile, le message est recopié dans
+//! le journal fichier (vidé), puis le thread audio est arrêté et son export WAV
+//! finalisé, pour ne pas laisser le périphérique (ALSA) dans un état incohérent
+//! jusqu'à la mort du processus.
+//!
+//! Le contexte GL, propriété du thread de rendu, ne peut pas être libéré depuis
+//! le hook : il l'est pendant le déroulement, par `Drop for Simulator` (`close`).
+
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use crate::audio_engine::AudioShutdown;
+use crate::utils::log_file::log_file;
+
+/// Attente maximale de la fin du thread audio après une panique
+pub const PANIC_AUDIO_STOP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Installe le hook (le hook précédent affiche toujours le message). Seules les
+/// paniques du thread appelant (thread de rendu) arrêtent l'audio.
+pub fn install_panic_hook(audio: AudioShutdown) {
+    let owner = thread::current().id();
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        write_panic_to_log_file(&info.to_string());
+        stop_audio_on_panic(&audio, owner);
+    }));
+}
+
+/// Recopie la panique dans le journal fichier (`--log-file`) et le vide
+pub fn write_panic_to_log_file(message: &str) {
+    if let Some(file) = log_file() {
+        let thread = thread::current();
+        file.write_emergency(&format!(
+            "💥 Panic in thread '{}': {}",
+            thread.name().unwrap_or("<unnamed>"),
+            message
+        ));
+    }
+}
+
+fn stop_audio_on_panic(audio: &AudioShutdown, owner: ThreadId) {
+    // Le thread audio ne peut pas attendre sa propre fin
+    if thread::current().id() != owner || !audio.is_running() {
+        return;
+    }
+    // Pas de `log` : le logger peut être dans un état incohérent
+    eprintln!("💥 Panic: stopping the audio engine");
+    audio.request_stop();
+    if !audio.wait_finished(PANIC_AUDIO_STOP_TIMEOUT) {
+        eprintln!(
+            "⚠️ Audio thread still running after {:?}",
+            PANIC_AUDIO_STOP_TIMEOUT
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Rapport système : versions (application, compilateur, crates), OS, CPU, puis
+//! contexte OpenGL et backend audio une fois ouverts.
+//!
+//! Le rapport est global, comme le suivi mémoire : `show_rust_core_dependencies`
+//! l'affiche au démarrage, le renderer (`record_graphics`) et le moteur audio
+//! (`record_audio`) le complètent, et `sim.report` l'écrit en JSON avec les
+//! configs courantes pour joindre à un rapport de bug.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::Context;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::config_presets::PresetSnapshot;
+
+/// Fichier écrit par `sim.report` sans argument
+pub const BUG_REPORT_PATH: &str = "fireworks-report.json";
+
+/// Crates dont la version est relevée à la compilation (cf. `build.rs`)
+const TRACKED_CRATES: [(&str, Option<&str>); 3] = [
+    ("gl", option_env!("GL")),
+    ("glfw", option_env!("GLFW")),
+    ("cpal", option_env!("CPAL")),
+];
+
+static GLOBAL_SYSTEM_REPORT: OnceLock<Mutex<SystemReport>> = OnceLock::new();
+
+fn global() -> &'static Mutex<SystemReport> {
+    GLOBAL_SYSTEM_REPORT.get_or_init(|| Mutex::new(SystemReport::gather()))
+}
+
+/// Rapport courant (infos graphiques et audio comprises si déjà relevées)
+pub fn system_report() -> SystemReport {
+    global().lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Complète le rapport global avec le contexte OpenGL (après sa création)
+pub fn record_graphics(graphics: GraphicsInfo) {
+    global().lock().unwrap_or_else(|e| e.into_inner()).graphics = Some(graphics);
+}
+
+/// Complète le rapport global avec le backend audio (à l'ouverture du flux)
+pub fn record_audio(audio: AudioBackendInfo) {
+    global().lock().unwrap_or_else(|e| e.into_inner()).audio = Some(audio);
+}
+
+/// Système d'exploitation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OsInfo {
+    /// Famille (`linux`, `windows`, `macos`...)
+    pub name: String,
+    pub arch: String,
+    /// Distribution (`PRETTY_NAME` de `/etc/os-release`)
+    pub version: Option<String>,
+    /// Version du noyau
+    pub kernel: Option<String>,
+}
+
+impl OsInfo {
+    pub fn current() -> Self {
+        Self {
+            name: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            version: os_release_name(),
+            kernel: kernel_version(),
+        }
+    }
+}
+
+/// `PRETTY_NAME` d'un contenu `/etc/os-release`
+pub fn parse_os_release(text: &str) -> Option<String> {
+    text.lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+fn os_release_name() -> Option<String> {
+    parse_os_release(&std::fs::read_to_string("/etc/os-release").ok()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn os_release_name() -> Option<String> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn kernel_version() -> Option<String> {
+    let text = std::fs::read_to_string("/proc/sys/kernel/osrelease").ok()?;
+    Some(text.trim().to_string()).filter(|version| !version.is_empty())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn kernel_version() -> Option<String> {
+    None
+}
+
+/// Contexte OpenGL et fenêtrage
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraphicsInfo {
+    pub vendor: String,
+    pub renderer: String,
+    pub version: String,
+    pub glsl_version: String,
+}
+
+/// Backend audio : hôte CPAL et périphérique de sortie
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioBackendInfo {
+    /// Hôte CPAL (`ALSA`, `WASAPI`...) ou `offline` en rendu hors-ligne
+    pub host: String,
+    /// Périphérique par défaut (`None` si absent ou sans nom)
+    pub device: Option<String>,
+    pub sample_rate: u32,
+    pub block_size: usize,
+}
+
+/// Rapport système complet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SystemReport {
+    pub app_version: String,
+    pub rustc_version: String,
+    /// Versions des crates suivies (`Unknown` si non relevée)
+    pub crates: BTreeMap<String, String>,
+    /// Version de la bibliothèque GLFW chargée
+    pub glfw_version: String,
+    pub os: OsInfo,
+    pub cpu_cores: usize,
+    /// `None` tant qu'aucun contexte OpenGL n'est créé (headless audio seul, tests)
+    pub graphics: Option<GraphicsInfo>,
+    /// `None` tant que le moteur audio n'a pas démarré
+    pub audio: Option<AudioBackendInfo>,
+}
+
+impl SystemReport {
+    /// Tout ce qui se relève sans matériel : versions, OS, CPU.
+    pub fn gather() -> Self {
+        let glfw = glfw::get_version();
+        Self {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            rustc_version: rustc_version_runtime::version().to_string(),
+            crates: TRACKED_CRATES
+                .iter()
+                .map(|(name, version)| (name.to_string(), version.unwrap_or("Unknown").to_string()))
+                .collect(),
+            glfw_version: format!("{}.{}.{}", glfw.major, glfw.minor, glfw.patch),
+            os: OsInfo::current(),
+            cpu_cores: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            graphics: None,
+            audio: None,
+        }
+    }
+
+    /// Rapport lisible, une information par ligne
+    pub fn pretty(&self) -> String {
+        let unknown = |value: &Option<String>| value.clone().unwrap_or_else(|| "n/a".into());
+        let mut lines = vec![
+            format!("Fireworks sim {}", self.app_version),
+            format!("  Rust compiler : {}", self.rustc_version),
+            format!(
+                "  OS            : {} {} ({}, kernel {})",
+                self.os.name,
+                self.os.arch,
+                unknown(&self.os.version),
+                unknown(&self.os.kernel)
+            ),
+            format!("  CPU cores     : {}", self.cpu_cores),
+            format!("  GLFW library  : {}", self.glfw_version),
+        ];
+        lines.extend(
+            self.crates
+                .iter()
+                .map(|(name, version)| format!("  {:<5} crate    : {}", name, version)),
+        );
+        match &self.graphics {
+            Some(gl) => lines.extend([
+                format!("  GL vendor     : {}", gl.vendor),
+                format!("  GL renderer   : {}", gl.renderer),
+                format!(
+                    "  GL version    : {} (GLSL {})",
+                    gl.version, gl.glsl_version
+                ),
+            ]),
+            None => lines.push("  OpenGL        : no context".to_string()),
+        }
+        match &self.audio {
+            Some(audio) => lines.push(format!(
+                "  Audio         : {} / {} @ {} Hz, blocks of {}",
+                audio.host,
+                unknown(&audio.device),
+                audio.sample_rate,
+                audio.block_size
+            )),
+            None => lines.push("  Audio         : not started".to_string()),
+        }
+        lines.join("\n")
+    }
+
+    /// Affiche le rapport dans les logs
+    pub fn log(&self) {
+        for line in self.pretty().lines() {
+            info!("{}", line);
+        }
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Contenu de `sim.report` : rapport système et configs courantes.
+#[derive(Debug, Serialize)]
+pub struct BugReport<'a> {
+    pub system: &'a SystemReport,
+    pub configs: PresetSnapshot<'a>,
+}
+
+impl BugReport<'_> {
+    /// Écrit le rapport (JSON) dans `path`, répertoires parents compris.
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Cannot create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("Cannot write {}", path.display()))?;
+        info!("🐞 Bug report written to {}", path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use fireworks_sim::renderer_engine::utils::adaptative_sampler::{
+    ascii_frame_time_timeline, ascii_one_percent_low_timeline, median, one_percent_low,
+    AdaptiveSampler, SpikeReport, DEFAULT_SPIKE_FACTOR, SPIKE_MARKER,
+};
+use std::time::Duration;
+
+/// Ligne du graphe (entre les `|`) d'une timeline
+fn graph_line(text: &str) -> &str {
+    text.lines().nth(1).unwrap().trim_matches('|')
+}
+
+// ==================================
+// 1. Durées de frame échantillonnées
+// ==================================
+
+#[test]
+fn test_sampler_records_frame_times() {
+    // Budget largement supérieur aux frames attendues : chaque frame est prise
+    let mut sampler = AdaptiveSampler::new(Duration::from_secs(10), 10_000, 60.0);
+    assert!(sampler.should_sample(0.020));
+    assert!(sampler.should_sample(0.010));
+    let ms: Vec<f32> = sampler.frame_times.iter().map(|(_, ms)| *ms).collect();
+    assert_eq!(ms.len(), sampler.samples.len());
+    assert!((ms[0] - 20.0).abs() < 1e-3 && (ms[1] - 10.0).abs() < 1e-3);
+
+    sampler.reset();
+    assert!(sampler.frame_times.is_empty());
+}
+
+// ==================================
+// 2. Pics annotés
+// ==================================
+
+#[test]
+fn test_spike_markers_and_count() {
+    // Un sample par seconde sur 10 s (une colonne par sample), pics à 3 s et 7 s
+    let frame_times: Vec<(f32, f32)> = (0..=10)
+        .map(|t| (t as f32, if t == 3 || t == 7 { 40.0 } else { 16.0 }))
+        .collect();
+
+    let text = ascii_frame_time_timeline(&frame_times, 10.0, 11, DEFAULT_SPIKE_FACTOR);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "[0s......10s]");
+    assert_eq!(lines[0].len(), lines[1].len());
+    assert_eq!(graph_line(&text), "###!###!###");
+    assert_eq!(lines[2], "Spikes (> 2.0x median 16.00 ms): 2");
+
+    // Seuil plus haut : plus aucun pic
+    let text = ascii_frame_time_timeline(&frame_times, 10.0, 11, 3.0);
+    assert!(!graph_line(&text).contains(SPIKE_MARKER));
+    assert!(text.ends_with("): 0"), "{}", text);
+
+    // Deux samples dans la même colonne : le pic reste visible
+    let merged = [(0.0, 16.0), (0.1, 16.0), (0.2, 50.0), (0.3, 16.0)];
+    let text = ascii_frame_time_timeline(&merged, 10.0, 11, DEFAULT_SPIKE_FACTOR);
+    assert_eq!(graph_line(&text), "!..........");
+
+    let report = SpikeReport::detect(&[16.0, 16.0, 40.0, 16.0], 2.0).unwrap();
+    assert_eq!((report.median_ms, report.spikes), (16.0, vec![2]));
+    assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), Some(2.5));
+    assert!(SpikeReport::detect(&[], 2.0).is_none());
+}
+
+// ==================================
+// 3. 1% low glissant
+// ==================================
+
+#[test]
+fn test_one_percent_low_sliding_timeline() {
+    // 99 frames à 10 ms et une à 50 ms : le 1% low est la pire frame
+    let mut series = vec![10.0; 99];
+    series.push(50.0);
+    assert_eq!(one_percent_low(&series), Some(20.0));
+    // 150 frames : les 2 plus lentes
+    series.extend([10.0; 49]);
+    series.push(30.0);
+    assert_eq!(one_percent_low(&series), Some(25.0));
+    assert!(one_percent_low(&[]).is_none());
+
+    // Un sample toutes les 0,5 s, une frame lente à 7 s ; fenêtre glissante de 2 s
+    let frame_times: Vec<(f32, f32)> = (0..=20)
+        .map(|i| {
+            let t = i as f32 * 0.5;
+            (t, if i == 14 { 50.0 } else { 10.0 })
+        })
+        .collect();
+    let text = ascii_one_percent_low_timeline(&frame_times, 10.0, 11, 2.0);
+    // Les colonnes 6 à 8 voient la frame lente : niveau le plus bas
+    assert_eq!(graph_line(&text), "######___##");
+    assert_eq!(
+        text.lines().nth(2).unwrap(),
+        "1% low (2s sliding): min 20.0 FPS | max 100.0 FPS"
+    );
+
+    let empty = ascii_one_percent_low_timeline(&[], 10.0, 11, 2.0);
+    assert_eq!(graph_line(&empty), " ".repeat(11));
+    assert!(empty.ends_with("no frame sampled"));
+}
@@ -0,0 +1,188 @@
+use clap::error::ErrorKind;
+use fireworks_sim::app_options::{parse_window_size, AUDIO_EXPORT_ENV, DEFAULT_WINDOW_SIZE};
+use fireworks_sim::bench::{BenchOptions, BenchRenderer};
+use fireworks_sim::physic_engine::config::PHYSIC_CONFIG_PATH;
+use fireworks_sim::profiler_export::{METRICS_APPEND_ENV, METRICS_OUT_ENV};
+use fireworks_sim::{AppCommand, AppOptions};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Analyse `args` sans variable d'environnement
+fn parse(args: &[&str]) -> AppOptions {
+    parse_with_env(args, &[])
+}
+
+fn parse_with_env(args: &[&str], env: &[(&str, &str)]) -> AppOptions {
+    let env: HashMap<String, String> = env
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let argv = std::iter::once("fireworks-sim").chain(args.iter().copied());
+    AppOptions::try_parse_from(argv, |name| env.get(name).cloned()).unwrap()
+}
+
+fn parse_error(args: &[&str]) -> clap::Error {
+    let argv = std::iter::once("fireworks-sim").chain(args.iter().copied());
+    AppOptions::try_parse_from(argv, |_| None).unwrap_err()
+}
+
+// ==================================
+// 1. Sous-commandes et options
+// ==================================
+
+#[test]
+fn test_default_run_and_run_flags() {
+    assert_eq!(parse(&[]), AppOptions::default());
+    assert_eq!(parse(&["run"]), AppOptions::default());
+    assert_eq!(
+        AppOptions::default().physic_config,
+        PathBuf::from(PHYSIC_CONFIG_PATH)
+    );
+
+    let expected = AppOptions {
+        physic_config: "custom/physic.toml".into(),
+        renderer_config: "custom/renderer.toml".into(),
+        audio_export: Some("out.wav".into()),
+        fullscreen: true,
+        size: (1280, 720),
+        size_from_cli: true,
+        seed: Some(42),
+        record: Some("show.mp4".into()),
+        ..AppOptions::default()
+    };
+    let flags = [
+        "--physic-config",
+        "custom/physic.toml",
+        "--renderer-config",
+        "custom/renderer.toml",
+        "--audio-export",
+        "out.wav",
+        "--fullscreen",
+        "--size",
+        "1280x720",
+        "--seed",
+        "42",
+        "--record",
+        "show.mp4",
+    ];
+    // `run` implicite ou explicite : mêmes options
+    assert_eq!(parse(&flags), expected);
+    let explicit: Vec<&str> = std::iter::once("run").chain(flags).collect();
+    assert_eq!(parse(&explicit), expected);
+}
+
+#[test]
+fn test_bench_and_headless_subcommands() {
+    let bench = parse(&[
+        "bench", "--frames", "500", "--seed", "7", "--size", "640x480",
+    ]);
+    let expected = BenchOptions {
+        frames: 500,
+        max_rockets: None,
+        fail_below_fps: None,
+        renderer: BenchRenderer::Offscreen,
+    };
+    assert_eq!(bench.command, AppCommand::Bench(expected));
+    assert_eq!((bench.seed, bench.size), (Some(7), (640, 480)));
+
+    let bench = parse(&[
+        "bench",
+        "--frames",
+        "2000",
+        "--max-rockets",
+        "64",
+        "--fail-below-fps",
+        "55.5",
+        "--no-render",
+    ]);
+    let expected = BenchOptions {
+        frames: 2000,
+        max_rockets: Some(64),
+        fail_below_fps: Some(55.5),
+        renderer: BenchRenderer::None,
+    };
+    assert_eq!(bench.command, AppCommand::Bench(expected));
+    // Pas de graine par défaut au niveau du parseur (cf. `BENCH_DEFAULT_SEED` dans main)
+    assert_eq!(bench.seed, None);
+
+    let headless = parse(&["headless", "--metrics-out", "run.json"]);
+    assert_eq!(headless.command, AppCommand::Headless { duration: 60.0 });
+    assert_eq!(headless.metrics_out, Some("run.json".into()));
+    assert_eq!(headless.size, DEFAULT_WINDOW_SIZE);
+
+    // Forme courte `--headless`, et ancien nom `--export-audio`
+    let headless = parse(&["--headless", "--duration", "2.5", "--export-audio", "a.wav"]);
+    assert_eq!(headless.command, AppCommand::Headless { duration: 2.5 });
+    assert_eq!(headless.audio_export, Some("a.wav".into()));
+}
+
+#[test]
+fn test_invalid_arguments_are_reported() {
+    assert_eq!(parse_error(&["--bogus"]).kind(), ErrorKind::UnknownArgument);
+    // L'ancien chemin d'export positionnel n'est plus accepté silencieusement
+    assert_eq!(
+        parse_error(&["export.wav"]).kind(),
+        ErrorKind::InvalidSubcommand
+    );
+    assert_eq!(
+        parse_error(&["bench"]).kind(),
+        ErrorKind::MissingRequiredArgument
+    );
+    assert_eq!(
+        parse_error(&["bench", "--frames", "0"]).kind(),
+        ErrorKind::ValueValidation
+    );
+    assert_eq!(
+        parse_error(&["bench", "--frames", "10", "--window", "--no-render"]).kind(),
+        ErrorKind::ArgumentConflict
+    );
+    // Options de fenêtre hors du mode fenêtré
+    assert_eq!(
+        parse_error(&["headless", "--record", "out.mp4"]).kind(),
+        ErrorKind::UnknownArgument
+    );
+    assert_eq!(
+        parse_error(&["--seed", "abc"]).kind(),
+        ErrorKind::ValueValidation
+    );
+    assert_eq!(parse_error(&["--help"]).kind(), ErrorKind::DisplayHelp);
+
+    assert_eq!(parse_window_size("1920x1080"), Ok((1920, 1080)));
+    assert_eq!(parse_window_size("800X600"), Ok((800, 600)));
+    assert!(parse_window_size("0x600").is_err());
+    assert!(parse_window_size("1920").is_err());
+    let err = parse_error(&["--size", "big"]).to_string();
+    assert!(err.contains("expected WxH"), "{}", err);
+}
+
+// ==================================
+// 2. Variables d'environnement
+// ==================================
+
+#[test]
+fn test_env_fallbacks_and_precedence() {
+    let env = [
+        (AUDIO_EXPORT_ENV, "env.wav"),
+        (METRICS_OUT_ENV, "env.json"),
+        (METRICS_APPEND_ENV, "env.csv"),
+    ];
+    let options = parse_with_env(&[], &env);
+    assert_eq!(options.audio_export, Some("env.wav".into()));
+    assert_eq!(options.metrics_out, Some("env.json".into()));
+    assert_eq!(options.metrics_append, Some("env.csv".into()));
+
+    // La ligne de commande l'emporte sur l'environnement
+    let options = parse_with_env(
+        &[
+            "headless",
+            "--audio-export",
+            "cli.wav",
+            "--metrics-out",
+            "cli.csv",
+        ],
+        &env,
+    );
+    assert_eq!(options.audio_export, Some("cli.wav".into()));
+    assert_eq!(options.metrics_out, Some("cli.csv".into()));
+    assert_eq!(options.metrics_append, Some("env.csv".into()));
+}
@@ -0,0 +1,257 @@
+use fireworks_sim::app_options::{AppCommand, AppOptions};
+use fireworks_sim::asset_manifest::{AssetKind, AssetManifest, AssetRequirement};
+use fireworks_sim::audio_engine::FireworksAudioConfig;
+use fireworks_sim::bench::{BenchOptions, BenchRenderer};
+use fireworks_sim::physic_engine::particle_type::ParticleType;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::CONSOLE_FONT_PATH;
+use fireworks_sim::session::Session;
+use fireworks_sim::utils::assets::{asset_path, reroot, set_assets_dir};
+use fireworks_sim::AudioEngineSettings;
+use std::path::{Path, PathBuf};
+
+fn audio_config() -> FireworksAudioConfig {
+    FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (0.0, 0.0),
+        sample_rate: 48000,
+        block_size: 512,
+        max_voices: 8,
+        settings: AudioEngineSettings::default(),
+    }
+}
+
+fn paths(manifest: &AssetManifest) -> Vec<PathBuf> {
+    manifest.entries().iter().map(|e| e.path.clone()).collect()
+}
+
+/// Recopie `relative` (depuis la racine du dépôt) sous `root`.
+#[cfg(not(feature = "embedded_assets"))]
+fn copy_asset(root: &Path, relative: &Path) {
+    let target = root.join(relative);
+    std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+    std::fs::copy(relative, target).unwrap();
+}
+
+// ==================================
+// 1. Re-enracinement des chemins
+// ==================================
+
+#[test]
+fn test_reroot_only_touches_asset_paths() {
+    let root = Path::new("/opt/fireworks/data");
+    assert_eq!(
+        reroot(Path::new("assets/sounds/rocket.wav"), root),
+        root.join("sounds/rocket.wav")
+    );
+    assert_eq!(
+        reroot(Path::new("./assets/config/physic.toml"), root),
+        root.join("config/physic.toml")
+    );
+    // Hors `assets/` : inchangés
+    for path in ["scripts/show.cfg", "/tmp/assets/a.png", "my_assets/a.png"] {
+        assert_eq!(reroot(Path::new(path), root), PathBuf::from(path));
+    }
+}
+
+#[test]
+fn test_assets_dir_reroots_loaders() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("config")).unwrap();
+    std::fs::write(
+        dir.path().join("config/session.toml"),
+        "[audio]\nvolume = 0.25\nmuted = false\n",
+    )
+    .unwrap();
+
+    set_assets_dir(Some(dir.path().to_path_buf()));
+    let session = Session::load(Path::new("assets/config/session.toml"));
+    let rerooted = asset_path("assets/fonts/font.ttf");
+    set_assets_dir(None);
+
+    assert_eq!(session.unwrap().unwrap().audio.unwrap().volume, 0.25);
+    assert_eq!(rerooted, dir.path().join("fonts/font.ttf"));
+    assert_eq!(asset_path("assets/a.png"), PathBuf::from("assets/a.png"));
+}
+
+#[test]
+fn test_assets_dir_flag() {
+    let options = AppOptions::try_parse_from(
+        [
+            "fireworks-sim",
+            "headless",
+            "--assets-dir",
+            "/opt/fw/assets",
+        ],
+        |_| None,
+    )
+    .unwrap();
+    assert_eq!(options.assets_dir, Some(PathBuf::from("/opt/fw/assets")));
+    assert_eq!(AppOptions::default().assets_dir, None);
+}
+
+// ==================================
+// 2. Construction du manifeste
+// ==================================
+
+#[test]
+fn test_headless_manifest_has_configs_and_sounds_only() {
+    let options = AppOptions {
+        command: AppCommand::Headless { duration: 1.0 },
+        ..AppOptions::default()
+    };
+    let manifest = AssetManifest::from_configs(&options, &audio_config(), None);
+    assert_eq!(
+        paths(&manifest),
+        vec![
+            PathBuf::from("assets/config/physic.toml"),
+            PathBuf::from("assets/config/audio.toml"),
+            PathBuf::from("assets/sounds/rocket.wav"),
+            PathBuf::from("assets/sounds/explosion.wav"),
+        ]
+    );
+    assert!(manifest.entries()[2].is_required());
+}
+
+#[test]
+fn test_rumble_layer_is_optional() {
+    let options = AppOptions {
+        command: AppCommand::Headless { duration: 1.0 },
+        ..AppOptions::default()
+    };
+    let audio = FireworksAudioConfig {
+        rumble_path: Some("assets/sounds/rumble.wav".into()),
+        ..audio_config()
+    };
+    let manifest = AssetManifest::from_configs(&options, &audio, None);
+    let rumble = manifest.entries().last().unwrap();
+    assert_eq!(rumble.path, PathBuf::from("assets/sounds/rumble.wav"));
+    assert!(matches!(rumble.requirement, AssetRequirement::Degraded(_)));
+}
+
+#[test]
+fn test_window_manifest_lists_renderer_assets() {
+    let renderer = RendererConfig::default();
+    let manifest =
+        AssetManifest::from_configs(&AppOptions::default(), &audio_config(), Some(&renderer));
+    let count = |kind| manifest.entries().iter().filter(|e| e.kind == kind).count();
+    assert_eq!(count(AssetKind::Shader), 5);
+    assert_eq!(count(AssetKind::Font), 1);
+    // Textures par défaut des 4 types + salissures d'objectif
+    assert_eq!(count(AssetKind::Texture), 5);
+    assert_eq!(count(AssetKind::Config), 4);
+
+    // Rendu hors écran : pas de console, donc pas de police
+    let bench = AppOptions {
+        command: AppCommand::Bench(BenchOptions {
+            frames: 10,
+            max_rockets: None,
+            fail_below_fps: None,
+            renderer: BenchRenderer::Offscreen,
+        }),
+        ..AppOptions::default()
+    };
+    let manifest = AssetManifest::from_configs(&bench, &audio_config(), Some(&renderer));
+    assert!(!paths(&manifest).contains(&PathBuf::from(CONSOLE_FONT_PATH)));
+}
+
+#[test]
+fn test_configured_texture_falls_back_to_the_default() {
+    let mut renderer = RendererConfig::default();
+    renderer.particles.get_mut(ParticleType::Smoke).texture = "assets/textures/custom.png".into();
+    renderer.lens_dirt_texture.clear();
+    let manifest =
+        AssetManifest::from_configs(&AppOptions::default(), &audio_config(), Some(&renderer));
+
+    let entry = |path: &str| {
+        manifest
+            .entries()
+            .iter()
+            .find(|e| e.path == Path::new(path))
+            .cloned()
+            .unwrap()
+    };
+    assert!(matches!(
+        entry("assets/textures/custom.png").requirement,
+        AssetRequirement::Degraded(_)
+    ));
+    assert!(entry(ParticleType::Smoke.default_texture_path()).is_required());
+    let textures = manifest
+        .entries()
+        .iter()
+        .filter(|e| e.kind == AssetKind::Texture)
+        .count();
+    assert_eq!(textures, 5);
+}
+
+#[test]
+fn test_manifest_paths_are_rerooted() {
+    let options = AppOptions {
+        command: AppCommand::Headless { duration: 1.0 },
+        assets_dir: Some("/opt/fw/data".into()),
+        physic_config: "/etc/fireworks/physic.toml".into(),
+        ..AppOptions::default()
+    };
+    let manifest = AssetManifest::from_configs(&options, &audio_config(), None);
+    assert_eq!(
+        paths(&manifest),
+        vec![
+            PathBuf::from("/etc/fireworks/physic.toml"),
+            PathBuf::from("/opt/fw/data/config/audio.toml"),
+            PathBuf::from("/opt/fw/data/sounds/rocket.wav"),
+            PathBuf::from("/opt/fw/data/sounds/explosion.wav"),
+        ]
+    );
+}
+
+// ==================================
+// 3. Rapport des assets manquants
+// ==================================
+
+#[test]
+fn test_shipped_assets_are_complete() {
+    let renderer = RendererConfig::default();
+    let report =
+        AssetManifest::from_configs(&AppOptions::default(), &audio_config(), Some(&renderer))
+            .check();
+    assert!(report.is_complete(), "{}", report);
+}
+
+// Avec `embedded_assets`, rien n'est jamais manquant (cf. embedded_assets_test.rs)
+#[cfg(not(feature = "embedded_assets"))]
+#[test]
+fn test_report_from_another_working_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let renderer = RendererConfig::default();
+    let manifest =
+        AssetManifest::from_configs(&AppOptions::default(), &audio_config(), Some(&renderer));
+
+    // Rien : tout manque, démarrage impossible
+    let report = manifest.check_in(dir.path());
+    assert_eq!(report.missing.len(), manifest.entries().len());
+    assert!(report.is_fatal());
+    let text = report.to_string();
+    assert!(
+        text.contains(&format!("working directory: {}", dir.path().display())),
+        "{}",
+        text
+    );
+    assert!(text.contains(
+        &dir.path()
+            .join("assets/sounds/rocket.wav")
+            .display()
+            .to_string()
+    ));
+    assert!(text.contains("--assets-dir"), "{}", text);
+
+    // Assets indispensables présents : seules des fonctionnalités manquent
+    for entry in manifest.entries().iter().filter(|e| e.is_required()) {
+        copy_asset(dir.path(), &entry.path);
+    }
+    let report = manifest.check_in(dir.path());
+    assert!(!report.is_complete());
+    assert!(!report.is_fatal(), "{}", report);
+    assert!(report.to_string().contains("bloom disabled"));
+}
@@ -0,0 +1,135 @@
+use fireworks_sim::utils::assets::{
+    asset_path, resolve, resolve_in, resolve_with, search_roots, ASSETS_ENV,
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    move |name| vars.get(name).cloned()
+}
+
+fn touch(root: &Path, relative: &str) {
+    let path = root.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, "x").unwrap();
+}
+
+// ==================================
+// 1. Ordre de recherche
+// ==================================
+
+#[test]
+fn test_search_roots_order() {
+    let roots = search_roots(
+        Some(Path::new("/cli")),
+        env(&[
+            (ASSETS_ENV, "/env"),
+            ("XDG_DATA_HOME", "/xdg/home"),
+            ("XDG_DATA_DIRS", "/xdg/a::/xdg/b"),
+        ]),
+        Some(Path::new("/opt/bin")),
+    );
+    let expected: Vec<PathBuf> = [
+        "/cli",
+        "/env",
+        "/opt/bin/assets",
+        "assets",
+        "/xdg/home/fireworks-sim/assets",
+        "/xdg/a/fireworks-sim/assets",
+        "/xdg/b/fireworks-sim/assets",
+    ]
+    .iter()
+    .map(PathBuf::from)
+    .collect();
+    assert_eq!(roots, expected);
+
+    // Défauts XDG ; variables vides ignorées
+    let roots = search_roots(None, env(&[(ASSETS_ENV, ""), ("HOME", "/home/me")]), None);
+    assert_eq!(
+        roots,
+        vec![
+            PathBuf::from("assets"),
+            PathBuf::from("/home/me/.local/share/fireworks-sim/assets"),
+            PathBuf::from("/usr/local/share/fireworks-sim/assets"),
+            PathBuf::from("/usr/share/fireworks-sim/assets"),
+        ]
+    );
+}
+
+#[test]
+fn test_first_existing_match_wins() {
+    let dir = tempfile::tempdir().unwrap();
+    let [cli, env_root, exe, xdg] =
+        ["cli", "env", "bin/assets", "xdg/fireworks-sim/assets"].map(|name| dir.path().join(name));
+    touch(&env_root, "sounds/rocket.wav");
+    touch(&exe, "sounds/rocket.wav");
+    touch(&exe, "fonts/font.ttf");
+    touch(&xdg, "config/xdg_only.toml");
+    let roots = search_roots(
+        Some(&cli),
+        env(&[
+            (ASSETS_ENV, env_root.to_str().unwrap()),
+            ("XDG_DATA_HOME", dir.path().join("xdg").to_str().unwrap()),
+            ("XDG_DATA_DIRS", "/nonexistent"),
+        ]),
+        Some(&dir.path().join("bin")),
+    );
+
+    let found = |relative| resolve_in(relative, &roots).map(|(_, path)| path);
+    // `assets/` initial facultatif
+    assert_eq!(
+        found("assets/sounds/rocket.wav"),
+        Some(env_root.join("sounds/rocket.wav"))
+    );
+    assert_eq!(found("fonts/font.ttf"), Some(exe.join("fonts/font.ttf")));
+    assert_eq!(
+        found("assets/config/xdg_only.toml"),
+        Some(xdg.join("config/xdg_only.toml"))
+    );
+    assert_eq!(found("assets/nothing.png"), None);
+
+    // La racine explicite passe devant
+    touch(&cli, "sounds/rocket.wav");
+    assert_eq!(
+        resolve_in("assets/sounds/rocket.wav", &roots),
+        Some((cli.clone(), cli.join("sounds/rocket.wav")))
+    );
+}
+
+// ==================================
+// 2. Variables d'environnement du processus
+// ==================================
+
+#[test]
+fn test_resolve_with_process_env() {
+    let dir = tempfile::tempdir().unwrap();
+    touch(dir.path(), "textures/only_here.png");
+    std::env::set_var(ASSETS_ENV, dir.path());
+    let from_env = resolve("assets/textures/only_here.png");
+    // Présent dans ./assets : le dépôt l'emporte sur XDG, pas sur $FIREWORKS_ASSETS
+    let shipped = resolve("assets/config/physic.toml");
+    std::env::remove_var(ASSETS_ENV);
+
+    assert_eq!(from_env, dir.path().join("textures/only_here.png"));
+    assert_eq!(shipped, PathBuf::from("assets/config/physic.toml"));
+
+    // Introuvable : sous la racine explicite, sinon ./assets (comme avant)
+    let explicit = dir.path().join("explicit");
+    assert_eq!(
+        resolve_with(Some(&explicit), "assets/missing.png"),
+        explicit.join("missing.png")
+    );
+    assert_eq!(
+        resolve_with(None, "assets/missing.png"),
+        PathBuf::from("assets/missing.png")
+    );
+    // Hors `assets/` : inchangés
+    assert_eq!(
+        asset_path("scripts/show.cfg"),
+        PathBuf::from("scripts/show.cfg")
+    );
+}
@@ -1,19 +1,26 @@
 use fireworks_sim::audio_engine::fireworks_audio::FireworksAudio3D;
-use fireworks_sim::audio_engine::types::FireworksAudioConfig;
+use fireworks_sim::audio_engine::mixer::{apply_settings, mix_voices, rms};
+use fireworks_sim::audio_engine::types::{FireworksAudioConfig, PlayRequest, Voice};
 use fireworks_sim::audio_engine::AudioEngine;
 use fireworks_sim::AudioEngineSettings;
+use std::time::Instant;
 
-// Helper to build a test engine
-fn build_test_engine() -> FireworksAudio3D {
-    FireworksAudio3D::new(FireworksAudioConfig {
+fn build_test_engine_config() -> FireworksAudioConfig {
+    FireworksAudioConfig {
         rocket_path: "assets/sounds/rocket.wav".into(),
         explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
         listener_pos: (0.0, 0.0),
         sample_rate: 44100,
         block_size: 1024,
         max_voices: 16,
         settings: AudioEngineSettings::default(),
-    })
+    }
+}
+
+// Helper to build a test engine
+fn build_test_engine() -> FireworksAudio3D {
+    FireworksAudio3D::new(build_test_engine_config())
 }
 
 // ==================================
@@ -172,3 +179,149 @@ fn test_listener_movement_with_sounds() {
         engine.play_explosion((100.0, 100.0), 0.5);
     }
 }
+
+// ==================================
+// Group 6: Live settings
+// ==================================
+
+/// Rend `blocks` blocs hors-ligne ; `update(i)` est appelé avant le bloc `i`.
+/// Retourne l'amplitude max de chaque bloc du WAV exporté.
+fn render_blocks(blocks: usize, update: impl Fn(&FireworksAudio3D, usize)) -> Vec<i32> {
+    let dir = tempfile::tempdir().unwrap();
+    let wav = dir.path().join("live.wav");
+    // 32 kHz, blocs de 1000 frames : un bloc = 1/32 s, exact en f32
+    let mut engine = FireworksAudio3D::new(FireworksAudioConfig {
+        sample_rate: 32_000,
+        block_size: 1000,
+        ..build_test_engine_config()
+    });
+    engine.start_offline(wav.to_str());
+    engine.play_explosion((300.0, 0.0), 1.0);
+    for i in 0..blocks {
+        update(&engine, i);
+        engine.advance_offline(1000.0 / 32_000.0);
+    }
+    engine.stop_audio_thread();
+
+    let samples: Vec<i32> = hound::WavReader::open(&wav)
+        .unwrap()
+        .samples::<i16>()
+        .map(|s| (s.unwrap() as i32).abs())
+        .collect();
+    samples
+        .chunks(2000)
+        .map(|block| block.iter().copied().max().unwrap_or(0))
+        .collect()
+}
+
+#[test]
+fn test_global_gain_swap_applies_to_active_voice() {
+    let peaks = render_blocks(4, |engine, i| match i {
+        2 => engine.set_settings(AudioEngineSettings {
+            global_gain: 0.0,
+            ..AudioEngineSettings::default()
+        }),
+        3 => engine.set_settings(AudioEngineSettings::default()),
+        _ => {}
+    });
+    assert_eq!(peaks.len(), 4);
+    assert!(peaks[1] > 0, "{:?}", peaks);
+    // Voix déjà en cours : coupée dès le bloc suivant, puis rétablie
+    assert_eq!(peaks[2], 0, "{:?}", peaks);
+    assert!(peaks[3] > 0, "{:?}", peaks);
+}
+
+#[test]
+fn test_max_distance_swap_applies_to_active_voice() {
+    // Source à 300 : hors de portée dès que max_distance passe à 200
+    let peaks = render_blocks(3, |engine, i| {
+        if i == 2 {
+            engine.set_settings(AudioEngineSettings {
+                max_distance: 200.0,
+                ..AudioEngineSettings::default()
+            });
+        }
+    });
+    assert!(peaks[1] > 0, "{:?}", peaks);
+    assert_eq!(peaks[2], 0, "{:?}", peaks);
+}
+
+#[test]
+fn test_apply_settings_rescales_baked_attenuation() {
+    let defaults = AudioEngineSettings::default();
+    let mut voice = Voice::new();
+    voice.reset_from_request(&PlayRequest {
+        data: vec![[0.5, 0.5]; 256],
+        fade_in: 0,
+        fade_out: 0,
+        gain: 1.0,
+        filter_a: 1.0,
+        distance: Some(500.0),
+        attenuation: defaults.distance_attenuation(500.0),
+        sustain: None,
+        sent_at: Instant::now(),
+    });
+    let mut farther = voice.clone();
+
+    let energy = |voice: &mut Voice, settings: &AudioEngineSettings| {
+        let mut voices = [voice.clone()];
+        apply_settings(&mut voices, settings, 32_000);
+        let mut acc = vec![[0.0; 2]; 64];
+        let mut chunk = vec![[0.0; 2]; 64];
+        mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+        *voice = voices[0].clone();
+        acc.iter().map(|s| s[0]).sum::<f32>()
+    };
+
+    let base = energy(&mut voice, &defaults);
+    let doubled = AudioEngineSettings {
+        max_distance: 2000.0,
+        ..defaults.clone()
+    };
+    let louder = energy(&mut farther, &doubled);
+
+    // Atténuation 0.5 figée, 0.75 avec la nouvelle portée ; filtre inchangé
+    assert!((louder / base - 1.5).abs() < 1e-4, "{} / {}", louder, base);
+    assert_eq!(voice.filter_a, farther.filter_a);
+    assert_eq!(voice.filter_a, defaults.lowpass_coefficient(500.0, 32_000));
+}
+
+// ==================================
+// Group 7: Output meter
+// ==================================
+
+#[test]
+fn test_rms() {
+    assert_eq!(rms(&[]), 0.0);
+    assert_eq!(rms(&[0.0; 64]), 0.0);
+    assert!((rms(&[0.5, -0.5, 0.5, -0.5]) - 0.5).abs() < 1e-6);
+    // Sinusoïde d'amplitude 1 : 1/√2
+    let sine: Vec<f32> = (0..1000)
+        .map(|i| (i as f32 * std::f32::consts::TAU / 100.0).sin())
+        .collect();
+    assert!((rms(&sine) - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+}
+
+#[test]
+fn test_output_level_follows_offline_mix() {
+    let mut engine = FireworksAudio3D::new(FireworksAudioConfig {
+        sample_rate: 32_000,
+        block_size: 1000,
+        ..build_test_engine_config()
+    });
+    assert_eq!(engine.output_level(), 0.0);
+    engine.start_offline(None);
+    engine.advance_offline(1000.0 / 32_000.0);
+    assert_eq!(engine.output_level(), 0.0);
+
+    engine.play_explosion((300.0, 0.0), 1.0);
+    engine.advance_offline(1000.0 / 32_000.0);
+    let level = engine.output_level();
+    assert!(level > 0.0 && level <= 1.0, "{}", level);
+
+    // En pause : silence
+    engine.set_paused(true);
+    engine.advance_offline(1000.0 / 32_000.0);
+    assert_eq!(engine.output_level(), 0.0);
+    engine.stop_audio_thread();
+}
@@ -0,0 +1,385 @@
+use fireworks_sim::audio_engine::mixer::{
+    drain_play_queue, mix_voices, read_looped, release_rocket_voices, RocketVoices,
+};
+use fireworks_sim::audio_engine::types::{PlayRequest, Sustain, Voice};
+use fireworks_sim::audio_engine::{play_physic_events, AudioEngine};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::PhysicEngine;
+use fireworks_sim::profiler::Profiler;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Rampe : la frame `i` vaut `i` sur les deux canaux
+fn ramp(len: usize) -> Vec<[f32; 2]> {
+    (0..len).map(|i| [i as f32, i as f32]).collect()
+}
+
+fn sustain(rocket_id: u64, loop_start: usize, loop_end: usize) -> Sustain {
+    Sustain {
+        rocket_id,
+        loop_start,
+        loop_end,
+        frames_left: usize::MAX,
+    }
+}
+
+fn request(len: usize, sustain: Option<Sustain>) -> PlayRequest {
+    PlayRequest {
+        data: vec![[0.5, 0.5]; len],
+        fade_in: 0,
+        fade_out: 0,
+        gain: 1.0,
+        filter_a: 1.0,
+        distance: None,
+        attenuation: 1.0,
+        sustain,
+        sent_at: Instant::now(),
+    }
+}
+
+// ==================================
+// Group 1: Loop wraparound
+// ==================================
+
+#[test]
+fn test_wrap_keeps_positions_before_loop_end() {
+    let s = sustain(1, 100, 200);
+    assert_eq!(s.wrap(0.0), 0.0);
+    assert_eq!(s.wrap(150.5), 150.5);
+    assert_eq!(s.wrap(199.75), 199.75);
+}
+
+#[test]
+fn test_wrap_folds_positions_into_loop() {
+    let s = sustain(1, 100, 200);
+    assert_eq!(s.wrap(200.0), 100.0);
+    assert_eq!(s.wrap(250.5), 150.5);
+    // Plusieurs tours d'un coup (grand bloc, vitesse de lecture élevée)
+    assert_eq!(s.wrap(420.0), 120.0);
+}
+
+#[test]
+fn test_sustain_new_from_fractions() {
+    let s = Sustain::new(3, 1000, 0.25, 0.75, 48_000).unwrap();
+    assert_eq!((s.rocket_id, s.loop_start, s.loop_end), (3, 250, 750));
+    assert_eq!(s.frames_left, 48_000);
+    // Bornes ramenées dans l'échantillon
+    let s = Sustain::new(3, 1000, -1.0, 2.0, 1).unwrap();
+    assert_eq!((s.loop_start, s.loop_end), (0, 1000));
+    // Région vide : pas de boucle, le son reste ponctuel
+    assert!(Sustain::new(3, 1000, 0.5, 0.5, 1).is_none());
+    assert!(Sustain::new(3, 0, 0.25, 0.75, 1).is_none());
+}
+
+#[test]
+fn test_read_looped_wraps_at_loop_end() {
+    let data = ramp(8);
+    let s = sustain(1, 2, 5);
+    let mut out = [[0.0; 2]; 8];
+    let next = read_looped(&data, &s, 0.0, 1.0, &mut out);
+    let left: Vec<f32> = out.iter().map(|f| f[0]).collect();
+    // Intro, puis la boucle [2, 5) rejouée ; la fin [5, 8) n'est jamais lue
+    assert_eq!(left, [0.0, 1.0, 2.0, 3.0, 4.0, 2.0, 3.0, 4.0]);
+    assert_eq!(next, 2.0);
+}
+
+#[test]
+fn test_read_looped_interpolates_across_loop_seam() {
+    let data = ramp(8);
+    let s = sustain(1, 2, 5);
+    let mut out = [[0.0; 2]; 4];
+    let next = read_looped(&data, &s, 4.0, 0.5, &mut out);
+    let left: Vec<f32> = out.iter().map(|f| f[0]).collect();
+    // Entre la dernière frame de la boucle (4) et la première (2)
+    assert_eq!(left, [4.0, 3.0, 2.0, 2.5]);
+    assert_eq!(next, 3.0);
+}
+
+#[test]
+fn test_sustained_voice_outlives_its_sample() {
+    let mut voices = [Voice::new()];
+    voices[0].reset_from_request(&request(256, Some(sustain(1, 64, 192))));
+    let mut acc = vec![[0.0; 2]; 64];
+    let mut chunk = vec![[0.0; 2]; 64];
+    for _ in 0..32 {
+        mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+    }
+    assert!(voices[0].active);
+    assert!(
+        voices[0].pos >= 64 && voices[0].pos < 192,
+        "{}",
+        voices[0].pos
+    );
+    assert!(acc.iter().all(|f| f[0] == 0.5));
+}
+
+#[test]
+fn test_sustain_budget_forces_release() {
+    let mut voices = [Voice::new()];
+    let s = Sustain {
+        frames_left: 128,
+        ..sustain(1, 64, 192)
+    };
+    voices[0].reset_from_request(&request(256, Some(s)));
+    let mut acc = vec![[0.0; 2]; 64];
+    let mut chunk = vec![[0.0; 2]; 64];
+    mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+    assert!(voices[0].sustain.is_some());
+    mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+    assert!(voices[0].sustain.is_none());
+    // Fin de l'échantillon jouée, puis la voix se libère
+    mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+    mix_voices(&mut voices, &mut acc, &mut chunk, 1.0);
+    assert!(!voices[0].active);
+}
+
+// ==================================
+// Group 2: Id-based release
+// ==================================
+
+/// Mixeur minimal : file de lecture, explosions en attente et voix par fusée
+struct Mixer {
+    queue: VecDeque<PlayRequest>,
+    releases: Vec<u64>,
+    voices: Vec<Voice>,
+    rocket_voices: RocketVoices,
+    profiler: Profiler,
+}
+
+impl Mixer {
+    fn new(max_voices: usize) -> Self {
+        Self {
+            queue: VecDeque::new(),
+            releases: Vec::new(),
+            voices: vec![Voice::new(); max_voices],
+            rocket_voices: RocketVoices::default(),
+            profiler: Profiler::new(10),
+        }
+    }
+
+    /// Un bloc de 64 frames, comme le callback audio
+    fn block(&mut self, flush: bool) {
+        drain_play_queue(
+            &mut self.queue,
+            &mut self.voices,
+            &mut self.rocket_voices,
+            flush,
+            &self.profiler,
+        );
+        release_rocket_voices(
+            &mut self.releases,
+            &mut self.voices,
+            &mut self.rocket_voices,
+        );
+        let mut acc = vec![[0.0; 2]; 64];
+        let mut chunk = vec![[0.0; 2]; 64];
+        mix_voices(&mut self.voices, &mut acc, &mut chunk, 1.0);
+    }
+
+    fn sustained(&self, rocket_id: u64) -> bool {
+        self.voices
+            .iter()
+            .any(|v| v.active && v.sustain.is_some_and(|s| s.rocket_id == rocket_id))
+    }
+
+    fn active(&self) -> usize {
+        self.voices.iter().filter(|v| v.active).count()
+    }
+}
+
+#[test]
+fn test_release_stops_only_the_exploded_rocket() {
+    let mut mixer = Mixer::new(4);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(2, 64, 192))));
+    for _ in 0..16 {
+        mixer.block(false);
+    }
+    assert_eq!(mixer.rocket_voices.len(), 2);
+    assert!(mixer.sustained(1) && mixer.sustained(2));
+
+    mixer.releases.push(1);
+    mixer.block(false);
+    assert!(!mixer.sustained(1));
+    assert!(mixer.sustained(2));
+    assert_eq!(mixer.rocket_voices.len(), 1);
+
+    // La fusée 1 joue la fin de son échantillon, puis sa voix est libérée
+    for _ in 0..4 {
+        mixer.block(false);
+    }
+    assert_eq!(mixer.active(), 1);
+    assert!(mixer.sustained(2));
+}
+
+#[test]
+fn test_release_of_unknown_rocket_is_ignored() {
+    let mut mixer = Mixer::new(2);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.releases.push(42);
+    mixer.block(false);
+    assert!(mixer.sustained(1));
+    assert!(!mixer.rocket_voices.release(42, &mut mixer.voices));
+}
+
+#[test]
+fn test_spawn_and_explosion_in_same_block() {
+    let mut mixer = Mixer::new(2);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.releases.push(1);
+    mixer.block(false);
+    assert!(!mixer.sustained(1));
+    assert!(mixer.rocket_voices.is_empty());
+}
+
+#[test]
+fn test_relaunch_with_same_id_releases_previous_voice() {
+    // Fusée disparue sans exploser : son slot (et son id) repart
+    let mut mixer = Mixer::new(4);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.block(false);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.block(false);
+    let looping = mixer
+        .voices
+        .iter()
+        .filter(|v| v.active && v.sustain.is_some())
+        .count();
+    assert_eq!(looping, 1);
+    assert_eq!(mixer.rocket_voices.len(), 1);
+}
+
+#[test]
+fn test_flush_forgets_rocket_voices() {
+    let mut mixer = Mixer::new(2);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.block(false);
+    mixer.block(true);
+    assert_eq!(mixer.active(), 0);
+    assert!(mixer.rocket_voices.is_empty());
+}
+
+#[test]
+fn test_voice_reused_after_flush_is_not_released() {
+    let mut mixer = Mixer::new(1);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(1, 64, 192))));
+    mixer.block(false);
+    // Coupure, puis la voix repart pour une autre fusée
+    mixer.block(true);
+    mixer
+        .queue
+        .push_back(request(256, Some(sustain(2, 64, 192))));
+    mixer.releases.push(1);
+    mixer.block(false);
+    assert!(mixer.sustained(2));
+}
+
+// ==================================
+// Group 3: Physics events
+// ==================================
+
+/// Enregistre départs et explosions de fusées
+#[derive(Default)]
+struct RecordingAudio {
+    events: RefCell<Vec<(&'static str, u64)>>,
+}
+
+impl AudioEngine for RecordingAudio {
+    fn play_rocket(&self, _pos: (f32, f32), _gain: f32) {}
+    fn play_explosion(&self, _pos: (f32, f32), _gain: f32) {}
+    fn play_rocket_sustained(&self, rocket_id: u64, _pos: (f32, f32, f32), _gain: f32) {
+        self.events.borrow_mut().push(("launch", rocket_id));
+    }
+    fn release_rocket(&self, rocket_id: u64) {
+        self.events.borrow_mut().push(("release", rocket_id));
+    }
+    fn start_audio_thread(&mut self, _export_path: Option<&str>) {}
+    fn stop_audio_thread(&mut self) {}
+    fn set_listener_position(&mut self, _pos: (f32, f32)) {}
+    fn get_listener_position(&self) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+    fn mute(&mut self) {}
+    fn unmute(&mut self) -> f32 {
+        1.0
+    }
+}
+
+#[test]
+fn test_physic_events_release_each_launched_rocket() {
+    let config = PhysicConfig {
+        max_rockets: 8,
+        particles_per_explosion: 16,
+        particles_per_trail: 16,
+        ..Default::default()
+    };
+    let mut physic = PhysicEngineFireworks::new(&config, 1024.0);
+    let mut audio = RecordingAudio::default();
+    for _ in 0..60 * 20 {
+        let result = physic.update(1.0 / 60.0);
+        play_physic_events(&result, &mut audio);
+    }
+
+    let events = audio.events.into_inner();
+    let releases: Vec<u64> = events
+        .iter()
+        .filter(|(kind, _)| *kind == "release")
+        .map(|&(_, id)| id)
+        .collect();
+    assert!(!releases.is_empty(), "{:?}", events);
+    // Chaque explosion relâche une fusée lancée avant elle, et pas encore relâchée
+    let mut in_flight = Vec::new();
+    for (kind, id) in events {
+        match kind {
+            "launch" => in_flight.push(id),
+            _ => {
+                let pos = in_flight.iter().position(|&i| i == id);
+                assert!(pos.is_some(), "release of {} before its launch", id);
+                in_flight.swap_remove(pos.unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_default_trait_falls_back_to_one_shot() {
+    struct OneShot(RefCell<usize>);
+    impl AudioEngine for OneShot {
+        fn play_rocket(&self, _pos: (f32, f32), _gain: f32) {
+            *self.0.borrow_mut() += 1;
+        }
+        fn play_explosion(&self, _pos: (f32, f32), _gain: f32) {}
+        fn start_audio_thread(&mut self, _export_path: Option<&str>) {}
+        fn stop_audio_thread(&mut self) {}
+        fn set_listener_position(&mut self, _pos: (f32, f32)) {}
+        fn get_listener_position(&self) -> (f32, f32) {
+            (0.0, 0.0)
+        }
+        fn mute(&mut self) {}
+        fn unmute(&mut self) -> f32 {
+            1.0
+        }
+    }
+    let audio = OneShot(RefCell::new(0));
+    audio.play_rocket_sustained(7, (0.0, 0.0, 0.0), 1.0);
+    audio.release_rocket(7);
+    assert_eq!(*audio.0.borrow(), 1);
+}
@@ -0,0 +1,151 @@
+use fireworks_sim::audio_engine::fireworks_audio::FireworksAudio3D;
+use fireworks_sim::audio_engine::types::FireworksAudioConfig;
+use fireworks_sim::audio_engine::{
+    load_audio_with_rate, load_resampled, mix_layers, rumble_crossfade, AudioEngine,
+};
+use fireworks_sim::AudioEngineSettings;
+use std::path::Path;
+
+const EXPLOSION: &str = "assets/sounds/explosion.wav";
+
+fn engine_config(rumble_path: Option<&Path>) -> FireworksAudioConfig {
+    FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: EXPLOSION.into(),
+        rumble_path: rumble_path.map(|p| p.to_string_lossy().into_owned()),
+        listener_pos: (0.0, 0.0),
+        sample_rate: 32_000,
+        block_size: 1000,
+        max_voices: 4,
+        settings: AudioEngineSettings::default(),
+    }
+}
+
+/// Écrit une sinusoïde grave mono de `seconds` secondes à `sample_rate`
+fn write_rumble(path: &Path, sample_rate: u32, seconds: f32) {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    let frames = (sample_rate as f32 * seconds) as usize;
+    for i in 0..frames {
+        let t = i as f32 / sample_rate as f32;
+        let s = (t * 60.0 * std::f32::consts::TAU).sin() * 0.8;
+        writer.write_sample((s * i16::MAX as f32) as i16).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+/// Niveau de sortie moyen sur les `blocks` premiers blocs d'une explosion à `pos`
+fn explosion_level(engine: &mut FireworksAudio3D, pos: (f32, f32), blocks: usize) -> f32 {
+    engine.start_offline(None);
+    engine.play_explosion(pos, 1.0);
+    let mut total = 0.0;
+    for _ in 0..blocks {
+        engine.advance_offline(1000.0 / 32_000.0);
+        total += engine.output_level();
+    }
+    engine.stop_audio_thread();
+    total / blocks as f32
+}
+
+// ==================================
+// Group 1: Crossfade curve
+// ==================================
+
+#[test]
+fn test_crossfade_endpoints() {
+    assert_eq!(rumble_crossfade(0.0, 300.0), 1.0);
+    assert_eq!(rumble_crossfade(300.0, 300.0), 0.0);
+    assert_eq!(rumble_crossfade(1000.0, 300.0), 0.0);
+    assert!((rumble_crossfade(150.0, 300.0) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_crossfade_decreases_with_distance() {
+    let factors: Vec<f32> = (0..=30)
+        .map(|i| rumble_crossfade(i as f32 * 10.0, 300.0))
+        .collect();
+    assert!(factors.windows(2).all(|w| w[1] <= w[0]), "{:?}", factors);
+    // Raccords doux : pente nulle aux deux bouts
+    assert!(1.0 - rumble_crossfade(1.0, 300.0) < 1e-3);
+    assert!(rumble_crossfade(299.0, 300.0) < 1e-3);
+}
+
+#[test]
+fn test_zero_crossfade_distance_disables_rumble() {
+    assert_eq!(rumble_crossfade(0.0, 0.0), 0.0);
+    assert_eq!(rumble_crossfade(10.0, -5.0), 0.0);
+}
+
+#[test]
+fn test_mix_layers() {
+    let main = [[1.0, 1.0]; 4];
+    let layer = [[0.5, -0.5]; 6];
+    let mixed = mix_layers(&main, &layer, 0.5);
+    // La couche la plus longue prolonge le son
+    assert_eq!(mixed.len(), 6);
+    assert_eq!(mixed[0], [1.25, 0.75]);
+    assert_eq!(mixed[5], [0.25, -0.25]);
+    // Facteur nul : son principal seul, longueur comprise
+    assert_eq!(mix_layers(&main, &layer, 0.0), main.to_vec());
+}
+
+// ==================================
+// Group 2: Loading
+// ==================================
+
+#[test]
+fn test_rumble_is_resampled_to_engine_rate() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rumble.wav");
+    write_rumble(&path, 16_000, 0.5);
+
+    let (native, rate) = load_audio_with_rate(path.to_str().unwrap());
+    assert_eq!((native.len(), rate), (8_000, 16_000));
+    let resampled = load_resampled(path.to_str().unwrap(), 32_000).unwrap();
+    assert_eq!(resampled.len(), 16_000);
+    // Mono dupliqué sur les deux canaux
+    assert!(resampled.iter().all(|f| f[0] == f[1]));
+}
+
+#[test]
+fn test_missing_rumble_degrades_to_single_layer() {
+    assert!(load_resampled("assets/sounds/missing_rumble.wav", 32_000).is_err());
+
+    let mut plain = FireworksAudio3D::new(engine_config(None));
+    let mut missing = FireworksAudio3D::new(engine_config(Some(Path::new(
+        "assets/sounds/missing_rumble.wav",
+    ))));
+    assert!(!plain.has_rumble_layer());
+    assert!(!missing.has_rumble_layer());
+
+    // Même rendu qu'un moteur sans couche grave
+    let expected = explosion_level(&mut plain, (0.0, 50.0), 8);
+    assert!(expected > 0.0);
+    assert_eq!(explosion_level(&mut missing, (0.0, 50.0), 8), expected);
+}
+
+#[test]
+fn test_rumble_reinforces_close_explosions_only() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rumble.wav");
+    write_rumble(&path, 22_050, 1.0);
+
+    let mut layered = FireworksAudio3D::new(engine_config(Some(&path)));
+    assert!(layered.has_rumble_layer());
+    let mut plain = FireworksAudio3D::new(engine_config(None));
+
+    let near = (0.0, 20.0);
+    assert!(explosion_level(&mut layered, near, 8) > explosion_level(&mut plain, near, 8));
+
+    // Au-delà de la distance de fondu, la couche grave disparaît
+    let far = (0.0, 600.0);
+    assert_eq!(
+        explosion_level(&mut layered, far, 8),
+        explosion_level(&mut plain, far, 8)
+    );
+}
@@ -0,0 +1,148 @@
+use fireworks_sim::audio_engine::{FireworksAudio3D, FireworksAudioConfig};
+use fireworks_sim::bench::{BenchReport, ACTIVE_PARTICLES_METRIC, BENCH_DEFAULT_SEED};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::PhysicEngine;
+use fireworks_sim::profiler::FRAME_LABEL;
+use fireworks_sim::renderer_engine::NullRendererEngine;
+use fireworks_sim::{AudioEngineSettings, Simulator};
+use std::process::Command;
+
+const WIDTH: f32 = 1024.0;
+
+/// Simulateur de bench sans rendu, avec les vrais moteurs physique et audio
+fn bench_simulator(
+    max_rockets: usize,
+) -> Simulator<NullRendererEngine, PhysicEngineFireworks, FireworksAudio3D> {
+    let physic_config = PhysicConfig {
+        max_rockets,
+        ..PhysicConfig::default()
+    };
+    let audio = FireworksAudio3D::new(FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (WIDTH / 2.0, 0.0),
+        sample_rate: 48_000,
+        block_size: 512,
+        max_voices: 32.min(max_rockets),
+        settings: AudioEngineSettings::default(),
+    });
+    let physic = PhysicEngineFireworks::with_seed(&physic_config, WIDTH, BENCH_DEFAULT_SEED);
+    let mut simulator = Simulator::new(NullRendererEngine::new(), physic, audio);
+    simulator.set_offline_audio(true);
+    simulator
+}
+
+/// Rapport JSON sur stdout du binaire (`bench --no-render`)
+fn run_cli(args: &[&str]) -> (std::process::ExitStatus, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_fireworks_sim"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["bench", "--no-render"])
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status,
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+// ==================================
+// 1. Rapport de bench
+// ==================================
+
+#[test]
+fn test_bench_runs_exactly_n_frames() {
+    let mut simulator = bench_simulator(64);
+    simulator.set_frame_limit(Some(300));
+    simulator.run(None).unwrap();
+    let report = simulator.bench_report(None).unwrap();
+    simulator.close();
+
+    assert_eq!(report.frames, 300);
+    assert!(report.passed);
+    assert!(report.avg_fps > 0.0);
+    let frame_time = report.frame_time.as_ref().expect("frame time summary");
+    assert_eq!(frame_time.metric, FRAME_LABEL);
+    // Fenêtre du profiler élargie : toutes les frames sont prises en compte
+    assert_eq!(frame_time.count, 300);
+    assert!(frame_time.p50 <= frame_time.p99);
+    assert!(report
+        .stages
+        .iter()
+        .any(|s| s.metric == ACTIVE_PARTICLES_METRIC));
+    assert!(report.peak_particles > 0);
+
+    // JSON lisible par une machine
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["frames"], 300);
+    assert_eq!(json["frame_time"]["count"], 300);
+    assert!(json["dropped_audio_requests"].is_u64());
+    assert_eq!(json["passed"], true);
+}
+
+#[test]
+fn test_bench_threshold_and_frame_limit() {
+    let mut simulator = bench_simulator(8);
+    simulator.set_frame_limit(Some(10));
+    simulator.run(None).unwrap();
+
+    let report: BenchReport = simulator.bench_report(Some(f32::MAX)).unwrap();
+    assert!(!report.passed);
+    assert_eq!(report.fail_below_fps, Some(f32::MAX));
+    assert!(simulator.bench_report(Some(0.0)).unwrap().passed);
+
+    // Sans limite de frames, le moteur sans rendu n'a pas de boucle
+    simulator.set_frame_limit(None);
+    assert!(simulator.run(None).is_err());
+    simulator.close();
+}
+
+#[test]
+fn test_bench_loop_runs_the_demo_and_duration_limit() {
+    let mut simulator = bench_simulator(64);
+    let base_config = simulator.physic_engine().get_config().clone();
+    simulator.set_demo(true);
+    simulator.set_duration_limit(Some(0.5));
+    simulator.set_frame_limit(Some(1200));
+    simulator.run(None).unwrap();
+    let report = simulator.bench_report(None).unwrap();
+
+    // Même pas que la boucle fenêtrée : le directeur de démo a changé de scène...
+    let physic = simulator.physic_engine();
+    assert_ne!(physic.get_config(), &base_config);
+    // ... et la durée limite a coupé les lancements puis arrêté la boucle, ciel vidé
+    assert!(!physic.launches_enabled());
+    assert_eq!(physic.get_stats().active_rockets, 0);
+    assert!(report.frames < 1200, "{} frames", report.frames);
+    simulator.close();
+}
+
+// ==================================
+// 2. Ligne de commande
+// ==================================
+
+#[test]
+fn test_bench_cli_prints_json_report() {
+    let (status, stdout) = run_cli(&["--frames", "30", "--max-rockets", "16"]);
+    assert!(status.success(), "{}", stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["frames"], 30);
+    assert!(json["avg_fps"].as_f64().unwrap() > 0.0);
+    assert!(json["stages"].is_array());
+
+    // Graine par défaut : deux exécutions simulent les mêmes particules
+    let (_, again) = run_cli(&["--frames", "30", "--max-rockets", "16"]);
+    let again: serde_json::Value = serde_json::from_str(&again).unwrap();
+    assert_eq!(json["peak_particles"], again["peak_particles"]);
+}
+
+#[test]
+fn test_bench_cli_fails_below_fps() {
+    let (status, stdout) = run_cli(&["--frames", "5", "--fail-below-fps", "1e9"]);
+    assert_eq!(status.code(), Some(1));
+    // Le rapport est tout de même écrit
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["passed"], false);
+}
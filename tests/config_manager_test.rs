@@ -0,0 +1,497 @@
+use fireworks_sim::audio_engine::AudioConfig;
+use fireworks_sim::config_manager::{
+    env_overrides, parse_override_value, ConfigManager, ConfigOverride, ConfigSection, Reloadable,
+    WATCH_INTERVAL,
+};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::config_reload::ConfigChange;
+use fireworks_sim::AppOptions;
+use std::path::Path;
+use std::time::Instant;
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn write(dir: &Path, name: &str, text: &str) {
+    std::fs::write(dir.join(name), text).unwrap();
+}
+
+// ==================================
+// 1. Couches de surcharge
+// ==================================
+
+#[test]
+fn test_missing_files_give_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut manager = ConfigManager::new(dir.path());
+    manager.load().unwrap();
+    assert_eq!(
+        format!("{:?}", manager.physic_config()),
+        format!("{:?}", PhysicConfig::default())
+    );
+    assert_eq!(manager.audio_config(), AudioConfig::default());
+    assert_eq!(
+        manager.path(ConfigSection::Renderer),
+        dir.path().join("renderer.toml")
+    );
+}
+
+#[test]
+fn test_layering_precedence() {
+    let dir = tempfile::tempdir().unwrap();
+    write(
+        dir.path(),
+        "physic.toml",
+        "max_rockets = 10\ngravity = -100.0\ntrail_spacing = 3.0\n",
+    );
+    let mut manager = ConfigManager::new(dir.path()).with_env(vars(&[
+        ("FIREWORKS_PHYSIC__MAX_ROCKETS", "20"),
+        ("FIREWORKS_PHYSIC__GRAVITY", "-50.0"),
+    ]));
+    manager.set_override(ConfigSection::Physic, "max_rockets", 30.into());
+    manager.load().unwrap();
+
+    let config = manager.physic_config();
+    // CLI > env > fichier > défauts
+    assert_eq!(config.max_rockets, 30);
+    assert_eq!(config.gravity, -50.0);
+    assert_eq!(config.trail_spacing, 3.0);
+    assert_eq!(
+        config.particles_per_explosion,
+        PhysicConfig::default().particles_per_explosion
+    );
+}
+
+#[test]
+fn test_nested_env_override_and_preset() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "renderer.toml", "preset = \"low\"\n");
+    let mut manager = ConfigManager::new(dir.path()).with_env(vars(&[
+        ("FIREWORKS_RENDERER__CAMERA__MIN_ZOOM", "0.5"),
+        ("FIREWORKS_RENDERER__BLOOM_ENABLED", "true"),
+    ]));
+    manager.load().unwrap();
+
+    let config = manager.renderer_config();
+    assert_eq!(config.camera.min_zoom, 0.5);
+    // Le préréglage du fichier est développé, l'environnement l'emporte
+    let expected = RendererConfig::from_toml("preset = \"low\"\n").unwrap();
+    assert_eq!(config.render_scale, expected.render_scale);
+    assert!(config.bloom_enabled);
+}
+
+#[test]
+fn test_env_overrides_parsing() {
+    let overrides = env_overrides(vars(&[
+        ("FIREWORKS_AUDIO__ROCKET_SOUND", "boom.wav"),
+        ("FIREWORKS_AUDIO_EXPORT", "out.wav"),
+        ("FIREWORKS_UNKNOWN__FIELD", "1"),
+        ("FIREWORKS_PHYSIC__", "1"),
+        ("HOME", "/root"),
+    ]));
+    let expected: toml::Table = toml::from_str("[audio]\nrocket_sound = \"boom.wav\"\n").unwrap();
+    assert_eq!(overrides, expected);
+
+    assert_eq!(parse_override_value("64"), toml::Value::Integer(64));
+    assert_eq!(parse_override_value("false"), toml::Value::Boolean(false));
+    assert_eq!(
+        parse_override_value("assets/x.wav"),
+        toml::Value::String("assets/x.wav".into())
+    );
+}
+
+#[test]
+fn test_invalid_file_keeps_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "physic.toml", "max_rockets = \"many\"\n");
+    write(dir.path(), "audio.toml", "max_voices = 4\n");
+    let mut manager = ConfigManager::new(dir.path());
+    let err = manager.load().unwrap_err();
+    assert!(err.to_string().contains("physic"), "{:#}", err);
+    assert_eq!(
+        format!("{:?}", manager.physic_config()),
+        format!("{:?}", PhysicConfig::default())
+    );
+    // Les autres sections sont chargées
+    assert_eq!(manager.audio_config().max_voices, 4);
+}
+
+// ==================================
+// 2. Rechargement : à chaud / au redémarrage
+// ==================================
+
+#[test]
+fn test_hot_and_restart_fields() {
+    assert!(PhysicConfig::is_hot_field("gravity"));
+    assert!(!PhysicConfig::is_hot_field("particles_per_explosion"));
+    assert!(!RendererConfig::is_hot_field("gl_debug"));
+    assert!(!RendererConfig::is_hot_field("remote_console.port"));
+    assert!(RendererConfig::is_hot_field("camera.min_zoom"));
+    assert!(!AudioConfig::is_hot_field("sample_rate"));
+}
+
+#[test]
+fn test_reload_applies_hot_fields_only() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "physic.toml", "gravity = -100.0\n");
+    let mut manager = ConfigManager::new(dir.path());
+    manager.load().unwrap();
+    let handle = manager.physic();
+
+    // Rien de changé : pas d'événement
+    assert_eq!(manager.reload(ConfigSection::Physic).unwrap(), None);
+
+    write(
+        dir.path(),
+        "physic.toml",
+        "gravity = -50.0\nparticles_per_explosion = 64\n",
+    );
+    let event = manager.reload(ConfigSection::Physic).unwrap().unwrap();
+    assert_eq!(event.section, ConfigSection::Physic);
+    let fields = |changes: &[ConfigChange]| -> Vec<String> {
+        changes.iter().map(|c| c.field.clone()).collect()
+    };
+    assert_eq!(fields(&event.hot), vec!["gravity"]);
+    assert_eq!(fields(&event.restart), vec!["particles_per_explosion"]);
+    assert!(event
+        .summary()
+        .contains("restart required for: particles_per_explosion"));
+
+    // Le handle partagé voit la nouvelle gravité, pas la nouvelle taille de pool
+    let config = handle.read().unwrap();
+    assert_eq!(config.gravity, -50.0);
+    assert_eq!(
+        config.particles_per_explosion,
+        PhysicConfig::default().particles_per_explosion
+    );
+}
+
+// ==================================
+// 3. Surveillance des fichiers
+// ==================================
+
+#[test]
+fn test_watched_file_edit_emits_event() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "renderer.toml", "bloom_intensity = 1.0\n");
+    let mut manager = ConfigManager::new(dir.path());
+    manager.load().unwrap();
+
+    // Hors surveillance : aucun événement
+    write(dir.path(), "renderer.toml", "bloom_intensity = 2.25\n");
+    let start = Instant::now();
+    assert!(manager.poll(start).is_empty());
+
+    manager.set_watching(true);
+    let events = manager.poll(start);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].section, ConfigSection::Renderer);
+    assert!(
+        events[0].summary().contains("bloom_intensity: 1 → 2.25"),
+        "{}",
+        events[0].summary()
+    );
+    assert_eq!(manager.renderer_config().bloom_intensity, 2.25);
+
+    // Fichier inchangé : plus d'événement
+    assert!(manager.poll(start + WATCH_INTERVAL).is_empty());
+
+    // Nouvelle modification, vue au prochain intervalle seulement
+    write(
+        dir.path(),
+        "renderer.toml",
+        "bloom_intensity = 3.0\n[camera]\n",
+    );
+    assert!(manager.poll(start + WATCH_INTERVAL).is_empty());
+    let events = manager.poll(start + WATCH_INTERVAL * 2);
+    assert_eq!(events.len(), 1);
+    assert_eq!(manager.renderer_config().bloom_intensity, 3.0);
+}
+
+#[test]
+fn test_watched_invalid_edit_is_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "audio.toml", "max_voices = 8\n");
+    let mut manager = ConfigManager::new(dir.path());
+    manager.load().unwrap();
+    manager.set_watching(true);
+
+    write(dir.path(), "audio.toml", "max_voices = [\n");
+    assert!(manager.poll(Instant::now()).is_empty());
+    assert_eq!(manager.audio_config().max_voices, 8);
+}
+
+#[test]
+fn test_watch_config_flag() {
+    let options =
+        AppOptions::try_parse_from(["fireworks-sim", "run", "--watch-config"], |_| None).unwrap();
+    assert!(options.watch_config);
+    assert!(!AppOptions::default().watch_config);
+}
+
+// ==================================
+// 4. Validation
+// ==================================
+
+#[test]
+fn test_shipped_configs_are_valid() {
+    let mut manager = ConfigManager::default();
+    manager.load().unwrap();
+    assert!(PhysicConfig::default().validate().is_empty());
+    assert!(RendererConfig::default().validate().is_empty());
+    assert!(manager.renderer_config().validate().is_empty());
+}
+
+#[test]
+fn test_invalid_toml_reports_line_and_column() {
+    let dir = tempfile::tempdir().unwrap();
+    write(
+        dir.path(),
+        "physic.toml",
+        "gravity = -200.0\nparticles_per_explosion = -5\n",
+    );
+    let err = ConfigManager::new(dir.path()).load().unwrap_err();
+    let text = format!("{:#}", err);
+    assert!(text.contains("physic.toml"), "{}", text);
+    assert!(text.contains("line 2, column 27"), "{}", text);
+
+    write(dir.path(), "physic.toml", "gravity = \n");
+    let text = format!("{:#}", ConfigManager::new(dir.path()).load().unwrap_err());
+    assert!(text.contains("line 1"), "{}", text);
+}
+
+#[test]
+fn test_out_of_range_values_are_listed() {
+    let mut physic = PhysicConfig {
+        max_rockets: 0,
+        spawn_rocket_min_speed: 600.0,
+        ..PhysicConfig::default()
+    };
+    physic.trail_end_color[0] = 2.0;
+    let violations = physic.validate();
+    assert_eq!(violations.len(), 3, "{:?}", violations);
+    assert_eq!(violations[0], "max_rockets: must be greater than 0");
+    assert!(violations[1].starts_with("spawn_rocket_min_speed: 600 is greater than"));
+    assert_eq!(violations[2], "trail_end_color: 2 is out of range [0, 1]");
+
+    let mut renderer = RendererConfig {
+        bloom_blur_passes: 0,
+        bloom_intensity: 50.0,
+        ..RendererConfig::default()
+    };
+    renderer.camera.min_zoom = 10.0;
+    renderer.particles.smoke.softness = -1.0;
+    let violations = renderer.validate();
+    for field in [
+        "bloom_intensity: 50 is out of range",
+        "bloom_blur_passes: 0 is out of range [1, 16]",
+        "camera.min_zoom: 10 is greater than camera.max_zoom",
+        "particles.smoke.softness",
+    ] {
+        assert!(
+            violations.iter().any(|v| v.starts_with(field)),
+            "{}: {:?}",
+            field,
+            violations
+        );
+    }
+}
+
+#[test]
+fn test_invalid_reload_keeps_previous_config() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "renderer.toml", "bloom_intensity = 2.0\n");
+    let mut manager = ConfigManager::new(dir.path());
+    manager.load().unwrap();
+
+    write(
+        dir.path(),
+        "renderer.toml",
+        "bloom_intensity = 20.0\nrender_scale = 4.0\n",
+    );
+    let err = manager.reload(ConfigSection::Renderer).unwrap_err();
+    let text = err.to_string();
+    assert!(text.contains("renderer.toml"), "{}", text);
+    assert!(
+        text.contains("  - bloom_intensity: 20 is out of range"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("  - render_scale: 4 is out of range"),
+        "{}",
+        text
+    );
+    assert_eq!(manager.renderer_config().bloom_intensity, 2.0);
+
+    // Surcharge hors bornes : refusée de la même façon
+    let mut manager =
+        ConfigManager::new(dir.path()).with_env(vars(&[("FIREWORKS_PHYSIC__MAX_ROCKETS", "0")]));
+    let text = format!("{:#}", manager.load().unwrap_err());
+    assert!(
+        text.contains("max_rockets: must be greater than 0"),
+        "{}",
+        text
+    );
+}
+
+// ==================================
+// 5. Surcharges individuelles (--set, environnement)
+// ==================================
+
+/// Manager avec les `--set` de `args` appliqués après l'environnement
+fn manager_with(dir: &Path, env: &[(&str, &str)], args: &[&str]) -> ConfigManager {
+    let argv = std::iter::once("fireworks-sim").chain(args.iter().copied());
+    let options = AppOptions::try_parse_from(argv, |_| None).unwrap();
+    let mut manager = ConfigManager::new(dir).with_env(vars(env));
+    for o in &options.config_overrides {
+        manager.set_override(o.section, &o.field, o.value.clone());
+    }
+    manager
+}
+
+#[test]
+fn test_set_override_parsing() {
+    let parsed: ConfigOverride = "physic.max_rockets=128".parse().unwrap();
+    assert_eq!(
+        parsed,
+        ConfigOverride {
+            section: ConfigSection::Physic,
+            field: "max_rockets".to_string(),
+            value: toml::Value::Integer(128),
+        }
+    );
+    let parsed: ConfigOverride = " renderer.camera.min_zoom = 0.5".parse().unwrap();
+    assert_eq!(parsed.field, "camera.min_zoom");
+    assert_eq!(parsed.value, toml::Value::Float(0.5));
+
+    for bad in [
+        "physic.max_rockets",
+        "video.fps=60",
+        "physic=1",
+        "physic..x=1",
+    ] {
+        assert!(bad.parse::<ConfigOverride>().is_err(), "{}", bad);
+    }
+
+    // Répétable, dans l'ordre ; refusée par clap si mal formée
+    let argv = [
+        "fireworks-sim",
+        "bench",
+        "--frames",
+        "10",
+        "--set",
+        "physic.gravity=-50",
+        "--set",
+        "audio.max_voices=8",
+    ];
+    let options = AppOptions::try_parse_from(argv, |_| None).unwrap();
+    let fields: Vec<&str> = options
+        .config_overrides
+        .iter()
+        .map(|o| o.field.as_str())
+        .collect();
+    assert_eq!(fields, vec!["gravity", "max_voices"]);
+    assert!(AppOptions::try_parse_from(["fireworks-sim", "--set", "nope"], |_| None).is_err());
+}
+
+#[test]
+fn test_override_type_coercion() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut manager = manager_with(
+        dir.path(),
+        &[("FIREWORKS_PHYSIC__GRAVITY", "-50")],
+        &[
+            "--set",
+            "physic.shapes_dir=2024",
+            "--set",
+            "physic.max_active_trail_particles=500",
+            "--set",
+            "renderer.max_fps=30",
+        ],
+    );
+    manager.load().unwrap();
+
+    let physic = manager.physic_config();
+    // Entier pour un flottant, nombre pour une chaîne, champ optionnel
+    assert_eq!(physic.gravity, -50.0);
+    assert_eq!(physic.shapes_dir, "2024");
+    assert_eq!(physic.max_active_trail_particles, Some(500));
+    assert_eq!(manager.renderer_config().max_fps, Some(30));
+
+    let applied = manager.applied_overrides();
+    assert!(
+        applied.contains(&"physic.gravity = -50.0 (FIREWORKS_PHYSIC__GRAVITY)".to_string()),
+        "{:?}",
+        applied
+    );
+    assert!(
+        applied.contains(&"renderer.max_fps = 30 (--set renderer.max_fps)".to_string()),
+        "{:?}",
+        applied
+    );
+}
+
+#[test]
+fn test_override_errors_name_their_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut manager = manager_with(
+        dir.path(),
+        &[("FIREWORKS_PHYSIC__MAX_ROCKTES", "64")],
+        &[
+            "--set",
+            "physic.max_rockets=many",
+            "--set",
+            "renderer.camera.zoom_speed=2",
+            "--set",
+            "renderer.bloom_enabled=1",
+        ],
+    );
+    let text = format!("{:#}", manager.load().unwrap_err());
+    for expected in [
+        "physic.max_rocktes: unknown key (FIREWORKS_PHYSIC__MAX_ROCKTES)",
+        "physic.max_rockets: expected integer, got string \"many\" (--set physic.max_rockets)",
+        "renderer.camera.zoom_speed: unknown key (--set renderer.camera.zoom_speed)",
+        "renderer.bloom_enabled: expected boolean, got integer 1",
+    ] {
+        assert!(text.contains(expected), "{}\n---\n{}", expected, text);
+    }
+    // Sections refusées : valeurs par défaut ; audio chargé normalement
+    assert_eq!(
+        manager.physic_config().max_rockets,
+        PhysicConfig::default().max_rockets
+    );
+    assert_eq!(manager.audio_config(), AudioConfig::default());
+}
+
+#[test]
+fn test_cli_override_beats_env() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "physic.toml", "max_rockets = 10\n");
+    let mut manager = manager_with(
+        dir.path(),
+        &[
+            ("FIREWORKS_PHYSIC__MAX_ROCKETS", "20"),
+            ("FIREWORKS_PHYSIC__GRAVITY", "-70.0"),
+        ],
+        &["--set", "physic.max_rockets=128"],
+    );
+    manager.load().unwrap();
+    let config = manager.physic_config();
+    assert_eq!(config.max_rockets, 128);
+    assert_eq!(config.gravity, -70.0);
+
+    // Rechargement : les surcharges s'appliquent toujours au fichier relu
+    write(
+        dir.path(),
+        "physic.toml",
+        "max_rockets = 10\ngravity = -10.0\n",
+    );
+    assert!(manager.reload(ConfigSection::Physic).unwrap().is_none());
+    assert_eq!(manager.physic_config().max_rockets, 128);
+}
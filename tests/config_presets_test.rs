@@ -0,0 +1,222 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::AudioConfig;
+use fireworks_sim::config_manager::{ConfigManager, ConfigSection};
+use fireworks_sim::config_presets::{list_presets, preset_dir, save_preset, PresetSnapshot};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::types::ReloadResult;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use helpers::{DummyAudio, DummyPhysic};
+use std::path::Path;
+
+fn write_preset(root: &Path, name: &str, files: &[(&str, &str)]) {
+    let dir = root.join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    for (file, text) in files {
+        std::fs::write(dir.join(file), text).unwrap();
+    }
+}
+
+/// Manager sur `root/config`, préréglages dans `root/presets`
+fn manager(root: &Path) -> ConfigManager {
+    let mut manager = ConfigManager::new(root.join("config"));
+    manager.set_presets_dir(root.join("presets"));
+    manager.load().unwrap();
+    manager
+}
+
+// ==================================
+// 1. Découverte
+// ==================================
+
+#[test]
+fn test_preset_discovery() {
+    let dir = tempfile::tempdir().unwrap();
+    let presets = dir.path().join("presets");
+    write_preset(&presets, "finale", &[("physic.toml", "gravity = -80.0\n")]);
+    write_preset(
+        &presets,
+        "calm",
+        &[("renderer.toml", "bloom_intensity = 0.5\n")],
+    );
+    // Sans config, ou nom invalide : ignorés
+    write_preset(&presets, "empty", &[("notes.txt", "wip")]);
+    write_preset(&presets, "bad name", &[("physic.toml", "")]);
+    std::fs::write(presets.join("physic.toml"), "").unwrap();
+
+    assert_eq!(list_presets(&presets), vec!["calm", "finale"]);
+    assert!(list_presets(&dir.path().join("missing")).is_empty());
+
+    assert!(preset_dir(&presets, "../config").is_err());
+    assert!(preset_dir(&presets, "").is_err());
+    assert_eq!(
+        preset_dir(&presets, "bench_2").unwrap(),
+        presets.join("bench_2")
+    );
+}
+
+// ==================================
+// 2. Application
+// ==================================
+
+#[test]
+fn test_apply_preset_order_and_sources() {
+    let dir = tempfile::tempdir().unwrap();
+    let presets = dir.path().join("presets");
+    write_preset(
+        &presets,
+        "finale",
+        &[
+            ("audio.toml", "max_voices = 4\n"),
+            ("renderer.toml", "bloom_intensity = 2.5\n"),
+            ("physic.toml", "gravity = -80.0\n"),
+        ],
+    );
+    let mut manager = manager(dir.path());
+
+    let events = manager.apply_preset("finale").unwrap();
+    let sections: Vec<ConfigSection> = events.iter().map(|e| e.section).collect();
+    assert_eq!(
+        sections,
+        vec![
+            ConfigSection::Physic,
+            ConfigSection::Renderer,
+            ConfigSection::Audio
+        ]
+    );
+    assert_eq!(manager.physic_config().gravity, -80.0);
+    assert_eq!(manager.renderer_config().bloom_intensity, 2.5);
+    // Audio : appliqué au redémarrage seulement
+    assert_eq!(events[2].restart.len(), 1);
+    assert_eq!(
+        manager.audio_config().max_voices,
+        AudioConfig::default().max_voices
+    );
+    // Les rechargements suivants lisent le préréglage
+    assert_eq!(
+        manager.path(ConfigSection::Physic),
+        presets.join("finale/physic.toml")
+    );
+
+    // Réappliqué : plus rien ne change côté physique et rendu
+    let events = manager.apply_preset("finale").unwrap();
+    assert!(events.iter().all(|e| e.hot.is_empty()), "{:?}", events);
+}
+
+#[test]
+fn test_apply_preset_is_atomic() {
+    let dir = tempfile::tempdir().unwrap();
+    let presets = dir.path().join("presets");
+    write_preset(
+        &presets,
+        "broken",
+        &[
+            ("physic.toml", "gravity = -80.0\n"),
+            ("renderer.toml", "render_scale = 9.0\n"),
+        ],
+    );
+    let mut manager = manager(dir.path());
+
+    let err = manager.apply_preset("broken").unwrap_err();
+    assert!(format!("{:#}", err).contains("render_scale"), "{:#}", err);
+    // Physique valide mais non appliquée : le préréglage est refusé en entier
+    assert_eq!(
+        manager.physic_config().gravity,
+        PhysicConfig::default().gravity
+    );
+    assert_eq!(
+        manager.path(ConfigSection::Physic),
+        dir.path().join("config/physic.toml")
+    );
+
+    assert!(manager.apply_preset("missing").is_err());
+    write_preset(&presets, "empty", &[]);
+    assert!(manager.apply_preset("empty").is_err());
+}
+
+// ==================================
+// 3. Enregistrement et commandes console
+// ==================================
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let presets = dir.path().join("presets");
+    let physic = PhysicConfig {
+        gravity: -42.0,
+        max_rockets: 64,
+        ..PhysicConfig::default()
+    };
+    let renderer = RendererConfig {
+        bloom_enabled: true,
+        bloom_intensity: 1.75,
+        ..RendererConfig::default()
+    };
+    let audio = AudioConfig::default();
+    let saved = save_preset(
+        &presets,
+        "bench",
+        PresetSnapshot {
+            physic: &physic,
+            renderer: &renderer,
+            audio: &audio,
+        },
+    )
+    .unwrap();
+    assert_eq!(saved, presets.join("bench"));
+
+    let mut manager = manager(dir.path());
+    manager.apply_preset("bench").unwrap();
+    assert_eq!(manager.physic_config(), physic);
+    assert_eq!(manager.renderer_config(), renderer);
+    assert_eq!(manager.audio_config(), audio);
+}
+
+#[test]
+fn test_preset_commands() {
+    let dir = tempfile::tempdir().unwrap();
+    let shared = RendererShared::default();
+    *shared.config_manager.borrow_mut() = manager(dir.path());
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic {
+        config: PhysicConfig {
+            gravity: -10.0,
+            ..PhysicConfig::default()
+        },
+        ..DummyPhysic::default()
+    };
+
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset.list");
+    assert!(out.starts_with("No preset in"), "{}", out);
+
+    // Configs courantes figées, puis modifiées
+    shared.config.borrow_mut().bloom_intensity = 3.0;
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset.save calm");
+    assert!(out.starts_with("Preset 'calm' saved to"), "{}", out);
+    shared.config.borrow_mut().bloom_intensity = 1.0;
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset.list");
+    assert_eq!(out, "Presets: calm");
+
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset calm");
+    assert!(out.starts_with("Preset 'calm' applied"), "{}", out);
+    assert!(out.contains("gravity: -200 → -10"), "{}", out);
+    assert_eq!(shared.config.borrow().bloom_intensity, 3.0);
+    assert_eq!(shared.physic_reload.get(), Some(ReloadResult::Unchanged));
+
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset nope");
+    assert!(
+        out.starts_with("Preset not applied: Unknown preset 'nope'"),
+        "{}",
+        out
+    );
+    let out = registry.execute(&mut audio, &mut physic, "sim.preset.save ../x");
+    assert!(
+        out.starts_with("Preset not saved: Invalid preset name"),
+        "{}",
+        out
+    );
+}
@@ -0,0 +1,225 @@
+use fireworks_sim::renderer_engine::command_console::{autocomplete_suggestions, CommandRegistry};
+use fireworks_sim::renderer_engine::command_cvar::{Cvar, CvarValue};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::tonemap::ToneMappingMode;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+/// Registre avec une cvar de chaque type, et le journal de leurs changements
+fn registry_with_cvars() -> (CommandRegistry, Rc<RefCell<Vec<String>>>) {
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let mut registry = CommandRegistry::new();
+
+    let log = changes.clone();
+    registry.register_cvar("test.intensity", 1.0f32, Some((0.0, 4.0)), move |v| {
+        log.borrow_mut().push(format!("intensity={}", v))
+    });
+    let log = changes.clone();
+    registry.register_cvar("test.iterations", 5i32, Some((1, 8)), move |v| {
+        log.borrow_mut().push(format!("iterations={}", v))
+    });
+    let log = changes.clone();
+    registry.register_cvar("test.enabled", true, None, move |v| {
+        log.borrow_mut().push(format!("enabled={}", v))
+    });
+    let log = changes.clone();
+    registry.add_cvar(
+        Cvar::new("test.mode", ToneMappingMode::Linear, move |v| {
+            log.borrow_mut().push(format!("mode={}", v.name()))
+        })
+        .label("Mode"),
+    );
+    (registry, changes)
+}
+
+// ==================================
+// 1. Valeurs typées
+// ==================================
+
+#[test]
+fn test_typed_parsing() {
+    assert_eq!(<f32 as CvarValue>::parse("1.5"), Some(1.5));
+    assert_eq!(<f32 as CvarValue>::parse("inf"), None);
+    assert_eq!(<i32 as CvarValue>::parse("3"), Some(3));
+    assert_eq!(<i32 as CvarValue>::parse("3.5"), None);
+    assert_eq!(<bool as CvarValue>::parse("ON"), Some(true));
+    assert_eq!(<bool as CvarValue>::parse("0"), Some(false));
+    assert_eq!(<bool as CvarValue>::parse("maybe"), None);
+    assert_eq!(
+        <ToneMappingMode as CvarValue>::parse("AgX"),
+        Some(ToneMappingMode::Agx)
+    );
+
+    let (registry, changes) = registry_with_cvars();
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+    let mut run = |line: &str| registry.execute(&mut audio, &mut physic, line);
+
+    assert_eq!(run("test.intensity"), "test.intensity: 1.00");
+    assert_eq!(run("test.enabled off"), "test.enabled: off");
+    assert_eq!(run("test.mode aces"), "Mode: aces");
+
+    // Valeur du mauvais type : usage, aucun changement
+    assert_eq!(
+        run("test.intensity high"),
+        "Usage: test.intensity <f>  (0.0..4.0)"
+    );
+    assert_eq!(
+        run("test.mode hable"),
+        "Usage: test.mode <linear|reinhard|aces|agx>"
+    );
+    assert_eq!(*changes.borrow(), vec!["enabled=false", "mode=aces"]);
+}
+
+#[test]
+fn test_range_clamping() {
+    let (registry, changes) = registry_with_cvars();
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+    let mut run = |line: &str| registry.execute(&mut audio, &mut physic, line);
+
+    assert_eq!(run("test.intensity 2.5"), "test.intensity set to 2.50");
+    assert_eq!(
+        run("test.intensity 9"),
+        "test.intensity set to 4.00 (clamped)"
+    );
+    assert_eq!(
+        run("test.iterations 0"),
+        "test.iterations set to 1 (clamped)"
+    );
+    assert_eq!(
+        registry.cvars().value_string("test.intensity").unwrap(),
+        "4.00"
+    );
+    // Le callback reçoit la valeur appliquée, pas la demande
+    assert_eq!(
+        *changes.borrow(),
+        vec!["intensity=2.5", "intensity=4", "iterations=1"]
+    );
+}
+
+#[test]
+fn test_change_callback_and_reset() {
+    let calls = Rc::new(Cell::new(0));
+    let mut registry = CommandRegistry::new();
+    let counter = calls.clone();
+    registry.register_cvar("test.gain", 0.5f32, None, move |_| {
+        counter.set(counter.get() + 1)
+    });
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    registry.execute(&mut audio, &mut physic, "test.gain 0.8");
+    registry.execute(&mut audio, &mut physic, "test.gain");
+    assert_eq!(calls.get(), 1);
+
+    let out = registry.execute(&mut audio, &mut physic, "cvar.reset test.gain");
+    assert_eq!(out, "test.gain reset to 0.50");
+    assert_eq!(calls.get(), 2);
+    assert!(registry
+        .execute(&mut audio, &mut physic, "cvar.reset test.nope")
+        .starts_with("Unknown cvar"));
+}
+
+// ==================================
+// 2. Profil TOML
+// ==================================
+
+#[test]
+fn test_profile_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("profiles/cvars.toml");
+    let path_arg = path.display().to_string();
+    let (registry, changes) = registry_with_cvars();
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+    let mut run = |line: &str| registry.execute(&mut audio, &mut physic, line);
+
+    run("test.intensity 3");
+    run("test.iterations 7");
+    run("test.enabled off");
+    run("test.mode agx");
+    assert_eq!(
+        run(&format!("cvar.save {}", path_arg)),
+        format!("Cvars saved to {}", path_arg)
+    );
+
+    run("cvar.reset all");
+    assert_eq!(
+        registry.cvars().value_string("test.mode").unwrap(),
+        "linear"
+    );
+
+    changes.borrow_mut().clear();
+    assert_eq!(
+        run(&format!("cvar.load {}", path_arg)),
+        format!("4 cvar(s) loaded from {}", path_arg)
+    );
+    let cvars = registry.cvars();
+    assert_eq!(cvars.value_string("test.intensity").unwrap(), "3.00");
+    assert_eq!(cvars.value_string("test.iterations").unwrap(), "7");
+    assert_eq!(cvars.value_string("test.enabled").unwrap(), "off");
+    assert_eq!(cvars.value_string("test.mode").unwrap(), "agx");
+    // Chaque valeur chargée passe par le callback
+    assert_eq!(changes.borrow().len(), 4);
+}
+
+#[test]
+fn test_profile_tables_and_warnings() {
+    let (registry, _) = registry_with_cvars();
+
+    // Tables TOML imbriquées, valeurs hors plage, clés inconnues, mauvais type
+    let text = "[test]\nintensity = 12\nmode = \"reinhard\"\nenabled = \"yes\"\nghost = 1\n";
+    let (applied, warnings) = registry.cvars().apply_toml(text).unwrap();
+    assert_eq!(applied, 2);
+    assert_eq!(
+        registry.cvars().value_string("test.intensity").unwrap(),
+        "4.00"
+    );
+    assert_eq!(
+        registry.cvars().value_string("test.mode").unwrap(),
+        "reinhard"
+    );
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].starts_with("test.enabled: expected <on|off>"));
+    assert_eq!(warnings[1], "Unknown cvar 'test.ghost' ignored");
+}
+
+// ==================================
+// 3. Cvars du renderer
+// ==================================
+
+#[test]
+fn test_renderer_cvars_follow_config() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    // La cvar lit la config : un rechargement (ici direct) est visible
+    shared.config.borrow_mut().bloom_threshold = 0.7;
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.threshold");
+    assert_eq!(out, "Bloom threshold: 0.70");
+
+    registry.execute(&mut audio, &mut physic, "renderer.tonemapping agx");
+    assert_eq!(shared.config.borrow().tonemapping, ToneMappingMode::Agx);
+
+    let list = registry.execute(&mut audio, &mut physic, "cvar.list renderer.bloom");
+    assert!(list.contains("renderer.bloom.threshold = 0.70"), "{}", list);
+    assert!(list.contains("renderer.bloom.knee"), "{}", list);
+    assert!(!list.contains("renderer.tonemapping"), "{}", list);
+
+    // Autocomplétion : nom de cvar et valeurs du type
+    let matcher = SkimMatcherV2::default();
+    let names = autocomplete_suggestions(&registry, &matcher, "renderer.bloom.thr");
+    assert!(names.contains(&"renderer.bloom.threshold".to_string()));
+    assert_eq!(
+        autocomplete_suggestions(&registry, &matcher, "renderer.tonemapping re"),
+        vec!["renderer.tonemapping reinhard"]
+    );
+}
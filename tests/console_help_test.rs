@@ -0,0 +1,138 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_help::{
+    command_group, find_command, format_command_help, format_help, grouped_commands, usage_hint,
+    wrap_text,
+};
+
+/// Registre d'une douzaine de commandes réparties sur les trois moteurs
+fn sample_registry() -> CommandRegistry {
+    let mut registry = CommandRegistry::new();
+    for name in ["audio.unmute", "audio.mute", "audio.volume"] {
+        registry.register_for_audio(name, |_, _| String::new());
+    }
+    for name in [
+        "physic.stats",
+        "physic.config",
+        "physic.attractor.add",
+        "physic.shape.ring",
+    ] {
+        registry.register_for_physic(name, |_, _| String::new());
+    }
+    for name in [
+        "renderer.vsync",
+        "renderer.bloom",
+        "renderer.screenshot",
+        "renderer.preset",
+        "renderer.hud",
+    ] {
+        registry.register_for_renderer(name, |_| String::new());
+    }
+    registry.register_args("renderer.bloom", &[&["on", "off"]]);
+    registry.register_args("renderer.preset", &[&["low", "medium", "high", "ultra"]]);
+    registry.register_usage("renderer.screenshot", "[path]");
+    registry.register_description("renderer.bloom", "Toggle the glow around bright areas");
+    registry.register_description(
+        "physic.attractor.add",
+        "Add a point attracting (or repelling, if negative) the particles towards it",
+    );
+    registry
+}
+
+// ==================================
+// 1. Liste groupée
+// ==================================
+
+#[test]
+fn test_commands_grouped_by_prefix_and_sorted() {
+    let registry = sample_registry();
+    let groups = grouped_commands(&registry);
+    let names: Vec<&str> = groups.iter().map(|(group, _)| *group).collect();
+    assert_eq!(names, ["audio", "physic", "renderer", "internal"]);
+
+    assert_eq!(groups[0].1, ["audio.mute", "audio.unmute", "audio.volume"]);
+    assert_eq!(
+        groups[1].1,
+        [
+            "physic.attractor.add",
+            "physic.config",
+            "physic.shape.ring",
+            "physic.stats"
+        ]
+    );
+    assert!(groups[3].1.contains(&"help".to_string()));
+    assert!(groups[3].1.contains(&"cvar.list".to_string()));
+    assert_eq!(command_group("cvar.list"), "internal");
+    assert_eq!(command_group("renderer.bloom.threshold"), "renderer");
+}
+
+#[test]
+fn test_help_listing_hints_descriptions_and_wrapping() {
+    let registry = sample_registry();
+    assert_eq!(usage_hint(&registry, "renderer.bloom"), "<on|off>");
+    assert_eq!(usage_hint(&registry, "renderer.screenshot"), "[path]");
+    assert_eq!(usage_hint(&registry, "exec"), "[-e] <path>");
+    assert_eq!(usage_hint(&registry, "renderer.hud"), "");
+
+    let width = 60;
+    let help = format_help(&registry, width);
+    assert!(help.starts_with("audio:\n  audio.mute"));
+    let bloom = help
+        .lines()
+        .find(|l| l.contains("renderer.bloom <on|off>"))
+        .unwrap();
+    assert!(bloom.contains("Toggle the glow"));
+    // Aucune ligne ne dépasse la largeur, la description longue continue en retrait
+    for line in help.lines() {
+        assert!(line.chars().count() <= width, "{:?}", line);
+    }
+    assert!(help.contains("towards it"));
+
+    assert_eq!(
+        wrap_text("one two three four", 9),
+        ["one two", "three", "four"]
+    );
+    assert_eq!(wrap_text("", 10), Vec::<String>::new());
+}
+
+// ==================================
+// 2. help <commande>
+// ==================================
+
+#[test]
+fn test_single_command_fuzzy_lookup() {
+    let registry = sample_registry();
+    assert_eq!(
+        find_command(&registry, "renderer.bloom").as_deref(),
+        Some("renderer.bloom")
+    );
+    assert_eq!(
+        find_command(&registry, "blom").as_deref(),
+        Some("renderer.bloom")
+    );
+    assert_eq!(
+        find_command(&registry, "attractor").as_deref(),
+        Some("physic.attractor.add")
+    );
+    assert_eq!(find_command(&registry, "zzzz"), None);
+
+    let exact = format_command_help(&registry, "renderer.preset");
+    assert!(exact.starts_with("renderer.preset <low|medium|high|ultra>"));
+    assert!(exact.contains("Argument 1: low, medium, high, ultra"));
+    assert!(exact.contains("Group: renderer"));
+
+    let fuzzy = format_command_help(&registry, "blom");
+    assert!(fuzzy.starts_with("No command 'blom', closest match:\nrenderer.bloom <on|off>"));
+    assert!(fuzzy.contains("Toggle the glow"));
+
+    assert!(format_command_help(&registry, "zzzz").starts_with("Unknown command 'zzzz'"));
+}
+
+#[test]
+fn test_help_on_alias() {
+    let registry = sample_registry();
+    registry.define_alias("glow", "renderer.bloom on").unwrap();
+    assert_eq!(
+        format_command_help(&registry, "glow"),
+        "glow is an alias for: renderer.bloom on"
+    );
+}
@@ -0,0 +1,89 @@
+use fireworks_sim::renderer_engine::command_console::Console;
+use fireworks_sim::renderer_engine::console_output::{ConsoleOutput, Severity};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
+
+mod helpers;
+use helpers::{TestAudio, TestPhysic};
+
+#[test]
+fn test_severity_classification() {
+    assert_eq!(Severity::classify("> renderer.bloom on"), Severity::Echo);
+    assert_eq!(Severity::classify("Error: bad value"), Severity::Error);
+    assert_eq!(Severity::classify("Unknown command 'x'."), Severity::Error);
+    assert_eq!(Severity::classify("⚠️ shader fallback"), Severity::Warn);
+    assert_eq!(Severity::classify("Bloom enabled"), Severity::Info);
+}
+
+#[test]
+fn test_ring_buffer_evicts_oldest_lines() {
+    let mut output = ConsoleOutput::new(3);
+    for i in 0..5 {
+        output.push(Severity::Info, format!("line {}", i));
+    }
+    assert_eq!(output.len(), 3);
+    assert_eq!(output.texts(), ["line 2", "line 3", "line 4"]);
+
+    // Réduction de la capacité : les plus anciennes partent
+    output.set_max_lines(1);
+    assert_eq!(output.texts(), ["line 4"]);
+    // Capacité nulle ramenée à 1
+    output.set_max_lines(0);
+    assert_eq!(output.max_lines(), 1);
+    output.push(Severity::Error, "last");
+    assert_eq!(output.texts(), ["last"]);
+    assert_eq!(output.lines().next().unwrap().severity, Severity::Error);
+}
+
+#[test]
+fn test_filter_matching() {
+    let mut output = ConsoleOutput::default();
+    output.push(Severity::Echo, "> renderer.bloom on");
+    output.push(Severity::Info, "Bloom enabled");
+    output.push(Severity::Info, "Tone mapping: aces");
+
+    output.set_filter(Some("bloom"));
+    let visible: Vec<&str> = output.visible().map(|l| l.text.as_str()).collect();
+    assert_eq!(visible, ["> renderer.bloom on", "Bloom enabled"]);
+    // Recherche floue : lettres dans l'ordre
+    assert!(output.matches("blur loop zoom"));
+    assert!(output.matches("Bloom"));
+    assert!(!output.matches("Tone mapping: aces"));
+
+    // Motif vide : aucun filtre
+    output.set_filter(Some("  "));
+    assert_eq!(output.filter(), None);
+    assert_eq!(output.visible().count(), 3);
+}
+
+#[test]
+fn test_console_filter_commands() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = fireworks_sim::renderer_engine::command_console::CommandRegistry::new();
+    let mut console = Console::new();
+    let now = Instant::now();
+
+    console.log("Error: something broke");
+    console.log_with(Severity::Warn, "careful");
+    let severities: Vec<Severity> = console.output().lines().map(|l| l.severity).collect();
+    assert_eq!(severities, [Severity::Error, Severity::Warn]);
+
+    console.submit("filter broke", now, &mut audio, &mut physic, &registry);
+    assert_eq!(console.output().filter(), Some("broke"));
+    assert!(console.output().matches("Error: something broke"));
+    assert!(!console.output().matches("careful"));
+
+    console.submit("filter off", now, &mut audio, &mut physic, &registry);
+    assert_eq!(console.output().filter(), None);
+    assert_eq!(console.output().texts().last().unwrap(), &"Filter off");
+    console.submit("filter", now, &mut audio, &mut physic, &registry);
+    assert!(console
+        .output()
+        .texts()
+        .last()
+        .unwrap()
+        .starts_with("Usage: filter"));
+}
@@ -0,0 +1,127 @@
+use fireworks_sim::renderer_engine::command_console::{CommandRegistry, Console};
+use fireworks_sim::renderer_engine::console_server::{ConsoleServer, RemoteConsoleConfig};
+use std::cell::RefCell;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+mod helpers;
+use helpers::{TestAudio, TestPhysic};
+
+fn local_config(token: &str) -> RemoteConsoleConfig {
+    RemoteConsoleConfig {
+        enabled: true,
+        port: 0,
+        token: token.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Client de test : envoie une ligne, lit la réponse jusqu'à la ligne vide.
+struct Client {
+    writer: TcpStream,
+    reader: BufReader<TcpStream>,
+}
+
+impl Client {
+    fn connect(server: &ConsoleServer) -> Self {
+        let stream = TcpStream::connect(server.local_addr()).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        Self {
+            reader: BufReader::new(stream.try_clone().unwrap()),
+            writer: stream,
+        }
+    }
+
+    fn send(&mut self, line: &str) {
+        writeln!(self.writer, "{}", line).unwrap();
+    }
+
+    fn read_reply(&mut self) -> String {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line).unwrap() == 0 {
+                break;
+            }
+            let line = line.trim_end().to_string();
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+/// Boucle principale simulée : exécute les commandes reçues jusqu'à `count`.
+fn serve(server: &ConsoleServer, console: &mut Console, registry: &CommandRegistry, count: usize) {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut served = 0;
+    while served < count && Instant::now() < deadline {
+        for command in server.poll() {
+            let output = console.run_remote(
+                &command.line,
+                Instant::now(),
+                &mut audio,
+                &mut physic,
+                registry,
+            );
+            command.reply(output);
+            served += 1;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(served, count, "commands not received in time");
+}
+
+#[test]
+fn test_remote_help_and_registry_commands() {
+    let server = ConsoleServer::start(&local_config("")).unwrap();
+    assert!(server.local_addr().ip().is_loopback());
+    let mut registry = CommandRegistry::new();
+    registry.register_for_renderer("renderer.echo", |args| format!("echo <{}>", args));
+    let mut console = Console::new();
+
+    let mut client = Client::connect(&server);
+    client.send("help");
+    client.send("renderer.echo 1; renderer.echo 2");
+    serve(&server, &mut console, &registry, 2);
+
+    let help = client.read_reply();
+    assert!(help.contains("renderer.echo"), "{}", help);
+    assert!(help.contains("internal:"));
+    assert_eq!(
+        client.read_reply(),
+        "echo <renderer.echo 1>\necho <renderer.echo 2>"
+    );
+    // Commandes journalisées dans la console comme une saisie
+    assert!(console
+        .output()
+        .texts()
+        .contains(&"> renderer.echo 1; renderer.echo 2"));
+}
+
+#[test]
+fn test_remote_token_required() {
+    let server = ConsoleServer::start(&local_config("s3cret")).unwrap();
+    let registry = CommandRegistry::new();
+    let mut console = Console::new();
+
+    let mut intruder = Client::connect(&server);
+    intruder.send("help");
+    assert_eq!(intruder.read_reply(), "Error: invalid token");
+
+    let mut client = Client::connect(&server);
+    client.send("s3cret");
+    assert_eq!(client.read_reply(), "Authenticated");
+    client.send("wait");
+    serve(&server, &mut console, &registry, 1);
+    assert_eq!(client.read_reply(), "Usage: wait <seconds>");
+}
@@ -0,0 +1,135 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig,
+    physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
+};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_help::command_group;
+use fireworks_sim::renderer_engine::command_sim::{register_sim_commands, SimState};
+use glam::Vec2;
+use helpers::{DummyAudio, TestAudio, TestPhysic};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn sim_registry() -> CommandRegistry {
+    let mut registry = CommandRegistry::new();
+    register_sim_commands(&mut registry);
+    registry
+}
+
+// ==================================
+// 1. Routage des commandes sim.*
+// ==================================
+
+#[test]
+fn test_sim_commands_routed_with_both_engines() {
+    let mut registry = sim_registry();
+    registry.register_for_simulator("sim.both", |ctx, args| {
+        ctx.audio.mute();
+        ctx.physic.set_window_width(800.0);
+        format!("both: {}", args)
+    });
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log.clone());
+
+    // Une seule commande modifie les deux moteurs
+    let out = registry.execute(&mut audio, &mut physic, "sim.both 42");
+    assert_eq!(out, "both: sim.both 42");
+    assert_eq!(*log.borrow(), ["mute called", "physic.set_width"]);
+
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "sim.nope"),
+        "Unknown command 'sim.nope'."
+    );
+    assert!(registry.get_commands().contains(&"sim.reset".to_string()));
+    assert_eq!(command_group("sim.info"), "sim");
+}
+
+#[test]
+fn test_sim_reset_clears_physics_audio_and_camera() {
+    let mut registry = sim_registry();
+    let state = SimState::default();
+    registry.set_sim_state(state.clone());
+    state.camera.borrow_mut().target_zoom = 3.0;
+
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log.clone());
+    registry.execute(&mut audio, &mut physic, "sim.reset");
+    assert_eq!(
+        *log.borrow(),
+        ["physic.clear_particles", "stop_all_voices called"]
+    );
+    assert_eq!(state.camera.borrow().target_zoom, 1.0);
+
+    // Avec le vrai moteur : plus aucune fusée en vol
+    let mut engine = PhysicEngineFireworks::with_seed(&PhysicConfig::default(), 1920.0, 7);
+    assert!(engine.spawn_n_rockets(5) > 0);
+    let out = registry.execute(&mut DummyAudio, &mut engine, "sim.reset");
+    assert!(out.starts_with("Simulation reset"), "{}", out);
+    assert_eq!(engine.rockets_count(), 0);
+}
+
+// ==================================
+// 2. Pause et état
+// ==================================
+
+#[test]
+fn test_sim_pause_shares_the_renderer_flag() {
+    let mut registry = sim_registry();
+    let state = SimState::default();
+    registry.set_sim_state(state.clone());
+    let mut physic = TestPhysic::new(Rc::new(RefCell::new(vec![])));
+
+    assert_eq!(
+        registry.execute(&mut DummyAudio, &mut physic, "sim.pause"),
+        "Simulation paused"
+    );
+    assert!(state.paused.get());
+    assert_eq!(
+        registry.execute(&mut DummyAudio, &mut physic, "sim.pause off"),
+        "Simulation resumed"
+    );
+    assert!(!state.paused.get());
+    assert!(registry
+        .execute(&mut DummyAudio, &mut physic, "sim.pause maybe")
+        .starts_with("Usage"));
+
+    state.paused.set(true);
+    state.camera.borrow_mut().center = Vec2::new(100.0, 50.0);
+    let info = registry.execute(&mut DummyAudio, &mut physic, "sim.info");
+    assert!(info.contains("State: paused"), "{}", info);
+    assert!(info.contains("center (100, 50)"), "{}", info);
+}
+
+#[test]
+fn test_sim_speed_sets_the_shared_speed() {
+    let mut registry = sim_registry();
+    let state = SimState::default();
+    registry.set_sim_state(state.clone());
+    let mut physic = TestPhysic::new(Rc::new(RefCell::new(vec![])));
+
+    assert_eq!(
+        registry.execute(&mut DummyAudio, &mut physic, "sim.speed"),
+        "Simulation speed: x1"
+    );
+    assert_eq!(
+        registry.execute(&mut DummyAudio, &mut physic, "sim.speed 0.5"),
+        "Simulation speed: x0.5"
+    );
+    assert_eq!(state.sim_speed.get().value(), 0.5);
+
+    // Hors bornes : vitesse ramenée dans [0.1, 4]
+    let out = registry.execute(&mut DummyAudio, &mut physic, "sim.speed 10");
+    assert!(out.contains("clamped"), "{}", out);
+    assert_eq!(state.sim_speed.get().value(), 4.0);
+    assert!(registry
+        .execute(&mut DummyAudio, &mut physic, "sim.speed fast")
+        .starts_with("Usage"));
+    assert_eq!(state.sim_speed.get().value(), 4.0);
+
+    let info = registry.execute(&mut DummyAudio, &mut physic, "sim.info");
+    assert!(info.contains("Speed: x4"), "{}", info);
+}
@@ -0,0 +1,92 @@
+mod helpers;
+
+use fireworks_sim::renderer_engine::command_console::{autocomplete_suggestions, CommandRegistry};
+use fireworks_sim::renderer_engine::command_stats::{
+    ranked_score, CommandStats, DEFAULT_USAGE_WEIGHT,
+};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Classement combiné
+// ==================================
+
+#[test]
+fn test_ranked_score_frequency_breaks_ties_only() {
+    let w = DEFAULT_USAGE_WEIGHT;
+    // Jamais utilisée : score flou inchangé
+    assert_eq!(ranked_score(50, 0, w), 50);
+    // Même score flou : la plus utilisée passe devant
+    assert!(ranked_score(50, 20, w) > ranked_score(50, 2, w));
+    // Une correspondance nettement meilleure l'emporte sur un usage intensif
+    assert!(ranked_score(150, 0, w) > ranked_score(50, 1000, w));
+    // Poids nul : score flou seul
+    assert_eq!(ranked_score(50, 1000, 0.0), 50);
+}
+
+#[test]
+fn test_autocomplete_prefers_frequent_commands() {
+    let mut registry = CommandRegistry::new();
+    registry.register_for_renderer("renderer.bloom", |_| String::new());
+    registry.register_for_renderer("renderer.bloom.intensity", |_| String::new());
+    let matcher = SkimMatcherV2::default();
+
+    let first = |registry: &CommandRegistry| {
+        autocomplete_suggestions(registry, &matcher, "bloom")
+            .into_iter()
+            .next()
+    };
+    // Nom le plus court en tête tant qu'aucune commande n'a servi
+    assert_eq!(first(&registry).as_deref(), Some("renderer.bloom"));
+
+    for _ in 0..30 {
+        registry.record_command("renderer.bloom.intensity 1.5");
+    }
+    assert_eq!(
+        first(&registry).as_deref(),
+        Some("renderer.bloom.intensity")
+    );
+
+    registry.set_usage_weight(0.0);
+    assert_eq!(first(&registry).as_deref(), Some("renderer.bloom"));
+}
+
+// ==================================
+// 2. Compteurs et persistance
+// ==================================
+
+#[test]
+fn test_command_stats_persisted_and_listed() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("command_stats.toml");
+
+    let mut registry = CommandRegistry::new();
+    registry.register_for_audio("audio.mute", |_, _| "muted".into());
+    registry.set_stats_file(&path);
+    let (mut audio, mut physic) = (DummyAudio, DummyPhysic::default());
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "stats.commands"),
+        "No command used yet"
+    );
+
+    registry.record_command("audio.mute");
+    registry.record_command("audio.mute");
+    registry.record_command("help audio");
+    // Nom inconnu : non compté
+    registry.record_command("audio.nope");
+
+    let stats = CommandStats::load(&path).unwrap();
+    assert_eq!(stats.count("audio.mute"), 2);
+    assert_eq!(stats.count("help"), 1);
+    assert_eq!(stats.count("audio.nope"), 0);
+
+    // Rechargés par un nouveau registre
+    let mut reloaded = CommandRegistry::new();
+    reloaded.set_stats_file(&path);
+    assert_eq!(reloaded.command_stats().count("audio.mute"), 2);
+    let listing = reloaded.execute(&mut audio, &mut physic, "stats.commands 1");
+    assert_eq!(listing, "Most used commands:\n  audio.mute  2");
+    assert!(reloaded
+        .execute(&mut audio, &mut physic, "stats.commands x")
+        .starts_with("Usage"));
+}
@@ -1,8 +1,18 @@
+use fireworks_sim::renderer_engine::command_alias::AliasTable;
+use fireworks_sim::renderer_engine::command_bind::{parse_key, CommandBinds};
 use fireworks_sim::renderer_engine::command_console::{
-    CommandRegistry, HistoryCursor, SelectionCycler,
+    autocomplete_suggestions, split_commands, CommandRegistry, CommandScheduler, Console,
+    HistoryCursor, SelectionCycler,
 };
+use fireworks_sim::renderer_engine::command_script::{
+    command_failed, script_lines, ExecArgs, MAX_EXEC_DEPTH,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::window_event::KeyCode;
+use fuzzy_matcher::skim::SkimMatcherV2;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 mod helpers;
 use helpers::{TestAudio, TestPhysic};
@@ -77,3 +87,492 @@ fn test_command_registry_execution() {
     let res4 = registry.execute(&mut audio, &mut physic, "audio.unknown");
     assert!(res4.contains("Unknown command"));
 }
+
+/// Registre avec une commande renderer qui renvoie ses arguments
+fn echo_registry() -> CommandRegistry {
+    let mut registry = CommandRegistry::new();
+    registry.register_for_renderer("renderer.bloom.intensity", |args| {
+        format!("intensity <{}>", args)
+    });
+    registry
+}
+
+#[test]
+fn test_alias_expansion_appends_arguments() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+
+    let out = registry.execute(&mut audio, &mut physic, "alias bi renderer.bloom.intensity");
+    assert_eq!(out, "Alias 'bi' = renderer.bloom.intensity");
+
+    // Les arguments saisis après l'alias sont ajoutés à la commande cible
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "bi 3.5"),
+        "intensity <renderer.bloom.intensity 3.5>"
+    );
+    // Alias d'alias
+    registry.define_alias("glow", "bi 2").unwrap();
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "glow"),
+        "intensity <renderer.bloom.intensity 2>"
+    );
+
+    // Liste, affichage, suppression
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "alias"),
+        "Aliases:\n  bi = renderer.bloom.intensity\n  glow = bi 2"
+    );
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "alias bi"),
+        "bi = renderer.bloom.intensity"
+    );
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "unalias glow"),
+        "Alias 'glow' removed"
+    );
+    assert!(registry
+        .execute(&mut audio, &mut physic, "glow")
+        .contains("Unknown command"));
+    assert!(registry
+        .execute(&mut audio, &mut physic, "unalias glow")
+        .contains("Unknown alias"));
+}
+
+#[test]
+fn test_alias_recursion_rejected() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+
+    // Récursion directe
+    let out = registry.execute(&mut audio, &mut physic, "alias loop loop 1");
+    assert_eq!(out, "Error: Recursive alias: loop -> loop");
+
+    // Récursion indirecte : refusée, l'alias existant reste inchangé
+    registry.define_alias("a", "b").unwrap();
+    let err = registry.define_alias("b", "a 1").unwrap_err();
+    assert_eq!(err.to_string(), "Recursive alias: b -> a -> b");
+    assert_eq!(registry.aliases().get("b"), None);
+
+    // Noms réservés ou invalides
+    assert!(registry
+        .define_alias("help", "renderer.bloom.intensity")
+        .is_err());
+    assert!(registry
+        .define_alias("renderer.x", "renderer.bloom.intensity")
+        .is_err());
+    assert!(registry.define_alias("x", "  ").is_err());
+}
+
+#[test]
+fn test_alias_persistence_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config/aliases.toml");
+
+    let mut registry = echo_registry();
+    registry.set_alias_file(&path);
+    assert!(registry.aliases().is_empty());
+    registry
+        .define_alias("bi", "renderer.bloom.intensity")
+        .unwrap();
+    registry.define_alias("glow", "bi 2").unwrap();
+    registry.define_alias("tmp", "bi 0").unwrap();
+    assert!(registry.remove_alias("tmp"));
+
+    // Sauvegarde à chaque changement, relue par un nouveau registre
+    let mut reloaded = echo_registry();
+    reloaded.set_alias_file(&path);
+    assert_eq!(*reloaded.aliases(), *registry.aliases());
+    assert_eq!(reloaded.aliases().get("glow"), Some("bi 2"));
+    assert_eq!(reloaded.aliases().get("tmp"), None);
+
+    // Fichier édité à la main avec un cycle : refusé
+    let err = AliasTable::parse("[aliases]\na = \"b\"\nb = \"a\"\n").unwrap_err();
+    assert!(err.to_string().contains("Recursive alias"));
+}
+
+fn write_script(dir: &tempfile::TempDir, name: &str, text: &str) -> String {
+    let path = dir.path().join(name);
+    std::fs::write(&path, text).unwrap();
+    path.display().to_string()
+}
+
+#[test]
+fn test_script_lines_and_exec_args() {
+    let lines: Vec<(usize, &str)> =
+        script_lines("# réglages\n\n  renderer.bloom.intensity 2  \n#x\nalias\n").collect();
+    assert_eq!(lines, vec![(3, "renderer.bloom.intensity 2"), (5, "alias")]);
+
+    assert_eq!(
+        ExecArgs::parse(" -e scripts/demo.cfg"),
+        Some(ExecArgs {
+            path: "scripts/demo.cfg".into(),
+            abort_on_error: true
+        })
+    );
+    assert!(!ExecArgs::parse("demo.cfg").unwrap().abort_on_error);
+    assert_eq!(ExecArgs::parse("-e"), None);
+    assert!(command_failed("Unknown command 'x'."));
+    assert!(command_failed("Usage: renderer.hud [on|off]"));
+    assert!(!command_failed("Bloom intensity: 2.0"));
+}
+
+#[test]
+fn test_exec_script_runs_each_line() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+    let dir = tempfile::tempdir().unwrap();
+    let script = write_script(
+        &dir,
+        "setup.cfg",
+        "# Réglages préférés\nalias bi renderer.bloom.intensity\n\nbi 3.5\nrenderer.missing\nunalias bi\n",
+    );
+
+    let out = registry.execute(&mut audio, &mut physic, &format!("exec {}", script));
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "> alias bi renderer.bloom.intensity",
+            "Alias 'bi' = renderer.bloom.intensity",
+            "> bi 3.5",
+            "intensity <renderer.bloom.intensity 3.5>",
+            "> renderer.missing",
+            "Unknown command 'renderer.missing'.",
+            "> unalias bi",
+            "Alias 'bi' removed",
+            &format!("exec {}: 3 succeeded, 1 failed", script),
+        ]
+    );
+}
+
+#[test]
+fn test_exec_abort_on_error() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+    let dir = tempfile::tempdir().unwrap();
+    let script = write_script(
+        &dir,
+        "abort.cfg",
+        "renderer.bloom.intensity 1\nrenderer.missing\nrenderer.bloom.intensity 2\n",
+    );
+
+    let report = registry
+        .exec_file(
+            &mut audio,
+            &mut physic,
+            &ExecArgs::parse(&format!("-e {}", script)).unwrap(),
+        )
+        .unwrap();
+    assert!(report.aborted);
+    assert_eq!((report.succeeded, report.failed), (1, 1));
+    assert_eq!(
+        report.output.last().unwrap(),
+        &format!("Aborted at {}:2", script)
+    );
+    assert!(report.summary().ends_with("1 succeeded, 1 failed, aborted"));
+
+    // Fichier absent : erreur, sans rien exécuter
+    let out = registry.execute(&mut audio, &mut physic, "exec does/not/exist.cfg");
+    assert!(out.starts_with("Error: Cannot read script"));
+}
+
+#[test]
+fn test_nested_exec_depth_limit() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+    let dir = tempfile::tempdir().unwrap();
+
+    // Imbrication simple : le script parent compte l'enfant comme une commande
+    let child = write_script(&dir, "child.cfg", "renderer.bloom.intensity 1\n");
+    let parent = write_script(&dir, "parent.cfg", &format!("exec {}\n", child));
+    let report = registry
+        .exec_file(&mut audio, &mut physic, &ExecArgs::parse(&parent).unwrap())
+        .unwrap();
+    assert_eq!((report.succeeded, report.failed), (1, 0));
+
+    // Script qui s'appelle lui-même : arrêté à MAX_EXEC_DEPTH, l'échec remonte
+    let path = dir.path().join("self.cfg");
+    std::fs::write(&path, format!("exec {}\n", path.display())).unwrap();
+    let out = registry.execute(&mut audio, &mut physic, &format!("exec {}", path.display()));
+    assert!(out.contains(&format!(
+        "Error: exec nested too deeply (max {})",
+        MAX_EXEC_DEPTH
+    )));
+    assert_eq!(out.matches("0 succeeded, 1 failed").count(), MAX_EXEC_DEPTH);
+
+    // La profondeur est libérée : un nouvel exec fonctionne
+    let report = registry
+        .exec_file(&mut audio, &mut physic, &ExecArgs::parse(&child).unwrap())
+        .unwrap();
+    assert_eq!(report.succeeded, 1);
+}
+
+#[test]
+fn test_autocomplete_tonemapping_arguments() {
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &RendererShared::default());
+    let matcher = SkimMatcherV2::default();
+    let suggest = |input: &str| autocomplete_suggestions(&registry, &matcher, input);
+
+    // Nom de commande : espace final si la commande attend des arguments
+    let names = suggest("renderer.tonemapping");
+    assert!(names.contains(&"renderer.tonemapping ".to_string()));
+    assert!(names.contains(&"renderer.tonemapping.compare ".to_string()));
+    assert!(suggest("renderer.stats").contains(&"renderer.stats".to_string()));
+
+    // Après l'espace : toutes les valeurs, puis filtrage sur le mot en cours
+    assert_eq!(
+        suggest("renderer.tonemapping "),
+        vec![
+            "renderer.tonemapping linear",
+            "renderer.tonemapping reinhard",
+            "renderer.tonemapping aces",
+            "renderer.tonemapping agx",
+        ]
+    );
+    assert_eq!(
+        suggest("renderer.tonemapping ac")[0],
+        "renderer.tonemapping aces"
+    );
+    assert_eq!(suggest("renderer.preset ul"), vec!["renderer.preset ultra"]);
+
+    // Commande sans valeurs déclarées, ou position au-delà des arguments connus
+    assert!(suggest("renderer.bloom.intensity 2").is_empty());
+    assert!(suggest("renderer.tonemapping aces ").is_empty());
+}
+
+#[test]
+fn test_autocomplete_second_argument_position() {
+    let mut registry = CommandRegistry::new();
+    registry.register_for_renderer("renderer.test.pair", |args| args.to_string());
+    registry.register_args(
+        "renderer.test.pair",
+        &[&["rocket", "smoke"], &["low", "high"]],
+    );
+    let matcher = SkimMatcherV2::default();
+    let suggest = |input: &str| autocomplete_suggestions(&registry, &matcher, input);
+
+    // Première position : espace final, une seconde valeur est attendue
+    assert_eq!(
+        suggest("renderer.test.pair sm"),
+        vec!["renderer.test.pair smoke "]
+    );
+    // Seconde position : le premier argument est conservé
+    assert_eq!(
+        suggest("renderer.test.pair smoke "),
+        vec![
+            "renderer.test.pair smoke low",
+            "renderer.test.pair smoke high"
+        ]
+    );
+    assert_eq!(
+        suggest("renderer.test.pair  rocket  hi"),
+        vec!["renderer.test.pair rocket high"]
+    );
+    assert_eq!(registry.get_arg_suggestions("renderer.test.pair").len(), 2);
+    assert!(registry.get_arg_suggestions("renderer.unknown").is_empty());
+}
+
+#[test]
+fn test_split_commands() {
+    assert_eq!(
+        split_commands("physic.launch 300 ; wait 2;renderer.bloom off"),
+        vec!["physic.launch 300", "wait 2", "renderer.bloom off"]
+    );
+    // Segments vides ignorés
+    assert_eq!(split_commands(" ; help;; "), vec!["help"]);
+    assert!(split_commands("").is_empty());
+}
+
+#[test]
+fn test_wait_schedules_in_due_order() {
+    let now = Instant::now();
+    let mut scheduler = CommandScheduler::default();
+
+    let immediate = scheduler.schedule("a; wait 2; b; wait 1; c", now);
+    assert_eq!(immediate, vec!["a"]);
+    // Une seconde chaîne s'intercale selon l'échéance
+    assert!(scheduler.schedule("wait 2.5; d", now).is_empty());
+    assert_eq!(scheduler.pending(), 3);
+
+    assert!(scheduler.due(now + Duration::from_millis(1999)).is_empty());
+    assert_eq!(scheduler.due(now + Duration::from_secs(2)), vec!["b"]);
+    assert_eq!(scheduler.due(now + Duration::from_secs(10)), vec!["d", "c"]);
+    assert_eq!(scheduler.pending(), 0);
+
+    // `wait` invalide : laissé à la commande (message d'usage)
+    assert_eq!(
+        scheduler.schedule("wait soon; a", now),
+        vec!["wait soon", "a"]
+    );
+    assert_eq!(scheduler.pending(), 0);
+}
+
+#[test]
+fn test_console_chain_with_wait_and_internal_commands() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = echo_registry();
+    let mut console = Console::new();
+    let now = Instant::now();
+
+    console.submit(
+        "renderer.bloom.intensity 1; wait 1; renderer.bloom.intensity 2",
+        now,
+        &mut audio,
+        &mut physic,
+        &registry,
+    );
+    assert_eq!(console.pending_commands(), 1);
+    assert!(console
+        .output()
+        .texts()
+        .contains(&"intensity <renderer.bloom.intensity 1>"));
+    assert!(!console
+        .output()
+        .texts()
+        .contains(&"intensity <renderer.bloom.intensity 2>"));
+
+    // La commande différée s'exécute et s'affiche à son échéance
+    console.tick(
+        now + Duration::from_secs(1),
+        &mut audio,
+        &mut physic,
+        &registry,
+    );
+    assert_eq!(console.pending_commands(), 0);
+    let output = console.output().texts();
+    assert_eq!(output[output.len() - 2], "> renderer.bloom.intensity 2");
+    assert_eq!(
+        output[output.len() - 1],
+        "intensity <renderer.bloom.intensity 2>"
+    );
+
+    // `help` et `clear` fonctionnent au sein d'une chaîne
+    console.submit("help; clear", now, &mut audio, &mut physic, &registry);
+    assert!(console.output().is_empty());
+    console.submit("clear; help", now, &mut audio, &mut physic, &registry);
+    assert!(console
+        .output()
+        .texts()
+        .iter()
+        .any(|l| l.contains("renderer.bloom.intensity")));
+
+    console.submit("wait", now, &mut audio, &mut physic, &registry);
+    assert_eq!(
+        console.output().texts().last().unwrap(),
+        &"Usage: wait <seconds>"
+    );
+}
+
+#[test]
+fn test_bind_key_name_parsing() {
+    assert_eq!(parse_key("F5").unwrap(), KeyCode::F5);
+    assert_eq!(parse_key("grave").unwrap(), KeyCode::GraveAccent);
+    assert_eq!(parse_key(" Space ").unwrap(), KeyCode::Space);
+    let err = parse_key("f13").unwrap_err().to_string();
+    assert!(err.contains("Unknown key 'f13'"), "{}", err);
+}
+
+#[test]
+fn test_bind_storage_and_lookup() {
+    let mut binds = CommandBinds::default();
+    // Guillemets optionnels, nom de touche normalisé
+    assert_eq!(
+        binds.bind("F5", "\"renderer.preset high\"").unwrap(),
+        KeyCode::F5
+    );
+    binds
+        .bind("g", "physic.launch 10; wait 1; physic.launch 10")
+        .unwrap();
+    assert_eq!(binds.command_for(KeyCode::F5), Some("renderer.preset high"));
+    assert_eq!(binds.command_for(KeyCode::H), None);
+    assert!(binds.bind("f5", "  ").is_err());
+    assert!(binds.bind("nope", "help").is_err());
+
+    // Persistance
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("binds.toml");
+    binds.save(&path).unwrap();
+    assert_eq!(CommandBinds::load(&path).unwrap(), binds);
+
+    assert_eq!(
+        binds.unbind("F5").unwrap().as_deref(),
+        Some("renderer.preset high")
+    );
+    assert_eq!(binds.unbind("f5").unwrap(), None);
+}
+
+#[test]
+fn test_bind_console_commands() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("binds.toml");
+
+    let mut registry = echo_registry();
+    registry.set_bind_file(&path);
+    assert_eq!(
+        registry.execute(
+            &mut audio,
+            &mut physic,
+            "bind F5 \"renderer.bloom.intensity 2\""
+        ),
+        "Bind 'f5' = renderer.bloom.intensity 2"
+    );
+    assert_eq!(
+        registry.bound_command(KeyCode::F5).as_deref(),
+        Some("renderer.bloom.intensity 2")
+    );
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "bind f5"),
+        "f5 = renderer.bloom.intensity 2"
+    );
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "binds"),
+        "Key binds:\n  f5       renderer.bloom.intensity 2"
+    );
+    assert!(registry
+        .execute(&mut audio, &mut physic, "bind f99 help")
+        .starts_with("Error: Unknown key 'f99'"));
+
+    // Rechargé depuis le fichier au démarrage suivant
+    let mut reloaded = echo_registry();
+    reloaded.set_bind_file(&path);
+    assert!(reloaded.bound_command(KeyCode::F5).is_some());
+
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "unbind F5"),
+        "Key 'f5' unbound"
+    );
+    assert_eq!(registry.bound_command(KeyCode::F5), None);
+
+    // Exécution via la console : journalisée, hors historique
+    registry.execute(
+        &mut audio,
+        &mut physic,
+        "bind f6 renderer.bloom.intensity 3",
+    );
+    let mut console = Console::new();
+    let command = registry.bound_command(KeyCode::F6).unwrap();
+    console.run_bound(&command, Instant::now(), &mut audio, &mut physic, &registry);
+    assert_eq!(
+        console.output().texts(),
+        [
+            "> renderer.bloom.intensity 3",
+            "intensity <renderer.bloom.intensity 3>"
+        ]
+    );
+}
@@ -0,0 +1,281 @@
+use fireworks_sim::renderer_engine::command_console::{CommandRegistry, Console};
+use fireworks_sim::renderer_engine::command_transcript::{
+    echoed_command, format_line, replay_commands, replay_transcript, skip_reason, TranscriptArgs,
+};
+use fireworks_sim::renderer_engine::console_output::{OutputLine, Severity};
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+mod helpers;
+use helpers::{TestAudio, TestPhysic};
+
+fn mock_registry() -> CommandRegistry {
+    let mut registry = CommandRegistry::new();
+    registry.register_for_audio("audio.mute", |engine, _args| {
+        engine.mute();
+        "Muted".to_string()
+    });
+    registry.register_for_physic("physic.width", |engine, args| {
+        engine.set_window_width(100.0);
+        format!("width <{}>", args)
+    });
+    registry
+}
+
+fn line(seconds: u64, severity: Severity, text: &str) -> OutputLine {
+    OutputLine {
+        timestamp: Duration::from_secs(seconds),
+        severity,
+        text: text.to_string(),
+    }
+}
+
+// ==================================
+// Group 1: Save format
+// ==================================
+
+#[test]
+fn test_transcript_line_format() {
+    assert_eq!(
+        format_line(&line(75, Severity::Echo, "> audio.mute")),
+        "[01:15] ECHO  > audio.mute"
+    );
+    assert_eq!(
+        format_line(&line(3, Severity::Error, "Unknown command 'x'.")),
+        "[00:03] ERROR Unknown command 'x'."
+    );
+    // Lignes suivantes indentées sous le texte
+    assert_eq!(
+        format_line(&line(0, Severity::Info, "first\nsecond")),
+        "[00:00] INFO  first\n              second"
+    );
+}
+
+#[test]
+fn test_transcript_args() {
+    assert_eq!(
+        TranscriptArgs::parse("save out/session.txt"),
+        Some(TranscriptArgs::Save(PathBuf::from("out/session.txt")))
+    );
+    assert_eq!(
+        TranscriptArgs::parse("replay --force session.txt"),
+        Some(TranscriptArgs::Replay {
+            path: PathBuf::from("session.txt"),
+            force: true
+        })
+    );
+    assert_eq!(
+        TranscriptArgs::parse("replay --forced.txt"),
+        Some(TranscriptArgs::Replay {
+            path: PathBuf::from("--forced.txt"),
+            force: false
+        })
+    );
+    assert_eq!(TranscriptArgs::parse("replay --force"), None);
+    assert_eq!(TranscriptArgs::parse("save"), None);
+    assert_eq!(TranscriptArgs::parse("load x.txt"), None);
+}
+
+#[test]
+fn test_console_saves_its_output() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = mock_registry();
+    let mut console = Console::new();
+    let now = Instant::now();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("logs/session.txt");
+
+    console.submit("audio.mute", now, &mut audio, &mut physic, &registry);
+    console.submit("nope", now, &mut audio, &mut physic, &registry);
+    let save = format!("transcript save {}", path.display());
+    console.submit(&save, now, &mut audio, &mut physic, &registry);
+
+    assert_eq!(
+        console.output().texts().last().unwrap(),
+        &format!("Transcript saved to {} (5 lines)", path.display())
+    );
+    let text = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = text.lines().map(|l| &l["[00:00] ".len()..]).collect();
+    assert_eq!(
+        lines,
+        [
+            "ECHO  > audio.mute",
+            "INFO  Muted",
+            "ECHO  > nope",
+            "ERROR Unknown command 'nope'. Missing engine prefix.",
+            &format!("ECHO  > {}", save),
+        ]
+    );
+}
+
+// ==================================
+// Group 2: Echo-line extraction
+// ==================================
+
+#[test]
+fn test_echo_line_extraction() {
+    assert_eq!(
+        echoed_command("[00:01] ECHO  > audio.mute"),
+        Some("audio.mute")
+    );
+    // Transcription écrite à la main : préfixe seul
+    assert_eq!(echoed_command("> physic.width 3"), Some("physic.width 3"));
+    // Sorties ignorées, même si elles reprennent l'écho d'un script
+    assert_eq!(echoed_command("[00:01] INFO  > audio.mute"), None);
+    assert_eq!(echoed_command("              > audio.mute"), None);
+    assert_eq!(echoed_command("[00:01] INFO  Muted"), None);
+    assert_eq!(echoed_command("[00:01] ECHO  > "), None);
+    assert_eq!(echoed_command("Muted"), None);
+}
+
+#[test]
+fn test_replay_keeps_only_immediate_commands() {
+    let text = "\
+[00:00] ECHO  > audio.mute; wait 1; physic.width 2
+[00:00] INFO  Muted
+[00:01] ECHO  > physic.width 2
+[00:01] INFO  width <physic.width 2>
+";
+    // La commande différée n'est rejouée qu'une fois, depuis son propre écho
+    assert_eq!(replay_commands(text), ["audio.mute", "physic.width 2"]);
+}
+
+#[test]
+fn test_destructive_commands_are_guarded() {
+    assert!(skip_reason("clear", false).unwrap().contains("--force"));
+    assert_eq!(skip_reason("clear", true), None);
+    assert_eq!(skip_reason("audio.mute", false), None);
+    // Un rejeu imbriqué n'est jamais relancé
+    assert!(skip_reason("transcript replay a.txt", true).is_some());
+    assert!(skip_reason("transcript save a.txt", false).is_some());
+}
+
+// ==================================
+// Group 3: Replay against mock engines
+// ==================================
+
+#[test]
+fn test_replay_runs_echoed_commands_through_the_registry() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log.clone());
+    let registry = mock_registry();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.txt");
+    std::fs::write(
+        &path,
+        "\
+[00:00] ECHO  > audio.mute
+[00:00] INFO  Muted
+[00:02] ECHO  > clear
+[00:03] ECHO  > physic.width 7
+[00:03] INFO  width <physic.width 7>
+[00:04] ECHO  > audio.nope
+[00:04] ERROR Unknown command 'audio.nope'.
+",
+    )
+    .unwrap();
+
+    let report = replay_transcript(&path, false, |command| {
+        let output = registry.execute(&mut audio, &mut physic, command);
+        let failed = output.starts_with("Unknown");
+        (output, failed)
+    })
+    .unwrap();
+    assert_eq!((report.succeeded, report.failed, report.skipped), (2, 1, 1));
+    assert_eq!(
+        report.summary(),
+        format!(
+            "transcript replay {}: 2 succeeded, 1 failed, 1 skipped",
+            path.display()
+        )
+    );
+    assert!(log.borrow().contains(&"mute called".into()));
+    assert!(log.borrow().contains(&"physic.set_width".into()));
+
+    assert!(
+        replay_transcript(&dir.path().join("missing.txt"), false, |_| {
+            (String::new(), false)
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn test_console_round_trip_replays_once() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log.clone());
+    let registry = mock_registry();
+    let now = Instant::now();
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("first.txt");
+    let second = dir.path().join("second.txt");
+
+    let mut console = Console::new();
+    console.submit("audio.mute", now, &mut audio, &mut physic, &registry);
+    console.submit("physic.width 4", now, &mut audio, &mut physic, &registry);
+    let save = format!("transcript save {}", first.display());
+    console.submit(&save, now, &mut audio, &mut physic, &registry);
+    log.borrow_mut().clear();
+
+    // Rejeu dans une console neuve, sans relancer la sauvegarde
+    let mut replayed = Console::new();
+    let replay = format!("transcript replay {}", first.display());
+    replayed.submit(&replay, now, &mut audio, &mut physic, &registry);
+    let report = replayed.output().texts().last().unwrap().to_string();
+    assert!(report.contains("Skipped 'transcript save"), "{}", report);
+    assert!(report.ends_with("2 succeeded, 0 failed, 1 skipped"));
+    assert_eq!(*log.borrow(), ["mute called", "physic.set_width"]);
+    assert_eq!(
+        replayed.output().lines().last().unwrap().severity,
+        Severity::Info
+    );
+
+    // Le rapport d'un rejeu n'est pas un écho : resauvé, il ne rejoue rien deux fois
+    let save = format!("transcript save {}", second.display());
+    replayed.submit(&save, now, &mut audio, &mut physic, &registry);
+    let text = std::fs::read_to_string(&second).unwrap();
+    assert_eq!(replay_commands(&text), [replay.as_str(), save.as_str()]);
+}
+
+#[test]
+fn test_console_replays_clear_only_when_forced() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut audio = TestAudio::new(log.clone());
+    let mut physic = TestPhysic::new(log);
+    let registry = mock_registry();
+    let now = Instant::now();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.txt");
+    std::fs::write(&path, "> physic.width 1\n> clear\n").unwrap();
+
+    let mut console = Console::new();
+    let replay = format!("transcript replay {}", path.display());
+    console.submit(&replay, now, &mut audio, &mut physic, &registry);
+    assert_eq!(console.output().len(), 2);
+    assert!(console
+        .output()
+        .texts()
+        .last()
+        .unwrap()
+        .ends_with("1 succeeded, 0 failed, 1 skipped"));
+
+    // --force : `clear` vide la sortie, seul le rapport reste
+    let replay = format!("transcript replay --force {}", path.display());
+    console.submit(&replay, now, &mut audio, &mut physic, &registry);
+    assert_eq!(console.output().len(), 1);
+    assert!(console.output().texts()[0].ends_with("2 succeeded, 0 failed, 0 skipped"));
+
+    console.submit("transcript", now, &mut audio, &mut physic, &registry);
+    assert!(console
+        .output()
+        .texts()
+        .last()
+        .unwrap()
+        .starts_with("Usage: transcript"));
+}
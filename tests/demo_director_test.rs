@@ -0,0 +1,193 @@
+mod helpers;
+
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::demo_director::{DemoConfig, DemoDirector, DemoEvent};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::PhysicEngine;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_sim::{register_sim_commands, SimState};
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use helpers::DummyAudio;
+
+/// Horloge factice : pas fixe de 1/60 s
+const DT: f32 = 1.0 / 60.0;
+
+fn seeded_config() -> DemoConfig {
+    DemoConfig {
+        change_interval: [2.0, 5.0],
+        seed: Some(7),
+        ..DemoConfig::default()
+    }
+}
+
+fn setup(config: DemoConfig) -> (DemoDirector, PhysicEngineFireworks, RendererConfig) {
+    let renderer = RendererConfig {
+        demo: config.clone(),
+        bloom_intensity: 0.9,
+        ..RendererConfig::default()
+    };
+    let physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 1024.0);
+    (DemoDirector::new(config), physic, renderer)
+}
+
+/// Avance le directeur de `seconds` secondes simulées, événements cumulés
+fn run(
+    demo: &mut DemoDirector,
+    physic: &mut PhysicEngineFireworks,
+    renderer: &mut RendererConfig,
+    seconds: f32,
+) -> Vec<DemoEvent> {
+    let mut events = vec![];
+    for _ in 0..(seconds / DT).round() as usize {
+        events.extend(demo.tick(DT, physic, renderer));
+    }
+    events
+}
+
+// ==================================
+// 1. Changements dans les bornes
+// ==================================
+
+#[test]
+fn test_demo_changes_stay_within_bounds() {
+    let config = DemoConfig {
+        finale_chance: 0.0,
+        ..seeded_config()
+    };
+    let (mut demo, mut physic, mut renderer) = setup(config.clone());
+    let base = physic.get_config().clone();
+
+    // Désactivé : aucun effet
+    assert!(run(&mut demo, &mut physic, &mut renderer, 10.0).is_empty());
+
+    assert_eq!(
+        demo.set_enabled(true, &mut physic, &mut renderer),
+        Some(DemoEvent::Started)
+    );
+    assert_eq!(demo.set_enabled(true, &mut physic, &mut renderer), None);
+
+    let events = run(&mut demo, &mut physic, &mut renderer, 120.0);
+    let scenes: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            DemoEvent::Changed(scene) => Some(scene.clone()),
+            _ => None,
+        })
+        .collect();
+    // Un changement immédiat, puis toutes les 2 à 5 s
+    assert!(scenes.len() >= 120 / 5, "{} changes", scenes.len());
+    assert!(scenes.len() <= 120 / 2 + 1, "{} changes", scenes.len());
+
+    for scene in &scenes {
+        assert!((0.5..=2.0).contains(&scene.launch_density), "{:?}", scene);
+        assert!((0.4..=1.4).contains(&scene.bloom_intensity), "{:?}", scene);
+        assert!(config.palettes.iter().any(|p| p.name == scene.palette));
+        assert!(config.shape_weights.contains_key(&scene.shape));
+    }
+    // La dernière scène est bien appliquée
+    let last = scenes.last().unwrap();
+    assert_eq!(renderer.bloom_intensity, last.bloom_intensity);
+    let mean = physic.get_config().rocket_interval_mean;
+    assert!((mean - base.rocket_interval_mean / last.launch_density).abs() < 1e-4);
+    assert!(!events.contains(&DemoEvent::FinaleStarted));
+}
+
+#[test]
+fn test_demo_stop_restores_physic_and_bloom() {
+    let (mut demo, mut physic, mut renderer) = setup(seeded_config());
+    let base = physic.get_config().clone();
+
+    demo.set_enabled(true, &mut physic, &mut renderer);
+    run(&mut demo, &mut physic, &mut renderer, 30.0);
+    assert_eq!(
+        demo.set_enabled(false, &mut physic, &mut renderer),
+        Some(DemoEvent::Stopped)
+    );
+
+    assert!(!demo.is_enabled());
+    assert!(!demo.in_finale());
+    assert_eq!(renderer.bloom_intensity, 0.9);
+    assert_eq!(
+        physic.get_config().rocket_interval_mean,
+        base.rocket_interval_mean
+    );
+    assert!(physic.get_config().rocket_palette.is_empty());
+}
+
+// ==================================
+// 2. Finales
+// ==================================
+
+#[test]
+fn test_demo_finale_terminates() {
+    let config = DemoConfig {
+        finale_chance: 1.0,
+        finale_duration: 3.0,
+        change_interval: [1.0, 1.0],
+        ..seeded_config()
+    };
+    let (mut demo, mut physic, mut renderer) = setup(config);
+    demo.set_enabled(true, &mut physic, &mut renderer);
+
+    let events = demo.tick(DT, &mut physic, &mut renderer);
+    assert!(matches!(events[0], DemoEvent::Changed(_)));
+    assert_eq!(events[1], DemoEvent::FinaleStarted);
+    assert!(demo.in_finale());
+    assert_eq!(physic.get_config().rocket_interval_mean, 0.002);
+
+    // Pas de changement de scène pendant le final
+    let during = run(&mut demo, &mut physic, &mut renderer, 2.9);
+    assert!(during.is_empty(), "{:?}", during);
+    assert!(demo.in_finale());
+
+    let after = run(&mut demo, &mut physic, &mut renderer, 0.2);
+    assert_eq!(after[0], DemoEvent::FinaleEnded);
+    // Le final enchaîne sur un changement (échu pendant le final)
+    assert!(matches!(after[1], DemoEvent::Changed(_)));
+    // finale_chance = 1 : un nouveau final suit chaque changement
+    assert_eq!(after[2], DemoEvent::FinaleStarted);
+}
+
+// ==================================
+// 3. Console et ligne de commande
+// ==================================
+
+#[test]
+fn test_sim_demo_command() {
+    let mut registry = CommandRegistry::new();
+    register_sim_commands(&mut registry);
+    let state = SimState::default();
+    registry.set_sim_state(state.clone());
+    let mut audio = DummyAudio;
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 1024.0);
+
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "sim.demo on"),
+        "Demo mode: on"
+    );
+    assert!(state.demo.borrow().is_enabled());
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "sim.demo"),
+        "Demo mode: off"
+    );
+    assert!(!state.demo.borrow().is_enabled());
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "sim.demo maybe"),
+        "Usage: sim.demo [on|off]"
+    );
+}
+
+#[test]
+fn test_demo_flag_and_config_section() {
+    let parse = |args: &[&str]| AppOptions::try_parse_from(args, |_| None).unwrap();
+    assert!(!parse(&["fireworks-sim"]).demo);
+    assert!(parse(&["fireworks-sim", "--demo"]).demo);
+    assert!(parse(&["fireworks-sim", "headless", "--demo"]).demo);
+
+    let config = RendererConfig::from_file("assets/config/renderer.toml").unwrap();
+    assert_eq!(config.demo.change_interval, [20.0, 60.0]);
+    assert_eq!(config.demo.shape_weights["sphere"], 3.0);
+    // Palettes absentes du fichier : celles par défaut
+    assert_eq!(config.demo.palettes, DemoConfig::default().palettes);
+}
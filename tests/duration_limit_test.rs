@@ -0,0 +1,140 @@
+mod helpers;
+
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::duration_limit::{DurationLimit, DURATION_LIMIT_TIMEOUT};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::PhysicEngine;
+use fireworks_sim::renderer_engine::headless::HEADLESS_DEFAULT_TIME_STEP;
+use fireworks_sim::renderer_engine::hud::format_remaining;
+use fireworks_sim::renderer_engine::NullRendererEngine;
+use fireworks_sim::Simulator;
+use helpers::{DummyAudio, TestAudio};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// ==================================
+// 1. DurationLimit
+// ==================================
+
+#[test]
+fn test_launches_stop_when_only_the_clear_time_remains() {
+    let mut physic = PhysicEngineFireworks::with_seed(&PhysicConfig::default(), 1024.0, 1);
+    let mut limit = DurationLimit::new(10.0, 4.0);
+
+    limit.tick(5.5, &mut physic);
+    assert!(!limit.launches_stopped());
+    assert!(physic.launches_enabled());
+    assert_eq!(limit.remaining(), 4.5);
+
+    limit.tick(0.5, &mut physic);
+    assert!(limit.launches_stopped());
+    assert!(!physic.launches_enabled());
+    // Lancement manuel refusé lui aussi
+    assert!(!physic.launch_rocket());
+    assert!(!limit.is_finished(0));
+
+    limit.tick(4.0, &mut physic);
+    assert_eq!(limit.remaining(), 0.0);
+    assert!(limit.is_finished(0));
+}
+
+#[test]
+fn test_active_rockets_delay_the_end_until_the_timeout() {
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 1024.0);
+    let mut limit = DurationLimit::new(2.0, 1.0);
+    limit.tick(2.0, &mut physic);
+    assert!(!limit.is_finished(3));
+    limit.tick(DURATION_LIMIT_TIMEOUT, &mut physic);
+    assert!(limit.is_finished(3));
+}
+
+#[test]
+fn test_sky_clear_time_covers_flight_and_particles() {
+    let config = PhysicConfig::default();
+    let clear_time = config.sky_clear_time();
+    // Fusée la plus rapide, puis étincelles (1.5 s au plus)
+    assert!(
+        clear_time > config.rocket_flight_time() + 1.5,
+        "{}",
+        clear_time
+    );
+
+    let floating = PhysicConfig {
+        gravity: 0.0,
+        ..PhysicConfig::default()
+    };
+    assert!(floating.sky_clear_time() >= 1.5);
+}
+
+// ==================================
+// 2. Fin de spectacle en headless
+// ==================================
+
+#[test]
+fn test_headless_run_ends_with_a_clear_sky() {
+    let duration = 8.0;
+    let dt = HEADLESS_DEFAULT_TIME_STEP;
+    let log = Rc::new(RefCell::new(vec![]));
+    let physic = PhysicEngineFireworks::with_seed(&PhysicConfig::default(), 1024.0, 42);
+    let mut simulator = Simulator::new(
+        NullRendererEngine::new(),
+        physic,
+        TestAudio::new(log.clone()),
+    );
+    simulator.set_duration_limit(Some(duration));
+
+    let report = simulator.run_headless(duration, dt, None).unwrap();
+
+    // Des fusées sont parties et ont explosé avant la fin, ciel vide à la fin
+    let count = |call: &str| log.borrow().iter().filter(|c| *c == call).count();
+    assert!(count("play_rocket called") > 0);
+    assert_eq!(count("play_rocket called"), count("play_explosion called"));
+    assert_eq!(simulator.physic_engine().get_stats().active_rockets, 0);
+    assert!(
+        (report.simulated - duration).abs() <= dt + 1e-4,
+        "{:?}",
+        report
+    );
+    let limit = simulator.duration_limit().unwrap();
+    assert!(limit.launches_stopped());
+    assert_eq!(limit.remaining(), 0.0);
+}
+
+#[test]
+fn test_headless_run_without_limit_keeps_fixed_steps() {
+    let physic = PhysicEngineFireworks::with_seed(&PhysicConfig::default(), 1024.0, 42);
+    let mut simulator = Simulator::new(NullRendererEngine::new(), physic, DummyAudio);
+    simulator.set_duration_limit(None);
+    let report = simulator.run_headless(1.0, 0.1, None).unwrap();
+    assert_eq!(report.steps, 10);
+    assert!(simulator.physic_engine().launches_enabled());
+}
+
+// ==================================
+// 3. Ligne de commande et HUD
+// ==================================
+
+#[test]
+fn test_duration_flag() {
+    let parse = |args: &[&str]| {
+        AppOptions::try_parse_from(
+            std::iter::once("fireworks-sim").chain(args.iter().copied()),
+            |_| None,
+        )
+        .unwrap()
+    };
+    assert_eq!(parse(&["--duration", "90"]).duration, Some(90.0));
+    assert_eq!(parse(&["run", "--duration", "1.5"]).duration, Some(1.5));
+    assert_eq!(parse(&[]).duration, None);
+    assert_eq!(parse(&["headless"]).duration, Some(60.0));
+    assert_eq!(parse(&["bench", "--frames", "10"]).duration, None);
+}
+
+#[test]
+fn test_remaining_time_format() {
+    assert_eq!(format_remaining(0.0), "0:00.0");
+    assert_eq!(format_remaining(65.34), "1:05.3");
+    assert_eq!(format_remaining(59.96), "1:00.0");
+    assert_eq!(format_remaining(-1.0), "0:00.0");
+}
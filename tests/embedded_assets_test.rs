@@ -0,0 +1,81 @@
+//! Démarrage sans répertoire `assets/` : `cargo test --features embedded_assets`
+#![cfg(feature = "embedded_assets")]
+
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::asset_manifest::AssetManifest;
+use fireworks_sim::audio_engine::AudioConfig;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::utils::embedded_assets::{embedded, embedded_paths, read_asset};
+use std::path::Path;
+use std::process::Command;
+
+// ==================================
+// 1. Copies embarquées
+// ==================================
+
+#[test]
+fn test_embedded_lookup_and_disk_first() {
+    assert!(embedded("./assets/config/physic.toml").is_some());
+    assert!(embedded("assets/shaders/post/common/tonemap.glsl").is_some());
+    assert!(embedded("assets/sounds/missing.wav").is_none());
+
+    // Copies identiques aux fichiers du dépôt, sauf les sons raccourcis
+    for path in embedded_paths().filter(|p| !p.starts_with("assets/sounds/")) {
+        let on_disk = std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap();
+        assert_eq!(embedded(path).unwrap(), on_disk.as_slice(), "{}", path);
+    }
+    // Le disque reste prioritaire
+    let rocket = read_asset("assets/sounds/rocket.wav").unwrap();
+    assert_ne!(&*rocket, embedded("assets/sounds/rocket.wav").unwrap());
+}
+
+#[test]
+fn test_manifest_never_missing_embedded_assets() {
+    let dir = tempfile::tempdir().unwrap();
+    let renderer = RendererConfig::default();
+    let audio = AudioConfig::default().engine_config(32);
+    let manifest = AssetManifest::from_configs(&AppOptions::default(), &audio, Some(&renderer));
+
+    let report = manifest.check_in(dir.path());
+    assert!(!report.is_fatal(), "{}", report);
+    assert!(report.missing.iter().all(|entry| !entry.embedded));
+}
+
+// ==================================
+// 2. Lancement depuis un répertoire vide
+// ==================================
+
+#[test]
+fn test_headless_run_from_empty_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let wav = dir.path().join("out.wav");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fireworks_sim"))
+        .current_dir(dir.path())
+        .env("RUST_LOG", "info")
+        .args(["--headless", "--duration", "4", "--seed", "7"])
+        .arg("--export-audio")
+        .arg(&wav)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    for asset in ["assets/config/physic.toml", "assets/sounds/explosion.wav"] {
+        assert!(
+            stderr.contains(&format!(
+                "Asset {} not found on disk, using the embedded copy",
+                asset
+            )),
+            "{}",
+            stderr
+        );
+    }
+    // Les sons embarqués ont bien été mixés
+    let samples: Vec<i16> = hound::WavReader::open(&wav)
+        .unwrap()
+        .samples::<i16>()
+        .map(Result::unwrap)
+        .collect();
+    assert!(samples.iter().any(|s| *s != 0));
+    assert!(!dir.path().join("assets").exists());
+}
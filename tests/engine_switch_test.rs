@@ -0,0 +1,134 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::any_engine::physic_update_label;
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::{AnyPhysicEngine, PhysicEngine, PHYSIC_ENGINES};
+use fireworks_sim::renderer_engine::command_script::ExecArgs;
+use fireworks_sim::renderer_engine::{NullRendererEngine, RendererEngine};
+use fireworks_sim::Simulator;
+use glam::Vec2;
+use helpers::{DummyAudio, DummyPhysic};
+
+const WIDTH: f32 = 800.0;
+
+fn arena_engine() -> AnyPhysicEngine {
+    AnyPhysicEngine::new(
+        Box::new(PhysicEngineFireworks::with_seed(
+            &PhysicConfig::default(),
+            WIDTH,
+            5,
+        )),
+        WIDTH,
+    )
+}
+
+// ==================================
+// 1. Changement de moteur
+// ==================================
+
+#[test]
+fn test_switch_preserves_config_shape_and_attractors() {
+    let mut engine = arena_engine();
+    assert_eq!(engine.engine_name(), "arena");
+    assert!(!engine.take_engine_switched());
+
+    let config = PhysicConfig {
+        max_rockets: 64,
+        rocket_palette: vec![[1.0, 0.0, 0.0]],
+        ..PhysicConfig::default()
+    };
+    engine.reload_config(&config);
+    engine.set_window_width(1280.0);
+    engine
+        .load_explosion_parametric("star", &[7.0, 0.3])
+        .unwrap();
+    engine.add_attractor(Vec2::new(100.0, 200.0), 5000.0, 150.0);
+    assert!(engine.launch_rocket());
+    engine.update(0.01);
+    assert_eq!(engine.get_stats().active_rockets, 1);
+
+    engine.switch_engine("arena").unwrap();
+
+    assert_eq!(engine.engine_name(), "arena");
+    assert!(engine.take_engine_switched());
+    assert!(!engine.take_engine_switched());
+    // Fusées en vol abandonnées, réglages conservés
+    assert_eq!(engine.get_stats().active_rockets, 0);
+    assert_eq!(engine.get_config().max_rockets, 64);
+    assert_eq!(engine.get_config().rocket_palette, config.rocket_palette);
+    assert_eq!(engine.window_width(), 1280.0);
+    assert_eq!(engine.explosion_shape_name(), "star");
+    assert_eq!(engine.explosion_shape_params(), vec![7.0, 0.3]);
+    let attractors = engine.attractors();
+    assert_eq!(attractors.len(), 1);
+    assert_eq!(attractors[0].pos, Vec2::new(100.0, 200.0));
+    assert_eq!(attractors[0].radius, 150.0);
+
+    // Le nouveau moteur tourne normalement
+    assert!(engine.launch_rocket());
+    engine.update(0.01);
+    assert_eq!(engine.get_stats().active_rockets, 1);
+}
+
+#[test]
+fn test_switch_keeps_launch_state() {
+    let mut engine = arena_engine();
+    engine.set_launches_enabled(false);
+    engine.switch_engine("arena").unwrap();
+    assert!(!engine.launches_enabled());
+    assert!(!engine.launch_rocket());
+}
+
+#[test]
+fn test_unknown_engine_keeps_the_current_one() {
+    let mut engine = arena_engine();
+    engine.load_explosion_parametric("ring", &[]).unwrap();
+    let err = engine.switch_engine("soa").unwrap_err();
+    assert!(err.to_string().contains("arena"), "{}", err);
+    assert!(!engine.take_engine_switched());
+    assert_eq!(engine.explosion_shape_name(), "ring");
+
+    // Moteur concret : pas de changement à chaud
+    assert!(DummyPhysic::default().switch_engine("arena").is_err());
+    assert!(PHYSIC_ENGINES.contains(&"arena"));
+}
+
+// ==================================
+// 2. Commande console et profiler
+// ==================================
+
+#[test]
+fn test_physic_engine_command_and_tagged_metrics() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sim = Simulator::new(NullRendererEngine::new(), arena_engine(), DummyAudio);
+    sim.init_console_commands();
+
+    let script = dir.path().join("switch.cfg");
+    std::fs::write(&script, "physic.shape.heart\nphysic.engine arena\n").unwrap();
+    let report = sim
+        .exec_script(&ExecArgs {
+            path: script.clone(),
+            abort_on_error: true,
+        })
+        .unwrap();
+    assert_eq!(report.failed, 0, "{:?}", report);
+    assert_eq!(sim.physic_engine().explosion_shape_name(), "heart");
+
+    std::fs::write(&script, "physic.engine soa\n").unwrap();
+    let report = sim
+        .exec_script(&ExecArgs {
+            path: script,
+            abort_on_error: false,
+        })
+        .unwrap();
+    assert_eq!(report.failed, 1, "{:?}", report);
+
+    // Mesure de la mise à jour physique étiquetée par le moteur
+    sim.step(0.01);
+    let profiler = sim.renderer_engine().profiler().unwrap();
+    assert!(profiler.percentiles("physic - update").is_some());
+    assert!(profiler
+        .percentiles(&physic_update_label("arena"))
+        .is_some());
+}
@@ -0,0 +1,155 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::{FireworksAudio3D, FireworksAudioConfig};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::headless::HEADLESS_DEFAULT_TIME_STEP;
+use fireworks_sim::renderer_engine::{NullRendererEngine, RendererEngine};
+use fireworks_sim::{AudioEngineSettings, Simulator};
+use helpers::{DummyAudio, DummyPhysic};
+use hound::WavReader;
+use std::path::Path;
+use std::process::Command;
+
+const SAMPLE_RATE: u32 = 48_000;
+const WIDTH: f32 = 1024.0;
+
+/// Simulateur headless avec les vrais moteurs physique et audio (comme `main`)
+fn headless_simulator(
+    seed: u64,
+) -> Simulator<NullRendererEngine, PhysicEngineFireworks, FireworksAudio3D> {
+    let physic_config = PhysicConfig::default();
+    let audio = FireworksAudio3D::new(FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (WIDTH / 2.0, 0.0),
+        sample_rate: SAMPLE_RATE,
+        block_size: 512,
+        max_voices: 32,
+        settings: AudioEngineSettings::default(),
+    });
+    let physic = PhysicEngineFireworks::with_seed(&physic_config, WIDTH, seed);
+    Simulator::new(NullRendererEngine::new(), physic, audio)
+}
+
+/// Exécution headless complète : retourne le contenu du WAV exporté
+fn run_to_wav(seed: u64, duration: f32, wav: &Path) -> Vec<u8> {
+    let mut simulator = headless_simulator(seed);
+    simulator
+        .run_headless(duration, HEADLESS_DEFAULT_TIME_STEP, wav.to_str())
+        .unwrap();
+    simulator.close();
+    std::fs::read(wav).unwrap()
+}
+
+// ==================================
+// 1. Boucle à pas fixe
+// ==================================
+
+#[test]
+fn test_headless_run_faster_than_real_time() {
+    let dir = tempfile::tempdir().unwrap();
+    let wav = dir.path().join("out.wav");
+    let metrics = dir.path().join("run.json");
+
+    let mut simulator = headless_simulator(42);
+    simulator.set_metrics_output(&metrics);
+    let report = simulator
+        .run_headless(5.0, HEADLESS_DEFAULT_TIME_STEP, wav.to_str())
+        .unwrap();
+    simulator.close();
+
+    assert_eq!(report.steps, 300);
+    assert!((report.simulated - 5.0).abs() < 1e-3);
+    // Même en debug, bien plus rapide que le temps réel
+    assert!(report.speedup() > 1.0, "{:?}", report);
+
+    // L'audio rendu hors-ligne couvre exactement la durée simulée
+    let reader = WavReader::open(&wav).unwrap();
+    assert_eq!(reader.spec().sample_rate, SAMPLE_RATE);
+    assert_eq!(reader.duration(), 5 * SAMPLE_RATE);
+    let samples: Vec<i16> = reader.into_samples().map(Result::unwrap).collect();
+    assert!(samples.iter().any(|s| *s != 0), "silent export");
+
+    // Métriques : une frame par pas, physique profilée
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&metrics).unwrap()).unwrap();
+    let metric = |name: &str| {
+        json["metrics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|m| m["metric"] == name)
+            .cloned()
+    };
+    assert!(metric("frame").is_some(), "{}", json);
+    assert!(metric("physic - update").is_some(), "{}", json);
+}
+
+#[test]
+fn test_headless_run_is_deterministic() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = run_to_wav(7, 2.0, &dir.path().join("a.wav"));
+    let second = run_to_wav(7, 2.0, &dir.path().join("b.wav"));
+    assert_eq!(first, second);
+
+    let other_seed = run_to_wav(8, 2.0, &dir.path().join("c.wav"));
+    assert_ne!(first, other_seed);
+}
+
+#[test]
+fn test_null_renderer_and_invalid_time_step() {
+    let mut renderer = NullRendererEngine::new();
+    assert!(renderer.profiler().is_some());
+    let err = renderer
+        .run_loop(
+            &mut DummyPhysic::default(),
+            &mut DummyAudio,
+            &CommandRegistry::new(),
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("run_headless"), "{}", err);
+
+    let mut simulator = Simulator::new(renderer, DummyPhysic::default(), DummyAudio);
+    assert!(simulator.run_headless(1.0, 0.0, None).is_err());
+    let report = simulator.run_headless(0.5, 0.1, None).unwrap();
+    assert_eq!(report.steps, 5);
+}
+
+// ==================================
+// 2. Ligne de commande
+// ==================================
+
+#[test]
+fn test_headless_cli_writes_audio_and_metrics() {
+    let dir = tempfile::tempdir().unwrap();
+    let wav = dir.path().join("out.wav");
+    let metrics = dir.path().join("run.csv");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fireworks_sim"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--headless", "--duration", "1", "--seed", "42"])
+        .arg("--export-audio")
+        .arg(&wav)
+        .arg("--metrics-out")
+        .arg(&metrics)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(WavReader::open(&wav).unwrap().duration(), SAMPLE_RATE);
+    let csv = std::fs::read_to_string(&metrics).unwrap();
+    assert!(csv.lines().any(|l| l.starts_with("frame,")), "{}", csv);
+
+    // Enregistrement vidéo sans fenêtre : refusé par le parseur
+    let output = Command::new(env!("CARGO_BIN_EXE_fireworks_sim"))
+        .args(["--headless", "--record", "out.mp4"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
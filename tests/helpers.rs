@@ -1,11 +1,12 @@
 use fireworks_sim::audio_engine::AudioEngine;
 use fireworks_sim::physic_engine::config::PhysicConfig;
 use fireworks_sim::physic_engine::particle::Particle;
-use fireworks_sim::physic_engine::types::UpdateResult;
+use fireworks_sim::physic_engine::types::{ReloadResult, UpdateResult};
 use fireworks_sim::physic_engine::{
     ParticleType, PhysicEngine, PhysicEngineFull, PhysicEngineIterator,
 };
 use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::flash::Flash;
 use fireworks_sim::renderer_engine::RendererEngine;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -35,31 +36,24 @@ impl AudioEngine for DummyAudio {
 }
 
 #[allow(dead_code)]
+#[derive(Default)]
 pub struct DummyPhysic {
     pub config: PhysicConfig,
     pub particles: Vec<Particle>,
 }
 
-impl Default for DummyPhysic {
-    fn default() -> Self {
-        Self {
-            config: PhysicConfig::default(),
-            particles: Vec::new(),
-        }
-    }
-}
-
 impl PhysicEngine for DummyPhysic {
     fn update(&mut self, _dt: f32) -> UpdateResult<'_> {
         UpdateResult {
             new_rocket: None,
             triggered_explosions: &[],
+            exploded_rocket_ids: &[],
         }
     }
     fn close(&mut self) {}
     fn set_window_width(&mut self, _width: f32) {}
-    fn reload_config(&mut self, _config: &PhysicConfig) -> bool {
-        false
+    fn reload_config(&mut self, _config: &PhysicConfig) -> ReloadResult {
+        ReloadResult::Unchanged
     }
     fn get_config(&self) -> &PhysicConfig {
         &self.config
@@ -147,6 +141,11 @@ impl AudioEngine for TestAudio {
     fn play_explosion(&self, _pos: (f32, f32), _gain: f32) {
         self.log.borrow_mut().push("play_explosion called".into());
     }
+    fn play_music(&self, data: &[[f32; 2]], sample_rate: u32, _gain: f32) {
+        self.log
+            .borrow_mut()
+            .push(format!("play_music({}, {})", data.len(), sample_rate));
+    }
     fn mute(&mut self) {
         self.log.borrow_mut().push("mute called".into());
     }
@@ -154,6 +153,19 @@ impl AudioEngine for TestAudio {
         self.log.borrow_mut().push("unmute called".into());
         1.0
     }
+    fn stop_all_voices(&mut self) {
+        self.log.borrow_mut().push("stop_all_voices called".into());
+    }
+    fn set_paused(&mut self, paused: bool) {
+        self.log
+            .borrow_mut()
+            .push(format!("audio.set_paused({})", paused));
+    }
+    fn set_time_scale(&mut self, scale: f32) {
+        self.log
+            .borrow_mut()
+            .push(format!("audio.set_time_scale({})", scale));
+    }
 }
 
 #[allow(dead_code)]
@@ -161,6 +173,8 @@ pub struct TestPhysic {
     pub log: SharedLog,
     pub config: PhysicConfig,
     pub fail_on_update: bool,
+    /// `dt` de chaque appel à `update`
+    pub update_dts: Vec<f32>,
 }
 
 #[allow(dead_code)]
@@ -170,19 +184,22 @@ impl TestPhysic {
             log,
             config: PhysicConfig::default(),
             fail_on_update: false,
+            update_dts: Vec::new(),
         }
     }
 }
 
 impl PhysicEngine for TestPhysic {
-    fn update(&mut self, _dt: f32) -> UpdateResult<'_> {
+    fn update(&mut self, dt: f32) -> UpdateResult<'_> {
         self.log.borrow_mut().push("physic.update".into());
+        self.update_dts.push(dt);
         if self.fail_on_update {
             panic!("PhysicEngine failed during update");
         }
         UpdateResult {
             new_rocket: None,
             triggered_explosions: &[],
+            exploded_rocket_ids: &[],
         }
     }
     fn set_window_width(&mut self, _width: f32) {
@@ -191,8 +208,11 @@ impl PhysicEngine for TestPhysic {
     fn close(&mut self) {
         self.log.borrow_mut().push("physic.close".into());
     }
-    fn reload_config(&mut self, _config: &PhysicConfig) -> bool {
-        false
+    fn clear_particles(&mut self) {
+        self.log.borrow_mut().push("physic.clear_particles".into());
+    }
+    fn reload_config(&mut self, _config: &PhysicConfig) -> ReloadResult {
+        ReloadResult::Unchanged
     }
     fn get_config(&self) -> &PhysicConfig {
         &self.config
@@ -255,6 +275,10 @@ impl RendererEngine for TestRenderer {
     fn close(&mut self) {
         self.log.borrow_mut().push("renderer.close".into());
     }
+
+    fn add_flash(&mut self, _flash: Flash) {
+        self.log.borrow_mut().push("renderer.add_flash".into());
+    }
 }
 
 // Legacy Logging structs (kept for compatibility if needed, but Test* structs are preferred)
@@ -0,0 +1,89 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::particles_pools::ParticlesPoolsForRockets;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_sim::register_sim_commands;
+use fireworks_sim::utils::human_bytes::ByteSize;
+use fireworks_sim::utils::memory_stats::{
+    memory_stats, parse_statm, MemorySnapshot, MemoryStats, PAGE_SIZE,
+};
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Agrégation et formatage
+// ==================================
+
+#[test]
+fn test_allocations_aggregated_by_label() {
+    let stats = MemoryStats::default();
+    stats.register_allocation("physic: trail particles pool", 2048);
+    stats.register_allocation("audio: sample data", 3 * 1024 * 1024);
+    // Redéclaration (buffers recréés) : la taille est remplacée
+    stats.register_allocation("physic: trail particles pool", 1536);
+    stats.register_allocation("gpu: rocket particles VBO", 512);
+    stats.unregister_allocation("gpu: rocket particles VBO");
+
+    let snapshot = stats.snapshot();
+    assert_eq!(
+        snapshot.allocations,
+        [
+            ("audio: sample data".to_string(), 3 * 1024 * 1024),
+            ("physic: trail particles pool".to_string(), 1536),
+        ]
+    );
+    assert_eq!(snapshot.tracked_total(), ByteSize(3 * 1024 * 1024 + 1536));
+    assert_eq!(stats.allocation("gpu: rocket particles VBO"), None);
+
+    let report = MemorySnapshot {
+        rss: Some(150 * 1024 * 1024),
+        ..snapshot
+    }
+    .format();
+    assert_eq!(
+        report,
+        "Process RSS: 150.00 MiB\n  \
+         audio: sample data            3.00 MiB\n  \
+         physic: trail particles pool  1.50 KiB\n\
+         Tracked allocations: 3.00 MiB"
+    );
+    assert!(MemorySnapshot::default()
+        .format()
+        .starts_with("Process RSS: n/a"));
+}
+
+#[test]
+fn test_parse_statm_sample() {
+    // Capture de /proc/self/statm : size resident shared text lib data dt (en pages)
+    let sample = "61035 3822 2954 238 0 5193 0\n";
+    assert_eq!(parse_statm(sample, PAGE_SIZE), Some(3822 * 4096));
+    assert_eq!(parse_statm(sample, 16384), Some(3822 * 16384));
+    assert_eq!(parse_statm("61035", PAGE_SIZE), None);
+    assert_eq!(parse_statm("61035 abc", PAGE_SIZE), None);
+}
+
+// ==================================
+// 2. Allocations des moteurs
+// ==================================
+
+#[test]
+fn test_particle_pools_register_and_sim_memory_command() {
+    let mut pools = ParticlesPoolsForRockets::new(4, 100, 10);
+    let explosions = pools.particles_pool_for_explosions.memory_bytes();
+    assert!(explosions > 0);
+    pools.grow(8);
+    let grown = pools.particles_pool_for_explosions.memory_bytes();
+    assert!(grown >= 2 * explosions);
+    // Collecteur global (partagé avec les autres tests) : le pool y est déclaré
+    assert!(memory_stats()
+        .allocation("physic: explosion particles pool")
+        .is_some());
+
+    let mut registry = CommandRegistry::new();
+    register_sim_commands(&mut registry);
+    let out = registry.execute(&mut DummyAudio, &mut DummyPhysic::default(), "sim.memory");
+    assert!(out.starts_with("Process RSS: "), "{}", out);
+    assert!(out.contains("physic: explosion particles pool"), "{}", out);
+    assert!(out.contains("Tracked allocations: "), "{}", out);
+    #[cfg(target_os = "linux")]
+    assert!(!out.contains("n/a"), "{}", out);
+}
@@ -0,0 +1,204 @@
+mod helpers;
+
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::audio_engine::{detect_onsets, MusicTrack, OnsetSettings};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::{Choreography, PhysicEngine};
+use fireworks_sim::Simulator;
+use helpers::{DummyRenderer, TestAudio};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Piste de clics : bruit de fond faible et tonalité continue, plus une salve
+/// de 1 kHz amortie (30 ms) à chaque instant de `clicks`.
+fn click_track(clicks: &[f32], duration: f32) -> Vec<[f32; 2]> {
+    let len = (duration * SAMPLE_RATE as f32) as usize;
+    let mut samples: Vec<[f32; 2]> = (0..len)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let background = 0.01 * (2.0 * std::f32::consts::PI * 220.0 * t).sin()
+                + 0.002 * ((i * 7919 % 1000) as f32 / 500.0 - 1.0);
+            [background; 2]
+        })
+        .collect();
+    let burst_len = (0.03 * SAMPLE_RATE as f32) as usize;
+    for &click in clicks {
+        let start = (click * SAMPLE_RATE as f32) as usize;
+        for j in 0..burst_len.min(len - start) {
+            let t = j as f32 / SAMPLE_RATE as f32;
+            let value = 0.8 * (-t / 0.008).exp() * (2.0 * std::f32::consts::PI * 1000.0 * t).sin();
+            samples[start + j][0] += value;
+            samples[start + j][1] += value;
+        }
+    }
+    samples
+}
+
+// ==================================
+// 1. Détection des onsets
+// ==================================
+
+#[test]
+fn test_onsets_of_a_click_track_within_20_ms() {
+    let clicks = [0.5, 1.0, 1.37, 2.0, 2.25, 3.1, 3.62, 4.4];
+    let samples = click_track(&clicks, 5.0);
+
+    let onsets = detect_onsets(&samples, SAMPLE_RATE, &OnsetSettings::default());
+
+    assert_eq!(onsets.len(), clicks.len(), "{:?}", onsets);
+    for (onset, click) in onsets.iter().zip(clicks) {
+        assert!(
+            (onset - click).abs() <= 0.020,
+            "onset {} for a click at {}",
+            onset,
+            click
+        );
+    }
+}
+
+#[test]
+fn test_onsets_respect_min_interval_and_silence() {
+    assert!(detect_onsets(
+        &vec![[0.0; 2]; 44_100],
+        SAMPLE_RATE,
+        &OnsetSettings::default()
+    )
+    .is_empty());
+
+    // Deux clics à 50 ms : un seul onset avec un écart minimal de 100 ms
+    let samples = click_track(&[1.0, 1.05], 2.0);
+    let onsets = detect_onsets(&samples, SAMPLE_RATE, &OnsetSettings::default());
+    assert_eq!(onsets.len(), 1, "{:?}", onsets);
+
+    let settings = OnsetSettings {
+        min_interval: 0.02,
+        ..OnsetSettings::default()
+    };
+    assert_eq!(detect_onsets(&samples, SAMPLE_RATE, &settings).len(), 2);
+}
+
+// ==================================
+// 2. Chorégraphie et temps de vol
+// ==================================
+
+#[test]
+fn test_choreography_back_schedules_by_flight_time() {
+    let mut choreography = Choreography::from_onsets(&[1.0, 2.5, 3.0, 6.0], 1.8);
+
+    // 1.0 s arrive avant la fin du temps de vol : abandonné
+    assert_eq!(choreography.skipped(), 1);
+    let launches = choreography.launch_times().to_vec();
+    assert_eq!(launches.len(), 3);
+    for (launch, expected) in launches.iter().zip([0.7, 1.2, 4.2]) {
+        assert!((launch - expected).abs() < 1e-5, "{:?}", launches);
+    }
+
+    assert_eq!(choreography.advance(0.5), 0);
+    assert_eq!(choreography.advance(0.8), 2);
+    assert!(!choreography.is_finished());
+    assert_eq!(choreography.advance(5.0), 1);
+    assert!(choreography.is_finished());
+    assert_eq!(choreography.advance(1.0), 0);
+}
+
+#[test]
+fn test_flight_time_matches_launch_speed() {
+    let config = PhysicConfig::default();
+    let flight_time = config.rocket_flight_time();
+    // Vitesse moyenne 425, verticale, seuil 50, gravité -200
+    assert!((flight_time - 1.875).abs() < 1e-4, "{}", flight_time);
+    let speed = config.launch_speed_for_flight_time(flight_time);
+    assert!((speed - 425.0).abs() < 1e-3, "{}", speed);
+}
+
+#[test]
+fn test_choreographed_explosions_land_on_onsets() {
+    let config = PhysicConfig::default();
+    let mut physic = PhysicEngineFireworks::with_seed(&config, 1024.0, 3);
+    let onsets = [2.5, 3.0, 3.8, 5.0];
+    let choreography = Choreography::from_onsets(&onsets, config.rocket_flight_time());
+    physic.set_choreography(Some(choreography));
+
+    let dt = 1.0 / 120.0;
+    let mut time = 0.0;
+    let mut launches = 0;
+    let mut explosions = vec![];
+    while time < 6.0 {
+        time += dt;
+        let result = physic.update(dt);
+        launches += result.new_rocket.is_some() as usize;
+        explosions.extend(result.triggered_explosions.iter().map(|_| time));
+    }
+
+    // Pas de lancement aléatoire : une fusée par onset
+    assert_eq!(launches, onsets.len());
+    assert_eq!(explosions.len(), onsets.len(), "{:?}", explosions);
+    for (explosion, onset) in explosions.iter().zip(onsets) {
+        assert!(
+            (explosion - onset).abs() <= 2.0 * dt,
+            "explosion at {} for an onset at {}",
+            explosion,
+            onset
+        );
+    }
+    assert!(physic.choreography().unwrap().is_finished());
+}
+
+// ==================================
+// 3. Bande-son (--music)
+// ==================================
+
+#[test]
+fn test_set_music_schedules_launches_and_plays_the_track() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("track.wav");
+    let samples = click_track(&[2.5, 3.0, 4.0], 5.0);
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+    for frame in &samples {
+        for s in frame {
+            writer.write_sample((s * 32767.0) as i16).unwrap();
+        }
+    }
+    writer.finalize().unwrap();
+
+    let track = MusicTrack::load(&path).unwrap();
+    assert_eq!(track.sample_rate, SAMPLE_RATE);
+    assert!((track.duration() - 5.0).abs() < 1e-3);
+
+    let log = Rc::new(RefCell::new(vec![]));
+    let physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 1024.0);
+    let mut sim = Simulator::new(DummyRenderer, physic, TestAudio::new(log.clone()));
+    assert_eq!(sim.set_music(&path).unwrap(), 3);
+    assert_eq!(
+        sim.physic_engine()
+            .choreography()
+            .unwrap()
+            .launch_times()
+            .len(),
+        3
+    );
+    assert_eq!(
+        *log.borrow(),
+        [format!("play_music({}, {})", samples.len(), SAMPLE_RATE)]
+    );
+
+    // Fichier illisible : erreur, pas de panique
+    assert!(sim.set_music(&dir.path().join("missing.wav")).is_err());
+}
+
+#[test]
+fn test_music_flag() {
+    let options =
+        AppOptions::try_parse_from(["fireworks-sim", "--music", "song.wav"], |_| None).unwrap();
+    assert_eq!(options.music, Some("song.wav".into()));
+    assert_eq!(AppOptions::default().music, None);
+}
@@ -0,0 +1,155 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::{
+    attractor::{Attractor, ATTRACTOR_MIN_DISTANCE},
+    config::PhysicConfig,
+    particles_pools::ParticlesPoolsForRockets,
+    physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
+    rocket::Rocket,
+    PhysicEngine, PhysicEngineIterator,
+};
+use fireworks_sim::renderer_engine::command_script::ExecArgs;
+use fireworks_sim::renderer_engine::NullRendererEngine;
+use fireworks_sim::Simulator;
+use glam::Vec2;
+use helpers::DummyAudio;
+use rand::SeedableRng;
+
+/// Config sans gravité ni lancement automatique
+fn zero_gravity_config() -> PhysicConfig {
+    PhysicConfig {
+        max_rockets: 1,
+        gravity: 0.0,
+        rocket_interval_mean: 1.0e6,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    }
+}
+
+// ==================================
+// 1. Attractor
+// ==================================
+
+#[test]
+fn test_acceleration_points_toward_attractor() {
+    let attractor = Attractor::new(Vec2::new(100.0, 0.0), 1.0e5, 500.0);
+    let acc = attractor.acceleration(Vec2::ZERO);
+    assert!(acc.x > 0.0);
+    assert_eq!(acc.y, 0.0);
+    // Loi en 1/d²
+    let acc_far = attractor.acceleration(Vec2::new(-100.0, 0.0));
+    assert!((acc.x / acc_far.x - 4.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_acceleration_is_clamped_near_center() {
+    let strength = 1.0e5;
+    let attractor = Attractor::new(Vec2::ZERO, strength, 500.0);
+    let max_acc = strength / (ATTRACTOR_MIN_DISTANCE * ATTRACTOR_MIN_DISTANCE);
+
+    // Pile au centre : aucune force, pas de NaN
+    assert_eq!(attractor.acceleration(Vec2::ZERO), Vec2::ZERO);
+    for d in [1.0e-6, 0.1, 1.0, ATTRACTOR_MIN_DISTANCE] {
+        let acc = attractor.acceleration(Vec2::new(d, 0.0));
+        assert!(acc.is_finite());
+        assert!(acc.length() <= max_acc * 1.0001);
+    }
+}
+
+#[test]
+fn test_acceleration_zero_outside_radius() {
+    let attractor = Attractor::new(Vec2::ZERO, 1.0e5, 50.0);
+    assert_eq!(attractor.acceleration(Vec2::new(51.0, 0.0)), Vec2::ZERO);
+    assert_ne!(attractor.acceleration(Vec2::new(49.0, 0.0)), Vec2::ZERO);
+}
+
+// ==================================
+// 2. Intégration dans la simulation
+// ==================================
+
+#[test]
+fn test_rocket_accelerates_toward_attractor_without_nan() {
+    let config = zero_gravity_config();
+    let mut pools = ParticlesPoolsForRockets::new(1, 16, config.trail_block_size());
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(&config, 1920.0);
+    rocket.pos = Vec2::ZERO;
+    rocket.vel = Vec2::new(0.0, 100.0);
+
+    // Attracteur droit devant : la fusée passe par son centre
+    let attractors = [Attractor::new(Vec2::new(0.0, 50.0), 1.0e6, 1000.0)];
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.add_attractor(Vec2::new(0.0, 50.0), 1.0e6, 1000.0);
+    assert_eq!(engine.attractors(), attractors);
+
+    let dt = 0.001;
+    for _ in 0..200 {
+        let before = rocket.vel;
+        let dir = attractors[0].pos - rocket.pos;
+        rocket.apply_attractors(&attractors, dt, &mut pools);
+        rocket.update(dt, &mut pools, &config);
+        assert!(rocket.vel.is_finite() && rocket.pos.is_finite());
+        if (ATTRACTOR_MIN_DISTANCE..1000.0).contains(&dir.length()) {
+            // La vitesse gagne une composante dirigée vers l'attracteur
+            assert!((rocket.vel - before).dot(dir) > 0.0);
+        }
+    }
+    for p in rocket.iter_active_particles(&pools) {
+        assert!(p.vel.is_finite() && p.pos.is_finite());
+    }
+}
+
+#[test]
+fn test_engine_attractor_bends_trajectory() {
+    let config = zero_gravity_config();
+
+    // Sans gravité, la vitesse de la fusée est constante jusqu'à l'ajout de l'attracteur
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.spawn_n_rockets(1);
+    engine.update(0.016);
+    let head = *engine.iter_active_heads_not_exploded().next().unwrap();
+    let id = engine.add_attractor(head.pos + Vec2::new(200.0, 0.0), 1.0e7, 1000.0);
+
+    engine.update(0.016);
+    let bent = *engine.iter_active_heads_not_exploded().next().unwrap();
+    assert!(bent.vel.x > head.vel.x);
+
+    assert!(engine.remove_attractor(id));
+    assert!(!engine.remove_attractor(id));
+    assert!(engine.attractors().is_empty());
+
+    engine.add_attractor(Vec2::ZERO, 1.0, 10.0);
+    engine.clear_attractors();
+    assert!(engine.attractors().is_empty());
+}
+
+#[test]
+fn test_console_rejects_non_finite_attractor() {
+    let dir = tempfile::tempdir().unwrap();
+    let engine = PhysicEngineFireworks::new(&zero_gravity_config(), 1920.0);
+    let mut sim = Simulator::new(NullRendererEngine::new(), engine, DummyAudio);
+    sim.init_console_commands();
+
+    let script = dir.path().join("attractors.cfg");
+    std::fs::write(
+        &script,
+        "physic.attractor.add 0 0 nan\n\
+         physic.attractor.add inf 0 1\n\
+         physic.attractor.add 0 0 1 -inf\n\
+         physic.attractor.add 0 0 1e5 200\n",
+    )
+    .unwrap();
+    let report = sim
+        .exec_script(&ExecArgs {
+            path: script,
+            abort_on_error: false,
+        })
+        .unwrap();
+    assert_eq!(report.failed, 3, "{:?}", report);
+    assert_eq!(
+        sim.physic_engine().attractors(),
+        [Attractor::new(Vec2::ZERO, 1.0e5, 200.0)]
+    );
+}
@@ -0,0 +1,127 @@
+mod helpers;
+
+use fireworks_sim::config_manager::ConfigSection;
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use helpers::{DummyAudio, DummyPhysic};
+
+/// Config dont aucun champ n'a sa valeur par défaut
+fn tuned_config() -> PhysicConfig {
+    PhysicConfig {
+        max_rockets: 321,
+        particles_per_explosion: 77,
+        particles_per_trail: 99,
+        rocket_interval_mean: 0.3,
+        rocket_interval_variation: 0.1,
+        rocket_max_next_interval: 0.7,
+        spawn_rocket_margin: 12.5,
+        spawn_rocket_vertical_angle: 1.2,
+        spawn_rocket_angle_variation: 0.05,
+        spawn_rocket_min_speed: 310.0,
+        spawn_rocket_max_speed: 640.0,
+        explosion_threshold: 42.0,
+        gravity: -123.4,
+        trail_spacing: 1.5,
+        trail_particle_life: 0.45,
+        trail_particle_size: 3.0,
+        trail_length_multiplier_min: 0.5,
+        trail_length_multiplier_max: 1.75,
+        trail_velocity_inherit: 0.3,
+        trail_lateral_jitter: 4.0,
+        trail_end_color: [0.1, 0.2, 0.3, 0.4],
+        depth_enabled: true,
+        spawn_rocket_min_depth: 10.0,
+        spawn_rocket_max_depth: 900.0,
+        max_active_explosion_particles: Some(5000),
+        max_active_trail_particles: Some(2000),
+        min_explosion_particles: 8,
+        shapes_dir: "assets/my_shapes".to_string(),
+        rocket_palette: vec![[1.0, 0.5, 0.25], [0.2, 0.9, 0.6]],
+        pool_compaction_threshold: 40.0,
+        pool_compaction_blocks_per_frame: 8,
+    }
+}
+
+// ==================================
+// 1. Sauvegarde TOML
+// ==================================
+
+#[test]
+fn test_save_round_trips_all_fields() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested/physic.toml");
+
+    for config in [tuned_config(), PhysicConfig::default()] {
+        config.save_to_file(&path).unwrap();
+        let loaded = PhysicConfig::from_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded, config);
+    }
+}
+
+#[test]
+fn test_save_keeps_unknown_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("physic.toml");
+    std::fs::write(
+        &path,
+        "max_rockets = 10\nmax_active_trail_particles = 50\nfuture_setting = \"keep me\"\n",
+    )
+    .unwrap();
+
+    // Budget retiré : la clé disparaît du fichier
+    tuned_config_without_budgets().save_to_file(&path).unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(text.contains("future_setting = \"keep me\""), "{}", text);
+    assert!(!text.contains("max_active_trail_particles"), "{}", text);
+    let loaded = PhysicConfig::from_file(path.to_str().unwrap()).unwrap();
+    assert_eq!(loaded, tuned_config_without_budgets());
+}
+
+fn tuned_config_without_budgets() -> PhysicConfig {
+    PhysicConfig {
+        max_active_explosion_particles: None,
+        max_active_trail_particles: None,
+        ..tuned_config()
+    }
+}
+
+// ==================================
+// 2. Commande console
+// ==================================
+
+#[test]
+fn test_physic_config_save_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let loaded_from = dir.path().join("physic.toml");
+    let shared = RendererShared::default();
+    shared
+        .config_manager
+        .borrow_mut()
+        .set_path(ConfigSection::Physic, &loaded_from);
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut physic = DummyPhysic {
+        config: tuned_config(),
+        ..DummyPhysic::default()
+    };
+
+    // Sans argument : fichier d'origine
+    let out = registry.execute(&mut DummyAudio, &mut physic, "physic.config.save");
+    assert_eq!(
+        out,
+        format!("Physic config saved to {}", loaded_from.display())
+    );
+    let saved = PhysicConfig::from_file(loaded_from.to_str().unwrap()).unwrap();
+    assert_eq!(saved, tuned_config());
+
+    let other = dir.path().join("tuned.toml");
+    let out = registry.execute(
+        &mut DummyAudio,
+        &mut physic,
+        &format!("physic.config.save {}", other.display()),
+    );
+    assert!(out.starts_with("Physic config saved"), "{}", out);
+    assert!(other.is_file());
+}
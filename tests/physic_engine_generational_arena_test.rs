@@ -1,7 +1,7 @@
 use fireworks_sim::physic_engine::{
     config::PhysicConfig,
     physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
-    PhysicEngine, PhysicEngineIterator,
+    PhysicEngine, PhysicEngineIterator, ReloadResult,
 };
 
 // ==================================
@@ -21,8 +21,10 @@ fn test_new_engine_initialization() {
 
 #[test]
 fn test_spawn_rocket_margin_calculation() {
-    let mut config = PhysicConfig::default();
-    config.spawn_rocket_margin = 100.0;
+    let config = PhysicConfig {
+        spawn_rocket_margin: 100.0,
+        ..Default::default()
+    };
 
     // Cas 1: Fenêtre normale
     let _engine = PhysicEngineFireworks::new(&config, 1920.0);
@@ -59,8 +61,10 @@ fn test_spawn_rocket_success() {
 
 #[test]
 fn test_spawn_rocket_exhaustion() {
-    let mut config = PhysicConfig::default();
-    config.max_rockets = 3; // Limite à 3 fusées
+    let config = PhysicConfig {
+        max_rockets: 3, // Limite à 3 fusées
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Spawn 3 fusées
@@ -80,9 +84,11 @@ fn test_spawn_rocket_exhaustion() {
 
 #[test]
 fn test_rocket_deactivation_after_lifecycle() {
-    let mut config = PhysicConfig::default();
-    config.max_rockets = 10;
-    config.rocket_interval_mean = 100.0; // Empêcher le spawn automatique
+    let config = PhysicConfig {
+        max_rockets: 10,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Spawn une fusée
@@ -108,10 +114,12 @@ fn test_rocket_deactivation_after_lifecycle() {
 
 #[test]
 fn test_compute_next_interval_respects_bounds() {
-    let mut config = PhysicConfig::default();
-    config.rocket_interval_mean = 1.0;
-    config.rocket_interval_variation = 0.3;
-    config.rocket_max_next_interval = 0.5;
+    let config = PhysicConfig {
+        rocket_interval_mean: 1.0,
+        rocket_interval_variation: 0.3,
+        rocket_max_next_interval: 0.5,
+        ..Default::default()
+    };
 
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
@@ -129,10 +137,12 @@ fn test_compute_next_interval_respects_bounds() {
 
 #[test]
 fn test_update_spawns_rocket_after_interval() {
-    let mut config = PhysicConfig::default();
-    config.rocket_interval_mean = 0.1;
-    config.rocket_interval_variation = 0.0;
-    config.rocket_max_next_interval = 0.01;
+    let config = PhysicConfig {
+        rocket_interval_mean: 0.1,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 0.01,
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Update avec dt < intervalle
@@ -166,16 +176,18 @@ fn test_reload_config_no_change() {
     let mut new_config = config.clone();
     new_config.rocket_interval_mean = 2.0; // Changement mineur
 
-    let changed = engine.reload_config(&new_config);
+    let result = engine.reload_config(&new_config);
 
-    assert!(!changed); // max_rockets n'a pas changé
+    assert_eq!(result, ReloadResult::Unchanged); // max_rockets n'a pas changé
     assert_eq!(engine.rockets_count(), rockets_before); // Fusées préservées
 }
 
 #[test]
 fn test_reload_config_with_max_rockets_change() {
-    let mut config = PhysicConfig::default();
-    config.max_rockets = 10;
+    let config = PhysicConfig {
+        max_rockets: 10,
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Spawn quelques fusées
@@ -187,10 +199,101 @@ fn test_reload_config_with_max_rockets_change() {
     let mut new_config = config.clone();
     new_config.max_rockets = 20; // Augmentation
 
-    let changed = engine.reload_config(&new_config);
+    let result = engine.reload_config(&new_config);
+
+    assert_eq!(result, ReloadResult::Grew);
+    assert!(result.needs_buffer_recreation());
+    assert_eq!(engine.rockets_count(), 2); // Fusées en vol préservées
+}
+
+#[test]
+fn test_reload_config_grow_preserves_active_particles() {
+    let config = PhysicConfig {
+        max_rockets: 4,
+        rocket_interval_mean: 1.0e6, // Pas de lancement automatique
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.spawn_n_rockets(4), 4);
+    engine.update(0.016);
+    engine.force_explode_all();
+
+    let particles_before = engine.iter_active_particles().count();
+    let positions_before: Vec<_> = engine.iter_active_particles().map(|p| p.pos).collect();
+    assert!(particles_before > 0);
+
+    let new_config = PhysicConfig {
+        max_rockets: 12,
+        ..config.clone()
+    };
+    assert_eq!(engine.reload_config(&new_config), ReloadResult::Grew);
+
+    // Rien n'a bougé pour les fusées en vol
+    assert_eq!(engine.rockets_count(), 4);
+    assert_eq!(engine.iter_active_particles().count(), particles_before);
+    let positions_after: Vec<_> = engine.iter_active_particles().map(|p| p.pos).collect();
+    assert_eq!(positions_after, positions_before);
+
+    // ... et la nouvelle capacité est utilisable (arena + pools)
+    assert_eq!(engine.spawn_n_rockets(20), 8);
+    assert_eq!(engine.rockets_count(), 12);
+    let stats = engine.get_stats();
+    assert_eq!(stats.explosions_pool.blocks_total, 12);
+    assert_eq!(stats.trails_pool.blocks_total, 12);
+    assert_eq!(stats.allocation_failures, 0);
+}
+
+#[test]
+fn test_reload_config_shrink_defers_until_rockets_die() {
+    let config = PhysicConfig {
+        max_rockets: 6,
+        rocket_interval_mean: 1.0e6,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.spawn_n_rockets(6), 6);
+
+    let new_config = PhysicConfig {
+        max_rockets: 2,
+        ..config.clone()
+    };
+    let result = engine.reload_config(&new_config);
+    assert_eq!(result, ReloadResult::ShrinkPending);
+    assert!(!result.needs_buffer_recreation());
+
+    // Les fusées actives survivent, mais aucun lancement n'est possible
+    assert_eq!(engine.rockets_count(), 6);
+    assert_eq!(engine.spawn_n_rockets(1), 0);
+
+    // On laisse la simulation s'éteindre : les lancements reprennent sous le plafond
+    for _ in 0..2000 {
+        engine.update(0.016);
+        if engine.rockets_count() == 0 {
+            break;
+        }
+    }
+    assert_eq!(engine.rockets_count(), 0);
+    assert_eq!(engine.spawn_n_rockets(10), 2);
+}
+
+#[test]
+fn test_reload_config_shrink_without_active_rockets() {
+    let config = PhysicConfig {
+        max_rockets: 6,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
-    assert!(changed);
-    assert_eq!(engine.rockets_count(), 0); // Réinitialisé
+    let new_config = PhysicConfig {
+        max_rockets: 3,
+        ..config.clone()
+    };
+    assert_eq!(engine.reload_config(&new_config), ReloadResult::Shrank);
+    assert_eq!(engine.spawn_n_rockets(10), 3);
 }
 
 // ==================================
@@ -205,14 +308,16 @@ fn test_update_returns_valid_result() {
     let result = engine.update(0.016);
 
     // Le résultat devrait être valide (peut être vide)
-    assert!(result.triggered_explosions.len() == 0 || result.triggered_explosions.len() > 0);
+    assert!(result.triggered_explosions.is_empty() || !result.triggered_explosions.is_empty());
 }
 
 #[test]
 fn test_update_with_multiple_rockets() {
-    let mut config = PhysicConfig::default();
-    config.max_rockets = 100;
-    config.rocket_interval_mean = 100.0; // Empêcher le spawn automatique
+    let config = PhysicConfig {
+        max_rockets: 100,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Spawn plusieurs fusées
@@ -361,8 +466,10 @@ fn test_iter_active_particles_increases_with_trails() {
 
 #[test]
 fn test_iter_active_heads_not_exploded() {
-    let mut config = PhysicConfig::default();
-    config.rocket_interval_mean = 100.0; // Empêcher le spawn automatique
+    let config = PhysicConfig {
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
     let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
 
     // Spawn 3 fusées
@@ -458,3 +565,29 @@ fn test_reload_config_multiple_times() {
     let result = engine.update(0.016);
     assert!(result.new_rocket.is_some());
 }
+
+// ==================================
+// 9. Helpers de test (benchmarks)
+// ==================================
+
+#[test]
+fn test_spawn_n_rockets_and_force_explode_all() {
+    let config = PhysicConfig {
+        max_rockets: 5,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+
+    // Borné par max_rockets
+    assert_eq!(engine.spawn_n_rockets(8), 5);
+    assert_eq!(engine.rockets_count(), 5);
+    assert_eq!(engine.iter_active_heads_not_exploded().count(), 5);
+
+    engine.force_explode_all();
+    assert_eq!(engine.iter_active_heads_not_exploded().count(), 0);
+    assert_eq!(
+        engine.iter_active_particles().count(),
+        5 * config.particles_per_explosion
+    );
+}
@@ -0,0 +1,138 @@
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig,
+    explosion_shape::{ParametricKind, ParametricShape, EXPLOSION_SHAPE_SPEED, MAX_STAR_POINTS},
+    physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
+    ParticleType, PhysicEngine, PhysicEngineIterator,
+};
+
+const SAMPLES: usize = 256;
+
+// ==================================
+// 1. Générateurs procéduraux
+// ==================================
+
+#[test]
+fn test_ring_points_within_thickness() {
+    let (radius, thickness) = (0.4, 0.05);
+    let shape = ParametricShape::new(ParametricKind::Ring { radius, thickness }, SAMPLES);
+
+    assert_eq!(shape.sampled_points.len(), SAMPLES);
+    for p in shape.sampled_points.iter() {
+        let r = p.length();
+        assert!(
+            (r - radius).abs() <= thickness * 0.5 + 1e-5,
+            "Point {:?} outside ring band (r = {})",
+            p,
+            r
+        );
+    }
+}
+
+#[test]
+fn test_star_point_count() {
+    for points in [4, 5, 7] {
+        let shape = ParametricShape::new(
+            ParametricKind::Star {
+                points,
+                inner_ratio: 0.4,
+            },
+            SAMPLES,
+        );
+        // Les sommets extérieurs (branches) sont sur le cercle unité
+        let tips = shape
+            .sampled_points
+            .iter()
+            .filter(|p| (p.length() - 1.0).abs() < 1e-4)
+            .count();
+        assert_eq!(tips, points as usize);
+
+        // Aucun point en dehors du disque unité
+        assert!(shape
+            .sampled_points
+            .iter()
+            .all(|p| p.length() <= 1.0 + 1e-5));
+    }
+}
+
+#[test]
+fn test_heart_and_spiral_respect_unit_disc() {
+    let heart = ParametricShape::new(ParametricKind::Heart { scale: 1.0 }, SAMPLES);
+    assert!(heart.sampled_points.iter().all(|p| p.length() <= 1.0));
+    // Symétrie gauche/droite : point t et point -t
+    let pts = &heart.sampled_points;
+    for i in 1..SAMPLES {
+        let (a, b) = (pts[i], pts[SAMPLES - i]);
+        assert!((a.x + b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4);
+    }
+
+    let spiral = ParametricShape::new(ParametricKind::Spiral { turns: 3.0 }, SAMPLES);
+    let radii: Vec<f32> = spiral.sampled_points.iter().map(|p| p.length()).collect();
+    assert!(
+        radii.windows(2).all(|w| w[1] > w[0]),
+        "Spiral radius must grow"
+    );
+    assert!((radii[SAMPLES - 1] - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_from_params_defaults_and_errors() {
+    assert_eq!(
+        ParametricKind::from_params("ring", &[0.4, 0.05]).unwrap(),
+        ParametricKind::Ring {
+            radius: 0.4,
+            thickness: 0.05
+        }
+    );
+    assert!(matches!(
+        ParametricKind::from_params("star", &[]).unwrap(),
+        ParametricKind::Star { points: 5, .. }
+    ));
+    assert!(ParametricKind::from_params("triangle", &[]).is_err());
+    assert!(ParametricKind::from_params("ring", &[1.5]).is_err());
+    assert!(ParametricKind::from_params("star", &[1.0]).is_err());
+}
+
+#[test]
+fn test_star_points_upper_bound() {
+    assert!(ParametricKind::from_params("star", &[MAX_STAR_POINTS as f32]).is_ok());
+    assert!(ParametricKind::from_params("star", &[MAX_STAR_POINTS as f32 + 1.0]).is_err());
+    // `physic.shape.star 1e9` ne doit rien allouer
+    let err = ParametricKind::from_params("star", &[1e9]).unwrap_err();
+    assert!(err.to_string().contains("points"), "{err}");
+}
+
+// ==================================
+// 2. Intégration moteur
+// ==================================
+
+#[test]
+fn test_engine_explosions_follow_parametric_shape() {
+    let config = PhysicConfig {
+        max_rockets: 4,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+
+    engine
+        .load_explosion_parametric("ring", &[0.5, 0.0])
+        .unwrap();
+    assert_eq!(engine.explosion_shape_name(), "ring");
+    assert!(engine.load_explosion_parametric("unknown", &[]).is_err());
+
+    engine.spawn_n_rockets(2);
+    engine.force_explode_all();
+
+    let speeds: Vec<f32> = engine
+        .iter_particles_by_type(ParticleType::Explosion)
+        .map(|p| p.vel.length())
+        .collect();
+    assert_eq!(speeds.len(), 2 * config.particles_per_explosion);
+    for speed in speeds {
+        assert!((speed - 0.5 * EXPLOSION_SHAPE_SPEED).abs() < 1e-2);
+    }
+
+    engine.clear_explosion_shape();
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+}
@@ -0,0 +1,333 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::finale::{finale_intensity, FinaleSettings, FinaleState};
+use fireworks_sim::physic_engine::physic_engine_generational_arena::{
+    PhysicEngineFireworks, PhysicEngineTestHelpers,
+};
+use fireworks_sim::physic_engine::{
+    AnyPhysicEngine, ParticleType, PhysicEngine, PhysicEngineIterator,
+};
+use fireworks_sim::renderer_engine::command_script::ExecArgs;
+use fireworks_sim::renderer_engine::NullRendererEngine;
+use fireworks_sim::Simulator;
+use helpers::DummyAudio;
+
+/// Pas fixe de 1/60 s
+const DT: f32 = 1.0 / 60.0;
+const WIDTH: f32 = 1024.0;
+
+/// Cadence de base régulière : une fusée toutes les 0.5 s
+fn paced_config() -> PhysicConfig {
+    PhysicConfig {
+        max_rockets: 2048,
+        particles_per_explosion: 16,
+        particles_per_trail: 16,
+        rocket_interval_mean: 0.5,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 0.5,
+        ..Default::default()
+    }
+}
+
+/// Avance le moteur de `seconds` secondes ; retourne le nombre de frames ayant lancé une fusée
+fn run(engine: &mut PhysicEngineFireworks, seconds: f32) -> usize {
+    (0..(seconds / DT).round() as usize)
+        .filter(|_| engine.update(DT).new_rocket.is_some())
+        .count()
+}
+
+fn launched(engine: &PhysicEngineFireworks) -> usize {
+    engine.get_stats().finale.map_or(0, |f| f.launched)
+}
+
+// ==================================
+// 1. Courbe d'intensité
+// ==================================
+
+#[test]
+fn test_intensity_ramps_up_and_down() {
+    let (duration, ramp) = (10.0, 2.0);
+    assert_eq!(finale_intensity(0.0, duration, ramp), 0.0);
+    assert_eq!(finale_intensity(1.0, duration, ramp), 0.5);
+    assert_eq!(finale_intensity(2.0, duration, ramp), 1.0);
+    assert_eq!(finale_intensity(5.0, duration, ramp), 1.0);
+    // Descente symétrique de la montée
+    assert_eq!(
+        finale_intensity(9.5, duration, ramp),
+        finale_intensity(0.5, duration, ramp)
+    );
+    // Hors du final
+    assert_eq!(finale_intensity(-0.1, duration, ramp), 0.0);
+    assert_eq!(finale_intensity(duration, duration, ramp), 0.0);
+
+    // Montée monotone, sans saut
+    let mut previous = 0.0;
+    for i in 1..=100 {
+        let value = finale_intensity(i as f32 * 0.02, duration, ramp);
+        assert!(value >= previous && value - previous < 0.05);
+        previous = value;
+    }
+}
+
+#[test]
+fn test_intensity_ramp_bounds() {
+    // Sans rampe : bascule franche
+    assert_eq!(finale_intensity(0.0, 4.0, 0.0), 1.0);
+    assert_eq!(finale_intensity(3.99, 4.0, 0.0), 1.0);
+    // Rampe bornée à la moitié de la durée : sommet au milieu
+    assert_eq!(finale_intensity(2.0, 4.0, 10.0), 1.0);
+    assert!(finale_intensity(1.0, 4.0, 10.0) < 1.0);
+}
+
+#[test]
+fn test_interval_interpolates_rates() {
+    let settings = FinaleSettings {
+        ramp: 0.0,
+        peak_interval: 0.01,
+        ..FinaleSettings::new(2.0)
+    };
+    let mut finale = FinaleState::new(settings, &PhysicConfig::default());
+    // Plateau : cadence maximale quelle que soit la base
+    assert!((finale.interval(0.5) - 0.01).abs() < 1e-6);
+    // Une base déjà plus rapide que le final n'est pas ralentie
+    assert!((finale.interval(0.001) - 0.001).abs() < 1e-6);
+
+    // Fini : intensité nulle, intervalle de base
+    assert!(finale.advance(2.0));
+    assert_eq!(finale.intensity(), 0.0);
+    assert!((finale.interval(0.5) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_settings_validation() {
+    assert!(FinaleSettings::new(5.0).validate().is_ok());
+    for duration in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        assert!(
+            FinaleSettings::new(duration).validate().is_err(),
+            "{}",
+            duration
+        );
+    }
+    let settings = FinaleSettings {
+        peak_interval: 0.0,
+        ..FinaleSettings::new(5.0)
+    };
+    assert!(settings.validate().is_err());
+    let settings = FinaleSettings {
+        palette: Some(vec![[1.0, 2.0, 0.0]]),
+        ..FinaleSettings::new(5.0)
+    };
+    assert!(settings.validate().is_err());
+
+    let mut engine = PhysicEngineFireworks::with_seed(&paced_config(), WIDTH, 1);
+    assert!(engine.start_finale(FinaleSettings::new(0.0)).is_err());
+    assert!(engine.get_stats().finale.is_none());
+}
+
+// ==================================
+// 2. Final sur un moteur headless
+// ==================================
+
+#[test]
+fn test_spawn_rate_during_and_after_finale() {
+    let mut engine = PhysicEngineFireworks::with_seed(&paced_config(), WIDTH, 3);
+    let config_before = engine.get_config().clone();
+
+    // Cadence de base : ~2 fusées par seconde
+    let before = run(&mut engine, 2.0);
+    assert!((3..=5).contains(&before), "{}", before);
+
+    let settings = FinaleSettings {
+        peak_interval: 0.005,
+        ..FinaleSettings::new(3.0)
+    };
+    engine.start_finale(settings).unwrap();
+    // La config n'est jamais modifiée pendant le final
+    run(&mut engine, 0.1);
+    assert_eq!(engine.get_config(), &config_before);
+
+    // Montée : encore loin du plateau
+    let ramp_launches = launched(&engine);
+    run(&mut engine, 1.0);
+    let progress = engine.get_stats().finale.unwrap();
+    assert!((progress.elapsed - 1.1).abs() < 1e-3, "{:?}", progress);
+    assert_eq!(progress.intensity, 1.0);
+
+    // Plateau : jusqu'à 200 fusées par seconde, plusieurs par frame
+    let start = launched(&engine);
+    run(&mut engine, 1.0);
+    let plateau = launched(&engine) - start;
+    assert!(plateau >= 190, "{}", plateau);
+    assert!(plateau > 30 * before);
+    assert!(start - ramp_launches < plateau);
+
+    // Fin du final : stats vidées, config intacte
+    run(&mut engine, 1.0);
+    assert!(engine.get_stats().finale.is_none());
+    assert_eq!(engine.get_config(), &config_before);
+
+    // Retour à la cadence de base
+    let after = run(&mut engine, 2.0);
+    assert!((3..=5).contains(&after), "{}", after);
+}
+
+#[test]
+fn test_finale_limited_by_pool_capacity() {
+    let config = PhysicConfig {
+        max_rockets: 32,
+        ..paced_config()
+    };
+    let mut engine = PhysicEngineFireworks::with_seed(&config, WIDTH, 4);
+    let settings = FinaleSettings {
+        ramp: 0.0,
+        ..FinaleSettings::new(0.5)
+    };
+    engine.start_finale(settings).unwrap();
+
+    run(&mut engine, 0.2);
+    assert_eq!(engine.rockets_count(), 32);
+    assert_eq!(launched(&engine), 32);
+    assert_eq!(engine.get_stats().allocation_failures, 0);
+}
+
+#[test]
+fn test_finale_palette_and_full_explosions() {
+    let gold = [1.0, 0.85, 0.4];
+    let config = PhysicConfig {
+        max_active_explosion_particles: Some(64),
+        min_explosion_particles: 1,
+        rocket_palette: vec![[0.2, 0.3, 1.0]],
+        ..paced_config()
+    };
+    let mut engine = PhysicEngineFireworks::with_seed(&config, WIDTH, 5);
+    let settings = FinaleSettings {
+        ramp: 0.0,
+        peak_interval: 0.05,
+        full_explosions: true,
+        palette: Some(vec![gold]),
+        ..FinaleSettings::new(1.0)
+    };
+    engine.start_finale(settings).unwrap();
+    run(&mut engine, 0.5);
+
+    let heads: Vec<_> = engine
+        .iter_particles_by_type(ParticleType::Rocket)
+        .collect();
+    assert!(heads.len() >= 8, "{}", heads.len());
+    assert!(heads.iter().all(|p| p.color.truncate().to_array() == gold));
+
+    // Budget levé : chaque gerbe allume tout son bloc
+    engine.force_explode_all();
+    let explosions = engine.count_particles_by_type(ParticleType::Explosion);
+    assert_eq!(explosions, engine.rockets_count() * 16);
+    assert!(explosions > 64);
+
+    // Après le final : palette et budget de la config
+    engine.stop_finale();
+    engine.clear_particles();
+    engine.spawn_n_rockets(4);
+    engine.update(DT);
+    assert!(engine
+        .iter_particles_by_type(ParticleType::Rocket)
+        .all(|p| p.color.truncate().to_array() == [0.2, 0.3, 1.0]));
+    assert_eq!(engine.get_config(), &config);
+}
+
+#[test]
+fn test_reload_during_finale_is_kept() {
+    let mut engine = PhysicEngineFireworks::with_seed(&paced_config(), WIDTH, 6);
+    engine.start_finale(FinaleSettings::new(1.0)).unwrap();
+    run(&mut engine, 0.5);
+
+    let reloaded = PhysicConfig {
+        rocket_interval_mean: 0.25,
+        rocket_max_next_interval: 0.25,
+        ..paced_config()
+    };
+    engine.reload_config(&reloaded);
+    assert!(engine.get_stats().finale.is_some());
+    run(&mut engine, 1.0);
+    assert!(engine.get_stats().finale.is_none());
+
+    // Les valeurs rechargées restent en place, à l'identique
+    assert_eq!(engine.get_config(), &reloaded);
+    let after = run(&mut engine, 2.0);
+    assert!((7..=9).contains(&after), "{}", after);
+}
+
+#[test]
+fn test_stop_and_restart_finale() {
+    let mut engine = PhysicEngineFireworks::with_seed(&paced_config(), WIDTH, 7);
+    assert!(!engine.stop_finale());
+
+    engine.start_finale(FinaleSettings::new(5.0)).unwrap();
+    run(&mut engine, 1.0);
+    // Un nouveau final remplace le précédent
+    engine.start_finale(FinaleSettings::new(2.0)).unwrap();
+    let progress = engine.get_stats().finale.unwrap();
+    assert_eq!((progress.elapsed, progress.duration), (0.0, 2.0));
+    assert_eq!(progress.launched, 0);
+
+    assert!(engine.stop_finale());
+    assert!(engine.get_stats().finale.is_none());
+    assert_eq!(engine.get_config(), &paced_config());
+
+    // Lancements coupés : le final n'en déclenche aucun
+    engine.clear_particles();
+    engine.set_launches_enabled(false);
+    engine.start_finale(FinaleSettings::new(1.0)).unwrap();
+    run(&mut engine, 0.5);
+    assert_eq!(engine.rockets_count(), 0);
+}
+
+// ==================================
+// 3. Commande console
+// ==================================
+
+#[test]
+fn test_physic_finale_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let physic = AnyPhysicEngine::new(
+        Box::new(PhysicEngineFireworks::with_seed(&paced_config(), WIDTH, 8)),
+        WIDTH,
+    );
+    let mut sim = Simulator::new(NullRendererEngine::new(), physic, DummyAudio);
+    sim.init_console_commands();
+
+    let mut exec = |lines: &str| {
+        let script = dir.path().join("finale.cfg");
+        std::fs::write(&script, lines).unwrap();
+        sim.exec_script(&ExecArgs {
+            path: script,
+            abort_on_error: false,
+        })
+        .unwrap()
+    };
+
+    let report = exec("physic.finale\nphysic.finale 8 big gold\nphysic.finale\n");
+    assert_eq!(report.failed, 0, "{:?}", report);
+    assert_eq!(
+        report.output,
+        [
+            "> physic.finale",
+            "No finale in progress",
+            "> physic.finale 8 big gold",
+            "Finale: 8.0 s, full explosions, palette gold",
+            "> physic.finale",
+            "Finale: 0.0 / 8.0 s, intensity 0.00, 0 rockets launched",
+        ]
+    );
+
+    let report = exec("physic.finale stop\nphysic.finale stop\n");
+    assert_eq!(report.failed, 0, "{:?}", report);
+    assert_eq!(report.output[1], "Finale stopped");
+    assert_eq!(report.output[3], "No finale in progress");
+
+    let report = exec("physic.finale soon\nphysic.finale 5 neon\nphysic.finale 0\n");
+    assert_eq!(report.failed, 3, "{:?}", report);
+    assert!(report.output[1].starts_with("Usage"));
+    assert!(report.output[3].starts_with("Unknown palette 'neon'"));
+    assert!(report.output[3].contains("gold"));
+    assert!(report.output[5].starts_with("Error"));
+}
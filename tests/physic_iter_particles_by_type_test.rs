@@ -1,9 +1,13 @@
 use fireworks_sim::physic_engine::{
     config::PhysicConfig,
+    particle::Particle,
     physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
     ParticleType, PhysicEngine, PhysicEngineIterator,
 };
 
+mod helpers;
+use helpers::DummyPhysic;
+
 /// Test que iter_particles_by_type retourne les particules de tête pour ParticleType::Rocket
 /// Ce test aurait détecté la régression où les têtes de fusées n'étaient pas visibles
 #[test]
@@ -78,7 +82,7 @@ fn test_iter_particles_by_type_returns_trails() {
 
     // Devrait avoir des particules de traînée
     assert!(
-        trail_particles.len() > 0,
+        !trail_particles.is_empty(),
         "Devrait avoir des particules de traînée après simulation"
     );
 
@@ -113,7 +117,7 @@ fn test_iter_particles_by_type_returns_explosions() {
 
     // Devrait avoir des particules d'explosion
     assert!(
-        explosion_particles.len() > 0,
+        !explosion_particles.is_empty(),
         "Devrait avoir des particules d'explosion après simulation"
     );
 
@@ -217,3 +221,126 @@ fn test_regression_rocket_particles_visible() {
         assert!(p.active, "Particule {} devrait être active", i);
     }
 }
+
+/// Vérifie que les compteurs incrémentaux correspondent au parcours complet, pour chaque type
+fn assert_counts_match_iteration<P: PhysicEngineIterator>(engine: &P, step: &str) {
+    let mut total = 0;
+    for t in ParticleType::ALL {
+        let iterated = engine.iter_particles_by_type(t).count();
+        assert_eq!(
+            engine.count_particles_by_type(t),
+            iterated,
+            "{}: compteur {:?} désynchronisé",
+            step,
+            t
+        );
+        total += iterated;
+    }
+    assert_eq!(engine.count_all_active(), total, "{}: total", step);
+}
+
+/// Compteurs cohérents avec l'itération au fil d'un scénario complet :
+/// lancements, explosions forcées et naturelles, extinction, purge
+#[test]
+fn test_counts_consistent_with_iteration() {
+    let config = PhysicConfig {
+        max_rockets: 32,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_counts_match_iteration(&engine, "initial");
+    assert_eq!(engine.count_all_active(), 0);
+
+    assert_eq!(engine.spawn_n_rockets(8), 8);
+    assert_counts_match_iteration(&engine, "spawn");
+    assert_eq!(engine.count_particles_by_type(ParticleType::Rocket), 8);
+
+    // Des trails apparaissent pendant la montée
+    for _ in 0..20 {
+        engine.update(0.016);
+    }
+    assert_counts_match_iteration(&engine, "ascension");
+    assert!(engine.count_particles_by_type(ParticleType::Trail) > 0);
+
+    // Explosion forcée : les têtes laissent place aux gerbes
+    engine.force_explode_all();
+    assert_counts_match_iteration(&engine, "explosion forcée");
+    assert_eq!(engine.count_particles_by_type(ParticleType::Rocket), 0);
+    assert!(engine.count_particles_by_type(ParticleType::Explosion) > 0);
+
+    // Nouvelle vague pendant que la précédente s'éteint (explosions naturelles)
+    engine.spawn_n_rockets(6);
+    for i in 0..600 {
+        engine.update(0.016);
+        if i % 25 == 0 {
+            assert_counts_match_iteration(&engine, &format!("update {}", i));
+        }
+    }
+    assert_counts_match_iteration(&engine, "extinction");
+    assert_eq!(engine.rockets_count(), 0);
+    assert_eq!(engine.count_all_active(), 0);
+
+    // Purge en plein vol : fusées retirées avant d'exploser
+    engine.spawn_n_rockets(5);
+    for _ in 0..10 {
+        engine.update(0.016);
+    }
+    engine.clear_particles();
+    assert_counts_match_iteration(&engine, "clear_particles");
+    assert_eq!(engine.count_all_active(), 0);
+
+    // Les emplacements libérés sont réutilisés sans fausser les compteurs
+    engine.spawn_n_rockets(3);
+    assert_counts_match_iteration(&engine, "réutilisation");
+    assert_eq!(engine.count_particles_by_type(ParticleType::Rocket), 3);
+
+    engine.close();
+    assert_eq!(engine.count_all_active(), 0);
+}
+
+/// Les statistiques exposent les mêmes compteurs que le trait
+#[test]
+fn test_stats_expose_counts() {
+    let config = PhysicConfig {
+        rocket_interval_mean: 100.0,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.spawn_n_rockets(4);
+    for _ in 0..20 {
+        engine.update(0.016);
+    }
+    engine.force_explode_all();
+    engine.spawn_n_rockets(2);
+
+    let counts = engine.get_stats().active_particles;
+    for t in ParticleType::ALL {
+        assert_eq!(counts.get(t), engine.count_particles_by_type(t));
+    }
+    assert_eq!(counts.rockets, 2);
+    assert_eq!(counts.total(), engine.count_all_active());
+}
+
+/// Implémentation par défaut du trait : comptage par parcours
+#[test]
+fn test_default_counts_iterate() {
+    let particle = |particle_type| Particle {
+        particle_type,
+        active: true,
+        ..Default::default()
+    };
+    let physic = DummyPhysic {
+        particles: vec![
+            particle(ParticleType::Rocket),
+            particle(ParticleType::Explosion),
+            particle(ParticleType::Explosion),
+            particle(ParticleType::Trail),
+        ],
+        ..Default::default()
+    };
+    assert_counts_match_iteration(&physic, "dummy");
+    assert_eq!(physic.count_particles_by_type(ParticleType::Explosion), 2);
+    assert_eq!(physic.count_particles_by_type(ParticleType::Smoke), 0);
+    assert_eq!(physic.count_all_active(), 4);
+}
@@ -0,0 +1,246 @@
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::particles_pools::{
+    ParticlesPool, ParticlesPoolsForRockets, PoolKind,
+};
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::rocket::Rocket;
+use fireworks_sim::physic_engine::{PhysicEngine, PhysicEngineIterator};
+use rand::SeedableRng;
+
+const PER_BLOCK: usize = 4;
+
+/// `count` fusées, chacune avec un bloc d'explosion marqué par son rang
+/// (position `(rang, i)` pour la particule `i` du bloc).
+fn rockets_with_blocks(pools: &mut ParticlesPoolsForRockets, count: usize) -> Vec<Rocket> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    (0..count)
+        .map(|rank| {
+            let mut rocket = Rocket::new(&mut rng);
+            let range = pools
+                .particles_pool_for_explosions
+                .allocate_block()
+                .unwrap();
+            let block = pools.access_mut(PoolKind::Explosions, &range);
+            for (i, p) in block.iter_mut().enumerate() {
+                p.pos.x = rank as f32;
+                p.pos.y = i as f32;
+                p.active = i % 2 == 0;
+            }
+            rocket.explosion_particle_indices = Some(range);
+            rocket
+        })
+        .collect()
+}
+
+/// Contenu (positions, activité) du bloc d'explosion d'une fusée
+fn block_contents(pools: &ParticlesPoolsForRockets, rocket: &Rocket) -> Vec<(f32, f32, bool)> {
+    let range = rocket.explosion_particle_indices.as_ref().unwrap();
+    pools
+        .access(PoolKind::Explosions, range)
+        .iter()
+        .map(|p| (p.pos.x, p.pos.y, p.active))
+        .collect()
+}
+
+/// Pool de 8 blocs dont les fusées de rang pair ont été libérées : blocs
+/// occupés 1, 3, 5, 7.
+fn fragmented_pools() -> (ParticlesPoolsForRockets, Vec<Rocket>) {
+    let mut pools = ParticlesPoolsForRockets::new(8, PER_BLOCK, 2);
+    let mut rockets = rockets_with_blocks(&mut pools, 8);
+    for rocket in rockets.iter_mut().step_by(2) {
+        pools.free_blocks(rocket);
+    }
+    rockets.retain(|r| r.explosion_particle_indices.is_some());
+    (pools, rockets)
+}
+
+// ==================================
+// Group 1: Fragmentation
+// ==================================
+
+#[test]
+fn test_fresh_pool_fills_from_the_front() {
+    let mut pool = ParticlesPool::new(4, PER_BLOCK);
+    assert_eq!(pool.allocate_block(), Some(0..4));
+    assert_eq!(pool.allocate_block(), Some(4..8));
+    assert_eq!(pool.fragmentation(), 0.0);
+
+    // Les blocs ajoutés par `grow` passent après les blocs déjà libres
+    pool.grow(6);
+    let starts: Vec<usize> = (0..4)
+        .map(|_| pool.allocate_block().unwrap().start)
+        .collect();
+    assert_eq!(starts, [8, 12, 16, 20]);
+    assert_eq!(pool.stats().fragmented_blocks, 0);
+}
+
+#[test]
+fn test_fragmentation_percentage() {
+    let (pools, _rockets) = fragmented_pools();
+    let stats = pools.particles_pool_for_explosions.stats();
+    assert_eq!(stats.blocks_free, 4);
+    // Blocs 5 et 7 hors des 4 premiers
+    assert_eq!(stats.fragmented_blocks, 2);
+    assert_eq!(stats.fragmentation(), 50.0);
+    assert_eq!(pools.fragmentation(), 50.0);
+
+    // Pool vide : aucune fragmentation
+    assert_eq!(ParticlesPool::new(3, PER_BLOCK).fragmentation(), 0.0);
+}
+
+// ==================================
+// Group 2: Compaction
+// ==================================
+
+#[test]
+fn test_compaction_keeps_rocket_contents() {
+    let (mut pools, mut rockets) = fragmented_pools();
+    let before: Vec<_> = rockets.iter().map(|r| block_contents(&pools, r)).collect();
+
+    let moved = pools.compact(usize::MAX, rockets.iter_mut());
+    assert_eq!(moved, 2);
+
+    let after: Vec<_> = rockets.iter().map(|r| block_contents(&pools, r)).collect();
+    assert_eq!(after, before);
+
+    // Zone active contiguë en tête du pool
+    let mut starts: Vec<usize> = rockets
+        .iter()
+        .map(|r| r.explosion_particle_indices.as_ref().unwrap().start)
+        .collect();
+    starts.sort();
+    assert_eq!(starts, [0, 4, 8, 12]);
+    assert_eq!(pools.fragmentation(), 0.0);
+
+    // Les blocs libérés sont servis du premier trou au dernier
+    let pool = &mut pools.particles_pool_for_explosions;
+    assert_eq!(pool.blocks_free(), 4);
+    assert_eq!(pool.allocate_block(), Some(16..20));
+    assert_eq!(pool.allocate_block(), Some(20..24));
+}
+
+#[test]
+fn test_moved_blocks_leave_no_live_particle_behind() {
+    let (mut pools, mut rockets) = fragmented_pools();
+    pools.compact(usize::MAX, rockets.iter_mut());
+    for start in [16, 20, 24, 28] {
+        let block = pools.access(PoolKind::Explosions, &(start..start + PER_BLOCK));
+        assert!(block.iter().all(|p| !p.active), "block {}", start);
+    }
+}
+
+#[test]
+fn test_compaction_is_amortized() {
+    let (mut pools, mut rockets) = fragmented_pools();
+    let before: Vec<_> = rockets.iter().map(|r| block_contents(&pools, r)).collect();
+
+    assert_eq!(pools.compact(1, rockets.iter_mut()), 1);
+    assert_eq!(pools.fragmentation(), 25.0);
+    assert_eq!(pools.compact(1, rockets.iter_mut()), 1);
+    assert_eq!(pools.fragmentation(), 0.0);
+    // Rien à déplacer : aucune fusée n'est touchée
+    assert_eq!(pools.compact(1, rockets.iter_mut()), 0);
+
+    let after: Vec<_> = rockets.iter().map(|r| block_contents(&pools, r)).collect();
+    assert_eq!(after, before);
+}
+
+// ==================================
+// Group 3: Engine
+// ==================================
+
+/// Lancements rapprochés ; `blocks_per_frame` = 0 désactive la compaction automatique.
+fn churn_config(threshold: f32, blocks_per_frame: usize) -> PhysicConfig {
+    PhysicConfig {
+        max_rockets: 48,
+        particles_per_explosion: 32,
+        rocket_interval_mean: 0.02,
+        rocket_interval_variation: 0.01,
+        rocket_max_next_interval: 0.02,
+        pool_compaction_threshold: threshold,
+        pool_compaction_blocks_per_frame: blocks_per_frame,
+        ..Default::default()
+    }
+}
+
+/// Particules actives, triées (comparaison indépendante de leur emplacement)
+fn particle_snapshot(engine: &PhysicEngineFireworks) -> Vec<String> {
+    let mut snapshot: Vec<String> = engine
+        .iter_active_particles()
+        .map(|p| format!("{:?}", p))
+        .collect();
+    snapshot.sort();
+    snapshot
+}
+
+/// Fait tourner le moteur puis coupe les lancements jusqu'à ce que les extinctions
+/// fragmentent les pools ; retourne `false` si les fusées s'éteignent avant.
+fn run_until_fragmented(engine: &mut PhysicEngineFireworks) -> bool {
+    for _ in 0..200 {
+        engine.update(0.016);
+    }
+    engine.set_launches_enabled(false);
+    for _ in 0..2000 {
+        engine.update(0.016);
+        let stats = engine.get_stats();
+        if stats.active_rockets == 0 {
+            return false;
+        }
+        if stats.pool_fragmentation > 0.0 {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn test_physic_compact_keeps_active_particles() {
+    let mut engine = PhysicEngineFireworks::new(&churn_config(25.0, 0), 1920.0);
+    assert!(run_until_fragmented(&mut engine));
+
+    let before = particle_snapshot(&engine);
+    let moved = engine.compact_pools();
+    assert!(moved > 0);
+    assert_eq!(engine.get_stats().pool_fragmentation, 0.0);
+    assert_eq!(particle_snapshot(&engine), before);
+    assert_eq!(engine.compact_pools(), 0);
+
+    // La simulation continue normalement sur les blocs déplacés
+    for _ in 0..50 {
+        engine.update(0.016);
+        let stats = engine.get_stats();
+        let trails = engine
+            .iter_active_particles()
+            .filter(|p| p.particle_type == fireworks_sim::physic_engine::ParticleType::Trail)
+            .count();
+        assert_eq!(stats.active_particles.trails, trails);
+    }
+}
+
+#[test]
+fn test_automatic_compaction_above_threshold() {
+    // Seuil nul et budget suffisant : les pools sont compactés à chaque frame
+    let mut engine = PhysicEngineFireworks::new(&churn_config(0.0, 64), 1920.0);
+    for _ in 0..200 {
+        engine.update(0.016);
+        assert_eq!(engine.get_stats().pool_fragmentation, 0.0);
+    }
+    engine.set_launches_enabled(false);
+    for _ in 0..300 {
+        engine.update(0.016);
+        assert_eq!(engine.get_stats().pool_fragmentation, 0.0);
+    }
+}
+
+#[test]
+fn test_compaction_config_validation() {
+    let config = PhysicConfig {
+        pool_compaction_threshold: 120.0,
+        ..Default::default()
+    };
+    assert!(config
+        .validate()
+        .iter()
+        .any(|v| v.starts_with("pool_compaction_threshold")));
+    assert!(PhysicConfig::default().validate().is_empty());
+}
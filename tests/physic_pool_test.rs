@@ -1,5 +1,12 @@
-use fireworks_sim::physic_engine::particles_pools::{ParticlesPoolsForRockets, PoolKind};
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::particles_pools::{
+    ParticlesPool, ParticlesPoolsForRockets, PoolKind,
+};
+use fireworks_sim::physic_engine::physic_engine_generational_arena::{
+    PhysicEngineFireworks, PhysicEngineTestHelpers,
+};
 use fireworks_sim::physic_engine::rocket::Rocket;
+use fireworks_sim::physic_engine::{ParticleType, PhysicEngine, PhysicEngineIterator};
 use rand::SeedableRng;
 
 #[test]
@@ -58,3 +65,188 @@ fn test_pool_allocation_and_freeing() {
     // The reclaimed block should be the one we just freed (LIFO usually, but implementation detail)
     // We just care that we got one.
 }
+
+#[test]
+fn test_pool_accounting() {
+    let mut pools = ParticlesPoolsForRockets::new(3, 8, 4);
+    let pool = &mut pools.particles_pool_for_explosions;
+    assert_eq!(pool.blocks_total(), 3);
+    assert_eq!(pool.blocks_free(), 3);
+
+    let _a = pool.allocate_block().unwrap();
+    let _b = pool.allocate_block().unwrap();
+    let _c = pool.allocate_block().unwrap();
+    assert_eq!(pool.blocks_free(), 0);
+    assert_eq!(pool.allocation_failures(), 0);
+
+    assert!(pool.allocate_block().is_none());
+    assert!(pool.allocate_block().is_none());
+    assert_eq!(pool.allocation_failures(), 2);
+    assert_eq!(pool.stats().utilization(), 1.0);
+    assert_eq!(pools.allocation_failures(), 2);
+}
+
+#[test]
+fn test_exhausted_explosion_pool_falls_back_to_trail_block() {
+    let config = PhysicConfig::default();
+    // Pool d'explosions minuscule : un seul bloc pour trois fusées
+    let mut pools = ParticlesPoolsForRockets {
+        particles_pool_for_explosions: ParticlesPool::new(1, 32),
+        particles_pool_for_trails: ParticlesPool::new(3, config.particles_per_trail),
+        active_counts: Default::default(),
+    };
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rockets: Vec<Rocket> = (0..3)
+        .map(|_| {
+            let mut r = Rocket::new(&mut rng);
+            r.reset(&config, 1920.0);
+            r
+        })
+        .collect();
+
+    // Simuler jusqu'à ce que toutes les fusées aient explosé
+    for _ in 0..1000 {
+        for r in &mut rockets {
+            r.update(0.016, &mut pools, &config);
+        }
+        if rockets.iter().all(|r| r.exploded) {
+            break;
+        }
+    }
+    assert!(rockets.iter().all(|r| r.exploded));
+
+    // Deux fusées n'ont pas obtenu de bloc d'explosion
+    assert!(pools.allocation_failures() >= 2);
+
+    // ... mais chaque explosion produit tout de même des particules visibles
+    for r in &rockets {
+        let explosion_particles = r
+            .iter_active_particles(&pools)
+            .filter(|p| p.particle_type == ParticleType::Explosion)
+            .count();
+        assert!(
+            explosion_particles > 0,
+            "Rocket {} exploded without any visible particle",
+            r.id
+        );
+    }
+}
+
+#[test]
+fn test_engine_stats_reports_pools() {
+    let config = PhysicConfig {
+        max_rockets: 4,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+
+    let stats = engine.get_stats();
+    assert_eq!(stats.active_rockets, 0);
+    assert_eq!(stats.explosions_pool.blocks_total, 4);
+    assert_eq!(stats.trails_pool.blocks_free, 4);
+    assert_eq!(stats.allocation_failures, 0);
+
+    engine.force_next_launch();
+    engine.update(0.016);
+
+    let stats = engine.get_stats();
+    assert_eq!(stats.active_rockets, 1);
+    assert_eq!(stats.trails_pool.blocks_free, 3);
+}
+
+/// Config sans lancement automatique, avec un budget d'explosion réduit
+fn budget_config(budget: usize) -> PhysicConfig {
+    PhysicConfig {
+        max_rockets: 6,
+        particles_per_explosion: 64,
+        max_active_explosion_particles: Some(budget),
+        min_explosion_particles: 8,
+        rocket_interval_mean: 1.0e6,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_explosion_budget_caps_active_count_with_min_stars() {
+    let config = budget_config(200);
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.spawn_n_rockets(6), 6);
+    engine.update(0.016);
+    engine.force_explode_all();
+
+    // Les particules d'une explosion partent toutes de la position de sa fusée
+    let mut per_explosion = std::collections::HashMap::new();
+    for p in engine
+        .iter_active_particles()
+        .filter(|p| p.particle_type == ParticleType::Explosion)
+    {
+        *per_explosion
+            .entry((p.pos.x.to_bits(), p.pos.y.to_bits()))
+            .or_insert(0usize) += 1;
+    }
+    assert_eq!(per_explosion.len(), 6);
+    assert!(per_explosion.values().all(|&n| n >= 8));
+
+    let total: usize = per_explosion.values().sum();
+    let stats = engine.get_stats();
+    assert_eq!(stats.active_particles.explosions, total);
+    assert!(total <= 200, "budget exceeded: {}", total);
+
+    // Le budget reste respecté pendant toute la vie des explosions
+    for _ in 0..200 {
+        engine.update(0.016);
+        let stats = engine.get_stats();
+        assert!(stats.active_particles.explosions <= 200);
+        let counted = engine
+            .iter_active_particles()
+            .filter(|p| p.particle_type == ParticleType::Explosion)
+            .count();
+        assert_eq!(stats.active_particles.explosions, counted);
+    }
+}
+
+#[test]
+fn test_active_counters_track_trails_until_rockets_die() {
+    let config = PhysicConfig {
+        max_rockets: 4,
+        rocket_interval_mean: 1.0e6,
+        rocket_interval_variation: 0.0,
+        rocket_max_next_interval: 1.0e6,
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.spawn_n_rockets(4);
+
+    for _ in 0..2000 {
+        engine.update(0.016);
+        let counts = engine.get_stats().active_particles;
+        let trails = engine
+            .iter_active_particles()
+            .filter(|p| p.particle_type == ParticleType::Trail)
+            .count();
+        assert_eq!(counts.trails, trails);
+        if engine.rockets_count() == 0 {
+            break;
+        }
+    }
+    assert_eq!(engine.rockets_count(), 0);
+    assert_eq!(engine.get_stats().active_particles.total(), 0);
+}
+
+#[test]
+fn test_trail_budget_limits_active_trails() {
+    let config = PhysicConfig {
+        max_active_trail_particles: Some(50),
+        ..budget_config(usize::MAX)
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.spawn_n_rockets(6);
+    for _ in 0..30 {
+        engine.update(0.016);
+        assert!(engine.get_stats().active_particles.trails <= 50);
+    }
+    assert!(engine.get_stats().active_particles.trails > 0);
+}
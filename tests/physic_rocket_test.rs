@@ -1,6 +1,7 @@
 use fireworks_sim::physic_engine::config::PhysicConfig;
 use fireworks_sim::physic_engine::particles_pools::ParticlesPoolsForRockets;
 use fireworks_sim::physic_engine::rocket::Rocket;
+use fireworks_sim::physic_engine::ParticleType;
 use rand::SeedableRng;
 
 // ==================================
@@ -45,11 +46,13 @@ fn test_random_color_in_valid_range() {
 
 #[test]
 fn test_random_vel_respects_config() {
-    let mut config = PhysicConfig::default();
-    config.spawn_rocket_vertical_angle = std::f32::consts::FRAC_PI_2; // π/2 (vertical)
-    config.spawn_rocket_angle_variation = 0.3; // ±0.3 rad
-    config.spawn_rocket_min_speed = 350.0;
-    config.spawn_rocket_max_speed = 500.0;
+    let config = PhysicConfig {
+        spawn_rocket_vertical_angle: std::f32::consts::FRAC_PI_2, // π/2 (vertical)
+        spawn_rocket_angle_variation: 0.3,                        // ±0.3 rad
+        spawn_rocket_min_speed: 350.0,
+        spawn_rocket_max_speed: 500.0,
+        ..Default::default()
+    };
 
     let mut rng = rand::rngs::StdRng::seed_from_u64(42);
     let mut rocket = Rocket::new(&mut rng);
@@ -80,23 +83,27 @@ fn test_random_vel_different_configs() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(42);
 
     // Config 1: Vitesse lente
-    let mut config_slow = PhysicConfig::default();
-    config_slow.spawn_rocket_min_speed = 100.0;
-    config_slow.spawn_rocket_max_speed = 200.0;
+    let config_slow = PhysicConfig {
+        spawn_rocket_min_speed: 100.0,
+        spawn_rocket_max_speed: 200.0,
+        ..Default::default()
+    };
 
     let mut rocket = Rocket::new(&mut rng);
     rocket.reset(&config_slow, 1920.0);
     let speed_slow = rocket.vel.length();
-    assert!(speed_slow >= 100.0 && speed_slow <= 200.0);
+    assert!((100.0..=200.0).contains(&speed_slow));
 
     // Config 2: Vitesse rapide
-    let mut config_fast = PhysicConfig::default();
-    config_fast.spawn_rocket_min_speed = 600.0;
-    config_fast.spawn_rocket_max_speed = 800.0;
+    let config_fast = PhysicConfig {
+        spawn_rocket_min_speed: 600.0,
+        spawn_rocket_max_speed: 800.0,
+        ..Default::default()
+    };
 
     rocket.reset(&config_fast, 1920.0);
     let speed_fast = rocket.vel.length();
-    assert!(speed_fast >= 600.0 && speed_fast <= 800.0);
+    assert!((600.0..=800.0).contains(&speed_fast));
 }
 
 // ==================================
@@ -344,3 +351,222 @@ fn test_rocket_full_lifecycle() {
         "Deactivation should happen after explosion"
     );
 }
+
+// ==================================
+// 7. Tests du mode pseudo-3D (profondeur)
+// ==================================
+
+#[test]
+fn test_reset_depth_disabled_is_zero() {
+    let config = PhysicConfig::default();
+    assert!(!config.depth_enabled);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rocket = Rocket::new(&mut rng);
+    for _ in 0..50 {
+        rocket.reset(&config, 1920.0);
+        assert_eq!(rocket.depth, 0.0);
+    }
+}
+
+#[test]
+fn test_reset_depth_sampled_in_range_and_inherited() {
+    let config = PhysicConfig {
+        depth_enabled: true,
+        spawn_rocket_min_depth: 100.0,
+        spawn_rocket_max_depth: 900.0,
+        ..Default::default()
+    };
+
+    let mut pools = ParticlesPoolsForRockets::new(4, 16, 16);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let mut rocket = Rocket::new(&mut rng);
+
+    for _ in 0..50 {
+        rocket.reset(&config, 1920.0);
+        assert!(
+            (100.0..=900.0).contains(&rocket.depth),
+            "Depth out of range: {}",
+            rocket.depth
+        );
+    }
+
+    // Les particules (tête + trails) héritent de la profondeur de la fusée
+    rocket.update(0.016, &mut pools, &config);
+    assert_eq!(rocket.head_particle().depth, rocket.depth);
+    assert!(rocket
+        .iter_active_particles(&pools)
+        .all(|p| p.depth == rocket.depth));
+}
+
+// ==================================
+// 8. Tests de configuration des trails
+// ==================================
+
+/// Nombre de particules de trail spawnées après un pas de simulation,
+/// pour une fusée lancée verticalement à vitesse fixe.
+fn trail_spawn_count(config: &PhysicConfig) -> usize {
+    let mut pools = ParticlesPoolsForRockets::new(1, 16, config.trail_block_size());
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(config, 1920.0);
+    rocket.vel = glam::Vec2::new(0.0, 1000.0);
+
+    rocket.update(0.05, &mut pools, config);
+    rocket
+        .iter_active_particles(&pools)
+        .filter(|p| p.particle_type == ParticleType::Trail)
+        .count()
+}
+
+#[test]
+fn test_doubling_trail_spacing_halves_spawn_count() {
+    let config = PhysicConfig {
+        trail_spacing: 2.0,
+        ..Default::default()
+    };
+    let config_double = PhysicConfig {
+        trail_spacing: 4.0,
+        ..config.clone()
+    };
+
+    let count = trail_spawn_count(&config);
+    let count_double = trail_spawn_count(&config_double);
+
+    assert!(count > 0);
+    assert!(
+        count.abs_diff(2 * count_double) <= 1,
+        "spacing ×2 should halve spawn count: {} vs {}",
+        count,
+        count_double
+    );
+}
+
+#[test]
+fn test_trail_particles_use_config_life_and_size() {
+    let config = PhysicConfig {
+        trail_particle_life: 0.8,
+        trail_particle_size: 5.0,
+        trail_length_multiplier_min: 2.0,
+        trail_length_multiplier_max: 2.0,
+        ..Default::default()
+    };
+    let mut pools = ParticlesPoolsForRockets::new(1, 16, config.trail_block_size());
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(&config, 1920.0);
+    assert_eq!(rocket.trail_length_multiplier, 2.0);
+
+    rocket.update(0.016, &mut pools, &config);
+    let trail: Vec<_> = rocket.iter_active_particles(&pools).collect();
+    assert!(!trail.is_empty());
+    for p in trail {
+        assert_eq!(p.max_life, 1.6);
+        assert_eq!(p.size, 5.0);
+    }
+}
+
+#[test]
+fn test_trail_block_size_derived_from_config() {
+    let config = PhysicConfig {
+        spawn_rocket_max_speed: 500.0,
+        trail_particle_life: 0.4,
+        trail_spacing: 2.0,
+        particles_per_trail: 1000,
+        ..Default::default()
+    };
+    // 500 × 0.4 / 2 = 100 (+1 pour l'arrondi)
+    assert_eq!(config.implied_trail_particles(), 101);
+    assert_eq!(config.trail_block_size(), 101);
+    assert!(config.check_trail_budget().is_none());
+
+    // Plafond trop bas : bloc tronqué + avertissement
+    let capped = PhysicConfig {
+        particles_per_trail: 32,
+        ..config
+    };
+    assert_eq!(capped.trail_block_size(), 32);
+    assert!(capped.check_trail_budget().is_some());
+}
+
+/// Vitesses des particules de trail après un pas, fusée verticale à vitesse fixe.
+fn trail_velocities(config: &PhysicConfig) -> (glam::Vec2, Vec<glam::Vec2>) {
+    let mut pools = ParticlesPoolsForRockets::new(1, 16, config.trail_block_size());
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(config, 1920.0);
+    rocket.vel = glam::Vec2::new(0.0, 400.0);
+
+    rocket.update(0.05, &mut pools, config);
+    let vels = rocket
+        .iter_active_particles(&pools)
+        .filter(|p| p.particle_type == ParticleType::Trail)
+        .map(|p| p.vel)
+        .collect();
+    (rocket.vel, vels)
+}
+
+#[test]
+fn test_trail_particles_inherit_rocket_velocity() {
+    let base = PhysicConfig {
+        trail_lateral_jitter: 0.0,
+        trail_velocity_inherit: 0.0,
+        ..Default::default()
+    };
+    let inherit = PhysicConfig {
+        trail_velocity_inherit: 0.5,
+        ..base.clone()
+    };
+
+    // Sans héritage, seule la gravité du pas courant s'applique
+    let (_, vels_base) = trail_velocities(&base);
+    let (rocket_vel, vels) = trail_velocities(&inherit);
+    assert!(!vels.is_empty());
+    assert_eq!(vels.len(), vels_base.len());
+
+    for (v, v0) in vels.iter().zip(&vels_base) {
+        assert!(v.length() > 0.0);
+        let inherited = *v - *v0;
+        assert!(
+            (inherited - rocket_vel * 0.5).length() < 1e-3,
+            "expected {:?}, got {:?}",
+            rocket_vel * 0.5,
+            inherited
+        );
+    }
+}
+
+#[test]
+fn test_trail_lateral_jitter_is_perpendicular_and_bounded() {
+    let config = PhysicConfig {
+        trail_velocity_inherit: 0.0,
+        trail_lateral_jitter: 10.0,
+        ..Default::default()
+    };
+    let (_, vels) = trail_velocities(&config);
+    assert!(!vels.is_empty());
+    // Fusée verticale : la dispersion est horizontale
+    assert!(vels.iter().all(|v| v.x.abs() <= 10.0));
+    assert!(vels.iter().any(|v| v.x != 0.0));
+}
+
+#[test]
+fn test_trail_color_blends_toward_end_color() {
+    let config = PhysicConfig {
+        trail_end_color: [0.0, 0.0, 0.0, 1.0],
+        ..Default::default()
+    };
+    let mut pools = ParticlesPoolsForRockets::new(1, 16, config.trail_block_size());
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(&config, 1920.0);
+
+    rocket.update(0.016, &mut pools, &config);
+    for p in rocket.iter_active_particles(&pools) {
+        let age = 1.0 - p.life / p.max_life;
+        let expected = rocket.color.lerp(glam::Vec4::new(0.0, 0.0, 0.0, 1.0), age);
+        assert!((p.color - expected).length() < 1e-4);
+        // En vieillissant, la particule s'assombrit
+        assert!(p.color.x <= rocket.color.x);
+    }
+}
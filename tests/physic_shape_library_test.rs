@@ -0,0 +1,211 @@
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig,
+    explosion_shape::{ExplosionShape, ImageShape, ImageShapeSettings},
+    physic_engine_generational_arena::PhysicEngineFireworks,
+    shape_library::ShapeLibrary,
+    PhysicEngine,
+};
+use image::{Rgba, RgbaImage};
+use std::path::Path;
+
+const SAMPLES: usize = 64;
+
+/// Génère une petite image : fond transparent, pixels `lit` blancs et opaques.
+fn write_png(path: &Path, size: u32, lit: impl Fn(u32, u32) -> bool) {
+    let img = RgbaImage::from_fn(size, size, |x, y| {
+        if lit(x, y) {
+            Rgba([255, 255, 255, 255])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    });
+    img.save(path).unwrap();
+}
+
+fn write_test_shapes(dir: &Path) {
+    // Croix
+    write_png(&dir.join("cross.png"), 9, |x, y| x == 4 || y == 4);
+    // Carré plein, avec sidecar
+    write_png(&dir.join("square.png"), 8, |x, y| {
+        (2..6).contains(&x) && (2..6).contains(&y)
+    });
+    std::fs::write(
+        dir.join("square.toml"),
+        "scale = 0.5\nflight_time = 2.0\nweight = 3.0\n",
+    )
+    .unwrap();
+}
+
+// ==================================
+// 1. ImageShape
+// ==================================
+
+#[test]
+fn test_image_shape_points_inside_unit_disc() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_shapes(dir.path());
+
+    let cross =
+        ImageShape::from_image(dir.path().join("cross.png"), SAMPLES, Default::default()).unwrap();
+    assert_eq!(cross.name, "cross");
+    assert_eq!(cross.sampled_points.len(), SAMPLES);
+    assert!(cross
+        .sampled_points
+        .iter()
+        .all(|p| p.length() <= 1.0 + 1e-5));
+    // Les extrémités de la croix atteignent le bord du disque unité
+    let max_norm = cross
+        .sampled_points
+        .iter()
+        .map(|p| p.length())
+        .fold(0.0_f32, f32::max);
+    assert!((max_norm - 1.0).abs() < 1e-5);
+
+    let settings = ImageShapeSettings {
+        scale: 0.5,
+        flight_time: 2.0,
+        weight: 1.0,
+    };
+    let square = ImageShape::from_image(dir.path().join("square.png"), SAMPLES, settings).unwrap();
+    assert!(square
+        .sampled_points
+        .iter()
+        .all(|p| p.length() <= 0.5 + 1e-5));
+    assert!((square.speed() * 2.0 - cross.speed()).abs() < 1e-3);
+}
+
+#[test]
+fn test_image_shape_rejects_empty_or_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let empty = dir.path().join("empty.png");
+    write_png(&empty, 4, |_, _| false);
+    assert!(ImageShape::from_image(&empty, SAMPLES, Default::default()).is_err());
+
+    let bad_scale = ImageShapeSettings {
+        scale: 2.0,
+        ..Default::default()
+    };
+    write_test_shapes(dir.path());
+    assert!(ImageShape::from_image(dir.path().join("cross.png"), SAMPLES, bad_scale).is_err());
+}
+
+// ==================================
+// 2. ShapeLibrary
+// ==================================
+
+#[test]
+fn test_shape_library_loads_stems_and_weights() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_shapes(dir.path());
+
+    let mut library = ShapeLibrary::new(dir.path());
+    assert_eq!(library.scan(SAMPLES), 2);
+
+    let shapes = library.shapes();
+    let names: Vec<&str> = shapes.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["cross", "square"]);
+    assert_eq!(shapes[0].settings, ImageShapeSettings::default());
+    assert_eq!(shapes[1].settings.weight, 3.0);
+    assert_eq!(shapes[1].settings.scale, 0.5);
+    assert_eq!(shapes[1].settings.flight_time, 2.0);
+
+    assert!(matches!(
+        library.explosion_shape(),
+        Some(ExplosionShape::MultiImage(set)) if set.len() == 2
+    ));
+}
+
+#[test]
+fn test_shape_library_skips_invalid_files() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_shapes(dir.path());
+    // Fichier non décodable et sidecar invalide : ignorés, les autres restent chargés
+    std::fs::write(dir.path().join("broken.png"), b"not a png").unwrap();
+    write_png(&dir.path().join("dot.png"), 4, |x, y| x == 1 && y == 1);
+    std::fs::write(dir.path().join("dot.toml"), "weight = -1.0\n").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+    let mut library = ShapeLibrary::new(dir.path());
+    assert_eq!(library.scan(SAMPLES), 2);
+}
+
+#[test]
+fn test_shape_library_missing_dir_is_empty() {
+    let mut library = ShapeLibrary::new("/nonexistent/shapes/dir");
+    assert_eq!(library.scan(SAMPLES), 0);
+    assert!(library.explosion_shape().is_none());
+}
+
+// ==================================
+// 3. Intégration moteur
+// ==================================
+
+#[test]
+fn test_engine_installs_library_and_rescans() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = PhysicConfig {
+        max_rockets: 4,
+        shapes_dir: dir.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    };
+
+    // Répertoire vide au démarrage : gerbe sphérique
+    let mut engine = PhysicEngineFireworks::from_options(&config, &AppOptions::default());
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+
+    // Images déposées à chaud puis "physic.shape.rescan"
+    write_test_shapes(dir.path());
+    assert_eq!(engine.rescan_shapes().unwrap(), 2);
+    assert_eq!(engine.explosion_shape_name(), "images");
+
+    // Démarrage avec des images présentes
+    let engine = PhysicEngineFireworks::from_options(&config, &AppOptions::default());
+    assert_eq!(engine.explosion_shape_name(), "images");
+}
+
+#[test]
+fn test_rescan_empty_dir_falls_back_to_sphere() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_shapes(dir.path());
+    let mut config = PhysicConfig {
+        max_rockets: 4,
+        shapes_dir: dir.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::from_options(&config, &AppOptions::default());
+    assert_eq!(engine.explosion_shape_name(), "images");
+
+    // Images retirées du disque : plus de forme "images" périmée
+    for entry in std::fs::read_dir(dir.path()).unwrap() {
+        std::fs::remove_file(entry.unwrap().path()).unwrap();
+    }
+    assert_eq!(engine.rescan_shapes().unwrap(), 0);
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+
+    // Idem après un changement de répertoire vers un dossier vide
+    write_test_shapes(dir.path());
+    assert_eq!(engine.rescan_shapes().unwrap(), 2);
+    let empty = tempfile::tempdir().unwrap();
+    config.shapes_dir = empty.path().to_string_lossy().into_owned();
+    engine.reload_config(&config);
+    assert_eq!(engine.rescan_shapes().unwrap(), 0);
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+}
+
+#[test]
+fn test_engine_constructor_does_not_scan_shapes() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_shapes(dir.path());
+    let config = PhysicConfig {
+        max_rockets: 4,
+        shapes_dir: dir.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    };
+
+    // Tests, benchs et `physic.engine` : aucune lecture disque, forme par défaut
+    let engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+    let engine = PhysicEngineFireworks::with_seed(&config, 1920.0, 42);
+    assert_eq!(engine.explosion_shape_name(), "sphere");
+}
@@ -0,0 +1,112 @@
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::particles_pools::ParticlesPoolsForRockets;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::{
+    PhysicEngineFireworks, PhysicEngineTestHelpers,
+};
+use fireworks_sim::physic_engine::rocket::Rocket;
+use fireworks_sim::physic_engine::{Particle, ParticleType, PhysicEngine, PhysicEngineIterator};
+use rand::SeedableRng;
+
+/// Trail court (8 slots) et longue durée de vie : le ring boucle plusieurs fois
+fn wrapping_trail_config() -> PhysicConfig {
+    PhysicConfig {
+        particles_per_trail: 8,
+        trail_particle_life: 10.0,
+        trail_lateral_jitter: 0.0,
+        ..PhysicConfig::default()
+    }
+}
+
+// ==================================
+// 1. Ordre de la polyligne d'une fusée
+// ==================================
+
+#[test]
+fn test_trail_polyline_is_ordered_oldest_to_newest_across_ring_wrap() {
+    let config = wrapping_trail_config();
+    let mut pools = ParticlesPoolsForRockets::new(4, config.particles_per_explosion, 8);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(&config, 1920.0);
+
+    // Assez de frames pour remplir le ring puis en réécrire une partie
+    let mut wrapped = false;
+    for _ in 0..200 {
+        rocket.update(0.016, &mut pools, &config);
+        if rocket.exploded {
+            break;
+        }
+        let spawned = rocket
+            .iter_trail_polyline(&pools)
+            .filter(|p| p.particle_type == ParticleType::Trail)
+            .count();
+        if spawned == 8 && rocket.trail_index != 0 {
+            wrapped = true;
+            break;
+        }
+    }
+    assert!(wrapped, "le ring de trail aurait dû boucler");
+
+    let polyline: Vec<Particle> = rocket.iter_trail_polyline(&pools).copied().collect();
+    assert_eq!(polyline.len(), 8);
+
+    // Vie restante croissante : de la plus ancienne à la plus récente
+    for pair in polyline.windows(2) {
+        assert!(
+            pair[0].life <= pair[1].life,
+            "ordre incorrect : {} puis {}",
+            pair[0].life,
+            pair[1].life
+        );
+    }
+    // Le dernier point est le dernier posé
+    let newest = polyline.last().unwrap();
+    assert!(newest.pos.distance(rocket.last_trail_pos) < 1.0);
+    // Le premier est le plus éloigné de la tête
+    let head = rocket.head_particle().pos;
+    assert!(polyline[0].pos.distance(head) > newest.pos.distance(head));
+}
+
+#[test]
+fn test_trail_polyline_empty_before_first_update() {
+    let config = wrapping_trail_config();
+    let pools = ParticlesPoolsForRockets::new(4, config.particles_per_explosion, 8);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let mut rocket = Rocket::new(&mut rng);
+    rocket.reset(&config, 1920.0);
+    assert_eq!(rocket.iter_trail_polyline(&pools).count(), 0);
+}
+
+// ==================================
+// 2. Polylignes du moteur
+// ==================================
+
+#[test]
+fn test_engine_yields_one_trail_polyline_per_active_rocket() {
+    let config = wrapping_trail_config();
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    assert_eq!(engine.iter_trail_polylines().count(), 0);
+
+    assert_eq!(engine.spawn_n_rockets(3), 3);
+    for _ in 0..10 {
+        engine.update(0.016);
+    }
+
+    let polylines: Vec<Vec<&Particle>> = engine
+        .iter_trail_polylines()
+        .map(|polyline| polyline.collect())
+        .collect();
+    assert_eq!(polylines.len(), engine.rockets_count());
+    for polyline in &polylines {
+        assert!(!polyline.is_empty());
+        assert!(polyline
+            .iter()
+            .all(|p| p.active && p.particle_type == ParticleType::Trail));
+    }
+    // Mêmes particules que le flux par type
+    let total: usize = polylines.iter().map(Vec::len).sum();
+    assert_eq!(
+        total,
+        engine.iter_particles_by_type(ParticleType::Trail).count()
+    );
+}
@@ -0,0 +1,140 @@
+use fireworks_sim::profiler::Profiler;
+use fireworks_sim::profiler_export::{MetricsFormat, PERIODIC_CSV_HEADER, SUMMARY_CSV_HEADER};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Profiler rempli : un bloc mesuré, deux métriques et trois frames
+fn populated_profiler() -> Profiler {
+    let profiler = Profiler::new(100);
+    for _ in 0..3 {
+        let _frame = profiler.frame();
+        profiler.profile_block("physic - update", || {
+            std::thread::sleep(Duration::from_micros(200))
+        });
+    }
+    for n in [10usize, 20, 30, 40] {
+        profiler.record_metric("total particles drawn", n);
+    }
+    profiler.record_metric("gpu sync wait", Duration::from_millis(2));
+    profiler
+}
+
+// ==================================
+// 1. Résumé complet
+// ==================================
+
+#[test]
+fn test_export_summary_csv_and_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let profiler = populated_profiler();
+
+    let csv_path = dir.path().join("nested/metrics.csv");
+    profiler
+        .export_summary(&csv_path, MetricsFormat::Csv)
+        .unwrap();
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], SUMMARY_CSV_HEADER);
+    // Une ligne par série, triées par nom
+    let names: Vec<&str> = lines[1..]
+        .iter()
+        .map(|l| l.split(',').next().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "frame",
+            "gpu sync wait",
+            "physic - update",
+            "total particles drawn"
+        ]
+    );
+    let particles: Vec<&str> = lines[4].split(',').collect();
+    assert_eq!(particles.len(), SUMMARY_CSV_HEADER.split(',').count());
+    assert_eq!(particles[1], "4");
+    assert_eq!(particles[2].parse::<f32>().unwrap(), 25.0);
+    assert_eq!(particles[3].parse::<f32>().unwrap(), 10.0);
+    assert_eq!(particles[7].parse::<f32>().unwrap(), 40.0);
+
+    let json_path = dir.path().join("metrics.json");
+    assert_eq!(MetricsFormat::from_path(&json_path), MetricsFormat::Json);
+    profiler
+        .export_summary(&json_path, MetricsFormat::from_path(&json_path))
+        .unwrap();
+    let doc: Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert!(doc["fps"].as_f64().unwrap() > 0.0);
+    let metrics = doc["metrics"].as_array().unwrap();
+    assert_eq!(metrics.len(), 4);
+    let gpu = metrics
+        .iter()
+        .find(|m| m["metric"] == "gpu sync wait")
+        .unwrap();
+    assert_eq!(gpu["count"], 1);
+    assert!((gpu["p99"].as_f64().unwrap() - 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_metrics_format_parsing() {
+    assert_eq!("CSV".parse::<MetricsFormat>().unwrap(), MetricsFormat::Csv);
+    assert_eq!(
+        "json".parse::<MetricsFormat>().unwrap(),
+        MetricsFormat::Json
+    );
+    assert!("xml".parse::<MetricsFormat>().is_err());
+    assert_eq!(
+        MetricsFormat::from_path("out/run.jsonl"),
+        MetricsFormat::Json
+    );
+    assert_eq!(MetricsFormat::from_path("out/run.txt"), MetricsFormat::Csv);
+}
+
+// ==================================
+// 2. Export périodique
+// ==================================
+
+#[test]
+fn test_periodic_append_rows() {
+    let dir = tempfile::tempdir().unwrap();
+    let profiler = populated_profiler();
+    // Sans export actif : aucun effet
+    profiler.flush_periodic().unwrap();
+
+    let csv_path = dir.path().join("periodic.csv");
+    profiler
+        .set_periodic_export(&csv_path, MetricsFormat::Csv)
+        .unwrap();
+    profiler.flush_periodic().unwrap();
+    profiler.flush_periodic().unwrap();
+    // Réouverture d'un fichier existant : pas de second en-tête
+    profiler
+        .set_periodic_export(&csv_path, MetricsFormat::Csv)
+        .unwrap();
+    profiler.flush_periodic().unwrap();
+
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], PERIODIC_CSV_HEADER);
+    for row in &lines[1..] {
+        let fields: Vec<&str> = row.split(',').collect();
+        assert_eq!(fields.len(), PERIODIC_CSV_HEADER.split(',').count());
+        assert_eq!(fields[1], "3");
+        assert!(fields.iter().all(|f| f.parse::<f32>().is_ok()), "{}", row);
+    }
+
+    // JSON Lines : un objet complet par intervalle
+    let jsonl_path = dir.path().join("periodic.jsonl");
+    profiler
+        .set_periodic_export(&jsonl_path, MetricsFormat::Json)
+        .unwrap();
+    profiler.flush_periodic().unwrap();
+    profiler.flush_periodic().unwrap();
+    let rows: Vec<Value> = std::fs::read_to_string(&jsonl_path)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["frames"], 3);
+    assert_eq!(rows[1]["metrics"].as_array().unwrap().len(), 4);
+}
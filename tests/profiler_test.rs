@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use fireworks_sim::physic_engine::{ParticleType, PhysicStats};
+use fireworks_sim::profiler::{
+    ascii_histogram, histogram_buckets, percentile_nearest_rank, Percentiles, Profiler,
+};
+use fireworks_sim::profiler_scopes::{format_scope_tree, ScopeTree};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::hud::HudStats;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Historique des séries
+// ==================================
+
+#[test]
+fn test_history_of_metric_is_bounded_and_ordered() {
+    let profiler = Profiler::new(5);
+    for i in 0..8usize {
+        profiler.record_metric("particles", i);
+    }
+    // Seules les `max_samples` dernières valeurs, de la plus ancienne à la plus récente
+    assert_eq!(profiler.history("particles"), vec![3.0, 4.0, 5.0, 6.0, 7.0]);
+}
+
+#[test]
+fn test_history_converts_durations_to_ms() {
+    let profiler = Profiler::new(10);
+    profiler.record_metric("wait", Duration::from_micros(2500));
+    let history = profiler.history("wait");
+    assert_eq!(history.len(), 1);
+    assert!((history[0] - 2.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_history_of_block_and_unknown_label() {
+    let profiler = Profiler::new(10);
+    profiler.profile_block("update", || ());
+    profiler.profile_block("update", || ());
+    let history = profiler.history("update");
+    assert_eq!(history.len(), 2);
+    assert!(history.iter().all(|&ms| ms >= 0.0));
+
+    assert!(profiler.history("missing").is_empty());
+}
+
+#[test]
+fn test_frame_times_history() {
+    let profiler = Profiler::new(3);
+    assert!(profiler.frame_times().is_empty());
+    for _ in 0..5 {
+        let _frame = profiler.frame();
+    }
+    assert_eq!(profiler.frame_times().len(), 3);
+}
+
+// ==================================
+// 2. HUD
+// ==================================
+
+#[test]
+fn test_hud_stats_collect() {
+    let profiler = Profiler::new(10);
+    drop(profiler.frame());
+    let mut physic = PhysicStats {
+        active_rockets: 6,
+        ..Default::default()
+    };
+    // Deux fusées ont déjà explosé : quatre têtes encore en vol
+    physic.active_particles.rockets = 4;
+    physic.active_particles.explosions = 120;
+
+    let stats = HudStats::collect(60.0, &profiler, &physic, 3, &RendererConfig::default());
+    assert_eq!(stats.frame_times.len(), 1);
+    assert_eq!(stats.audio_voices, 3);
+    assert_eq!(stats.active_rockets, 6);
+    assert!(stats.particles.contains(&(ParticleType::Rocket, 4)));
+    assert!(stats.particles.contains(&(ParticleType::Explosion, 120)));
+    assert!(!stats.bloom_enabled);
+}
+
+#[test]
+fn test_hud_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    assert!(!shared.hud_visible.get());
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "renderer.hud"),
+        "HUD: on"
+    );
+    assert!(shared.hud_visible.get());
+    assert_eq!(
+        registry.execute(&mut audio, &mut physic, "renderer.hud off"),
+        "HUD: off"
+    );
+    let out = registry.execute(&mut audio, &mut physic, "renderer.hud big");
+    assert!(out.starts_with("Usage"), "{}", out);
+}
+
+// ==================================
+// 3. Percentiles et histogramme
+// ==================================
+
+#[test]
+fn test_percentiles_nearest_rank() {
+    // 1..=100 : le percentile p vaut exactement p
+    let series: Vec<f32> = (1..=100).rev().map(|v| v as f32).collect();
+    let p = Percentiles::from_series(&series).unwrap();
+    assert_eq!(
+        (p.min, p.p50, p.p95, p.p99, p.max),
+        (1.0, 50.0, 95.0, 99.0, 100.0)
+    );
+
+    // Petite série : rang ⌈p·n⌉ (n = 5 → p50 = 3e, p95 = p99 = 5e valeur)
+    let sorted = [10.0, 20.0, 30.0, 40.0, 50.0];
+    assert_eq!(percentile_nearest_rank(&sorted, 50.0), 30.0);
+    assert_eq!(percentile_nearest_rank(&sorted, 95.0), 50.0);
+    assert_eq!(percentile_nearest_rank(&sorted, 0.0), 10.0);
+    assert!(Percentiles::from_series(&[]).is_none());
+
+    // 1 % de frames lentes : invisibles dans la médiane, visibles au p99
+    let mut frames = vec![16.0f32; 99];
+    frames.push(80.0);
+    let p = Percentiles::from_series(&frames).unwrap();
+    assert_eq!((p.p50, p.p95, p.p99, p.max), (16.0, 16.0, 16.0, 80.0));
+    frames.push(80.0);
+    assert_eq!(Percentiles::from_series(&frames).unwrap().p99, 80.0);
+}
+
+#[test]
+fn test_profiler_percentiles_follow_per_metric_window() {
+    let profiler = Profiler::new(200);
+    profiler.set_window("voices", 4);
+    for i in 1..=10usize {
+        profiler.record_metric("voices", i);
+        profiler.record_metric("particles", i);
+    }
+    assert_eq!(profiler.history("voices"), vec![7.0, 8.0, 9.0, 10.0]);
+    assert_eq!(profiler.history("particles").len(), 10);
+    let p = profiler.percentiles("voices").unwrap();
+    assert_eq!((p.min, p.p50, p.max), (7.0, 8.0, 10.0));
+
+    // Réduire la fenêtre oublie les valeurs les plus anciennes
+    profiler.set_window("particles", 3);
+    assert_eq!(profiler.history("particles"), vec![8.0, 9.0, 10.0]);
+    assert!(profiler.percentiles("unknown").is_none());
+}
+
+#[test]
+fn test_histogram_bucketing() {
+    let series = [0.0, 1.0, 2.5, 3.0, 9.9, 10.0];
+    let buckets = histogram_buckets(&series, 5);
+    assert_eq!(buckets.len(), 5);
+    let counts: Vec<usize> = buckets.iter().map(|(_, _, c)| *c).collect();
+    // Classes de largeur 2 : [0,2) [2,4) [4,6) [6,8) [8,10] (le max dans la dernière)
+    assert_eq!(counts, [2, 2, 0, 0, 2]);
+    assert_eq!((buckets[0].0, buckets[4].1), (0.0, 10.0));
+
+    // Série constante : une seule classe
+    assert_eq!(histogram_buckets(&[5.0; 3], 8), vec![(5.0, 5.0, 3)]);
+    assert!(histogram_buckets(&[], 8).is_empty());
+
+    let text = ascii_histogram(&series, 5, "ms");
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(
+        lines[0].contains("ms |#") && lines[0].ends_with("| 2"),
+        "{}",
+        text
+    );
+    assert!(lines[2].ends_with("| 0"), "{}", text);
+}
+
+// ==================================
+// 4. Arbre des scopes
+// ==================================
+
+fn path(labels: &[&str]) -> Vec<String> {
+    labels.iter().map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn test_scope_tree_self_and_total_times() {
+    // Deux frames de 10 ms : render (6 ms, dont voices 4 ms) puis physic (3 ms)
+    let mut tree = ScopeTree::default();
+    for _ in 0..2 {
+        tree.record(&path(&["frame", "render", "voices"]), 4.0);
+        tree.record(&path(&["frame", "render"]), 6.0);
+        tree.record(&path(&["frame", "physic"]), 3.0);
+        tree.record(&path(&["frame"]), 10.0);
+    }
+
+    let stats = tree.stats();
+    let labels: Vec<(usize, &str)> = stats.iter().map(|s| (s.depth(), s.label())).collect();
+    // Profondeur d'abord, enfants par temps total décroissant
+    assert_eq!(
+        labels,
+        [(0, "frame"), (1, "render"), (2, "voices"), (1, "physic")]
+    );
+    let times: Vec<(u64, f64, f64, f64)> = stats
+        .iter()
+        .map(|s| (s.calls, s.total_ms, s.self_ms, s.percent_of_parent))
+        .collect();
+    assert_eq!(
+        times,
+        [
+            (2, 20.0, 2.0, 100.0),
+            (2, 12.0, 4.0, 60.0),
+            (2, 8.0, 8.0, 100.0 * 8.0 / 12.0),
+            (2, 6.0, 6.0, 30.0),
+        ]
+    );
+
+    let text = format_scope_tree(&stats);
+    assert!(text
+        .lines()
+        .nth(2)
+        .unwrap()
+        .starts_with("    voices: total = 8.000 ms"));
+}
+
+#[test]
+fn test_profiler_scopes_record_their_parent() {
+    let profiler = Profiler::new(10);
+    for _ in 0..3 {
+        let _frame = profiler.frame();
+        profiler.profile_block("render frame", || {
+            let _guard = profiler.measure("process_active_voices");
+        });
+        profiler.profile_block("physic - update", || ());
+    }
+    // Hors frame : scope racine
+    drop(profiler.measure("loose"));
+
+    let stats = profiler.scope_tree();
+    let paths: Vec<String> = stats.iter().map(|s| s.path.join(" > ")).collect();
+    for expected in [
+        "frame",
+        "frame > render frame",
+        "frame > render frame > process_active_voices",
+        "frame > physic - update",
+        "loose",
+    ] {
+        assert!(paths.contains(&expected.to_string()), "{:?}", paths);
+    }
+    // Agrégé par chemin : une entrée par chemin, quel que soit le nombre d'appels
+    assert_eq!(stats.len(), 5);
+    let render = stats.iter().find(|s| s.label() == "render frame").unwrap();
+    assert_eq!(render.calls, 3);
+    assert!(render.self_ms <= render.total_ms);
+
+    profiler.reset_scopes();
+    assert!(profiler.scope_tree().is_empty());
+}
@@ -0,0 +1,144 @@
+mod helpers;
+
+use fireworks_sim::profiler::{Profiler, ProfilerCategory};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use helpers::{DummyAudio, DummyPhysic};
+use std::hint::black_box;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// L'interrupteur est global au processus : les tests de ce fichier le
+/// manipulent un par un.
+static GLOBAL_SWITCH: Mutex<()> = Mutex::new(());
+
+fn reset_switch() {
+    Profiler::set_enabled(true);
+    Profiler::only(None);
+}
+
+// ==================================
+// 1. Bascule en cours d'exécution
+// ==================================
+
+#[test]
+fn test_toggle_mid_run_keeps_windows_consistent() {
+    let _lock = GLOBAL_SWITCH.lock().unwrap_or_else(|e| e.into_inner());
+    reset_switch();
+    let profiler = Profiler::new(4);
+
+    for i in 0..3 {
+        profiler.record_metric("particles", i as f32);
+    }
+    Profiler::set_enabled(false);
+    for i in 0..10 {
+        profiler.record_metric("particles", 100.0 + i as f32);
+        drop(profiler.measure("render"));
+        profiler.profile_block("physic - update", || ());
+        drop(profiler.frame());
+    }
+    assert_eq!(profiler.history("particles"), vec![0.0, 1.0, 2.0]);
+    assert!(profiler.history("render").is_empty());
+    assert_eq!(profiler.total_frames(), 0.0);
+
+    // Guard ouvert désactivé puis profiler réactivé : rien n'est enregistré
+    let guard = profiler.measure("opened while off");
+    Profiler::set_enabled(true);
+    drop(guard);
+    // Guard ouvert actif puis profiler coupé : la mesure est complète
+    let frame = profiler.frame();
+    let guard = profiler.measure("render");
+    Profiler::set_enabled(false);
+    drop(guard);
+    drop(frame);
+    Profiler::set_enabled(true);
+
+    assert!(profiler.history("opened while off").is_empty());
+    assert_eq!(profiler.history("render").len(), 1);
+    assert_eq!(profiler.total_frames(), 1.0);
+    // Pile de scopes intacte : un nouveau scope est une racine
+    drop(profiler.measure("after"));
+    let paths: Vec<String> = profiler
+        .scope_tree()
+        .iter()
+        .map(|s| s.path.join(" > "))
+        .collect();
+    assert_eq!(paths, ["frame", "frame > render", "after"]);
+
+    // Les fenêtres glissantes reprennent normalement
+    for i in 3..8 {
+        profiler.record_metric("particles", i as f32);
+    }
+    assert_eq!(profiler.history("particles"), vec![4.0, 5.0, 6.0, 7.0]);
+}
+
+#[test]
+fn test_categories_and_console_commands() {
+    let _lock = GLOBAL_SWITCH.lock().unwrap_or_else(|e| e.into_inner());
+    reset_switch();
+    let render = Profiler::new(10);
+    let physic = render.for_category(ProfilerCategory::Physics);
+    let audio = Profiler::with_category(10, ProfilerCategory::Audio);
+    assert_eq!(physic.category(), ProfilerCategory::Physics);
+
+    let registry = CommandRegistry::new();
+    let run = |cmd: &str| registry.execute(&mut DummyAudio, &mut DummyPhysic::default(), cmd);
+    assert_eq!(run("profiler.only physics"), "Profiler enabled (physics)");
+    render.record_metric("render metric", 1.0);
+    physic.record_metric("physic metric", 2.0);
+    audio.record_metric("audio metric", 3.0);
+    // Même données pour les deux handles, seule la catégorie physique est mesurée
+    assert!(render.history("render metric").is_empty());
+    assert_eq!(render.history("physic metric"), vec![2.0]);
+    assert!(audio.history("audio metric").is_empty());
+
+    assert_eq!(run("profiler.off"), "Profiler disabled");
+    physic.record_metric("physic metric", 4.0);
+    assert_eq!(physic.history("physic metric"), vec![2.0]);
+
+    assert_eq!(
+        run("profiler.only all"),
+        "Profiler enabled (audio, physics, render)"
+    );
+    assert!(Profiler::is_enabled());
+    assert!(run("profiler.only gpu").starts_with("Error: Unknown profiler category"));
+    assert!(run("profiler.only").starts_with("Usage"));
+    Profiler::set_category_enabled(ProfilerCategory::Audio, false);
+    assert_eq!(run("profiler.on"), "Profiler enabled (physics, render)");
+    reset_switch();
+}
+
+// ==================================
+// 2. Coût du chemin désactivé
+// ==================================
+
+#[test]
+fn test_disabled_profiler_overhead() {
+    let _lock = GLOBAL_SWITCH.lock().unwrap_or_else(|e| e.into_inner());
+    reset_switch();
+    let profiler = Profiler::new(200);
+    Profiler::set_enabled(false);
+
+    const CALLS: u32 = 100_000;
+    // Meilleur de plusieurs passes : insensible aux préemptions ponctuelles
+    let per_call_ns = (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for i in 0..CALLS {
+                drop(black_box(profiler.measure("audio_frame")));
+                profiler.profile_block("physic - update", || black_box(i));
+                profiler.record_metric("particles", black_box(i as usize));
+            }
+            start.elapsed().as_nanos() as f64 / (3 * CALLS) as f64
+        })
+        .fold(f64::INFINITY, f64::min);
+    reset_switch();
+
+    // Quelques ns en release ; marge pour les builds de debug non optimisés
+    let budget_ns = if cfg!(debug_assertions) { 250.0 } else { 15.0 };
+    assert!(
+        per_call_ns < budget_ns,
+        "disabled profiler costs {:.1} ns per call",
+        per_call_ns
+    );
+    assert!(profiler.history("particles").is_empty());
+}
@@ -0,0 +1,106 @@
+use fireworks_sim::profiler::Profiler;
+use fireworks_sim::profiler_trace::TraceCapture;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Événements complets (`"ph": "X"`) d'un document Chrome trace
+fn complete_events(doc: &Value) -> Vec<&Value> {
+    doc["traceEvents"]
+        .as_array()
+        .expect("traceEvents array")
+        .iter()
+        .filter(|e| e["ph"] == "X")
+        .collect()
+}
+
+// ==================================
+// 1. Structure du JSON
+// ==================================
+
+#[test]
+fn test_chrome_trace_from_scopes_on_two_threads() {
+    let capture = TraceCapture::new();
+    // Hors capture : rien n'est enregistré
+    capture.record("ignored", Instant::now(), Duration::from_millis(1));
+
+    capture.start("unused.json").unwrap();
+    assert!(capture.start("other.json").is_err());
+    let t0 = Instant::now();
+    capture.record("main scope", t0, Duration::from_micros(1500));
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .name("audio".into())
+            .spawn_scoped(scope, || {
+                capture.record("audio_frame", Instant::now(), Duration::from_micros(250));
+                capture.record("audio_frame", Instant::now(), Duration::from_micros(300));
+            })
+            .unwrap();
+    });
+
+    let trace = capture.stop().expect("capture running");
+    assert!(capture.stop().is_none());
+    assert_eq!(trace.events.len(), 3);
+    assert_eq!(trace.threads.len(), 2);
+    assert!(trace.threads.iter().any(|(_, name)| name == "audio"));
+
+    let doc = trace.to_chrome_json();
+    assert_eq!(doc["displayTimeUnit"], "ms");
+    let events = complete_events(&doc);
+    assert_eq!(events.len(), 3);
+    for event in &events {
+        for field in ["name", "cat", "ts", "dur", "pid", "tid"] {
+            assert!(!event[field].is_null(), "missing {} in {}", field, event);
+        }
+    }
+    let main = events.iter().find(|e| e["name"] == "main scope").unwrap();
+    assert!((main["dur"].as_f64().unwrap() - 1500.0).abs() < 1e-6);
+    let tids: HashSet<u64> = events.iter().map(|e| e["tid"].as_u64().unwrap()).collect();
+    assert_eq!(tids.len(), 2);
+
+    // Un nom de thread par tid utilisé
+    let named: HashSet<u64> = doc["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|e| e["ph"] == "M" && e["name"] == "thread_name")
+        .map(|e| e["tid"].as_u64().unwrap())
+        .collect();
+    assert_eq!(named, tids);
+}
+
+// ==================================
+// 2. Capture via le Profiler
+// ==================================
+
+#[test]
+fn test_profiler_capture_writes_trace_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested/out.json");
+    assert!(Profiler::stop_capture().unwrap().is_none());
+
+    let profiler = Profiler::new(10);
+    Profiler::start_capture(&path).unwrap();
+    assert!(Profiler::is_capturing());
+    {
+        let _guard = profiler.measure("render");
+        profiler.profile_block("physic - update", || ());
+    }
+    let audio = profiler.clone();
+    std::thread::spawn(move || drop(audio.measure("process_active_voices")))
+        .join()
+        .unwrap();
+    let (saved, scopes) = Profiler::stop_capture().unwrap().expect("capture running");
+    assert_eq!(saved, path);
+    assert_eq!(scopes, 3);
+
+    let doc: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let names: HashSet<&str> = complete_events(&doc)
+        .iter()
+        .map(|e| e["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        HashSet::from(["render", "physic - update", "process_active_voices"])
+    );
+}
@@ -0,0 +1,279 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig,
+    physic_engine_generational_arena::{PhysicEngineFireworks, PhysicEngineTestHelpers},
+    ParticleType, PhysicEngineIterator,
+};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::utils::atlas::{
+    sprite_rect, AtlasDescriptor, UvRect, MAX_ATLAS_SPRITES,
+};
+use glam::Vec2;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+const BUNDLED_ATLAS: &str = "assets/textures/atlas/explosion_atlas.toml";
+
+const ATLAS_TOML: &str = r#"
+texture = "atlas.png"
+
+[[sprites]]
+name = "soft_dot"
+rect = [0, 0, 64, 64]
+
+[[sprites]]
+name = "star"
+rect = [64, 0, 64, 64]
+
+[[sprites]]
+name = "streak"
+rect = [128, 0, 32, 64]
+"#;
+
+fn assert_vec2_eq(actual: Vec2, expected: Vec2) {
+    assert!(
+        actual.abs_diff_eq(expected, 1e-6),
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+// ==================================
+// 1. Descripteur d'atlas
+// ==================================
+
+#[test]
+fn test_descriptor_toml_parsing() {
+    let atlas = AtlasDescriptor::from_toml(ATLAS_TOML).unwrap();
+    assert_eq!(atlas.texture, "atlas.png");
+    let names: Vec<&str> = atlas.sprites.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["soft_dot", "star", "streak"]);
+    assert_eq!(atlas.sprites[2].rect, [128, 0, 32, 64]);
+    assert_eq!(atlas.sprite_index("star"), Some(1));
+    assert_eq!(atlas.sprite_index("heart"), None);
+}
+
+#[test]
+fn test_descriptor_json_parsing_matches_toml() {
+    let json = r#"{
+        "texture": "atlas.png",
+        "sprites": [
+            { "name": "soft_dot", "rect": [0, 0, 64, 64] },
+            { "name": "star", "rect": [64, 0, 64, 64] },
+            { "name": "streak", "rect": [128, 0, 32, 64] }
+        ]
+    }"#;
+    let from_json = AtlasDescriptor::from_json(json).unwrap();
+    assert_eq!(from_json, AtlasDescriptor::from_toml(ATLAS_TOML).unwrap());
+
+    // Format choisi d'après l'extension
+    assert_eq!(
+        AtlasDescriptor::parse(json, Path::new("sprites.JSON")).unwrap(),
+        from_json
+    );
+    assert_eq!(
+        AtlasDescriptor::parse(ATLAS_TOML, Path::new("sprites.toml")).unwrap(),
+        from_json
+    );
+    assert!(AtlasDescriptor::parse(json, Path::new("sprites.toml")).is_err());
+}
+
+#[test]
+fn test_descriptor_rejects_invalid_atlases() {
+    let no_sprite = "texture = \"a.png\"\nsprites = []";
+    let err = AtlasDescriptor::from_toml(no_sprite).unwrap_err();
+    assert!(format!("{:#}", err).contains("no sprite"), "{:#}", err);
+
+    let empty_rect = "texture = \"a.png\"\n[[sprites]]\nname = \"dot\"\nrect = [0, 0, 0, 8]";
+    let err = AtlasDescriptor::from_toml(empty_rect).unwrap_err();
+    assert!(format!("{:#}", err).contains("'dot'"), "{:#}", err);
+
+    let mut too_many = String::from("texture = \"a.png\"\n");
+    for i in 0..=MAX_ATLAS_SPRITES {
+        too_many += &format!("[[sprites]]\nname = \"s{}\"\nrect = [{}, 0, 1, 1]\n", i, i);
+    }
+    assert!(AtlasDescriptor::from_toml(&too_many).is_err());
+
+    // Champ manquant, rectangle incomplet
+    assert!(
+        AtlasDescriptor::from_toml("[[sprites]]\nname = \"dot\"\nrect = [0, 0, 8, 8]").is_err()
+    );
+    assert!(AtlasDescriptor::from_toml(
+        "texture = \"a.png\"\n[[sprites]]\nname = \"d\"\nrect = [0, 0, 8]"
+    )
+    .is_err());
+    assert!(AtlasDescriptor::load("assets/textures/atlas/missing.toml").is_err());
+}
+
+#[test]
+fn test_bundled_atlas_fits_its_texture() {
+    let atlas = AtlasDescriptor::load(BUNDLED_ATLAS).unwrap();
+    let names: Vec<&str> = atlas.sprites.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["soft_dot", "star", "streak"]);
+
+    let (width, height) = image::image_dimensions(&atlas.texture).unwrap();
+    let rects = atlas.uv_rects(width, height).unwrap();
+    assert_eq!(rects.len(), 3);
+    for rect in rects {
+        let (min, max) = (rect.apply(Vec2::ZERO), rect.apply(Vec2::ONE));
+        assert!(min.cmpge(Vec2::ZERO).all() && max.cmple(Vec2::ONE).all());
+        // Sprites carrés
+        assert!((rect.aspect_ratio(width as f32 / height as f32) - 1.0).abs() < 1e-6);
+    }
+}
+
+// ==================================
+// 2. Rectangles UV
+// ==================================
+
+#[test]
+fn test_uv_rect_from_pixels_flips_rows() {
+    // Texture 256x128 : sprite 64x32 en (64, 32) depuis le haut
+    let rect = UvRect::from_pixels([64, 32, 64, 32], 256, 128);
+    assert_vec2_eq(rect.offset, Vec2::new(0.25, 0.5));
+    assert_vec2_eq(rect.scale, Vec2::new(0.25, 0.25));
+    assert_eq!(rect.to_array(), [0.25, 0.5, 0.25, 0.25]);
+
+    // Coins du quad → coins du sprite (v croissant vers le haut de l'image)
+    assert_vec2_eq(rect.apply(Vec2::ZERO), Vec2::new(0.25, 0.5));
+    assert_vec2_eq(rect.apply(Vec2::ONE), Vec2::new(0.5, 0.75));
+
+    // Ligne du haut de l'image = haut de la texture retournée
+    let top = UvRect::from_pixels([0, 0, 256, 32], 256, 128);
+    assert_vec2_eq(top.apply(Vec2::ONE), Vec2::ONE);
+    assert_vec2_eq(top.apply(Vec2::ZERO), Vec2::new(0.0, 0.75));
+}
+
+#[test]
+fn test_uv_rect_aspect_ratio() {
+    assert_eq!(UvRect::FULL.aspect_ratio(1.5), 1.5);
+    assert_vec2_eq(UvRect::FULL.apply(Vec2::new(0.3, 0.7)), Vec2::new(0.3, 0.7));
+    // Sprite 32x64 dans une texture 160x64
+    let rect = UvRect::from_pixels([128, 0, 32, 64], 160, 64);
+    assert!((rect.aspect_ratio(160.0 / 64.0) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_uv_rects_must_fit_texture() {
+    let atlas = AtlasDescriptor::from_toml(ATLAS_TOML).unwrap();
+    let rects = atlas.uv_rects(160, 64).unwrap();
+    assert_eq!(rects[1], UvRect::from_pixels([64, 0, 64, 64], 160, 64));
+
+    let err = atlas.uv_rects(128, 64).unwrap_err();
+    assert!(format!("{:#}", err).contains("'streak'"), "{:#}", err);
+    assert!(atlas.uv_rects(160, 32).is_err());
+}
+
+#[test]
+fn test_sprite_rect_wraps_index() {
+    // Sans atlas : texture entière, quel que soit l'indice
+    assert_eq!(sprite_rect(&[], 0), UvRect::FULL);
+    assert_eq!(sprite_rect(&[], 200), UvRect::FULL);
+
+    let rects = AtlasDescriptor::from_toml(ATLAS_TOML)
+        .unwrap()
+        .uv_rects(160, 64)
+        .unwrap();
+    assert_eq!(sprite_rect(&rects, 0), rects[0]);
+    assert_eq!(sprite_rect(&rects, 2), rects[2]);
+    assert_eq!(sprite_rect(&rects, 3), rects[0]);
+    assert_eq!(sprite_rect(&rects, 255), rects[0]);
+    assert_eq!(sprite_rect(&rects, 254), rects[2]);
+}
+
+// ==================================
+// 3. Indice de sprite côté physique
+// ==================================
+
+#[test]
+fn test_explosion_sprite_randomized_per_explosion() {
+    let config = PhysicConfig {
+        max_rockets: 16,
+        rocket_interval_mean: 100.0, // Empêcher le spawn automatique
+        ..Default::default()
+    };
+    let mut engine = PhysicEngineFireworks::new(&config, 1920.0);
+    engine.spawn_n_rockets(12);
+    engine.force_explode_all();
+
+    // Particules d'une même explosion : même position de départ
+    let mut sprites_by_explosion: HashMap<(u32, u32), BTreeSet<u8>> = HashMap::new();
+    for p in engine.iter_particles_by_type(ParticleType::Explosion) {
+        sprites_by_explosion
+            .entry((p.pos.x.to_bits(), p.pos.y.to_bits()))
+            .or_default()
+            .insert(p.sprite);
+    }
+    assert_eq!(sprites_by_explosion.len(), 12);
+    assert!(sprites_by_explosion
+        .values()
+        .all(|sprites| sprites.len() == 1));
+
+    let distinct: BTreeSet<u8> = sprites_by_explosion.values().flatten().copied().collect();
+    assert!(distinct.len() > 1, "{:?}", distinct);
+
+    // Têtes de fusée : sprite par défaut
+    engine.spawn_n_rockets(2);
+    assert!(engine
+        .iter_particles_by_type(ParticleType::Rocket)
+        .all(|p| p.sprite == 0));
+}
+
+// ==================================
+// 4. Commande console
+// ==================================
+
+#[test]
+fn test_particles_atlas_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    // Texture seule par défaut
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.atlas explosion",
+    );
+    assert_eq!(out, "explosion atlas: none");
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        &format!("renderer.particles.atlas explosion {}", BUNDLED_ATLAS),
+    );
+    assert_eq!(
+        out,
+        format!("explosion atlas set to {} (3 sprites)", BUNDLED_ATLAS)
+    );
+    assert_eq!(
+        shared.config.borrow().particles.explosion.atlas,
+        BUNDLED_ATLAS
+    );
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.atlas smoke missing.toml",
+    );
+    assert!(out.starts_with("Invalid atlas"), "{}", out);
+    assert!(shared.config.borrow().particles.smoke.atlas.is_empty());
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.atlas explosion none",
+    );
+    assert_eq!(out, "explosion atlas disabled");
+    assert!(shared.config.borrow().particles.explosion.atlas.is_empty());
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.particles.atlas sparks");
+    assert!(out.starts_with("Usage"), "{}", out);
+}
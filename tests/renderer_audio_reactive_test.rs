@@ -0,0 +1,198 @@
+use fireworks_sim::renderer_engine::audio_reactive::{audio_reactive_intensity, EnvelopeFollower};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{RendererConfig, BLOOM_INTENSITY_RANGE};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Suit un niveau constant pendant `seconds` (pas de `DT`)
+fn follow(envelope: &mut EnvelopeFollower, level: f32, seconds: f32, attack: f32, release: f32) {
+    for _ in 0..(seconds / DT).round() as usize {
+        envelope.update(level, DT, attack, release);
+    }
+}
+
+// ==================================
+// 1. Suiveur d'enveloppe
+// ==================================
+
+#[test]
+fn test_envelope_time_constants() {
+    // Après une constante de temps : 1 - e⁻¹ ≈ 63 % du chemin
+    let mut envelope = EnvelopeFollower::new();
+    let value = envelope.update(1.0, 0.1, 0.1, 1.0);
+    assert!((value - (1.0 - (-1.0f32).exp())).abs() < 1e-6, "{}", value);
+
+    // Relâchement : même loi avec la constante de descente
+    let mut envelope = EnvelopeFollower::new();
+    envelope.update(1.0, 0.0, 0.0, 1.0);
+    assert_eq!(envelope.value(), 1.0);
+    let value = envelope.update(0.0, 0.5, 0.0, 0.5);
+    assert!((value - (-1.0f32).exp()).abs() < 1e-6, "{}", value);
+}
+
+#[test]
+fn test_envelope_attack_faster_than_release() {
+    let (attack, release) = (0.05, 0.6);
+    let mut envelope = EnvelopeFollower::new();
+    follow(&mut envelope, 0.5, 0.25, attack, release);
+    // Cinq constantes d'attaque : niveau quasiment atteint
+    assert!(
+        (envelope.value() - 0.5).abs() < 0.01,
+        "{}",
+        envelope.value()
+    );
+
+    // Retombée lente : encore plus de la moitié après 0.25 s
+    follow(&mut envelope, 0.0, 0.25, attack, release);
+    assert!(envelope.value() > 0.25 && envelope.value() < 0.5);
+
+    // Convergence monotone, sans dépassement
+    let mut previous = envelope.value();
+    for _ in 0..600 {
+        let value = envelope.update(0.0, DT, attack, release);
+        assert!(value <= previous && value >= 0.0);
+        previous = value;
+    }
+    assert!(previous < 1e-3);
+}
+
+#[test]
+fn test_envelope_smooths_per_frame_jitter() {
+    // Niveau alternant d'une frame à l'autre : l'enveloppe varie bien moins
+    let mut envelope = EnvelopeFollower::new();
+    let mut values = Vec::new();
+    for i in 0..240 {
+        let level = if i % 2 == 0 { 0.6 } else { 0.2 };
+        values.push(envelope.update(level, DT, 0.05, 0.6));
+    }
+    let tail = &values[120..];
+    let spread = tail.iter().cloned().fold(f32::MIN, f32::max)
+        - tail.iter().cloned().fold(f32::MAX, f32::min);
+    assert!(spread < 0.2, "{}", spread);
+}
+
+#[test]
+fn test_envelope_ignores_invalid_input() {
+    let mut envelope = EnvelopeFollower::new();
+    follow(&mut envelope, 0.4, 1.0, 0.05, 0.6);
+    let before = envelope.value();
+    // NaN / infini / négatif traités comme du silence, dt négatif sans effet
+    assert!(envelope.update(f32::NAN, DT, 0.05, 0.6).is_finite());
+    assert!(envelope.update(f32::INFINITY, DT, 0.05, 0.6) <= before);
+    assert!(envelope.update(-1.0, DT, 0.05, 0.6) >= 0.0);
+    let value = envelope.value();
+    assert_eq!(envelope.update(1.0, -1.0, 0.05, 0.6), value);
+
+    envelope.reset();
+    assert_eq!(envelope.value(), 0.0);
+}
+
+// ==================================
+// 2. Intensité effective
+// ==================================
+
+#[test]
+fn test_zero_reactivity_reproduces_static_intensity() {
+    for base in [0.0, 0.8, 1.234_567, BLOOM_INTENSITY_RANGE.1] {
+        for level in [0.0, 0.3, 1.0, 7.5] {
+            assert_eq!(
+                audio_reactive_intensity(base, 0.0, level).to_bits(),
+                base.to_bits()
+            );
+        }
+    }
+
+    let mut config = RendererConfig {
+        bloom_audio_reactive: true,
+        bloom_intensity: 0.8,
+        ..Default::default()
+    };
+    config.set_bloom_audio_reactivity(0.0);
+    assert_eq!(
+        config.effective_bloom_intensity(0.9),
+        config.bloom_intensity
+    );
+}
+
+#[test]
+fn test_effective_bloom_intensity() {
+    assert_eq!(audio_reactive_intensity(0.8, 2.0, 0.25), 1.3);
+
+    let mut config = RendererConfig {
+        bloom_intensity: 0.8,
+        bloom_audio_reactivity: 2.0,
+        ..Default::default()
+    };
+    // Désactivé : intensité statique quel que soit le niveau
+    assert!(!config.bloom_audio_reactive);
+    assert_eq!(config.effective_bloom_intensity(0.5), 0.8);
+
+    config.bloom_audio_reactive = true;
+    assert_eq!(config.effective_bloom_intensity(0.0), 0.8);
+    assert!((config.effective_bloom_intensity(0.5) - 1.8).abs() < 1e-6);
+    // Bornée au maximum admis
+    config.set_bloom_audio_reactivity(10.0);
+    assert_eq!(
+        config.effective_bloom_intensity(5.0),
+        BLOOM_INTENSITY_RANGE.1
+    );
+}
+
+#[test]
+fn test_audio_reactive_config_validation() {
+    let config = RendererConfig::default();
+    assert!(!config.bloom_audio_reactive);
+    assert!(config.validate().is_empty());
+
+    let config = RendererConfig {
+        bloom_audio_reactivity: -1.0,
+        bloom_audio_release: 60.0,
+        ..Default::default()
+    };
+    let errors = config.validate().join("\n");
+    assert!(errors.contains("bloom_audio_reactivity"), "{}", errors);
+    assert!(errors.contains("bloom_audio_release"), "{}", errors);
+}
+
+// ==================================
+// 3. Commande console
+// ==================================
+
+#[test]
+fn test_bloom_audio_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+    let mut run = |command: &str| registry.execute(&mut audio, &mut physic, command);
+
+    assert_eq!(
+        run("renderer.bloom.audio"),
+        "Audio-reactive bloom: on (reactivity 1.50)"
+    );
+    assert!(shared.config.borrow().bloom_audio_reactive);
+    assert_eq!(run("renderer.bloom.audio"), "Audio-reactive bloom: off");
+    assert!(!shared.config.borrow().bloom_audio_reactive);
+
+    assert_eq!(
+        run("renderer.bloom.audio 3"),
+        "Audio-reactive bloom: on (reactivity 3.00)"
+    );
+    assert_eq!(shared.config.borrow().bloom_audio_reactivity, 3.0);
+    assert_eq!(
+        run("renderer.bloom.audio 50"),
+        "Audio-reactive bloom: on (reactivity 10.00, clamped)"
+    );
+    assert_eq!(run("renderer.bloom.audio off"), "Audio-reactive bloom: off");
+    assert_eq!(shared.config.borrow().bloom_audio_reactivity, 10.0);
+
+    for bad in ["renderer.bloom.audio loud", "renderer.bloom.audio NaN"] {
+        assert!(run(bad).starts_with("Usage"), "{}", bad);
+    }
+    assert!(!shared.config.borrow().bloom_audio_reactive);
+}
@@ -0,0 +1,91 @@
+use fireworks_sim::renderer_engine::background::{generate_stars, BackgroundConfig};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Configuration
+// ==================================
+
+#[test]
+fn test_background_config_parsing() {
+    let config: RendererConfig = toml::from_str(
+        r#"
+        [background]
+        top_color = [0.1, 0.2, 0.3]
+        star_count = 42
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.background.top_color, [0.1, 0.2, 0.3]);
+    assert_eq!(config.background.star_count, 42);
+    // Clés absentes : valeurs par défaut
+    let defaults = BackgroundConfig::default();
+    assert!(config.background.enabled);
+    assert_eq!(config.background.bottom_color, defaults.bottom_color);
+    assert_eq!(config.background.star_seed, defaults.star_seed);
+
+    let file = RendererConfig::from_file("assets/config/renderer.toml").unwrap();
+    assert!(file.background.star_max_brightness < 1.0);
+}
+
+// ==================================
+// 2. Génération du champ d'étoiles
+// ==================================
+
+#[test]
+fn test_star_generation_is_deterministic_per_seed() {
+    let a = generate_stars(300, 7, 0.6);
+    let b = generate_stars(300, 7, 0.6);
+    let c = generate_stars(300, 8, 0.6);
+    assert_eq!(a.len(), 300);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(generate_stars(0, 7, 0.6).is_empty());
+}
+
+#[test]
+fn test_stars_stay_on_screen_and_below_brightness_cap() {
+    let stars = generate_stars(1000, 3, 0.5);
+    for star in &stars {
+        assert!((0.0..=1.0).contains(&star.pos.x));
+        assert!((0.0..=1.0).contains(&star.pos.y));
+        assert!(star.brightness > 0.0 && star.brightness <= 0.5);
+        assert!(star.size > 0.0);
+    }
+    // Ciel plus étoilé en haut qu'à l'horizon
+    let upper = stars.iter().filter(|s| s.pos.y > 0.5).count();
+    assert!(upper > stars.len() / 2);
+}
+
+// ==================================
+// 3. Commande console renderer.background
+// ==================================
+
+#[test]
+fn test_renderer_background_command_toggles() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.background off");
+    assert_eq!(out, "Background: off");
+    assert!(!shared.config.borrow().background.enabled);
+
+    registry.execute(&mut audio, &mut physic, "renderer.background on");
+    assert!(shared.config.borrow().background.enabled);
+
+    // Sans argument : bascule
+    registry.execute(&mut audio, &mut physic, "renderer.background");
+    assert!(!shared.config.borrow().background.enabled);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.background maybe");
+    assert!(out.starts_with("Usage"), "{}", out);
+    assert!(!shared.config.borrow().background.enabled);
+}
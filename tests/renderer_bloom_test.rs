@@ -0,0 +1,290 @@
+use fireworks_sim::renderer_engine::bloom::{
+    adapt_exposure, load_lens_dirt, soft_threshold, target_exposure,
+};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{
+    RendererConfig, BLOOM_SOFT_KNEE_RANGE, BLOOM_THRESHOLD_RANGE, LENS_DIRT_STRENGTH_RANGE,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Courbe de seuil
+// ==================================
+
+#[test]
+fn test_soft_threshold_hard_cut_without_knee() {
+    // Genou nul : rien sous le seuil, (luma - seuil) / luma au-dessus
+    assert_eq!(soft_threshold(0.5, 0.75, 0.0), 0.0);
+    assert!((soft_threshold(1.5, 0.75, 0.0) - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_soft_threshold_knee_is_continuous_and_monotonic() {
+    let (threshold, knee) = (0.75, 0.5);
+    // Sous `threshold × (1 - knee)` : aucune contribution
+    assert_eq!(soft_threshold(0.3, threshold, knee), 0.0);
+    // Dans le genou : contribution partielle, même sous le seuil
+    assert!(soft_threshold(0.6, threshold, knee) > 0.0);
+
+    let mut previous = 0.0;
+    for i in 0..200 {
+        let luma = i as f32 * 0.01;
+        let contribution = soft_threshold(luma, threshold, knee) * luma;
+        assert!(contribution >= previous - 1e-4, "luma {}", luma);
+        assert!(
+            (contribution - previous).abs() < 0.05,
+            "jump at luma {}",
+            luma
+        );
+        previous = contribution;
+    }
+}
+
+#[test]
+fn test_default_stars_stay_below_bloom_knee() {
+    let config = RendererConfig::default();
+    let knee_start = config.bloom_threshold * (1.0 - config.bloom_soft_knee);
+    assert!(config.background.star_max_brightness < knee_start);
+}
+
+// ==================================
+// 2. Configuration TOML
+// ==================================
+
+#[test]
+fn test_bloom_config_parsing_and_defaults() {
+    let config: RendererConfig = toml::from_str("bloom_threshold = 1.2").unwrap();
+    assert_eq!(config.bloom_threshold, 1.2);
+    assert_eq!(
+        config.bloom_soft_knee,
+        RendererConfig::default().bloom_soft_knee
+    );
+    assert!(!config.bloom_enabled);
+}
+
+#[test]
+fn test_renderer_config_toml_round_trip() {
+    let mut config = RendererConfig {
+        bloom_enabled: true,
+        bloom_intensity: 1.3,
+        ..Default::default()
+    };
+    config.set_bloom_threshold(0.9);
+    config.set_bloom_soft_knee(0.25);
+
+    let text = toml::to_string(&config).unwrap();
+    let parsed: RendererConfig = toml::from_str(&text).unwrap();
+    assert_eq!(parsed, config);
+}
+
+#[test]
+fn test_bundled_renderer_config_has_bloom_keys() {
+    let config = RendererConfig::from_file("assets/config/renderer.toml").unwrap();
+    assert!(config.bloom_threshold > 0.0);
+    assert!((0.0..=1.0).contains(&config.bloom_soft_knee));
+}
+
+// ==================================
+// 3. Commandes console
+// ==================================
+
+#[test]
+fn test_bloom_setters_clamp_to_range() {
+    let mut config = RendererConfig::default();
+    assert_eq!(config.set_bloom_threshold(100.0), BLOOM_THRESHOLD_RANGE.1);
+    assert_eq!(config.set_bloom_threshold(-1.0), BLOOM_THRESHOLD_RANGE.0);
+    assert_eq!(config.set_bloom_soft_knee(2.0), BLOOM_SOFT_KNEE_RANGE.1);
+    assert_eq!(config.set_bloom_soft_knee(0.3), 0.3);
+}
+
+#[test]
+fn test_bloom_console_commands() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom on");
+    assert_eq!(out, "Bloom: on");
+    assert!(shared.config.borrow().bloom_enabled);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.threshold 1.1");
+    assert_eq!(out, "Bloom threshold set to 1.10");
+    assert_eq!(shared.config.borrow().bloom_threshold, 1.1);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.threshold 50");
+    assert!(out.ends_with("(clamped)"), "{}", out);
+    assert_eq!(
+        shared.config.borrow().bloom_threshold,
+        BLOOM_THRESHOLD_RANGE.1
+    );
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.knee 0.2");
+    assert_eq!(out, "Bloom soft knee set to 0.20");
+
+    // Sans argument : valeur courante
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.knee");
+    assert_eq!(out, "Bloom soft knee: 0.20");
+
+    // Argument invalide : usage avec la plage, config inchangée
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.knee soft");
+    assert!(
+        out.starts_with("Usage") && out.contains("0.0..1.0"),
+        "{}",
+        out
+    );
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.threshold NaN");
+    assert!(out.starts_with("Usage"), "{}", out);
+    assert_eq!(shared.config.borrow().bloom_soft_knee, 0.2);
+}
+
+// ==================================
+// 4. Salissures d'objectif
+// ==================================
+
+#[test]
+fn test_lens_dirt_config_and_command() {
+    let config = RendererConfig::default();
+    // Désactivé par défaut, texture fournie avec les assets
+    assert_eq!(config.lens_dirt_strength, 0.0);
+    assert!(load_lens_dirt(&config.lens_dirt_texture).is_ok());
+
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.dirt 0.6");
+    assert_eq!(out, "Lens dirt strength set to 0.60");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.bloom.dirt 3");
+    assert!(out.ends_with("(clamped)"), "{}", out);
+    assert_eq!(
+        shared.config.borrow().lens_dirt_strength,
+        LENS_DIRT_STRENGTH_RANGE.1
+    );
+}
+
+#[test]
+fn test_missing_lens_dirt_texture_is_an_error_not_a_panic() {
+    let err = load_lens_dirt("assets/textures/does_not_exist.png").unwrap_err();
+    assert!(format!("{:#}", err).contains("does_not_exist.png"));
+}
+
+// ==================================
+// 5. Exposition automatique
+// ==================================
+
+#[test]
+fn test_target_exposure_brings_average_to_key() {
+    // Scène sombre : on éclaircit, mais sans dépasser le maximum
+    assert_eq!(target_exposure(0.18, 0.18, 0.5, 2.0), 1.0);
+    assert!((target_exposure(0.12, 0.18, 0.5, 2.0) - 1.5).abs() < 1e-5);
+    assert_eq!(target_exposure(0.0, 0.18, 0.5, 2.0), 2.0);
+    // Bouquet final éblouissant : exposition réduite jusqu'au minimum
+    assert_eq!(target_exposure(5.0, 0.18, 0.5, 2.0), 0.5);
+}
+
+#[test]
+fn test_adapt_exposure_is_framerate_independent() {
+    let (start, target, speed) = (1.0, 0.5, 2.0);
+    // Une grande frame ≈ plusieurs petites frames couvrant la même durée
+    let one_step = adapt_exposure(start, target, speed, 0.1);
+    let mut many_steps = start;
+    for _ in 0..10 {
+        many_steps = adapt_exposure(many_steps, target, speed, 0.01);
+    }
+    assert!((one_step - many_steps).abs() < 1e-5);
+
+    // Convergence monotone vers la cible, sans la dépasser
+    let mut exposure = start;
+    for _ in 0..600 {
+        let next = adapt_exposure(exposure, target, speed, 1.0 / 60.0);
+        assert!(next <= exposure && next >= target);
+        exposure = next;
+    }
+    assert!((exposure - target).abs() < 1e-3);
+
+    // Vitesse ou dt nuls : exposition figée
+    assert_eq!(adapt_exposure(start, target, 0.0, 1.0), start);
+    assert_eq!(adapt_exposure(start, target, speed, 0.0), start);
+}
+
+#[test]
+fn test_auto_exposure_config_and_commands() {
+    let config: RendererConfig = toml::from_str(
+        r#"
+        auto_exposure_enabled = true
+        auto_exposure_speed = 3.0
+        "#,
+    )
+    .unwrap();
+    assert!(config.auto_exposure_enabled);
+    assert_eq!(config.auto_exposure_speed, 3.0);
+    assert_eq!(
+        config.auto_exposure_min,
+        RendererConfig::default().auto_exposure_min
+    );
+
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.exposure on");
+    assert_eq!(out, "Auto exposure: on");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.exposure.speed 4");
+    assert_eq!(out, "Exposure speed set to 4.00");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.exposure.speed -1");
+    assert!(out.starts_with("Usage"), "{}", out);
+
+    // Bornes inversées : remises dans l'ordre
+    let out = registry.execute(&mut audio, &mut physic, "renderer.exposure.range 3 0.25");
+    assert_eq!(out, "Exposure range set to 0.25..3.00");
+    let cfg = shared.config.borrow();
+    assert!(cfg.auto_exposure_enabled);
+    assert_eq!((cfg.auto_exposure_min, cfg.auto_exposure_max), (0.25, 3.0));
+}
+
+// ==================================
+// 6. Redimensionnement (contexte GL requis)
+// ==================================
+
+#[cfg(feature = "interactive_tests")]
+#[test]
+fn test_bloom_resize_creates_no_shader_program() {
+    use fireworks_sim::physic_engine::config::PhysicConfig;
+    use fireworks_sim::renderer_engine::bloom::{internal_size, BloomPass};
+    use fireworks_sim::renderer_engine::renderer::Renderer;
+
+    /// Programmes vivants du contexte courant (noms attribués à partir de 1)
+    fn live_programs() -> usize {
+        (1..4096)
+            .filter(|&id| unsafe { gl::IsProgram(id) } == gl::TRUE)
+            .count()
+    }
+
+    let mut renderer = Renderer::new_headless(320, 240, &PhysicConfig::default()).unwrap();
+    let mut bloom = unsafe { BloomPass::new(320, 240) }.unwrap();
+    let programs = live_programs();
+    assert!(programs > 0);
+
+    for i in 0..50 {
+        let (width, height) = (320 + i * 7, 240 + (i % 5) * 11);
+        unsafe { bloom.resize(width, height) }.unwrap();
+        // Seules les surfaces suivent la nouvelle taille
+        let (w, h) = internal_size(width, height, bloom.render_scale());
+        assert_eq!(bloom.framebuffer_sizes()[0], ("scene", w, h));
+    }
+    assert_eq!(live_programs(), programs);
+    assert_eq!(unsafe { gl::GetError() }, gl::NO_ERROR);
+
+    unsafe { bloom.close() };
+    renderer.close();
+}
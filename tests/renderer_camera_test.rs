@@ -0,0 +1,163 @@
+use fireworks_sim::renderer_engine::camera::{Camera2D, CameraConfig};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use glam::Vec2;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+const VIEWPORT: Vec2 = Vec2::new(1024.0, 800.0);
+
+fn approx(a: Vec2, b: Vec2) -> bool {
+    (a - b).length() < 1e-3
+}
+
+fn camera() -> Camera2D {
+    Camera2D::new(VIEWPORT, CameraConfig::default())
+}
+
+// ==================================
+// 1. Transformations monde <-> écran
+// ==================================
+
+#[test]
+fn test_default_camera_is_identity() {
+    let camera = camera();
+    for p in [Vec2::ZERO, Vec2::new(100.0, 700.0), VIEWPORT] {
+        assert!(approx(camera.world_to_screen(p), p));
+        assert!(approx(camera.screen_to_world(p), p));
+    }
+}
+
+#[test]
+fn test_world_screen_roundtrip_with_zoom_and_pan() {
+    let mut camera = camera();
+    camera.set_zoom(2.5);
+    camera.pan_by_screen(Vec2::new(-120.0, 40.0));
+    camera.snap();
+
+    for p in [
+        Vec2::new(3.0, 4.0),
+        Vec2::new(-250.0, 900.0),
+        VIEWPORT * 0.3,
+    ] {
+        assert!(approx(camera.screen_to_world(camera.world_to_screen(p)), p));
+    }
+    // Le centre du monde visé est au centre de l'écran
+    assert!(approx(
+        camera.world_to_screen(camera.center),
+        VIEWPORT * 0.5
+    ));
+}
+
+#[test]
+fn test_view_projection_matches_world_to_screen() {
+    let mut camera = camera();
+    camera.zoom_about(Vec2::new(200.0, 150.0), 3.0);
+    camera.snap();
+
+    for p in [Vec2::new(10.0, 20.0), Vec2::new(640.0, 480.0)] {
+        let clip = camera.view_projection().transform_point2(p);
+        let screen = (clip + Vec2::ONE) * 0.5 * VIEWPORT;
+        assert!(approx(screen, camera.world_to_screen(p)), "{:?}", p);
+    }
+}
+
+// ==================================
+// 2. Zoom autour du curseur et déplacement
+// ==================================
+
+#[test]
+fn test_zoom_about_cursor_keeps_point_under_cursor() {
+    let mut camera = camera();
+    let cursor = Vec2::new(800.0, 120.0);
+    let anchor = camera.screen_to_world(cursor);
+
+    for factor in [1.15, 2.0, 0.5, 4.0] {
+        camera.zoom_about(cursor, factor);
+        camera.snap();
+        assert!(approx(camera.world_to_screen(anchor), cursor));
+        assert!(approx(camera.screen_to_world(cursor), anchor));
+    }
+}
+
+#[test]
+fn test_zoom_is_clamped_and_pan_follows_cursor() {
+    let mut camera = camera();
+    camera.set_zoom(1000.0);
+    assert_eq!(camera.target_zoom, CameraConfig::default().max_zoom);
+    camera.set_zoom(0.0001);
+    assert_eq!(camera.target_zoom, CameraConfig::default().min_zoom);
+
+    // Glisser : le point saisi reste sous le curseur
+    camera.set_zoom(2.0);
+    camera.snap();
+    let grab = Vec2::new(300.0, 300.0);
+    let world = camera.screen_to_world(grab);
+    let delta = Vec2::new(50.0, -25.0);
+    camera.pan_by_screen(delta);
+    camera.snap();
+    assert!(approx(camera.world_to_screen(world), grab + delta));
+}
+
+#[test]
+fn test_camera_smoothly_converges_to_target() {
+    let mut camera = camera();
+    camera.set_zoom(4.0);
+    camera.update(1.0 / 60.0);
+    assert!(camera.zoom > 1.0 && camera.zoom < 4.0);
+    for _ in 0..600 {
+        camera.update(1.0 / 60.0);
+    }
+    assert!((camera.zoom - 4.0).abs() < 1e-3);
+
+    // Sans lissage : saut immédiat
+    let mut camera = Camera2D::new(
+        VIEWPORT,
+        CameraConfig {
+            smoothing: 0.0,
+            ..Default::default()
+        },
+    );
+    camera.set_zoom(2.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.zoom, 2.0);
+}
+
+#[test]
+fn test_resize_recenters_idle_camera_only() {
+    let mut camera = camera();
+    camera.set_viewport(Vec2::new(1920.0, 1080.0));
+    assert!(approx(camera.center, Vec2::new(960.0, 540.0)));
+
+    camera.set_zoom(2.0);
+    let target = camera.target_center;
+    camera.set_viewport(VIEWPORT);
+    assert!(approx(camera.target_center, target));
+}
+
+// ==================================
+// 3. Commandes console renderer.camera.*
+// ==================================
+
+#[test]
+fn test_renderer_camera_commands() {
+    let shared = RendererShared::default();
+    *shared.camera.borrow_mut() = camera();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.camera.zoom 3");
+    assert!(out.contains("3.00"), "{}", out);
+    assert_eq!(shared.camera.borrow().target_zoom, 3.0);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.camera.zoom -1");
+    assert!(out.starts_with("Usage"), "{}", out);
+
+    registry.execute(&mut audio, &mut physic, "renderer.camera.reset");
+    assert_eq!(shared.camera.borrow().target_zoom, 1.0);
+    assert!(approx(shared.camera.borrow().target_center, VIEWPORT * 0.5));
+}
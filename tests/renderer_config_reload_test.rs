@@ -0,0 +1,227 @@
+mod helpers;
+
+use fireworks_sim::config_manager::ConfigSection;
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::types::ReloadResult;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::config_reload::{
+    config_changes, format_changes, gpu_buffer_capacity, particles_within_budget, ReloadDebounce,
+    RELOAD_DEBOUNCE,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::window_event::{default_key_action, Action, KeyCode};
+use fireworks_sim::utils::human_bytes::ByteSize;
+use helpers::{DummyAudio, DummyPhysic};
+use std::time::{Duration, Instant};
+
+// ==================================
+// 1. Champs modifiés
+// ==================================
+
+#[test]
+fn test_physic_config_changes_are_listed() {
+    let old = PhysicConfig::default();
+    let new = PhysicConfig {
+        max_rockets: 64,
+        gravity: -50.0,
+        max_active_trail_particles: Some(1000),
+        ..old.clone()
+    };
+
+    let changes = config_changes(&old, &new).unwrap();
+    let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        vec!["gravity", "max_active_trail_particles", "max_rockets"]
+    );
+    // Option absente côté ancienne config
+    assert_eq!(changes[1].old, None);
+    assert_eq!(changes[1].new.as_deref(), Some("1000"));
+    assert_eq!(changes[2].to_string(), "max_rockets: 16384 → 64");
+
+    let summary = format_changes(&changes);
+    assert!(summary.starts_with("3 field(s) changed:"), "{}", summary);
+    assert!(summary.contains("max_active_trail_particles: unset → 1000"));
+}
+
+#[test]
+fn test_nested_and_unchanged_renderer_config() {
+    let old = RendererConfig::default();
+    assert!(config_changes(&old, &old.clone()).unwrap().is_empty());
+    assert_eq!(format_changes(&[]), "no field changed");
+
+    let mut new = old.clone();
+    new.camera.wheel_zoom_step = old.camera.wheel_zoom_step * 2.0;
+    let changes = config_changes(&old, &new).unwrap();
+    assert_eq!(changes.len(), 1, "{:?}", changes);
+    assert_eq!(changes[0].field, "camera.wheel_zoom_step");
+}
+
+// ==================================
+// 2. Recréation des buffers GPU
+// ==================================
+
+#[test]
+fn test_buffers_kept_when_capacity_is_unchanged() {
+    // Paramètres de simulation seuls : pas de réallocation (pas d'à-coup)
+    assert_eq!(
+        gpu_buffer_capacity(4096, 4096, ReloadResult::Unchanged),
+        None
+    );
+    // Réduction : les buffers gardent l'ancienne capacité
+    assert_eq!(gpu_buffer_capacity(4096, 1024, ReloadResult::Shrank), None);
+    assert_eq!(
+        gpu_buffer_capacity(4096, 1024, ReloadResult::ShrinkPending),
+        None
+    );
+    // Capacité moteur agrandie, mais déjà couverte par les buffers
+    assert_eq!(gpu_buffer_capacity(4096, 2048, ReloadResult::Grew), None);
+}
+
+#[test]
+fn test_buffers_grow_with_the_capacity() {
+    assert_eq!(
+        gpu_buffer_capacity(4096, 8192, ReloadResult::Grew),
+        Some(8192)
+    );
+    // Besoin supérieur aux buffers, même sans changement côté moteur
+    assert_eq!(
+        gpu_buffer_capacity(4096, 5000, ReloadResult::Unchanged),
+        Some(5000)
+    );
+}
+
+#[test]
+fn test_gpu_buffer_budget_caps_capacity() {
+    // 1 MiB de particules de 64 octets : 16384 au plus
+    assert_eq!(particles_within_budget(1000, 64, ByteSize::mib(1)), 1000);
+    assert_eq!(
+        particles_within_budget(16_384, 64, ByteSize::mib(1)),
+        16_384
+    );
+    assert_eq!(
+        particles_within_budget(50_000, 64, ByteSize::mib(1)),
+        16_384
+    );
+
+    // Budget lu dans renderer.toml sous forme lisible
+    let config: RendererConfig = toml::from_str("max_gpu_buffer = \"1.5 MiB\"").unwrap();
+    assert_eq!(config.max_gpu_buffer, ByteSize(1_572_864));
+    assert!(config.validate().is_empty());
+    let err = toml::from_str::<RendererConfig>("max_gpu_buffer = \"12 MiBs\"").unwrap_err();
+    assert!(err.to_string().contains("unknown unit 'MiBs'"), "{}", err);
+    let config: RendererConfig = toml::from_str("max_gpu_buffer = 0").unwrap();
+    assert!(config
+        .validate()
+        .iter()
+        .any(|v| v.contains("max_gpu_buffer")));
+    // Valeur par défaut des configs du dépôt
+    assert_eq!(RendererConfig::default().max_gpu_buffer, ByteSize::mib(256));
+    assert_eq!(
+        RendererConfig::from_file("assets/config/renderer.toml")
+            .unwrap()
+            .max_gpu_buffer,
+        ByteSize::mib(256)
+    );
+}
+
+// ==================================
+// 3. Anti-rebond
+// ==================================
+
+#[test]
+fn test_repeated_reloads_are_debounced() {
+    let mut debounce = ReloadDebounce::default();
+    let start = Instant::now();
+    assert!(debounce.accept(start));
+    assert!(!debounce.accept(start + Duration::from_millis(100)));
+    assert!(!debounce.accept(start + Duration::from_millis(249)));
+    // Délai compté depuis le dernier rechargement accepté
+    assert!(debounce.accept(start + RELOAD_DEBOUNCE));
+    assert!(!debounce.accept(start + RELOAD_DEBOUNCE + Duration::from_millis(10)));
+    assert!(debounce.accept(start + Duration::from_secs(1)));
+}
+
+// ==================================
+// 4. Touche et commande du rechargement de renderer.toml
+// ==================================
+
+#[test]
+fn test_renderer_reload_key() {
+    assert_eq!(default_key_action(KeyCode::R), Some(Action::ReloadConfig));
+    assert_eq!(
+        default_key_action(KeyCode::F7),
+        Some(Action::ReloadRendererConfig)
+    );
+    assert_eq!(
+        Action::from_name("reload_renderer_config"),
+        Some(Action::ReloadRendererConfig)
+    );
+}
+
+#[test]
+fn test_renderer_config_reload_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("renderer.toml");
+    std::fs::write(&path, "bloom_intensity = 3.5\n[camera]\nmin_zoom = 0.5\n").unwrap();
+
+    let shared = RendererShared::default();
+    shared
+        .config_manager
+        .borrow_mut()
+        .set_path(ConfigSection::Renderer, &path);
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let out = registry.execute(
+        &mut DummyAudio,
+        &mut DummyPhysic::default(),
+        "renderer.config.reload",
+    );
+    assert!(out.contains("bloom_intensity: 0.8 → 3.5"), "{}", out);
+    assert!(out.contains("camera.min_zoom"), "{}", out);
+    assert_eq!(shared.config.borrow().bloom_intensity, 3.5);
+    // La caméra reçoit sa nouvelle config
+    assert_eq!(shared.camera.borrow().config().min_zoom, 0.5);
+
+    // Fichier invalide : config courante conservée
+    std::fs::write(&path, "bloom_intensity = \"high\"\n").unwrap();
+    let out = registry.execute(
+        &mut DummyAudio,
+        &mut DummyPhysic::default(),
+        "renderer.config.reload",
+    );
+    assert!(out.starts_with("Renderer config not reloaded"), "{}", out);
+    assert_eq!(shared.config.borrow().bloom_intensity, 3.5);
+}
+
+#[test]
+fn test_physic_config_reload_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("physic.toml");
+    std::fs::write(&path, "gravity = -50.0\n").unwrap();
+
+    let shared = RendererShared::default();
+    shared
+        .config_manager
+        .borrow_mut()
+        .set_path(ConfigSection::Physic, &path);
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut physic = DummyPhysic::default();
+    let out = registry.execute(&mut DummyAudio, &mut physic, "physic.config.reload");
+    assert!(out.contains("gravity: -200 → -50"), "{}", out);
+    assert_eq!(shared.physic_reload.get(), Some(ReloadResult::Unchanged));
+
+    // Erreurs de validation recopiées telles quelles
+    std::fs::write(&path, "max_rockets = 0\n").unwrap();
+    let out = registry.execute(&mut DummyAudio, &mut physic, "physic.config.reload");
+    assert!(out.starts_with("Physic config not reloaded"), "{}", out);
+    assert!(
+        out.contains("max_rockets: must be greater than 0"),
+        "{}",
+        out
+    );
+}
@@ -0,0 +1,137 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use std::path::PathBuf;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. RendererConfig
+// ==================================
+
+#[test]
+fn test_renderer_config_defaults_and_partial_toml() {
+    let config = RendererConfig::default();
+    assert!(!config.motion_blur_enabled);
+    assert_eq!(config.effective_motion_blur(), 0.0);
+
+    // Les clés absentes prennent leur valeur par défaut
+    let config: RendererConfig = toml::from_str("motion_blur_enabled = true").unwrap();
+    assert!(config.motion_blur_enabled);
+    assert_eq!(config.effective_motion_blur(), 0.5);
+}
+
+#[test]
+fn test_renderer_config_file_in_assets_parses() {
+    assert!(RendererConfig::from_file("assets/config/renderer.toml").is_ok());
+}
+
+#[test]
+fn test_set_motion_blur_clamps_and_toggles() {
+    let mut config = RendererConfig::default();
+    config.set_motion_blur(2.0);
+    assert!(config.motion_blur_enabled);
+    assert_eq!(config.effective_motion_blur(), 1.0);
+
+    config.set_motion_blur(0.0);
+    assert!(!config.motion_blur_enabled);
+    assert_eq!(config.effective_motion_blur(), 0.0);
+}
+
+// ==================================
+// 2. Commandes console renderer.*
+// ==================================
+
+#[test]
+fn test_renderer_motionblur_command_updates_shared_config() {
+    let shared = RendererShared::default();
+    let config = shared.config.clone();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    assert!(registry
+        .get_commands()
+        .contains(&"renderer.motionblur".to_string()));
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.motionblur 0.8");
+    assert!(out.contains("0.80"), "{}", out);
+    assert!(config.borrow().motion_blur_enabled);
+    assert!((config.borrow().effective_motion_blur() - 0.8).abs() < 1e-6);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.motionblur");
+    assert!(out.contains("on"), "{}", out);
+
+    // Valeurs invalides : config inchangée
+    for bad in ["renderer.motionblur 1.5", "renderer.motionblur abc"] {
+        let out = registry.execute(&mut audio, &mut physic, bad);
+        assert!(out.starts_with("Usage"), "{}", out);
+    }
+    assert!((config.borrow().motion_blur_strength - 0.8).abs() < 1e-6);
+
+    registry.execute(&mut audio, &mut physic, "renderer.motionblur 0");
+    assert!(!config.borrow().motion_blur_enabled);
+}
+
+#[test]
+fn test_renderer_screenshot_command_queues_request() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+    assert!(shared.screenshot_request.borrow().is_none());
+
+    // Sans argument : chemin horodaté choisi au moment de la capture
+    registry.execute(&mut audio, &mut physic, "renderer.screenshot");
+    assert_eq!(shared.screenshot_request.borrow_mut().take(), Some(None));
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.screenshot shots/a.png");
+    assert!(out.contains("shots/a.png"), "{}", out);
+    assert_eq!(
+        *shared.screenshot_request.borrow(),
+        Some(Some(PathBuf::from("shots/a.png")))
+    );
+}
+
+#[test]
+fn test_renderer_screenshot_hdr_flag() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.screenshot --hdr");
+    assert!(out.contains("HDR"), "{}", out);
+    assert_eq!(
+        shared.hdr_screenshot_request.borrow_mut().take(),
+        Some(None)
+    );
+    assert!(shared.screenshot_request.borrow().is_none());
+
+    registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.screenshot --hdr shots/a.exr",
+    );
+    assert_eq!(
+        shared.hdr_screenshot_request.borrow_mut().take(),
+        Some(Some(PathBuf::from("shots/a.exr")))
+    );
+
+    // Option inconnue ou argument en trop : rien n'est demandé
+    for command in [
+        "renderer.screenshot --exr",
+        "renderer.screenshot a.png b.png",
+    ] {
+        let out = registry.execute(&mut audio, &mut physic, command);
+        assert!(out.starts_with("Usage:"), "{}", out);
+    }
+    assert!(shared.screenshot_request.borrow().is_none());
+    assert!(shared.hdr_screenshot_request.borrow().is_none());
+}
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+
+use fireworks_sim::physic_engine::{Particle, ParticleType};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{BlendMode, RendererConfig};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::utils::depth_sort::{back_to_front, sort_key, DepthSorter};
+use fireworks_sim::renderer_engine::ParticleGPU;
+use glam::Vec2;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+fn particle_at(depth: f32, x: f32) -> Particle {
+    Particle {
+        depth,
+        pos: Vec2::new(x, 0.0),
+        ..Default::default()
+    }
+}
+
+// ==================================
+// 1. Comparateur
+// ==================================
+
+#[test]
+fn test_back_to_front_comparator() {
+    let far = (sort_key(&particle_at(800.0, 0.0)), 0);
+    let near = (sort_key(&particle_at(10.0, 0.0)), 1);
+    // Le plus lointain est dessiné en premier
+    assert_eq!(back_to_front(&far, &near), Ordering::Less);
+    assert_eq!(back_to_front(&near, &far), Ordering::Greater);
+    // Profondeur égale : ordre d'émission
+    assert_eq!(back_to_front(&(5.0, 2), &(5.0, 3)), Ordering::Less);
+    // NaN : ordre total, pas de panique
+    assert_ne!(back_to_front(&(f32::NAN, 0), &(1.0, 1)), Ordering::Equal);
+}
+
+// ==================================
+// 2. Tri dans le buffer
+// ==================================
+
+#[test]
+fn test_fill_sorted_writes_back_to_front() {
+    let particles = [
+        particle_at(10.0, 1.0),
+        particle_at(500.0, 2.0),
+        particle_at(10.0, 3.0),
+        particle_at(100.0, 4.0),
+    ];
+    let mut sorter = DepthSorter::new(8);
+    let mut out = [ParticleGPU::default(); 8];
+
+    let count = sorter.fill_sorted(particles.iter(), &mut out);
+    assert_eq!(count, 4);
+    let order: Vec<f32> = out[..count].iter().map(|p| p.pos_x).collect();
+    assert_eq!(order, vec![2.0, 4.0, 1.0, 3.0]);
+}
+
+#[test]
+fn test_fill_sorted_respects_capacity_and_reuses_buffers() {
+    let particles: Vec<Particle> = (0..100).map(|i| particle_at(i as f32, i as f32)).collect();
+    let mut sorter = DepthSorter::new(16);
+    let mut out = [ParticleGPU::default(); 16];
+
+    assert_eq!(sorter.fill_sorted(particles.iter(), &mut out), 16);
+    // Les 16 premières émises, la plus lointaine d'abord
+    assert_eq!(out[0].pos_x, 15.0);
+    assert_eq!(out[15].pos_x, 0.0);
+
+    // Frames suivantes : aucune réallocation
+    let capacity = sorter.capacity();
+    for _ in 0..10 {
+        sorter.fill_sorted(particles.iter(), &mut out);
+    }
+    assert_eq!(sorter.capacity(), capacity);
+}
+
+// ==================================
+// 3. Configuration
+// ==================================
+
+#[test]
+fn test_additive_types_skip_sorting() {
+    let mut config = RendererConfig {
+        depth_sort_enabled: true,
+        ..Default::default()
+    };
+    config.particles.smoke.blend = BlendMode::Alpha;
+    config.particles.explosion.blend = BlendMode::Additive;
+
+    assert!(config.depth_sort_for(ParticleType::Smoke));
+    assert!(!config.depth_sort_for(ParticleType::Explosion));
+
+    // Tri désactivé : aucun type trié
+    config.depth_sort_enabled = false;
+    assert!(ParticleType::ALL.iter().all(|&t| !config.depth_sort_for(t)));
+}
+
+#[test]
+fn test_depth_sort_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.particles.sort on");
+    assert_eq!(out, "Depth sort: on");
+    assert!(shared.config.borrow().depth_sort_enabled);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.particles.sort");
+    assert_eq!(out, "Depth sort: off");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.particles.sort maybe");
+    assert!(out.starts_with("Usage"), "{}", out);
+}
@@ -0,0 +1,146 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::display_scale::{
+    effective_content_scale, format_display_scale, DisplayScale, CONTENT_SCALE_RANGE,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::window_event::{EventRouter, Reaction, WindowEvent};
+use glam::Vec2;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+/// Fenêtre 1280x720 : écran standard, Retina (framebuffer doublé), Windows à 150 %
+fn standard() -> DisplayScale {
+    DisplayScale {
+        content_scale: 1.0,
+        window_size: (1280, 720),
+        framebuffer_size: (1280, 720),
+    }
+}
+
+fn retina() -> DisplayScale {
+    DisplayScale {
+        content_scale: 2.0,
+        window_size: (1280, 720),
+        framebuffer_size: (2560, 1440),
+    }
+}
+
+fn windows_150() -> DisplayScale {
+    DisplayScale {
+        content_scale: 1.5,
+        window_size: (1920, 1080),
+        framebuffer_size: (1920, 1080),
+    }
+}
+
+// ==================================
+// 1. Échelles dérivées
+// ==================================
+
+#[test]
+fn test_effective_content_scale_is_sanitized() {
+    assert_eq!(effective_content_scale(1.25, 1.25), 1.25);
+    // Axes différents : le plus grand
+    assert_eq!(effective_content_scale(1.0, 2.0), 2.0);
+    // Valeurs aberrantes : bornées, ou 1.0 si inutilisables
+    assert_eq!(effective_content_scale(12.0, 12.0), CONTENT_SCALE_RANGE.1);
+    assert_eq!(effective_content_scale(0.1, 0.1), CONTENT_SCALE_RANGE.0);
+    assert_eq!(effective_content_scale(0.0, 0.0), 1.0);
+    assert_eq!(effective_content_scale(f32::NAN, f32::NAN), 1.0);
+}
+
+#[test]
+fn test_scales_per_platform() {
+    let display = standard();
+    assert_eq!(display.framebuffer_ratio(), Vec2::ONE);
+    assert_eq!(display.ui_scale(), 1.0);
+    assert_eq!(display.particle_scale(), 1.0);
+
+    // Retina : le backend ImGui applique déjà le rapport x2, rien à ajouter
+    let display = retina();
+    assert_eq!(display.framebuffer_ratio(), Vec2::splat(2.0));
+    assert_eq!(display.ui_scale(), 1.0);
+    assert_eq!(display.particle_scale(), 2.0);
+
+    // Windows 150 % : framebuffer en pixels, tout est agrandi par le facteur
+    let display = windows_150();
+    assert_eq!(display.framebuffer_ratio(), Vec2::ONE);
+    assert_eq!(display.ui_scale(), 1.5);
+    assert_eq!(display.particle_scale(), 1.5);
+}
+
+#[test]
+fn test_unknown_sizes_keep_unit_ratio() {
+    // Fenêtre réduite (tailles nulles) : pas de division par zéro
+    let display = DisplayScale {
+        window_size: (0, 0),
+        framebuffer_size: (0, 0),
+        ..retina()
+    };
+    assert_eq!(display.framebuffer_ratio(), Vec2::ONE);
+    assert_eq!(
+        display.to_framebuffer(Vec2::new(10.0, 20.0)),
+        Vec2::new(10.0, 20.0)
+    );
+    assert_eq!(DisplayScale::default().ui_scale(), 1.0);
+}
+
+// ==================================
+// 2. Propagation par l'EventRouter
+// ==================================
+
+#[test]
+fn test_router_propagates_content_scale_changes() {
+    let mut router = EventRouter::new(720.0);
+    router.display = standard();
+
+    // Fenêtre déplacée sur un écran à 200 %
+    let reaction = router.route(&WindowEvent::ContentScale(2.0, 2.0), false);
+    assert_eq!(router.display.content_scale, 2.0);
+    assert_eq!(reaction, Some(Reaction::Rescale(router.display)));
+
+    // Le système agrandit ensuite le framebuffer puis la fenêtre
+    assert_eq!(
+        router.route(&WindowEvent::Resize(2560, 1440), false),
+        Some(Reaction::Resize(2560, 1440))
+    );
+    let reaction = router.route(&WindowEvent::WindowSize(1280, 720), false);
+    assert_eq!(router.display, retina());
+    assert_eq!(reaction, Some(Reaction::Rescale(retina())));
+}
+
+#[test]
+fn test_cursor_converted_to_framebuffer_pixels() {
+    let mut router = EventRouter::new(1440.0);
+    router.display = retina();
+
+    // Coin bas-droit de la fenêtre (coordonnées écran) → coin du framebuffer, y vers le haut
+    router.route(&WindowEvent::CursorPos(1280.0, 720.0), false);
+    assert_eq!(router.cursor_pos, Vec2::new(2560.0, 0.0));
+
+    // Le déplacement de la caméra suit les pixels du framebuffer
+    router.camera_drag = true;
+    let reaction = router.route(&WindowEvent::CursorPos(1270.0, 710.0), false);
+    assert_eq!(reaction, Some(Reaction::Pan(Vec2::new(-20.0, 20.0))));
+}
+
+// ==================================
+// 3. Console
+// ==================================
+
+#[test]
+fn test_scale_command_reports_current_scale() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    shared.display_scale.set(retina());
+    let out = registry.execute(&mut audio, &mut physic, "renderer.window.scale");
+    assert_eq!(out, format_display_scale(&retina()));
+    assert!(out.contains("content x2.00"));
+    assert!(out.contains("framebuffer 2560x1440 (x2.00)"));
+    assert!(out.contains("UI x1.00, particles x2.00"));
+}
@@ -0,0 +1,75 @@
+use fireworks_sim::renderer_engine::utils::fence_ring::{RegionRing, BUFFER_REGIONS};
+
+// ==================================
+// 1. Tourniquet des régions
+// ==================================
+
+#[test]
+fn test_region_ring_cycles_from_zero() {
+    let mut ring = RegionRing::new(BUFFER_REGIONS);
+    assert_eq!(ring.regions(), 3);
+    let order: Vec<usize> = (0..7).map(|_| ring.advance()).collect();
+    assert_eq!(order, vec![0, 1, 2, 0, 1, 2, 0]);
+}
+
+#[test]
+fn test_region_ring_offsets_advance_per_frame() {
+    let mut ring = RegionRing::new(3);
+    let region_size = 1000;
+    let offsets: Vec<usize> = (0..4)
+        .map(|_| {
+            ring.advance();
+            ring.offset(region_size)
+        })
+        .collect();
+    assert_eq!(offsets, vec![0, 1000, 2000, 0]);
+}
+
+#[test]
+fn test_region_ring_degenerate_sizes() {
+    // Zéro région : ramené à un buffer unique
+    let mut ring = RegionRing::new(0);
+    assert_eq!(ring.regions(), 1);
+    assert_eq!(ring.advance(), 0);
+    assert_eq!(ring.advance(), 0);
+    assert_eq!(ring.offset(64), 0);
+}
+
+// ==================================
+// 2. Rendu réel (contexte GL requis)
+// ==================================
+
+#[cfg(feature = "interactive_tests")]
+#[test]
+fn test_triple_buffered_particles_render_without_long_waits() {
+    use fireworks_sim::physic_engine::{
+        config::PhysicConfig, physic_engine_generational_arena::PhysicEngineFireworks,
+        ParticleType, PhysicEngine, PhysicEngineIterator,
+    };
+    use fireworks_sim::renderer_engine::renderer::Renderer;
+    use std::time::Duration;
+
+    let config = PhysicConfig::default();
+    let mut renderer = Renderer::new_headless(320, 240, &config).unwrap();
+    let mut physic = PhysicEngineFireworks::with_seed(&config, 320.0, 7);
+    let max_particles = config.max_rockets * config.particles_per_explosion;
+
+    let mut longest_wait = Duration::ZERO;
+    for _ in 0..600 {
+        physic.update(1.0 / 60.0);
+        let drawn = unsafe { renderer.render_offscreen(&physic) };
+        let expected = physic.iter_active_particles().count().min(max_particles)
+            + physic
+                .iter_particles_by_type(ParticleType::Rocket)
+                .count()
+                .min(config.max_rockets);
+        assert_eq!(drawn, expected);
+        longest_wait = longest_wait.max(renderer.gpu_sync_wait());
+    }
+    assert!(
+        longest_wait < Duration::from_millis(50),
+        "fence wait too long: {:?}",
+        longest_wait
+    );
+    renderer.close();
+}
@@ -0,0 +1,136 @@
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig, physic_engine_generational_arena::PhysicEngineFireworks, PhysicEngine,
+};
+use fireworks_sim::renderer_engine::file_drop::{handle_file_drop, DroppedFile};
+use fireworks_sim::renderer_engine::utils::glfw_window::translate_event;
+use fireworks_sim::renderer_engine::window_event::{EventRouter, Reaction, WindowEvent};
+use image::{Rgba, RgbaImage};
+use std::path::{Path, PathBuf};
+
+/// Petite image : un carré blanc opaque sur fond transparent (ou rien d'allumé).
+fn write_png(path: &Path, lit: bool) {
+    let img = RgbaImage::from_fn(8, 8, |x, y| {
+        if lit && (2..6).contains(&x) && (2..6).contains(&y) {
+            Rgba([255, 255, 255, 255])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    });
+    img.save(path).unwrap();
+}
+
+/// Dépôt simulé : événement glfw → événement neutre → réaction du routeur.
+fn drop_files(router: &mut EventRouter, paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let event = translate_event(&glfw::WindowEvent::FileDrop(paths)).unwrap();
+    match router.route(&event, false) {
+        Some(Reaction::FilesDropped(paths)) => paths,
+        other => panic!("unexpected reaction: {:?}", other),
+    }
+}
+
+// ==================================
+// 1. Événement et classification
+// ==================================
+
+#[test]
+fn test_file_drop_event_is_routed() {
+    let mut router = EventRouter::new(600.0);
+    let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+    assert_eq!(drop_files(&mut router, paths.clone()), paths);
+    // Dépôt vide : rien à faire
+    assert_eq!(
+        router.route(&WindowEvent::FileDrop(Vec::new()), false),
+        None
+    );
+}
+
+#[test]
+fn test_dropped_file_classification() {
+    assert_eq!(
+        DroppedFile::classify(Path::new("heart.PNG")),
+        DroppedFile::Image
+    );
+    assert_eq!(
+        DroppedFile::classify(Path::new("assets/config/renderer.toml")),
+        DroppedFile::Config
+    );
+    assert_eq!(
+        DroppedFile::classify(Path::new("assets/config/physic.toml")),
+        DroppedFile::Config
+    );
+    // Seules les configs rechargées par le renderer sont reconnues
+    assert_eq!(
+        DroppedFile::classify(Path::new("Cargo.toml")),
+        DroppedFile::Unsupported
+    );
+    assert_eq!(
+        DroppedFile::classify(Path::new("notes.txt")),
+        DroppedFile::Unsupported
+    );
+}
+
+// ==================================
+// 2. Chargement dans le moteur physique
+// ==================================
+
+#[test]
+fn test_dropped_images_install_explosion_shape() {
+    let dir = tempfile::tempdir().unwrap();
+    let (heart, star) = (dir.path().join("heart.png"), dir.path().join("star.png"));
+    write_png(&heart, true);
+    write_png(&star, true);
+
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 800.0);
+    assert_eq!(physic.explosion_shape_name(), "sphere");
+
+    let mut router = EventRouter::new(600.0);
+    let paths = drop_files(&mut router, vec![heart, star]);
+    let outcome = handle_file_drop(&paths, &mut physic);
+
+    assert_eq!(physic.explosion_shape_name(), "images");
+    assert_eq!(
+        outcome.messages,
+        ["Explosion shape: 2 image(s) (heart, star)"]
+    );
+    assert!(!outcome.reload_config);
+}
+
+#[test]
+fn test_dropped_image_errors_reach_console() {
+    let dir = tempfile::tempdir().unwrap();
+    let dark = dir.path().join("dark.png");
+    write_png(&dark, false);
+
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 800.0);
+    let outcome = handle_file_drop(&[dark, dir.path().join("missing.png")], &mut physic);
+
+    // Aucune forme installée si une image échoue
+    assert_eq!(physic.explosion_shape_name(), "sphere");
+    assert_eq!(outcome.messages.len(), 1);
+    assert!(outcome.messages[0].starts_with("Error:"), "{:?}", outcome);
+    assert!(
+        outcome.messages[0].contains("no lit pixel"),
+        "{:?}",
+        outcome
+    );
+
+    let outcome = handle_file_drop(&[dir.path().join("missing.png")], &mut physic);
+    assert!(outcome.messages[0].contains("missing.png"), "{:?}", outcome);
+}
+
+#[test]
+fn test_dropped_config_requests_reload() {
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 800.0);
+    let outcome = handle_file_drop(
+        &[
+            PathBuf::from("assets/config/physic.toml"),
+            PathBuf::from("notes.txt"),
+        ],
+        &mut physic,
+    );
+    assert!(outcome.reload_config);
+    assert_eq!(
+        outcome.messages,
+        ["Ignored dropped file: notes.txt", "Config reload requested"]
+    );
+}
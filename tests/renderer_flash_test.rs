@@ -0,0 +1,202 @@
+mod helpers;
+
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::flash::{
+    flash_decay, flash_falloff, with_flash_glsl, Flash, FlashList, FLASH_GLSL, FLASH_RADIUS,
+    MAX_FLASHES,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::Simulator;
+use glam::{Vec2, Vec3};
+use helpers::{DummyAudio, DummyPhysic, TestRenderer};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DURATION: f32 = 0.15;
+
+fn flash_at(x: f32) -> Flash {
+    Flash::new(Vec2::new(x, 0.0), Vec3::ONE, 1.0)
+}
+
+// ==================================
+// 1. Décroissance et atténuation
+// ==================================
+
+#[test]
+fn test_flash_decay() {
+    assert_eq!(flash_decay(0.0, DURATION), 1.0);
+    // e^-3 à la fin de la durée, puis éteint
+    let end = flash_decay(DURATION * 0.999, DURATION);
+    assert!((end - (-3.0f32).exp()).abs() < 1e-3, "{}", end);
+    assert_eq!(flash_decay(DURATION, DURATION), 0.0);
+    assert_eq!(flash_decay(0.0, 0.0), 0.0);
+    // Exponentielle : chaque tiers de la durée divise par e
+    let third = flash_decay(DURATION / 3.0, DURATION);
+    let two_thirds = flash_decay(2.0 * DURATION / 3.0, DURATION);
+    assert!((third - (-1.0f32).exp()).abs() < 1e-5);
+    assert!((two_thirds / third - third).abs() < 1e-5);
+}
+
+#[test]
+fn test_flash_falloff() {
+    assert_eq!(flash_falloff(0.0, FLASH_RADIUS), 1.0);
+    assert_eq!(flash_falloff(FLASH_RADIUS, FLASH_RADIUS), 0.5);
+    assert_eq!(flash_falloff(2.0 * FLASH_RADIUS, FLASH_RADIUS), 0.2);
+}
+
+#[test]
+fn test_light_at_sums_flashes() {
+    let mut flashes = FlashList::new();
+    assert_eq!(flashes.light_at(Vec2::ZERO, DURATION, 1.0), Vec3::ZERO);
+
+    flashes.add(Flash::new(Vec2::ZERO, Vec3::new(1.0, 0.5, 0.0), 2.0));
+    flashes.add(Flash::new(
+        Vec2::new(FLASH_RADIUS, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        1.0,
+    ));
+    let light = flashes.light_at(Vec2::ZERO, DURATION, 0.5);
+    assert_eq!(light, Vec3::new(1.0, 0.5, 0.25));
+}
+
+// ==================================
+// 2. Liste des éclairs actifs
+// ==================================
+
+#[test]
+fn test_update_removes_faded_flashes() {
+    let mut flashes = FlashList::new();
+    flashes.add(flash_at(0.0));
+    flashes.update(0.1, DURATION);
+    flashes.add(flash_at(1.0));
+    assert_eq!(flashes.len(), 2);
+
+    flashes.update(0.06, DURATION);
+    assert_eq!(flashes.len(), 1);
+    let remaining = flashes.iter().next().unwrap();
+    assert_eq!(remaining.position.x, 1.0);
+    assert!((remaining.age - 0.06).abs() < 1e-6);
+
+    flashes.update(0.1, DURATION);
+    assert!(flashes.is_empty());
+}
+
+#[test]
+fn test_full_list_evicts_oldest() {
+    let mut flashes = FlashList::new();
+    for i in 0..MAX_FLASHES {
+        flashes.add(flash_at(i as f32));
+        flashes.update(0.001, 1.0);
+    }
+    assert_eq!(flashes.len(), MAX_FLASHES);
+
+    // Le premier ajouté (x = 0) est le plus ancien
+    flashes.add(flash_at(100.0));
+    assert_eq!(flashes.len(), MAX_FLASHES);
+    let xs: Vec<f32> = flashes.iter().map(|f| f.position.x).collect();
+    assert!(!xs.contains(&0.0), "{:?}", xs);
+    assert!(xs.contains(&100.0));
+    assert!(xs.contains(&1.0));
+
+    // Puis le suivant (x = 1)
+    flashes.add(flash_at(200.0));
+    let xs: Vec<f32> = flashes.iter().map(|f| f.position.x).collect();
+    assert!(!xs.contains(&1.0) && xs.contains(&200.0), "{:?}", xs);
+}
+
+#[test]
+fn test_uniforms_packing() {
+    let mut flashes = FlashList::new();
+    assert_eq!(flashes.uniforms(DURATION, 1.0).count, 0);
+
+    flashes.add(Flash::new(
+        Vec2::new(3.0, 4.0),
+        Vec3::new(1.0, 0.5, 0.25),
+        2.0,
+    ));
+    flashes.update(DURATION / 3.0, DURATION);
+    let uniforms = flashes.uniforms(DURATION, 0.5);
+    assert_eq!(uniforms.count, 1);
+    assert_eq!(uniforms.positions[0], [3.0, 4.0]);
+    let decay = (-1.0f32).exp();
+    for (got, expected) in uniforms.colors[0].iter().zip([1.0, 0.5, 0.25]) {
+        assert!(
+            (got - expected * decay).abs() < 1e-5,
+            "{:?}",
+            uniforms.colors[0]
+        );
+    }
+    // Emplacements inutilisés à zéro
+    assert_eq!(uniforms.colors[1], [0.0; 3]);
+}
+
+// ==================================
+// 3. Shaders, config et console
+// ==================================
+
+#[test]
+fn test_flash_glsl_inserted_after_version() {
+    let source = "  #version 330 core\nout vec4 FragColor;\nvoid main() {}\n";
+    let patched = with_flash_glsl(source);
+    assert!(patched.starts_with("  #version 330 core\n"));
+    let glsl_at = patched.find("vec3 flash_light").unwrap();
+    assert!(glsl_at < patched.find("out vec4 FragColor").unwrap());
+    assert!(FLASH_GLSL.contains(&format!("#define MAX_FLASHES {}", MAX_FLASHES)));
+}
+
+#[test]
+fn test_flash_config() {
+    let config = RendererConfig::from_file("assets/config/renderer.toml").unwrap();
+    assert!(config.flash_enabled);
+    assert_eq!(config.flash_duration, DURATION);
+    assert_eq!(config, RendererConfig::default());
+
+    let invalid = RendererConfig {
+        flash_intensity: -1.0,
+        flash_duration: 0.0,
+        ..RendererConfig::default()
+    };
+    let violations = invalid.validate();
+    assert!(violations.iter().any(|v| v.starts_with("flash_intensity")));
+    assert!(violations.iter().any(|v| v.starts_with("flash_duration")));
+}
+
+#[test]
+fn test_flash_console_toggle() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut DummyAudio, &mut physic, "renderer.flash off");
+    assert_eq!(out, "Explosion flash: off");
+    assert!(!shared.config.borrow().flash_enabled);
+    let out = registry.execute(&mut DummyAudio, &mut physic, "renderer.flash");
+    assert_eq!(out, "Explosion flash: on");
+    let out = registry.execute(&mut DummyAudio, &mut physic, "renderer.flash maybe");
+    assert_eq!(out, "Usage: renderer.flash <on|off>");
+}
+
+// ==================================
+// 4. Transmission par le Simulator
+// ==================================
+
+#[test]
+fn test_simulator_forwards_explosions_to_renderer() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let physic = PhysicEngineFireworks::with_seed(&PhysicConfig::default(), 1024.0, 3);
+    let mut simulator = Simulator::new(TestRenderer::new(log.clone()), physic, DummyAudio);
+    for _ in 0..60 * 20 {
+        simulator.step(1.0 / 60.0);
+        if !log.borrow().is_empty() {
+            break;
+        }
+    }
+    assert_eq!(
+        log.borrow().first().map(String::as_str),
+        Some("renderer.add_flash")
+    );
+}
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::frame_graph::{
+    execution_order, format_pass_list, FrameContext, FrameGraph, PassResources, PassStatus,
+    RenderPass, Resource,
+};
+use fireworks_sim::renderer_engine::render_passes::default_passes;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+/// Passe factice : ne déclare que ses dépendances
+struct MockPass {
+    name: &'static str,
+    inputs: &'static [Resource],
+    outputs: &'static [Resource],
+}
+
+impl RenderPass for MockPass {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn inputs(&self) -> &'static [Resource] {
+        self.inputs
+    }
+
+    fn outputs(&self) -> &'static [Resource] {
+        self.outputs
+    }
+
+    unsafe fn execute(&mut self, _res: &mut PassResources, _frame: &FrameContext) {}
+}
+
+fn mock(
+    name: &'static str,
+    inputs: &'static [Resource],
+    outputs: &'static [Resource],
+) -> Box<dyn RenderPass> {
+    Box::new(MockPass {
+        name,
+        inputs,
+        outputs,
+    })
+}
+
+fn names(passes: &[Box<dyn RenderPass>], order: &[usize]) -> Vec<&'static str> {
+    order.iter().map(|&i| passes[i].name()).collect()
+}
+
+// ==================================
+// 1. Ordre d'exécution
+// ==================================
+
+#[test]
+fn test_passes_follow_their_producers() {
+    // Déclarées dans le désordre
+    let passes = vec![
+        mock("fxaa", &[Resource::Ldr], &[Resource::Output]),
+        mock(
+            "tonemap",
+            &[Resource::Scene, Resource::BloomBlur],
+            &[Resource::Ldr],
+        ),
+        mock("blur", &[Resource::BloomBright], &[Resource::BloomBlur]),
+        mock("extract", &[Resource::Scene], &[Resource::BloomBright]),
+        mock("scene", &[], &[Resource::Scene]),
+    ];
+    let order = execution_order(&passes).unwrap();
+    assert_eq!(
+        names(&passes, &order),
+        vec!["scene", "extract", "blur", "tonemap", "fxaa"]
+    );
+}
+
+#[test]
+fn test_independent_passes_keep_declaration_order() {
+    let passes = vec![
+        mock("b", &[], &[Resource::BloomBright]),
+        mock("a", &[], &[Resource::Scene]),
+        mock(
+            "c",
+            &[Resource::Scene, Resource::BloomBright],
+            &[Resource::Ldr],
+        ),
+    ];
+    let order = execution_order(&passes).unwrap();
+    assert_eq!(names(&passes, &order), vec!["b", "a", "c"]);
+}
+
+#[test]
+fn test_invalid_graphs_are_rejected() {
+    // Entrée sans producteur
+    let missing = vec![mock("tonemap", &[Resource::Scene], &[Resource::Ldr])];
+    let err = execution_order(&missing).unwrap_err().to_string();
+    assert!(err.contains("scene"), "{err}");
+
+    // Deux producteurs pour la même ressource
+    let duplicate = vec![
+        mock("scene", &[], &[Resource::Scene]),
+        mock("overlay", &[], &[Resource::Scene]),
+    ];
+    let err = execution_order(&duplicate).unwrap_err().to_string();
+    assert!(err.contains("scene") && err.contains("overlay"), "{err}");
+
+    // Cycle
+    let cycle = vec![
+        mock("a", &[Resource::Ldr], &[Resource::Scene]),
+        mock("b", &[Resource::Scene], &[Resource::Ldr]),
+    ];
+    let err = execution_order(&cycle).unwrap_err().to_string();
+    assert!(err.contains("cycle"), "{err}");
+    assert!(FrameGraph::new(cycle).is_err());
+}
+
+#[test]
+fn test_default_passes_order() {
+    let graph = FrameGraph::new(default_passes()).unwrap();
+    assert_eq!(
+        graph.names(),
+        vec!["scene", "bloom.extract", "bloom.blur", "tonemap", "fxaa"]
+    );
+}
+
+// ==================================
+// 2. Console
+// ==================================
+
+#[test]
+fn test_pass_list_format() {
+    let statuses = vec![
+        PassStatus {
+            name: "scene",
+            inputs: &[],
+            outputs: &[Resource::Scene],
+            enabled: true,
+            gpu_time: Some(Duration::from_micros(420)),
+        },
+        PassStatus {
+            name: "fxaa",
+            inputs: &[Resource::Ldr],
+            outputs: &[Resource::Output],
+            enabled: false,
+            gpu_time: None,
+        },
+    ];
+    let text = format_pass_list(&statuses);
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("scene") && lines[1].contains("0.420 ms"));
+    assert!(lines[2].contains("ldr -> output") && lines[2].contains("off"));
+}
+
+#[test]
+fn test_passes_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    // Avant la première frame
+    let out = registry.execute(&mut audio, &mut physic, "renderer.passes");
+    assert!(out.contains("No render pass"));
+
+    shared.passes.borrow_mut().push(PassStatus {
+        name: "scene",
+        inputs: &[],
+        outputs: &[Resource::Scene],
+        enabled: true,
+        gpu_time: None,
+    });
+    let out = registry.execute(&mut audio, &mut physic, "renderer.passes");
+    assert!(out.contains("1. scene"));
+    assert!(out.contains("n/a"));
+}
@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::utils::frame_limiter::{
+    frame_budget, wait_plan, FrameLimiter, SPIN_MARGIN,
+};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+const MS: Duration = Duration::from_millis(1);
+
+// ==================================
+// 1. Calcul de l'attente
+// ==================================
+
+#[test]
+fn test_frame_budget() {
+    assert_eq!(frame_budget(None), None);
+    assert_eq!(frame_budget(Some(0)), None);
+    assert_eq!(frame_budget(Some(50)), Some(20 * MS));
+    let budget_60 = frame_budget(Some(60)).unwrap();
+    assert!((budget_60.as_secs_f64() - 1.0 / 60.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_wait_plan_for_various_frame_durations() {
+    let budget = 20 * MS;
+    // Frame rapide : sommeil puis attente active sur la marge
+    assert_eq!(
+        wait_plan(5 * MS, budget, SPIN_MARGIN),
+        (13 * MS, SPIN_MARGIN)
+    );
+    // Proche de l'échéance : uniquement de l'attente active
+    assert_eq!(
+        wait_plan(19 * MS, budget, SPIN_MARGIN),
+        (Duration::ZERO, MS)
+    );
+    // Exactement au budget, ou au-delà : aucune attente
+    assert_eq!(
+        wait_plan(budget, budget, SPIN_MARGIN),
+        (Duration::ZERO, Duration::ZERO)
+    );
+    assert_eq!(
+        wait_plan(35 * MS, budget, SPIN_MARGIN),
+        (Duration::ZERO, Duration::ZERO)
+    );
+    // La somme couvre toujours exactement le temps restant
+    for elapsed_ms in 0..25 {
+        let elapsed = elapsed_ms * MS;
+        let (sleep, spin) = wait_plan(elapsed, budget, SPIN_MARGIN);
+        assert_eq!(sleep + spin, budget.saturating_sub(elapsed));
+        assert!(spin <= SPIN_MARGIN);
+    }
+}
+
+#[test]
+fn test_frame_limiter_holds_the_cap() {
+    let mut limiter = FrameLimiter::default();
+    let budget = Some(5 * MS);
+    limiter.wait(budget);
+
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.wait(budget);
+    }
+    // 4 frames « vides » à 200 FPS : au moins 20 ms
+    assert!(start.elapsed() >= 20 * MS);
+
+    // Sans plafond : aucune attente
+    assert!(limiter.wait(None) < MS);
+}
+
+// ==================================
+// 2. Configuration et console
+// ==================================
+
+#[test]
+fn test_limiter_only_active_without_vsync() {
+    let mut config: RendererConfig = toml::from_str("max_fps = 30").unwrap();
+    assert!(config.vsync);
+    assert_eq!(config.max_fps, Some(30));
+    assert_eq!(config.frame_budget(), None);
+
+    config.vsync = false;
+    assert_eq!(config.frame_budget(), frame_budget(Some(30)));
+    assert_eq!(RendererConfig::default().max_fps, None);
+}
+
+#[test]
+fn test_vsync_and_fps_cap_commands() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.fps_cap 120");
+    assert_eq!(out, "FPS cap: 120 (inactive while v-sync is on)");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.vsync off");
+    assert_eq!(out, "V-sync: off");
+    assert_eq!(
+        shared.config.borrow().frame_budget(),
+        frame_budget(Some(120))
+    );
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.fps_cap");
+    assert_eq!(out, "FPS cap: 120");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.fps_cap off");
+    assert_eq!(out, "FPS cap: off");
+    assert_eq!(shared.config.borrow().max_fps, None);
+
+    for bad in [
+        "renderer.fps_cap 0",
+        "renderer.fps_cap fast",
+        "renderer.vsync 2",
+    ] {
+        let out = registry.execute(&mut audio, &mut physic, bad);
+        assert!(out.starts_with("Usage"), "{}", out);
+    }
+}
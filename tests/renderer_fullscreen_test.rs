@@ -0,0 +1,223 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::fullscreen::{
+    format_monitors, DisplayMode, FullscreenRequest, FullscreenState, MonitorInfo, WindowPlacement,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+const WINDOW_POS: (i32, i32) = (100, 80);
+const WINDOW_SIZE: (i32, i32) = (1280, 720);
+
+/// Écran principal 1080p et projecteur 720p à sa droite
+fn monitors() -> Vec<MonitorInfo> {
+    vec![
+        MonitorInfo {
+            name: "Desktop".to_string(),
+            position: (0, 0),
+            size: (1920, 1080),
+            refresh_rate: 144,
+            work_area: (0, 0, 1920, 1040),
+            primary: true,
+        },
+        MonitorInfo {
+            name: "Projector".to_string(),
+            position: (1920, 0),
+            size: (1280, 720),
+            refresh_rate: 60,
+            work_area: (1920, 0, 1280, 720),
+            primary: false,
+        },
+    ]
+}
+
+fn resolve(
+    state: &mut FullscreenState,
+    request: FullscreenRequest,
+    config: (usize, bool),
+) -> Option<WindowPlacement> {
+    state
+        .resolve(request, &monitors(), WINDOW_POS, WINDOW_SIZE, config)
+        .unwrap()
+}
+
+// ==================================
+// 1. Machine à états
+// ==================================
+
+#[test]
+fn test_toggle_uses_configured_monitor() {
+    let mut state = FullscreenState::new(WINDOW_POS, WINDOW_SIZE);
+
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Toggle, (1, false)),
+        Some(WindowPlacement::Fullscreen {
+            monitor: 1,
+            size: (1280, 720),
+            refresh_rate: 60
+        })
+    );
+    assert_eq!(state.mode, DisplayMode::Fullscreen { monitor: 1 });
+
+    // Seconde bascule : retour à la géométrie fenêtrée d'origine
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Toggle, (1, false)),
+        Some(WindowPlacement::Windowed {
+            pos: WINDOW_POS,
+            size: WINDOW_SIZE
+        })
+    );
+    assert_eq!(state.mode, DisplayMode::Windowed);
+}
+
+#[test]
+fn test_borderless_covers_work_area() {
+    let mut state = FullscreenState::new(WINDOW_POS, WINDOW_SIZE);
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Toggle, (0, true)),
+        Some(WindowPlacement::Borderless {
+            pos: (0, 0),
+            size: (1920, 1040)
+        })
+    );
+
+    // Passage direct sur un autre écran : la géométrie fenêtrée n'est pas écrasée
+    let placement = state
+        .resolve(
+            FullscreenRequest::Borderless(1),
+            &monitors(),
+            (0, 0),
+            (1920, 1040),
+            (0, true),
+        )
+        .unwrap();
+    assert_eq!(
+        placement,
+        Some(WindowPlacement::Borderless {
+            pos: (1920, 0),
+            size: (1280, 720)
+        })
+    );
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Windowed, (0, true)),
+        Some(WindowPlacement::Windowed {
+            pos: WINDOW_POS,
+            size: WINDOW_SIZE
+        })
+    );
+}
+
+#[test]
+fn test_unchanged_mode_and_missing_monitor() {
+    let mut state = FullscreenState::new(WINDOW_POS, WINDOW_SIZE);
+    // Déjà fenêtré : rien à faire
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Windowed, (0, false)),
+        None
+    );
+    resolve(&mut state, FullscreenRequest::Fullscreen(0), (0, false));
+    assert_eq!(
+        resolve(&mut state, FullscreenRequest::Fullscreen(0), (0, false)),
+        None
+    );
+
+    // Écran inconnu : erreur, mode inchangé
+    let err = state
+        .resolve(
+            FullscreenRequest::Fullscreen(3),
+            &monitors(),
+            WINDOW_POS,
+            WINDOW_SIZE,
+            (0, false),
+        )
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Monitor 3 not found (2 connected)");
+    assert_eq!(state.mode, DisplayMode::Fullscreen { monitor: 0 });
+}
+
+#[test]
+fn test_toggle_falls_back_to_primary_monitor() {
+    // Projecteur configuré mais débranché
+    let mut state = FullscreenState::new(WINDOW_POS, WINDOW_SIZE);
+    let placement = state
+        .resolve(
+            FullscreenRequest::Toggle,
+            &monitors()[..1],
+            WINDOW_POS,
+            WINDOW_SIZE,
+            (1, false),
+        )
+        .unwrap();
+    assert!(matches!(
+        placement,
+        Some(WindowPlacement::Fullscreen { monitor: 0, .. })
+    ));
+}
+
+// ==================================
+// 2. Commandes console et config
+// ==================================
+
+#[test]
+fn test_fullscreen_request_parse() {
+    assert_eq!(
+        FullscreenRequest::parse(&[], 1),
+        Some(FullscreenRequest::Toggle)
+    );
+    assert_eq!(
+        FullscreenRequest::parse(&["2"], 1),
+        Some(FullscreenRequest::Fullscreen(2))
+    );
+    assert_eq!(
+        FullscreenRequest::parse(&["borderless"], 1),
+        Some(FullscreenRequest::Borderless(1))
+    );
+    assert_eq!(
+        FullscreenRequest::parse(&["borderless", "0"], 1),
+        Some(FullscreenRequest::Borderless(0))
+    );
+    assert_eq!(
+        FullscreenRequest::parse(&["off"], 1),
+        Some(FullscreenRequest::Windowed)
+    );
+    assert_eq!(FullscreenRequest::parse(&["projector"], 1), None);
+}
+
+#[test]
+fn test_window_commands() {
+    let shared = RendererShared::default();
+    *shared.monitors.borrow_mut() = monitors();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.window.monitors");
+    assert!(
+        out.contains("1: Projector 1280x720@60Hz at (1920, 0)"),
+        "{}",
+        out
+    );
+    assert!(out.contains("[primary]"), "{}", out);
+    assert!(out.ends_with("Display: windowed"), "{}", out);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.window.fullscreen 1");
+    assert_eq!(out, "Display mode change requested");
+    assert_eq!(
+        *shared.fullscreen_request.borrow(),
+        Some(FullscreenRequest::Fullscreen(1))
+    );
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.window.fullscreen 5");
+    assert_eq!(out, "Monitor 5 not found (2 connected)");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.window.fullscreen big");
+    assert!(out.starts_with("Usage"), "{}", out);
+
+    let config: RendererConfig =
+        toml::from_str("fullscreen_monitor = 1\nfullscreen_borderless = true").unwrap();
+    assert_eq!(config.fullscreen_monitor, 1);
+    assert!(config.fullscreen_borderless);
+    assert_eq!(format_monitors(&[]), "No monitor detected");
+}
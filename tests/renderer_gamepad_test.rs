@@ -0,0 +1,233 @@
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig, explosion_shape::cycle_explosion_shape,
+    physic_engine_generational_arena::PhysicEngineFireworks, PhysicEngine,
+};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::gamepad::{
+    stick_response, GamepadBindings, GamepadController, GamepadDevice, GamepadInput, GamepadState,
+    RepeatLimiter, LAUNCH_REPEAT_INTERVAL, STICK_DEADZONE,
+};
+use fireworks_sim::renderer_engine::key_bindings::KeyBindings;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::window_event::{
+    Action, EventRouter, KeyCode, Reaction, WindowEvent,
+};
+use glam::Vec2;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+fn pad(pressed: &[GamepadInput], left_stick: Vec2) -> Vec<GamepadDevice> {
+    vec![GamepadDevice {
+        id: 0,
+        name: "Test Pad".to_string(),
+        state: GamepadState {
+            pressed: pressed.to_vec(),
+            left_stick,
+        },
+    }]
+}
+
+// ==================================
+// 1. Gâchette : répétition bornée
+// ==================================
+
+#[test]
+fn test_repeat_limiter_fires_on_press_then_at_interval() {
+    let mut limiter = RepeatLimiter::new(0.25);
+    assert!(!limiter.update(false, 0.016));
+    // Appui : déclenchement immédiat
+    assert!(limiter.update(true, 0.016));
+
+    // Tenu 1 s à 100 Hz : 4 répétitions (t = 0.25, 0.5, 0.75, 1.0)
+    let repeats = (0..100).filter(|_| limiter.update(true, 0.01)).count();
+    assert_eq!(repeats, 4);
+
+    // Relâché puis réappuyé : nouveau déclenchement immédiat
+    assert!(!limiter.update(false, 0.01));
+    assert!(limiter.update(true, 0.01));
+}
+
+#[test]
+fn test_repeat_limiter_is_framerate_independent() {
+    // Une frame longue ne déclenche qu'une fois (pas de rafale de rattrapage)
+    let mut limiter = RepeatLimiter::new(0.25);
+    limiter.update(true, 0.0);
+    assert!(limiter.update(true, 1.0));
+    assert!(!limiter.update(true, 0.01));
+
+    for dt in [0.005, 0.033] {
+        let mut limiter = RepeatLimiter::new(0.25);
+        let frames = (2.0 / dt) as usize;
+        let fired = (0..frames).filter(|_| limiter.update(true, dt)).count();
+        assert!((8..=9).contains(&fired), "dt={} fired={}", dt, fired);
+    }
+}
+
+// ==================================
+// 2. Sticks : zone morte et courbe de réponse
+// ==================================
+
+#[test]
+fn test_stick_deadzone_and_response_curve() {
+    assert_eq!(stick_response(Vec2::new(0.1, 0.05), 0.15, 2.0), Vec2::ZERO);
+    // Sortie de zone morte sans saut
+    let edge = stick_response(Vec2::new(0.151, 0.0), 0.15, 2.0);
+    assert!(edge.x > 0.0 && edge.x < 1e-4, "{:?}", edge);
+    // Course complète : 1, direction conservée
+    let full = stick_response(Vec2::new(0.0, -1.0), 0.15, 2.0);
+    assert!((full - Vec2::new(0.0, -1.0)).length() < 1e-6);
+    // Courbe quadratique : mi-course utile → quart de la vitesse
+    let half = stick_response(Vec2::new(0.575, 0.0), 0.15, 2.0);
+    assert!((half.x - 0.25).abs() < 1e-4, "{:?}", half);
+    // Diagonale saturée (stick carré) : norme plafonnée à 1
+    let corner = stick_response(Vec2::new(1.0, 1.0), 0.15, 1.0);
+    assert!((corner.length() - 1.0).abs() < 1e-6);
+}
+
+// ==================================
+// 3. Contrôleur
+// ==================================
+
+#[test]
+fn test_controller_maps_buttons_to_actions() {
+    let bindings = GamepadBindings::default();
+    let mut controller = GamepadController::default();
+
+    let (reactions, changed) =
+        controller.update(&pad(&[GamepadInput::A], Vec2::ZERO), &bindings, 0.016);
+    assert!(changed);
+    assert_eq!(reactions, [Reaction::Action(Action::NextShape)]);
+    assert_eq!(controller.connected(), [(0, "Test Pad".to_string())]);
+
+    // Bouton tenu : pas de répétition
+    let (reactions, changed) =
+        controller.update(&pad(&[GamepadInput::A], Vec2::ZERO), &bindings, 0.016);
+    assert!(!changed);
+    assert!(reactions.is_empty());
+
+    // Gâchette droite : lancement immédiat puis répété
+    let trigger = pad(&[GamepadInput::RightTrigger], Vec2::ZERO);
+    let (reactions, _) = controller.update(&trigger, &bindings, 0.016);
+    assert_eq!(reactions, [Reaction::Action(Action::LaunchRocket)]);
+    let (reactions, _) = controller.update(&trigger, &bindings, 0.1);
+    assert!(reactions.is_empty());
+    let (reactions, _) = controller.update(&trigger, &bindings, LAUNCH_REPEAT_INTERVAL);
+    assert_eq!(reactions, [Reaction::Action(Action::LaunchRocket)]);
+}
+
+#[test]
+fn test_controller_stick_pans_and_survives_disconnect() {
+    let bindings = GamepadBindings::default();
+    let mut controller = GamepadController::default();
+
+    // Stick dans la zone morte : caméra immobile
+    let (reactions, _) = controller.update(
+        &pad(&[], Vec2::new(STICK_DEADZONE * 0.5, 0.0)),
+        &bindings,
+        0.5,
+    );
+    assert!(reactions.is_empty());
+
+    // Stick à droite : la scène glisse vers la gauche
+    let (reactions, _) = controller.update(&pad(&[], Vec2::X), &bindings, 0.5);
+    match reactions.as_slice() {
+        [Reaction::Pan(delta)] => assert!(delta.x < 0.0 && delta.y == 0.0, "{:?}", delta),
+        other => panic!("unexpected reactions: {:?}", other),
+    }
+
+    // Débranchement en pleine pression : aucune réaction, liste vidée
+    let (reactions, changed) = controller.update(&[], &bindings, 0.016);
+    assert!(changed);
+    assert!(reactions.is_empty());
+    assert!(controller.connected().is_empty());
+}
+
+// ==================================
+// 4. Liaisons, actions et console
+// ==================================
+
+#[test]
+fn test_gamepad_bindings_from_input_toml() {
+    let (bindings, warnings) = KeyBindings::parse(
+        r#"
+        [gamepad]
+        launch_rocket = "RB"
+        screenshot = "none"
+        next_shape = "paddle"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("paddle"));
+    let gamepad = &bindings.gamepad;
+    assert_eq!(
+        gamepad.input_for(Action::LaunchRocket),
+        Some(GamepadInput::RightBumper)
+    );
+    assert_eq!(gamepad.input_for(Action::Screenshot), None);
+    assert_eq!(gamepad.input_for(Action::NextShape), Some(GamepadInput::A));
+    assert_eq!(gamepad.action_for(GamepadInput::RightTrigger), None);
+}
+
+#[test]
+fn test_launch_and_shape_cycle_actions() {
+    let mut physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 800.0);
+    assert!(physic.launch_rocket());
+    assert!(physic.update(0.0).new_rocket.is_some());
+
+    // Formes : sphère → ring → heart… ; retour arrière vers la sphère
+    assert_eq!(cycle_explosion_shape(&mut physic, true).unwrap(), "ring");
+    assert_eq!(physic.explosion_shape_name(), "ring");
+    assert_eq!(cycle_explosion_shape(&mut physic, true).unwrap(), "heart");
+    assert_eq!(cycle_explosion_shape(&mut physic, false).unwrap(), "ring");
+    assert_eq!(cycle_explosion_shape(&mut physic, false).unwrap(), "sphere");
+}
+
+#[test]
+fn test_console_typing_does_not_trigger_actions() {
+    let mut router = EventRouter::new(600.0);
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::Space), false),
+        Some(Reaction::Action(Action::LaunchRocket))
+    );
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::Space), true),
+        None
+    );
+    assert_eq!(router.route(&WindowEvent::KeyPress(KeyCode::N), true), None);
+    // Touches non imprimables : toujours actives
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::F12), true),
+        Some(Reaction::Action(Action::Screenshot))
+    );
+}
+
+#[test]
+fn test_gamepad_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.input.gamepad");
+    assert!(out.starts_with("Gamepads: none"), "{}", out);
+    assert!(
+        out.lines().any(|l| l.trim() == "launch_rocket      rt"),
+        "{}",
+        out
+    );
+    assert!(out.ends_with("camera_pan         left stick"), "{}", out);
+
+    shared
+        .gamepads
+        .borrow_mut()
+        .push((2, "Xbox Controller".to_string()));
+    let out = registry.execute(&mut audio, &mut physic, "renderer.input.gamepad");
+    assert!(
+        out.starts_with("Gamepads:\n  2: Xbox Controller"),
+        "{}",
+        out
+    );
+}
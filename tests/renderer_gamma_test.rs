@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{RendererConfig, OUTPUT_GAMMA_RANGE};
+use fireworks_sim::renderer_engine::post_process::{post_process_chain, PostPass};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::shader::preprocess_shader_file;
+use fireworks_sim::renderer_engine::tonemap::{
+    comparison_cells, encode_gamma, shader_gamma, ToneMappingMode, SRGB_GAMMA,
+};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Encodage gamma
+// ==================================
+
+#[test]
+fn test_shader_gamma_skips_srgb_share() {
+    // Sortie classique : tout l'encodage est fait dans le shader
+    assert_eq!(shader_gamma(2.2, false), 2.2);
+    // Framebuffer sRGB : l'encodage matériel couvre déjà ~2.2
+    assert!((shader_gamma(SRGB_GAMMA, true) - 1.0).abs() < 1e-6);
+    assert!((shader_gamma(2.4, true) - 2.4 / SRGB_GAMMA).abs() < 1e-6);
+
+    // Shader puis sRGB : même résultat qu'un encodage gamma unique
+    let value = 0.18;
+    let direct = encode_gamma(value, 2.4);
+    let split = encode_gamma(encode_gamma(value, shader_gamma(2.4, true)), SRGB_GAMMA);
+    assert!((direct - split).abs() < 1e-5, "{} != {}", direct, split);
+}
+
+#[test]
+fn test_encode_gamma_brightens_mid_tones() {
+    assert_eq!(encode_gamma(0.5, 1.0), 0.5);
+    assert!(encode_gamma(0.5, 2.2) > 0.7);
+    assert_eq!(encode_gamma(0.0, 2.2), 0.0);
+    assert_eq!(encode_gamma(-1.0, 2.2), 0.0);
+    assert!((encode_gamma(1.0, 2.2) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_composition_shader_applies_gamma() {
+    let path = Path::new("assets/shaders/post/bloom_composition.frag.glsl");
+    let shader = preprocess_shader_file(path).unwrap();
+    assert!(shader.source.contains("uniform float uGamma;"));
+    assert!(shader.source.contains("vec3 encode_gamma("));
+    assert!(shader.source.contains("encode_gamma(tonemap("));
+}
+
+// ==================================
+// 2. Grille de comparaison
+// ==================================
+
+#[test]
+fn test_comparison_cells() {
+    // Aucune comparaison : composition simple
+    assert!(comparison_cells(&[], ToneMappingMode::Aces, false, 2.2).is_empty());
+
+    // Gamma : même opérateur, sans correction puis avec le gamma de sortie
+    let cells = comparison_cells(&[], ToneMappingMode::Aces, true, 2.2);
+    assert_eq!(cells.len(), 2);
+    assert!(cells.iter().all(|c| c.mode == ToneMappingMode::Aces));
+    assert_eq!(
+        (cells[0].gamma, cells[0].label.as_str()),
+        (1.0, "GAMMA 1.0")
+    );
+    assert_eq!(
+        (cells[1].gamma, cells[1].label.as_str()),
+        (2.2, "GAMMA 2.2")
+    );
+
+    // La grille des opérateurs reste prioritaire
+    let modes = [ToneMappingMode::Agx, ToneMappingMode::Reinhard];
+    let cells = comparison_cells(&modes, ToneMappingMode::Aces, true, 1.8);
+    let labels: Vec<&str> = cells.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, ["AGX", "REINHARD"]);
+    assert!(cells.iter().all(|c| c.gamma == 1.8));
+}
+
+// ==================================
+// 3. Configuration
+// ==================================
+
+#[test]
+fn test_gamma_config() {
+    let config = RendererConfig::default();
+    assert_eq!(config.output_gamma, 2.2);
+    assert!(!config.srgb_framebuffer);
+    assert!(!config.gamma_compare);
+
+    let mut config: RendererConfig = toml::from_str(
+        r#"
+        output_gamma = 1.8
+        srgb_framebuffer = true
+        gamma_compare = true
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.output_gamma, 1.8);
+    assert!(config.srgb_framebuffer);
+    // La grille gamma passe par la composition HDR
+    assert!(post_process_chain(&config).contains(&PostPass::Tonemap));
+
+    assert_eq!(config.set_output_gamma(9.0), OUTPUT_GAMMA_RANGE.1);
+    assert_eq!(config.set_output_gamma(0.2), OUTPUT_GAMMA_RANGE.0);
+}
+
+#[test]
+fn test_shipped_renderer_toml_gamma() {
+    let text = std::fs::read_to_string("assets/config/renderer.toml").unwrap();
+    let config: RendererConfig = toml::from_str(&text).unwrap();
+    assert_eq!(config.output_gamma, 2.2);
+    assert!(!config.gamma_compare);
+}
+
+// ==================================
+// 4. Commandes console
+// ==================================
+
+#[test]
+fn test_gamma_commands() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma");
+    assert_eq!(out, "Output gamma: 2.20");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma 2.4");
+    assert_eq!(out, "Output gamma set to 2.40");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma 10");
+    assert!(out.contains("clamped"), "{}", out);
+    assert_eq!(shared.config.borrow().output_gamma, OUTPUT_GAMMA_RANGE.1);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma bright");
+    assert!(out.starts_with("Usage"), "{}", out);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma.compare");
+    assert_eq!(out, "Gamma comparison: on");
+    assert!(shared.config.borrow().gamma_compare);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma.compare maybe");
+    assert!(out.starts_with("Usage"), "{}", out);
+
+    // La grille gamma peut être exportée comme celle des opérateurs
+    let out = registry.execute(&mut audio, &mut physic, "renderer.tonemapping.compare.save");
+    assert_eq!(out, "Comparison grid requested");
+    assert_eq!(*shared.comparison_save_request.borrow(), Some(None));
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.gamma.compare off");
+    assert_eq!(out, "Gamma comparison: off");
+}
@@ -0,0 +1,38 @@
+// Nécessite un contexte OpenGL (xvfb en CI) : `cargo test --features interactive_tests`
+#![cfg(feature = "interactive_tests")]
+
+use fireworks_sim::gl_check;
+use fireworks_sim::physic_engine::PhysicConfig;
+use fireworks_sim::renderer_engine::renderer::Renderer;
+use fireworks_sim::renderer_engine::tools::{
+    gl_debug_messages_logged, set_gl_checks_enabled, setup_opengl_debug,
+};
+
+// ==================================
+// 1. Erreur OpenGL volontaire
+// ==================================
+
+#[test]
+fn test_invalid_texture_parameter_is_reported() {
+    let mut renderer = Renderer::new_headless(64, 64, &PhysicConfig::default()).unwrap();
+    set_gl_checks_enabled(true);
+    unsafe { setup_opengl_debug() };
+    let before = gl_debug_messages_logged();
+
+    unsafe {
+        let mut texture = 0;
+        gl::GenTextures(1, &mut texture);
+        gl::BindTexture(gl::TEXTURE_2D, texture);
+        // Nom de paramètre invalide : GL_INVALID_ENUM
+        gl::TexParameteri(gl::TEXTURE_2D, 0xDEAD, 0);
+        // Le callback de debug (synchrone) a déjà logué le message
+        assert!(gl_debug_messages_logged() > before);
+        assert!(gl_check!("invalid texture parameter") >= 1);
+        gl::DeleteTextures(1, &texture);
+    }
+    // La pile d'erreurs a été vidée
+    assert_eq!(gl_check!(), 0);
+
+    set_gl_checks_enabled(false);
+    renderer.close();
+}
@@ -0,0 +1,237 @@
+use fireworks_sim::renderer_engine::testing::{
+    compare_images, delta_e, diff_image, srgb_to_lab, GoldenCheck, GoldenStatus, GoldenTolerance,
+    JUST_NOTICEABLE_DELTA_E,
+};
+use image::{Rgba, RgbaImage};
+use std::path::Path;
+
+fn solid(width: u32, height: u32, color: [u8; 3]) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, Rgba([color[0], color[1], color[2], 255]))
+}
+
+fn check_in(dir: &Path, name: &str) -> GoldenCheck {
+    GoldenCheck {
+        golden_dir: dir.join("golden"),
+        output_dir: dir.join("out"),
+        update: false,
+        ..GoldenCheck::new(name)
+    }
+}
+
+// ==================================
+// 1. ΔE et comparaison d'images
+// ==================================
+
+#[test]
+fn test_srgb_to_lab_reference_values() {
+    let close = |a: [f32; 3], b: [f32; 3]| delta_e(a, b) < 0.1;
+    assert!(close(srgb_to_lab([0, 0, 0]), [0.0, 0.0, 0.0]));
+    assert!(close(srgb_to_lab([255, 255, 255]), [100.0, 0.0, 0.0]));
+    assert!(close(srgb_to_lab([255, 0, 0]), [53.24, 80.09, 67.20]));
+    assert!(close(srgb_to_lab([0, 0, 255]), [32.30, 79.19, -107.86]));
+}
+
+#[test]
+fn test_delta_e() {
+    assert_eq!(delta_e([50.0, 0.0, 0.0], [50.0, 0.0, 0.0]), 0.0);
+    assert_eq!(delta_e([50.0, 3.0, 0.0], [50.0, 0.0, 4.0]), 5.0);
+    // Un niveau de gris d'écart est imperceptible
+    let step = delta_e(srgb_to_lab([128, 128, 128]), srgb_to_lab([129, 129, 129]));
+    assert!(step < JUST_NOTICEABLE_DELTA_E, "{}", step);
+}
+
+#[test]
+fn test_compare_images() {
+    let reference = solid(10, 10, [20, 40, 80]);
+    let same = compare_images(&reference, &reference).unwrap();
+    assert_eq!(same.mean_delta_e, 0.0);
+    assert_eq!((same.changed_pixels, same.total_pixels), (0, 100));
+    assert!(same.within(&GoldenTolerance::default()));
+
+    // Un pixel blanc sur 100 : écart moyen faible mais 1 % de pixels changés
+    let mut spot = reference.clone();
+    spot.put_pixel(3, 3, Rgba([255, 255, 255, 255]));
+    let comparison = compare_images(&reference, &spot).unwrap();
+    assert_eq!(comparison.changed_pixels, 1);
+    assert!((comparison.mean_delta_e - comparison.max_delta_e / 100.0).abs() < 1e-4);
+    assert!(comparison.within(&GoldenTolerance::default()));
+    let strict = GoldenTolerance {
+        max_changed_ratio: 0.0,
+        ..GoldenTolerance::default()
+    };
+    assert!(!comparison.within(&strict));
+
+    // Teinte légèrement différente partout : ΔE moyen au-delà de la tolérance
+    let shifted = solid(10, 10, [30, 40, 80]);
+    assert!(!compare_images(&reference, &shifted)
+        .unwrap()
+        .within(&GoldenTolerance::default()));
+
+    let err = compare_images(&reference, &solid(8, 10, [0, 0, 0])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "image size differs: expected 10x10, got 8x10"
+    );
+}
+
+#[test]
+fn test_diff_image_highlights_changes() {
+    let reference = solid(4, 4, [120, 120, 120]);
+    let mut actual = reference.clone();
+    actual.put_pixel(1, 2, Rgba([255, 0, 0, 255]));
+    let diff = diff_image(&reference, &actual);
+    assert_eq!(diff.dimensions(), (4, 4));
+    // Pixel inchangé : gris estompé ; pixel modifié : rouge franc
+    assert_eq!(*diff.get_pixel(0, 0), Rgba([30, 30, 30, 255]));
+    let changed = diff.get_pixel(1, 2);
+    assert_eq!(changed[0], 255);
+    assert!(changed[1] < 30 && changed[2] < 30);
+}
+
+// ==================================
+// 2. Vérification d'une image de référence
+// ==================================
+
+#[test]
+fn test_golden_check_writes_then_matches() {
+    let dir = tempfile::tempdir().unwrap();
+    let check = check_in(dir.path(), "scene");
+    let update = GoldenCheck {
+        update: true,
+        ..check.clone()
+    };
+    let image = solid(16, 8, [10, 200, 30]);
+
+    assert_eq!(update.check(&image).unwrap(), GoldenStatus::Written);
+    assert!(check.golden_path().exists());
+    match check.check(&image).unwrap() {
+        GoldenStatus::Matched(comparison) => assert_eq!(comparison.max_delta_e, 0.0),
+        status => panic!("{:?}", status),
+    }
+    // Aucun artefact d'échec
+    assert!(!check.actual_path().exists());
+}
+
+#[test]
+fn test_golden_check_missing_reference_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let check = check_in(dir.path(), "scene");
+    let image = solid(16, 8, [10, 200, 30]);
+
+    // Jamais de référence écrite en silence : le test échoue et nomme la variable
+    let err = check.check(&image).unwrap_err().to_string();
+    assert!(err.contains("missing"), "{}", err);
+    assert!(err.contains("UPDATE_GOLDEN=1"), "{}", err);
+    assert!(!check.golden_path().exists());
+    // L'image obtenue reste disponible pour inspection (ou copie manuelle)
+    assert_eq!(image::open(check.actual_path()).unwrap().to_rgba8(), image);
+}
+
+#[test]
+fn test_golden_check_failure_writes_artifacts() {
+    let dir = tempfile::tempdir().unwrap();
+    let check = check_in(dir.path(), "scene");
+    let update = GoldenCheck {
+        update: true,
+        ..check.clone()
+    };
+    update.check(&solid(16, 8, [10, 200, 30])).unwrap();
+
+    let err = check
+        .check(&solid(16, 8, [200, 10, 30]))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'scene' differs from"), "{}", err);
+    assert!(err.contains("UPDATE_GOLDEN=1"), "{}", err);
+    assert!(check.actual_path().exists());
+    assert!(check.diff_path().exists());
+    assert_eq!(
+        image::open(check.diff_path())
+            .unwrap()
+            .to_rgba8()
+            .dimensions(),
+        (16, 8)
+    );
+
+    // Taille différente : image obtenue seule (pas de différences calculables)
+    std::fs::remove_file(check.diff_path()).unwrap();
+    let err = check.check(&solid(8, 8, [10, 200, 30])).unwrap_err();
+    assert!(err.to_string().contains("image size differs"), "{}", err);
+    assert!(!check.diff_path().exists());
+
+    // Régénération demandée : la référence est remplacée
+    let replaced = solid(8, 8, [1, 2, 3]);
+    assert_eq!(update.check(&replaced).unwrap(), GoldenStatus::Written);
+    assert!(matches!(
+        check.check(&replaced).unwrap(),
+        GoldenStatus::Matched(_)
+    ));
+}
+
+// ==================================
+// 3. Scènes de référence (contexte OpenGL requis)
+// ==================================
+
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+mod helpers;
+
+/// Rendu des scènes déterministes comparé aux PNG de `tests/golden/`
+/// (`make test-golden` : contexte EGL sans affichage, en CI aussi ;
+/// `make update-golden` pour régénérer après un changement de shader voulu).
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+#[test]
+fn test_golden_scenes() {
+    use fireworks_sim::physic_engine::{
+        config::PhysicConfig, physic_engine_generational_arena::PhysicEngineFireworks, PhysicEngine,
+    };
+    use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+    use fireworks_sim::renderer_engine::tonemap::ToneMappingMode;
+    use fireworks_sim::renderer_engine::{Renderer, RendererEngine};
+
+    const SEED: u64 = 42;
+    const STEPS: usize = 90;
+    const DT: f32 = 1.0 / 60.0;
+    const SIZE: (i32, i32) = (320, 240);
+
+    // Pas de formes images : la scène ne dépend que de la graine
+    let config = PhysicConfig {
+        shapes_dir: "/nonexistent/shapes/dir".into(),
+        ..Default::default()
+    };
+    let mut physic = PhysicEngineFireworks::with_seed(&config, SIZE.0 as f32, SEED);
+    for _ in 0..STEPS {
+        physic.update(DT);
+    }
+
+    let mut renderer = Renderer::new_headless(SIZE.0, SIZE.1, &config).unwrap();
+    renderer.advance_clock(STEPS as f32 * DT);
+    let mut registry = CommandRegistry::new();
+    renderer.register_commands(&mut registry);
+
+    let mut failures = Vec::new();
+    for mode in ToneMappingMode::ALL {
+        for bloom in ["off", "on"] {
+            for command in [
+                format!("renderer.tonemapping {}", mode.name()),
+                format!("renderer.bloom {}", bloom),
+            ] {
+                registry.execute(&mut helpers::DummyAudio, &mut physic, &command);
+            }
+            let image = renderer.render_to_image(&physic).unwrap();
+            let name = format!(
+                "seed{}_{}steps_{}_bloom-{}",
+                SEED,
+                STEPS,
+                mode.name(),
+                bloom
+            );
+            match GoldenCheck::new(&name).check(&image) {
+                Ok(GoldenStatus::Matched(comparison)) => println!("{}: {}", name, comparison),
+                Ok(GoldenStatus::Written) => println!("{}: golden written", name),
+                Err(e) => failures.push(format!("{:#}", e)),
+            }
+        }
+    }
+    renderer.close();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,91 @@
+use fireworks_sim::renderer_engine::utils::gpu_timer::{
+    supports_timer_queries, QueryRing, GPU_TIMER_LATENCY,
+};
+
+// ==================================
+// 1. Tourniquet des requêtes
+// ==================================
+
+#[test]
+fn test_query_ring_resolves_after_latency_frames() {
+    let mut ring = QueryRing::new(GPU_TIMER_LATENCY);
+    // Premier tour : créneaux neufs, rien à relire
+    for expected in 0..GPU_TIMER_LATENCY {
+        assert_eq!(ring.begin(), (expected, false));
+    }
+    assert_eq!(ring.in_flight(), GPU_TIMER_LATENCY);
+
+    // Tour suivant : chaque créneau contient la mesure émise `latency` frames plus tôt
+    assert_eq!(ring.begin(), (0, true));
+    ring.resolve(true);
+    assert_eq!(ring.begin(), (1, true));
+    ring.resolve(false);
+    assert_eq!((ring.resolved(), ring.dropped()), (1, 1));
+    assert_eq!(ring.in_flight(), GPU_TIMER_LATENCY);
+}
+
+#[test]
+fn test_query_ring_of_depth_one() {
+    let mut ring = QueryRing::new(0);
+    assert_eq!(ring.begin(), (0, false));
+    assert_eq!(ring.begin(), (0, true));
+    assert_eq!(ring.in_flight(), 1);
+}
+
+// ==================================
+// 2. Disponibilité
+// ==================================
+
+#[test]
+fn test_timer_queries_support_detection() {
+    assert!(supports_timer_queries(3, 3, &[]));
+    assert!(supports_timer_queries(4, 1, &[]));
+    assert!(!supports_timer_queries(3, 2, &["GL_ARB_sync"]));
+    assert!(supports_timer_queries(
+        3,
+        2,
+        &["GL_ARB_sync", "GL_ARB_timer_query"]
+    ));
+    assert!(!supports_timer_queries(2, 1, &[]));
+}
+
+// ==================================
+// 3. Métriques gpu:<passe> (contexte OpenGL requis)
+// ==================================
+
+#[cfg(feature = "interactive_tests")]
+mod helpers;
+
+#[cfg(feature = "interactive_tests")]
+#[test]
+fn test_gpu_metrics_reported_after_rendering() {
+    use fireworks_sim::physic_engine::config::PhysicConfig;
+    use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+    use fireworks_sim::profiler::Profiler;
+    use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+    use fireworks_sim::renderer_engine::{HeadlessRenderer, RendererEngine};
+
+    let config = PhysicConfig::default();
+    let mut renderer = HeadlessRenderer::new(320, 240, &config, 30).unwrap();
+    let mut physic = PhysicEngineFireworks::with_seed(&config, 320.0, 7);
+    renderer
+        .run_loop(
+            &mut physic,
+            &mut helpers::DummyAudio,
+            &CommandRegistry::new(),
+        )
+        .unwrap();
+
+    let profiler = Profiler::new(10);
+    renderer.renderer().report_gpu_times(&profiler);
+    let gpu: Vec<String> = profiler
+        .metrics_summary()
+        .into_keys()
+        .filter(|label| label.starts_with("gpu:"))
+        .collect();
+    assert!(!gpu.is_empty(), "no gpu:* metric reported");
+    for label in &gpu {
+        let max = profiler.percentiles(label).unwrap().max;
+        assert!(max > 0.0, "{} = {} ms", label, max);
+    }
+}
@@ -0,0 +1,138 @@
+use fireworks_sim::physic_engine::{
+    config::PhysicConfig, physic_engine_generational_arena::PhysicEngineFireworks, PhysicEngine,
+    PhysicEngineIterator,
+};
+use fireworks_sim::renderer_engine::utils::golden::{
+    image_signature, signature_distance, signature_from_hex, signature_to_hex,
+};
+use image::{Rgba, RgbaImage};
+
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+mod helpers;
+
+const SEED: u64 = 42;
+const FRAMES: usize = 100;
+const DT: f32 = 1.0 / 60.0;
+
+/// Simulation de référence : pas de formes images, pour ne dépendre que de la graine
+fn golden_config() -> PhysicConfig {
+    PhysicConfig {
+        shapes_dir: "/nonexistent/shapes/dir".into(),
+        ..Default::default()
+    }
+}
+
+fn particle_positions(engine: &PhysicEngineFireworks) -> Vec<(f32, f32)> {
+    engine
+        .iter_active_particles()
+        .map(|p| (p.pos.x, p.pos.y))
+        .collect()
+}
+
+// ==================================
+// 1. Simulation déterministe
+// ==================================
+
+#[test]
+fn test_seeded_simulation_is_reproducible() {
+    let config = golden_config();
+    let mut a = PhysicEngineFireworks::with_seed(&config, 1024.0, SEED);
+    let mut b = PhysicEngineFireworks::with_seed(&config, 1024.0, SEED);
+    let mut c = PhysicEngineFireworks::with_seed(&config, 1024.0, SEED + 1);
+    for _ in 0..FRAMES {
+        a.update(DT);
+        b.update(DT);
+        c.update(DT);
+    }
+
+    let positions = particle_positions(&a);
+    assert!(!positions.is_empty());
+    assert_eq!(positions, particle_positions(&b));
+    assert_ne!(positions, particle_positions(&c));
+}
+
+// ==================================
+// 2. Signatures d'images
+// ==================================
+
+#[test]
+fn test_signature_tolerates_small_differences() {
+    let reference = RgbaImage::from_fn(64, 64, |x, _| {
+        if x < 32 {
+            Rgba([255, 255, 255, 255])
+        } else {
+            Rgba([0, 0, 0, 255])
+        }
+    });
+    // Un pixel isolé modifié : quasi invisible après réduction
+    let mut noisy = reference.clone();
+    noisy.put_pixel(40, 10, Rgba([255, 255, 255, 255]));
+    // Image miroir : composition totalement différente
+    let mirrored = image::imageops::flip_horizontal(&reference);
+
+    let sig = image_signature(&reference, 16);
+    assert_eq!(sig.len(), 16 * 16);
+    assert!(signature_distance(&sig, &image_signature(&noisy, 16)) < 0.01);
+    assert!(signature_distance(&sig, &image_signature(&mirrored, 16)) > 0.9);
+    assert_eq!(signature_distance(&sig, &sig[..8]), 1.0);
+}
+
+#[test]
+fn test_signature_hex_roundtrip() {
+    let sig = vec![0u8, 1, 127, 255];
+    assert_eq!(signature_to_hex(&sig), "00017fff");
+    assert_eq!(signature_from_hex(" 00017fff\n").unwrap(), sig);
+    assert!(signature_from_hex("abc").is_err());
+    assert!(signature_from_hex("zz").is_err());
+}
+
+// ==================================
+// 3. Rendu headless vs image de référence (contexte OpenGL requis : fenêtre
+//    invisible sous xvfb, ou contexte EGL sans affichage avec `headless_egl`)
+// ==================================
+
+/// Signature de référence, versionnée ; régénérée avec `UPDATE_GOLDEN=1`.
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+const GOLDEN_PATH: &str = "tests/golden/headless_seed42_100frames.hex";
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+const GOLDEN_TOLERANCE: f32 = 0.02;
+
+#[cfg(any(feature = "interactive_tests", feature = "headless_egl"))]
+#[test]
+fn test_headless_simulation_matches_golden() {
+    use fireworks_sim::renderer_engine::testing::UPDATE_GOLDEN_ENV;
+    use fireworks_sim::renderer_engine::HeadlessRenderer;
+    use fireworks_sim::Simulator;
+
+    let config = golden_config();
+    let renderer = HeadlessRenderer::new(320, 240, &config, FRAMES)
+        .unwrap()
+        .with_time_step(DT);
+    let physic = PhysicEngineFireworks::with_seed(&config, 320.0, SEED);
+    let mut simulator = Simulator::new(renderer, physic, helpers::DummyAudio);
+    simulator.run(None).unwrap();
+
+    let image = simulator.renderer_engine().last_image().unwrap();
+    assert_eq!(image.dimensions(), (320, 240));
+    let signature = image_signature(image, 16);
+    simulator.close();
+
+    if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+        std::fs::write(GOLDEN_PATH, signature_to_hex(&signature)).unwrap();
+        println!("{}: golden written", GOLDEN_PATH);
+        return;
+    }
+    // Référence absente : échec, jamais réécrite en silence
+    let golden = std::fs::read_to_string(GOLDEN_PATH).unwrap_or_else(|e| {
+        panic!(
+            "{}: {} (run with {}=1 to create it)",
+            GOLDEN_PATH, e, UPDATE_GOLDEN_ENV
+        )
+    });
+    let distance = signature_distance(&signature, &signature_from_hex(&golden).unwrap());
+    assert!(
+        distance <= GOLDEN_TOLERANCE,
+        "headless frame drifted from golden: {:.4}",
+        distance
+    );
+}
@@ -0,0 +1,150 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::key_bindings::{KeyBindings, INPUT_CONFIG_PATH};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::window_event::{
+    Action, EventRouter, KeyCode, Reaction, WindowEvent,
+};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Liaisons par défaut
+// ==================================
+
+#[test]
+fn test_default_bindings_are_unique() {
+    let bindings = KeyBindings::default();
+    assert!(bindings.duplicates().is_empty());
+    assert_eq!(bindings.key_for(Action::Quit), KeyCode::Escape);
+    assert_eq!(bindings.key_for(Action::PauseSim), KeyCode::P);
+    for action in Action::ALL {
+        assert_eq!(bindings.action_for(action.default_key()), Some(action));
+        assert_eq!(Action::from_name(action.name()), Some(action));
+    }
+    assert_eq!(bindings.action_for(KeyCode::W), None);
+}
+
+#[test]
+fn test_shipped_input_toml_matches_defaults() {
+    let text = std::fs::read_to_string(INPUT_CONFIG_PATH).unwrap();
+    let (bindings, warnings) = KeyBindings::parse(&text).unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(bindings, KeyBindings::default());
+}
+
+// ==================================
+// 2. Lecture de input.toml
+// ==================================
+
+#[test]
+fn test_parse_rebinds_actions() {
+    let (bindings, warnings) = KeyBindings::parse(
+        r#"
+        [bindings]
+        reload_config = "F6"
+        toggle_console = "tab"
+        "#,
+    )
+    .unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(bindings.key_for(Action::ReloadConfig), KeyCode::F6);
+    assert_eq!(
+        bindings.action_for(KeyCode::Tab),
+        Some(Action::ToggleConsole)
+    );
+    // R est libéré, les autres actions gardent leur touche
+    assert_eq!(bindings.action_for(KeyCode::R), None);
+    assert_eq!(bindings.key_for(Action::Quit), KeyCode::Escape);
+
+    // Fichier vide : liaisons par défaut
+    let (empty, _) = KeyBindings::parse("").unwrap();
+    assert_eq!(empty, KeyBindings::default());
+}
+
+#[test]
+fn test_unknown_names_warn_and_fall_back() {
+    let (bindings, warnings) = KeyBindings::parse(
+        r#"
+        [bindings]
+        quit = "hyper"
+        jump = "space"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(bindings.key_for(Action::Quit), KeyCode::Escape);
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings
+        .iter()
+        .any(|w| w.contains("hyper") && w.contains("escape")));
+    assert!(warnings.iter().any(|w| w.contains("jump")));
+
+    assert!(KeyBindings::parse("[bindings\n").is_err());
+}
+
+#[test]
+fn test_duplicate_bindings_are_reported() {
+    let (bindings, warnings) = KeyBindings::parse(
+        r#"
+        [bindings]
+        pause_sim = "r"
+        "#,
+    )
+    .unwrap();
+    let duplicates = bindings.duplicates();
+    assert_eq!(
+        duplicates,
+        vec![(KeyCode::R, vec![Action::ReloadConfig, Action::PauseSim])]
+    );
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'r'") && warnings[0].contains("reload_config"));
+    // La première action de `Action::ALL` l'emporte
+    assert_eq!(bindings.action_for(KeyCode::R), Some(Action::ReloadConfig));
+}
+
+// ==================================
+// 3. Routage et console
+// ==================================
+
+#[test]
+fn test_router_uses_shared_bindings() {
+    let mut router = EventRouter::new(600.0);
+    router
+        .bindings
+        .borrow_mut()
+        .bind(Action::Screenshot, KeyCode::S);
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::S), false),
+        Some(Reaction::Action(Action::Screenshot))
+    );
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::F12), false),
+        None
+    );
+}
+
+#[test]
+fn test_input_commands() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    shared
+        .key_bindings
+        .borrow_mut()
+        .bind(Action::Quit, KeyCode::Q);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.input.bindings");
+    assert!(out.starts_with("Key bindings:"), "{}", out);
+    assert!(
+        out.lines().any(|l| l.trim() == "quit               q"),
+        "{}",
+        out
+    );
+    assert_eq!(out.lines().count(), 1 + Action::ALL.len());
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.input.reload");
+    assert!(out.starts_with("Key bindings reloaded"), "{}", out);
+    assert_eq!(*shared.key_bindings.borrow(), KeyBindings::default());
+}
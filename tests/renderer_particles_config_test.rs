@@ -0,0 +1,240 @@
+use fireworks_sim::physic_engine::ParticleType;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{
+    BlendMode, ParticleRenderSettings, RendererConfig, SOFTNESS_RANGE,
+};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::renderer_graphics_instanced::{
+    soft_falloff, InstancedUniforms,
+};
+use fireworks_sim::renderer_engine::utils::texture::TextureSlot;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Configuration TOML
+// ==================================
+
+#[test]
+fn test_particles_section_parsing() {
+    let config: RendererConfig = toml::from_str(
+        r#"
+        [particles.explosion]
+        texture = "assets/textures/custom.png"
+        blend = "additive"
+        size_scale = 1.5
+        "#,
+    )
+    .unwrap();
+
+    let explosion = config.particles.get(ParticleType::Explosion);
+    assert_eq!(explosion.blend, BlendMode::Additive);
+    assert_eq!(explosion.size_scale, 1.5);
+    // Clé absente : valeur par défaut
+    assert_eq!(explosion.brightness, 1.0);
+    assert_eq!(
+        explosion.texture_path(ParticleType::Explosion),
+        "assets/textures/custom.png"
+    );
+    // Section absente : réglages par défaut
+    assert_eq!(config.particles.smoke, ParticleRenderSettings::default());
+}
+
+#[test]
+fn test_empty_texture_falls_back_to_type_default() {
+    let config = RendererConfig::default();
+    for particle_type in ParticleType::ALL {
+        let settings = config.particles.get(particle_type);
+        assert_eq!(settings.blend, BlendMode::Alpha);
+        assert_eq!(
+            settings.texture_path(particle_type),
+            particle_type.default_texture_path()
+        );
+    }
+}
+
+#[test]
+fn test_invalid_blend_mode_is_rejected() {
+    let result: Result<RendererConfig, _> = toml::from_str(
+        r#"
+        [particles.rocket]
+        blend = "multiply"
+        "#,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bundled_config_particles_section() {
+    let config = RendererConfig::from_file("assets/config/renderer.toml").unwrap();
+    for particle_type in ParticleType::ALL {
+        let path = config
+            .particles
+            .get(particle_type)
+            .texture_path(particle_type);
+        assert!(std::path::Path::new(path).is_file(), "{}", path);
+    }
+}
+
+#[test]
+fn test_softness_parsing_and_clamp() {
+    let config: RendererConfig = toml::from_str(
+        r#"
+        [particles.explosion]
+        softness = 0.6
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.particles.explosion.softness, 0.6);
+    // Défaut : sprite brut
+    assert_eq!(config.particles.rocket.softness, 0.0);
+
+    let mut settings = ParticleRenderSettings::default();
+    assert_eq!(settings.set_softness(2.0), SOFTNESS_RANGE.1);
+    assert_eq!(settings.set_softness(-1.0), SOFTNESS_RANGE.0);
+    assert_eq!(settings.set_softness(0.25), 0.25);
+}
+
+// ==================================
+// 2. Remplacement de texture
+// ==================================
+
+#[test]
+fn test_texture_slot_replace_returns_old_id() {
+    let mut slot = TextureSlot::new(3, "a.png", 64, 32);
+    assert_eq!(slot.aspect_ratio, 2.0);
+
+    let old = slot.replace(7, "b.png", 32, 64);
+    assert_eq!(old, Some(3));
+    assert_eq!(slot.id, 7);
+    assert_eq!(slot.path, "b.png");
+    assert_eq!(slot.aspect_ratio, 0.5);
+
+    // Même identifiant (ou slot vide) : rien à libérer
+    assert_eq!(slot.replace(7, "b.png", 32, 64), None);
+    assert_eq!(TextureSlot::default().replace(1, "c.png", 8, 8), None);
+}
+
+// ==================================
+// 3. Shader : atténuation et uniformes
+// ==================================
+
+#[test]
+fn test_soft_falloff_mirrors_shader() {
+    // Sans atténuation : opaque partout
+    assert_eq!(soft_falloff(0.0, 0.0), 1.0);
+    assert_eq!(soft_falloff(1.5, 0.0), 1.0);
+    // Plein dégradé : opaque au centre, nul au bord
+    assert_eq!(soft_falloff(0.0, 1.0), 1.0);
+    assert_eq!(soft_falloff(1.0, 1.0), 0.0);
+    assert!((soft_falloff(0.5, 1.0) - 0.5).abs() < 1e-6);
+    // Dégradé limité à l'anneau extérieur
+    assert_eq!(soft_falloff(0.7, 0.2), 1.0);
+    assert!(soft_falloff(0.9, 0.2) > 0.0 && soft_falloff(0.9, 0.2) < 1.0);
+    assert_eq!(soft_falloff(1.2, 0.2), 0.0);
+}
+
+#[test]
+fn test_uniform_locations_are_queried_once() {
+    let mut queried = Vec::new();
+    let uniforms = InstancedUniforms::locate(|name| {
+        queried.push(name);
+        queried.len() as i32
+    });
+    assert_eq!(queried, InstancedUniforms::NAMES);
+    assert!(queried.iter().all(|name| name.ends_with('\0')));
+    assert_eq!(uniforms.view_proj, 1);
+    assert_eq!(uniforms.softness, 7);
+}
+
+// ==================================
+// 4. Commande console
+// ==================================
+
+#[test]
+fn test_particles_texture_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let path = ParticleType::Smoke.default_texture_path();
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        &format!("renderer.particles.texture explosion {}", path),
+    );
+    assert_eq!(out, format!("explosion texture set to {}", path));
+    assert_eq!(shared.config.borrow().particles.explosion.texture, path);
+
+    // Sans chemin : texture courante
+    let out = registry.execute(&mut audio, &mut physic, "renderer.particles.texture rocket");
+    assert_eq!(
+        out,
+        format!(
+            "rocket texture: {}",
+            ParticleType::Rocket.default_texture_path()
+        )
+    );
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.texture rocket missing.png",
+    );
+    assert!(out.starts_with("Texture not found"), "{}", out);
+    assert!(shared.config.borrow().particles.rocket.texture.is_empty());
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.texture sparks x.png",
+    );
+    assert!(out.starts_with("Usage"), "{}", out);
+}
+
+#[test]
+fn test_particles_softness_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.softness explosion 0.5",
+    );
+    assert_eq!(out, "explosion softness set to 0.50");
+    assert_eq!(shared.config.borrow().particles.explosion.softness, 0.5);
+    // Les autres types ne changent pas
+    assert_eq!(shared.config.borrow().particles.rocket.softness, 0.0);
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.softness rocket 3",
+    );
+    assert!(out.ends_with("(clamped)"), "{}", out);
+    assert_eq!(shared.config.borrow().particles.rocket.softness, 1.0);
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.particles.softness explosion",
+    );
+    assert_eq!(out, "explosion softness: 0.50");
+
+    for bad in [
+        "renderer.particles.softness",
+        "renderer.particles.softness sparks 0.5",
+        "renderer.particles.softness smoke soft",
+        "renderer.particles.softness smoke NaN",
+    ] {
+        let out = registry.execute(&mut audio, &mut physic, bad);
+        assert!(out.starts_with("Usage"), "{}: {}", bad, out);
+    }
+}
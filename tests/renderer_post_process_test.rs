@@ -0,0 +1,86 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::post_process::{post_process_chain, PostPass};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Ordre des passes
+// ==================================
+
+#[test]
+fn test_default_chain_has_no_post_process() {
+    let chain = post_process_chain(&RendererConfig::default());
+    assert_eq!(chain, vec![PostPass::Scene, PostPass::Backbuffer]);
+}
+
+#[test]
+fn test_full_chain_order() {
+    let config = RendererConfig {
+        bloom_enabled: true,
+        fxaa_enabled: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        post_process_chain(&config),
+        vec![
+            PostPass::Scene,
+            PostPass::Bloom,
+            PostPass::Tonemap,
+            PostPass::Fxaa,
+            PostPass::Backbuffer,
+        ]
+    );
+}
+
+#[test]
+fn test_partial_chains_skip_disabled_passes() {
+    // FXAA seul : pas de passe HDR
+    let config = RendererConfig {
+        fxaa_enabled: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        post_process_chain(&config),
+        vec![PostPass::Scene, PostPass::Fxaa, PostPass::Backbuffer]
+    );
+
+    // Exposition automatique sans bloom : composition seule
+    let config = RendererConfig {
+        auto_exposure_enabled: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        post_process_chain(&config),
+        vec![PostPass::Scene, PostPass::Tonemap, PostPass::Backbuffer]
+    );
+}
+
+// ==================================
+// 2. Configuration et console
+// ==================================
+
+#[test]
+fn test_fxaa_config_and_command() {
+    let config: RendererConfig = toml::from_str("fxaa_enabled = true").unwrap();
+    assert!(config.fxaa_enabled);
+    assert!(!RendererConfig::default().fxaa_enabled);
+
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.fxaa on");
+    assert_eq!(out, "FXAA: on");
+    assert!(shared.config.borrow().fxaa_enabled);
+
+    registry.execute(&mut audio, &mut physic, "renderer.fxaa");
+    assert!(!shared.config.borrow().fxaa_enabled);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.fxaa smaa");
+    assert!(out.starts_with("Usage"), "{}", out);
+}
@@ -0,0 +1,124 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{QualityPreset, RendererConfig};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Préréglages
+// ==================================
+
+#[test]
+fn test_presets_scale_with_quality() {
+    let settings: Vec<_> = QualityPreset::ALL.iter().map(|p| p.settings()).collect();
+    for pair in settings.windows(2) {
+        assert!(pair[0].bloom_blur_passes <= pair[1].bloom_blur_passes);
+        assert!(pair[0].render_scale <= pair[1].render_scale);
+    }
+    assert!(!settings[0].bloom_enabled && !settings[0].fxaa_enabled);
+    assert!(settings[3].bloom_enabled && settings[3].fxaa_enabled);
+
+    for preset in QualityPreset::ALL {
+        assert_eq!(QualityPreset::from_name(preset.name()), Some(preset));
+    }
+    assert_eq!(
+        QualityPreset::from_name("ULTRA"),
+        Some(QualityPreset::Ultra)
+    );
+    assert_eq!(QualityPreset::from_name("epic"), None);
+}
+
+#[test]
+fn test_apply_preset_sets_every_knob() {
+    let mut config = RendererConfig::default();
+    assert_eq!(config.active_preset(), None);
+
+    let settings = config.apply_preset(QualityPreset::Low);
+    assert_eq!(config.quality_settings(), settings);
+    assert_eq!(config.active_preset(), Some(QualityPreset::Low));
+
+    config.apply_preset(QualityPreset::Ultra);
+    assert_eq!(config.quality_settings(), QualityPreset::Ultra.settings());
+    // Un réglage modifié à la main : le préréglage n'est plus en vigueur
+    config.fxaa_enabled = false;
+    assert_eq!(config.active_preset(), None);
+}
+
+// ==================================
+// 2. Expansion dans le TOML
+// ==================================
+
+#[test]
+fn test_toml_preset_is_expanded() {
+    let config = RendererConfig::from_toml(r#"preset = "medium""#).unwrap();
+    assert_eq!(config.quality_settings(), QualityPreset::Medium.settings());
+    assert_eq!(config.active_preset(), Some(QualityPreset::Medium));
+    // Les autres clés gardent leur valeur par défaut
+    assert_eq!(
+        config.bloom_threshold,
+        RendererConfig::default().bloom_threshold
+    );
+}
+
+#[test]
+fn test_toml_explicit_keys_override_preset() {
+    let config = RendererConfig::from_toml(
+        r#"
+        preset = "high"
+        render_scale = 0.5
+        fxaa_enabled = false
+        "#,
+    )
+    .unwrap();
+    let high = QualityPreset::High.settings();
+    assert_eq!(config.render_scale, 0.5);
+    assert!(!config.fxaa_enabled);
+    assert_eq!(config.bloom_enabled, high.bloom_enabled);
+    assert_eq!(config.bloom_blur_passes, high.bloom_blur_passes);
+    assert_eq!(config.active_preset(), None);
+
+    assert!(RendererConfig::from_toml(r#"preset = "epic""#).is_err());
+    // Sans préréglage : lecture inchangée
+    let plain = RendererConfig::from_toml("render_scale = 0.75").unwrap();
+    assert_eq!(plain.preset, None);
+    assert_eq!(plain.render_scale, 0.75);
+}
+
+// ==================================
+// 3. Commande console
+// ==================================
+
+#[test]
+fn test_renderer_preset_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.preset");
+    assert!(out.starts_with("Quality preset: custom"), "{}", out);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.preset high");
+    assert_eq!(
+        out,
+        "Quality preset: high (bloom on, 3 blur passes, scale 1.00, fxaa on)"
+    );
+    {
+        let config = shared.config.borrow();
+        assert!(config.bloom_enabled && config.fxaa_enabled);
+        assert_eq!(config.active_preset(), Some(QualityPreset::High));
+    }
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.preset low");
+    assert!(out.contains("scale 0.50"), "{}", out);
+    assert_eq!(shared.config.borrow().render_scale, 0.5);
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.preset epic");
+    assert_eq!(out, "Usage: renderer.preset <low|medium|high|ultra>");
+    assert_eq!(
+        shared.config.borrow().active_preset(),
+        Some(QualityPreset::Low)
+    );
+}
@@ -0,0 +1,136 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{RecordingConfig, RendererConfig};
+use fireworks_sim::renderer_engine::recorder::{ffmpeg_args, PboRingState};
+use fireworks_sim::renderer_engine::renderer::{
+    register_renderer_commands, RecordRequest, RendererShared,
+};
+use std::path::{Path, PathBuf};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Ring de PBO
+// ==================================
+
+#[test]
+fn test_pbo_ring_fills_before_reading() {
+    let mut ring = PboRingState::new(3);
+    // Remplissage : aucune relecture prête pendant les `slots` premières frames
+    assert_eq!(ring.advance(), (0, None));
+    assert_eq!(ring.advance(), (1, None));
+    assert_eq!(ring.advance(), (2, None));
+    assert_eq!(ring.in_flight(), 3);
+
+    // Régime établi : le slot à réécrire est lu juste avant (frame la plus ancienne)
+    assert_eq!(ring.advance(), (0, Some(0)));
+    assert_eq!(ring.advance(), (1, Some(1)));
+    assert_eq!(ring.in_flight(), 3);
+}
+
+#[test]
+fn test_pbo_ring_drain_returns_pending_oldest_first() {
+    let mut ring = PboRingState::new(3);
+    for _ in 0..5 {
+        ring.advance();
+    }
+    // Frames 2, 3, 4 en vol dans les slots 2, 0, 1
+    assert_eq!(ring.drain(), [2, 0, 1]);
+    assert_eq!(ring.in_flight(), 0);
+    assert!(ring.drain().is_empty());
+
+    // Ring partiellement rempli
+    let mut ring = PboRingState::new(4);
+    ring.advance();
+    ring.advance();
+    assert_eq!(ring.drain(), [0, 1]);
+    // Après un drain, le remplissage reprend sans relecture
+    assert_eq!(ring.advance().1, None);
+}
+
+#[test]
+fn test_pbo_ring_single_slot_is_synchronous() {
+    let mut ring = PboRingState::new(0); // ramené à 1 slot
+    assert_eq!(ring.slots(), 1);
+    assert_eq!(ring.advance(), (0, None));
+    assert_eq!(ring.advance(), (0, Some(0)));
+    assert_eq!(ring.drain(), [0]);
+}
+
+// ==================================
+// 2. Ligne de commande ffmpeg
+// ==================================
+
+#[test]
+fn test_ffmpeg_args_describe_raw_rgba_input() {
+    let config = RecordingConfig::default();
+    let args = ffmpeg_args(&config, 1024, 800, Path::new("out/show.mp4"));
+    let joined = args.join(" ");
+
+    assert!(
+        joined.contains("-f rawvideo -pix_fmt rgba -s 1024x800 -r 60 -i -"),
+        "{}",
+        joined
+    );
+    assert!(joined.contains("-vf vflip"), "{}", joined);
+    assert!(joined.contains("-c:v libx264 -b:v 12M -pix_fmt yuv420p"));
+    assert_eq!(args.last().unwrap(), "out/show.mp4");
+    // L'entrée est déclarée avant la sortie
+    let input = args.iter().position(|a| a == "-i").unwrap();
+    assert!(input < args.len() - 1);
+}
+
+#[test]
+fn test_ffmpeg_args_follow_config() {
+    let config = RecordingConfig {
+        fps: 30,
+        bitrate: String::new(),
+        codec: "libvpx-vp9".into(),
+        ..Default::default()
+    };
+    let args = ffmpeg_args(&config, 640, 480, Path::new("show.webm"));
+    let joined = args.join(" ");
+    assert!(joined.contains("-r 30"), "{}", joined);
+    assert!(joined.contains("-c:v libvpx-vp9"), "{}", joined);
+    assert!(!args.iter().any(|a| a == "-b:v"));
+}
+
+#[test]
+fn test_recording_config_from_renderer_toml() {
+    let config: RendererConfig = toml::from_str("[recording]\nfps = 25\n").unwrap();
+    assert_eq!(config.recording.fps, 25);
+    assert!(config.recording.lock_time_step);
+    assert_eq!(
+        config.recording.pbo_count,
+        RecordingConfig::default().pbo_count
+    );
+}
+
+// ==================================
+// 3. Commandes console renderer.record.*
+// ==================================
+
+#[test]
+fn test_renderer_record_commands_queue_requests() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    registry.execute(&mut audio, &mut physic, "renderer.record.start show.mp4");
+    assert_eq!(
+        shared.record_request.borrow_mut().take(),
+        Some(RecordRequest::Start(Some(PathBuf::from("show.mp4"))))
+    );
+
+    registry.execute(&mut audio, &mut physic, "renderer.record.start");
+    assert_eq!(
+        shared.record_request.borrow_mut().take(),
+        Some(RecordRequest::Start(None))
+    );
+
+    registry.execute(&mut audio, &mut physic, "renderer.record.stop");
+    assert_eq!(*shared.record_request.borrow(), Some(RecordRequest::Stop));
+}
@@ -0,0 +1,68 @@
+use fireworks_sim::renderer_engine::bloom::{bloom_buffer_size, internal_size};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{RendererConfig, RENDER_SCALE_RANGE};
+use fireworks_sim::renderer_engine::post_process::{post_process_chain, PostPass};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+// ==================================
+// 1. Tailles dérivées
+// ==================================
+
+#[test]
+fn test_internal_size_follows_scale() {
+    assert_eq!(internal_size(3840, 2160, 1.0), (3840, 2160));
+    assert_eq!(internal_size(3840, 2160, 0.5), (1920, 1080));
+    assert_eq!(internal_size(1366, 768, 0.75), (1025, 576));
+    // Échelle hors plage : bornée
+    assert_eq!(internal_size(3840, 2160, 0.1), (960, 540));
+    assert_eq!(internal_size(3840, 2160, 2.0), (3840, 2160));
+    // Fenêtre minimisée : jamais de surface vide
+    assert_eq!(internal_size(0, 1, 0.25), (1, 1));
+}
+
+#[test]
+fn test_bloom_buffers_compose_with_scale() {
+    // Le facteur de réduction du flou s'applique à la résolution interne
+    assert_eq!(bloom_buffer_size(3840, 2160, 1.0), (1920, 1080));
+    assert_eq!(bloom_buffer_size(3840, 2160, 0.5), (960, 540));
+    assert_eq!(bloom_buffer_size(1, 1, 0.25), (1, 1));
+}
+
+// ==================================
+// 2. Configuration et console
+// ==================================
+
+#[test]
+fn test_render_scale_config() {
+    let config = RendererConfig::default();
+    assert_eq!(config.render_scale, 1.0);
+    // Pleine résolution : pas de passe HDR imposée
+    assert!(!post_process_chain(&config).contains(&PostPass::Tonemap));
+
+    let mut config: RendererConfig = toml::from_str("render_scale = 0.5").unwrap();
+    assert!(post_process_chain(&config).contains(&PostPass::Tonemap));
+    assert_eq!(config.set_render_scale(0.0), RENDER_SCALE_RANGE.0);
+    assert_eq!(config.set_render_scale(4.0), RENDER_SCALE_RANGE.1);
+}
+
+#[test]
+fn test_render_scale_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.scale 0.5");
+    assert_eq!(out, "Render scale set to 0.50");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.scale 0.1");
+    assert!(out.ends_with("(clamped)"), "{}", out);
+    assert_eq!(shared.config.borrow().render_scale, RENDER_SCALE_RANGE.0);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.scale");
+    assert_eq!(out, "Render scale: 0.25");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.scale half");
+    assert!(out.starts_with("Usage"), "{}", out);
+}
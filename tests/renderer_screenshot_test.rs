@@ -0,0 +1,180 @@
+use fireworks_sim::renderer_engine::utils::screenshot::{
+    default_hdr_screenshot_path, default_screenshot_path, flip_rows, save_rgba32f_async,
+    save_rgba_async, SCREENSHOTS_DIR,
+};
+
+#[cfg(feature = "interactive_tests")]
+mod helpers;
+
+// ==================================
+// 1. Inversion des lignes
+// ==================================
+
+#[test]
+fn test_flip_rows_reverses_row_order() {
+    // 2 x 3 pixels, 1 octet par pixel
+    let mut pixels = vec![1, 2, 3, 4, 5, 6];
+    flip_rows(&mut pixels, 2, 3, 1);
+    assert_eq!(pixels, [5, 6, 3, 4, 1, 2]);
+
+    // Nombre de lignes pair, 4 octets par pixel
+    let mut pixels: Vec<u8> = (0..16).collect();
+    flip_rows(&mut pixels, 2, 2, 4);
+    let expected: Vec<u8> = (8..16).chain(0..8).collect();
+    assert_eq!(pixels, expected);
+}
+
+#[test]
+fn test_default_screenshot_path_is_timestamped_png() {
+    let path = default_screenshot_path();
+    assert!(path.starts_with(SCREENSHOTS_DIR));
+    assert_eq!(path.extension().unwrap(), "png");
+    assert!(path
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("screenshot_"));
+}
+
+// ==================================
+// 2. Encodage asynchrone
+// ==================================
+
+#[test]
+fn test_save_rgba_async_writes_flipped_png() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested/shot.png");
+
+    // Ligne du bas (première lue par OpenGL) rouge, ligne du haut verte
+    let mut pixels = Vec::new();
+    pixels.extend_from_slice(&[255, 0, 0, 255, 255, 0, 0, 255, 255, 0, 0, 255]);
+    pixels.extend_from_slice(&[0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255]);
+
+    let saved = save_rgba_async(pixels, 3, 2, path.clone())
+        .join()
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved, path);
+
+    let image = image::open(&path).unwrap().to_rgba8();
+    assert_eq!(image.dimensions(), (3, 2));
+    assert_eq!(image.get_pixel(0, 0).0, [0, 255, 0, 255]);
+    assert_eq!(image.get_pixel(0, 1).0, [255, 0, 0, 255]);
+}
+
+#[test]
+fn test_save_rgba_async_rejects_size_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let handle = save_rgba_async(vec![0; 4], 2, 2, dir.path().join("bad.png"));
+    assert!(handle.join().unwrap().is_err());
+    assert!(!dir.path().join("bad.png").exists());
+}
+
+#[test]
+fn test_save_rgba32f_async_keeps_hdr_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested/scene.exr");
+    assert!(default_hdr_screenshot_path()
+        .to_string_lossy()
+        .ends_with(".exr"));
+
+    // Ligne du bas (première lue par OpenGL) au-delà de 1.0, ligne du haut faible
+    let mut pixels = Vec::new();
+    pixels.extend_from_slice(&[4.0, 2.5, 0.0, 1.0, 4.0, 2.5, 0.0, 1.0]);
+    pixels.extend_from_slice(&[0.0, 0.0, 0.125, 1.0, 0.0, 0.0, 0.125, 1.0]);
+
+    let saved = save_rgba32f_async(pixels, 2, 2, path.clone())
+        .join()
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved, path);
+
+    let image = image::open(&path).unwrap().to_rgba32f();
+    assert_eq!(image.dimensions(), (2, 2));
+    assert_eq!(image.get_pixel(0, 0).0, [0.0, 0.0, 0.125, 1.0]);
+    assert_eq!(image.get_pixel(1, 1).0, [4.0, 2.5, 0.0, 1.0]);
+}
+
+#[test]
+fn test_save_rgba32f_async_requires_exr() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("scene.png");
+    let handle = save_rgba32f_async(vec![0.0; 4], 1, 1, path.clone());
+    let err = handle.join().unwrap().unwrap_err();
+    assert!(err.to_string().contains(".exr"), "{err}");
+    assert!(!path.exists());
+}
+
+// ==================================
+// 3. Capture réelle (contexte OpenGL requis)
+// ==================================
+
+#[cfg(feature = "interactive_tests")]
+#[test]
+fn test_capture_screenshot_matches_window_size() {
+    use fireworks_sim::physic_engine::PhysicConfig;
+    use fireworks_sim::renderer_engine::renderer::Renderer;
+
+    let physic = helpers::DummyPhysic::default();
+    let mut renderer =
+        Renderer::new(320, 240, "Screenshot Test", &PhysicConfig::default()).unwrap();
+    unsafe {
+        renderer.render_frame(&physic);
+    }
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("frame.png");
+    let saved = renderer
+        .capture_screenshot(Some(path.clone()))
+        .unwrap()
+        .join()
+        .unwrap()
+        .unwrap();
+
+    let (width, height) = renderer.window.as_ref().unwrap().get_framebuffer_size();
+    let image = image::open(saved).unwrap();
+    assert_eq!(
+        (image.width(), image.height()),
+        (width as u32, height as u32)
+    );
+
+    renderer.close();
+}
+
+#[cfg(feature = "interactive_tests")]
+#[test]
+fn test_capture_hdr_screenshot_reads_scene_target() {
+    use fireworks_sim::physic_engine::PhysicConfig;
+    use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+    use fireworks_sim::renderer_engine::{Renderer, RendererEngine};
+
+    let mut physic = helpers::DummyPhysic::default();
+    let mut renderer = Renderer::new_headless(320, 240, &PhysicConfig::default()).unwrap();
+    let mut registry = CommandRegistry::new();
+    renderer.register_commands(&mut registry);
+
+    // Sans passe HDR : rien à capturer
+    registry.execute(&mut helpers::DummyAudio, &mut physic, "renderer.bloom off");
+    registry.execute(
+        &mut helpers::DummyAudio,
+        &mut physic,
+        "renderer.tonemapping linear",
+    );
+    renderer.render_to_image(&physic).unwrap();
+    assert!(renderer.capture_hdr_screenshot(None).is_err());
+
+    registry.execute(&mut helpers::DummyAudio, &mut physic, "renderer.bloom on");
+    renderer.render_to_image(&physic).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let saved = renderer
+        .capture_hdr_screenshot(Some(dir.path().join("scene.exr")))
+        .unwrap()
+        .join()
+        .unwrap()
+        .unwrap();
+    let image = image::open(saved).unwrap();
+    assert_eq!((image.width(), image.height()), (320, 240));
+    assert!(matches!(image, image::DynamicImage::ImageRgba32F(_)));
+
+    renderer.close();
+}
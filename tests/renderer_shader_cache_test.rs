@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use fireworks_sim::renderer_engine::shader::{
+    get_or_compile_with, program_source_hash, CachedProgram, FsProgramCache, PreprocessedShader,
+    ProgramBackend, ProgramCacheStore, ProgramOrigin,
+};
+
+/// Backend GL simulé : un « binaire » est simplement la concaténation des sources
+#[derive(Default)]
+struct MockBackend {
+    binaries_supported: bool,
+    reject_binaries: bool,
+    compiles: usize,
+    loads: usize,
+    next_program: u32,
+}
+
+impl MockBackend {
+    fn with_binaries() -> Self {
+        Self {
+            binaries_supported: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl ProgramBackend for MockBackend {
+    fn driver_id(&self) -> String {
+        "mock|1.0".to_string()
+    }
+
+    fn supports_binaries(&self) -> bool {
+        self.binaries_supported
+    }
+
+    fn load_binary(&mut self, _cached: &CachedProgram) -> Option<u32> {
+        if self.reject_binaries {
+            return None;
+        }
+        self.loads += 1;
+        self.next_program += 1;
+        Some(self.next_program)
+    }
+
+    fn compile(
+        &mut self,
+        vertex: &PreprocessedShader,
+        fragment: &PreprocessedShader,
+    ) -> anyhow::Result<u32> {
+        if fragment.source.contains("syntax error") {
+            anyhow::bail!("compile failed: {}", vertex.source.len());
+        }
+        self.compiles += 1;
+        self.next_program += 1;
+        Ok(self.next_program)
+    }
+
+    fn program_binary(&mut self, _program: u32) -> Option<(u32, Vec<u8>)> {
+        Some((0x1234, b"binary".to_vec()))
+    }
+}
+
+/// Persistance en mémoire
+#[derive(Default)]
+struct MemoryStore {
+    entries: HashMap<String, CachedProgram>,
+    writes: usize,
+}
+
+impl ProgramCacheStore for MemoryStore {
+    fn load(&self, name: &str) -> Option<CachedProgram> {
+        self.entries.get(name).cloned()
+    }
+
+    fn store(&mut self, name: &str, program: &CachedProgram) -> anyhow::Result<()> {
+        self.writes += 1;
+        self.entries.insert(name.to_string(), program.clone());
+        Ok(())
+    }
+}
+
+fn shaders(fragment: &str) -> (PreprocessedShader, PreprocessedShader) {
+    (
+        PreprocessedShader::from_source("test.vert", "void main() {}"),
+        PreprocessedShader::from_source("test.frag", fragment),
+    )
+}
+
+// ==================================
+// 1. Empreinte et format
+// ==================================
+
+#[test]
+fn test_source_hash_is_stable_and_discriminating() {
+    let hash = program_source_hash("drv", "vs", "fs");
+    assert_eq!(hash, program_source_hash("drv", "vs", "fs"));
+    assert_ne!(hash, program_source_hash("drv", "vs", "fs2"));
+    assert_ne!(hash, program_source_hash("other", "vs", "fs"));
+    // Frontière entre sources prise en compte
+    assert_ne!(
+        program_source_hash("drv", "ab", "c"),
+        program_source_hash("drv", "a", "bc")
+    );
+}
+
+#[test]
+fn test_cached_program_bytes_round_trip() {
+    let program = CachedProgram {
+        source_hash: 0xdead_beef,
+        format: 7,
+        binary: vec![1, 2, 3],
+    };
+    assert_eq!(
+        CachedProgram::from_bytes(&program.to_bytes()),
+        Some(program)
+    );
+    assert_eq!(CachedProgram::from_bytes(b"FWSC"), None);
+    assert_eq!(CachedProgram::from_bytes(b"nope, not a cache file"), None);
+}
+
+// ==================================
+// 2. Invalidation
+// ==================================
+
+#[test]
+fn test_second_run_loads_from_cache() {
+    let (vs, fs) = shaders("void main() { }");
+    let mut backend = MockBackend::with_binaries();
+    let mut store = MemoryStore::default();
+
+    let (_, origin) = get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+    assert_eq!(origin, ProgramOrigin::Compiled);
+    assert_eq!(store.writes, 1);
+
+    let (_, origin) = get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+    assert_eq!(origin, ProgramOrigin::Cache);
+    assert_eq!((backend.compiles, backend.loads), (1, 1));
+}
+
+#[test]
+fn test_hash_mismatch_recompiles_and_refreshes_entry() {
+    let mut backend = MockBackend::with_binaries();
+    let mut store = MemoryStore::default();
+
+    let (vs, fs) = shaders("void main() { }");
+    get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+
+    // Même programme (même nom d'entrée), source modifiée
+    let (vs, fs) = shaders("void main() { discard; }");
+    let (_, origin) = get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+    assert_eq!(origin, ProgramOrigin::Compiled);
+    assert_eq!(backend.compiles, 2);
+    assert_eq!(store.entries.len(), 1);
+    let entry = store.entries.values().next().unwrap();
+    assert_eq!(
+        entry.source_hash,
+        program_source_hash("mock|1.0", &vs.source, &fs.source)
+    );
+}
+
+#[test]
+fn test_rejected_binary_falls_back_to_compilation() {
+    let (vs, fs) = shaders("void main() { }");
+    let mut store = MemoryStore::default();
+    get_or_compile_with(
+        &mut MockBackend::with_binaries(),
+        Some(&mut store),
+        &vs,
+        &fs,
+    )
+    .unwrap();
+
+    // Pilote mis à jour : le binaire est refusé malgré une empreinte identique
+    let mut backend = MockBackend {
+        reject_binaries: true,
+        ..MockBackend::with_binaries()
+    };
+    let (_, origin) = get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+    assert_eq!(origin, ProgramOrigin::Compiled);
+    assert_eq!(store.writes, 2);
+}
+
+#[test]
+fn test_without_binary_support_or_store_always_compiles() {
+    let (vs, fs) = shaders("void main() { }");
+    let mut store = MemoryStore::default();
+    let mut backend = MockBackend::default();
+    for _ in 0..2 {
+        let (_, origin) = get_or_compile_with(&mut backend, Some(&mut store), &vs, &fs).unwrap();
+        assert_eq!(origin, ProgramOrigin::Compiled);
+    }
+    assert_eq!(store.writes, 0);
+
+    let mut backend = MockBackend::with_binaries();
+    get_or_compile_with(&mut backend, None, &vs, &fs).unwrap();
+    assert_eq!(backend.compiles, 1);
+}
+
+#[test]
+fn test_compile_errors_are_not_cached() {
+    let (vs, fs) = shaders("syntax error");
+    let mut store = MemoryStore::default();
+    let result = get_or_compile_with(
+        &mut MockBackend::with_binaries(),
+        Some(&mut store),
+        &vs,
+        &fs,
+    );
+    assert!(result.is_err());
+    assert_eq!(store.writes, 0);
+}
+
+// ==================================
+// 3. Cache sur disque
+// ==================================
+
+#[test]
+fn test_fs_cache_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cache = FsProgramCache::new(dir.path().join("shaders"));
+    assert_eq!(cache.load("a.vert+b.frag"), None);
+
+    let program = CachedProgram {
+        source_hash: 42,
+        format: 1,
+        binary: vec![9; 32],
+    };
+    cache.store("a.vert+b.frag", &program).unwrap();
+    assert_eq!(cache.load("a.vert+b.frag"), Some(program));
+}
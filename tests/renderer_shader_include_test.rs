@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use fireworks_sim::renderer_engine::shader::{
+    preprocess_shader_file, preprocess_shader_with, PreprocessedShader, SourceLocation,
+    MAX_INCLUDE_DEPTH,
+};
+
+/// Préprocesse `entry` à partir de fichiers en mémoire
+fn preprocess(files: &[(&str, &str)], entry: &str) -> anyhow::Result<PreprocessedShader> {
+    let files: HashMap<PathBuf, String> = files
+        .iter()
+        .map(|(path, text)| (PathBuf::from(path), text.to_string()))
+        .collect();
+    preprocess_shader_with(entry, |path: &Path| {
+        files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| anyhow!("missing file {}", path.display()))
+    })
+}
+
+fn location(file: &str, line: usize) -> SourceLocation {
+    SourceLocation {
+        file: PathBuf::from(file),
+        line,
+    }
+}
+
+// ==================================
+// 1. Résolution des #include
+// ==================================
+
+#[test]
+fn test_nested_includes_are_expanded_in_place() {
+    let shader = preprocess(
+        &[
+            (
+                "post/main.frag",
+                "#version 330 core\n#include \"common/a.glsl\"\nvoid main() {}",
+            ),
+            ("post/common/a.glsl", "// a\n  #include \"b.glsl\""),
+            ("post/common/b.glsl", "float b() { return 1.0; }"),
+        ],
+        "post/main.frag",
+    )
+    .unwrap();
+
+    assert_eq!(
+        shader.source,
+        "#version 330 core\n// a\nfloat b() { return 1.0; }\nvoid main() {}\n"
+    );
+    assert!(!shader.source.contains("#include"));
+    assert_eq!(
+        shader.line_map,
+        vec![
+            location("post/main.frag", 1),
+            location("post/common/a.glsl", 1),
+            location("post/common/b.glsl", 1),
+            location("post/main.frag", 3),
+        ]
+    );
+}
+
+#[test]
+fn test_relative_parent_includes_are_normalized() {
+    let shader = preprocess(
+        &[
+            ("post/a/main.frag", "#include \"../shared/x.glsl\""),
+            ("post/shared/x.glsl", "float x;"),
+        ],
+        "post/a/main.frag",
+    )
+    .unwrap();
+    assert_eq!(
+        shader.original_location(1),
+        Some(&location("post/shared/x.glsl", 1))
+    );
+}
+
+// ==================================
+// 2. Erreurs
+// ==================================
+
+#[test]
+fn test_missing_include_reports_the_including_line() {
+    let err = preprocess(
+        &[("main.frag", "#version 330 core\n#include \"nope.glsl\"")],
+        "main.frag",
+    )
+    .unwrap_err();
+    let message = format!("{:#}", err);
+    assert!(message.contains("main.frag:2"), "{}", message);
+    assert!(message.contains("nope.glsl"), "{}", message);
+}
+
+#[test]
+fn test_malformed_include_is_rejected() {
+    let err = preprocess(&[("main.frag", "#include <system.glsl>")], "main.frag").unwrap_err();
+    assert!(format!("{:#}", err).contains("Malformed #include"));
+}
+
+#[test]
+fn test_include_cycle_is_detected() {
+    let err = preprocess(
+        &[
+            ("a.glsl", "#include \"b.glsl\""),
+            ("b.glsl", "#include \"./a.glsl\""),
+        ],
+        "a.glsl",
+    )
+    .unwrap_err();
+    let message = format!("{:#}", err);
+    assert!(
+        message.contains("cycle: a.glsl -> b.glsl -> a.glsl"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn test_include_depth_is_limited() {
+    // Chaîne 0 → 1 → 2 → … sans cycle, plus longue que la limite
+    let files: Vec<(String, String)> = (0..=MAX_INCLUDE_DEPTH)
+        .map(|i| {
+            (
+                format!("f{}.glsl", i),
+                format!("#include \"f{}.glsl\"", i + 1),
+            )
+        })
+        .collect();
+    let refs: Vec<(&str, &str)> = files
+        .iter()
+        .map(|(p, t)| (p.as_str(), t.as_str()))
+        .collect();
+    let err = preprocess(&refs, "f0.glsl").unwrap_err();
+    assert!(format!("{:#}", err).contains("include depth"));
+}
+
+// ==================================
+// 3. Remappage des lignes d'erreur
+// ==================================
+
+#[test]
+fn test_compile_error_is_mapped_to_included_file() {
+    let shader = preprocess(
+        &[
+            (
+                "main.frag",
+                "#version 330 core\nout vec4 FragColor;\n#include \"broken.glsl\"\nvoid main() {}",
+            ),
+            (
+                "broken.glsl",
+                "float ok() { return 1.0; }\nfloat ko( { return }",
+            ),
+        ],
+        "main.frag",
+    )
+    .unwrap();
+
+    // Le driver signale la ligne 4 du source généré = ligne 2 de broken.glsl
+    let log = "0:4(11): error: syntax error, unexpected '{'";
+    assert_eq!(
+        shader.original_location(4),
+        Some(&location("broken.glsl", 2))
+    );
+
+    let described = shader.describe_compile_error(log);
+    assert!(described.starts_with(log));
+    assert!(described.contains("broken.glsl:2"), "{}", described);
+    // Extrait du source généré autour de la ligne fautive
+    assert!(
+        described.contains(">   4 | float ko( { return }"),
+        "{}",
+        described
+    );
+}
+
+#[test]
+fn test_unparseable_log_is_left_untouched() {
+    let shader = preprocess(&[("main.frag", "void main() {}")], "main.frag").unwrap();
+    assert_eq!(shader.describe_compile_error("link error\n"), "link error");
+    assert_eq!(shader.original_location(0), None);
+    assert_eq!(shader.original_location(2), None);
+}
+
+// ==================================
+// 4. Shaders fournis
+// ==================================
+
+#[test]
+fn test_bundled_post_shaders_preprocess() {
+    for name in [
+        "fullscreen.vert.glsl",
+        "bloom_extract.frag.glsl",
+        "bloom_blur.frag.glsl",
+        "bloom_composition.frag.glsl",
+        "luminance.frag.glsl",
+        "fxaa.frag.glsl",
+    ] {
+        let path = Path::new("assets/shaders/post").join(name);
+        let shader = preprocess_shader_file(&path).unwrap();
+        assert!(shader.source.starts_with("#version"), "{}", name);
+        assert!(!shader.source.contains("#include"), "{}", name);
+    }
+}
@@ -0,0 +1,145 @@
+use fireworks_sim::physic_engine::ParticleType;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::render_stats::{DrawStats, GpuMemory, RenderStats};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::utils::texture::TextureSlot;
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+fn points_draw(explosion: usize, trail: usize) -> DrawStats {
+    let mut particles = [0; ParticleType::ALL.len()];
+    particles[ParticleType::Explosion as usize] = explosion;
+    particles[ParticleType::Trail as usize] = trail;
+    DrawStats {
+        particles,
+        bytes_uploaded: ((explosion + trail) * 40) as u64,
+        draw_calls: 1,
+        instanced_draw_calls: 0,
+    }
+}
+
+fn rocket_draw(rockets: usize) -> DrawStats {
+    let mut particles = [0; ParticleType::ALL.len()];
+    particles[ParticleType::Rocket as usize] = rockets;
+    DrawStats {
+        particles,
+        bytes_uploaded: (rockets * 40) as u64,
+        draw_calls: 1,
+        instanced_draw_calls: 1,
+    }
+}
+
+// ==================================
+// 1. Accumulation
+// ==================================
+
+#[test]
+fn test_draws_accumulate_per_type() {
+    let mut stats = RenderStats::default();
+    stats.record_draw(&points_draw(100, 20));
+    stats.record_draw(&rocket_draw(5));
+
+    assert_eq!(stats.draw_calls, 2);
+    assert_eq!(stats.instanced_draw_calls, 1);
+    assert_eq!(stats.particles_of(ParticleType::Explosion), 100);
+    assert_eq!(stats.particles_of(ParticleType::Trail), 20);
+    assert_eq!(stats.particles_of(ParticleType::Rocket), 5);
+    assert_eq!(stats.particles_of(ParticleType::Smoke), 0);
+    assert_eq!(stats.bytes_uploaded, 125 * 40);
+}
+
+#[test]
+fn test_reset_starts_a_new_frame() {
+    let mut stats = RenderStats::default();
+    stats.record_draw(&rocket_draw(5));
+    stats.memory.buffers = 1024;
+    stats.add_framebuffer("output", 800, 600);
+
+    stats.reset();
+    assert_eq!(stats, RenderStats::default());
+
+    // Frame suivante : seuls ses propres dessins comptent
+    stats.record_draw(&rocket_draw(2));
+    assert_eq!(stats.particles_of(ParticleType::Rocket), 2);
+    assert_eq!(stats.draw_calls, 1);
+}
+
+#[test]
+fn test_unallocated_framebuffers_are_skipped() {
+    let mut stats = RenderStats::default();
+    stats.add_framebuffer("output", 1920, 1080);
+    stats.add_framebuffer("fxaa", 0, 0);
+    assert_eq!(stats.framebuffers, vec![("output", 1920, 1080)]);
+}
+
+// ==================================
+// 2. Mémoire estimée
+// ==================================
+
+#[test]
+fn test_gpu_memory_sums() {
+    let mut memory = GpuMemory {
+        buffers: 10,
+        framebuffers: 20,
+        textures: 30,
+    };
+    memory += GpuMemory {
+        buffers: 1,
+        ..Default::default()
+    };
+    assert_eq!(memory.buffers, 11);
+    assert_eq!(memory.total(), 61);
+}
+
+#[test]
+fn test_texture_slot_size() {
+    assert_eq!(TextureSlot::default().byte_size(), 0);
+    let slot = TextureSlot::new(1, "a.png", 64, 32);
+    assert_eq!(slot.byte_size(), 64 * 32 * 4);
+}
+
+// ==================================
+// 3. Console
+// ==================================
+
+#[test]
+fn test_stats_report() {
+    let mut stats = RenderStats::default();
+    stats.record_draw(&points_draw(1000, 0));
+    stats.record_draw(&rocket_draw(24));
+    stats.memory = GpuMemory {
+        buffers: 3 * 1024 * 1024,
+        framebuffers: 512 * 1024,
+        textures: 100,
+    };
+    stats.add_framebuffer("output", 1280, 720);
+    stats.add_framebuffer("scene", 640, 360);
+
+    let report = stats.format();
+    assert!(report.contains("Draw calls: 2 (1 instanced)"), "{report}");
+    assert!(report.contains("rocket 24, explosion 1000, smoke 0, trail 0"));
+    assert!(
+        report.contains("Mapped buffer writes: 40.00 KB"),
+        "{report}"
+    );
+    assert!(report.contains("buffers 3.00 MB, framebuffers 512.00 KB, textures 100 B"));
+    assert!(report.contains("Framebuffers: output 1280x720, scene 640x360"));
+}
+
+#[test]
+fn test_stats_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.stats");
+    assert!(out.contains("Draw calls: 0 (0 instanced)"), "{out}");
+    assert!(out.contains("Framebuffers: -"));
+
+    shared.stats.borrow_mut().record_draw(&rocket_draw(3));
+    let out = registry.execute(&mut audio, &mut physic, "renderer.stats");
+    assert!(out.contains("rocket 3"), "{out}");
+}
@@ -0,0 +1,205 @@
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::post_process::{post_process_chain, PostPass};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::tonemap::{
+    comparison_grid, grid_dimensions, parse_comparison_modes, CellRect, ToneMappingMode,
+    MAX_COMPARISON_CELLS,
+};
+use fireworks_sim::renderer_engine::utils::label::{burn_label, label_size};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+fn overlaps(a: &CellRect, b: &CellRect) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+// ==================================
+// 1. Disposition de la grille
+// ==================================
+
+#[test]
+fn test_grid_dimensions_follow_window_orientation() {
+    // Deux cellules : côte à côte en paysage, empilées en portrait
+    assert_eq!(grid_dimensions(2, 1600, 900), (2, 1));
+    assert_eq!(grid_dimensions(2, 900, 1600), (1, 2));
+    // Trois ou quatre : 2×2 (échelle 1/2 plutôt que 1/3)
+    assert_eq!(grid_dimensions(3, 1600, 900), (2, 2));
+    assert_eq!(grid_dimensions(4, 1600, 900), (2, 2));
+    assert_eq!(grid_dimensions(6, 1600, 900), (3, 2));
+    assert_eq!(grid_dimensions(6, 900, 1600), (2, 3));
+}
+
+#[test]
+fn test_comparison_grid_cells_fit_and_keep_aspect() {
+    for (width, height) in [(1600, 900), (900, 1600), (1024, 1024), (2560, 1080)] {
+        let aspect = width as f32 / height as f32;
+        for count in 2..=MAX_COMPARISON_CELLS {
+            let cells = comparison_grid(count, width, height);
+            assert_eq!(cells.len(), count);
+            for (i, cell) in cells.iter().enumerate() {
+                assert!(cell.x >= 0 && cell.y >= 0, "{:?}", cell);
+                assert!(cell.x + cell.width <= width && cell.y + cell.height <= height);
+                let cell_aspect = cell.width as f32 / cell.height as f32;
+                assert!((cell_aspect - aspect).abs() / aspect < 0.02, "{:?}", cell);
+                for other in &cells[i + 1..] {
+                    assert!(!overlaps(cell, other), "{:?} / {:?}", cell, other);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_comparison_grid_reading_order_and_centered_last_row() {
+    let cells = comparison_grid(3, 1600, 900);
+    // Ligne du haut (y le plus grand en convention OpenGL), de gauche à droite
+    assert_eq!(cells[0].y, cells[1].y);
+    assert!(cells[0].x < cells[1].x);
+    assert!(cells[2].y < cells[0].y);
+    // Dernière ligne incomplète : centrée
+    let center = cells[2].x + cells[2].width / 2;
+    assert!((center - 800).abs() <= 1, "{:?}", cells[2]);
+
+    // Une seule cellule : plein écran ; au-delà du maximum : tronqué
+    assert_eq!(
+        comparison_grid(1, 1600, 900),
+        vec![CellRect {
+            x: 0,
+            y: 0,
+            width: 1600,
+            height: 900
+        }]
+    );
+    assert_eq!(comparison_grid(9, 1600, 900).len(), MAX_COMPARISON_CELLS);
+    assert!(comparison_grid(0, 1600, 900).is_empty());
+    assert!(comparison_grid(3, 0, 900).is_empty());
+}
+
+// ==================================
+// 2. Opérateurs et configuration
+// ==================================
+
+#[test]
+fn test_parse_comparison_modes() {
+    assert_eq!(
+        parse_comparison_modes(["aces", "Reinhard", "agx"]),
+        Ok(vec![
+            ToneMappingMode::Aces,
+            ToneMappingMode::Reinhard,
+            ToneMappingMode::Agx
+        ])
+    );
+    assert!(parse_comparison_modes(["aces"]).is_err());
+    assert!(parse_comparison_modes(["aces", "filmic"])
+        .unwrap_err()
+        .contains("filmic"));
+    assert!(parse_comparison_modes(["aces"; 7]).is_err());
+}
+
+#[test]
+fn test_tonemapping_config_and_chain() {
+    let config: RendererConfig = toml::from_str(
+        r#"
+        tonemapping = "agx"
+        tonemapping_compare_modes = ["aces", "linear"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.tonemapping, ToneMappingMode::Agx);
+    assert_eq!(
+        config.tonemapping_compare_modes,
+        vec![ToneMappingMode::Aces, ToneMappingMode::Linear]
+    );
+    // Un opérateur non linéaire active la composition HDR
+    assert!(post_process_chain(&config).contains(&PostPass::Tonemap));
+    assert!(!post_process_chain(&RendererConfig::default()).contains(&PostPass::Tonemap));
+}
+
+// ==================================
+// 3. Commandes console
+// ==================================
+
+#[test]
+fn test_tonemapping_commands() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.tonemapping aces");
+    assert_eq!(out, "Tone mapping: aces");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.tonemapping hable");
+    assert!(out.starts_with("Usage") && out.contains("agx"), "{}", out);
+
+    // Sauvegarde refusée tant que la grille n'est pas affichée
+    let out = registry.execute(&mut audio, &mut physic, "renderer.tonemapping.compare.save");
+    assert!(out.contains("off"), "{}", out);
+    assert!(shared.comparison_save_request.borrow().is_none());
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.tonemapping.compare.modes agx aces reinhard",
+    );
+    assert_eq!(out, "Tone mapping comparison: agx aces reinhard");
+    {
+        let config = shared.config.borrow();
+        assert!(config.tonemapping_compare);
+        assert_eq!(config.tonemapping_compare_modes[0], ToneMappingMode::Agx);
+    }
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.tonemapping.compare.modes agx bogus",
+    );
+    assert!(out.contains("bogus"), "{}", out);
+    assert_eq!(shared.config.borrow().tonemapping_compare_modes.len(), 3);
+
+    let out = registry.execute(
+        &mut audio,
+        &mut physic,
+        "renderer.tonemapping.compare.save docs/grid.png",
+    );
+    assert_eq!(out, "Comparison grid requested: docs/grid.png");
+    assert_eq!(
+        *shared.comparison_save_request.borrow(),
+        Some(Some("docs/grid.png".into()))
+    );
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.tonemapping.compare off");
+    assert_eq!(out, "Tone mapping comparison: off");
+}
+
+// ==================================
+// 4. Étiquettes incrustées
+// ==================================
+
+#[test]
+fn test_burn_label_stays_inside_its_box() {
+    let (width, height) = (64, 32);
+    let mut pixels = vec![10u8; width * height * 4];
+    burn_label(&mut pixels, width, height, 4, 2, "ACES", 1);
+
+    let (label_w, label_h) = label_size("ACES", 1);
+    let mut white = 0;
+    for y in 0..height {
+        for x in 0..width {
+            // Lignes stockées de bas en haut
+            let offset = ((height - 1 - y) * width + x) * 4;
+            let inside = (4..4 + label_w).contains(&x) && (2..2 + label_h).contains(&y);
+            if !inside {
+                assert_eq!(pixels[offset], 10, "pixel ({}, {}) modified", x, y);
+            } else if pixels[offset] == 255 {
+                white += 1;
+            }
+        }
+    }
+    assert!(white > 20);
+
+    // Étiquette débordant de l'image : tronquée sans panique
+    burn_label(&mut pixels, width, height, 60, 30, "AGX", 2);
+}
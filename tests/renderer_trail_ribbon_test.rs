@@ -0,0 +1,117 @@
+use fireworks_sim::physic_engine::{Particle, ParticleType};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::{RendererConfig, TrailStyle};
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::renderer_engine::renderer_trail_ribbon::{append_ribbon, RibbonVertex};
+use glam::{Vec2, Vec4};
+
+mod helpers;
+use helpers::{DummyAudio, DummyPhysic};
+
+/// Trail horizontal de `n` points espacés de 10 px, pleine vie, au premier plan
+fn straight_trail(n: usize) -> Vec<Particle> {
+    (0..n)
+        .map(|i| Particle {
+            pos: Vec2::new(i as f32 * 10.0, 100.0),
+            color: Vec4::new(1.0, 0.5, 0.25, 1.0),
+            life: 1.0,
+            max_life: 1.0,
+            active: true,
+            particle_type: ParticleType::Trail,
+            ..Particle::default()
+        })
+        .collect()
+}
+
+fn width_at(vertices: &[RibbonVertex], point: usize) -> f32 {
+    let left = Vec2::from(vertices[2 * point].pos);
+    let right = Vec2::from(vertices[2 * point + 1].pos);
+    left.distance(right)
+}
+
+// ==================================
+// 1. Géométrie du ruban
+// ==================================
+
+#[test]
+fn test_ribbon_needs_two_points() {
+    let mut out = Vec::new();
+    assert_eq!(append_ribbon(&[], 4.0, &mut out), 0);
+    assert_eq!(append_ribbon(&straight_trail(1), 4.0, &mut out), 0);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_ribbon_tapers_and_fades_toward_tail() {
+    let mut out = Vec::new();
+    let count = append_ribbon(&straight_trail(5), 4.0, &mut out);
+    assert_eq!(count, 10);
+    assert_eq!(out.len(), 10);
+
+    // Largeur nulle à la queue, pleine à la tête, croissante entre les deux
+    assert!(width_at(&out, 0) < 1e-5);
+    assert!((width_at(&out, 4) - 4.0).abs() < 1e-4);
+    for point in 0..4 {
+        assert!(width_at(&out, point) < width_at(&out, point + 1));
+        assert!(out[2 * point].color[3] < out[2 * point + 2].color[3]);
+    }
+    // Trail horizontal : bords décalés verticalement, de part et d'autre du point
+    assert_eq!(out[8].pos[0], 40.0);
+    assert!(((out[8].pos[1] - 100.0).abs() - 2.0).abs() < 1e-4);
+    assert_eq!((out[8].side, out[9].side), (-1.0, 1.0));
+    assert_eq!(&out[9].color[..3], &[1.0, 0.5, 0.25]);
+}
+
+#[test]
+fn test_ribbons_append_after_each_other() {
+    let mut out = Vec::new();
+    append_ribbon(&straight_trail(3), 2.0, &mut out);
+    let first = out.len();
+    append_ribbon(&straight_trail(4), 2.0, &mut out);
+    assert_eq!((first, out.len()), (6, 14));
+    // Points confondus : la normale précédente est conservée, pas de NaN
+    let mut stacked = straight_trail(3);
+    stacked[1].pos = stacked[0].pos;
+    stacked[2].pos = stacked[0].pos;
+    let mut out = Vec::new();
+    append_ribbon(&stacked, 2.0, &mut out);
+    assert!(out.iter().all(|v| v.pos.iter().all(|c| c.is_finite())));
+}
+
+// ==================================
+// 2. Configuration et console
+// ==================================
+
+#[test]
+fn test_trail_style_config() {
+    assert_eq!(RendererConfig::default().trail_style, TrailStyle::Dots);
+    let config: RendererConfig = toml::from_str(
+        r#"
+        trail_style = "ribbon"
+        trail_ribbon_width = 5.0
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.trail_style, TrailStyle::Ribbon);
+    assert_eq!(config.trail_ribbon_width, 5.0);
+    assert_eq!(TrailStyle::from_name("RIBBON"), Some(TrailStyle::Ribbon));
+    assert_eq!(TrailStyle::from_name("lines"), None);
+}
+
+#[test]
+fn test_renderer_trails_command() {
+    let shared = RendererShared::default();
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &shared);
+    let mut audio = DummyAudio;
+    let mut physic = DummyPhysic::default();
+
+    let out = registry.execute(&mut audio, &mut physic, "renderer.trails");
+    assert_eq!(out, "Trail style: dots");
+    let out = registry.execute(&mut audio, &mut physic, "renderer.trails ribbon");
+    assert_eq!(out, "Trail style: ribbon");
+    assert_eq!(shared.config.borrow().trail_style, TrailStyle::Ribbon);
+    let out = registry.execute(&mut audio, &mut physic, "renderer.trails lines");
+    assert!(out.starts_with("Usage"), "{}", out);
+    assert_eq!(shared.config.borrow().trail_style, TrailStyle::Ribbon);
+}
@@ -0,0 +1,128 @@
+use fireworks_sim::physic_engine::Particle;
+use fireworks_sim::renderer_engine::renderer_graphics_instanced::{
+    rocket_streak_length, ROCKET_STREAK_MAX_LENGTH,
+};
+use fireworks_sim::renderer_engine::types::{
+    depth_to_scale, DEPTH_FOCAL_LENGTH, INSTANCED_ATTRIBS, INSTANCED_STRIDE,
+};
+use fireworks_sim::renderer_engine::ParticleGPU;
+use memoffset::offset_of;
+
+// ==================================
+// 1. Projection pseudo-3D
+// ==================================
+
+#[test]
+fn test_depth_to_scale_mapping() {
+    // Premier plan : comportement 2D inchangé
+    assert_eq!(depth_to_scale(0.0), 1.0);
+    // À la distance focale : moitié de la taille
+    assert!((depth_to_scale(DEPTH_FOCAL_LENGTH) - 0.5).abs() < 1e-6);
+    // Profondeur négative clampée au premier plan
+    assert_eq!(depth_to_scale(-100.0), 1.0);
+
+    // Décroissance stricte : plus loin = plus petit
+    let mut previous = depth_to_scale(0.0);
+    for depth in (1..20).map(|i| i as f32 * 100.0) {
+        let scale = depth_to_scale(depth);
+        assert!(scale < previous && scale > 0.0);
+        previous = scale;
+    }
+}
+
+#[test]
+fn test_particle_gpu_near_larger_than_far() {
+    let near = Particle {
+        depth: 0.0,
+        ..Default::default()
+    };
+    let far = Particle {
+        depth: 1200.0,
+        ..Default::default()
+    };
+    assert!(ParticleGPU::from(&near).depth_scale > ParticleGPU::from(&far).depth_scale);
+}
+
+// ==================================
+// 2. Layout mémoire GPU
+// ==================================
+
+#[test]
+fn test_particle_gpu_layout() {
+    assert_eq!(std::mem::size_of::<ParticleGPU>(), 13 * 4);
+    assert_eq!(std::mem::align_of::<ParticleGPU>(), 4);
+    assert_eq!(offset_of!(ParticleGPU, pos_x), 0);
+    assert_eq!(offset_of!(ParticleGPU, col_r), 8);
+    assert_eq!(offset_of!(ParticleGPU, life), 20);
+    assert_eq!(offset_of!(ParticleGPU, depth_scale), 36);
+    assert_eq!(offset_of!(ParticleGPU, vel_x), 40);
+    assert_eq!(offset_of!(ParticleGPU, vel_y), 44);
+    assert_eq!(offset_of!(ParticleGPU, sprite), 48);
+}
+
+#[test]
+fn test_particle_gpu_carries_velocity() {
+    let p = Particle {
+        vel: glam::Vec2::new(12.0, -34.0),
+        ..Default::default()
+    };
+    let gpu = ParticleGPU::from(&p);
+    assert_eq!((gpu.vel_x, gpu.vel_y), (12.0, -34.0));
+}
+
+#[test]
+fn test_particle_gpu_carries_sprite() {
+    let p = Particle {
+        sprite: 7,
+        ..Default::default()
+    };
+    assert_eq!(ParticleGPU::from(&p).sprite, 7.0);
+    assert_eq!(ParticleGPU::from(&Particle::default()).sprite, 0.0);
+}
+
+#[test]
+fn test_instanced_attribs_match_particle_gpu_layout() {
+    assert_eq!(INSTANCED_STRIDE, std::mem::size_of::<ParticleGPU>());
+
+    let expected = [
+        (1, 2, offset_of!(ParticleGPU, pos_x)),
+        (2, 3, offset_of!(ParticleGPU, col_r)),
+        (3, 4, offset_of!(ParticleGPU, life)),
+        (4, 1, offset_of!(ParticleGPU, depth_scale)),
+        (5, 2, offset_of!(ParticleGPU, vel_x)),
+        (6, 1, offset_of!(ParticleGPU, sprite)),
+    ];
+    assert_eq!(INSTANCED_ATTRIBS.len(), expected.len());
+    for (attrib, (location, components, offset)) in INSTANCED_ATTRIBS.iter().zip(expected) {
+        assert_eq!(
+            (attrib.location, attrib.components, attrib.offset),
+            (location, components, offset)
+        );
+    }
+
+    // Les attributs couvrent exactement la structure, sans chevauchement
+    let floats: i32 = INSTANCED_ATTRIBS.iter().map(|a| a.components).sum();
+    assert_eq!(floats as usize * 4, INSTANCED_STRIDE);
+    for pair in INSTANCED_ATTRIBS.windows(2) {
+        assert_eq!(
+            pair[0].offset + pair[0].components as usize * 4,
+            pair[1].offset
+        );
+    }
+}
+
+// ==================================
+// 3. Traînée des fusées
+// ==================================
+
+#[test]
+fn test_rocket_streak_length_grows_with_speed_and_is_clamped() {
+    assert_eq!(rocket_streak_length(0.0, 1.0), 0.0);
+    let slow = rocket_streak_length(200.0, 1.0);
+    let fast = rocket_streak_length(800.0, 1.0);
+    assert!(slow > 0.0 && fast > slow);
+    // Plafonnée pour les vitesses extrêmes
+    assert_eq!(rocket_streak_length(1.0e6, 1.0), ROCKET_STREAK_MAX_LENGTH);
+    // Plus courte au loin, comme le reste du sprite
+    assert!(rocket_streak_length(800.0, 0.5) < fast);
+}
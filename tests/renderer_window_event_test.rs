@@ -0,0 +1,151 @@
+use fireworks_sim::renderer_engine::utils::glfw_window::{translate_event, translate_key};
+use fireworks_sim::renderer_engine::window_event::{
+    default_key_action, Action, EventRouter, KeyCode, MouseButton, Reaction, WindowEvent,
+};
+use glam::Vec2;
+use glfw::{Key, Modifiers};
+
+fn key_event(key: Key, action: glfw::Action) -> glfw::WindowEvent {
+    glfw::WindowEvent::Key(key, 0, action, Modifiers::empty())
+}
+
+// ==================================
+// 1. Traduction des événements glfw
+// ==================================
+
+#[test]
+fn test_translate_glfw_events() {
+    assert_eq!(
+        translate_event(&glfw::WindowEvent::FramebufferSize(800, 600)),
+        Some(WindowEvent::Resize(800, 600))
+    );
+    assert_eq!(
+        translate_event(&key_event(Key::GraveAccent, glfw::Action::Press)),
+        Some(WindowEvent::KeyPress(KeyCode::GraveAccent))
+    );
+    // Relâchement et répétition ignorés, comme avant
+    assert_eq!(
+        translate_event(&key_event(Key::R, glfw::Action::Release)),
+        None
+    );
+    assert_eq!(
+        translate_event(&key_event(Key::R, glfw::Action::Repeat)),
+        None
+    );
+    assert_eq!(
+        translate_event(&glfw::WindowEvent::MouseButton(
+            glfw::MouseButton::Button3,
+            glfw::Action::Press,
+            Modifiers::empty()
+        )),
+        Some(WindowEvent::MouseButton(MouseButton::Middle, true))
+    );
+    assert_eq!(
+        translate_event(&glfw::WindowEvent::Scroll(0.0, -2.0)),
+        Some(WindowEvent::Scroll(-2.0))
+    );
+    assert_eq!(
+        translate_event(&glfw::WindowEvent::Char('a')),
+        Some(WindowEvent::Char('a'))
+    );
+    assert_eq!(translate_event(&glfw::WindowEvent::Focus(true)), None);
+}
+
+#[test]
+fn test_key_names_round_trip() {
+    for &key in KeyCode::ALL {
+        assert_eq!(KeyCode::from_name(key.name()), Some(key), "{:?}", key);
+    }
+    assert_eq!(KeyCode::from_name("F11"), Some(KeyCode::F11));
+    assert_eq!(KeyCode::from_name("grave"), Some(KeyCode::GraveAccent));
+    assert_eq!(KeyCode::from_name("hyper"), None);
+    assert_eq!(translate_key(Key::Num7), Some(KeyCode::Num7));
+    assert_eq!(translate_key(Key::Menu), None);
+}
+
+// ==================================
+// 2. Réactions du simulateur
+// ==================================
+
+#[test]
+fn test_default_key_actions() {
+    assert_eq!(default_key_action(KeyCode::Escape), Some(Action::Quit));
+    assert_eq!(default_key_action(KeyCode::R), Some(Action::ReloadConfig));
+    assert_eq!(
+        default_key_action(KeyCode::F11),
+        Some(Action::ToggleFullscreen)
+    );
+    assert_eq!(
+        default_key_action(KeyCode::GraveAccent),
+        Some(Action::ToggleConsole)
+    );
+    assert_eq!(default_key_action(KeyCode::F1), Some(Action::ToggleHud));
+    assert_eq!(default_key_action(KeyCode::F12), Some(Action::Screenshot));
+    assert_eq!(default_key_action(KeyCode::W), None);
+}
+
+#[test]
+fn test_router_keys_resize_and_close() {
+    let mut router = EventRouter::new(600.0);
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::Escape), false),
+        Some(Reaction::Action(Action::Quit))
+    );
+    assert_eq!(
+        router.route(&WindowEvent::Close, false),
+        Some(Reaction::Action(Action::Quit))
+    );
+    assert_eq!(router.route(&WindowEvent::Char('r'), false), None);
+
+    assert_eq!(
+        router.route(&WindowEvent::Resize(1024, 768), false),
+        Some(Reaction::Resize(1024, 768))
+    );
+    // Le curseur est retourné avec la nouvelle hauteur
+    router.route(&WindowEvent::CursorPos(10.0, 8.0), false);
+    assert_eq!(router.cursor_pos, Vec2::new(10.0, 760.0));
+}
+
+#[test]
+fn test_router_middle_drag_pans_camera() {
+    let mut router = EventRouter::new(600.0);
+    // Sans glisser : le curseur est suivi sans déplacer la caméra
+    assert_eq!(
+        router.route(&WindowEvent::CursorPos(100.0, 100.0), false),
+        None
+    );
+
+    router.route(&WindowEvent::MouseButton(MouseButton::Middle, true), false);
+    assert_eq!(
+        router.route(&WindowEvent::CursorPos(110.0, 90.0), false),
+        Some(Reaction::Pan(Vec2::new(10.0, 10.0)))
+    );
+    router.route(&WindowEvent::MouseButton(MouseButton::Middle, false), false);
+    assert_eq!(router.route(&WindowEvent::CursorPos(0.0, 0.0), false), None);
+
+    // Les autres boutons ne déclenchent pas de glisser
+    router.route(&WindowEvent::MouseButton(MouseButton::Left, true), false);
+    assert!(!router.camera_drag);
+}
+
+#[test]
+fn test_router_console_blocks_camera_controls() {
+    let mut router = EventRouter::new(600.0);
+    router.route(&WindowEvent::CursorPos(200.0, 100.0), false);
+    assert_eq!(
+        router.route(&WindowEvent::Scroll(1.0), false),
+        Some(Reaction::Zoom {
+            anchor: Vec2::new(200.0, 500.0),
+            steps: 1.0
+        })
+    );
+
+    assert_eq!(router.route(&WindowEvent::Scroll(1.0), true), None);
+    router.route(&WindowEvent::MouseButton(MouseButton::Middle, true), true);
+    assert!(!router.camera_drag);
+    // Les raccourcis restent actifs console ouverte (fermeture par ²)
+    assert_eq!(
+        router.route(&WindowEvent::KeyPress(KeyCode::GraveAccent), true),
+        Some(Reaction::Action(Action::ToggleConsole))
+    );
+}
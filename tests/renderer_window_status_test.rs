@@ -0,0 +1,92 @@
+use fireworks_sim::renderer_engine::utils::glfw_window::translate_event;
+use fireworks_sim::renderer_engine::window_event::{EventRouter, Reaction, WindowEvent};
+use fireworks_sim::renderer_engine::window_status::{
+    format_window_title, TitleUpdater, WindowActivity, MINIMIZED_UPDATE_HZ, WINDOW_ICON_PNG,
+};
+use std::time::Duration;
+
+// ==================================
+// 1. Titre de la fenêtre
+// ==================================
+
+#[test]
+fn test_format_window_title() {
+    assert_eq!(
+        format_window_title("Fireworks", 59.6, 12345),
+        "Fireworks | 60 FPS | 12345 particles"
+    );
+    assert_eq!(
+        format_window_title("Fireworks", -1.0, 0),
+        "Fireworks | 0 FPS | 0 particles"
+    );
+}
+
+#[test]
+fn test_title_updated_once_per_second() {
+    let mut title = TitleUpdater::new("Fireworks");
+    assert_eq!(title.base(), "Fireworks");
+
+    // 60 frames à ~60 Hz : un seul rafraîchissement, à la fin de la seconde
+    let updates: Vec<(usize, String)> = (0..61)
+        .filter_map(|i| title.update(1.0 / 60.0, 60.0, i).map(|t| (i, t)))
+        .collect();
+    assert_eq!(updates.len(), 1, "{:?}", updates);
+    assert!(updates[0].0 >= 59);
+    assert!(updates[0].1.starts_with("Fireworks | 60 FPS"));
+
+    // Frame très longue : un seul rafraîchissement, pas de rattrapage
+    assert!(title.update(3.5, 1.0, 0).is_some());
+    assert!(title.update(0.1, 1.0, 0).is_none());
+}
+
+// ==================================
+// 2. Fenêtre réduite
+// ==================================
+
+#[test]
+fn test_minimized_throttle_state_machine() {
+    let mut activity = WindowActivity::default();
+    let vsync_budget = Some(Duration::from_millis(7));
+    assert!(activity.should_render());
+    assert_eq!(activity.frame_budget(None), None);
+    assert_eq!(activity.frame_budget(vsync_budget), vsync_budget);
+
+    // Réduite : plus de rendu, boucle à 10 Hz quel que soit le plafond normal
+    assert!(activity.set_minimized(true));
+    assert!(!activity.should_render());
+    let throttled = Duration::from_secs_f32(1.0 / MINIMIZED_UPDATE_HZ);
+    assert_eq!(activity.frame_budget(None), Some(throttled));
+    assert_eq!(activity.frame_budget(vsync_budget), Some(throttled));
+    // Événement répété : pas de changement
+    assert!(!activity.set_minimized(true));
+
+    // Restaurée : retour au budget normal
+    assert!(activity.set_minimized(false));
+    assert!(activity.should_render());
+    assert_eq!(activity.frame_budget(vsync_budget), vsync_budget);
+}
+
+#[test]
+fn test_iconify_event_is_routed() {
+    assert_eq!(
+        translate_event(&glfw::WindowEvent::Iconify(true)),
+        Some(WindowEvent::Iconified(true))
+    );
+    let mut router = EventRouter::new(600.0);
+    router.camera_drag = true;
+    assert_eq!(
+        router.route(&WindowEvent::Iconified(true), false),
+        Some(Reaction::Minimized(true))
+    );
+    assert!(!router.camera_drag);
+    assert_eq!(
+        router.route(&WindowEvent::Iconified(false), false),
+        Some(Reaction::Minimized(false))
+    );
+}
+
+#[test]
+fn test_embedded_window_icon_is_valid_png() {
+    let icon = image::load_from_memory(WINDOW_ICON_PNG).unwrap();
+    assert_eq!((icon.width(), icon.height()), (64, 64));
+}
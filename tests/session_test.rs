@@ -0,0 +1,243 @@
+mod helpers;
+
+use fireworks_sim::app_options::{AppOptions, DEFAULT_WINDOW_SIZE};
+use fireworks_sim::audio_engine::fireworks_audio::FireworksAudio3D;
+use fireworks_sim::audio_engine::types::FireworksAudioConfig;
+use fireworks_sim::audio_engine::AudioEngine;
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::physic_engine::physic_engine_generational_arena::PhysicEngineFireworks;
+use fireworks_sim::physic_engine::PhysicEngine;
+use fireworks_sim::renderer_engine::command_script::ExecArgs;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::session::{AudioSession, CameraSession, PhysicSession, Session, WindowSession};
+use fireworks_sim::AudioEngineSettings;
+use fireworks_sim::Simulator;
+use helpers::DummyRenderer;
+
+fn build_simulator() -> Simulator<DummyRenderer, PhysicEngineFireworks, FireworksAudio3D> {
+    let audio = FireworksAudio3D::new(FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (0.0, 0.0),
+        sample_rate: 44100,
+        block_size: 1024,
+        max_voices: 16,
+        settings: AudioEngineSettings::default(),
+    });
+    let physic = PhysicEngineFireworks::new(&PhysicConfig::default(), 1024.0);
+    Simulator::new(DummyRenderer, physic, audio)
+}
+
+fn sample_session() -> Session {
+    let mut renderer = toml::Table::new();
+    renderer.insert("bloom_intensity".into(), toml::Value::Float(2.5));
+    Session {
+        window: Some(WindowSession {
+            size: [1600, 900],
+            position: Some([120, 80]),
+            fullscreen: false,
+        }),
+        camera: Some(CameraSession {
+            center: [10.0, -20.0],
+            zoom: 1.5,
+        }),
+        audio: Some(AudioSession {
+            volume: 0.4,
+            muted: true,
+        }),
+        physic: Some(PhysicSession {
+            rocket_palette: vec![[1.0, 0.2, 0.1], [0.1, 0.4, 1.0]],
+            explosion_shape: "ring".into(),
+            explosion_params: vec![0.5, 0.1],
+        }),
+        renderer,
+    }
+}
+
+// ==================================
+// 1. Aller-retour du fichier de session
+// ==================================
+
+#[test]
+fn test_session_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested/session.toml");
+    let session = sample_session();
+
+    session.save(&path).unwrap();
+    assert_eq!(Session::load(&path).unwrap(), Some(session));
+}
+
+#[test]
+fn test_session_load_missing_or_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.toml");
+    assert_eq!(Session::load(&path).unwrap(), None);
+
+    std::fs::write(&path, "window = 3").unwrap();
+    assert!(Session::load(&path).is_err());
+
+    // Sections absentes : session vide
+    std::fs::write(&path, "").unwrap();
+    assert_eq!(Session::load(&path).unwrap(), Some(Session::default()));
+}
+
+// ==================================
+// 2. Priorités : défauts < fichiers de config < session < ligne de commande
+// ==================================
+
+#[test]
+fn test_renderer_overrides_layer_over_the_config_file() {
+    let defaults = RendererConfig::default();
+    let mut file = defaults.clone();
+    file.bloom_intensity = defaults.bloom_intensity + 1.0;
+    file.vsync = !defaults.vsync;
+
+    // Réglage modifié à chaud : seul lui est retenu
+    let mut current = file.clone();
+    current.bloom_threshold = file.bloom_threshold + 0.25;
+    let mut session = Session::default();
+    session.set_renderer_overrides(&file, &current).unwrap();
+    assert_eq!(session.renderer.len(), 1, "{:?}", session.renderer);
+
+    // Fichier de config modifié entre-temps : ses autres valeurs sont gardées
+    let mut edited = file.clone();
+    edited.vsync = defaults.vsync;
+    let restored = session.apply_renderer_overrides(&edited).unwrap();
+    assert_eq!(restored.bloom_threshold, current.bloom_threshold);
+    assert_eq!(restored.bloom_intensity, file.bloom_intensity);
+    assert_eq!(restored.vsync, defaults.vsync);
+
+    // Sans surcharge, la config passe inchangée
+    let restored = Session::default().apply_renderer_overrides(&file).unwrap();
+    assert_eq!(restored.vsync, file.vsync);
+}
+
+#[test]
+fn test_nested_renderer_overrides() {
+    let file = RendererConfig::default();
+    let mut current = file.clone();
+    current.camera.wheel_zoom_step = file.camera.wheel_zoom_step * 2.0;
+
+    let mut session = Session::default();
+    session.set_renderer_overrides(&file, &current).unwrap();
+    let camera = session.renderer["camera"].as_table().unwrap();
+    assert_eq!(camera.len(), 1, "{:?}", camera);
+
+    let restored = session.apply_renderer_overrides(&file).unwrap();
+    assert_eq!(
+        restored.camera.wheel_zoom_step,
+        current.camera.wheel_zoom_step
+    );
+    assert_eq!(restored.camera.min_zoom, file.camera.min_zoom);
+}
+
+#[test]
+fn test_invalid_renderer_overrides_are_rejected() {
+    let mut session = Session::default();
+    session
+        .renderer
+        .insert("vsync".into(), toml::Value::String("yes".into()));
+    assert!(session
+        .apply_renderer_overrides(&RendererConfig::default())
+        .is_err());
+}
+
+#[test]
+fn test_window_size_precedence() {
+    let session = sample_session();
+
+    // La session l'emporte sur la taille par défaut
+    let mut options = AppOptions::try_parse_from(["fireworks-sim"], |_| None).unwrap();
+    assert_eq!(options.size, DEFAULT_WINDOW_SIZE);
+    session.apply_to_options(&mut options);
+    assert_eq!(options.size, (1600, 900));
+    assert!(!options.fullscreen);
+
+    // `--size` l'emporte sur la session
+    let mut options =
+        AppOptions::try_parse_from(["fireworks-sim", "--size", "800x600"], |_| None).unwrap();
+    assert!(options.size_from_cli);
+    session.apply_to_options(&mut options);
+    assert_eq!(options.size, (800, 600));
+
+    // Plein écran : la session ou `--fullscreen`
+    let mut fullscreen = session.clone();
+    fullscreen.window.as_mut().unwrap().fullscreen = true;
+    let mut options = AppOptions::default();
+    fullscreen.apply_to_options(&mut options);
+    assert!(options.fullscreen);
+    let mut options =
+        AppOptions::try_parse_from(["fireworks-sim", "--fullscreen"], |_| None).unwrap();
+    session.apply_to_options(&mut options);
+    assert!(options.fullscreen);
+}
+
+#[test]
+fn test_fresh_flag() {
+    let options = AppOptions::try_parse_from(["fireworks-sim", "--fresh"], |_| None).unwrap();
+    assert!(options.fresh);
+    assert!(!AppOptions::default().fresh);
+}
+
+// ==================================
+// 3. Capture et restauration par le simulateur
+// ==================================
+
+#[test]
+fn test_simulator_session_round_trip() {
+    let mut sim = build_simulator();
+    let session = sample_session();
+    sim.restore_session(&session);
+
+    assert_eq!(sim.audio_engine().volume(), 0.4);
+    assert!(sim.audio_engine().is_muted());
+    assert_eq!(
+        sim.physic_engine().get_config().rocket_palette,
+        session.physic.as_ref().unwrap().rocket_palette
+    );
+    assert_eq!(sim.physic_engine().explosion_shape_name(), "ring");
+
+    let captured = sim.capture_session();
+    assert_eq!(captured.audio, session.audio);
+    assert_eq!(captured.physic, session.physic);
+    // Pas de fenêtre ni de caméra sans renderer
+    assert_eq!(captured.window, None);
+}
+
+#[test]
+fn test_unknown_session_shape_keeps_the_sphere() {
+    let mut sim = build_simulator();
+    let mut session = sample_session();
+    session.physic.as_mut().unwrap().explosion_shape = "cube".into();
+    sim.restore_session(&session);
+    assert_eq!(sim.physic_engine().explosion_shape_name(), "sphere");
+}
+
+#[test]
+fn test_session_saved_on_close_unless_reset() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.toml");
+
+    let mut sim = build_simulator();
+    sim.set_session_file(Some(path.clone()));
+    sim.audio_engine.set_volume(0.7);
+    sim.close();
+    let saved = Session::load(&path).unwrap().unwrap();
+    assert_eq!(saved.audio.unwrap().volume, 0.7);
+
+    // `sim.session.reset` : fichier effacé, rien d'enregistré
+    let mut sim = build_simulator();
+    sim.init_console_commands();
+    sim.set_session_file(Some(path.clone()));
+    let script = dir.path().join("reset.cfg");
+    std::fs::write(&script, "sim.session.reset\n").unwrap();
+    sim.exec_script(&ExecArgs {
+        path: script,
+        abort_on_error: true,
+    })
+    .unwrap();
+    sim.close();
+    assert!(!path.exists());
+}
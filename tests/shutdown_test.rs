@@ -0,0 +1,101 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::fireworks_audio::FireworksAudio3D;
+use fireworks_sim::audio_engine::types::FireworksAudioConfig;
+use fireworks_sim::audio_engine::AudioEngine;
+use fireworks_sim::utils::panic_hook::install_panic_hook;
+use fireworks_sim::AudioEngineSettings;
+use fireworks_sim::Simulator;
+use helpers::{TestAudio, TestPhysic, TestRenderer};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+fn build_audio_engine() -> FireworksAudio3D {
+    FireworksAudio3D::new(FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (0.0, 0.0),
+        sample_rate: 44100,
+        block_size: 1024,
+        max_voices: 16,
+        settings: AudioEngineSettings::default(),
+    })
+}
+
+const CLOSE_CALLS: [&str; 3] = ["renderer.close", "physic.close", "audio.stop"];
+
+// ==================================
+// 1. close idempotent
+// ==================================
+
+#[test]
+fn test_simulator_close_is_idempotent() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut sim = Simulator::new(
+        TestRenderer::new(log.clone()),
+        TestPhysic::new(log.clone()),
+        TestAudio::new(log.clone()),
+    );
+    sim.close();
+    sim.close();
+    drop(sim);
+
+    assert_eq!(*log.borrow(), CLOSE_CALLS);
+}
+
+#[test]
+fn test_simulator_drop_closes_the_engines() {
+    let log = Rc::new(RefCell::new(vec![]));
+    {
+        let mut sim = Simulator::new(
+            TestRenderer::new(log.clone()),
+            TestPhysic::new(log.clone()),
+            TestAudio::new(log.clone()),
+        );
+        // Retour anticipé : pas d'appel explicite à `close`
+        sim.step(0.01);
+    }
+    let calls = log.borrow();
+    assert_eq!(calls[calls.len() - 3..], CLOSE_CALLS);
+}
+
+#[test]
+fn test_audio_stop_is_idempotent() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("out.wav");
+    let mut audio = build_audio_engine();
+    audio.start_offline(Some(path.to_str().unwrap()));
+    audio.advance_offline(0.1);
+
+    audio.stop_audio_thread();
+    assert!(!audio.shutdown_handle().is_running());
+    audio.stop_audio_thread();
+
+    // Export finalisé une seule fois, fichier lisible
+    let reader = hound::WavReader::open(&path).unwrap();
+    assert!(reader.duration() > 0);
+}
+
+// ==================================
+// 2. Hook de panique
+// ==================================
+
+#[test]
+fn test_panic_hook_stops_the_audio_engine() {
+    let audio = build_audio_engine();
+    let shutdown = audio.shutdown_handle();
+    assert!(shutdown.is_running());
+
+    install_panic_hook(audio.shutdown_handle());
+    let result = std::panic::catch_unwind(|| panic!("render thread panic"));
+    // Retour au hook par défaut pour les autres tests du binaire
+    let _ = std::panic::take_hook();
+
+    assert!(result.is_err());
+    // Signal d'arrêt posé dans la paire (Mutex, Condvar) du thread audio
+    assert!(!shutdown.is_running());
+    // Thread audio jamais démarré : rien à attendre
+    assert!(shutdown.wait_finished(Duration::ZERO));
+}
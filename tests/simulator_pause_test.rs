@@ -0,0 +1,207 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::{FireworksAudio3D, FireworksAudioConfig};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_sim::register_sim_commands;
+use fireworks_sim::renderer_engine::key_bindings::KeyBindings;
+use fireworks_sim::renderer_engine::window_event::{Action, KeyCode};
+use fireworks_sim::sim_clock::{FrameTiming, SimClock, RESUME_MAX_DT};
+use fireworks_sim::simulator::{begin_sim_frame, step_simulation};
+use fireworks_sim::{AudioEngine, AudioEngineSettings};
+use helpers::{SharedLog, TestAudio, TestPhysic};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Horloge factice : `at(ms)` est l'instant `t0 + ms`
+struct MockClock {
+    t0: Instant,
+}
+
+impl MockClock {
+    fn new() -> Self {
+        Self { t0: Instant::now() }
+    }
+
+    fn at(&self, ms: u64) -> Instant {
+        self.t0 + Duration::from_millis(ms)
+    }
+}
+
+/// Moteurs factices et registre des commandes `sim.*` (état partagé avec la
+/// boucle de rendu)
+struct PauseFixture {
+    physic: TestPhysic,
+    audio: TestAudio,
+    registry: CommandRegistry,
+    clock: SimClock,
+    log: SharedLog,
+}
+
+impl PauseFixture {
+    fn new() -> Self {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut registry = CommandRegistry::new();
+        register_sim_commands(&mut registry);
+        Self {
+            physic: TestPhysic::new(log.clone()),
+            audio: TestAudio::new(log.clone()),
+            registry,
+            clock: SimClock::new(),
+            log,
+        }
+    }
+
+    fn command(&mut self, line: &str) -> String {
+        self.registry
+            .execute(&mut self.audio, &mut self.physic, line)
+    }
+
+    /// Frame de la boucle de rendu : horloge réglée par l'état `sim.*`, puis pas
+    /// de simulation partagé (rien en pause)
+    fn frame(&mut self, now: Instant) -> FrameTiming {
+        let state = self.registry.sim_state();
+        let timing = begin_sim_frame(&mut self.clock, state, &mut self.audio, now);
+        if !timing.paused {
+            step_simulation(
+                None,
+                &mut self.physic,
+                &mut self.audio,
+                state,
+                None,
+                timing.sim_dt,
+            );
+        }
+        timing
+    }
+}
+
+// ==================================
+// 1. Horloge de simulation
+// ==================================
+
+#[test]
+fn test_sim_clock_frame_timing() {
+    let clock = MockClock::new();
+    let mut sim_clock = SimClock::new();
+
+    // Première frame : durée nulle
+    let timing = sim_clock.update_frame_timing(clock.at(0));
+    assert_eq!((timing.frame_dt, timing.sim_dt), (0.0, 0.0));
+
+    let timing = sim_clock.update_frame_timing(clock.at(20));
+    assert!((timing.sim_dt - 0.020).abs() < 1e-6);
+    assert!(!timing.paused);
+
+    // En pause : le temps réel passe, pas le temps simulé
+    assert!(sim_clock.set_paused(true));
+    assert!(!sim_clock.set_paused(true));
+    let timing = sim_clock.update_frame_timing(clock.at(40));
+    assert!((timing.frame_dt - 0.020).abs() < 1e-6);
+    assert_eq!(timing.sim_dt, 0.0);
+    assert!(timing.paused);
+    assert!((sim_clock.sim_time() - 0.020).abs() < 1e-6);
+
+    // Reprise après une frame très longue : pas borné, une seule fois
+    assert!(sim_clock.set_paused(false));
+    let timing = sim_clock.update_frame_timing(clock.at(2_040));
+    assert!((timing.frame_dt - 2.0).abs() < 1e-6);
+    assert_eq!(timing.sim_dt, RESUME_MAX_DT);
+    let timing = sim_clock.update_frame_timing(clock.at(2_140));
+    assert!((timing.sim_dt - 0.100).abs() < 1e-6);
+}
+
+// ==================================
+// 2. Pause globale du simulateur
+// ==================================
+
+#[test]
+fn test_no_physics_update_while_paused() {
+    let clock = MockClock::new();
+    let mut sim = PauseFixture::new();
+
+    sim.frame(clock.at(0));
+    sim.frame(clock.at(16));
+    assert_eq!(sim.physic.update_dts.len(), 2);
+
+    assert_eq!(sim.command("sim.pause"), "Simulation paused");
+    for frame in 1..=100 {
+        let timing = sim.frame(clock.at(16 + frame * 16));
+        assert!(timing.paused);
+        assert_eq!(timing.sim_dt, 0.0);
+    }
+    // Aucun pas de physique pendant les 100 frames en pause
+    assert_eq!(sim.physic.update_dts.len(), 2);
+    assert!(sim.clock.is_paused());
+    assert!(sim
+        .log
+        .borrow()
+        .contains(&"audio.set_paused(true)".to_string()));
+
+    // Touche P : action liée par défaut, qui bascule le même état
+    assert_eq!(
+        KeyBindings::default().action_for(KeyCode::P),
+        Some(Action::PauseSim)
+    );
+}
+
+#[test]
+fn test_resume_clamps_dt() {
+    let clock = MockClock::new();
+    let mut sim = PauseFixture::new();
+
+    sim.frame(clock.at(0));
+    sim.command("sim.pause on");
+    sim.frame(clock.at(16));
+    // Pause de 10 s, puis reprise sur une frame de 5 s
+    sim.frame(clock.at(10_016));
+    assert_eq!(sim.command("sim.pause off"), "Simulation resumed");
+    sim.frame(clock.at(15_016));
+    sim.frame(clock.at(15_032));
+
+    let dts = &sim.physic.update_dts;
+    assert_eq!(dts.len(), 3);
+    assert_eq!(dts[1], RESUME_MAX_DT);
+    assert!((dts[2] - 0.016).abs() < 1e-6);
+
+    // L'audio n'est notifié qu'aux changements d'état
+    sim.command("sim.pause off");
+    sim.frame(clock.at(15_048));
+    let pauses: Vec<String> = sim
+        .log
+        .borrow()
+        .iter()
+        .filter(|l| l.starts_with("audio.set_paused"))
+        .cloned()
+        .collect();
+    assert_eq!(
+        pauses,
+        vec!["audio.set_paused(true)", "audio.set_paused(false)"]
+    );
+}
+
+#[test]
+fn test_offline_audio_frozen_while_paused() {
+    let dir = tempfile::tempdir().unwrap();
+    let wav = dir.path().join("paused.wav");
+    let mut audio = FireworksAudio3D::new(FireworksAudioConfig {
+        rocket_path: "assets/sounds/rocket.wav".into(),
+        explosion_path: "assets/sounds/explosion.wav".into(),
+        rumble_path: None,
+        listener_pos: (0.0, 0.0),
+        sample_rate: 48_000,
+        block_size: 512,
+        max_voices: 8,
+        settings: AudioEngineSettings::default(),
+    });
+    audio.start_offline(wav.to_str());
+    audio.advance_offline(0.5);
+    audio.set_paused(true);
+    audio.advance_offline(1.0);
+    audio.set_paused(false);
+    audio.advance_offline(0.5);
+    audio.stop_audio_thread();
+
+    // La seconde en pause n'est pas rendue
+    assert_eq!(hound::WavReader::open(&wav).unwrap().duration(), 48_000);
+}
@@ -0,0 +1,193 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::mixer::{mix_voices, playback_rate, resample_into};
+use fireworks_sim::audio_engine::types::{PlayRequest, Voice};
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::command_sim::register_sim_commands;
+use fireworks_sim::renderer_engine::key_bindings::KeyBindings;
+use fireworks_sim::renderer_engine::window_event::{Action, KeyCode};
+use fireworks_sim::sim_clock::{
+    clamp_sim_speed, next_speed_preset, FrameTiming, SimClock, SimSpeed, SIM_SPEED_MAX,
+    SIM_SPEED_MIN,
+};
+use fireworks_sim::simulator::{begin_sim_frame, step_simulation};
+use helpers::{TestAudio, TestPhysic};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+fn ms(t0: Instant, ms: u64) -> Instant {
+    t0 + Duration::from_millis(ms)
+}
+
+// ==================================
+// 1. Vitesse de simulation
+// ==================================
+
+#[test]
+fn test_speed_clamping() {
+    assert_eq!(clamp_sim_speed(0.5), 0.5);
+    assert_eq!(clamp_sim_speed(0.01), SIM_SPEED_MIN);
+    assert_eq!(clamp_sim_speed(100.0), SIM_SPEED_MAX);
+    assert_eq!(clamp_sim_speed(f32::NAN), 1.0);
+    assert_eq!(SimSpeed::default().value(), 1.0);
+    assert_eq!(SimSpeed::new(-3.0).value(), SIM_SPEED_MIN);
+
+    let mut clock = SimClock::new();
+    assert_eq!(clock.time_scale(), 1.0);
+    assert_eq!(clock.set_time_scale(8.0), SIM_SPEED_MAX);
+    assert_eq!(clock.time_scale(), SIM_SPEED_MAX);
+}
+
+#[test]
+fn test_speed_presets_and_bracket_keys() {
+    assert_eq!(next_speed_preset(1.0, true), 1.5);
+    assert_eq!(next_speed_preset(1.0, false), 0.5);
+    // Vitesse quelconque : preset voisin
+    assert_eq!(next_speed_preset(0.7, true), 1.0);
+    assert_eq!(next_speed_preset(0.7, false), 0.5);
+    // Aux bornes, la vitesse ne bouge plus
+    assert_eq!(next_speed_preset(SIM_SPEED_MAX, true), SIM_SPEED_MAX);
+    assert_eq!(next_speed_preset(SIM_SPEED_MIN, false), SIM_SPEED_MIN);
+
+    let bindings = KeyBindings::default();
+    assert_eq!(
+        bindings.action_for(KeyCode::LeftBracket),
+        Some(Action::SpeedDown)
+    );
+    assert_eq!(
+        bindings.action_for(KeyCode::RightBracket),
+        Some(Action::SpeedUp)
+    );
+    assert_eq!(Action::from_name("speed_up"), Some(Action::SpeedUp));
+    assert_eq!(KeyCode::from_name("]"), Some(KeyCode::RightBracket));
+}
+
+// ==================================
+// 2. Pas de simulation
+// ==================================
+
+/// Frame de la boucle de rendu : horloge réglée par l'état `sim.*`, puis pas
+/// de simulation partagé (rien en pause)
+fn run_frame(
+    clock: &mut SimClock,
+    registry: &CommandRegistry,
+    physic: &mut TestPhysic,
+    audio: &mut TestAudio,
+    now: Instant,
+) -> FrameTiming {
+    let state = registry.sim_state();
+    let timing = begin_sim_frame(clock, state, audio, now);
+    if !timing.paused {
+        step_simulation(None, physic, audio, state, None, timing.sim_dt);
+    }
+    timing
+}
+
+#[test]
+fn test_speed_multiplies_dt() {
+    let t0 = Instant::now();
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut physic = TestPhysic::new(log.clone());
+    let mut audio = TestAudio::new(log.clone());
+    let mut registry = CommandRegistry::new();
+    register_sim_commands(&mut registry);
+    let mut clock = SimClock::new();
+
+    run_frame(&mut clock, &registry, &mut physic, &mut audio, ms(t0, 0));
+    registry.execute(&mut audio, &mut physic, "sim.speed 0.25");
+    run_frame(&mut clock, &registry, &mut physic, &mut audio, ms(t0, 20));
+    registry.execute(&mut audio, &mut physic, "sim.speed 2");
+    run_frame(&mut clock, &registry, &mut physic, &mut audio, ms(t0, 40));
+
+    let dts = &physic.update_dts;
+    assert_eq!(dts.len(), 3);
+    assert!((dts[1] - 0.005).abs() < 1e-6, "{:?}", dts);
+    assert!((dts[2] - 0.040).abs() < 1e-6, "{:?}", dts);
+    assert_eq!(clock.time_scale(), 2.0);
+
+    // L'audio suit la vitesse, notifié aux seuls changements
+    let scales: Vec<String> = log
+        .borrow()
+        .iter()
+        .filter(|l| l.starts_with("audio.set_time_scale"))
+        .cloned()
+        .collect();
+    assert_eq!(
+        scales,
+        ["audio.set_time_scale(0.25)", "audio.set_time_scale(2)"]
+    );
+}
+
+#[test]
+fn test_resume_clamp_is_scaled() {
+    let t0 = Instant::now();
+    let mut clock = SimClock::new();
+    clock.set_time_scale(0.5);
+    clock.update_frame_timing(ms(t0, 0));
+    clock.set_paused(true);
+    clock.update_frame_timing(ms(t0, 16));
+    clock.set_paused(false);
+    let timing = clock.update_frame_timing(ms(t0, 5_000));
+    assert!((timing.sim_dt - 0.5 / 60.0).abs() < 1e-6);
+}
+
+// ==================================
+// 3. Vitesse de lecture audio
+// ==================================
+
+#[test]
+fn test_playback_rate_math() {
+    // Option désactivée : hauteur inchangée
+    assert_eq!(playback_rate(0.25, false), 1.0);
+    assert_eq!(playback_rate(0.25, true), 0.25);
+    assert_eq!(playback_rate(100.0, true), 4.0);
+    assert_eq!(playback_rate(f32::INFINITY, true), 1.0);
+
+    let data: Vec<[f32; 2]> = (0..5).map(|i| [i as f32, -(i as f32)]).collect();
+    let mut out = [[0.0; 2]; 16];
+
+    // Demi-vitesse : interpolation entre les échantillons
+    let n = resample_into(&data, 0.0, 0.5, &mut out);
+    assert_eq!(n, 9);
+    let left: Vec<f32> = out[..n].iter().map(|f| f[0]).collect();
+    assert_eq!(left, [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0]);
+    assert_eq!(out[1][1], -0.5);
+
+    // Double vitesse, depuis une position fractionnaire
+    let n = resample_into(&data, 0.5, 2.0, &mut out);
+    let left: Vec<f32> = out[..n].iter().map(|f| f[0]).collect();
+    assert_eq!(left, [0.5, 2.5]);
+}
+
+/// Frames de mixage nécessaires pour jouer entièrement un son de `len` frames
+fn frames_to_finish(len: usize, rate: f32) -> usize {
+    let mut voices = [Voice::new()];
+    voices[0].reset_from_request(&PlayRequest {
+        data: vec![[0.5, 0.5]; len],
+        fade_in: 0,
+        fade_out: 0,
+        gain: 1.0,
+        filter_a: 1.0,
+        distance: None,
+        attenuation: 1.0,
+        sustain: None,
+        sent_at: Instant::now(),
+    });
+    let mut acc = vec![[0.0; 2]; 64];
+    let mut chunk = vec![[0.0; 2]; 64];
+    let mut frames = 0;
+    while voices[0].active {
+        mix_voices(&mut voices, &mut acc, &mut chunk, rate);
+        frames += acc.len();
+    }
+    frames
+}
+
+#[test]
+fn test_voice_duration_follows_playback_rate() {
+    assert_eq!(frames_to_finish(1024, 1.0), 1024);
+    // Ralenti x0.5 : le son dure deux fois plus longtemps
+    assert_eq!(frames_to_finish(1024, 0.5), 2048);
+    assert_eq!(frames_to_finish(1024, 2.0), 512);
+}
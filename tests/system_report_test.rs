@@ -0,0 +1,194 @@
+mod helpers;
+
+use fireworks_sim::audio_engine::AudioConfig;
+use fireworks_sim::config_presets::PresetSnapshot;
+use fireworks_sim::physic_engine::config::PhysicConfig;
+use fireworks_sim::renderer_engine::command_console::CommandRegistry;
+use fireworks_sim::renderer_engine::config::RendererConfig;
+use fireworks_sim::renderer_engine::renderer::{register_renderer_commands, RendererShared};
+use fireworks_sim::utils::system_report::{
+    parse_os_release, record_audio, system_report, AudioBackendInfo, BugReport, GraphicsInfo,
+    SystemReport,
+};
+use helpers::{DummyAudio, DummyPhysic};
+
+fn sample_report() -> SystemReport {
+    SystemReport {
+        graphics: Some(GraphicsInfo {
+            vendor: "Mesa".into(),
+            renderer: "llvmpipe (LLVM 17.0.6, 256 bits)".into(),
+            version: "4.5 (Core Profile) Mesa 24.0.5".into(),
+            glsl_version: "4.50".into(),
+        }),
+        audio: Some(AudioBackendInfo {
+            host: "ALSA".into(),
+            device: Some("default".into()),
+            sample_rate: 48_000,
+            block_size: 512,
+        }),
+        ..SystemReport::gather()
+    }
+}
+
+// ==================================
+// 1. Infos relevées sans matériel
+// ==================================
+
+#[test]
+fn test_gather_host_information() {
+    let report = SystemReport::gather();
+    assert_eq!(report.app_version, env!("CARGO_PKG_VERSION"));
+    assert!(!report.rustc_version.is_empty());
+    assert_eq!(report.os.name, std::env::consts::OS);
+    assert_eq!(report.os.arch, std::env::consts::ARCH);
+    assert!(report.cpu_cores >= 1);
+    // Versions relevées par build.rs
+    for name in ["gl", "glfw", "cpal"] {
+        let version = &report.crates[name];
+        assert_ne!(version, "Unknown", "{}", name);
+        assert!(
+            version.chars().next().unwrap().is_ascii_digit(),
+            "{}",
+            version
+        );
+    }
+    // Pas de contexte OpenGL ni de flux audio ouverts par `gather`
+    assert_eq!(report.graphics, None);
+    assert_eq!(report.audio, None);
+}
+
+#[test]
+fn test_parse_os_release() {
+    let text = "NAME=\"Ubuntu\"\nVERSION_ID=\"24.04\"\nPRETTY_NAME=\"Ubuntu 24.04.1 LTS\"\n";
+    assert_eq!(
+        parse_os_release(text).as_deref(),
+        Some("Ubuntu 24.04.1 LTS")
+    );
+    assert_eq!(
+        parse_os_release("PRETTY_NAME=Arch Linux").as_deref(),
+        Some("Arch Linux")
+    );
+    assert_eq!(parse_os_release("NAME=Debian\n"), None);
+    assert_eq!(parse_os_release("PRETTY_NAME=\"\"\n"), None);
+}
+
+#[test]
+fn test_recorded_audio_backend_in_global_report() {
+    let audio = AudioBackendInfo {
+        host: "offline".into(),
+        device: None,
+        sample_rate: 44_100,
+        block_size: 1024,
+    };
+    record_audio(audio.clone());
+    assert_eq!(system_report().audio, Some(audio));
+}
+
+// ==================================
+// 2. Affichage et sérialisation
+// ==================================
+
+#[test]
+fn test_pretty_report() {
+    let pretty = sample_report().pretty();
+    assert!(pretty.starts_with("Fireworks sim "), "{}", pretty);
+    for expected in [
+        "CPU cores",
+        "GLFW library",
+        "cpal  crate",
+        "GL renderer   : llvmpipe (LLVM 17.0.6, 256 bits)",
+        "GL version    : 4.5 (Core Profile) Mesa 24.0.5 (GLSL 4.50)",
+        "Audio         : ALSA / default @ 48000 Hz, blocks of 512",
+    ] {
+        assert!(pretty.contains(expected), "{}\n{}", expected, pretty);
+    }
+
+    let bare = SystemReport::gather().pretty();
+    assert!(bare.contains("OpenGL        : no context"), "{}", bare);
+    assert!(bare.contains("Audio         : not started"), "{}", bare);
+}
+
+#[test]
+fn test_json_round_trip() {
+    let report = sample_report();
+    let json = report.to_json().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["graphics"]["vendor"], "Mesa");
+    assert_eq!(value["audio"]["sample_rate"], 48_000);
+    assert_eq!(value["os"]["name"], std::env::consts::OS);
+    assert!(value["crates"]["glfw"].is_string());
+
+    let parsed: SystemReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, report);
+
+    // Sections absentes : `null`
+    let bare: serde_json::Value =
+        serde_json::from_str(&SystemReport::gather().to_json().unwrap()).unwrap();
+    assert!(bare["graphics"].is_null());
+}
+
+// ==================================
+// 3. Rapport de bug (`sim.report`)
+// ==================================
+
+#[test]
+fn test_bug_report_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("reports/bug.json");
+    let report = sample_report();
+    let physic = PhysicConfig::default();
+    let renderer = RendererConfig::default();
+    let audio = AudioConfig::default();
+    BugReport {
+        system: &report,
+        configs: PresetSnapshot {
+            physic: &physic,
+            renderer: &renderer,
+            audio: &audio,
+        },
+    }
+    .write(&path)
+    .unwrap();
+
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(value["system"]["graphics"]["glsl_version"], "4.50");
+    assert_eq!(
+        value["configs"]["physic"]["max_rockets"],
+        physic.max_rockets
+    );
+    assert_eq!(value["configs"]["renderer"]["max_gpu_buffer"], "256 MiB");
+    assert_eq!(value["configs"]["audio"]["sample_rate"], audio.sample_rate);
+}
+
+#[test]
+fn test_sim_report_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.json");
+    let mut registry = CommandRegistry::new();
+    register_renderer_commands(&mut registry, &RendererShared::default());
+
+    let mut physic = DummyPhysic::default();
+    physic.config.max_rockets = 77;
+    let out = registry.execute(
+        &mut DummyAudio,
+        &mut physic,
+        &format!("sim.report {}", path.display()),
+    );
+    assert_eq!(out, format!("Bug report written to {}", path.display()));
+
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(value["configs"]["physic"]["max_rockets"], 77);
+    assert_eq!(value["system"]["cpu_cores"], system_report().cpu_cores);
+
+    // Répertoire impossible à créer : erreur rapportée
+    let blocker = dir.path().join("file");
+    std::fs::write(&blocker, "").unwrap();
+    let out = registry.execute(
+        &mut DummyAudio,
+        &mut physic,
+        &format!("sim.report {}", blocker.join("report.json").display()),
+    );
+    assert!(out.starts_with("Bug report not written: "), "{}", out);
+}
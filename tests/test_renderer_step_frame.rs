@@ -23,7 +23,7 @@ fn test_renderer_step_frame_coverage() {
 
     // ✅ On appelle step_frame directement pour couvrir tout
     unsafe {
-        renderer.render_frame(&mut physic);
+        renderer.render_frame(&physic);
     }
 
     // Vérifie qu'on peut fermer correctement
@@ -0,0 +1,205 @@
+use fireworks_sim::app_options::AppOptions;
+use fireworks_sim::utils::human_bytes::ByteSize;
+use fireworks_sim::utils::log_file::{
+    format_timestamp, log_file, open_log_file, rotated_path, FileLogger, LogFileConfig,
+    RotatingFile, DEFAULT_LOG_FILE_KEEP, DEFAULT_LOG_FILE_MAX_SIZE, LOG_FILE_ENV,
+};
+use fireworks_sim::utils::log_sink::LogFilter;
+use fireworks_sim::utils::panic_hook::write_panic_to_log_file;
+use log::{Level, Log, Record};
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn config(path: &Path, max_size: u64, keep: usize) -> LogFileConfig {
+    LogFileConfig {
+        path: path.to_path_buf(),
+        max_size: ByteSize(max_size),
+        keep,
+    }
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+// ==================================
+// 1. Rotation
+// ==================================
+
+#[test]
+fn test_rotation_when_size_exceeded() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("logs/fireworks.log");
+    // Lignes de 10 octets (saut de ligne compris), 25 octets par fichier
+    let mut file = RotatingFile::open(config(&path, 25, 2)).unwrap();
+    for i in 0..2 {
+        file.write_line(&format!("line {:04}", i)).unwrap();
+    }
+    file.flush().unwrap();
+    assert!(!rotated_path(&path, 1).exists());
+    assert_eq!(read(&path), "line 0000\nline 0001\n");
+
+    // La 3e ligne dépasserait 25 octets : bascule vers fireworks.log.1
+    file.write_line("line 0002").unwrap();
+    file.flush().unwrap();
+    assert_eq!(read(&rotated_path(&path, 1)), "line 0000\nline 0001\n");
+    assert_eq!(read(&path), "line 0002\n");
+
+    for i in 3..8 {
+        file.write_line(&format!("line {:04}", i)).unwrap();
+    }
+    file.flush().unwrap();
+    // Deux archives au plus, la plus ancienne est supprimée
+    assert_eq!(read(&path), "line 0006\nline 0007\n");
+    assert_eq!(read(&rotated_path(&path, 1)), "line 0004\nline 0005\n");
+    assert_eq!(read(&rotated_path(&path, 2)), "line 0002\nline 0003\n");
+    assert!(!rotated_path(&path, 3).exists());
+}
+
+#[test]
+fn test_rotation_without_archives_truncates() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("run.log");
+    let mut file = RotatingFile::open(config(&path, 12, 0)).unwrap();
+    file.write_line("first line").unwrap();
+    file.write_line("second").unwrap();
+    file.flush().unwrap();
+    assert_eq!(read(&path), "second\n");
+    assert!(!rotated_path(&path, 1).exists());
+}
+
+#[test]
+fn test_oversized_line_and_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("run.log");
+    {
+        let mut file = RotatingFile::open(config(&path, 8, 3)).unwrap();
+        // Plus longue que la limite : écrite seule dans un fichier
+        file.write_line("a very long line").unwrap();
+        file.flush().unwrap();
+    }
+    assert_eq!(read(&path), "a very long line\n");
+
+    // Réouverture en ajout : la taille existante compte
+    let mut file = RotatingFile::open(config(&path, 8, 3)).unwrap();
+    file.write_line("next").unwrap();
+    file.flush().unwrap();
+    assert_eq!(read(&rotated_path(&path, 1)), "a very long line\n");
+    assert_eq!(read(&path), "next\n");
+}
+
+// ==================================
+// 2. Formatage des enregistrements
+// ==================================
+
+#[test]
+fn test_format_timestamp() {
+    assert_eq!(format_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+    let t = UNIX_EPOCH + Duration::from_millis(1_792_056_707_507);
+    assert_eq!(format_timestamp(t), "2026-10-15T09:31:47.507Z");
+    // 29 février d'une année bissextile
+    let leap = UNIX_EPOCH + Duration::from_secs(951_782_400);
+    assert_eq!(format_timestamp(leap), "2000-02-29T00:00:00.000Z");
+}
+
+#[test]
+fn test_file_logger_filters_and_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("run.log");
+    let logger = FileLogger::new(
+        RotatingFile::open(config(&path, 1 << 20, 1)).unwrap(),
+        "info,cpal=warn".parse::<LogFilter>().unwrap(),
+    );
+    for (target, level, message) in [
+        ("fireworks_sim::simulator", Level::Info, "🚀 started"),
+        ("fireworks_sim::simulator", Level::Debug, "hidden"),
+        ("cpal::host::alsa", Level::Info, "hidden too"),
+        (
+            "fireworks_sim::audio_engine",
+            Level::Error,
+            "❌ CPAL stream error",
+        ),
+    ] {
+        logger.log(
+            &Record::builder()
+                .target(target)
+                .level(level)
+                .args(format_args!("{}", message))
+                .build(),
+        );
+    }
+    // Les erreurs sont vidées immédiatement, sans `flush`
+    let text = read(&path);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", text);
+    assert!(lines[0].ends_with(" INFO  [fireworks_sim::simulator] 🚀 started"));
+    assert!(lines[1].ends_with(" ERROR [fireworks_sim::audio_engine] ❌ CPAL stream error"));
+    assert!(lines[0].chars().nth(4) == Some('-') && lines[0].contains('T'));
+}
+
+#[test]
+fn test_panic_message_written_to_global_log_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("panic.log");
+    open_log_file(config(&path, 1 << 20, 1)).unwrap();
+    assert!(log_file().is_some());
+
+    write_panic_to_log_file("boom at src/main.rs:1:1");
+    assert!(
+        read(&path)
+            .contains("💥 Panic in thread 'test_panic_message_written_to_global_log_file': boom"),
+        "{}",
+        read(&path)
+    );
+}
+
+// ==================================
+// 3. Options
+// ==================================
+
+#[test]
+fn test_log_file_options() {
+    let no_env = |_: &str| None;
+    assert_eq!(
+        AppOptions::try_parse_from(["app"], no_env)
+            .unwrap()
+            .log_file,
+        None
+    );
+
+    let options = AppOptions::try_parse_from(["app", "--log-file", "run.log"], no_env).unwrap();
+    let log_file = options.log_file.unwrap();
+    assert_eq!(log_file.path, Path::new("run.log"));
+    assert_eq!(log_file.max_size, DEFAULT_LOG_FILE_MAX_SIZE);
+    assert_eq!(log_file.keep, DEFAULT_LOG_FILE_KEEP);
+
+    let options = AppOptions::try_parse_from(
+        [
+            "app",
+            "headless",
+            "--log-file",
+            "run.log",
+            "--log-file-max-size",
+            "2 MiB",
+            "--log-file-keep",
+            "9",
+        ],
+        no_env,
+    )
+    .unwrap();
+    let log_file = options.log_file.unwrap();
+    assert_eq!(log_file.max_size, ByteSize::mib(2));
+    assert_eq!(log_file.keep, 9);
+
+    // Variable d'environnement en repli
+    let env = |name: &str| (name == LOG_FILE_ENV).then(|| "env.log".to_string());
+    let options = AppOptions::try_parse_from(["app"], env).unwrap();
+    assert_eq!(options.log_file.unwrap().path, Path::new("env.log"));
+
+    assert!(AppOptions::try_parse_from(
+        ["app", "--log-file", "a", "--log-file-max-size", "1 TB"],
+        no_env
+    )
+    .is_err());
+    assert!(AppOptions::try_parse_from(["app", "--log-file-keep", "3"], no_env).is_err());
+}
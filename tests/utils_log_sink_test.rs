@@ -0,0 +1,102 @@
+use fireworks_sim::utils::log_sink::{LogFilter, LogSink};
+use log::{Level, LevelFilter, Log, Record};
+
+fn record(sink: &LogSink, target: &str, level: Level, message: &str) {
+    sink.log(
+        &Record::builder()
+            .target(target)
+            .level(level)
+            .args(format_args!("{}", message))
+            .build(),
+    );
+}
+
+// ==================================
+// 1. Filtre par module
+// ==================================
+
+#[test]
+fn test_default_filter_keeps_only_simulator_logs() {
+    let filter = LogFilter::default();
+    assert!(filter.enabled("fireworks_sim::renderer_engine::shader", Level::Info));
+    assert!(!filter.enabled("fireworks_sim::renderer_engine::shader", Level::Debug));
+    assert!(!filter.enabled("cpal::host::alsa", Level::Warn));
+    // Préfixe de module complet uniquement
+    assert!(!filter.enabled("fireworks_sim_extra", Level::Error));
+    assert_eq!(filter.max_level(), LevelFilter::Info);
+}
+
+#[test]
+fn test_filter_longest_prefix_wins() {
+    let filter: LogFilter = "warn, fireworks_sim=info, fireworks_sim::audio_engine=error"
+        .parse()
+        .unwrap();
+    assert_eq!(filter.level_for("glfw"), LevelFilter::Warn);
+    assert_eq!(
+        filter.level_for("fireworks_sim::simulator"),
+        LevelFilter::Info
+    );
+    assert_eq!(
+        filter.level_for("fireworks_sim::audio_engine::fireworks_audio"),
+        LevelFilter::Error
+    );
+
+    assert!("fireworks_sim=loud".parse::<LogFilter>().is_err());
+}
+
+// ==================================
+// 2. File bornée
+// ==================================
+
+#[test]
+fn test_sink_filters_and_formats_records() {
+    let sink = LogSink::new(LogFilter::default(), 8);
+    record(
+        &sink,
+        "fireworks_sim::renderer_engine::shader",
+        Level::Warn,
+        "reload failed",
+    );
+    record(&sink, "cpal::host::alsa", Level::Warn, "underrun");
+    record(&sink, "fireworks_sim::simulator", Level::Debug, "tick");
+
+    let (messages, dropped) = sink.drain();
+    assert_eq!(dropped, 0);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].level, Level::Warn);
+    assert_eq!(messages[0].text, "[shader] reload failed");
+
+    // Filtre changé à chaud
+    sink.set_filter("cpal=warn".parse().unwrap());
+    record(&sink, "cpal::host::alsa", Level::Warn, "underrun");
+    assert_eq!(sink.drain().0.len(), 1);
+}
+
+#[test]
+fn test_sink_queue_is_bounded_and_counts_drops() {
+    let sink = LogSink::new(LogFilter::default(), 3);
+    for i in 0..5 {
+        record(
+            &sink,
+            "fireworks_sim",
+            Level::Info,
+            &format!("message {}", i),
+        );
+    }
+    let (messages, dropped) = sink.drain();
+    let texts: Vec<&str> = messages.iter().map(|m| m.text.as_str()).collect();
+    // Les plus anciens sont gardés, les suivants comptés comme perdus
+    assert_eq!(
+        texts,
+        [
+            "[fireworks_sim] message 0",
+            "[fireworks_sim] message 1",
+            "[fireworks_sim] message 2"
+        ]
+    );
+    assert_eq!(dropped, 2);
+
+    // Compteur remis à zéro par le vidage
+    record(&sink, "fireworks_sim", Level::Info, "again");
+    assert_eq!(sink.drain().1, 0);
+}
@@ -0,0 +1,243 @@
+#![cfg(feature = "backend_winit")]
+
+use fireworks_sim::renderer_engine::utils::winit_window::{
+    translate_event, translate_key, translate_key_input, PIXELS_PER_SCROLL_STEP,
+};
+use fireworks_sim::renderer_engine::window_event::{
+    Action, EventRouter, KeyCode, MouseButton, Reaction, WindowEvent,
+};
+use std::path::PathBuf;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{DeviceId, ElementState, MouseScrollDelta, TouchPhase};
+use winit::keyboard::{KeyCode as WinitKey, NativeKeyCode, PhysicalKey};
+
+fn translate(event: winit::event::WindowEvent) -> Vec<WindowEvent> {
+    translate_event(&event, 2.0)
+}
+
+// ==================================
+// 1. Traduction des événements winit
+// ==================================
+
+#[test]
+fn test_translate_winit_events() {
+    // Framebuffer en pixels physiques, fenêtre en coordonnées logiques
+    assert_eq!(
+        translate(winit::event::WindowEvent::Resized(PhysicalSize::new(
+            1600, 1200
+        ))),
+        [
+            WindowEvent::Resize(1600, 1200),
+            WindowEvent::WindowSize(800, 600)
+        ]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::CursorMoved {
+            device_id: DeviceId::dummy(),
+            position: PhysicalPosition::new(200.0, 100.0),
+        }),
+        [WindowEvent::CursorPos(100.0, 50.0)]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state: ElementState::Pressed,
+            button: winit::event::MouseButton::Middle,
+        }),
+        [WindowEvent::MouseButton(MouseButton::Middle, true)]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::MouseWheel {
+            device_id: DeviceId::dummy(),
+            delta: MouseScrollDelta::LineDelta(0.0, -2.0),
+            phase: TouchPhase::Moved,
+        }),
+        [WindowEvent::Scroll(-2.0)]
+    );
+    // Pavé tactile : pixels ramenés en crans
+    assert_eq!(
+        translate(winit::event::WindowEvent::MouseWheel {
+            device_id: DeviceId::dummy(),
+            delta: MouseScrollDelta::PixelDelta(PhysicalPosition::new(0.0, PIXELS_PER_SCROLL_STEP)),
+            phase: TouchPhase::Moved,
+        }),
+        [WindowEvent::Scroll(1.0)]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::DroppedFile(PathBuf::from(
+            "shape.png"
+        ))),
+        [WindowEvent::FileDrop(vec![PathBuf::from("shape.png")])]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::Occluded(true)),
+        [WindowEvent::Iconified(true)]
+    );
+    assert_eq!(
+        translate(winit::event::WindowEvent::CloseRequested),
+        [WindowEvent::Close]
+    );
+    assert!(translate(winit::event::WindowEvent::Focused(true)).is_empty());
+}
+
+#[test]
+fn test_translate_key_input() {
+    let grave = PhysicalKey::Code(WinitKey::Backquote);
+    assert_eq!(
+        translate_key_input(grave, ElementState::Pressed, false, None),
+        [WindowEvent::KeyPress(KeyCode::GraveAccent)]
+    );
+    // Touche et texte produit
+    assert_eq!(
+        translate_key_input(
+            PhysicalKey::Code(WinitKey::KeyQ),
+            ElementState::Pressed,
+            false,
+            Some("a")
+        ),
+        [WindowEvent::KeyPress(KeyCode::Q), WindowEvent::Char('a')]
+    );
+    // Répétition : le texte seul ; relâchement ignoré
+    assert_eq!(
+        translate_key_input(
+            PhysicalKey::Code(WinitKey::KeyA),
+            ElementState::Pressed,
+            true,
+            Some("a")
+        ),
+        [WindowEvent::Char('a')]
+    );
+    assert!(translate_key_input(grave, ElementState::Released, false, None).is_empty());
+    // Caractères de contrôle (Entrée, Échap) non transmis comme texte
+    assert_eq!(
+        translate_key_input(
+            PhysicalKey::Code(WinitKey::Enter),
+            ElementState::Pressed,
+            false,
+            Some("\r")
+        ),
+        [WindowEvent::KeyPress(KeyCode::Enter)]
+    );
+    assert!(translate_key_input(
+        PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+        ElementState::Pressed,
+        false,
+        None
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_winit_keys_cover_every_key_code() {
+    assert_eq!(translate_key(WinitKey::Digit7), Some(KeyCode::Num7));
+    assert_eq!(
+        translate_key(WinitKey::BracketRight),
+        Some(KeyCode::RightBracket)
+    );
+    assert_eq!(translate_key(WinitKey::ContextMenu), None);
+
+    let translated: Vec<KeyCode> = [
+        WinitKey::KeyA,
+        WinitKey::KeyB,
+        WinitKey::KeyC,
+        WinitKey::KeyD,
+        WinitKey::KeyE,
+        WinitKey::KeyF,
+        WinitKey::KeyG,
+        WinitKey::KeyH,
+        WinitKey::KeyI,
+        WinitKey::KeyJ,
+        WinitKey::KeyK,
+        WinitKey::KeyL,
+        WinitKey::KeyM,
+        WinitKey::KeyN,
+        WinitKey::KeyO,
+        WinitKey::KeyP,
+        WinitKey::KeyQ,
+        WinitKey::KeyR,
+        WinitKey::KeyS,
+        WinitKey::KeyT,
+        WinitKey::KeyU,
+        WinitKey::KeyV,
+        WinitKey::KeyW,
+        WinitKey::KeyX,
+        WinitKey::KeyY,
+        WinitKey::KeyZ,
+        WinitKey::Digit0,
+        WinitKey::Digit1,
+        WinitKey::Digit2,
+        WinitKey::Digit3,
+        WinitKey::Digit4,
+        WinitKey::Digit5,
+        WinitKey::Digit6,
+        WinitKey::Digit7,
+        WinitKey::Digit8,
+        WinitKey::Digit9,
+        WinitKey::F1,
+        WinitKey::F2,
+        WinitKey::F3,
+        WinitKey::F4,
+        WinitKey::F5,
+        WinitKey::F6,
+        WinitKey::F7,
+        WinitKey::F8,
+        WinitKey::F9,
+        WinitKey::F10,
+        WinitKey::F11,
+        WinitKey::F12,
+        WinitKey::Escape,
+        WinitKey::Enter,
+        WinitKey::Tab,
+        WinitKey::Backspace,
+        WinitKey::Space,
+        WinitKey::Backquote,
+        WinitKey::BracketLeft,
+        WinitKey::BracketRight,
+        WinitKey::ArrowUp,
+        WinitKey::ArrowDown,
+        WinitKey::ArrowLeft,
+        WinitKey::ArrowRight,
+        WinitKey::PageUp,
+        WinitKey::PageDown,
+        WinitKey::Home,
+        WinitKey::End,
+    ]
+    .into_iter()
+    .filter_map(translate_key)
+    .collect();
+    // Chaque touche neutre a son équivalent winit, dans l'ordre de déclaration
+    assert_eq!(translated, KeyCode::ALL);
+}
+
+// ==================================
+// 2. Routage : mêmes réactions qu'avec glfw
+// ==================================
+
+#[test]
+fn test_winit_events_route_like_glfw() {
+    let mut router = EventRouter::new(600.0);
+    router.display.window_size = (400, 300);
+    router.display.framebuffer_size = (800, 600);
+
+    let reactions: Vec<Reaction> = [
+        winit::event::WindowEvent::Resized(PhysicalSize::new(800, 600)),
+        winit::event::WindowEvent::CloseRequested,
+    ]
+    .iter()
+    .flat_map(|event| translate_event(event, 2.0))
+    .filter_map(|event| router.route(&event, false))
+    .collect();
+    assert_eq!(reactions[0], Reaction::Resize(800, 600));
+    assert_eq!(reactions.last(), Some(&Reaction::Action(Action::Quit)));
+
+    let space = translate_key_input(
+        PhysicalKey::Code(WinitKey::Space),
+        ElementState::Pressed,
+        false,
+        Some(" "),
+    );
+    assert_eq!(
+        router.route(&space[0], false),
+        Some(Reaction::Action(Action::LaunchRocket))
+    );
+}